@@ -0,0 +1,38 @@
+# Copyright 2020-2025 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+"""Creates a federation for university degrees, accredits a university and validates a degree.
+
+Expects a local network node with the Hierarchies package deployed and an IOTA CLI keystore
+whose active address holds funds.
+"""
+
+import asyncio
+import os
+
+from hierarchies_py import FederationProperty, HierarchiesClient
+
+NODE_URL = os.environ.get("API_ENDPOINT", "http://127.0.0.1:9000")
+
+
+async def main() -> None:
+    client = await HierarchiesClient.connect(NODE_URL, os.environ.get("IOTA_HIERARCHIES_PKG_ID"))
+
+    federation_id = await client.create_federation()
+    print(f"Federation created: {federation_id}")
+
+    degree = FederationProperty("degree.level", allowed_values=["bachelor", "master", "doctorate"])
+    await client.add_property(federation_id, degree)
+
+    university = "0x" + os.urandom(32).hex()
+    await client.create_accreditation_to_attest(federation_id, university, [degree])
+    print(f"University {university} accredited to attest degree levels")
+
+    verifier = client.read_only()
+    assert await verifier.validate_property(federation_id, university, "degree.level", "master")
+    assert not await verifier.validate_property(federation_id, university, "degree.level", "honorary")
+    print("Degree validated")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
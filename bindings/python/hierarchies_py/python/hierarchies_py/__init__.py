@@ -0,0 +1,18 @@
+# Copyright 2020-2025 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+"""Python bindings for IOTA Trust Hierarchies."""
+
+from ._native import (
+    FederationProperty,
+    HierarchiesClient,
+    HierarchiesClientReadOnly,
+    HierarchiesError,
+)
+
+__all__ = [
+    "FederationProperty",
+    "HierarchiesClient",
+    "HierarchiesClientReadOnly",
+    "HierarchiesError",
+]
@@ -0,0 +1,77 @@
+# Copyright 2020-2025 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from typing import Any, Optional, Sequence, Union
+
+PropertyNameLike = Union[str, Sequence[str]]
+PropertyValueLike = Union[bool, int, str, bytes]
+
+class HierarchiesError(Exception): ...
+
+class FederationProperty:
+    def __init__(
+        self,
+        name: PropertyNameLike,
+        allowed_values: Optional[Sequence[PropertyValueLike]] = None,
+        allow_any: bool = False,
+        valid_from_ms: Optional[int] = None,
+        valid_until_ms: Optional[int] = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+
+class HierarchiesClientReadOnly:
+    @staticmethod
+    async def connect(url: str, package_id: Optional[str] = None) -> "HierarchiesClientReadOnly": ...
+    @property
+    def package_id(self) -> str: ...
+    @property
+    def network(self) -> str: ...
+    async def get_federation(self, federation_id: str) -> dict[str, Any]: ...
+    async def is_root_authority(self, federation_id: str, user_id: str) -> bool: ...
+    async def get_properties(self, federation_id: str) -> list[str]: ...
+    async def get_accreditations_to_attest(self, federation_id: str, user_id: str) -> dict[str, Any]: ...
+    async def validate_property(
+        self,
+        federation_id: str,
+        attester_id: str,
+        name: PropertyNameLike,
+        value: PropertyValueLike,
+    ) -> bool: ...
+    async def validate_properties(
+        self,
+        federation_id: str,
+        attester_id: str,
+        properties: dict[PropertyNameLike, PropertyValueLike],
+    ) -> bool: ...
+
+class HierarchiesClient:
+    @staticmethod
+    async def connect(url: str, package_id: Optional[str] = None) -> "HierarchiesClient": ...
+    @property
+    def sender_address(self) -> str: ...
+    def read_only(self) -> HierarchiesClientReadOnly: ...
+    async def create_federation(self) -> str: ...
+    async def add_root_authority(self, federation_id: str, account_id: str) -> None: ...
+    async def revoke_root_authority(
+        self, federation_id: str, account_id: str, *, confirm_irreversible: bool = False
+    ) -> None: ...
+    async def add_property(self, federation_id: str, property: FederationProperty) -> None: ...
+    async def revoke_property(
+        self,
+        federation_id: str,
+        name: PropertyNameLike,
+        valid_to_ms: Optional[int] = None,
+        *,
+        confirm_irreversible: bool = False,
+    ) -> None: ...
+    async def create_accreditation_to_attest(
+        self, federation_id: str, receiver: str, properties: Sequence[FederationProperty]
+    ) -> None: ...
+    async def revoke_accreditation_to_attest(self, federation_id: str, user_id: str, accreditation_id: str) -> None: ...
+    async def create_accreditation_to_accredit(
+        self, federation_id: str, receiver: str, properties: Sequence[FederationProperty]
+    ) -> None: ...
+    async def revoke_accreditation_to_accredit(
+        self, federation_id: str, user_id: str, accreditation_id: str
+    ) -> None: ...
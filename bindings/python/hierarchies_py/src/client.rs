@@ -0,0 +1,250 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use hierarchies::client::{HierarchiesClient, HierarchiesClientReadOnly};
+use hierarchies::core::transactions::Destructive;
+use iota_interaction_rust::keytool::KeytoolSigner;
+use product_common::core_client::CoreClient;
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::future_into_py;
+
+use crate::client_read_only::PyHierarchiesClientReadOnly;
+use crate::error::py_error;
+use crate::types::{PyFederationProperty, parse_object_id, parse_subject, property_name};
+
+/// A client signing transactions with the active address of the IOTA CLI keystore.
+///
+/// All transaction methods are coroutines resolving once the transaction was executed.
+#[pyclass(name = "HierarchiesClient", module = "hierarchies_py")]
+#[derive(Clone)]
+pub struct PyHierarchiesClient(Arc<HierarchiesClient<KeytoolSigner>>);
+
+#[pymethods]
+impl PyHierarchiesClient {
+    /// Connects to the IOTA node at `url`.
+    ///
+    /// The Hierarchies package is resolved from the network unless `package_id` is given.
+    #[staticmethod]
+    #[pyo3(signature = (url, package_id = None))]
+    fn connect(py: Python<'_>, url: String, package_id: Option<String>) -> PyResult<Bound<'_, PyAny>> {
+        let package_id = package_id.as_deref().map(parse_object_id).transpose()?;
+        future_into_py(py, async move {
+            let read_only = HierarchiesClientReadOnly::new_from_url(url, package_id)
+                .await
+                .map_err(py_error)?;
+            let signer = KeytoolSigner::builder().build().map_err(py_error)?;
+            let client = HierarchiesClient::new(read_only, signer).await.map_err(py_error)?;
+            Ok(Self(Arc::new(client)))
+        })
+    }
+
+    /// The address signing the transactions.
+    #[getter]
+    fn sender_address(&self) -> String {
+        self.0.sender_address().to_string()
+    }
+
+    /// Returns a read-only client sharing the connection of this client.
+    fn read_only(&self) -> PyHierarchiesClientReadOnly {
+        PyHierarchiesClientReadOnly((**self.0).clone())
+    }
+
+    /// Creates a new federation with the sender as root authority and returns its ID.
+    fn create_federation<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        future_into_py(py, async move {
+            let federation = client
+                .create_new_federation()
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?
+                .output;
+            Ok(federation.id.object_id().to_string())
+        })
+    }
+
+    /// Adds a root authority to the federation.
+    fn add_root_authority<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        account_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let account_id = parse_object_id(account_id)?;
+        future_into_py(py, async move {
+            client
+                .add_root_authority(federation_id, account_id)
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Revokes a root authority of the federation.
+    ///
+    /// The revocation takes effect immediately and can only be reversed by an explicit reinstatement,
+    /// so it fails unless `confirm_irreversible` is true.
+    #[pyo3(signature = (federation_id, account_id, *, confirm_irreversible = false))]
+    fn revoke_root_authority<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        account_id: &str,
+        confirm_irreversible: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let account_id = parse_object_id(account_id)?;
+        future_into_py(py, async move {
+            let builder = client.revoke_root_authority(federation_id, account_id);
+            let builder = if confirm_irreversible {
+                builder.confirm_irreversible()
+            } else {
+                builder
+            };
+            builder.build_and_execute(&*client).await.map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Adds a property to the federation.
+    fn add_property<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        property: PyFederationProperty,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        future_into_py(py, async move {
+            client
+                .add_property(federation_id, property.0)
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Revokes a property of the federation at `valid_to_ms`, or immediately.
+    ///
+    /// An immediate revocation is irreversible and fails unless `confirm_irreversible` is true.
+    #[pyo3(signature = (federation_id, name, valid_to_ms = None, *, confirm_irreversible = false))]
+    fn revoke_property<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        name: &Bound<'py, PyAny>,
+        valid_to_ms: Option<u64>,
+        confirm_irreversible: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let name = property_name(name)?;
+        future_into_py(py, async move {
+            let builder = client.revoke_property(federation_id, name, valid_to_ms);
+            let builder = if confirm_irreversible {
+                builder.confirm_irreversible()
+            } else {
+                builder
+            };
+            builder.build_and_execute(&*client).await.map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Grants `receiver` the right to attest `properties`.
+    ///
+    /// `receiver` is an object ID or an IOTA DID.
+    fn create_accreditation_to_attest<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        receiver: &str,
+        properties: Vec<PyFederationProperty>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let receiver = parse_subject(receiver)?;
+        future_into_py(py, async move {
+            client
+                .create_accreditation_to_attest(federation_id, receiver, properties.into_iter().map(|p| p.0))
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Revokes the accreditation to attest `accreditation_id` of `user_id`.
+    fn revoke_accreditation_to_attest<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        user_id: &str,
+        accreditation_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let user_id = parse_object_id(user_id)?;
+        let accreditation_id = parse_object_id(accreditation_id)?;
+        future_into_py(py, async move {
+            client
+                .revoke_accreditation_to_attest(federation_id, user_id, accreditation_id)
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Grants `receiver` the right to accredit others for `properties`.
+    ///
+    /// `receiver` is an object ID or an IOTA DID.
+    fn create_accreditation_to_accredit<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        receiver: &str,
+        properties: Vec<PyFederationProperty>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let receiver = parse_subject(receiver)?;
+        future_into_py(py, async move {
+            client
+                .create_accreditation_to_accredit(federation_id, receiver, properties.into_iter().map(|p| p.0))
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?;
+            Ok(())
+        })
+    }
+
+    /// Revokes the accreditation to accredit `accreditation_id` of `user_id`.
+    fn revoke_accreditation_to_accredit<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        user_id: &str,
+        accreditation_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let user_id = parse_object_id(user_id)?;
+        let accreditation_id = parse_object_id(accreditation_id)?;
+        future_into_py(py, async move {
+            client
+                .revoke_accreditation_to_accredit(federation_id, user_id, accreditation_id)
+                .build_and_execute(&*client)
+                .await
+                .map_err(py_error)?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use hierarchies::client::HierarchiesClientReadOnly;
+use hierarchies::core::types::Accreditations;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3_async_runtimes::tokio::future_into_py;
+
+use crate::error::py_error;
+use crate::types::{parse_object_id, parse_subject, property_name, property_value, to_python};
+
+/// A read-only client for querying and validating federations.
+///
+/// All query methods are coroutines.
+#[pyclass(name = "HierarchiesClientReadOnly", module = "hierarchies_py")]
+#[derive(Clone)]
+pub struct PyHierarchiesClientReadOnly(pub(crate) HierarchiesClientReadOnly);
+
+#[pymethods]
+impl PyHierarchiesClientReadOnly {
+    /// Connects to the IOTA node at `url`.
+    ///
+    /// The Hierarchies package is resolved from the network unless `package_id` is given.
+    #[staticmethod]
+    #[pyo3(signature = (url, package_id = None))]
+    fn connect(py: Python<'_>, url: String, package_id: Option<String>) -> PyResult<Bound<'_, PyAny>> {
+        let package_id = package_id.as_deref().map(parse_object_id).transpose()?;
+        future_into_py(py, async move {
+            let client = HierarchiesClientReadOnly::new_from_url(url, package_id)
+                .await
+                .map_err(py_error)?;
+            Ok(Self(client))
+        })
+    }
+
+    /// The ID of the Hierarchies package in use.
+    #[getter]
+    fn package_id(&self) -> String {
+        use product_common::core_client::CoreClientReadOnly;
+
+        self.0.package_id().to_string()
+    }
+
+    /// The name of the network the client is connected to.
+    #[getter]
+    fn network(&self) -> String {
+        self.0.network().to_string()
+    }
+
+    /// Retrieves the federation `federation_id` as a dictionary.
+    fn get_federation<'py>(&self, py: Python<'py>, federation_id: &str) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        future_into_py(py, async move {
+            let federation = client.get_federation_by_id(federation_id).await.map_err(py_error)?;
+            to_python(&federation)
+        })
+    }
+
+    /// Checks if `user_id` is a root authority of the federation.
+    fn is_root_authority<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        user_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let user_id = parse_object_id(user_id)?;
+        future_into_py(py, async move {
+            client.is_root_authority(federation_id, user_id).await.map_err(py_error)
+        })
+    }
+
+    /// Retrieves the dotted names of the properties of the federation.
+    fn get_properties<'py>(&self, py: Python<'py>, federation_id: &str) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        future_into_py(py, async move {
+            let properties = client.get_properties(federation_id).await.map_err(py_error)?;
+            Ok(properties
+                .into_iter()
+                .map(|name| name.names().join("."))
+                .collect::<Vec<_>>())
+        })
+    }
+
+    /// Retrieves the accreditations to attest of `user_id` as a dictionary.
+    ///
+    /// `user_id` is an object ID or an IOTA DID.
+    fn get_accreditations_to_attest<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        user_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let user_id = parse_subject(user_id)?;
+        future_into_py(py, async move {
+            let query = AccreditationQuery::new()
+                .for_entity(client.resolve_subject(user_id).map_err(py_error)?)
+                .scope(AccreditationScope::Attest);
+            let matches = client
+                .query_accreditations(federation_id, &query)
+                .await
+                .map_err(py_error)?;
+            let accreditations = Accreditations::new(matches.into_iter().map(|found| found.accreditation).collect());
+            to_python(&accreditations)
+        })
+    }
+
+    /// Checks if `attester_id` is accredited to attest `value` for the property `name`.
+    ///
+    /// `attester_id` is an object ID or an IOTA DID.
+    fn validate_property<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        attester_id: &str,
+        name: &Bound<'py, PyAny>,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let attester_id = parse_subject(attester_id)?;
+        let name = property_name(name)?;
+        let value = property_value(value)?;
+        future_into_py(py, async move {
+            client
+                .validate_property(federation_id, attester_id, name, value)
+                .await
+                .map_err(py_error)
+        })
+    }
+
+    /// Checks if `attester_id` is accredited to attest all `properties`.
+    ///
+    /// `properties` maps property names to values.
+    fn validate_properties<'py>(
+        &self,
+        py: Python<'py>,
+        federation_id: &str,
+        attester_id: &str,
+        properties: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.0.clone();
+        let federation_id = parse_object_id(federation_id)?;
+        let attester_id = parse_subject(attester_id)?;
+        let properties = properties
+            .iter()
+            .map(|(name, value)| Ok((property_name(&name)?, property_value(&value)?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        future_into_py(py, async move {
+            client
+                .validate_properties(federation_id, attester_id, properties)
+                .await
+                .map_err(py_error)
+        })
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use pyo3::exceptions::PyException;
+use pyo3::{PyErr, create_exception};
+
+create_exception!(
+    _native,
+    HierarchiesError,
+    PyException,
+    "Raised when a Hierarchies operation fails."
+);
+
+/// Converts an error of the Rust client into a [`HierarchiesError`].
+pub(crate) fn py_error(error: impl Display) -> PyErr {
+    HierarchiesError::new_err(error.to_string())
+}
@@ -0,0 +1,23 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Python bindings for the IOTA Trust Hierarchies client.
+//!
+//! The module is published as `hierarchies_py._native` and re-exported by the `hierarchies_py`
+//! Python package. All network calls are coroutines driven by a shared Tokio runtime.
+
+use pyo3::prelude::*;
+
+mod client;
+mod client_read_only;
+mod error;
+mod types;
+
+#[pymodule]
+fn _native(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("HierarchiesError", m.py().get_type::<error::HierarchiesError>())?;
+    m.add_class::<types::PyFederationProperty>()?;
+    m.add_class::<client_read_only::PyHierarchiesClientReadOnly>()?;
+    m.add_class::<client::PyHierarchiesClient>()?;
+    Ok(())
+}
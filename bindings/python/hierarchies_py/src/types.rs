@@ -0,0 +1,117 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use hierarchies::core::types::property::FederationProperty;
+use hierarchies::core::types::property_name::PropertyName;
+use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::subject_id::SubjectId;
+use hierarchies::core::types::timespan::Timespan;
+use iota_interaction::types::base_types::ObjectID;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyInt, PyString};
+use serde::Serialize;
+
+use crate::error::py_error;
+
+/// A property granted by an accreditation or added to a federation.
+///
+/// `name` is either a dotted string like `"degree.level"` or a list of name segments.
+#[pyclass(name = "FederationProperty", module = "hierarchies_py")]
+#[derive(Clone)]
+pub struct PyFederationProperty(pub(crate) FederationProperty);
+
+#[pymethods]
+impl PyFederationProperty {
+    #[new]
+    #[pyo3(signature = (name, allowed_values = None, allow_any = false, valid_from_ms = None, valid_until_ms = None))]
+    fn new(
+        name: &Bound<'_, PyAny>,
+        allowed_values: Option<Vec<Bound<'_, PyAny>>>,
+        allow_any: bool,
+        valid_from_ms: Option<u64>,
+        valid_until_ms: Option<u64>,
+    ) -> PyResult<Self> {
+        let allowed_values = allowed_values
+            .unwrap_or_default()
+            .iter()
+            .map(property_value)
+            .collect::<PyResult<Vec<_>>>()?;
+        let name = property_name(name)?;
+        if name.is_wildcard() {
+            return Err(PyValueError::new_err(
+                "wildcard property names can only be granted as accreditations",
+            ));
+        }
+        let property = FederationProperty::new(name)
+            .with_allowed_values(allowed_values)
+            .with_allow_any(allow_any)
+            .with_timespan(Timespan {
+                valid_from_ms,
+                valid_until_ms,
+            });
+
+        Ok(Self(property))
+    }
+
+    /// The dotted name of the property.
+    #[getter]
+    fn name(&self) -> String {
+        self.0.name.names().join(".")
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FederationProperty({:?})", self.name())
+    }
+}
+
+/// Parses a property name given as dotted string or list of segments.
+pub(crate) fn property_name(name: &Bound<'_, PyAny>) -> PyResult<PropertyName> {
+    if let Ok(dotted) = name.downcast::<PyString>() {
+        return Ok(PropertyName::new(dotted.to_str()?.split('.')));
+    }
+    let names: Vec<String> = name
+        .extract()
+        .map_err(|_| PyTypeError::new_err("a property name is a dotted string or a list of strings"))?;
+
+    Ok(PropertyName::new(names))
+}
+
+/// Converts a Python value into a [`PropertyValue`].
+///
+/// `bool`, `int`, `str` and `bytes` map to the respective value types.
+pub(crate) fn property_value(value: &Bound<'_, PyAny>) -> PyResult<PropertyValue> {
+    // `bool` is a subclass of `int` and must be checked first.
+    if let Ok(value) = value.downcast::<PyBool>() {
+        return Ok(PropertyValue::Bool(value.is_true()));
+    }
+    if let Ok(value) = value.downcast::<PyInt>() {
+        return Ok(PropertyValue::Number(value.extract()?));
+    }
+    if let Ok(value) = value.downcast::<PyString>() {
+        return Ok(PropertyValue::Text(value.to_str()?.to_owned()));
+    }
+    if let Ok(value) = value.downcast::<PyBytes>() {
+        return Ok(PropertyValue::Bytes(value.as_bytes().to_vec()));
+    }
+
+    Err(PyTypeError::new_err(
+        "a property value is a bool, an int, a str or bytes",
+    ))
+}
+
+pub(crate) fn parse_object_id(object_id: &str) -> PyResult<ObjectID> {
+    ObjectID::from_str(object_id).map_err(py_error)
+}
+
+/// Parses an entity given as object ID or IOTA DID.
+pub(crate) fn parse_subject(subject: &str) -> PyResult<SubjectId> {
+    SubjectId::from_str(subject).map_err(py_error)
+}
+
+/// Converts a value of the Rust client into plain Python objects.
+pub(crate) fn to_python<T: Serialize>(value: &T) -> PyResult<PyObject> {
+    Python::with_gil(|py| pythonize::pythonize(py, value).map(Bound::unbind).map_err(py_error))
+}
@@ -1,7 +1,13 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import { Federation, FederationProperty, PropertyName, PropertyValue } from "@iota/hierarchies/node";
+import {
+    Federation,
+    FederationProperty,
+    FederationPropertyBuilder,
+    PropertyName,
+    PropertyValue,
+} from "@iota/hierarchies/node";
 import { HierarchiesClient } from "@iota/hierarchies/node";
 import { strict as assert } from "assert";
 import { generateRandomAddress, getFundedClient } from "./util";
@@ -43,7 +49,7 @@ export async function createAccreditationToAttest(client?: HierarchiesClient) {
     const receiver = generateRandomAddress();
 
     // Property
-    const property = new FederationProperty(propertyName).withAllowedValues([PropertyValue.newText("Hello")]);
+    const property = new FederationPropertyBuilder(propertyName).withAllowedTextValues(["Hello"]).build();
 
     // Let us issue an accreditation to attest to the Property
     await hierarchies
@@ -38,7 +38,7 @@ export async function revokeRootAuthority(): Promise<void> {
     // Now revoke the second root authority
     console.log("Revoking second root authority: ", secondRootAuthority);
     await hierarchies
-        .revokeRootAuthority(federation.id, secondRootAuthority)
+        .revokeRootAuthority(federation.id, secondRootAuthority, true)
         .buildAndExecute(hierarchies);
 
     console.log("\n✅ Root authority revoked successfully!");
@@ -73,7 +73,7 @@ export async function revokeRootAuthority(): Promise<void> {
 
         try {
             await hierarchies
-                .revokeRootAuthority(federation.id, lastRootAuthority)
+                .revokeRootAuthority(federation.id, lastRootAuthority, true)
                 .buildAndExecute(hierarchies);
             console.log("❌ Should not be able to revoke the last root authority");
         } catch (error: any) {
@@ -38,7 +38,7 @@ export async function reinstateRootAuthority(): Promise<void> {
     // Now revoke the second root authority
     console.log("Revoking second root authority: ", secondRootAuthority);
     await hierarchies
-        .revokeRootAuthority(federation.id, secondRootAuthority)
+        .revokeRootAuthority(federation.id, secondRootAuthority, true)
         .buildAndExecute(hierarchies);
 
     console.log("\n✅ Root authority revoked successfully!");
@@ -0,0 +1,154 @@
+// Copyright 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cancellation and timeouts for the async methods of the bindings.
+//!
+//! JavaScript promises cannot be cancelled, so the Rust future behind a promise keeps running
+//! after the caller lost interest in its result, e.g. while paging through owned objects during
+//! capability discovery. [`cancellable`] races such a future against an `AbortSignal` and an
+//! optional timeout; as soon as either fires, the promise rejects and the Rust future is dropped,
+//! which stops all of its pending work at the next `await` point.
+
+use std::future::{Future, poll_fn};
+use std::pin::pin;
+use std::task::Poll;
+
+use iota_interaction_ts::wasm_error::Result;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A DOM `AbortSignal`, as created by an `AbortController`.
+    #[wasm_bindgen(typescript_type = "AbortSignal")]
+    #[derive(Clone)]
+    pub type AbortSignal;
+
+    #[wasm_bindgen(method, getter)]
+    fn aborted(this: &AbortSignal) -> bool;
+
+    #[wasm_bindgen(method, getter)]
+    fn reason(this: &AbortSignal) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = addEventListener)]
+    fn add_event_listener(this: &AbortSignal, kind: &str, listener: &js_sys::Function);
+
+    #[wasm_bindgen(method, js_name = removeEventListener)]
+    fn remove_event_listener(this: &AbortSignal, kind: &str, listener: &js_sys::Function);
+
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &js_sys::Function, timeout_ms: u32) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(handle: &JsValue);
+}
+
+/// Options controlling the cancellation of async client methods.
+///
+/// # TypeScript Usage
+///
+/// ```typescript
+/// const controller = new AbortController();
+/// const scoped = client.withCallOptions(new CallOptions(controller.signal, 10_000));
+/// const pending = scoped.getFederationById(federationId);
+/// controller.abort(); // `pending` rejects with the abort reason
+/// ```
+#[wasm_bindgen(js_name = CallOptions, inspectable)]
+#[derive(Clone, Default)]
+pub struct WasmCallOptions {
+    signal: Option<AbortSignal>,
+    timeout_ms: Option<u32>,
+}
+
+#[wasm_bindgen(js_class = CallOptions)]
+impl WasmCallOptions {
+    /// Creates new call options.
+    ///
+    /// # Arguments
+    ///
+    /// * `signal` - An optional `AbortSignal` rejecting pending calls once aborted.
+    /// * `timeout_ms` - An optional timeout in milliseconds for each call.
+    #[wasm_bindgen(constructor)]
+    pub fn new(signal: Option<AbortSignal>, timeout_ms: Option<u32>) -> Self {
+        Self { signal, timeout_ms }
+    }
+
+    /// The timeout in milliseconds for each call, if any.
+    #[wasm_bindgen(getter, js_name = timeoutMs)]
+    pub fn timeout_ms(&self) -> Option<u32> {
+        self.timeout_ms
+    }
+}
+
+/// Removes the abort listener and clears the timer once the call completes or is dropped.
+///
+/// The callbacks are owned by the guard, so they are released together with the call.
+#[derive(Default)]
+struct CancelGuard {
+    on_abort: Option<(AbortSignal, Closure<dyn FnMut()>)>,
+    on_timeout: Option<(JsValue, Closure<dyn FnMut()>)>,
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if let Some((signal, listener)) = &self.on_abort {
+            signal.remove_event_listener("abort", listener.as_ref().unchecked_ref());
+        }
+        if let Some((timer, _)) = &self.on_timeout {
+            clear_timeout(timer);
+        }
+    }
+}
+
+/// Runs `future` until it completes, `options.signal` is aborted or `options.timeout_ms` elapses.
+///
+/// On abort the returned error is the reason of the signal, on timeout a JavaScript `Error`.
+/// In both cases `future` is dropped before returning.
+pub(crate) async fn cancellable<T>(options: &WasmCallOptions, future: impl Future<Output = Result<T>>) -> Result<T> {
+    if options.signal.is_none() && options.timeout_ms.is_none() {
+        return future.await;
+    }
+    if let Some(signal) = options.signal.as_ref().filter(|signal| signal.aborted()) {
+        return Err(signal.reason());
+    }
+
+    let mut guard = CancelGuard::default();
+    let cancelled = js_sys::Promise::new(&mut |_, reject| {
+        if let Some(signal) = &options.signal {
+            let on_abort = {
+                let signal = signal.clone();
+                let reject = reject.clone();
+                Closure::once(move || {
+                    let _ = reject.call1(&JsValue::UNDEFINED, &signal.reason());
+                })
+            };
+            signal.add_event_listener("abort", on_abort.as_ref().unchecked_ref());
+            guard.on_abort = Some((signal.clone(), on_abort));
+        }
+        if let Some(timeout_ms) = options.timeout_ms {
+            let on_timeout = Closure::once(move || {
+                let error = js_sys::Error::new(&format!("operation timed out after {timeout_ms} ms"));
+                let _ = reject.call1(&JsValue::UNDEFINED, &error);
+            });
+            let timer = set_timeout(on_timeout.as_ref().unchecked_ref(), timeout_ms);
+            guard.on_timeout = Some((timer, on_timeout));
+        }
+    });
+
+    let mut future = pin!(future);
+    let mut cancelled = pin!(JsFuture::from(cancelled));
+    let result = poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(output);
+        }
+        match cancelled.as_mut().poll(cx) {
+            Poll::Ready(Err(reason)) => Poll::Ready(Err(reason)),
+            Poll::Ready(Ok(_)) => Poll::Ready(Err(js_sys::Error::new("operation cancelled").into())),
+            Poll::Pending => Poll::Pending,
+        }
+    })
+    .await;
+    drop(guard);
+
+    result
+}
@@ -6,25 +6,36 @@ use std::str::FromStr;
 
 use anyhow::anyhow;
 use hierarchies::client::HierarchiesClientReadOnly;
+use hierarchies::core::types::Accreditations;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
 use hierarchies::core::types::property_name::PropertyName;
 use hierarchies::core::types::property_value::PropertyValue;
 use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::digests::ObjectDigest;
 use iota_interaction_ts::bindings::WasmIotaClient;
-use iota_interaction_ts::wasm_error::{Result, WasmResult, wasm_error};
+use iota_interaction_ts::wasm_error::{Result, WasmResult};
 use product_common::bindings::WasmObjectID;
 use product_common::bindings::utils::parse_wasm_object_id;
 use product_common::core_client::CoreClientReadOnly;
 use wasm_bindgen::prelude::*;
 
-use crate::wasm_types::{WasmAccreditations, WasmFederation, WasmPropertyName, WasmPropertyValue};
+use crate::cancellation::{WasmCallOptions, cancellable};
+use crate::error::hierarchies_error;
+use crate::wasm_types::{
+    WasmAccreditations, WasmAttestation, WasmFederation, WasmFederationMetadata, WasmFederationStats, WasmGrantPolicy,
+    WasmPropertyMetadata, WasmPropertyName, WasmPropertyValue, WasmValidationConfig, WasmValidationReport,
+    WasmValueSetMatch,
+};
 
 /// A client to interact with Hierarchies objects on the IOTA ledger.
 ///
 /// This client is used for read-only operations, meaning it does not require an account
 /// or signing capabilities. For write operations, use {@link HierarchiesClient}.
+///
+/// Async methods can be cancelled or bounded in time through {@link withCallOptions}.
 #[derive(Clone)]
 #[wasm_bindgen(js_name = HierarchiesClientReadOnly)]
-pub struct WasmHierarchiesClientReadOnly(pub(crate) HierarchiesClientReadOnly);
+pub struct WasmHierarchiesClientReadOnly(pub(crate) HierarchiesClientReadOnly, pub(crate) WasmCallOptions);
 
 // Builder-related functions
 #[wasm_bindgen(js_class = HierarchiesClientReadOnly)]
@@ -52,8 +63,10 @@ impl WasmHierarchiesClientReadOnly {
     /// ```
     #[wasm_bindgen(js_name = create)]
     pub async fn new(iota_client: WasmIotaClient) -> Result<WasmHierarchiesClientReadOnly> {
-        let inner_client = HierarchiesClientReadOnly::new(iota_client).await.map_err(wasm_error)?;
-        Ok(WasmHierarchiesClientReadOnly(inner_client))
+        let inner_client = HierarchiesClientReadOnly::new(iota_client)
+            .await
+            .map_err(hierarchies_error)?;
+        Ok(WasmHierarchiesClientReadOnly(inner_client, WasmCallOptions::default()))
     }
 
     /// Creates a new instance of `HierarchiesClientReadOnly` using a specific package ID.
@@ -90,8 +103,8 @@ impl WasmHierarchiesClientReadOnly {
                 .wasm_result()?,
         )
         .await
-        .map_err(wasm_error)?;
-        Ok(WasmHierarchiesClientReadOnly(inner_client))
+        .map_err(hierarchies_error)?;
+        Ok(WasmHierarchiesClientReadOnly(inner_client, WasmCallOptions::default()))
     }
 
     /// Retrieves the package ID of the used hierarchies package.
@@ -103,6 +116,42 @@ impl WasmHierarchiesClientReadOnly {
         self.0.package_id().to_string()
     }
 
+    /// Returns a client whose async methods use `options`.
+    ///
+    /// Pending calls of the returned client reject once the signal of `options` is aborted or
+    /// their timeout elapses; the underlying work, e.g. paging through owned objects, is
+    /// stopped as well. The original client is not affected.
+    ///
+    /// # TypeScript Usage
+    ///
+    /// ```typescript
+    /// const controller = new AbortController();
+    /// const scoped = client.withCallOptions(new CallOptions(controller.signal));
+    /// ```
+    #[wasm_bindgen(js_name = withCallOptions)]
+    pub fn with_call_options(&self, options: &WasmCallOptions) -> WasmHierarchiesClientReadOnly {
+        WasmHierarchiesClientReadOnly(self.0.clone(), options.clone())
+    }
+
+    /// Returns a client whose point-in-time validations, e.g. {@link validatePropertyAt},
+    /// tolerate the clock skew of `config`. The original client is not affected.
+    ///
+    /// # TypeScript Usage
+    ///
+    /// ```typescript
+    /// const tolerant = client.withValidationConfig(new ValidationConfig(5000));
+    /// ```
+    #[wasm_bindgen(js_name = withValidationConfig)]
+    pub fn with_validation_config(&self, config: &WasmValidationConfig) -> WasmHierarchiesClientReadOnly {
+        WasmHierarchiesClientReadOnly(self.0.clone().with_validation_config(config.0), self.1.clone())
+    }
+
+    /// Returns the configuration of client-side validations.
+    #[wasm_bindgen(js_name = validationConfig)]
+    pub fn validation_config(&self) -> WasmValidationConfig {
+        (*self.0.validation_config()).into()
+    }
+
     /// Retrieves the history of hierarchies package IDs.
     ///
     /// # Returns
@@ -143,6 +192,33 @@ impl WasmHierarchiesClientReadOnly {
         self.0.chain_id().to_string()
     }
 
+    /// Verifies that the package deployed at the configured package ID matches a pinned digest.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_digest`: The Base58 encoded object digest the package is expected to have.
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript that rejects if the digest cannot be parsed,
+    /// the package cannot be fetched, or a different package is deployed at the configured ID.
+    ///
+    /// ```typescript
+    /// await client.verifyPackageIntegrity(PINNED_PACKAGE_DIGEST);
+    /// ```
+    #[wasm_bindgen(js_name = verifyPackageIntegrity)]
+    pub async fn verify_package_integrity(&self, expected_digest: String) -> Result<()> {
+        cancellable(&self.1, async {
+            let expected_digest = ObjectDigest::from_str(&expected_digest)
+                .map_err(|e| anyhow!("Could not parse expected_digest: {}", e.to_string()))
+                .wasm_result()?;
+            self.0
+                .verify_package_integrity(expected_digest)
+                .await
+                .map_err(hierarchies_error)
+        })
+        .await
+    }
+
     /// Retrieves a federation by its ID.
     ///
     /// # Arguments
@@ -167,9 +243,16 @@ impl WasmHierarchiesClientReadOnly {
     /// ```
     #[wasm_bindgen(js_name = getFederationById)]
     pub async fn get_federation_by_id(&self, federation_id: WasmObjectID) -> Result<WasmFederation> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let federation = self.0.get_federation_by_id(federation_id).await.map_err(wasm_error)?;
-        Ok(federation.into())
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let federation = self
+                .0
+                .get_federation_by_id(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(federation.into())
+        })
+        .await
     }
 
     /// Check if root authority is in the federation.
@@ -182,14 +265,17 @@ impl WasmHierarchiesClientReadOnly {
     /// A `Result` containing a boolean indicating if the user is a root authority or an [`Error`].
     #[wasm_bindgen(js_name = isRootAuthority)]
     pub async fn is_root_authority(&self, federation_id: WasmObjectID, user_id: WasmObjectID) -> Result<bool> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let user_id = parse_wasm_object_id(&user_id)?;
-        let is_root_authority = self
-            .0
-            .is_root_authority(federation_id, user_id)
-            .await
-            .map_err(wasm_error)?;
-        Ok(is_root_authority)
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let is_root_authority = self
+                .0
+                .is_root_authority(federation_id, user_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_root_authority)
+        })
+        .await
     }
 
     /// Retrieves all property names registered in the federation.
@@ -216,9 +302,12 @@ impl WasmHierarchiesClientReadOnly {
     /// ```
     #[wasm_bindgen(js_name = getProperties)]
     pub async fn get_properties(&self, federation_id: WasmObjectID) -> Result<Vec<WasmPropertyName>> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let properties = self.0.get_properties(federation_id).await.map_err(wasm_error)?;
-        Ok(properties.into_iter().map(|property| property.into()).collect())
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let properties = self.0.get_properties(federation_id).await.map_err(hierarchies_error)?;
+            Ok(properties.into_iter().map(|property| property.into()).collect())
+        })
+        .await
     }
 
     /// Checks if a property is registered in the federation.
@@ -250,12 +339,519 @@ impl WasmHierarchiesClientReadOnly {
         federation_id: WasmObjectID,
         property_name: WasmPropertyName,
     ) -> Result<bool> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        self.0
-            .is_property_in_federation(federation_id, property_name.into())
-            .await
-            .map_err(wasm_error)
-            .wasm_result()
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            self.0
+                .is_property_in_federation(federation_id, property_name.into())
+                .await
+                .map_err(hierarchies_error)
+                .wasm_result()
+        })
+        .await
+    }
+
+    /// Checks if a property is marked as critical in the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `property_name`: The name of the property to check.
+    ///
+    /// # Returns
+    /// A `Result` containing a boolean indicating if the property is critical or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with a `boolean`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const isCritical = await client.isPropertyCritical(federationId, propertyName);
+    /// ```
+    #[wasm_bindgen(js_name = isPropertyCritical)]
+    pub async fn is_property_critical(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            self.0
+                .is_property_critical(federation_id, property_name.into())
+                .await
+                .map_err(hierarchies_error)
+                .wasm_result()
+        })
+        .await
+    }
+
+    /// Retrieves the names of all properties labelled with a tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `tag`: The tag to look up.
+    ///
+    /// # Returns
+    /// A `Result` containing the list of tagged property names or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `PropertyName[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const foodSafety = await client.getPropertiesByTag(federationId, "food-safety");
+    /// ```
+    #[wasm_bindgen(js_name = getPropertiesByTag)]
+    pub async fn get_properties_by_tag(
+        &self,
+        federation_id: WasmObjectID,
+        tag: String,
+    ) -> Result<Vec<WasmPropertyName>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let properties = self
+                .0
+                .get_properties_by_tag(federation_id, tag)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(properties.into_iter().map(|property| property.into()).collect())
+        })
+        .await
+    }
+
+    /// Retrieves the names of all properties in a namespace.
+    ///
+    /// A property is in the namespace if the leading segments of its name are those of `prefix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `prefix`: The name of the namespace.
+    ///
+    /// # Returns
+    /// A `Result` containing the list of property names in the namespace or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `PropertyName[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const compliance = await client.getPropertiesByPrefix(federationId, new PropertyName(["compliance"]));
+    /// ```
+    #[wasm_bindgen(js_name = getPropertiesByPrefix)]
+    pub async fn get_properties_by_prefix(
+        &self,
+        federation_id: WasmObjectID,
+        prefix: WasmPropertyName,
+    ) -> Result<Vec<WasmPropertyName>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let properties = self
+                .0
+                .get_properties_by_prefix(federation_id, PropertyName::from(prefix))
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(properties.into_iter().map(|property| property.into()).collect())
+        })
+        .await
+    }
+
+    /// Retrieves the metadata of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `property_name`: The name of the property.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`WasmPropertyMetadata`] or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `PropertyMetadata`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const metadata = await client.getPropertyMetadata(federationId, propertyName);
+    /// console.log(metadata.displayName, metadata.unit);
+    /// ```
+    #[wasm_bindgen(js_name = getPropertyMetadata)]
+    pub async fn get_property_metadata(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+    ) -> Result<WasmPropertyMetadata> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let metadata = self
+                .0
+                .get_property_metadata(federation_id, property_name.into())
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(metadata.into())
+        })
+        .await
+    }
+
+    /// Retrieves the tags of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `property_name`: The name of the property.
+    ///
+    /// # Returns
+    /// A `Result` containing the tags of the property or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `string[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const tags = await client.getPropertyTags(federationId, propertyName);
+    /// ```
+    #[wasm_bindgen(js_name = getPropertyTags)]
+    pub async fn get_property_tags(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+    ) -> Result<Vec<String>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let tags = self
+                .0
+                .get_property_tags(federation_id, property_name.into())
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(tags)
+        })
+        .await
+    }
+
+    /// Retrieves the tags of an accreditation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `accreditation_id`: The [`ObjectID`] of the accreditation.
+    ///
+    /// # Returns
+    /// A `Result` containing the tags of the accreditation or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `string[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const tags = await client.getAccreditationTags(federationId, accreditationId);
+    /// ```
+    #[wasm_bindgen(js_name = getAccreditationTags)]
+    pub async fn get_accreditation_tags(
+        &self,
+        federation_id: WasmObjectID,
+        accreditation_id: WasmObjectID,
+    ) -> Result<Vec<String>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
+            let tags = self
+                .0
+                .get_accreditation_tags(federation_id, accreditation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(tags)
+        })
+        .await
+    }
+
+    /// Retrieves all property names marked as critical in the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # Returns
+    /// A `Result` containing the list of critical property names or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `PropertyName[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const criticalProperties = await client.getCriticalProperties(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getCriticalProperties)]
+    pub async fn get_critical_properties(&self, federation_id: WasmObjectID) -> Result<Vec<WasmPropertyName>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let properties = self
+                .0
+                .get_critical_properties(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(properties.into_iter().map(|property| property.into()).collect())
+        })
+        .await
+    }
+
+    /// Checks if a property is deprecated in the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `property_name`: The name of the property to check.
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with a `boolean`.
+    /// - On failure, the promise rejects with a `HierarchiesError`.
+    ///
+    /// ```typescript
+    /// const isDeprecated = await client.isPropertyDeprecated(federationId, propertyName);
+    /// ```
+    #[wasm_bindgen(js_name = isPropertyDeprecated)]
+    pub async fn is_property_deprecated(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            self.0
+                .is_property_deprecated(federation_id, property_name.into())
+                .await
+                .map_err(hierarchies_error)
+                .wasm_result()
+        })
+        .await
+    }
+
+    /// Retrieves the deprecated properties of the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with a `Map` from dotted property names to the dotted name of their
+    ///   replacement, `undefined` if none was recorded.
+    /// - On failure, the promise rejects with a `HierarchiesError`.
+    ///
+    /// ```typescript
+    /// const deprecated = await client.getDeprecatedProperties(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getDeprecatedProperties)]
+    pub async fn get_deprecated_properties(&self, federation_id: WasmObjectID) -> Result<js_sys::Map> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let deprecated = self
+                .0
+                .get_deprecated_properties(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            let map = js_sys::Map::new();
+            for (name, replacement) in deprecated {
+                let replacement = replacement.map_or(JsValue::UNDEFINED, |replacement| {
+                    JsValue::from_str(&replacement.names().join("."))
+                });
+                map.set(&JsValue::from_str(&name.names().join(".")), &replacement);
+            }
+            Ok(map)
+        })
+        .await
+    }
+
+    /// Retrieves the IDs of the child federations recognized by the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `string[]`.
+    /// - On failure, the promise rejects with a `HierarchiesError`.
+    ///
+    /// ```typescript
+    /// const children = await client.getRecognizedFederations(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getRecognizedFederations)]
+    pub async fn get_recognized_federations(&self, federation_id: WasmObjectID) -> Result<Vec<WasmObjectID>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let federations = self
+                .0
+                .get_recognized_federations(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(federations.into_iter().map(|id| id.to_string()).collect())
+        })
+        .await
+    }
+
+    /// Retrieves the grant policy of the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`WasmGrantPolicy`] or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `GrantPolicy`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const policy = await client.getGrantPolicy(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getGrantPolicy)]
+    pub async fn get_grant_policy(&self, federation_id: WasmObjectID) -> Result<WasmGrantPolicy> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let policy = self
+                .0
+                .get_grant_policy(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(policy.into())
+        })
+        .await
+    }
+
+    /// Retrieves the metadata of the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`WasmFederationMetadata`] or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `FederationMetadata`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const metadata = await client.getFederationMetadata(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getFederationMetadata)]
+    pub async fn get_federation_metadata(&self, federation_id: WasmObjectID) -> Result<WasmFederationMetadata> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let metadata = self
+                .0
+                .get_federation_metadata(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(metadata.into())
+        })
+        .await
+    }
+
+    /// Retrieves the summary statistics of the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`WasmFederationStats`] or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `FederationStats`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const stats = await client.getFederationStats(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getFederationStats)]
+    pub async fn get_federation_stats(&self, federation_id: WasmObjectID) -> Result<WasmFederationStats> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let stats = self
+                .0
+                .get_federation_stats(federation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(stats.into())
+        })
+        .await
+    }
+
+    /// Retrieves the names of all value dictionaries of the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    ///
+    /// # Returns
+    /// A `Result` containing the list of dictionary names or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `string[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const names = await client.getValueDictionaryNames(federationId);
+    /// ```
+    #[wasm_bindgen(js_name = getValueDictionaryNames)]
+    pub async fn get_value_dictionary_names(&self, federation_id: WasmObjectID) -> Result<Vec<String>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            self.0
+                .get_value_dictionary_names(federation_id)
+                .await
+                .map_err(hierarchies_error)
+        })
+        .await
+    }
+
+    /// Retrieves the values of a value dictionary of the federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `name`: The name of the value dictionary.
+    ///
+    /// # Returns
+    /// A `Result` containing the values of the dictionary or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with `PropertyValue[]`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const countries = await client.getValueDictionary(federationId, "countries");
+    /// ```
+    #[wasm_bindgen(js_name = getValueDictionary)]
+    pub async fn get_value_dictionary(
+        &self,
+        federation_id: WasmObjectID,
+        name: String,
+    ) -> Result<Vec<WasmPropertyValue>> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let values = self
+                .0
+                .get_value_dictionary(federation_id, name)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(values.into_iter().map(|value| value.into()).collect())
+        })
+        .await
     }
 
     /// Retrieves attestation accreditations for a specific user.
@@ -287,14 +883,20 @@ impl WasmHierarchiesClientReadOnly {
         federation_id: WasmObjectID,
         user_id: WasmObjectID,
     ) -> Result<WasmAccreditations> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let user_id = parse_wasm_object_id(&user_id)?;
-        let accreditations = self
-            .0
-            .get_accreditations_to_attest(federation_id, user_id)
-            .await
-            .map_err(wasm_error)?;
-        Ok(accreditations.into())
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let query = AccreditationQuery::new()
+                .for_entity(user_id)
+                .scope(AccreditationScope::Attest);
+            let matches = self
+                .0
+                .query_accreditations(federation_id, &query)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(Accreditations::new(matches.into_iter().map(|found| found.accreditation).collect()).into())
+        })
+        .await
     }
 
     /// Checks if a user has attestation accreditation.
@@ -322,10 +924,17 @@ impl WasmHierarchiesClientReadOnly {
     /// ```
     #[wasm_bindgen(js_name = isAttester)]
     pub async fn is_attester(&self, federation_id: WasmObjectID, user_id: WasmObjectID) -> Result<bool> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let user_id = parse_wasm_object_id(&user_id)?;
-        let is_attester = self.0.is_attester(federation_id, user_id).await.map_err(wasm_error)?;
-        Ok(is_attester)
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let is_attester = self
+                .0
+                .is_attester(federation_id, user_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_attester)
+        })
+        .await
     }
 
     /// Retrieves accreditations to accredit for a specific user.
@@ -357,14 +966,20 @@ impl WasmHierarchiesClientReadOnly {
         federation_id: WasmObjectID,
         user_id: WasmObjectID,
     ) -> Result<WasmAccreditations> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let user_id = parse_wasm_object_id(&user_id)?;
-        let accreditations = self
-            .0
-            .get_accreditations_to_accredit(federation_id, user_id)
-            .await
-            .map_err(wasm_error)?;
-        Ok(accreditations.into())
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let query = AccreditationQuery::new()
+                .for_entity(user_id)
+                .scope(AccreditationScope::Accredit);
+            let matches = self
+                .0
+                .query_accreditations(federation_id, &query)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(Accreditations::new(matches.into_iter().map(|found| found.accreditation).collect()).into())
+        })
+        .await
     }
 
     /// Checks if a user has accreditations to accredit.
@@ -392,10 +1007,17 @@ impl WasmHierarchiesClientReadOnly {
     /// ```
     #[wasm_bindgen(js_name = isAccreditor)]
     pub async fn is_accreditor(&self, federation_id: WasmObjectID, user_id: WasmObjectID) -> Result<bool> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let user_id = parse_wasm_object_id(&user_id)?;
-        let is_accreditor = self.0.is_accreditor(federation_id, user_id).await.map_err(wasm_error)?;
-        Ok(is_accreditor)
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let is_accreditor = self
+                .0
+                .is_accreditor(federation_id, user_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_accreditor)
+        })
+        .await
     }
 
     /// Validates a property for a specific user.
@@ -431,16 +1053,143 @@ impl WasmHierarchiesClientReadOnly {
         property_name: WasmPropertyName,
         property_value: WasmPropertyValue,
     ) -> Result<bool> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let user_id = parse_wasm_object_id(&user_id)?;
-        let property_name = property_name.into();
-        let property_value = property_value.into();
-        let is_valid = self
-            .0
-            .validate_property(federation_id, user_id, property_name, property_value)
-            .await
-            .map_err(wasm_error)?;
-        Ok(is_valid)
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let property_name = property_name.into();
+            let property_value = property_value.into();
+            let is_valid = self
+                .0
+                .validate_property(federation_id, user_id, property_name, property_value)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_valid)
+        })
+        .await
+    }
+
+    /// Checks if an entity is allowed to attest a value for a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `entity_id`: The [`ObjectID`] of the entity.
+    /// * `property_name`: The name of the property.
+    /// * `property_value`: The value to attest.
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript that resolves with a `boolean`.
+    ///
+    /// ```typescript
+    /// const allowed = await client.isAllowedToAttest(federationId, entityId, propertyName, propertyValue);
+    /// ```
+    #[wasm_bindgen(js_name = isAllowedToAttest)]
+    pub async fn is_allowed_to_attest(
+        &self,
+        federation_id: WasmObjectID,
+        entity_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        property_value: WasmPropertyValue,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let entity_id = parse_wasm_object_id(&entity_id)?;
+            let allowed = self
+                .0
+                .is_allowed_to_attest(federation_id, entity_id, property_name.into(), property_value.into())
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(allowed)
+        })
+        .await
+    }
+
+    /// Checks if an entity is allowed to delegate accreditations for a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `entity_id`: The [`ObjectID`] of the entity.
+    /// * `property_name`: The name of the property.
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript that resolves with a `boolean`.
+    ///
+    /// ```typescript
+    /// const allowed = await client.isAllowedToAccredit(federationId, entityId, propertyName);
+    /// ```
+    #[wasm_bindgen(js_name = isAllowedToAccredit)]
+    pub async fn is_allowed_to_accredit(
+        &self,
+        federation_id: WasmObjectID,
+        entity_id: WasmObjectID,
+        property_name: WasmPropertyName,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let entity_id = parse_wasm_object_id(&entity_id)?;
+            let allowed = self
+                .0
+                .is_allowed_to_accredit(federation_id, entity_id, property_name.into())
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(allowed)
+        })
+        .await
+    }
+
+    /// Validates a set of values attested for a single property by a specific user.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `user_id`: The [`ObjectID`] of the user.
+    /// * `property_name`: The name of the property to validate.
+    /// * `property_values`: The set of values to validate.
+    /// * `value_match`: Whether every value (`Superset`) or at least one value (`Contains`) must be accredited.
+    ///
+    /// # Returns
+    /// A `Result` containing a boolean indicating if the values are valid or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with a `boolean`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// try {
+    ///   const isValid = await client.validatePropertyValues(federationId, userId, propertyName, [eu, fda], ValueSetMatch.Superset);
+    ///   console.log("Are values valid:", isValid);
+    /// } catch (error) {
+    ///   console.error("Failed to validate property values:", error);
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = validatePropertyValues)]
+    pub async fn validate_property_values(
+        &self,
+        federation_id: WasmObjectID,
+        user_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        property_values: Vec<WasmPropertyValue>,
+        value_match: WasmValueSetMatch,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let is_valid = self
+                .0
+                .validate_property_values(
+                    federation_id,
+                    user_id,
+                    property_name.into(),
+                    property_values.into_iter().map(Into::into),
+                    value_match.into(),
+                )
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_valid)
+        })
+        .await
     }
 
     /// Validates multiple properties for a specific user.
@@ -474,23 +1223,193 @@ impl WasmHierarchiesClientReadOnly {
         entity_id: WasmObjectID,
         properties: js_sys::Map,
     ) -> Result<bool> {
-        let federation_id = parse_wasm_object_id(&federation_id)?;
-        let entity_id = parse_wasm_object_id(&entity_id)?;
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let entity_id = parse_wasm_object_id(&entity_id)?;
 
-        let mut converted_properties = HashMap::new();
+            let mut converted_properties = HashMap::new();
 
-        properties.for_each(&mut |value, key| {
-            if let (Some(name), Some(val)) = (extract_property_name(&key), extract_property_value(&value)) {
-                converted_properties.insert(name, val);
-            }
-        });
+            properties.for_each(&mut |value, key| {
+                if let (Some(name), Some(val)) = (extract_property_name(&key), extract_property_value(&value)) {
+                    converted_properties.insert(name, val);
+                }
+            });
 
-        let is_valid = self
-            .0
-            .validate_properties(federation_id, entity_id, converted_properties)
-            .await
-            .map_err(wasm_error)?;
-        Ok(is_valid)
+            let is_valid = self
+                .0
+                .validate_properties(federation_id, entity_id, converted_properties)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_valid)
+        })
+        .await
+    }
+
+    /// Validates multiple properties for a specific user and reports why validation failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `user_id`: The [`ObjectID`] of the user.
+    /// * `properties`: The properties to validate.
+    ///
+    /// # Returns
+    /// A `Result` containing a [`WasmValidationReport`] naming the failed rule of every property, or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with a `ValidationReport`.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const report = await client.validatePropertiesReport(federationId, userId, properties);
+    /// if (!report.isValid) {
+    ///   report.failures.forEach((reason, property) => console.log(`${property}: ${reason}`));
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = validatePropertiesReport)]
+    pub async fn validate_properties_report(
+        &self,
+        federation_id: WasmObjectID,
+        entity_id: WasmObjectID,
+        properties: js_sys::Map,
+    ) -> Result<WasmValidationReport> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let entity_id = parse_wasm_object_id(&entity_id)?;
+            let properties = parse_property_map(&properties)?;
+
+            let report = self
+                .0
+                .validate_properties_report(federation_id, entity_id, properties)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(report.into())
+        })
+        .await
+    }
+
+    /// Validates a property of a user against the state of the federation as of `timestampMs`.
+    ///
+    /// Timespans tolerate the clock skew of the client's {@link ValidationConfig}.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `user_id`: The [`ObjectID`] of the user.
+    /// * `property_name`: The name of the property to validate.
+    /// * `property_value`: The value to validate.
+    /// * `timestamp_ms`: The time to validate at, in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    /// A `Result` containing a boolean indicating if the property was valid at `timestamp_ms` or an [`Error`].
+    ///
+    /// # TypeScript Usage
+    /// This method returns a `Promise` in TypeScript.
+    /// - On success, the promise resolves with a `boolean`, false if the federation didn't exist yet.
+    /// - On failure, the promise rejects with an `Error`.
+    ///
+    /// ```typescript
+    /// const wasValid = await client.validatePropertyAt(federationId, userId, propertyName, value, attestation.issuedAtMs);
+    /// ```
+    #[wasm_bindgen(js_name = validatePropertyAt)]
+    pub async fn validate_property_at(
+        &self,
+        federation_id: WasmObjectID,
+        user_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        property_value: WasmPropertyValue,
+        timestamp_ms: u64,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let user_id = parse_wasm_object_id(&user_id)?;
+            let is_valid = self
+                .0
+                .validate_property_at(
+                    federation_id,
+                    user_id,
+                    property_name.into(),
+                    property_value.into(),
+                    timestamp_ms,
+                )
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(is_valid)
+        })
+        .await
+    }
+
+    /// Retrieves an attestation by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `attestation_id`: The [`ObjectID`] of the attestation.
+    #[wasm_bindgen(js_name = getAttestation)]
+    pub async fn get_attestation(&self, attestation_id: WasmObjectID) -> Result<WasmAttestation> {
+        cancellable(&self.1, async {
+            let attestation_id = parse_wasm_object_id(&attestation_id)?;
+            let attestation = self
+                .0
+                .get_attestation(attestation_id)
+                .await
+                .map_err(hierarchies_error)?;
+            Ok(attestation.into())
+        })
+        .await
+    }
+
+    /// Checks if an attestation issued in the federation is revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `attestation_id`: The [`ObjectID`] of the attestation.
+    #[wasm_bindgen(js_name = isAttestationRevoked)]
+    pub async fn is_attestation_revoked(
+        &self,
+        federation_id: WasmObjectID,
+        attestation_id: WasmObjectID,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let attestation_id = parse_wasm_object_id(&attestation_id)?;
+            self.0
+                .is_attestation_revoked(federation_id, attestation_id)
+                .await
+                .map_err(hierarchies_error)
+        })
+        .await
+    }
+
+    /// Validates an attestation.
+    ///
+    /// The attestation is valid if it was issued in the federation, is not revoked, is within its
+    /// validity window and its issuer is still accredited to attest all of its properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id`: The [`ObjectID`] of the federation.
+    /// * `attestation_id`: The [`ObjectID`] of the attestation.
+    ///
+    /// ```typescript
+    /// const isValid = await client.validateAttestation(federationId, attestationId);
+    /// ```
+    #[wasm_bindgen(js_name = validateAttestation)]
+    pub async fn validate_attestation(
+        &self,
+        federation_id: WasmObjectID,
+        attestation_id: WasmObjectID,
+    ) -> Result<bool> {
+        cancellable(&self.1, async {
+            let federation_id = parse_wasm_object_id(&federation_id)?;
+            let attestation_id = parse_wasm_object_id(&attestation_id)?;
+            self.0
+                .validate_attestation(federation_id, attestation_id)
+                .await
+                .map_err(hierarchies_error)
+        })
+        .await
     }
 
     /// Returns the `tf_components` package ID currently in use.
@@ -502,6 +1421,26 @@ impl WasmHierarchiesClientReadOnly {
     }
 }
 
+/// Parses a map from `PropertyName` to `PropertyValue`, failing on entries of other types.
+pub(crate) fn parse_property_map(properties: &js_sys::Map) -> Result<HashMap<PropertyName, PropertyValue>> {
+    let mut converted_properties = HashMap::new();
+    let mut invalid = false;
+
+    properties.for_each(
+        &mut |value, key| match (extract_property_name(&key), extract_property_value(&value)) {
+            (Some(name), Some(val)) => {
+                converted_properties.insert(name, val);
+            }
+            _ => invalid = true,
+        },
+    );
+
+    if invalid {
+        return Err(anyhow!("properties: expected a map from PropertyName to PropertyValue")).wasm_result();
+    }
+    Ok(converted_properties)
+}
+
 fn call_js_method(obj: &JsValue, method: &str) -> Option<JsValue> {
     let func = js_sys::Reflect::get(obj, &JsValue::from_str(method)).ok()?;
     let func: &js_sys::Function = func.unchecked_ref();
@@ -515,11 +1454,24 @@ fn extract_property_name(js_val: &JsValue) -> Option<PropertyName> {
 }
 
 fn extract_property_value(js_val: &JsValue) -> Option<PropertyValue> {
-    if call_js_method(js_val, "isText")?.as_bool().unwrap_or(false) {
+    let is = |method: &str| call_js_method(js_val, method).and_then(|value| value.as_bool()) == Some(true);
+    let as_u64 = |method: &str| {
+        let bigint: js_sys::BigInt = call_js_method(js_val, method)?.dyn_into().ok()?;
+        u64::try_from(bigint).ok()
+    };
+
+    if is("isText") {
         return Some(PropertyValue::Text(call_js_method(js_val, "asText")?.as_string()?));
     }
-    let bigint_val = call_js_method(js_val, "asNumber")?;
-    let bigint: js_sys::BigInt = bigint_val.dyn_into().ok()?;
-    let number = u64::try_from(bigint).ok()?;
-    Some(PropertyValue::Number(number))
+    if is("isBool") {
+        return Some(PropertyValue::Bool(call_js_method(js_val, "asBool")?.as_bool()?));
+    }
+    if is("isTimestamp") {
+        return Some(PropertyValue::Timestamp(as_u64("asTimestamp")?));
+    }
+    if is("isBytes") {
+        let bytes = js_sys::Uint8Array::new(&call_js_method(js_val, "asBytes")?);
+        return Some(PropertyValue::Bytes(bytes.to_vec()));
+    }
+    Some(PropertyValue::Number(as_u64("asNumber")?))
 }
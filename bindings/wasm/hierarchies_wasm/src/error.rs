@@ -0,0 +1,315 @@
+// Copyright 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structured errors of the bindings.
+//!
+//! Errors of the Rust client are rejected as a JavaScript `Error` named `HierarchiesError`,
+//! carrying a stable `code` and a `details` object next to the message, so callers can tell a
+//! missing capability from an unreachable node without parsing messages:
+//!
+//! ```typescript
+//! try {
+//!   await client.isAttester(federationId, userId);
+//! } catch (err) {
+//!   if (err.name === "HierarchiesError" && err.code === "RpcFailed") {
+//!     // retry later
+//!   }
+//! }
+//! ```
+//!
+//! The code is derived from the innermost error, e.g. a capability lookup failing inside a
+//! transaction is reported as `CapabilityNotFound`. `details.kind` holds the name of that error's
+//! variant, e.g. `NotRootAuthority` for a `TransactionRejected` error.
+//!
+//! Transactions are built and applied by the shared product bindings, which reject with their own
+//! errors; `HierarchiesError`s are raised by the client methods.
+
+use std::error::Error;
+
+use hierarchies::client::SponsorshipError;
+use hierarchies::core::types::property_name::PropertyName;
+use hierarchies::error::{
+    CapabilityError, ClientError, ConfigError, NetworkError, ObjectError, OperationError, TransactionError,
+};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const HIERARCHIES_ERROR: &'static str = r#"
+/** The code of a {@link HierarchiesError}. */
+export type HierarchiesErrorCode =
+  | "CapabilityNotFound"
+  | "InvalidCapability"
+  | "RpcFailed"
+  | "Configuration"
+  | "ObjectNotFound"
+  | "WrongObjectType"
+  | "DecodingFailed"
+  | "InvalidInput"
+  | "InvalidResponse"
+  | "ExecutionFailed"
+  | "TransactionRejected"
+  | "GrantPolicyViolation"
+  | "NotConfirmed"
+  | "InvariantViolation"
+  | "SerializationFailed"
+  | "SponsorshipFailed"
+  | "Unknown";
+
+/** An error of the Hierarchies client, as rejected by its async methods. */
+export interface HierarchiesError extends Error {
+  name: "HierarchiesError";
+  code: HierarchiesErrorCode;
+  /** The variant of the underlying error as `kind`, and its fields, e.g. `capType`. */
+  details: { kind: string } & Record<string, string>;
+}
+"#;
+
+/// The name of the JavaScript errors created from a [`WasmHierarchiesError`].
+pub const HIERARCHIES_ERROR_NAME: &str = "HierarchiesError";
+
+/// The category of a [`WasmHierarchiesError`], mirrored by the `HierarchiesErrorCode` TypeScript type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// The sender doesn't own the required capability.
+    CapabilityNotFound,
+    /// An object was passed as a capability it isn't.
+    InvalidCapability,
+    /// The node couldn't be reached or failed to respond.
+    RpcFailed,
+    /// The client is misconfigured, e.g. no package is known for the network.
+    Configuration,
+    ObjectNotFound,
+    WrongObjectType,
+    /// An object or return value doesn't match the layout of the SDK.
+    DecodingFailed,
+    InvalidInput,
+    InvalidResponse,
+    ExecutionFailed,
+    /// The transaction was aborted by the Hierarchies package, see `details.kind`.
+    TransactionRejected,
+    GrantPolicyViolation,
+    /// A destructive operation wasn't confirmed before it was built.
+    NotConfirmed,
+    InvariantViolation,
+    SerializationFailed,
+    SponsorshipFailed,
+    Unknown,
+}
+
+impl ErrorCode {
+    /// Returns the code as exposed to JavaScript.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::CapabilityNotFound => "CapabilityNotFound",
+            ErrorCode::InvalidCapability => "InvalidCapability",
+            ErrorCode::RpcFailed => "RpcFailed",
+            ErrorCode::Configuration => "Configuration",
+            ErrorCode::ObjectNotFound => "ObjectNotFound",
+            ErrorCode::WrongObjectType => "WrongObjectType",
+            ErrorCode::DecodingFailed => "DecodingFailed",
+            ErrorCode::InvalidInput => "InvalidInput",
+            ErrorCode::InvalidResponse => "InvalidResponse",
+            ErrorCode::ExecutionFailed => "ExecutionFailed",
+            ErrorCode::TransactionRejected => "TransactionRejected",
+            ErrorCode::GrantPolicyViolation => "GrantPolicyViolation",
+            ErrorCode::NotConfirmed => "NotConfirmed",
+            ErrorCode::InvariantViolation => "InvariantViolation",
+            ErrorCode::SerializationFailed => "SerializationFailed",
+            ErrorCode::SponsorshipFailed => "SponsorshipFailed",
+            ErrorCode::Unknown => "Unknown",
+        }
+    }
+}
+
+/// The code and details of the innermost error.
+type Classification = (ErrorCode, Vec<(&'static str, String)>);
+
+/// An error of the Rust client with a stable code, converted into a `HierarchiesError` when
+/// crossing into JavaScript.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmHierarchiesError {
+    pub code: ErrorCode,
+    /// The message of the error followed by the messages of its sources
+    pub message: String,
+    /// The variant of the innermost error as `kind`, followed by its fields
+    pub details: Vec<(&'static str, String)>,
+}
+
+impl WasmHierarchiesError {
+    fn new(error: &(dyn Error + 'static), (code, details): Classification) -> Self {
+        let mut message = error.to_string();
+        let mut source = error.source();
+        while let Some(cause) = source {
+            message.push_str(": ");
+            message.push_str(&cause.to_string());
+            source = cause.source();
+        }
+
+        Self { code, message, details }
+    }
+}
+
+impl From<WasmHierarchiesError> for JsValue {
+    fn from(error: WasmHierarchiesError) -> Self {
+        let js_error = js_sys::Error::new(&error.message);
+        js_error.set_name(HIERARCHIES_ERROR_NAME);
+
+        let details = js_sys::Object::new();
+        for (key, value) in &error.details {
+            let _ = js_sys::Reflect::set(&details, &JsValue::from_str(key), &JsValue::from_str(value));
+        }
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("code"), &error.code.as_str().into());
+        let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("details"), &details);
+
+        js_error.into()
+    }
+}
+
+/// Converts an error of the Rust client into a `HierarchiesError`.
+pub(crate) fn hierarchies_error(error: impl Into<WasmHierarchiesError>) -> JsValue {
+    error.into().into()
+}
+
+macro_rules! impl_from_error {
+    ($($error:ty => $classify:ident),* $(,)?) => {
+        $(
+            impl From<$error> for WasmHierarchiesError {
+                fn from(error: $error) -> Self {
+                    Self::new(&error, $classify(&error))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_error!(
+    ClientError => classify_client,
+    OperationError => classify_operation,
+    CapabilityError => classify_capability,
+    TransactionError => classify_transaction,
+    ObjectError => classify_object,
+);
+
+fn kind<'e, E>(error: &'e E) -> (&'static str, String)
+where
+    &'e E: Into<&'static str>,
+{
+    let kind: &'static str = error.into();
+    ("kind", kind.to_string())
+}
+
+fn classify_client(error: &ClientError) -> Classification {
+    let code = match error {
+        ClientError::Network(error) => return classify_network(error),
+        ClientError::Configuration(error) => return classify_config(error),
+        ClientError::Object(error) => return classify_object(error),
+        ClientError::Operation(error) => return classify_operation(error),
+        ClientError::Transaction(error) => return classify_transaction(error),
+        ClientError::Sponsorship(SponsorshipError::Transaction(error)) => return classify_transaction(error),
+        ClientError::Sponsorship(_) => ErrorCode::SponsorshipFailed,
+        ClientError::ExecutionFailed { .. } => ErrorCode::ExecutionFailed,
+        ClientError::InvalidResponse { .. } => ErrorCode::InvalidResponse,
+        ClientError::InvalidInput { .. } => ErrorCode::InvalidInput,
+        _ => ErrorCode::Unknown,
+    };
+    (code, vec![kind(error)])
+}
+
+fn classify_network(error: &NetworkError) -> Classification {
+    (ErrorCode::RpcFailed, vec![kind(error)])
+}
+
+fn classify_config(error: &ConfigError) -> Classification {
+    (ErrorCode::Configuration, vec![kind(error)])
+}
+
+fn classify_object(error: &ObjectError) -> Classification {
+    let mut details = vec![kind(error)];
+    let code = match error {
+        ObjectError::NotFound { id } => {
+            details.push(("id", id.clone()));
+            ErrorCode::ObjectNotFound
+        }
+        ObjectError::RetrievalFailed { .. } => ErrorCode::RpcFailed,
+        ObjectError::WrongType { expected, actual } => {
+            details.extend([("expected", expected.clone()), ("actual", actual.clone())]);
+            ErrorCode::WrongObjectType
+        }
+        ObjectError::Decoding { id, .. } => {
+            details.push(("id", id.clone()));
+            ErrorCode::DecodingFailed
+        }
+        _ => ErrorCode::Unknown,
+    };
+    (code, details)
+}
+
+fn classify_capability(error: &CapabilityError) -> Classification {
+    let mut details = vec![kind(error)];
+    let code = match error {
+        CapabilityError::NotFound { cap_type } => {
+            details.push(("capType", cap_type.clone()));
+            ErrorCode::CapabilityNotFound
+        }
+        CapabilityError::InvalidType { cap_type } => {
+            details.push(("capType", cap_type.clone()));
+            ErrorCode::InvalidCapability
+        }
+        CapabilityError::Rpc { .. } => ErrorCode::RpcFailed,
+        _ => ErrorCode::Unknown,
+    };
+    (code, details)
+}
+
+fn classify_operation(error: &OperationError) -> Classification {
+    let mut details = vec![kind(error)];
+    let code = match error {
+        OperationError::Capability(error) => return classify_capability(error),
+        OperationError::Object(error) => return classify_object(error),
+        OperationError::DestructiveNotConfirmed { operation } => {
+            details.push(("operation", operation.clone()));
+            ErrorCode::NotConfirmed
+        }
+        OperationError::GrantPolicy(_) => ErrorCode::GrantPolicyViolation,
+        OperationError::InvariantViolation { operation, condition } => {
+            details.extend([("operation", operation.clone()), ("condition", condition.clone())]);
+            ErrorCode::InvariantViolation
+        }
+        OperationError::Subject(_) | OperationError::EmptyBatch => ErrorCode::InvalidInput,
+        OperationError::WildcardProperty { name } => {
+            details.push(("property", name.clone()));
+            ErrorCode::InvalidInput
+        }
+        OperationError::Serialization { .. } => ErrorCode::SerializationFailed,
+        _ => ErrorCode::Unknown,
+    };
+    (code, details)
+}
+
+fn classify_transaction(error: &TransactionError) -> Classification {
+    let mut details = vec![kind(error)];
+    let code = match error {
+        TransactionError::Operation(error) => return classify_operation(error),
+        TransactionError::ExecutionFailed { reason } => {
+            details.push(("reason", reason.clone()));
+            ErrorCode::ExecutionFailed
+        }
+        TransactionError::InvalidResponse | TransactionError::EventProcessingFailed { .. } => {
+            ErrorCode::InvalidResponse
+        }
+        TransactionError::PropertyNotInFederation { name } | TransactionError::PropertyRevoked { name } => {
+            details.extend(name.as_ref().map(|name| ("property", dotted(name))));
+            ErrorCode::TransactionRejected
+        }
+        TransactionError::MoveAbort { module, code } => {
+            details.extend([("module", module.clone()), ("abortCode", code.to_string())]);
+            ErrorCode::TransactionRejected
+        }
+        _ => ErrorCode::TransactionRejected,
+    };
+    (code, details)
+}
+
+fn dotted(name: &PropertyName) -> String {
+    name.names().join(".")
+}
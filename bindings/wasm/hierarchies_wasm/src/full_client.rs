@@ -2,23 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hierarchies::client::HierarchiesClient;
+use hierarchies::core::transactions::Destructive;
+use hierarchies::core::transactions::preview::preview_programmable_transaction_bcs;
 use iota_interaction::types::base_types::ObjectID;
 use iota_interaction_ts::WasmPublicKey;
 use iota_interaction_ts::bindings::{WasmIotaClient, WasmTransactionSigner};
-use iota_interaction_ts::wasm_error::{Result, WasmResult};
+use iota_interaction_ts::wasm_error::Result;
 use product_common::bindings::transaction::WasmTransactionBuilder;
-use product_common::bindings::utils::{into_transaction_builder, parse_wasm_object_id};
+use product_common::bindings::utils::{into_transaction_builder, parse_wasm_iota_address, parse_wasm_object_id};
 use product_common::bindings::{WasmIotaAddress, WasmObjectID};
 use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use wasm_bindgen::prelude::*;
 
-use crate::client_read_only::WasmHierarchiesClientReadOnly;
+use crate::cancellation::{WasmCallOptions, cancellable};
+use crate::client_read_only::{WasmHierarchiesClientReadOnly, parse_property_map};
+use crate::error::hierarchies_error;
 use crate::wasm_types::transactions::{
-    WasmAddProperty, WasmAddRootAuthority, WasmCreateAccreditationToAccredit, WasmCreateAccreditationToAttest,
-    WasmCreateFederation, WasmReinstateRootAuthority, WasmRevokeAccreditationToAccredit,
-    WasmRevokeAccreditationToAttest, WasmRevokeProperty, WasmRevokeRootAuthority,
+    WasmActivateAccreditation, WasmAddProperty, WasmAddRootAuthority, WasmCreateAccreditationToAccredit,
+    WasmCreateAccreditationToAttest, WasmCreateAttestation, WasmCreateFederation, WasmDeprecateProperty,
+    WasmLinkFederation, WasmReinstateRootAuthority, WasmRemoveValueDictionary, WasmRevokeAccreditationToAccredit,
+    WasmRevokeAccreditationToAttest, WasmRevokeAttestation, WasmRevokeProperty, WasmRevokeRootAuthority,
+    WasmRotateRootAuthority, WasmSetAccreditationTags, WasmSetFederationMetadata, WasmSetGrantPolicy,
+    WasmSetPropertyCritical, WasmSetPropertyMetadata, WasmSetPropertyTags, WasmSetValueDictionary,
+    WasmUnlinkFederation,
+};
+use crate::wasm_types::{
+    WasmFederationMetadata, WasmGrantPolicy, WasmProperty, WasmPropertyMetadata, WasmPropertyName, WasmPropertyValue,
+    WasmTimespan, WasmTransactionEffectsPreview,
 };
-use crate::wasm_types::{WasmProperty, WasmPropertyName};
 
 /// A client to interact with Hierarchies objects on the IOTA ledger.
 ///
@@ -26,7 +37,10 @@ use crate::wasm_types::{WasmProperty, WasmPropertyName};
 /// you can use {@link HierarchiesClientReadOnly}, which does not require an account or signing capabilities.
 
 #[wasm_bindgen(js_name = HierarchiesClient)]
-pub struct WasmHierarchiesClient(pub(crate) HierarchiesClient<WasmTransactionSigner>);
+pub struct WasmHierarchiesClient(
+    pub(crate) HierarchiesClient<WasmTransactionSigner>,
+    pub(crate) WasmCallOptions,
+);
 
 #[wasm_bindgen(js_class=HierarchiesClient)]
 impl WasmHierarchiesClient {
@@ -34,7 +48,8 @@ impl WasmHierarchiesClient {
     ///
     /// # Arguments
     ///
-    /// * `client` - A read-only client for blockchain interaction.
+    /// * `client` - A read-only client for blockchain interaction. Its call options also apply to the transactions of
+    ///   this client.
     /// * `signer` - A signer for transaction authorization.
     ///
     /// # Errors
@@ -47,8 +62,12 @@ impl WasmHierarchiesClient {
         client: WasmHierarchiesClientReadOnly,
         signer: WasmTransactionSigner,
     ) -> Result<WasmHierarchiesClient> {
-        let inner_client = HierarchiesClient::new(client.0, signer).await.wasm_result()?;
-        Ok(WasmHierarchiesClient(inner_client))
+        let WasmHierarchiesClientReadOnly(client, options) = client;
+        let inner_client = cancellable(&options, async {
+            HierarchiesClient::new(client, signer).await.map_err(hierarchies_error)
+        })
+        .await?;
+        Ok(WasmHierarchiesClient(inner_client, options))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for creating a new federation.
@@ -58,7 +77,7 @@ impl WasmHierarchiesClient {
     pub fn create_new_federation(&self) -> Result<WasmTransactionBuilder> {
         let tx = self.0.create_new_federation().into_inner();
 
-        Ok(into_transaction_builder(WasmCreateFederation(tx)))
+        Ok(into_transaction_builder(WasmCreateFederation(tx, self.1.clone())))
     }
 
     /// Creates a [`WasmTransactionBuilder`] for adding a root authority to a federation.
@@ -77,7 +96,7 @@ impl WasmHierarchiesClient {
         let account_id = parse_wasm_object_id(&account_id)?;
 
         let tx = self.0.add_root_authority(federation_id, account_id).into_inner();
-        Ok(into_transaction_builder(WasmAddRootAuthority(tx)))
+        Ok(into_transaction_builder(WasmAddRootAuthority(tx, self.1.clone())))
     }
 
     /// Creates a [`WasmTransactionBuilder`] for revoking a root authority from a federation.
@@ -89,17 +108,56 @@ impl WasmHierarchiesClient {
     ///
     /// * `federation_id` - The [`WasmObjectID`] of the federation.
     /// * `account_id` - The [`WasmObjectID`] of the account to revoke as a root authority.
+    /// * `confirm_irreversible` - Confirms the immediate revocation, which only a reinstatement reverses. Building the
+    ///   transaction fails unless `true`.
     #[wasm_bindgen(js_name = revokeRootAuthority)]
     pub fn revoke_root_authority(
         &self,
         federation_id: WasmObjectID,
         account_id: WasmObjectID,
+        confirm_irreversible: Option<bool>,
     ) -> Result<WasmTransactionBuilder> {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let account_id = parse_wasm_object_id(&account_id)?;
 
-        let tx = self.0.revoke_root_authority(federation_id, account_id).into_inner();
-        Ok(into_transaction_builder(WasmRevokeRootAuthority(tx)))
+        let mut tx = self.0.revoke_root_authority(federation_id, account_id).into_inner();
+        if confirm_irreversible.unwrap_or(false) {
+            tx = tx.confirm_irreversible();
+        }
+        Ok(into_transaction_builder(WasmRevokeRootAuthority(tx, self.1.clone())))
+    }
+
+    /// Creates a [`WasmTransactionBuilder`] for handing a root authority over to another account.
+    ///
+    /// The new root authority is added and the old one revoked in the same transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `old_account_id` - The [`WasmObjectID`] of the root authority to hand over.
+    /// * `new_account_id` - The [`WasmObjectID`] of the account to hand the root authority over to.
+    /// * `confirm_irreversible` - Confirms the immediate revocation, which only a reinstatement reverses. Building the
+    ///   transaction fails unless `true`.
+    #[wasm_bindgen(js_name = rotateRootAuthority)]
+    pub fn rotate_root_authority(
+        &self,
+        federation_id: WasmObjectID,
+        old_account_id: WasmObjectID,
+        new_account_id: WasmObjectID,
+        confirm_irreversible: Option<bool>,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let old_account_id = parse_wasm_object_id(&old_account_id)?;
+        let new_account_id = parse_wasm_object_id(&new_account_id)?;
+
+        let mut tx = self
+            .0
+            .rotate_root_authority(federation_id, old_account_id, new_account_id)
+            .into_inner();
+        if confirm_irreversible.unwrap_or(false) {
+            tx = tx.confirm_irreversible();
+        }
+        Ok(into_transaction_builder(WasmRotateRootAuthority(tx, self.1.clone())))
     }
 
     /// Creates a [`WasmTransactionBuilder`] for reinstating a revoked root authority to a federation.
@@ -121,7 +179,7 @@ impl WasmHierarchiesClient {
         let account_id = parse_wasm_object_id(&account_id)?;
 
         let tx = self.0.reinstate_root_authority(federation_id, account_id).into_inner();
-        Ok(into_transaction_builder(WasmReinstateRootAuthority(tx)))
+        Ok(into_transaction_builder(WasmReinstateRootAuthority(tx, self.1.clone())))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for adding a property to a federation.
@@ -134,7 +192,7 @@ impl WasmHierarchiesClient {
     pub fn add_property(&self, federation_id: WasmObjectID, property: &WasmProperty) -> Result<WasmTransactionBuilder> {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let tx = self.0.add_property(federation_id, property.clone().into()).into_inner();
-        Ok(into_transaction_builder(WasmAddProperty(tx)))
+        Ok(into_transaction_builder(WasmAddProperty(tx, self.1.clone())))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for revoking a property from a federation.
@@ -144,19 +202,271 @@ impl WasmHierarchiesClient {
     /// * `federation_id` - The [`WasmObjectID`] of the federation.
     /// * `property_name` - The name of the property to revoke.
     /// * `valid_to_ms` - The timestamp in milliseconds until which the property is valid.
+    /// * `confirm_irreversible` - Confirms an immediate revocation. Building a transaction without `valid_to_ms` fails
+    ///   unless `true`.
     pub fn revoke_property(
         &self,
         federation_id: WasmObjectID,
         property_name: &WasmPropertyName,
         valid_to_ms: Option<u64>,
+        confirm_irreversible: Option<bool>,
     ) -> Result<WasmTransactionBuilder> {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let property_name = property_name.0.clone();
-        let tx = self
+        let mut tx = self
             .0
             .revoke_property(federation_id, property_name, valid_to_ms)
             .into_inner();
-        Ok(into_transaction_builder(WasmRevokeProperty(tx)))
+        if confirm_irreversible.unwrap_or(false) {
+            tx = tx.confirm_irreversible();
+        }
+        Ok(into_transaction_builder(WasmRevokeProperty(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for marking or unmarking a property as critical.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `property_name` - The name of the property.
+    /// * `critical` - Whether the property is critical.
+    #[wasm_bindgen(js_name = setPropertyCritical)]
+    pub fn set_property_critical(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: &WasmPropertyName,
+        critical: bool,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self
+            .0
+            .set_property_critical(federation_id, property_name.0.clone(), critical)
+            .into_inner();
+        Ok(into_transaction_builder(WasmSetPropertyCritical(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for deprecating a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `property_name` - The name of the property to deprecate.
+    /// * `replacement` - The name of the property replacing it, if any.
+    #[wasm_bindgen(js_name = deprecateProperty)]
+    pub fn deprecate_property(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: &WasmPropertyName,
+        replacement: Option<WasmPropertyName>,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self
+            .0
+            .deprecate_property(federation_id, property_name.0.clone(), replacement.map(Into::into))
+            .into_inner();
+        Ok(into_transaction_builder(WasmDeprecateProperty(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for replacing the metadata of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `property_name` - The name of the property.
+    /// * `metadata` - The new metadata of the property, empty metadata removes all entries.
+    #[wasm_bindgen(js_name = setPropertyMetadata)]
+    pub fn set_property_metadata(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: &WasmPropertyName,
+        metadata: &WasmPropertyMetadata,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self
+            .0
+            .set_property_metadata(federation_id, property_name.0.clone(), metadata.0.clone())
+            .into_inner();
+        Ok(into_transaction_builder(WasmSetPropertyMetadata(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for replacing the tags of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `property_name` - The name of the property.
+    /// * `tags` - The new tags of the property, an empty array removes all tags.
+    #[wasm_bindgen(js_name = setPropertyTags)]
+    pub fn set_property_tags(
+        &self,
+        federation_id: WasmObjectID,
+        property_name: &WasmPropertyName,
+        tags: Vec<String>,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self
+            .0
+            .set_property_tags(federation_id, property_name.0.clone(), tags)
+            .into_inner();
+        Ok(into_transaction_builder(WasmSetPropertyTags(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for replacing the tags of an accreditation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `accreditation_id` - The [`WasmObjectID`] of the accreditation.
+    /// * `tags` - The new tags of the accreditation, an empty array removes all tags.
+    #[wasm_bindgen(js_name = setAccreditationTags)]
+    pub fn set_accreditation_tags(
+        &self,
+        federation_id: WasmObjectID,
+        accreditation_id: WasmObjectID,
+        tags: Vec<String>,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
+        let tx = self
+            .0
+            .set_accreditation_tags(federation_id, accreditation_id, tags)
+            .into_inner();
+        Ok(into_transaction_builder(WasmSetAccreditationTags(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for creating or replacing a value dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `name` - The name of the value dictionary.
+    /// * `values` - The values of the dictionary.
+    #[wasm_bindgen(js_name = setValueDictionary)]
+    pub fn set_value_dictionary(
+        &self,
+        federation_id: WasmObjectID,
+        name: String,
+        values: Vec<WasmPropertyValue>,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self
+            .0
+            .set_value_dictionary(federation_id, name, values.into_iter().map(|value| value.0))
+            .into_inner();
+        Ok(into_transaction_builder(WasmSetValueDictionary(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for removing a value dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `name` - The name of the value dictionary.
+    #[wasm_bindgen(js_name = removeValueDictionary)]
+    pub fn remove_value_dictionary(&self, federation_id: WasmObjectID, name: String) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self.0.remove_value_dictionary(federation_id, name).into_inner();
+        Ok(into_transaction_builder(WasmRemoveValueDictionary(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for setting the metadata of a federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `metadata` - The human-readable description of the federation.
+    #[wasm_bindgen(js_name = setFederationMetadata)]
+    pub fn set_federation_metadata(
+        &self,
+        federation_id: WasmObjectID,
+        metadata: &WasmFederationMetadata,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self
+            .0
+            .set_federation_metadata(federation_id, metadata.0.clone())
+            .into_inner();
+        Ok(into_transaction_builder(WasmSetFederationMetadata(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for recognizing a child federation.
+    ///
+    /// Trust chains ending at a root authority of the child federation are trusted like chains
+    /// of the parent federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the parent federation.
+    /// * `child_federation_id` - The [`WasmObjectID`] of the child federation.
+    #[wasm_bindgen(js_name = linkFederation)]
+    pub fn link_federation(
+        &self,
+        federation_id: WasmObjectID,
+        child_federation_id: WasmObjectID,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let child_federation_id = parse_wasm_object_id(&child_federation_id)?;
+        let tx = self.0.link_federation(federation_id, child_federation_id).into_inner();
+        Ok(into_transaction_builder(WasmLinkFederation(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for no longer recognizing a child federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the parent federation.
+    /// * `child_federation_id` - The [`WasmObjectID`] of the child federation.
+    #[wasm_bindgen(js_name = unlinkFederation)]
+    pub fn unlink_federation(
+        &self,
+        federation_id: WasmObjectID,
+        child_federation_id: WasmObjectID,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let child_federation_id = parse_wasm_object_id(&child_federation_id)?;
+        let tx = self
+            .0
+            .unlink_federation(federation_id, child_federation_id)
+            .into_inner();
+        Ok(into_transaction_builder(WasmUnlinkFederation(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for setting the grant policy of a federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `policy` - The defaults and limits applied to newly granted accreditations.
+    #[wasm_bindgen(js_name = setGrantPolicy)]
+    pub fn set_grant_policy(
+        &self,
+        federation_id: WasmObjectID,
+        policy: &WasmGrantPolicy,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let tx = self.0.set_grant_policy(federation_id, policy.0.clone()).into_inner();
+        Ok(into_transaction_builder(WasmSetGrantPolicy(tx, self.1.clone())))
+    }
+
+    /// Creates a new [`WasmTransactionBuilder`] for ending the objection window of an accreditation.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `accreditation_id` - The [`WasmObjectID`] of the accreditation to activate.
+    #[wasm_bindgen(js_name = activateAccreditation)]
+    pub fn activate_accreditation(
+        &self,
+        federation_id: WasmObjectID,
+        accreditation_id: WasmObjectID,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
+        let tx = self
+            .0
+            .activate_accreditation(federation_id, accreditation_id)
+            .into_inner();
+        Ok(into_transaction_builder(WasmActivateAccreditation(tx, self.1.clone())))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for creating an accreditation to attest.
@@ -185,7 +495,10 @@ impl WasmHierarchiesClient {
             )
             .into_inner();
 
-        Ok(into_transaction_builder(WasmCreateAccreditationToAttest(tx)))
+        Ok(into_transaction_builder(WasmCreateAccreditationToAttest(
+            tx,
+            self.1.clone(),
+        )))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for revoking an accreditation to attest.
@@ -211,7 +524,10 @@ impl WasmHierarchiesClient {
             .revoke_accreditation_to_attest(federation_id, user_id, permission_id)
             .into_inner();
 
-        Ok(into_transaction_builder(WasmRevokeAccreditationToAttest(tx)))
+        Ok(into_transaction_builder(WasmRevokeAccreditationToAttest(
+            tx,
+            self.1.clone(),
+        )))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for creating an accreditation to accredit.
@@ -240,7 +556,10 @@ impl WasmHierarchiesClient {
             )
             .into_inner();
 
-        Ok(into_transaction_builder(WasmCreateAccreditationToAccredit(tx)))
+        Ok(into_transaction_builder(WasmCreateAccreditationToAccredit(
+            tx,
+            self.1.clone(),
+        )))
     }
 
     /// Creates a new [`WasmTransactionBuilder`] for revoking an accreditation to accredit.
@@ -265,7 +584,91 @@ impl WasmHierarchiesClient {
             .0
             .revoke_accreditation_to_accredit(federation_id, user_id, accreditation_id)
             .into_inner();
-        Ok(into_transaction_builder(WasmRevokeAccreditationToAccredit(tx)))
+        Ok(into_transaction_builder(WasmRevokeAccreditationToAccredit(
+            tx,
+            self.1.clone(),
+        )))
+    }
+
+    /// Creates a [`WasmTransactionBuilder`] for issuing an attestation.
+    ///
+    /// The sender must be accredited to attest all properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `receiver` - The [`WasmIotaAddress`] receiving the attestation.
+    /// * `properties` - A map from [`WasmPropertyName`] to the attested [`WasmPropertyValue`].
+    /// * `timespan` - The validity window of the attestation, valid right away and until revoked if omitted.
+    #[wasm_bindgen(js_name = createAttestation)]
+    pub fn create_attestation(
+        &self,
+        federation_id: WasmObjectID,
+        receiver: WasmIotaAddress,
+        properties: js_sys::Map,
+        timespan: Option<WasmTimespan>,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let receiver = parse_wasm_iota_address(&receiver)?;
+        let properties = parse_property_map(&properties)?;
+        let timespan = timespan.map(|timespan| timespan.0).unwrap_or_default();
+
+        let tx = self
+            .0
+            .create_attestation(federation_id, receiver, properties, timespan)
+            .into_inner();
+        Ok(into_transaction_builder(WasmCreateAttestation(tx, self.1.clone())))
+    }
+
+    /// Creates a [`WasmTransactionBuilder`] for revoking an attestation.
+    ///
+    /// Only the issuer of the attestation and root authorities can revoke it.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The [`WasmObjectID`] of the federation.
+    /// * `attestation_id` - The [`WasmObjectID`] of the attestation.
+    #[wasm_bindgen(js_name = revokeAttestation)]
+    pub fn revoke_attestation(
+        &self,
+        federation_id: WasmObjectID,
+        attestation_id: WasmObjectID,
+    ) -> Result<WasmTransactionBuilder> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let attestation_id = parse_wasm_object_id(&attestation_id)?;
+
+        let tx = self.0.revoke_attestation(federation_id, attestation_id).into_inner();
+        Ok(into_transaction_builder(WasmRevokeAttestation(tx, self.1.clone())))
+    }
+
+    /// Previews the changes a transaction would make to federations, without executing it.
+    ///
+    /// Dry-runs the transaction with the sender's address, e.g. for a governance board to review
+    /// a change before approving it.
+    ///
+    /// # Arguments
+    ///
+    /// * `programmable_transaction` - The BCS bytes returned by `buildProgrammableTransaction` of any transaction.
+    ///
+    /// # TypeScript Usage
+    ///
+    /// ```typescript
+    /// const tx = new AddProperty(federationId, property, client.senderAddress());
+    /// const preview = await client.previewTransaction(await tx.buildProgrammableTransaction(client));
+    /// console.log(preview.diff);
+    /// ```
+    #[wasm_bindgen(js_name = previewTransaction)]
+    pub async fn preview_transaction(
+        &self,
+        programmable_transaction: Vec<u8>,
+    ) -> Result<WasmTransactionEffectsPreview> {
+        cancellable(&self.1, async {
+            preview_programmable_transaction_bcs(&programmable_transaction, self.0.sender_address(), &self.0)
+                .await
+                .map(WasmTransactionEffectsPreview::from)
+                .map_err(hierarchies_error)
+        })
+        .await
     }
 
     /// Retrieves the sender's public key.
@@ -317,7 +720,7 @@ impl WasmHierarchiesClient {
     /// Retrieves a read-only version of the hierarchies client.
     #[wasm_bindgen(js_name = readOnly)]
     pub fn read_only(&self) -> WasmHierarchiesClientReadOnly {
-        WasmHierarchiesClientReadOnly((*self.0).clone())
+        WasmHierarchiesClientReadOnly((*self.0).clone(), self.1.clone())
     }
 
     /// Returns the `tf_components` package ID currently in use.
@@ -13,7 +13,9 @@ extern crate serde;
 
 use wasm_bindgen::prelude::*;
 
+pub mod cancellation;
 pub mod client_read_only;
+pub mod error;
 pub mod full_client;
 pub mod wasm_types;
 
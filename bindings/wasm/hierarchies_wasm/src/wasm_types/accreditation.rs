@@ -37,6 +37,14 @@ impl WasmAccreditation {
             .collect::<Vec<_>>()
             .into_boxed_slice()
     }
+
+    /// Returns the end of the objection window of the accreditation in milliseconds, if any.
+    ///
+    /// The accreditation is not effective before.
+    #[wasm_bindgen(getter, js_name = "pendingUntilMs")]
+    pub fn pending_until_ms(&self) -> Option<u64> {
+        self.0.pending_until_ms
+    }
 }
 
 impl From<Accreditation> for WasmAccreditation {
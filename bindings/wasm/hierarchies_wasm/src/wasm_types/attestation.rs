@@ -0,0 +1,78 @@
+// Copyright 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use hierarchies::core::types::attestation::Attestation;
+use product_common::bindings::WasmObjectID;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::wasm_types::{WasmPropertyName, WasmPropertyValue};
+
+/// Represents an attestation, properties of a receiver attested by an attester of a federation.
+#[wasm_bindgen(js_name = Attestation, inspectable)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct WasmAttestation(pub(crate) Attestation);
+
+#[wasm_bindgen(js_class = Attestation)]
+impl WasmAttestation {
+    /// Returns the unique identifier of the attestation.
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> WasmObjectID {
+        self.0.id().to_string()
+    }
+
+    /// Returns the identifier of the federation the attestation was issued in.
+    #[wasm_bindgen(getter, js_name = "federationId")]
+    pub fn federation_id(&self) -> WasmObjectID {
+        self.0.federation_id.to_string()
+    }
+
+    /// Returns the identifier of the attester that issued the attestation.
+    #[wasm_bindgen(getter)]
+    pub fn issuer(&self) -> WasmObjectID {
+        self.0.issuer.to_string()
+    }
+
+    /// Returns the attested properties as a map from property names to values.
+    #[wasm_bindgen(getter)]
+    pub fn properties(&self) -> js_sys::Map {
+        let map = js_sys::Map::new();
+        for (name, value) in &self.0.properties {
+            map.set(
+                &WasmPropertyName::from(name.clone()).into(),
+                &WasmPropertyValue::from(value.clone()).into(),
+            );
+        }
+        map
+    }
+
+    /// Returns the start of the validity window in milliseconds, if any.
+    #[wasm_bindgen(getter, js_name = "validFromMs")]
+    pub fn valid_from_ms(&self) -> Option<u64> {
+        self.0.timespan.valid_from_ms
+    }
+
+    /// Returns the end of the validity window in milliseconds, if any.
+    #[wasm_bindgen(getter, js_name = "validUntilMs")]
+    pub fn valid_until_ms(&self) -> Option<u64> {
+        self.0.timespan.valid_until_ms
+    }
+
+    /// Returns the time the attestation was issued in milliseconds.
+    #[wasm_bindgen(getter, js_name = "issuedAtMs")]
+    pub fn issued_at_ms(&self) -> u64 {
+        self.0.issued_at_ms
+    }
+}
+
+impl From<Attestation> for WasmAttestation {
+    fn from(value: Attestation) -> Self {
+        WasmAttestation(value)
+    }
+}
+
+impl From<WasmAttestation> for Attestation {
+    fn from(value: WasmAttestation) -> Self {
+        value.0
+    }
+}
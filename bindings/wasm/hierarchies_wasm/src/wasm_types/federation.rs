@@ -3,9 +3,14 @@
 
 use std::collections::HashSet;
 
-use hierarchies::core::types::property::{FederationProperties, FederationProperty};
+use anyhow::anyhow;
+use hierarchies::core::types::grant_policy::GrantPolicy;
+use hierarchies::core::types::property::{FederationProperties, FederationProperty, PropertyMetadata};
+use hierarchies::core::types::property_shape::PropertyShape;
+use hierarchies::core::types::property_value::PropertyValue;
 use hierarchies::core::types::timespan::Timespan;
-use hierarchies::core::types::{Federation, Governance, RootAuthority};
+use hierarchies::core::types::{Federation, FederationMetadata, FederationStats, Governance, RootAuthority};
+use iota_interaction_ts::wasm_error::{Result, WasmResult};
 use product_common::bindings::WasmObjectID;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -64,6 +69,30 @@ impl WasmFederation {
             .map(|ra| ra.to_string())
             .collect()
     }
+
+    /// Retrieves the summary statistics of the federation.
+    ///
+    /// # Returns
+    /// The statistics maintained on-chain by every mutation.
+    #[wasm_bindgen(getter)]
+    pub fn stats(&self) -> WasmFederationStats {
+        self.0.stats.into()
+    }
+
+    /// Retrieves the metadata of the federation.
+    ///
+    /// # Returns
+    /// The human-readable description set by root authorities.
+    #[wasm_bindgen(getter)]
+    pub fn metadata(&self) -> WasmFederationMetadata {
+        self.0.metadata.clone().into()
+    }
+
+    /// Retrieves the display name of the federation, falling back to its ID if none is set.
+    #[wasm_bindgen(getter, js_name = displayName)]
+    pub fn display_name(&self) -> String {
+        self.0.display_name()
+    }
 }
 
 /// Represents the governance of a federation
@@ -128,6 +157,268 @@ impl WasmGovernance {
         }
         map
     }
+
+    /// Retrieves the names of the properties marked as critical.
+    ///
+    /// # Returns
+    /// An array of property names.
+    #[wasm_bindgen(getter, js_name = criticalProperties)]
+    pub fn critical_properties(&self) -> Vec<WasmPropertyName> {
+        self.0.critical_properties.iter().cloned().map(Into::into).collect()
+    }
+
+    /// Retrieves the value dictionaries of the federation.
+    ///
+    /// # Returns
+    /// A JavaScript Map object from dictionary name to an array of property values.
+    #[wasm_bindgen(getter, js_name = valueDictionaries)]
+    pub fn value_dictionaries(&self) -> js_sys::Map {
+        let map = js_sys::Map::new();
+        for (name, values) in &self.0.value_dictionaries {
+            let values: js_sys::Array = values
+                .iter()
+                .map(|value| wasm_bindgen::JsValue::from(WasmPropertyValue::from(value.clone())))
+                .collect();
+            map.set(&wasm_bindgen::JsValue::from_str(name), &values);
+        }
+        map
+    }
+
+    /// Retrieves the tags of the accreditations.
+    ///
+    /// # Returns
+    /// A JavaScript Map object from accreditation ID to an array of tags.
+    #[wasm_bindgen(getter, js_name = accreditationTags)]
+    pub fn accreditation_tags(&self) -> js_sys::Map {
+        let map = js_sys::Map::new();
+        for (id, tags) in &self.0.accreditation_tags {
+            let tags: js_sys::Array = tags.iter().map(|tag| wasm_bindgen::JsValue::from_str(tag)).collect();
+            map.set(&wasm_bindgen::JsValue::from_str(&id.to_string()), &tags);
+        }
+        map
+    }
+
+    /// Retrieves the defaults and limits applied to newly granted accreditations.
+    ///
+    /// # Returns
+    /// The grant policy of the federation.
+    #[wasm_bindgen(getter, js_name = grantPolicy)]
+    pub fn grant_policy(&self) -> WasmGrantPolicy {
+        self.0.grant_policy.clone().into()
+    }
+
+    /// Retrieves the child federations recognized by the federation.
+    ///
+    /// # Returns
+    /// An array of federation IDs.
+    #[wasm_bindgen(getter, js_name = recognizedFederations)]
+    pub fn recognized_federations(&self) -> Vec<WasmObjectID> {
+        self.0.recognized_federations.iter().map(|id| id.to_string()).collect()
+    }
+}
+
+/// Summary statistics of a federation, maintained on-chain by every mutation
+#[wasm_bindgen(js_name = FederationStats, inspectable)]
+#[derive(Deserialize, Serialize, Clone, Copy)]
+pub struct WasmFederationStats(pub(crate) FederationStats);
+
+impl From<FederationStats> for WasmFederationStats {
+    fn from(value: FederationStats) -> Self {
+        WasmFederationStats(value)
+    }
+}
+
+#[wasm_bindgen(js_class = FederationStats)]
+impl WasmFederationStats {
+    /// Retrieves the number of properties, including revoked ones.
+    #[wasm_bindgen(getter, js_name = propertyCount)]
+    pub fn property_count(&self) -> u64 {
+        self.0.property_count
+    }
+
+    /// Retrieves the number of active root authorities.
+    #[wasm_bindgen(getter, js_name = rootAuthorityCount)]
+    pub fn root_authority_count(&self) -> u64 {
+        self.0.root_authority_count
+    }
+
+    /// Retrieves the number of accreditations to attest held by all entities.
+    #[wasm_bindgen(getter, js_name = accreditationToAttestCount)]
+    pub fn accreditation_to_attest_count(&self) -> u64 {
+        self.0.accreditation_to_attest_count
+    }
+
+    /// Retrieves the number of accreditations to accredit held by all entities.
+    #[wasm_bindgen(getter, js_name = accreditationToAccreditCount)]
+    pub fn accreditation_to_accredit_count(&self) -> u64 {
+        self.0.accreditation_to_accredit_count
+    }
+
+    /// Retrieves the number of mutations applied since the federation was created.
+    #[wasm_bindgen(getter, js_name = modificationCount)]
+    pub fn modification_count(&self) -> u64 {
+        self.0.modification_count
+    }
+
+    /// Retrieves the start of the epoch in which the last mutation was applied.
+    #[wasm_bindgen(getter, js_name = lastModifiedEpochMs)]
+    pub fn last_modified_epoch_ms(&self) -> u64 {
+        self.0.last_modified_epoch_ms
+    }
+}
+
+/// Optional human-readable description of a federation
+#[wasm_bindgen(js_name = FederationMetadata, inspectable)]
+#[derive(Deserialize, Serialize, Clone, Default)]
+pub struct WasmFederationMetadata(pub(crate) FederationMetadata);
+
+impl From<FederationMetadata> for WasmFederationMetadata {
+    fn from(value: FederationMetadata) -> Self {
+        WasmFederationMetadata(value)
+    }
+}
+
+#[wasm_bindgen(js_class = FederationMetadata)]
+impl WasmFederationMetadata {
+    /// Creates empty federation metadata.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieves the display name of the federation.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> Option<String> {
+        self.0.name.clone()
+    }
+
+    /// Sets the display name of the federation.
+    #[wasm_bindgen(setter)]
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.0.name = name;
+    }
+
+    /// Retrieves the description of the federation.
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.0.description.clone()
+    }
+
+    /// Sets the description of the federation.
+    #[wasm_bindgen(setter)]
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.0.description = description;
+    }
+
+    /// Retrieves the URI of the logo of the federation.
+    #[wasm_bindgen(getter, js_name = logoUri)]
+    pub fn logo_uri(&self) -> Option<String> {
+        self.0.logo_uri.clone()
+    }
+
+    /// Sets the URI of the logo of the federation.
+    #[wasm_bindgen(setter, js_name = logoUri)]
+    pub fn set_logo_uri(&mut self, logo_uri: Option<String>) {
+        self.0.logo_uri = logo_uri;
+    }
+
+    /// Retrieves the contact of the federation operators.
+    #[wasm_bindgen(getter)]
+    pub fn contact(&self) -> Option<String> {
+        self.0.contact.clone()
+    }
+
+    /// Sets the contact of the federation operators.
+    #[wasm_bindgen(setter)]
+    pub fn set_contact(&mut self, contact: Option<String>) {
+        self.0.contact = contact;
+    }
+}
+
+/// Federation-wide defaults and limits for granting accreditations
+#[wasm_bindgen(js_name = GrantPolicy, inspectable)]
+#[derive(Deserialize, Serialize, Clone)]
+pub struct WasmGrantPolicy(pub(crate) GrantPolicy);
+
+impl From<GrantPolicy> for WasmGrantPolicy {
+    fn from(value: GrantPolicy) -> Self {
+        WasmGrantPolicy(value)
+    }
+}
+
+impl Default for WasmGrantPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen(js_class = GrantPolicy)]
+impl WasmGrantPolicy {
+    /// Creates a new `WasmGrantPolicy` that permits any grant.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        WasmGrantPolicy(GrantPolicy::default())
+    }
+
+    /// Retrieves the validity length applied to granted properties without an explicit end.
+    ///
+    /// # Returns
+    /// The validity length in milliseconds if set.
+    #[wasm_bindgen(getter, js_name = defaultValidityMs)]
+    pub fn default_validity_ms(&self) -> Option<u64> {
+        self.0.default_validity_ms
+    }
+
+    /// Sets the validity length applied to granted properties without an explicit end.
+    #[wasm_bindgen(setter, js_name = defaultValidityMs)]
+    pub fn set_default_validity_ms(&mut self, default_validity_ms: Option<u64>) {
+        self.0.default_validity_ms = default_validity_ms;
+    }
+
+    /// Checks whether granted properties may allow any value.
+    ///
+    /// # Returns
+    /// A boolean indicating if `allowAny` grants are permitted.
+    #[wasm_bindgen(getter, js_name = allowAnyPermitted)]
+    pub fn allow_any_permitted(&self) -> bool {
+        self.0.allow_any_permitted
+    }
+
+    /// Sets whether granted properties may allow any value.
+    #[wasm_bindgen(setter, js_name = allowAnyPermitted)]
+    pub fn set_allow_any_permitted(&mut self, allow_any_permitted: bool) {
+        self.0.allow_any_permitted = allow_any_permitted;
+    }
+
+    /// Retrieves the maximum number of allowed values of a granted property.
+    ///
+    /// # Returns
+    /// The maximum number of allowed values if set.
+    #[wasm_bindgen(getter, js_name = maxAllowedValues)]
+    pub fn max_allowed_values(&self) -> Option<u64> {
+        self.0.max_allowed_values
+    }
+
+    /// Sets the maximum number of allowed values of a granted property.
+    #[wasm_bindgen(setter, js_name = maxAllowedValues)]
+    pub fn set_max_allowed_values(&mut self, max_allowed_values: Option<u64>) {
+        self.0.max_allowed_values = max_allowed_values;
+    }
+
+    /// Retrieves the objection window of new accreditations in milliseconds.
+    ///
+    /// # Returns
+    /// The objection window if set.
+    #[wasm_bindgen(getter, js_name = objectionWindowMs)]
+    pub fn objection_window_ms(&self) -> Option<u64> {
+        self.0.objection_window_ms
+    }
+
+    /// Sets the objection window of new accreditations in milliseconds.
+    #[wasm_bindgen(setter, js_name = objectionWindowMs)]
+    pub fn set_objection_window_ms(&mut self, objection_window_ms: Option<u64>) {
+        self.0.objection_window_ms = objection_window_ms;
+    }
 }
 
 /// Represents a root authority. A root authority is an entity that has the highest level of authority in a federation
@@ -227,9 +518,17 @@ impl WasmProperty {
             shape: None,
             allow_any: false,
             timespan: Timespan::default(),
+            value_dictionary: None,
+            metadata: PropertyMetadata::default(),
         })
     }
 
+    /// Creates a property granting every property in the namespace `prefix`, e.g. `degree.*`.
+    #[wasm_bindgen(js_name=newPrefix)]
+    pub fn new_prefix(prefix: &WasmPropertyName) -> Self {
+        WasmProperty(FederationProperty::new_prefix(prefix.0.clone()))
+    }
+
     #[wasm_bindgen(js_name=withAllowedValues)]
     pub fn with_allowed_values(mut self, allowed_values: Vec<WasmPropertyValue>) -> Self {
         self.0.allowed_values = allowed_values.iter().cloned().map(|v| v.0).collect();
@@ -248,6 +547,12 @@ impl WasmProperty {
         self
     }
 
+    #[wasm_bindgen(js_name=withValueDictionary)]
+    pub fn with_value_dictionary(mut self, value_dictionary: String) -> Self {
+        self.0.value_dictionary = Some(value_dictionary);
+        self
+    }
+
     /// Retrieves the property name.
     ///
     /// # Returns
@@ -308,6 +613,21 @@ impl WasmProperty {
         self.0.allow_any = allow_any;
     }
 
+    /// Retrieves the name of the value dictionary this property references.
+    ///
+    /// # Returns
+    /// The dictionary name if present.
+    #[wasm_bindgen(getter, js_name = valueDictionary)]
+    pub fn value_dictionary(&self) -> Option<String> {
+        self.0.value_dictionary.clone()
+    }
+
+    /// Sets the name of the value dictionary this property references.
+    #[wasm_bindgen(setter, js_name = valueDictionary)]
+    pub fn set_value_dictionary(&mut self, value_dictionary: Option<String>) {
+        self.0.value_dictionary = value_dictionary;
+    }
+
     /// Retrieves the timespan for this property.
     ///
     /// # Returns
@@ -322,6 +642,220 @@ impl WasmProperty {
     pub fn set_timespan(&mut self, timespan: WasmTimespan) {
         self.0.timespan = timespan.0;
     }
+
+    /// Retrieves the human-readable schema information of this property.
+    ///
+    /// # Returns
+    /// The metadata set by root authorities, empty if none is set.
+    #[wasm_bindgen(getter)]
+    pub fn metadata(&self) -> WasmPropertyMetadata {
+        self.0.metadata.clone().into()
+    }
+}
+
+/// A chainable builder of a [`WasmProperty`].
+///
+/// Unlike plain objects, every field is checked by its TypeScript signature, so malformed
+/// properties are rejected at compile time instead of when the transaction is built.
+///
+/// # TypeScript Usage
+///
+/// ```typescript
+/// const property = new FederationPropertyBuilder(new PropertyName(["age"]))
+///   .withShapeGreaterThan(18n)
+///   .withTimespan(timespan)
+///   .build();
+/// ```
+#[wasm_bindgen(js_name = FederationPropertyBuilder, inspectable)]
+#[derive(Clone)]
+pub struct WasmFederationPropertyBuilder(pub(crate) FederationProperty);
+
+#[wasm_bindgen(js_class = FederationPropertyBuilder)]
+impl WasmFederationPropertyBuilder {
+    /// Starts building a property named `property_name` that allows no value.
+    ///
+    /// Fails for wildcard names, use `FederationProperty.newPrefix` to grant a namespace.
+    #[wasm_bindgen(constructor)]
+    pub fn new(property_name: &WasmPropertyName) -> Result<WasmFederationPropertyBuilder> {
+        if property_name.0.is_wildcard() {
+            return Err(anyhow!(
+                "wildcard property names must be created with `FederationProperty.newPrefix`"
+            ))
+            .wasm_result();
+        }
+        Ok(Self(FederationProperty::new(property_name.0.clone())))
+    }
+
+    /// Allows the given values.
+    #[wasm_bindgen(js_name = withAllowedValues)]
+    pub fn with_allowed_values(self, allowed_values: Vec<WasmPropertyValue>) -> Self {
+        Self(
+            self.0
+                .with_allowed_values(allowed_values.into_iter().map(|value| value.0)),
+        )
+    }
+
+    /// Allows the given text values.
+    #[wasm_bindgen(js_name = withAllowedTextValues)]
+    pub fn with_allowed_text_values(self, allowed_values: Vec<String>) -> Self {
+        Self(
+            self.0
+                .with_allowed_values(allowed_values.into_iter().map(PropertyValue::Text)),
+        )
+    }
+
+    /// Allows the given number values.
+    #[wasm_bindgen(js_name = withAllowedNumberValues)]
+    pub fn with_allowed_number_values(self, allowed_values: Vec<u64>) -> Self {
+        Self(
+            self.0
+                .with_allowed_values(allowed_values.into_iter().map(PropertyValue::Number)),
+        )
+    }
+
+    /// Allows the values matching `shape`.
+    #[wasm_bindgen(js_name = withShape)]
+    pub fn with_shape(self, shape: WasmPropertyShape) -> Self {
+        Self(self.0.with_expression(shape.0))
+    }
+
+    /// Allows the text values starting with `text`.
+    #[wasm_bindgen(js_name = withShapeStartsWith)]
+    pub fn with_shape_starts_with(self, text: String) -> Self {
+        Self(self.0.with_expression(PropertyShape::StartsWith(text)))
+    }
+
+    /// Allows the number values greater than `value`.
+    #[wasm_bindgen(js_name = withShapeGreaterThan)]
+    pub fn with_shape_greater_than(self, value: u64) -> Self {
+        Self(self.0.with_expression(PropertyShape::GreaterThan(value)))
+    }
+
+    /// Allows the number values lower than `value`.
+    #[wasm_bindgen(js_name = withShapeLowerThan)]
+    pub fn with_shape_lower_than(self, value: u64) -> Self {
+        Self(self.0.with_expression(PropertyShape::LowerThan(value)))
+    }
+
+    /// Allows the number values between `min` and `max`.
+    #[wasm_bindgen(js_name = withShapeBetween)]
+    pub fn with_shape_between(self, min: u64, max: u64) -> Self {
+        Self(self.0.with_expression(PropertyShape::Between(min, max)))
+    }
+
+    /// Allows any value.
+    #[wasm_bindgen(js_name = withAllowAny)]
+    pub fn with_allow_any(self, allow_any: bool) -> Self {
+        Self(self.0.with_allow_any(allow_any))
+    }
+
+    /// Restricts the validity of the property to `timespan`.
+    #[wasm_bindgen(js_name = withTimespan)]
+    pub fn with_timespan(self, timespan: &WasmTimespan) -> Self {
+        Self(self.0.with_timespan(timespan.0.clone()))
+    }
+
+    /// Allows the values of the federation value dictionary `value_dictionary`.
+    #[wasm_bindgen(js_name = withValueDictionary)]
+    pub fn with_value_dictionary(self, value_dictionary: String) -> Self {
+        Self(self.0.with_value_dictionary(value_dictionary))
+    }
+
+    /// Builds the property.
+    pub fn build(&self) -> WasmProperty {
+        WasmProperty(self.0.clone())
+    }
+}
+
+/// Human-readable schema information of a property, e.g. its display name or unit
+#[wasm_bindgen(js_name = PropertyMetadata, inspectable)]
+#[derive(Deserialize, Serialize, Clone, Default)]
+pub struct WasmPropertyMetadata(pub(crate) PropertyMetadata);
+
+impl From<PropertyMetadata> for WasmPropertyMetadata {
+    fn from(value: PropertyMetadata) -> Self {
+        WasmPropertyMetadata(value)
+    }
+}
+
+#[wasm_bindgen(js_class = PropertyMetadata)]
+impl WasmPropertyMetadata {
+    /// Creates empty property metadata.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the entry `key` to `value`.
+    #[wasm_bindgen(js_name = withEntry)]
+    pub fn with_entry(self, key: String, value: String) -> Self {
+        Self(self.0.with(key, value))
+    }
+
+    #[wasm_bindgen(js_name = withDisplayName)]
+    pub fn with_display_name(self, display_name: String) -> Self {
+        Self(self.0.with_display_name(display_name))
+    }
+
+    #[wasm_bindgen(js_name = withDescription)]
+    pub fn with_description(self, description: String) -> Self {
+        Self(self.0.with_description(description))
+    }
+
+    #[wasm_bindgen(js_name = withUnit)]
+    pub fn with_unit(self, unit: String) -> Self {
+        Self(self.0.with_unit(unit))
+    }
+
+    #[wasm_bindgen(js_name = withDataType)]
+    pub fn with_data_type(self, data_type: String) -> Self {
+        Self(self.0.with_data_type(data_type))
+    }
+
+    #[wasm_bindgen(js_name = withLocale)]
+    pub fn with_locale(self, locale: String) -> Self {
+        Self(self.0.with_locale(locale))
+    }
+
+    /// Retrieves the value of the entry `key`.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.0.get(key).map(str::to_owned)
+    }
+
+    /// Retrieves the keys of all entries.
+    pub fn keys(&self) -> Vec<String> {
+        self.0.entries().keys().cloned().collect()
+    }
+
+    /// Retrieves the name shown instead of the property name.
+    #[wasm_bindgen(getter, js_name = displayName)]
+    pub fn display_name(&self) -> Option<String> {
+        self.0.display_name().map(str::to_owned)
+    }
+
+    /// Retrieves the description of the property.
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.0.description().map(str::to_owned)
+    }
+
+    /// Retrieves the unit of the values.
+    #[wasm_bindgen(getter)]
+    pub fn unit(&self) -> Option<String> {
+        self.0.unit().map(str::to_owned)
+    }
+
+    /// Retrieves the hint on how to interpret the values.
+    #[wasm_bindgen(getter, js_name = dataType)]
+    pub fn data_type(&self) -> Option<String> {
+        self.0.data_type().map(str::to_owned)
+    }
+
+    /// Retrieves the locale of the texts.
+    #[wasm_bindgen(getter)]
+    pub fn locale(&self) -> Option<String> {
+        self.0.locale().map(str::to_owned)
+    }
 }
 
 /// Represents the time span of validity for a property
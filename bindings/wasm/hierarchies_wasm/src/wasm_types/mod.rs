@@ -3,15 +3,21 @@
 
 mod accreditation;
 mod accreditations;
+mod attestation;
 mod federation;
+mod preview;
 mod property_name;
 mod property_shape;
 mod property_value;
 pub mod transactions;
+mod validation_report;
 
 pub use accreditation::*;
 pub use accreditations::*;
+pub use attestation::*;
 pub use federation::*;
+pub use preview::*;
 pub use property_name::*;
 pub use property_shape::*;
 pub use property_value::*;
+pub use validation_report::*;
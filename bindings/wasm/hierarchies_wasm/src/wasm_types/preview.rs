@@ -0,0 +1,63 @@
+// Copyright 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use hierarchies::core::transactions::TransactionEffectsPreview;
+use wasm_bindgen::prelude::*;
+
+/// The changes a transaction would make to federations, as found by dry-running it.
+#[wasm_bindgen(js_name = TransactionEffectsPreview, inspectable)]
+#[derive(Clone, Debug)]
+pub struct WasmTransactionEffectsPreview(pub(crate) TransactionEffectsPreview);
+
+#[wasm_bindgen(js_class = TransactionEffectsPreview)]
+impl WasmTransactionEffectsPreview {
+    /// Returns true if the transaction would execute successfully.
+    #[wasm_bindgen(getter)]
+    pub fn succeeds(&self) -> bool {
+        self.0.succeeds()
+    }
+
+    /// Returns why the transaction would fail, if it would.
+    #[wasm_bindgen(getter)]
+    pub fn failure(&self) -> Option<String> {
+        self.0.estimate().failure().map(ToString::to_string)
+    }
+
+    /// Returns the changes as diff lines, e.g. `+ added property kyc.level`, in the order the
+    /// transaction would make them.
+    #[wasm_bindgen(getter)]
+    pub fn changes(&self) -> Vec<String> {
+        self.0.changes().iter().map(ToString::to_string).collect()
+    }
+
+    /// Returns the IDs of the federations the transaction would change, once each.
+    #[wasm_bindgen(getter, js_name = "federationIds")]
+    pub fn federation_ids(&self) -> Vec<String> {
+        let mut federation_ids: Vec<String> = Vec::new();
+        for change in self.0.changes() {
+            let federation_id = change.federation_id.to_string();
+            if !federation_ids.contains(&federation_id) {
+                federation_ids.push(federation_id);
+            }
+        }
+        federation_ids
+    }
+
+    /// Returns the expected gas usage in NANOS, including the storage rebate.
+    #[wasm_bindgen(getter, js_name = "netGasUsage")]
+    pub fn net_gas_usage(&self) -> Option<i64> {
+        self.0.estimate().net_gas_usage()
+    }
+
+    /// Returns the human-readable diff of the federations, grouped by federation.
+    #[wasm_bindgen(getter)]
+    pub fn diff(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<TransactionEffectsPreview> for WasmTransactionEffectsPreview {
+    fn from(value: TransactionEffectsPreview) -> Self {
+        WasmTransactionEffectsPreview(value)
+    }
+}
@@ -28,6 +28,12 @@ impl WasmPropertyName {
         self.0.names().iter().map(JsValue::from).collect()
     }
 
+    /// Returns true if the last name is the wildcard `*`, e.g. `degree.*`.
+    #[wasm_bindgen(js_name = isWildcard)]
+    pub fn is_wildcard(&self) -> bool {
+        self.0.is_wildcard()
+    }
+
     /// Returns the dotted representation of the property name.
     #[wasm_bindgen(js_name = dotted)]
     pub fn dotted(&self) -> String {
@@ -1,10 +1,14 @@
 // Copyright 2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use hierarchies::core::types::property_pattern::validate_pattern;
 use hierarchies::core::types::property_shape::PropertyShape;
+use iota_interaction_ts::wasm_error::{Result, WasmResult};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+use crate::wasm_types::WasmPropertyValue;
+
 #[wasm_bindgen(js_name = PropertyShape, inspectable)]
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WasmPropertyShape(pub(crate) PropertyShape);
@@ -41,6 +45,53 @@ impl WasmPropertyShape {
         Self(PropertyShape::LowerThan(value))
     }
 
+    /// Creates a new `PropertyShape` of type `Between`, with both bounds inclusive.
+    #[wasm_bindgen(js_name = newBetween)]
+    pub fn new_between(min: u64, max: u64) -> Self {
+        Self(PropertyShape::Between(min, max))
+    }
+
+    /// Creates a new `PropertyShape` of type `EqualTo`.
+    #[wasm_bindgen(js_name = newEqualTo)]
+    pub fn new_equal_to(value: WasmPropertyValue) -> Self {
+        Self(PropertyShape::EqualTo(value.0))
+    }
+
+    /// Creates a new `PropertyShape` of type `NotEqualTo`.
+    #[wasm_bindgen(js_name = newNotEqualTo)]
+    pub fn new_not_equal_to(value: WasmPropertyValue) -> Self {
+        Self(PropertyShape::NotEqualTo(value.0))
+    }
+
+    /// Creates a new `PropertyShape` of type `MatchesPattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not valid.
+    #[wasm_bindgen(js_name = newMatchesPattern)]
+    pub fn new_matches_pattern(pattern: String) -> Result<Self> {
+        validate_pattern(&pattern).map_err(anyhow::Error::from).wasm_result()?;
+        Ok(Self(PropertyShape::MatchesPattern(pattern)))
+    }
+
+    /// Creates a new `PropertyShape` of type `All`, matching if every shape matches.
+    #[wasm_bindgen(js_name = newAll)]
+    pub fn new_all(shapes: Vec<WasmPropertyShape>) -> Self {
+        Self(PropertyShape::All(shapes.into_iter().map(|shape| shape.0).collect()))
+    }
+
+    /// Creates a new `PropertyShape` of type `Any`, matching if at least one shape matches.
+    #[wasm_bindgen(js_name = newAny)]
+    pub fn new_any(shapes: Vec<WasmPropertyShape>) -> Self {
+        Self(PropertyShape::Any(shapes.into_iter().map(|shape| shape.0).collect()))
+    }
+
+    /// Creates a new `PropertyShape` of type `Not`, matching if the shape doesn't match.
+    #[wasm_bindgen(js_name = newNot)]
+    pub fn new_not(shape: WasmPropertyShape) -> Self {
+        Self(PropertyShape::Not(Box::new(shape.0)))
+    }
+
     /// Returns `true` if the `PropertyShape` is of type `StartsWith`.
     #[wasm_bindgen(js_name = isStartsWith)]
     pub fn is_starts_with(&self) -> bool {
@@ -71,6 +122,36 @@ impl WasmPropertyShape {
         matches!(self.0, PropertyShape::LowerThan(_))
     }
 
+    /// Returns `true` if the `PropertyShape` is of type `Between`.
+    #[wasm_bindgen(js_name = isBetween)]
+    pub fn is_between(&self) -> bool {
+        matches!(self.0, PropertyShape::Between(..))
+    }
+
+    /// Returns `true` if the `PropertyShape` is of type `EqualTo`.
+    #[wasm_bindgen(js_name = isEqualTo)]
+    pub fn is_equal_to(&self) -> bool {
+        matches!(self.0, PropertyShape::EqualTo(_))
+    }
+
+    /// Returns `true` if the `PropertyShape` is of type `NotEqualTo`.
+    #[wasm_bindgen(js_name = isNotEqualTo)]
+    pub fn is_not_equal_to(&self) -> bool {
+        matches!(self.0, PropertyShape::NotEqualTo(_))
+    }
+
+    /// Returns `true` if the `PropertyShape` is of type `MatchesPattern`.
+    #[wasm_bindgen(js_name = isMatchesPattern)]
+    pub fn is_matches_pattern(&self) -> bool {
+        matches!(self.0, PropertyShape::MatchesPattern(_))
+    }
+
+    /// Returns `true` if the `PropertyShape` combines other shapes.
+    #[wasm_bindgen(js_name = isComposite)]
+    pub fn is_composite(&self) -> bool {
+        self.0.is_composite()
+    }
+
     /// Returns the `String` value if the `PropertyShape` is of type `StartsWith`.
     #[wasm_bindgen(js_name = asStartsWith)]
     pub fn as_starts_with(&self) -> Option<String> {
@@ -120,6 +201,81 @@ impl WasmPropertyShape {
             None
         }
     }
+
+    /// Returns the `[min, max]` bounds if the `PropertyShape` is of type `Between`.
+    #[wasm_bindgen(js_name = asBetween)]
+    pub fn as_between(&self) -> Option<Vec<u64>> {
+        if let PropertyShape::Between(min, max) = self.0 {
+            Some(vec![min, max])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `PropertyValue` if the `PropertyShape` is of type `EqualTo`.
+    #[wasm_bindgen(js_name = asEqualTo)]
+    pub fn as_equal_to(&self) -> Option<WasmPropertyValue> {
+        if let PropertyShape::EqualTo(value) = &self.0 {
+            Some(WasmPropertyValue(value.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `PropertyValue` if the `PropertyShape` is of type `NotEqualTo`.
+    #[wasm_bindgen(js_name = asNotEqualTo)]
+    pub fn as_not_equal_to(&self) -> Option<WasmPropertyValue> {
+        if let PropertyShape::NotEqualTo(value) = &self.0 {
+            Some(WasmPropertyValue(value.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the pattern if the `PropertyShape` is of type `MatchesPattern`.
+    #[wasm_bindgen(js_name = asMatchesPattern)]
+    pub fn as_matches_pattern(&self) -> Option<String> {
+        if let PropertyShape::MatchesPattern(pattern) = &self.0 {
+            Some(pattern.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the combined shapes if the `PropertyShape` is of type `All`.
+    #[wasm_bindgen(js_name = asAll)]
+    pub fn as_all(&self) -> Option<Vec<WasmPropertyShape>> {
+        if let PropertyShape::All(shapes) = &self.0 {
+            Some(shapes.iter().cloned().map(WasmPropertyShape).collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the combined shapes if the `PropertyShape` is of type `Any`.
+    #[wasm_bindgen(js_name = asAny)]
+    pub fn as_any(&self) -> Option<Vec<WasmPropertyShape>> {
+        if let PropertyShape::Any(shapes) = &self.0 {
+            Some(shapes.iter().cloned().map(WasmPropertyShape).collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the negated shape if the `PropertyShape` is of type `Not`.
+    #[wasm_bindgen(js_name = asNot)]
+    pub fn as_not(&self) -> Option<WasmPropertyShape> {
+        if let PropertyShape::Not(shape) = &self.0 {
+            Some(WasmPropertyShape(shape.as_ref().clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `value` matches the shape.
+    pub fn matches(&self, value: &WasmPropertyValue) -> bool {
+        self.0.matches(&value.0)
+    }
 }
 
 impl From<PropertyShape> for WasmPropertyShape {
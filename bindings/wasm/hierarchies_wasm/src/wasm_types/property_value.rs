@@ -1,7 +1,7 @@
 // Copyright 2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::property_value::{PropertyValue, ValueSetMatch};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -33,6 +33,36 @@ impl WasmPropertyValue {
         Self(PropertyValue::Number(number))
     }
 
+    /// Creates a new `PropertyValue` of type `Bool`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The boolean value.
+    #[wasm_bindgen(js_name = newBool)]
+    pub fn new_bool(value: bool) -> Self {
+        Self(PropertyValue::Bool(value))
+    }
+
+    /// Creates a new `PropertyValue` of type `Timestamp`.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp_ms` - Milliseconds since the Unix epoch.
+    #[wasm_bindgen(js_name = newTimestamp)]
+    pub fn new_timestamp(timestamp_ms: u64) -> Self {
+        Self(PropertyValue::Timestamp(timestamp_ms))
+    }
+
+    /// Creates a new `PropertyValue` of type `Bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The byte value.
+    #[wasm_bindgen(js_name = newBytes)]
+    pub fn new_bytes(bytes: Vec<u8>) -> Self {
+        Self(PropertyValue::Bytes(bytes))
+    }
+
     /// Returns `true` if the `PropertyValue` is of type `Text`.
     #[wasm_bindgen(js_name = isText)]
     pub fn is_text(&self) -> bool {
@@ -45,6 +75,24 @@ impl WasmPropertyValue {
         matches!(self.0, PropertyValue::Number(_))
     }
 
+    /// Returns `true` if the `PropertyValue` is of type `Bool`.
+    #[wasm_bindgen(js_name = isBool)]
+    pub fn is_bool(&self) -> bool {
+        matches!(self.0, PropertyValue::Bool(_))
+    }
+
+    /// Returns `true` if the `PropertyValue` is of type `Timestamp`.
+    #[wasm_bindgen(js_name = isTimestamp)]
+    pub fn is_timestamp(&self) -> bool {
+        matches!(self.0, PropertyValue::Timestamp(_))
+    }
+
+    /// Returns `true` if the `PropertyValue` is of type `Bytes`.
+    #[wasm_bindgen(js_name = isBytes)]
+    pub fn is_bytes(&self) -> bool {
+        matches!(self.0, PropertyValue::Bytes(_))
+    }
+
     /// Returns the `String` value if the `PropertyValue` is of type `Text`.
     ///
     /// # Returns
@@ -72,6 +120,48 @@ impl WasmPropertyValue {
             None
         }
     }
+
+    /// Returns the `bool` value if the `PropertyValue` is of type `Bool`.
+    ///
+    /// # Returns
+    ///
+    /// The boolean value, or `undefined` if the type is not `Bool`.
+    #[wasm_bindgen(js_name = asBool)]
+    pub fn as_bool(&self) -> Option<bool> {
+        if let PropertyValue::Bool(value) = self.0 {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the milliseconds since the Unix epoch if the `PropertyValue` is of type `Timestamp`.
+    ///
+    /// # Returns
+    ///
+    /// The timestamp, or `undefined` if the type is not `Timestamp`.
+    #[wasm_bindgen(js_name = asTimestamp)]
+    pub fn as_timestamp(&self) -> Option<u64> {
+        if let PropertyValue::Timestamp(timestamp_ms) = self.0 {
+            Some(timestamp_ms)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bytes if the `PropertyValue` is of type `Bytes`.
+    ///
+    /// # Returns
+    ///
+    /// The bytes, or `undefined` if the type is not `Bytes`.
+    #[wasm_bindgen(js_name = asBytes)]
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        if let PropertyValue::Bytes(bytes) = &self.0 {
+            Some(bytes.clone())
+        } else {
+            None
+        }
+    }
 }
 
 impl From<PropertyValue> for WasmPropertyValue {
@@ -85,3 +175,22 @@ impl From<WasmPropertyValue> for PropertyValue {
         value.0
     }
 }
+
+/// Defines how a set of values attested for a single property is validated.
+#[wasm_bindgen(js_name = ValueSetMatch)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmValueSetMatch {
+    /// At least one of the attested values must be accredited.
+    Contains = 0,
+    /// Every attested value must be accredited.
+    Superset = 1,
+}
+
+impl From<WasmValueSetMatch> for ValueSetMatch {
+    fn from(value: WasmValueSetMatch) -> Self {
+        match value {
+            WasmValueSetMatch::Contains => ValueSetMatch::Contains,
+            WasmValueSetMatch::Superset => ValueSetMatch::Superset,
+        }
+    }
+}
@@ -1,27 +1,43 @@
 // Copyright 2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::anyhow;
 use hierarchies::core::transactions::properties::add_property::AddProperty;
+use hierarchies::core::transactions::properties::deprecate_property::DeprecateProperty;
+use hierarchies::core::transactions::properties::remove_value_dictionary::RemoveValueDictionary;
 use hierarchies::core::transactions::properties::revoke_property::RevokeProperty;
+use hierarchies::core::transactions::properties::set_property_critical::SetPropertyCritical;
+use hierarchies::core::transactions::properties::set_property_metadata::SetPropertyMetadata;
+use hierarchies::core::transactions::properties::set_property_tags::SetPropertyTags;
+use hierarchies::core::transactions::properties::set_value_dictionary::SetValueDictionary;
 use hierarchies::core::transactions::{
-    AddRootAuthority, CreateAccreditation as CreateAccreditationToAccredit, CreateAccreditationToAttest,
-    CreateFederation, ReinstateRootAuthority, RevokeAccreditationToAccredit, RevokeAccreditationToAttest,
-    RevokeRootAuthority,
+    ActivateAccreditation, AddRootAuthority, CreateAccreditation as CreateAccreditationToAccredit,
+    CreateAccreditationToAttest, CreateAttestation, CreateFederation, Destructive, LinkFederation,
+    ReinstateRootAuthority, RevokeAccreditationToAccredit, RevokeAccreditationToAttest, RevokeAttestation,
+    RevokeRootAuthority, RotateRootAuthority, SetAccreditationTags, SetFederationMetadata, SetGrantPolicy,
+    UnlinkFederation,
 };
+use hierarchies::core::types::property::FederationProperty;
 use iota_interaction_ts::bindings::{WasmIotaTransactionBlockEffects, WasmIotaTransactionBlockEvents};
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
-use iota_interaction_ts::wasm_error::{Result, wasm_error};
+use iota_interaction_ts::wasm_error::{Result, WasmResult, wasm_error};
 use product_common::bindings::utils::{
     apply_with_events, build_programmable_transaction, parse_wasm_iota_address, parse_wasm_object_id,
 };
 use product_common::bindings::{WasmIotaAddress, WasmObjectID};
+use wasm_bindgen::convert::TryFromJsValue;
 use wasm_bindgen::prelude::*;
 
-use crate::wasm_types::{WasmFederation, WasmProperty, WasmPropertyName};
+use crate::cancellation::{WasmCallOptions, cancellable};
+use crate::client_read_only::parse_property_map;
+use crate::wasm_types::{
+    WasmAttestation, WasmFederation, WasmFederationMetadata, WasmFederationPropertyBuilder, WasmGrantPolicy,
+    WasmProperty, WasmPropertyMetadata, WasmPropertyName, WasmPropertyValue, WasmTimespan,
+};
 
 /// A wrapper for the `CreateFederation` transaction.
 #[wasm_bindgen (js_name=CreateFederation, inspectable)]
-pub struct WasmCreateFederation(pub(crate) CreateFederation);
+pub struct WasmCreateFederation(pub(crate) CreateFederation, pub(crate) WasmCallOptions);
 
 impl Default for WasmCreateFederation {
     fn default() -> Self {
@@ -34,7 +50,7 @@ impl WasmCreateFederation {
     /// Creates a new instance of `WasmCreateFederation`.
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self(CreateFederation::new())
+        Self(CreateFederation::new(), WasmCallOptions::default())
     }
 
     /// Builds and returns a programmable transaction for creating a new federation.
@@ -52,7 +68,7 @@ impl WasmCreateFederation {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this create federation operation.
@@ -73,13 +89,14 @@ impl WasmCreateFederation {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<WasmFederation> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client).await
+        let Self(tx, options) = self;
+        cancellable(&options, apply_with_events(tx, wasm_effects, wasm_events, client)).await
     }
 }
 
 /// A wrapper for the `AddRootAuthority` transaction.
 #[wasm_bindgen(js_name = AddRootAuthority, inspectable)]
-pub struct WasmAddRootAuthority(pub(crate) AddRootAuthority);
+pub struct WasmAddRootAuthority(pub(crate) AddRootAuthority, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = AddRootAuthority)]
 impl WasmAddRootAuthority {
@@ -95,7 +112,10 @@ impl WasmAddRootAuthority {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let account_id = parse_wasm_object_id(&account_id)?;
         let signer_address = parse_wasm_iota_address(&signer_address)?;
-        Ok(Self(AddRootAuthority::new(federation_id, account_id, signer_address)))
+        Ok(Self(
+            AddRootAuthority::new(federation_id, account_id, signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for adding a root authority.
@@ -113,7 +133,7 @@ impl WasmAddRootAuthority {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this add root authority operation.
@@ -130,15 +150,19 @@ impl WasmAddRootAuthority {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `RevokeRootAuthority` transaction.
 #[wasm_bindgen(js_name = RevokeRootAuthority, inspectable)]
-pub struct WasmRevokeRootAuthority(pub(crate) RevokeRootAuthority);
+pub struct WasmRevokeRootAuthority(pub(crate) RevokeRootAuthority, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = RevokeRootAuthority)]
 impl WasmRevokeRootAuthority {
@@ -155,11 +179,19 @@ impl WasmRevokeRootAuthority {
         let account_id = parse_wasm_object_id(&account_id)?;
         let signer_address = parse_wasm_iota_address(&signer_address)?;
 
-        Ok(Self(RevokeRootAuthority::new(
-            federation_id,
-            account_id,
-            signer_address,
-        )))
+        Ok(Self(
+            RevokeRootAuthority::new(federation_id, account_id, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Confirms the revocation of the root authority, which takes effect immediately and can only
+    /// be reversed by reinstating the root authority explicitly.
+    ///
+    /// Building the transaction fails unless it has been confirmed.
+    #[wasm_bindgen(js_name = confirmIrreversible)]
+    pub fn confirm_irreversible(self) -> Self {
+        Self(self.0.confirm_irreversible(), self.1)
     }
 
     /// Builds and returns a programmable transaction for revoking a root authority.
@@ -177,7 +209,7 @@ impl WasmRevokeRootAuthority {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this revoke root authority operation.
@@ -194,15 +226,102 @@ impl WasmRevokeRootAuthority {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `RotateRootAuthority` transaction.
+#[wasm_bindgen(js_name = RotateRootAuthority, inspectable)]
+pub struct WasmRotateRootAuthority(pub(crate) RotateRootAuthority, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = RotateRootAuthority)]
+impl WasmRotateRootAuthority {
+    /// Creates a new instance of `WasmRotateRootAuthority`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `old_account_id` - The ID of the root authority to hand over.
+    /// * `new_account_id` - The ID of the account to hand the root authority over to.
+    /// * `signer_address` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        old_account_id: WasmObjectID,
+        new_account_id: WasmObjectID,
+        signer_address: WasmIotaAddress,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let old_account_id = parse_wasm_object_id(&old_account_id)?;
+        let new_account_id = parse_wasm_object_id(&new_account_id)?;
+        let signer_address = parse_wasm_iota_address(&signer_address)?;
+
+        Ok(Self(
+            RotateRootAuthority::new(federation_id, old_account_id, new_account_id, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Confirms the revocation of the old root authority, which takes effect immediately and can
+    /// only be reversed by reinstating the root authority explicitly.
+    ///
+    /// Building the transaction fails unless it has been confirmed.
+    #[wasm_bindgen(js_name = confirmIrreversible)]
+    pub fn confirm_irreversible(self) -> Self {
+        Self(self.0.confirm_irreversible(), self.1)
+    }
+
+    /// Builds and returns a programmable transaction for rotating a root authority.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this rotate root authority operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `ReinstateRootAuthority` transaction.
 #[wasm_bindgen(js_name = ReinstateRootAuthority, inspectable)]
-pub struct WasmReinstateRootAuthority(pub(crate) ReinstateRootAuthority);
+pub struct WasmReinstateRootAuthority(pub(crate) ReinstateRootAuthority, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = ReinstateRootAuthority)]
 impl WasmReinstateRootAuthority {
@@ -219,11 +338,10 @@ impl WasmReinstateRootAuthority {
         let account_id = parse_wasm_object_id(&account_id)?;
         let signer_address = parse_wasm_iota_address(&signer_address)?;
 
-        Ok(Self(ReinstateRootAuthority::new(
-            federation_id,
-            account_id,
-            signer_address,
-        )))
+        Ok(Self(
+            ReinstateRootAuthority::new(federation_id, account_id, signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for reinstating a root authority.
@@ -241,7 +359,7 @@ impl WasmReinstateRootAuthority {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this reinstate root authority operation.
@@ -258,15 +376,19 @@ impl WasmReinstateRootAuthority {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `AddProperty` transaction.
 #[wasm_bindgen(js_name = AddProperty, inspectable)]
-pub struct WasmAddProperty(pub(crate) AddProperty);
+pub struct WasmAddProperty(pub(crate) AddProperty, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = AddProperty)]
 impl WasmAddProperty {
@@ -282,11 +404,10 @@ impl WasmAddProperty {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let signer_address = parse_wasm_iota_address(&owner)?;
 
-        Ok(Self(AddProperty::new(
-            federation_id,
-            property.clone().into(),
-            signer_address,
-        )))
+        Ok(Self(
+            AddProperty::new(federation_id, property.clone().into(), signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for adding a property.
@@ -304,7 +425,7 @@ impl WasmAddProperty {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this add property operation.
@@ -321,15 +442,19 @@ impl WasmAddProperty {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `RevokeProperty` transaction.
 #[wasm_bindgen(js_name = RevokeProperty, inspectable)]
-pub struct WasmRevokeProperty(pub(crate) RevokeProperty);
+pub struct WasmRevokeProperty(pub(crate) RevokeProperty, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = RevokeProperty)]
 impl WasmRevokeProperty {
@@ -351,12 +476,18 @@ impl WasmRevokeProperty {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let property_name = property_name.into();
         let signer_address = parse_wasm_iota_address(&owner)?;
-        Ok(Self(RevokeProperty::new(
-            federation_id,
-            property_name,
-            valid_to_ms,
-            signer_address,
-        )))
+        Ok(Self(
+            RevokeProperty::new(federation_id, property_name, valid_to_ms, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Confirms an immediate revocation of the property, which cannot be undone.
+    ///
+    /// Building the transaction fails unless it has been confirmed.
+    #[wasm_bindgen(js_name = confirmIrreversible)]
+    pub fn confirm_irreversible(self) -> Self {
+        Self(self.0.confirm_irreversible(), self.1)
     }
 
     /// Builds and returns a programmable transaction for revoking a property.
@@ -374,7 +505,7 @@ impl WasmRevokeProperty {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this revoke property operation.
@@ -391,15 +522,19 @@ impl WasmRevokeProperty {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `CreateAccreditationToAttest` transaction.
 #[wasm_bindgen(js_name = CreateAccreditationToAttest, inspectable)]
-pub struct WasmCreateAccreditationToAttest(pub(crate) CreateAccreditationToAttest);
+pub struct WasmCreateAccreditationToAttest(pub(crate) CreateAccreditationToAttest, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = CreateAccreditationToAttest)]
 impl WasmCreateAccreditationToAttest {
@@ -409,28 +544,29 @@ impl WasmCreateAccreditationToAttest {
     ///
     /// * `federation_id` - The ID of the federation.
     /// * `receiver` - The ID of the receiver of the accreditation.
-    /// * `want_properties` - The properties for which permissions are being granted.
+    /// * `want_properties` - The properties for which permissions are being granted, as `FederationProperty` or
+    ///   `FederationPropertyBuilder` instances. Plain objects are still accepted at runtime but are deprecated.
     /// * `owner` - The address of the transaction signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the index and field of the first malformed property.
     #[wasm_bindgen(constructor)]
     pub fn new(
         federation_id: WasmObjectID,
         receiver: WasmObjectID,
+        #[wasm_bindgen(unchecked_param_type = "Array<FederationProperty | FederationPropertyBuilder>")]
         want_properties: js_sys::Array,
         owner: WasmIotaAddress,
     ) -> Result<Self> {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let receiver = parse_wasm_object_id(&receiver)?;
-        let want_properties = want_properties
-            .iter()
-            .map(|v| serde_wasm_bindgen::from_value::<WasmProperty>(v).map_err(wasm_error))
-            .collect::<Result<Vec<_>>>()?;
+        let want_properties = parse_want_properties(&want_properties)?;
         let signer_address = parse_wasm_iota_address(&owner)?;
-        Ok(Self(CreateAccreditationToAttest::new(
-            federation_id,
-            receiver,
-            want_properties.into_iter().map(|s| s.into()),
-            signer_address,
-        )))
+        Ok(Self(
+            CreateAccreditationToAttest::new(federation_id, receiver, want_properties, signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for creating an accreditation to accredit.
@@ -448,7 +584,7 @@ impl WasmCreateAccreditationToAttest {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this create accreditation to accredit operation.
@@ -465,15 +601,19 @@ impl WasmCreateAccreditationToAttest {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `RevokeAccreditationToAccredit` transaction.
 #[wasm_bindgen(js_name = RevokeAccreditationToAttest, inspectable)]
-pub struct WasmRevokeAccreditationToAttest(pub(crate) RevokeAccreditationToAttest);
+pub struct WasmRevokeAccreditationToAttest(pub(crate) RevokeAccreditationToAttest, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = RevokeAccreditationToAttest)]
 impl WasmRevokeAccreditationToAttest {
@@ -496,12 +636,10 @@ impl WasmRevokeAccreditationToAttest {
         let entity_id = parse_wasm_object_id(&entity_id)?;
         let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
         let signer_address = parse_wasm_iota_address(&owner)?;
-        Ok(Self(RevokeAccreditationToAttest::new(
-            federation_id,
-            entity_id,
-            accreditation_id,
-            signer_address,
-        )))
+        Ok(Self(
+            RevokeAccreditationToAttest::new(federation_id, entity_id, accreditation_id, signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for revoking an accreditation to attest.
@@ -519,7 +657,7 @@ impl WasmRevokeAccreditationToAttest {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this revoke accreditation to attest operation.
@@ -536,15 +674,19 @@ impl WasmRevokeAccreditationToAttest {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `CreateAccreditationToAccredit` transaction.
 #[wasm_bindgen(js_name = CreateAccreditationToAccredit, inspectable)]
-pub struct WasmCreateAccreditationToAccredit(pub(crate) CreateAccreditationToAccredit);
+pub struct WasmCreateAccreditationToAccredit(pub(crate) CreateAccreditationToAccredit, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = CreateAccreditationToAccredit)]
 impl WasmCreateAccreditationToAccredit {
@@ -554,28 +696,29 @@ impl WasmCreateAccreditationToAccredit {
     ///
     /// * `federation_id` - The ID of the federation.
     /// * `receiver_id` - The ID of the receiver of the accreditation.
-    /// * `want_properties` - The properties for which permissions are being granted.
+    /// * `want_properties` - The properties for which permissions are being granted, as `FederationProperty` or
+    ///   `FederationPropertyBuilder` instances. Plain objects are still accepted at runtime but are deprecated.
     /// * `owner` - The address of the transaction signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the index and field of the first malformed property.
     #[wasm_bindgen(constructor)]
     pub fn new(
         federation_id: WasmObjectID,
         receiver_id: WasmObjectID,
+        #[wasm_bindgen(unchecked_param_type = "Array<FederationProperty | FederationPropertyBuilder>")]
         want_properties: js_sys::Array,
         owner: WasmIotaAddress,
     ) -> Result<Self> {
         let federation_id = parse_wasm_object_id(&federation_id)?;
         let receiver_id = parse_wasm_object_id(&receiver_id)?;
-        let want_properties = want_properties
-            .iter()
-            .map(|v| serde_wasm_bindgen::from_value::<WasmProperty>(v).map_err(wasm_error))
-            .collect::<Result<Vec<_>>>()?;
+        let want_properties = parse_want_properties(&want_properties)?;
         let signer_address = parse_wasm_iota_address(&owner)?;
-        Ok(Self(CreateAccreditationToAccredit::new(
-            federation_id,
-            receiver_id,
-            want_properties.into_iter().map(|s| s.into()).collect(),
-            signer_address,
-        )))
+        Ok(Self(
+            CreateAccreditationToAccredit::new(federation_id, receiver_id, want_properties, signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for creating an accreditation to accredit.
@@ -593,7 +736,7 @@ impl WasmCreateAccreditationToAccredit {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this create accreditation to accredit operation.
@@ -610,15 +753,19 @@ impl WasmCreateAccreditationToAccredit {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
 
 /// A wrapper for the `RevokeAccreditationToAccredit` transaction.
 #[wasm_bindgen(js_name = RevokeAccreditationToAccredit, inspectable)]
-pub struct WasmRevokeAccreditationToAccredit(pub(crate) RevokeAccreditationToAccredit);
+pub struct WasmRevokeAccreditationToAccredit(pub(crate) RevokeAccreditationToAccredit, pub(crate) WasmCallOptions);
 
 #[wasm_bindgen(js_class = RevokeAccreditationToAccredit)]
 impl WasmRevokeAccreditationToAccredit {
@@ -641,12 +788,10 @@ impl WasmRevokeAccreditationToAccredit {
         let entity_id = parse_wasm_object_id(&entity_id)?;
         let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
         let signer_address = parse_wasm_iota_address(&owner)?;
-        Ok(Self(RevokeAccreditationToAccredit::new(
-            federation_id,
-            entity_id,
-            accreditation_id,
-            signer_address,
-        )))
+        Ok(Self(
+            RevokeAccreditationToAccredit::new(federation_id, entity_id, accreditation_id, signer_address),
+            WasmCallOptions::default(),
+        ))
     }
 
     /// Builds and returns a programmable transaction for revoking an accreditation to accredit.
@@ -664,7 +809,7 @@ impl WasmRevokeAccreditationToAccredit {
     /// Returns an error if the transaction cannot be built.
     #[wasm_bindgen(js_name = buildProgrammableTransaction)]
     pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-        build_programmable_transaction(&self.0, client).await
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
     }
 
     /// Applies transaction effects and events to this revoke accreditation to accredit operation.
@@ -681,8 +826,1066 @@ impl WasmRevokeAccreditationToAccredit {
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
     ) -> Result<()> {
-        apply_with_events(self.0, wasm_effects, wasm_events, client)
-            .await
-            .map_err(wasm_error)
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `SetPropertyCritical` transaction.
+#[wasm_bindgen(js_name = SetPropertyCritical, inspectable)]
+pub struct WasmSetPropertyCritical(pub(crate) SetPropertyCritical, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetPropertyCritical)]
+impl WasmSetPropertyCritical {
+    /// Creates a new instance of `WasmSetPropertyCritical`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `property_name` - The name of the property.
+    /// * `critical` - Whether the property is critical.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        critical: bool,
+        owner: WasmIotaAddress,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            SetPropertyCritical::new(federation_id, property_name.into(), critical, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for changing the criticality of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set property critical operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `DeprecateProperty` transaction.
+#[wasm_bindgen(js_name = DeprecateProperty, inspectable)]
+pub struct WasmDeprecateProperty(pub(crate) DeprecateProperty, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = DeprecateProperty)]
+impl WasmDeprecateProperty {
+    /// Creates a new instance of `WasmDeprecateProperty`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `property_name` - The name of the property to deprecate.
+    /// * `replacement` - The name of the property replacing it, if any.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        replacement: Option<WasmPropertyName>,
+        owner: WasmIotaAddress,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            DeprecateProperty::new(
+                federation_id,
+                property_name.into(),
+                replacement.map(Into::into),
+                signer_address,
+            ),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for deprecating a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this deprecate property operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `SetPropertyTags` transaction.
+#[wasm_bindgen(js_name = SetPropertyTags, inspectable)]
+pub struct WasmSetPropertyTags(pub(crate) SetPropertyTags, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetPropertyTags)]
+impl WasmSetPropertyTags {
+    /// Creates a new instance of `WasmSetPropertyTags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `property_name` - The name of the property.
+    /// * `tags` - The new tags of the property, an empty array removes all tags.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        tags: Vec<String>,
+        owner: WasmIotaAddress,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            SetPropertyTags::new(
+                federation_id,
+                property_name.into(),
+                tags.into_iter().collect(),
+                signer_address,
+            ),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for replacing the tags of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set property tags operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `SetPropertyMetadata` transaction.
+#[wasm_bindgen(js_name = SetPropertyMetadata, inspectable)]
+pub struct WasmSetPropertyMetadata(pub(crate) SetPropertyMetadata, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetPropertyMetadata)]
+impl WasmSetPropertyMetadata {
+    /// Creates a new instance of `WasmSetPropertyMetadata`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `property_name` - The name of the property.
+    /// * `metadata` - The new metadata of the property, empty metadata removes all entries.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        property_name: WasmPropertyName,
+        metadata: &WasmPropertyMetadata,
+        owner: WasmIotaAddress,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            SetPropertyMetadata::new(federation_id, property_name.into(), metadata.0.clone(), signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for replacing the metadata of a property.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set property metadata operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
     }
 }
+
+/// A wrapper for the `SetAccreditationTags` transaction.
+#[wasm_bindgen(js_name = SetAccreditationTags, inspectable)]
+pub struct WasmSetAccreditationTags(pub(crate) SetAccreditationTags, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetAccreditationTags)]
+impl WasmSetAccreditationTags {
+    /// Creates a new instance of `WasmSetAccreditationTags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `accreditation_id` - The ID of the accreditation.
+    /// * `tags` - The new tags of the accreditation, an empty array removes all tags.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, accreditation_id: WasmObjectID, tags: Vec<String>) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
+        Ok(Self(
+            SetAccreditationTags::new(federation_id, accreditation_id, tags.into_iter().collect()),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for replacing the tags of an accreditation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set accreditation tags operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `SetValueDictionary` transaction.
+#[wasm_bindgen(js_name = SetValueDictionary, inspectable)]
+pub struct WasmSetValueDictionary(pub(crate) SetValueDictionary, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetValueDictionary)]
+impl WasmSetValueDictionary {
+    /// Creates a new instance of `WasmSetValueDictionary`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `name` - The name of the value dictionary.
+    /// * `values` - The values of the dictionary.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        name: String,
+        values: Vec<WasmPropertyValue>,
+        owner: WasmIotaAddress,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            SetValueDictionary::new(
+                federation_id,
+                name,
+                values.into_iter().map(|value| value.0).collect(),
+                signer_address,
+            ),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for setting a value dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set value dictionary operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `RemoveValueDictionary` transaction.
+#[wasm_bindgen(js_name = RemoveValueDictionary, inspectable)]
+pub struct WasmRemoveValueDictionary(pub(crate) RemoveValueDictionary, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = RemoveValueDictionary)]
+impl WasmRemoveValueDictionary {
+    /// Creates a new instance of `WasmRemoveValueDictionary`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `name` - The name of the value dictionary.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, name: String, owner: WasmIotaAddress) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            RemoveValueDictionary::new(federation_id, name, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for removing a value dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this remove value dictionary operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `SetFederationMetadata` transaction.
+#[wasm_bindgen(js_name = SetFederationMetadata, inspectable)]
+pub struct WasmSetFederationMetadata(pub(crate) SetFederationMetadata, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetFederationMetadata)]
+impl WasmSetFederationMetadata {
+    /// Creates a new instance of `WasmSetFederationMetadata`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `metadata` - The human-readable description of the federation.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, metadata: &WasmFederationMetadata, owner: WasmIotaAddress) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            SetFederationMetadata::new(federation_id, metadata.0.clone(), signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for setting the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set federation metadata operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `LinkFederation` transaction.
+#[wasm_bindgen(js_name = LinkFederation, inspectable)]
+pub struct WasmLinkFederation(pub(crate) LinkFederation, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = LinkFederation)]
+impl WasmLinkFederation {
+    /// Creates a new instance of `WasmLinkFederation`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the parent federation.
+    /// * `child_federation_id` - The ID of the child federation.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, child_federation_id: WasmObjectID, owner: WasmIotaAddress) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let child_federation_id = parse_wasm_object_id(&child_federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            LinkFederation::new(federation_id, child_federation_id, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for linking a child federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this link federation operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `UnlinkFederation` transaction.
+#[wasm_bindgen(js_name = UnlinkFederation, inspectable)]
+pub struct WasmUnlinkFederation(pub(crate) UnlinkFederation, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = UnlinkFederation)]
+impl WasmUnlinkFederation {
+    /// Creates a new instance of `WasmUnlinkFederation`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the parent federation.
+    /// * `child_federation_id` - The ID of the child federation.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, child_federation_id: WasmObjectID, owner: WasmIotaAddress) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let child_federation_id = parse_wasm_object_id(&child_federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            UnlinkFederation::new(federation_id, child_federation_id, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for unlinking a child federation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this unlink federation operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `SetGrantPolicy` transaction.
+#[wasm_bindgen(js_name = SetGrantPolicy, inspectable)]
+pub struct WasmSetGrantPolicy(pub(crate) SetGrantPolicy, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = SetGrantPolicy)]
+impl WasmSetGrantPolicy {
+    /// Creates a new instance of `WasmSetGrantPolicy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `policy` - The defaults and limits applied to newly granted accreditations.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, policy: &WasmGrantPolicy, owner: WasmIotaAddress) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            SetGrantPolicy::new(federation_id, policy.0.clone(), signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for setting the grant policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this set grant policy operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `ActivateAccreditation` transaction.
+#[wasm_bindgen(js_name = ActivateAccreditation, inspectable)]
+pub struct WasmActivateAccreditation(pub(crate) ActivateAccreditation, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = ActivateAccreditation)]
+impl WasmActivateAccreditation {
+    /// Creates a new instance of `WasmActivateAccreditation`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `accreditation_id` - The ID of the accreditation to activate.
+    /// * `owner` - The address of the transaction signer.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, accreditation_id: WasmObjectID, owner: WasmIotaAddress) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let accreditation_id = parse_wasm_object_id(&accreditation_id)?;
+        let signer_address = parse_wasm_iota_address(&owner)?;
+        Ok(Self(
+            ActivateAccreditation::new(federation_id, accreditation_id, signer_address),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for activating the accreditation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this activate accreditation operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A wrapper for the `CreateAttestation` transaction.
+#[wasm_bindgen(js_name = CreateAttestation, inspectable)]
+pub struct WasmCreateAttestation(pub(crate) CreateAttestation, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = CreateAttestation)]
+impl WasmCreateAttestation {
+    /// Creates a new instance of `WasmCreateAttestation`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `receiver` - The address receiving the attestation.
+    /// * `properties` - A map from `PropertyName` to the attested `PropertyValue`.
+    /// * `timespan` - The validity window of the attestation, valid right away and until revoked if omitted.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        federation_id: WasmObjectID,
+        receiver: WasmIotaAddress,
+        properties: js_sys::Map,
+        timespan: Option<WasmTimespan>,
+    ) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let receiver = parse_wasm_iota_address(&receiver)?;
+        let properties = parse_property_map(&properties)?;
+        let timespan = timespan.map(|timespan| timespan.0).unwrap_or_default();
+
+        Ok(Self(
+            CreateAttestation::new(federation_id, receiver, properties, timespan),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for issuing an attestation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this create attestation operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// A `WasmAttestation` object representing the issued attestation.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<WasmAttestation> {
+        let Self(tx, options) = self;
+        cancellable(&options, apply_with_events(tx, wasm_effects, wasm_events, client)).await
+    }
+}
+
+/// A wrapper for the `RevokeAttestation` transaction.
+#[wasm_bindgen(js_name = RevokeAttestation, inspectable)]
+pub struct WasmRevokeAttestation(pub(crate) RevokeAttestation, pub(crate) WasmCallOptions);
+
+#[wasm_bindgen(js_class = RevokeAttestation)]
+impl WasmRevokeAttestation {
+    /// Creates a new instance of `WasmRevokeAttestation`.
+    ///
+    /// # Arguments
+    ///
+    /// * `federation_id` - The ID of the federation.
+    /// * `attestation_id` - The ID of the attestation to revoke.
+    #[wasm_bindgen(constructor)]
+    pub fn new(federation_id: WasmObjectID, attestation_id: WasmObjectID) -> Result<Self> {
+        let federation_id = parse_wasm_object_id(&federation_id)?;
+        let attestation_id = parse_wasm_object_id(&attestation_id)?;
+
+        Ok(Self(
+            RevokeAttestation::new(federation_id, attestation_id),
+            WasmCallOptions::default(),
+        ))
+    }
+
+    /// Builds and returns a programmable transaction for revoking an attestation.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A read-only client for blockchain interaction.
+    ///
+    /// # Returns
+    ///
+    /// The binary BCS serialization of the programmable transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be built.
+    #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+    pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+        cancellable(&self.1, build_programmable_transaction(&self.0, client)).await
+    }
+
+    /// Applies transaction effects and events to this revoke attestation operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `effects` - The transaction block effects to apply.
+    /// * `events` - The transaction block events to apply.
+    /// * `client` - A read-only client for blockchain interaction.
+    #[wasm_bindgen(js_name = applyWithEvents)]
+    pub async fn apply_with_events(
+        self,
+        wasm_effects: &WasmIotaTransactionBlockEffects,
+        wasm_events: &WasmIotaTransactionBlockEvents,
+        client: &WasmCoreClientReadOnly,
+    ) -> Result<()> {
+        let Self(tx, options) = self;
+        cancellable(&options, async {
+            apply_with_events(tx, wasm_effects, wasm_events, client)
+                .await
+                .map_err(wasm_error)
+        })
+        .await
+    }
+}
+
+/// A valid property in the serde layout, used to check the fields of a malformed one in isolation.
+const PROPERTY_TEMPLATE: &str = r#"{
+    "name": { "names": [""] },
+    "allowed_values": { "contents": [] },
+    "shape": null,
+    "allow_any": false,
+    "timespan": { "valid_from_ms": null, "valid_until_ms": null },
+    "value_dictionary": null
+}"#;
+
+/// Fields of a property, in declaration order.
+const PROPERTY_FIELDS: [&str; 6] = [
+    "name",
+    "allowed_values",
+    "shape",
+    "allow_any",
+    "timespan",
+    "value_dictionary",
+];
+
+/// Fields of a property that may not be omitted.
+const REQUIRED_PROPERTY_FIELDS: [&str; 4] = ["name", "allowed_values", "allow_any", "timespan"];
+
+/// Parses the properties passed to the accreditation constructors.
+///
+/// Elements may either be `FederationProperty` instances, which are consumed like any other
+/// instance passed by value, `FederationPropertyBuilder` instances, which are built, or plain
+/// objects in the serde layout of [`FederationProperty`].
+fn parse_want_properties(want_properties: &js_sys::Array) -> Result<Vec<FederationProperty>> {
+    want_properties
+        .iter()
+        .enumerate()
+        .map(|(index, value)| parse_want_property(index, value))
+        .collect()
+}
+
+fn parse_want_property(index: usize, value: JsValue) -> Result<FederationProperty> {
+    if let Ok(property) = WasmProperty::try_from_js_value(value.clone()) {
+        return Ok(property.0);
+    }
+    if let Ok(builder) = WasmFederationPropertyBuilder::try_from_js_value(value.clone()) {
+        return Ok(builder.0);
+    }
+
+    if !value.is_object() || js_sys::Array::is_array(&value) {
+        return Err(anyhow!(
+            "want_properties[{index}]: expected a FederationProperty, a FederationPropertyBuilder or a plain object"
+        ))
+        .wasm_result();
+    }
+
+    if let Some(field) = REQUIRED_PROPERTY_FIELDS
+        .into_iter()
+        .find(|field| !js_sys::Reflect::has(&value, &JsValue::from_str(field)).unwrap_or(false))
+    {
+        return Err(anyhow!("want_properties[{index}].{field}: missing field")).wasm_result();
+    }
+
+    serde_wasm_bindgen::from_value::<FederationProperty>(value.clone())
+        .map_err(|err| {
+            match PROPERTY_FIELDS
+                .into_iter()
+                .find(|field| !is_valid_property_field(&value, field))
+            {
+                Some(field) => anyhow!("want_properties[{index}].{field}: {err}"),
+                None => anyhow!("want_properties[{index}]: {err}"),
+            }
+        })
+        .wasm_result()
+}
+
+/// Checks a single field of `value` by decoding it on top of [`PROPERTY_TEMPLATE`].
+fn is_valid_property_field(value: &JsValue, field: &str) -> bool {
+    let Ok(template) = js_sys::JSON::parse(PROPERTY_TEMPLATE) else {
+        return true;
+    };
+    let key = JsValue::from_str(field);
+    let Ok(field_value) = js_sys::Reflect::get(value, &key) else {
+        return false;
+    };
+    if field_value.is_undefined() {
+        return true;
+    }
+
+    js_sys::Reflect::set(&template, &key, &field_value).unwrap_or(false)
+        && serde_wasm_bindgen::from_value::<FederationProperty>(template).is_ok()
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use hierarchies::client::ValidationConfig;
+use hierarchies::core::offline::ValidationReport;
+use product_common::bindings::WasmObjectID;
+use wasm_bindgen::prelude::*;
+
+/// Explains the outcome of a validation, naming the rule every failed property broke.
+#[wasm_bindgen(js_name = ValidationReport, inspectable)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmValidationReport(pub(crate) ValidationReport);
+
+#[wasm_bindgen(js_class = ValidationReport)]
+impl WasmValidationReport {
+    /// Returns true if all properties passed the validation.
+    #[wasm_bindgen(getter, js_name = "isValid")]
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// Returns the identifier of the validated attester.
+    #[wasm_bindgen(getter, js_name = "attesterId")]
+    pub fn attester_id(&self) -> WasmObjectID {
+        self.0.attester_id.to_string()
+    }
+
+    /// Returns the time the rules were evaluated at in milliseconds.
+    #[wasm_bindgen(getter, js_name = "evaluatedAtMs")]
+    pub fn evaluated_at_ms(&self) -> u64 {
+        self.0.evaluated_at_ms
+    }
+
+    /// Returns the failed properties as a map from dotted property names to the reason of the failure.
+    #[wasm_bindgen(getter)]
+    pub fn failures(&self) -> js_sys::Map {
+        let map = js_sys::Map::new();
+        for check in self.0.failures() {
+            if let Some(failure) = &check.failure {
+                map.set(
+                    &JsValue::from_str(&check.property_name.names().join(".")),
+                    &JsValue::from_str(&failure.to_string()),
+                );
+            }
+        }
+        map
+    }
+
+    /// Returns the properties with a warning, e.g. deprecated ones, as a map from dotted property
+    /// names to the warning.
+    #[wasm_bindgen(getter)]
+    pub fn warnings(&self) -> js_sys::Map {
+        let map = js_sys::Map::new();
+        for check in self.0.warnings() {
+            if let Some(warning) = &check.warning {
+                map.set(
+                    &JsValue::from_str(&check.property_name.names().join(".")),
+                    &JsValue::from_str(&warning.to_string()),
+                );
+            }
+        }
+        map
+    }
+}
+
+impl From<ValidationReport> for WasmValidationReport {
+    fn from(value: ValidationReport) -> Self {
+        WasmValidationReport(value)
+    }
+}
+
+/// The tolerance of point-in-time validations evaluated by the client, e.g. `validatePropertyAt`.
+///
+/// Timespans are widened by the clock skew tolerance at both ends, so attestations that became
+/// valid just now on the issuer's clock don't fail spuriously.
+#[wasm_bindgen(js_name = ValidationConfig, inspectable)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WasmValidationConfig(pub(crate) ValidationConfig);
+
+#[wasm_bindgen(js_class = ValidationConfig)]
+impl WasmValidationConfig {
+    /// Creates a configuration tolerating a clock skew of `clockSkewToleranceMs` milliseconds.
+    #[wasm_bindgen(constructor)]
+    pub fn new(clock_skew_tolerance_ms: Option<u64>) -> Self {
+        let tolerance = Duration::from_millis(clock_skew_tolerance_ms.unwrap_or_default());
+        WasmValidationConfig(ValidationConfig::default().with_clock_skew_tolerance(tolerance))
+    }
+
+    /// Returns the tolerated clock skew in milliseconds.
+    #[wasm_bindgen(getter, js_name = "clockSkewToleranceMs")]
+    pub fn clock_skew_tolerance_ms(&self) -> u64 {
+        u64::try_from(self.0.clock_skew_tolerance.as_millis()).unwrap_or(u64::MAX)
+    }
+}
+
+impl From<ValidationConfig> for WasmValidationConfig {
+    fn from(value: ValidationConfig) -> Self {
+        WasmValidationConfig(value)
+    }
+}
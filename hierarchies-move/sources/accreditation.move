@@ -66,9 +66,10 @@ public(package) fun is_property_allowed(
 
     while (idx_properties_to_attest < len_properties_to_attest) {
         let accreditation = &self.accreditations[idx_properties_to_attest];
-        let maybe_property = accreditation.properties.try_get(property_name);
+        let maybe_property = accreditation.find_property(property_name);
 
-        if (maybe_property.is_none()) {
+        if (maybe_property.is_none() || accreditation.is_pending(current_time_ms)) {
+            idx_properties_to_attest = idx_properties_to_attest + 1;
             continue
         };
         if (
@@ -83,6 +84,56 @@ public(package) fun is_property_allowed(
     return false
 }
 
+/// Check if any of the accredited properties covers the property name and is valid at the given time.
+public(package) fun is_property_accredited(
+    self: &Accreditations,
+    property_name: &PropertyName,
+    current_time_ms: u64,
+): bool {
+    let mut idx = 0;
+    while (idx < self.accreditations.length()) {
+        let accreditation = &self.accreditations[idx];
+        let maybe_property = accreditation.find_property(property_name);
+        if (
+            maybe_property.is_some() && !accreditation.is_pending(current_time_ms) &&
+                maybe_property.borrow().is_valid_at_time(current_time_ms)
+        ) {
+            return true
+        };
+        idx = idx + 1;
+    };
+    false
+}
+
+/// Check if a set of values for a single property is allowed by the accredited properties.
+/// With `require_all` every value has to be allowed (the accredited values are a superset of the given set),
+/// otherwise at least one of the values has to be allowed (the given set contains an allowed value).
+/// An empty set of values is never allowed.
+public(package) fun are_property_values_allowed(
+    self: &Accreditations,
+    property_name: &PropertyName,
+    property_values: &vector<PropertyValue>,
+    require_all: bool,
+    current_time_ms: u64,
+): bool {
+    if (property_values.is_empty()) {
+        return false
+    };
+
+    let mut idx = 0;
+    while (idx < property_values.length()) {
+        let allowed = self.is_property_allowed(property_name, &property_values[idx], current_time_ms);
+        if (require_all && !allowed) {
+            return false
+        };
+        if (!require_all && allowed) {
+            return true
+        };
+        idx = idx + 1;
+    };
+    require_all
+}
+
 /// Check the compliance of the properties. The compliance is met if all set of properties names and values is at most the set of accredited properties.
 public(package) fun are_properties_compliant(
     self: &Accreditations,
@@ -101,6 +152,7 @@ public(package) fun are_properties_compliant(
 }
 
 /// Check the compliance of the property. The compliance is met if all set of property names and values is at most the set of accredited properties.
+/// The rules are specified in `hierarchies-rs/hierarchies/docs/delegation-semantics.md`.
 public(package) fun is_property_compliant(
     self: &Accreditations,
     property: &FederationProperty,
@@ -111,12 +163,14 @@ public(package) fun is_property_compliant(
     let mut want_properties: vector<PropertyValue> = utils::copy_vector(property
         .allowed_values()
         .keys());
+    // A reference to a value dictionary grants all of its values
+    let mut want_dictionary = property.value_dictionary().is_some();
 
     while (idx_accreditations < len_accreditations) {
         let accreditation = &self.accreditations[idx_accreditations];
 
-        let value_condition = accreditation.properties.try_get(property.property_name());
-        if (value_condition.is_none()) {
+        let value_condition = accreditation.find_property(property.property_name());
+        if (value_condition.is_none() || accreditation.is_pending(current_time_ms)) {
             idx_accreditations = idx_accreditations + 1;
             continue
         };
@@ -129,6 +183,14 @@ public(package) fun is_property_compliant(
             continue
         };
 
+        if (
+            want_dictionary && value_condition
+                .borrow()
+                .covers_value_dictionary(property.value_dictionary().borrow(), current_time_ms)
+        ) {
+            want_dictionary = false;
+        };
+
         // Check each required value against the accredited accreditation
         let mut len_want_properties = want_properties.length();
         let mut idx_want_properties = 0;
@@ -147,7 +209,7 @@ public(package) fun is_property_compliant(
     };
 
     // All wanted properties have been accredited
-    if (want_properties.length() == 0) {
+    if (want_properties.length() == 0 && !want_dictionary) {
         return true
     };
     return false
@@ -166,6 +228,7 @@ public(package) fun remove_accredited_property(self: &mut Accreditations, accred
         id: uid,
         properties: _,
         accredited_by: _,
+        pending_until_ms: _,
     } = self.accreditations.remove(idx.extract());
     object::delete(uid);
 }
@@ -181,11 +244,24 @@ public(package) fun find_accredited_property_id(self: &Accreditations, id: &ID):
     option::none()
 }
 
+/// Ends the objection window of an accreditation early.
+/// Returns false if the accreditation is not in the list.
+public(package) fun activate_accreditation(self: &mut Accreditations, accreditation_id: &ID): bool {
+    let idx = self.find_accredited_property_id(accreditation_id);
+    if (idx.is_none()) {
+        return false
+    };
+    self.accreditations[*idx.borrow()].pending_until_ms = option::none();
+    true
+}
+
 /// Accreditation represents properties that are accredited by a third party.
 public struct Accreditation has key, store {
     id: UID,
     accredited_by: String,
     properties: VecMap<PropertyName, FederationProperty>,
+    // The end of the objection window, before which the accreditation is not effective
+    pending_until_ms: Option<u64>,
 }
 
 public fun new_accreditation(
@@ -198,6 +274,7 @@ public fun new_accreditation(
         id: object::new(ctx),
         accredited_by: ctx.sender().to_string(),
         properties: properties_map,
+        pending_until_ms: option::none(),
     }
 }
 
@@ -213,6 +290,158 @@ public(package) fun properties(self: &Accreditation): &VecMap<PropertyName, Fede
     &self.properties
 }
 
+public(package) fun pending_until_ms(self: &Accreditation): Option<u64> {
+    self.pending_until_ms
+}
+
+public(package) fun set_pending_until_ms(self: &mut Accreditation, pending_until_ms: u64) {
+    self.pending_until_ms = option::some(pending_until_ms)
+}
+
+/// Returns the accredited property covering `property_name`: the property of that name or,
+/// if there is none, the most specific wildcard property covering it.
+public(package) fun find_property(
+    self: &Accreditation,
+    property_name: &PropertyName,
+): Option<FederationProperty> {
+    let exact = self.properties.try_get(property_name);
+    if (exact.is_some()) {
+        return exact
+    };
+
+    let mut found = option::none();
+    let mut len_found = 0;
+    let mut idx = 0;
+    while (idx < self.properties.length()) {
+        let (name, property) = self.properties.get_entry_by_idx(idx);
+        if (name.covers(property_name) && name.names().length() > len_found) {
+            found = option::some(*property);
+            len_found = name.names().length();
+        };
+        idx = idx + 1;
+    };
+    found
+}
+
+/// Checks if the objection window of the accreditation is still running at the given time.
+public(package) fun is_pending(self: &Accreditation, current_time_ms: u64): bool {
+    self.pending_until_ms.is_some() && current_time_ms < *self.pending_until_ms.borrow()
+}
+
+// ===== Paged Views =====
+
+/// A copy of an accreditation returned by paged views.
+/// Has the layout of `Accreditation`, with the ID in place of the UID.
+public struct AccreditationView has copy, drop, store {
+    id: ID,
+    accredited_by: String,
+    properties: VecMap<PropertyName, FederationProperty>,
+    pending_until_ms: Option<u64>,
+}
+
+/// A page of accreditations and the cursor of the next page, if any.
+public struct AccreditationPage has copy, drop {
+    accreditations: vector<AccreditationView>,
+    next_cursor: Option<u64>,
+}
+
+/// Creates an empty page without a next page.
+public(package) fun new_empty_page(): AccreditationPage {
+    AccreditationPage {
+        accreditations: vector::empty(),
+        next_cursor: option::none(),
+    }
+}
+
+/// Returns up to `limit` accreditations matching the filters, starting at index `cursor`.
+/// An accreditation matches if it covers `property_name`, like `find_property`, was issued by
+/// `accredited_by` and, if `active_at_ms` is set, its objection window has ended and a matching
+/// property is valid then.
+public(package) fun page(
+    self: &Accreditations,
+    cursor: u64,
+    limit: u64,
+    property_name: &Option<PropertyName>,
+    accredited_by: &Option<String>,
+    active_at_ms: Option<u64>,
+): AccreditationPage {
+    let mut accreditations = vector::empty();
+    let mut idx = cursor;
+    while (idx < self.accreditations.length() && accreditations.length() < limit) {
+        let accreditation = &self.accreditations[idx];
+        if (accreditation.matches_filter(property_name, accredited_by, active_at_ms)) {
+            accreditations.push_back(accreditation.to_view());
+        };
+        idx = idx + 1;
+    };
+
+    let next_cursor = if (idx < self.accreditations.length()) {
+        option::some(idx)
+    } else {
+        option::none()
+    };
+    AccreditationPage { accreditations, next_cursor }
+}
+
+fun matches_filter(
+    self: &Accreditation,
+    property_name: &Option<PropertyName>,
+    accredited_by: &Option<String>,
+    active_at_ms: Option<u64>,
+): bool {
+    if (accredited_by.is_some() && accredited_by.borrow() != &self.accredited_by) {
+        return false
+    };
+    let mut property = option::none();
+    if (property_name.is_some()) {
+        property = self.find_property(property_name.borrow());
+        if (property.is_none()) {
+            return false
+        };
+    };
+    if (active_at_ms.is_none()) {
+        return true
+    };
+
+    let now_ms = *active_at_ms.borrow();
+    if (self.is_pending(now_ms)) {
+        return false
+    };
+    if (property.is_some()) {
+        return property.borrow().is_valid_at_time(now_ms)
+    };
+    let mut idx = 0;
+    while (idx < self.properties.length()) {
+        let (_, property) = self.properties.get_entry_by_idx(idx);
+        if (property.is_valid_at_time(now_ms)) {
+            return true
+        };
+        idx = idx + 1;
+    };
+    false
+}
+
+fun to_view(self: &Accreditation): AccreditationView {
+    AccreditationView {
+        id: self.id.to_inner(),
+        accredited_by: self.accredited_by,
+        properties: self.properties,
+        pending_until_ms: self.pending_until_ms,
+    }
+}
+
+public(package) fun page_accreditations(self: &AccreditationPage): &vector<AccreditationView> {
+    &self.accreditations
+}
+
+public(package) fun page_next_cursor(self: &AccreditationPage): Option<u64> {
+    self.next_cursor
+}
+
+public(package) fun view_id(self: &AccreditationView): ID {
+    self.id
+}
+
 // ===== Test-only Functions =====
 
 #[test_only]
@@ -221,6 +450,7 @@ public(package) fun destroy_accreditation(self: Accreditation) {
         id: id,
         accredited_by: _,
         properties: _,
+        pending_until_ms: _,
     } = self;
 
     object::delete(id);
@@ -0,0 +1,100 @@
+module hierarchies::attestation;
+
+use hierarchies::{
+    property::{Self, Timespan},
+    property_name::PropertyName,
+    property_value::PropertyValue
+};
+use iota::vec_map::VecMap;
+
+/// Attestation represents properties of a receiver, attested by an attester of a federation.
+/// It is owned by the receiver and can't be transferred.
+public struct Attestation has key {
+    id: UID,
+    federation_id: ID,
+    issuer: ID,
+    properties: VecMap<PropertyName, PropertyValue>,
+    timespan: Timespan,
+    issued_at_ms: u64,
+}
+
+/// The issuer and revocation status of an attestation, kept by its federation.
+public struct AttestationRecord has drop, store {
+    issuer: ID,
+    revoked: bool,
+}
+
+/// The key of an `AttestationRecord` in its federation.
+public struct AttestationKey has copy, drop, store {
+    attestation_id: ID,
+}
+
+public(package) fun new_attestation(
+    federation_id: ID,
+    properties: VecMap<PropertyName, PropertyValue>,
+    valid_from_ms: Option<u64>,
+    valid_until_ms: Option<u64>,
+    issued_at_ms: u64,
+    ctx: &mut TxContext,
+): Attestation {
+    Attestation {
+        id: object::new(ctx),
+        federation_id,
+        issuer: ctx.sender().to_id(),
+        properties,
+        timespan: property::new_timespan(valid_from_ms, valid_until_ms),
+        issued_at_ms,
+    }
+}
+
+public fun federation_id(self: &Attestation): ID {
+    self.federation_id
+}
+
+public fun issuer(self: &Attestation): ID {
+    self.issuer
+}
+
+public fun properties(self: &Attestation): &VecMap<PropertyName, PropertyValue> {
+    &self.properties
+}
+
+public fun valid_from_ms(self: &Attestation): Option<u64> {
+    self.timespan.valid_from_ms()
+}
+
+public fun valid_until_ms(self: &Attestation): Option<u64> {
+    self.timespan.valid_until_ms()
+}
+
+public fun issued_at_ms(self: &Attestation): u64 {
+    self.issued_at_ms
+}
+
+/// Checks if the attestation is within its validity window at `current_time_ms`.
+public fun is_valid_at_time(self: &Attestation, current_time_ms: u64): bool {
+    self.timespan.timestamp_matches(current_time_ms)
+}
+
+public(package) fun new_record(issuer: ID): AttestationRecord {
+    AttestationRecord {
+        issuer,
+        revoked: false,
+    }
+}
+
+public(package) fun record_issuer(self: &AttestationRecord): ID {
+    self.issuer
+}
+
+public(package) fun is_revoked(self: &AttestationRecord): bool {
+    self.revoked
+}
+
+public(package) fun set_revoked(self: &mut AttestationRecord) {
+    self.revoked = true
+}
+
+public(package) fun new_key(attestation_id: ID): AttestationKey {
+    AttestationKey { attestation_id }
+}
@@ -1,14 +1,21 @@
 /// This module implements a hierarchical trust system where entities can delegate
 /// trust and attestation rights to other entities within a federation.
+///
+/// The layouts of `Federation`, `Governance` and the events differ from the published package, so
+/// this package is deployed fresh instead of as an upgrade. Federations of the published package
+/// are migrated by recreating them, see the `compat` module of the Rust SDK. Once published, new
+/// state is added as dynamic fields of the federation, like attestation records.
 module hierarchies::main;
 
 use hierarchies::{
-    accreditation::{Self, Accreditations},
-    property::{Self, FederationProperties, FederationProperty},
+    accreditation::{Self, Accreditation, AccreditationPage, Accreditations},
+    attestation::{Self, Attestation, AttestationKey, AttestationRecord},
+    property::{Self, FederationProperties, FederationProperty, PropertyPage},
     property_name::PropertyName,
     property_value::PropertyValue
 };
-use iota::{clock::Clock, event, vec_map::{Self, VecMap}};
+use iota::{clock::Clock, dynamic_field, event, vec_map::{Self, VecMap}, vec_set::{Self, VecSet}};
+use std::string::String;
 
 // ===== Errors =====
 /// Error when operation is performed with wrong federation
@@ -37,9 +44,83 @@ const EAlreadyRootAuthority: u64 = 11;
 const ENotRevokedRootAuthority: u64 = 12;
 /// Error when trying to create accreditation for a revoked property
 const EPropertyRevoked: u64 = 13;
+/// Error when a value dictionary does not exist in the federation
+const EValueDictionaryNotFound: u64 = 14;
+/// Error when a value dictionary is empty
+const EEmptyValueDictionary: u64 = 15;
+/// Error when a value dictionary is still referenced by a property
+const EValueDictionaryInUse: u64 = 16;
+/// Error when an accreditation references another value dictionary than the federation property
+const EValueDictionaryMismatch: u64 = 17;
+/// Error when the grant policy of the federation does not permit allow_any grants
+const EAllowAnyNotPermitted: u64 = 18;
+/// Error when a granted property exceeds the allowed values limit of the grant policy
+const EAllowedValuesLimitExceeded: u64 = 19;
+/// Error when more tags than permitted are set on a property or accreditation
+const ETooManyTags: u64 = 20;
+/// Error when a tag is empty or longer than permitted
+const EInvalidTag: u64 = 21;
+/// Error when tagging an accreditation by neither its issuer nor a root authority
+const ENotAccreditationIssuer: u64 = 22;
+/// Error when an action requiring a quorum of root authorities is executed directly
+const EQuorumRequired: u64 = 23;
+/// Error when a quorum threshold is zero or exceeds the number of root authorities
+const EInvalidQuorumThreshold: u64 = 24;
+/// Error when a governance proposal does not exist
+const EProposalNotFound: u64 = 25;
+/// Error when a root authority approves a proposal twice
+const EAlreadyApproved: u64 = 26;
+/// Error when a proposal is executed without enough approvals
+const EQuorumNotReached: u64 = 27;
+/// Error when a metadata field is empty or longer than permitted, or a property has too many metadata entries
+const EInvalidMetadata: u64 = 28;
+/// Error when a guarded capability transfer has not been confirmed by another root authority
+const ETransferNotConfirmed: u64 = 29;
+/// Error when a capability is transferred to its holder, an existing root authority or an account holding accreditations
+const EInvalidTransferRecipient: u64 = 30;
+/// Error when the annotation of a revocation reason is longer than permitted
+const EAnnotationTooLong: u64 = 31;
+/// Error when the sender is not allowed to issue or revoke an attestation
+const EUnauthorizedAttester: u64 = 32;
+/// Error when the validity window of an attestation ends before it starts
+const EInvalidValidityWindow: u64 = 33;
+/// Error when an attestation is not issued in the federation
+const EAttestationNotFound: u64 = 34;
+/// Error when an attestation is already revoked
+const EAttestationRevoked: u64 = 35;
+/// Error when a federation is linked to itself
+const ECannotLinkSelf: u64 = 36;
+/// Error when a federation is already recognized
+const EFederationAlreadyLinked: u64 = 37;
+/// Error when a federation is not recognized
+const EFederationNotLinked: u64 = 38;
+/// Error when a property is deprecated in favour of itself or of a property outside the federation
+const EInvalidPropertyReplacement: u64 = 39;
+/// Error when a property is named like a wildcard, which can only be granted as an accreditation
+const EWildcardProperty: u64 = 40;
+/// Error when the default validity of a grant policy is zero or exceeds `MAX_DEFAULT_VALIDITY_MS`
+const EInvalidDefaultValidity: u64 = 41;
+/// Error when an attestation is created without properties
+const EEmptyAttestation: u64 = 42;
 
 // ===== Constants =====
 const TIME_BUFFER_MS: u64 = 5000;
+/// Maximum number of tags on a property or accreditation
+const MAX_TAGS: u64 = 8;
+/// Maximum length of a tag in bytes
+const MAX_TAG_LENGTH: u64 = 64;
+/// Maximum number of accreditations returned by a paged view
+const MAX_PAGE_SIZE: u64 = 100;
+/// Maximum length of a metadata field in bytes
+const MAX_METADATA_LENGTH: u64 = 1024;
+/// Maximum number of metadata entries of a property
+const MAX_PROPERTY_METADATA_ENTRIES: u64 = 16;
+/// Maximum length of the annotation of a revocation reason in bytes
+const MAX_ANNOTATION_LENGTH: u64 = 256;
+/// Maximum default validity of a grant policy, 100 years
+const MAX_DEFAULT_VALIDITY_MS: u64 = 3_153_600_000_000;
+/// Version of the layouts of the package, matching the `compat` module of the Rust SDK
+const SCHEMA_VERSION: u64 = 25;
 
 // ===== Core Data Structures =====
 
@@ -50,6 +131,10 @@ public struct Federation has key, store {
     governance: Governance,
     root_authorities: vector<RootAuthority>,
     revoked_root_authorities: vector<ID>,
+    /// Summary statistics kept up to date by every mutation
+    stats: FederationStats,
+    /// Human-readable description shown by wallets and explorers
+    metadata: FederationMetadata,
 }
 
 /// Root authority with the highest trust level in the system.
@@ -69,6 +154,108 @@ public struct Governance has key, store {
     accreditations_to_accredit: VecMap<ID, Accreditations>,
     /// Rights for creating attestations
     accreditations_to_attest: VecMap<ID, Accreditations>,
+    /// Properties marked as critical, changes to them deserve prioritized attention
+    critical_properties: VecSet<PropertyName>,
+    /// Named sets of values shared by properties that reference them
+    value_dictionaries: VecMap<String, VecSet<PropertyValue>>,
+    /// Defaults and limits applied to newly granted accreditations
+    grant_policy: GrantPolicy,
+    /// Free-form labels grouping properties
+    property_tags: VecMap<PropertyName, VecSet<String>>,
+    /// Free-form labels grouping accreditations
+    accreditation_tags: VecMap<ID, VecSet<String>>,
+    /// Approvals required for destructive actions and the open proposals
+    quorum: Quorum,
+    /// Child federations whose root authorities are trusted like those of this federation
+    recognized_federations: VecSet<ID>,
+    /// Deprecated properties, mapped to the property replacing them if any
+    deprecated_properties: VecMap<PropertyName, Option<PropertyName>>,
+}
+
+/// Federation-wide defaults and limits for granting accreditations.
+public struct GrantPolicy has copy, drop, store {
+    /// Validity length applied to granted properties without an explicit end
+    default_validity_ms: Option<u64>,
+    /// Whether granted properties may allow any value
+    allow_any_permitted: bool,
+    /// Maximum number of allowed values of a granted property
+    max_allowed_values: Option<u64>,
+    /// Delay between granting an accreditation and it becoming effective, during which objections can be raised
+    objection_window_ms: Option<u64>,
+}
+
+/// The number of root authority approvals required for destructive actions.
+/// With a threshold of 1 every root authority acts alone, otherwise the actions
+/// are proposed, approved and executed as a `Proposal`.
+public struct Quorum has store {
+    threshold: u64,
+    /// Open proposals by their ID
+    proposals: VecMap<u64, Proposal>,
+    /// The ID of the next proposal
+    next_proposal_id: u64,
+    /// Confirmed capability transfers by capability ID, required while a quorum is required
+    transfer_confirmations: VecMap<ID, TransferConfirmation>,
+}
+
+/// The confirmation of a capability transfer by a root authority other than the holder.
+public struct TransferConfirmation has copy, drop, store {
+    recipient: address,
+    confirmed_by: ID,
+}
+
+/// A destructive action subject to the quorum of the federation.
+public enum GovernanceAction has copy, drop, store {
+    /// Revokes a root authority, see `revoke_root_authority`
+    RevokeRootAuthority { account_id: ID },
+    /// Revokes a property at `valid_to_ms` or on execution, see `revoke_property_at`
+    RevokeProperty { property_name: PropertyName, valid_to_ms: Option<u64> },
+    /// Changes the quorum threshold
+    SetQuorumThreshold { threshold: u64 },
+    /// Adds a root authority, see `add_root_authority`
+    AddRootAuthority { account_id: ID },
+    /// Reinstates a revoked root authority, see `reinstate_root_authority`
+    ReinstateRootAuthority { account_id: ID },
+    /// Creates or replaces a value dictionary, see `set_value_dictionary`
+    SetValueDictionary { name: String, values: VecSet<PropertyValue> },
+    /// Deprecates a property, see `deprecate_property`
+    DeprecateProperty { property_name: PropertyName, replacement: Option<PropertyName> },
+    /// Stops recognizing a child federation, see `unlink_federation`
+    UnlinkFederation { child_federation_id: ID },
+}
+
+/// A proposed action and the root authorities that approved it.
+public struct Proposal has copy, drop, store {
+    action: GovernanceAction,
+    proposed_by: ID,
+    approvals: VecSet<ID>,
+}
+
+/// Summary statistics of a federation, readable without scanning its maps.
+public struct FederationStats has copy, drop, store {
+    /// Number of properties, including revoked ones
+    property_count: u64,
+    /// Number of active root authorities
+    root_authority_count: u64,
+    /// Number of accreditations to attest held by all entities
+    accreditation_to_attest_count: u64,
+    /// Number of accreditations to accredit held by all entities
+    accreditation_to_accredit_count: u64,
+    /// Number of mutations applied since the federation was created
+    modification_count: u64,
+    /// Start of the epoch in which the last mutation was applied
+    last_modified_epoch_ms: u64,
+}
+
+/// Optional human-readable description of a federation, set by root authorities.
+/// It is informational only and never used by validation.
+public struct FederationMetadata has copy, drop, store {
+    /// Display name, e.g. "International Standards Consortium"
+    name: Option<String>,
+    description: Option<String>,
+    /// URI of the logo
+    logo_uri: Option<String>,
+    /// Contact of the federation operators, e.g. an email address or a URL
+    contact: Option<String>,
 }
 
 // ===== Capability Objects =====
@@ -93,6 +280,51 @@ public struct FederationCreatedEvent has copy, drop {
     federation_address: address,
 }
 
+/// Event emitted when the metadata of the federation is set
+public struct FederationMetadataSetEvent has copy, drop {
+    federation_address: address,
+    metadata: FederationMetadata,
+}
+
+/// Event emitted when a child federation is recognized
+public struct FederationLinkedEvent has copy, drop {
+    federation_address: address,
+    child_federation_id: ID,
+}
+
+/// Event emitted when a child federation is no longer recognized
+public struct FederationUnlinkedEvent has copy, drop {
+    federation_address: address,
+    child_federation_id: ID,
+}
+
+/// Event emitted when the grant policy of the federation is changed
+public struct GrantPolicySetEvent has copy, drop {
+    federation_address: address,
+    policy: GrantPolicy,
+}
+
+/// Event emitted when the tags of a property are set
+public struct PropertyTagsSetEvent has copy, drop {
+    federation_address: address,
+    property_name: PropertyName,
+    tags: VecSet<String>,
+}
+
+/// Event emitted when the metadata of a property is set
+public struct PropertyMetadataSetEvent has copy, drop {
+    federation_address: address,
+    property_name: PropertyName,
+    metadata: VecMap<String, String>,
+}
+
+/// Event emitted when the tags of an accreditation are set
+public struct AccreditationTagsSetEvent has copy, drop {
+    federation_address: address,
+    accreditation_id: ID,
+    tags: VecSet<String>,
+}
+
 /// Event emitted when a property is added to the federation
 public struct PropertyAddedEvent has copy, drop {
     federation_address: address,
@@ -104,6 +336,34 @@ public struct PropertyRevokedEvent has copy, drop {
     federation_address: address,
     property_name: PropertyName,
     valid_to_ms: u64,
+    critical: bool,
+}
+
+/// Event emitted when a property is marked or unmarked as critical
+public struct PropertyCriticalityChangedEvent has copy, drop {
+    federation_address: address,
+    property_name: PropertyName,
+    critical: bool,
+}
+
+/// Event emitted when a property is deprecated
+public struct PropertyDeprecatedEvent has copy, drop {
+    federation_address: address,
+    property_name: PropertyName,
+    replacement: Option<PropertyName>,
+}
+
+/// Event emitted when a value dictionary is created or replaced
+public struct ValueDictionarySetEvent has copy, drop {
+    federation_address: address,
+    name: String,
+    values: VecSet<PropertyValue>,
+}
+
+/// Event emitted when a value dictionary is removed
+public struct ValueDictionaryRemovedEvent has copy, drop {
+    federation_address: address,
+    name: String,
 }
 
 /// Event emitted when a root authority is added
@@ -139,6 +399,13 @@ public struct AccreditationToAttestCreatedEvent has copy, drop {
     accreditor: ID,
 }
 
+/// Event emitted when the objection window of an accreditation is ended early
+public struct AccreditationActivatedEvent has copy, drop {
+    federation_address: address,
+    accreditation_id: ID,
+    activated_by: ID,
+}
+
 /// Event emitted when accreditation to attest is revoked
 public struct AccreditationToAttestRevokedEvent has copy, drop {
     federation_address: address,
@@ -155,6 +422,74 @@ public struct AccreditationToAccreditRevokedEvent has copy, drop {
     revoker: ID,
 }
 
+/// Event emitted along with a revocation to record why it was made
+public struct RevocationReasonEvent has copy, drop {
+    federation_address: address,
+    reason_code: u64,
+    annotation: String,
+    revoked_by: ID,
+}
+
+/// Event emitted when an attestation is issued
+public struct AttestationCreatedEvent has copy, drop {
+    federation_address: address,
+    attestation_id: ID,
+    issuer: ID,
+    receiver: address,
+}
+
+/// Event emitted when an attestation is revoked
+public struct AttestationRevokedEvent has copy, drop {
+    federation_address: address,
+    attestation_id: ID,
+    revoker: ID,
+}
+
+/// Event emitted when the quorum threshold is changed
+public struct QuorumThresholdSetEvent has copy, drop {
+    federation_address: address,
+    threshold: u64,
+}
+
+/// Event emitted when a governance action is proposed
+public struct ActionProposedEvent has copy, drop {
+    federation_address: address,
+    proposal_id: u64,
+    action: GovernanceAction,
+    proposed_by: ID,
+}
+
+/// Event emitted when a governance proposal is approved
+public struct ActionApprovedEvent has copy, drop {
+    federation_address: address,
+    proposal_id: u64,
+    approved_by: ID,
+}
+
+/// Event emitted when a root authority confirms the transfer of a capability
+public struct CapabilityTransferConfirmedEvent has copy, drop {
+    federation_address: address,
+    capability_id: ID,
+    recipient: address,
+    confirmed_by: ID,
+}
+
+/// Event emitted when a capability is transferred to a new account
+public struct CapabilityTransferredEvent has copy, drop {
+    federation_address: address,
+    capability_id: ID,
+    from: address,
+    to: address,
+}
+
+/// Event emitted when a governance proposal is executed
+public struct ActionExecutedEvent has copy, drop {
+    federation_address: address,
+    proposal_id: u64,
+    action: GovernanceAction,
+    executed_by: ID,
+}
+
 // ===== Constructor Functions =====
 
 /// Creates a new federation with the sender as the first root authority.
@@ -170,13 +505,41 @@ public fun new_federation(ctx: &mut TxContext) {
             properties: property::new_properties(),
             accreditations_to_accredit: vec_map::empty(),
             accreditations_to_attest: vec_map::empty(),
+            critical_properties: vec_set::empty(),
+            value_dictionaries: vec_map::empty(),
+            grant_policy: new_grant_policy(option::none(), true, option::none()),
+            property_tags: vec_map::empty(),
+            accreditation_tags: vec_map::empty(),
+            quorum: Quorum {
+                threshold: 1,
+                proposals: vec_map::empty(),
+                next_proposal_id: 0,
+                transfer_confirmations: vec_map::empty(),
+            },
+            recognized_federations: vec_set::empty(),
+            deprecated_properties: vec_map::empty(),
         },
+        stats: FederationStats {
+            property_count: 0,
+            root_authority_count: 0,
+            accreditation_to_attest_count: 0,
+            accreditation_to_accredit_count: 0,
+            modification_count: 0,
+            last_modified_epoch_ms: ctx.epoch_timestamp_ms(),
+        },
+        metadata: new_federation_metadata(
+            option::none(),
+            option::none(),
+            option::none(),
+            option::none(),
+        ),
     };
 
     // Create root authority and capabilities
     let root_auth_cap = new_root_authority_cap(&federation, ctx.sender().to_id(), ctx);
     let root_authority = new_root_authority(ctx.sender().to_id(), ctx);
     vector::push_back(&mut federation.root_authorities, root_authority);
+    federation.stats.root_authority_count = federation.root_authorities.length();
 
     // Grant permissions to the creator
     let permission = accreditation::new_empty_accreditations();
@@ -201,6 +564,20 @@ public fun new_federation(ctx: &mut TxContext) {
     transfer::share_object(federation)
 }
 
+/// Creates a new grant policy
+public fun new_grant_policy(
+    default_validity_ms: Option<u64>,
+    allow_any_permitted: bool,
+    max_allowed_values: Option<u64>,
+): GrantPolicy {
+    GrantPolicy {
+        default_validity_ms,
+        allow_any_permitted,
+        max_allowed_values,
+        objection_window_ms: option::none(),
+    }
+}
+
 /// Creates a new root authority object
 public(package) fun new_root_authority(account_id: ID, ctx: &mut TxContext): RootAuthority {
     RootAuthority {
@@ -237,16 +614,390 @@ fun federation_id(self: &Federation): ID {
     self.id.to_inner()
 }
 
+/// Returns the number of root authority approvals required for destructive actions
+public fun get_quorum_threshold(self: &Federation): u64 {
+    self.governance.quorum.threshold
+}
+
+/// Returns true if destructive actions have to be approved by more than one root authority
+public fun is_quorum_required(self: &Federation): bool {
+    self.governance.quorum.threshold > 1
+}
+
+/// Returns the IDs of the open governance proposals
+public fun get_proposal_ids(self: &Federation): vector<u64> {
+    self.governance.quorum.proposals.keys()
+}
+
 /// Gets all property names trusted by the federation
 public fun get_properties(self: &Federation): vector<PropertyName> {
     self.governance.properties.data().keys()
 }
 
+/// Gets up to `limit` properties, capped at `MAX_PAGE_SIZE`, starting at index `cursor`.
+/// Unlike `get_properties`, the result stays bounded for federations with many properties.
+public fun get_properties_page(self: &Federation, cursor: u64, limit: u64): PropertyPage {
+    let limit = if (limit > MAX_PAGE_SIZE) { MAX_PAGE_SIZE } else { limit };
+    self.governance.properties.page(cursor, limit)
+}
+
+/// Get the names of all properties whose names start with the names of `prefix`
+public fun get_properties_by_prefix(self: &Federation, prefix: &PropertyName): vector<PropertyName> {
+    let mut names = vector::empty();
+    let data = self.governance.properties.data();
+    let mut idx = 0;
+    while (idx < data.size()) {
+        let (name, _) = data.get_entry_by_idx(idx);
+        if (name.starts_with(prefix)) {
+            names.push_back(*name);
+        };
+        idx = idx + 1;
+    };
+    names
+}
+
 /// Checks if a property is trusted by the federation
 public fun is_property_in_federation(self: &Federation, property_name: PropertyName): bool {
     self.governance.properties.data().contains(&property_name)
 }
 
+/// Checks if a property is marked as critical
+public fun is_property_critical(self: &Federation, property_name: PropertyName): bool {
+    self.governance.critical_properties.contains(&property_name)
+}
+
+/// Gets all property names marked as critical
+public fun get_critical_properties(self: &Federation): vector<PropertyName> {
+    *self.governance.critical_properties.keys()
+}
+
+/// Gets the IDs of the child federations recognized by the federation
+public fun get_recognized_federations(self: &Federation): vector<ID> {
+    *self.governance.recognized_federations.keys()
+}
+
+/// Checks if `federation_id` is recognized as a child federation
+public fun is_federation_recognized(self: &Federation, federation_id: &ID): bool {
+    self.governance.recognized_federations.contains(federation_id)
+}
+
+/// Checks if a property is deprecated
+public fun is_property_deprecated(self: &Federation, property_name: PropertyName): bool {
+    self.governance.deprecated_properties.contains(&property_name)
+}
+
+/// Gets the deprecated properties, mapped to the property replacing them if any
+public fun get_deprecated_properties(self: &Federation): VecMap<PropertyName, Option<PropertyName>> {
+    self.governance.deprecated_properties
+}
+
+/// Gets the names of all value dictionaries of the federation
+public fun get_value_dictionary_names(self: &Federation): vector<String> {
+    self.governance.value_dictionaries.keys()
+}
+
+/// Gets the values of a value dictionary
+public fun get_value_dictionary(self: &Federation, name: &String): vector<PropertyValue> {
+    assert!(self.governance.value_dictionaries.contains(name), EValueDictionaryNotFound);
+    *self.governance.value_dictionaries.get(name).keys()
+}
+
+/// Checks if a value is in the value dictionary referenced by a federation property.
+/// Properties that do not reference a value dictionary accept every value.
+fun is_value_in_dictionary(
+    self: &Federation,
+    property: &FederationProperty,
+    value: &PropertyValue,
+): bool {
+    let dictionary = property.value_dictionary();
+    if (dictionary.is_none()) {
+        return true
+    };
+    let name = dictionary.borrow();
+    self.governance.value_dictionaries.contains(name) &&
+        self.governance.value_dictionaries.get(name).contains(value)
+}
+
+/// Checks if a property references a value dictionary
+fun is_value_dictionary_in_use(self: &Federation, name: &String): bool {
+    let properties = self.governance.properties.data();
+    let mut idx = 0;
+    while (idx < properties.length()) {
+        let (_, property) = properties.get_entry_by_idx(idx);
+        let dictionary = property.value_dictionary();
+        if (dictionary.is_some() && dictionary.borrow() == name) {
+            return true
+        };
+        idx = idx + 1;
+    };
+    false
+}
+
+/// Get the tags of a property
+public fun get_property_tags(self: &Federation, property_name: &PropertyName): vector<String> {
+    if (!self.governance.property_tags.contains(property_name)) {
+        return vector::empty()
+    };
+    *self.governance.property_tags.get(property_name).keys()
+}
+
+/// Get the metadata of a property, empty if none is set
+public fun get_property_metadata(self: &Federation, property_name: &PropertyName): VecMap<String, String> {
+    assert!(self.is_property_in_federation(*property_name), EPropertyNotInFederation);
+    *self.governance.properties.data().get(property_name).metadata()
+}
+
+/// Get the names of all properties tagged with `tag`
+public fun get_properties_by_tag(self: &Federation, tag: &String): vector<PropertyName> {
+    let mut names = vector::empty();
+    let mut idx = 0;
+    while (idx < self.governance.property_tags.size()) {
+        let (name, tags) = self.governance.property_tags.get_entry_by_idx(idx);
+        if (tags.contains(tag)) {
+            names.push_back(*name);
+        };
+        idx = idx + 1;
+    };
+    names
+}
+
+/// Get the tags of an accreditation
+public fun get_accreditation_tags(self: &Federation, accreditation_id: &ID): vector<String> {
+    if (!self.governance.accreditation_tags.contains(accreditation_id)) {
+        return vector::empty()
+    };
+    *self.governance.accreditation_tags.get(accreditation_id).keys()
+}
+
+/// Get the IDs of all accreditations tagged with `tag`
+public fun get_accreditations_by_tag(self: &Federation, tag: &String): vector<ID> {
+    let mut ids = vector::empty();
+    let mut idx = 0;
+    while (idx < self.governance.accreditation_tags.size()) {
+        let (id, tags) = self.governance.accreditation_tags.get_entry_by_idx(idx);
+        if (tags.contains(tag)) {
+            ids.push_back(*id);
+        };
+        idx = idx + 1;
+    };
+    ids
+}
+
+/// Finds the issuer of an accreditation to attest or to accredit
+fun find_accreditation_issuer(self: &Federation, accreditation_id: &ID): Option<String> {
+    let issuer = find_issuer_in(&self.governance.accreditations_to_attest, accreditation_id);
+    if (issuer.is_some()) {
+        return issuer
+    };
+    find_issuer_in(&self.governance.accreditations_to_accredit, accreditation_id)
+}
+
+fun find_issuer_in(map: &VecMap<ID, Accreditations>, accreditation_id: &ID): Option<String> {
+    let mut idx = 0;
+    while (idx < map.size()) {
+        let (_, accreditations) = map.get_entry_by_idx(idx);
+        let found = accreditations.find_accredited_property_id(accreditation_id);
+        if (found.is_some()) {
+            let accreditation = &accreditations.accredited_properties()[*found.borrow()];
+            return option::some(*accreditation.accredited_by())
+        };
+        idx = idx + 1;
+    };
+    option::none()
+}
+
+fun activate_in(map: &mut VecMap<ID, Accreditations>, accreditation_id: &ID): bool {
+    let mut idx = 0;
+    while (idx < map.size()) {
+        let (_, accreditations) = map.get_entry_by_idx_mut(idx);
+        if (accreditations.activate_accreditation(accreditation_id)) {
+            return true
+        };
+        idx = idx + 1;
+    };
+    false
+}
+
+/// Starts the objection window of the grant policy for a newly granted accreditation
+fun apply_objection_window(self: &Federation, accreditation: &mut Accreditation, current_time_ms: u64) {
+    let window = self.governance.grant_policy.objection_window_ms;
+    if (window.is_some()) {
+        accreditation.set_pending_until_ms(current_time_ms + *window.borrow());
+    };
+}
+
+/// Checks that a set of tags stays within the tag limits
+fun assert_valid_tags(tags: &VecSet<String>) {
+    assert!(tags.size() <= MAX_TAGS, ETooManyTags);
+    let keys = tags.keys();
+    let mut idx = 0;
+    while (idx < keys.length()) {
+        let length = keys[idx].length();
+        assert!(length > 0 && length <= MAX_TAG_LENGTH, EInvalidTag);
+        idx = idx + 1;
+    };
+}
+
+/// Creates new federation metadata
+public fun new_federation_metadata(
+    name: Option<String>,
+    description: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+): FederationMetadata {
+    FederationMetadata { name, description, logo_uri, contact }
+}
+
+/// Get the metadata of the federation
+public fun get_metadata(self: &Federation): FederationMetadata {
+    self.metadata
+}
+
+/// Get the display name of the federation
+public fun metadata_name(self: &FederationMetadata): Option<String> {
+    self.name
+}
+
+/// Get the description of the federation
+public fun metadata_description(self: &FederationMetadata): Option<String> {
+    self.description
+}
+
+/// Get the URI of the logo of the federation
+public fun metadata_logo_uri(self: &FederationMetadata): Option<String> {
+    self.logo_uri
+}
+
+/// Get the contact of the federation operators
+public fun metadata_contact(self: &FederationMetadata): Option<String> {
+    self.contact
+}
+
+/// Checks that every set metadata field is non-empty and within the length limit
+fun assert_valid_metadata(metadata: &FederationMetadata) {
+    let fields = vector[metadata.name, metadata.description, metadata.logo_uri, metadata.contact];
+    let mut idx = 0;
+    while (idx < fields.length()) {
+        if (fields[idx].is_some()) {
+            let length = fields[idx].borrow().length();
+            assert!(length > 0 && length <= MAX_METADATA_LENGTH, EInvalidMetadata);
+        };
+        idx = idx + 1;
+    };
+}
+
+/// Checks that every metadata key and value is non-empty and within the length limits
+fun assert_valid_property_metadata(metadata: &VecMap<String, String>) {
+    let mut idx = 0;
+    while (idx < metadata.size()) {
+        let (key, value) = metadata.get_entry_by_idx(idx);
+        assert!(key.length() > 0 && key.length() <= MAX_TAG_LENGTH, EInvalidMetadata);
+        assert!(value.length() > 0 && value.length() <= MAX_METADATA_LENGTH, EInvalidMetadata);
+        idx = idx + 1;
+    };
+}
+
+/// Get the grant policy of the federation
+public fun get_grant_policy(self: &Federation): GrantPolicy {
+    self.governance.grant_policy
+}
+
+/// Get the validity length applied to granted properties without an explicit end
+public fun default_validity_ms(self: &GrantPolicy): Option<u64> {
+    self.default_validity_ms
+}
+
+/// Check whether granted properties may allow any value
+public fun allow_any_permitted(self: &GrantPolicy): bool {
+    self.allow_any_permitted
+}
+
+/// Get the maximum number of allowed values of a granted property
+public fun max_allowed_values(self: &GrantPolicy): Option<u64> {
+    self.max_allowed_values
+}
+
+/// Get the delay between granting an accreditation and it becoming effective
+public fun objection_window_ms(self: &GrantPolicy): Option<u64> {
+    self.objection_window_ms
+}
+
+/// Returns the schema version of the package.
+///
+/// Clients refuse packages reporting an older version, or lacking this function, since they
+/// can't decode their federations and events.
+public fun schema_version(): u64 {
+    SCHEMA_VERSION
+}
+
+/// Returns the summary statistics of the federation
+public fun get_stats(self: &Federation): FederationStats {
+    self.stats
+}
+
+public fun property_count(self: &FederationStats): u64 {
+    self.property_count
+}
+
+public fun root_authority_count(self: &FederationStats): u64 {
+    self.root_authority_count
+}
+
+public fun accreditation_to_attest_count(self: &FederationStats): u64 {
+    self.accreditation_to_attest_count
+}
+
+public fun accreditation_to_accredit_count(self: &FederationStats): u64 {
+    self.accreditation_to_accredit_count
+}
+
+public fun modification_count(self: &FederationStats): u64 {
+    self.modification_count
+}
+
+public fun last_modified_epoch_ms(self: &FederationStats): u64 {
+    self.last_modified_epoch_ms
+}
+
+/// Refreshes the statistics after a mutation.
+/// Accreditation counts are maintained by the functions creating and revoking accreditations.
+fun touch(self: &mut Federation, ctx: &TxContext) {
+    self.stats.property_count = self.governance.properties.data().size();
+    self.stats.root_authority_count = self.root_authorities.length();
+    self.stats.modification_count = self.stats.modification_count + 1;
+    self.stats.last_modified_epoch_ms = ctx.epoch_timestamp_ms();
+}
+
+/// Checks the granted properties against the grant policy and applies its default validity
+fun apply_grant_policy(
+    self: &Federation,
+    properties: &mut vector<FederationProperty>,
+    current_time_ms: u64,
+) {
+    let policy = &self.governance.grant_policy;
+    let mut idx = 0;
+    while (idx < properties.length()) {
+        let property = &mut properties[idx];
+        assert!(policy.allow_any_permitted || !property.allow_any(), EAllowAnyNotPermitted);
+        if (policy.max_allowed_values.is_some()) {
+            assert!(
+                property.allowed_values().size() <= *policy.max_allowed_values.borrow(),
+                EAllowedValuesLimitExceeded,
+            );
+        };
+        let timespan = *property.timespan();
+        if (policy.default_validity_ms.is_some() && timespan.valid_until_ms().is_none()) {
+            let mut start_ms = current_time_ms;
+            let valid_from_ms = timespan.valid_from_ms();
+            if (valid_from_ms.is_some() && *valid_from_ms.borrow() > start_ms) {
+                start_ms = *valid_from_ms.borrow();
+            };
+            property.set_valid_until(start_ms + *policy.default_validity_ms.borrow());
+        };
+        idx = idx + 1;
+    };
+}
+
 /// Gets accreditations for attestation for a specific entity
 public fun get_accreditations_to_attest(self: &Federation, entity_id: &ID): &Accreditations {
     self.governance.accreditations_to_attest.get(entity_id)
@@ -267,6 +1018,81 @@ public fun is_accreditor(self: &Federation, entity_id: &ID): bool {
     self.governance.accreditations_to_accredit.contains(entity_id)
 }
 
+/// Gets a page of the accreditations for attestation of a specific entity.
+/// See `page_accreditations` for the filters.
+public fun get_accreditations_to_attest_page(
+    self: &Federation,
+    entity_id: &ID,
+    cursor: u64,
+    limit: u64,
+    property_name: Option<PropertyName>,
+    accredited_by: Option<ID>,
+    active_only: bool,
+    clock: &Clock,
+): AccreditationPage {
+    if (!self.is_attester(entity_id)) {
+        return accreditation::new_empty_page()
+    };
+    page_accreditations(
+        self.get_accreditations_to_attest(entity_id),
+        cursor,
+        limit,
+        property_name,
+        accredited_by,
+        active_only,
+        clock,
+    )
+}
+
+/// Gets a page of the accreditations for delegation of a specific entity.
+/// See `page_accreditations` for the filters.
+public fun get_accreditations_to_accredit_page(
+    self: &Federation,
+    entity_id: &ID,
+    cursor: u64,
+    limit: u64,
+    property_name: Option<PropertyName>,
+    accredited_by: Option<ID>,
+    active_only: bool,
+    clock: &Clock,
+): AccreditationPage {
+    if (!self.is_accreditor(entity_id)) {
+        return accreditation::new_empty_page()
+    };
+    page_accreditations(
+        self.get_accreditations_to_accredit(entity_id),
+        cursor,
+        limit,
+        property_name,
+        accredited_by,
+        active_only,
+        clock,
+    )
+}
+
+/// Returns up to `limit` accreditations, capped at `MAX_PAGE_SIZE`, starting at index `cursor`.
+/// Only accreditations holding `property_name` and issued by `accredited_by` are returned, if set.
+/// With `active_only`, accreditations that are pending or have no valid property are skipped.
+fun page_accreditations(
+    accreditations: &Accreditations,
+    cursor: u64,
+    limit: u64,
+    property_name: Option<PropertyName>,
+    accredited_by: Option<ID>,
+    active_only: bool,
+    clock: &Clock,
+): AccreditationPage {
+    let limit = if (limit > MAX_PAGE_SIZE) { MAX_PAGE_SIZE } else { limit };
+    let accredited_by = accredited_by.map!(|id| id.to_address().to_string());
+    let active_at_ms = if (active_only) {
+        option::some(clock.timestamp_ms())
+    } else {
+        option::none()
+    };
+
+    accreditations.page(cursor, limit, &property_name, &accredited_by, active_at_ms)
+}
+
 /// Gets the list of root authorities (package-only access)
 public(package) fun root_authorities(self: &Federation): &vector<RootAuthority> {
     &self.root_authorities
@@ -280,21 +1106,31 @@ public fun add_property(
     self: &mut Federation,
     cap: &RootAuthorityCap,
     property: FederationProperty,
-    _: &mut TxContext,
+    ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
     assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(!property.property_name().is_wildcard(), EWildcardProperty);
     assert!(
         !(property.allow_any() && property.allowed_values().keys().length() > 0),
         EInvalidPropertyValueCondition,
     );
     assert!(
-        property.allow_any() || property.allowed_values().keys().length() > 0,
+        property.allow_any() || property.allowed_values().keys().length() > 0 ||
+            property.value_dictionary().is_some(),
         EEmptyAllowedValuesWithoutAllowAny,
     );
+    if (property.value_dictionary().is_some()) {
+        assert!(
+            self.governance.value_dictionaries.contains(property.value_dictionary().borrow()),
+            EValueDictionaryNotFound,
+        );
+    };
 
     self.governance.properties.add_property(property);
 
+    self.touch(ctx);
+
     // Emit property added event
     event::emit(PropertyAddedEvent {
         federation_address: self.federation_id().to_address(),
@@ -308,19 +1144,12 @@ public fun revoke_property(
     cap: &RootAuthorityCap,
     property_name: PropertyName,
     clock: &Clock,
-    _: &mut TxContext,
+    ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == federation.federation_id(), EUnauthorizedWrongFederation);
     assert!(!federation.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
-    let property = federation.governance.properties.data_mut().get_mut(&property_name);
-    property.revoke(clock.timestamp_ms());
-
-    // Emit property revoked event
-    event::emit(PropertyRevokedEvent {
-        federation_address: federation.federation_id().to_address(),
-        property_name,
-        valid_to_ms: clock.timestamp_ms(),
-    });
+    assert!(!federation.is_quorum_required(), EQuorumRequired);
+    federation.revoke_property_until(property_name, clock.timestamp_ms(), ctx);
 }
 
 /// Revokes a property by setting its validity period
@@ -330,374 +1159,1413 @@ public fun revoke_property_at(
     property_name: PropertyName,
     valid_to_ms: u64,
     clock: &Clock,
-    _: &mut TxContext,
+    ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == federation.federation_id(), EUnauthorizedWrongFederation);
     assert!(!federation.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(!federation.is_quorum_required(), EQuorumRequired);
     assert!(valid_to_ms > clock.timestamp_ms() + TIME_BUFFER_MS, ETimestampMustBeInTheFuture);
-    let property = federation.governance.properties.data_mut().get_mut(&property_name);
+    federation.revoke_property_until(property_name, valid_to_ms, ctx);
+}
+
+/// Revokes a property like `revoke_property` and records the reason of the revocation
+public fun revoke_property_with_reason(
+    federation: &mut Federation,
+    cap: &RootAuthorityCap,
+    property_name: PropertyName,
+    reason_code: u64,
+    annotation: String,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    federation.revoke_property(cap, property_name, clock, ctx);
+    federation.emit_revocation_reason(reason_code, annotation, ctx);
+}
+
+/// Revokes a property like `revoke_property_at` and records the reason of the revocation
+public fun revoke_property_at_with_reason(
+    federation: &mut Federation,
+    cap: &RootAuthorityCap,
+    property_name: PropertyName,
+    valid_to_ms: u64,
+    reason_code: u64,
+    annotation: String,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    federation.revoke_property_at(cap, property_name, valid_to_ms, clock, ctx);
+    federation.emit_revocation_reason(reason_code, annotation, ctx);
+}
+
+fun revoke_property_until(
+    self: &mut Federation,
+    property_name: PropertyName,
+    valid_to_ms: u64,
+    ctx: &TxContext,
+) {
+    let property = self.governance.properties.data_mut().get_mut(&property_name);
     property.revoke(valid_to_ms);
 
+    self.touch(ctx);
+
     // Emit property revoked event
     event::emit(PropertyRevokedEvent {
-        federation_address: federation.federation_id().to_address(),
+        federation_address: self.federation_id().to_address(),
         property_name,
         valid_to_ms,
+        critical: self.is_property_critical(property_name),
     });
 }
 
-/// Adds a new root authority to the federation.
-/// Only existing root authorities can perform this operation.
-public fun add_root_authority(
+/// Marks or unmarks a property as critical.
+/// Only root authorities can perform this operation.
+public fun set_property_critical(
     self: &mut Federation,
     cap: &RootAuthorityCap,
-    account_id: ID,
+    property_name: PropertyName,
+    critical: bool,
     ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
-
-    assert!(!self.is_root_authority(&account_id), EAlreadyRootAuthority);
     assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(self.is_property_in_federation(property_name), EPropertyNotInFederation);
 
-    let root_authority = new_root_authority(account_id, ctx);
-    vector::push_back(&mut self.root_authorities, root_authority);
+    let is_critical = self.governance.critical_properties.contains(&property_name);
+    if (critical && !is_critical) {
+        self.governance.critical_properties.insert(property_name);
+    } else if (!critical && is_critical) {
+        self.governance.critical_properties.remove(&property_name);
+    };
 
-    let cap = new_root_authority_cap(self, account_id, ctx);
-    transfer::transfer(cap, account_id.to_address());
+    self.touch(ctx);
 
-    event::emit(RootAuthorityAddedEvent {
+    event::emit(PropertyCriticalityChangedEvent {
         federation_address: self.federation_id().to_address(),
-        account_id,
+        property_name,
+        critical,
     });
 }
 
-/// Revokes a root authority from the federation.
+/// Marks a property as deprecated, optionally naming the property replacing it.
+/// A deprecated property stays valid, so existing attestations keep validating; clients warn
+/// about it and point to the replacement. Deprecating it again replaces the recorded replacement.
+/// While a quorum is required, properties are deprecated through `execute_action`.
 /// Only root authorities can perform this operation.
-/// Cannot revoke the last root authority to prevent lockout.
-public fun revoke_root_authority(
+public fun deprecate_property(
     self: &mut Federation,
     cap: &RootAuthorityCap,
-    account_id: ID,
-    _: &mut TxContext,
+    property_name: PropertyName,
+    replacement: Option<PropertyName>,
+    ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+    self.apply_property_deprecation(property_name, replacement, ctx);
+}
 
-    assert!(self.is_root_authority(&account_id), ERootAuthorityNotFound);
+fun apply_property_deprecation(
+    self: &mut Federation,
+    property_name: PropertyName,
+    replacement: Option<PropertyName>,
+    ctx: &mut TxContext,
+) {
+    assert!(self.is_property_in_federation(property_name), EPropertyNotInFederation);
+    if (replacement.is_some()) {
+        let successor = *replacement.borrow();
+        assert!(successor != property_name, EInvalidPropertyReplacement);
+        assert!(self.is_property_in_federation(successor), EInvalidPropertyReplacement);
+    };
 
-    assert!(self.root_authorities.length() > 1, ECannotRevokeLastRootAuthority);
+    if (self.governance.deprecated_properties.contains(&property_name)) {
+        self.governance.deprecated_properties.remove(&property_name);
+    };
+    self.governance.deprecated_properties.insert(property_name, replacement);
 
-    // Find and revoke the root authority
-    let mut idx = 0;
-    let mut found = false;
-    while (idx < self.root_authorities.length()) {
-        if (self.root_authorities[idx].account_id == account_id) {
-            let RootAuthority { id, account_id: removed_id } = vector::remove(
-                &mut self.root_authorities,
-                idx,
-            );
-            object::delete(id);
+    self.touch(ctx);
 
-            // Add to revocation list
-            vector::push_back(&mut self.revoked_root_authorities, removed_id);
+    event::emit(PropertyDeprecatedEvent {
+        federation_address: self.federation_id().to_address(),
+        property_name,
+        replacement,
+    });
+}
 
-            found = true;
-            break
-        };
-        idx = idx + 1;
+/// Creates or replaces a named value dictionary.
+/// Properties referencing the dictionary accept its new values right away.
+/// While a quorum is required, value dictionaries are set through `execute_action`.
+/// Only root authorities can perform this operation.
+public fun set_value_dictionary(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    name: String,
+    values: VecSet<PropertyValue>,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+    self.apply_value_dictionary(name, values, ctx);
+}
+
+fun apply_value_dictionary(
+    self: &mut Federation,
+    name: String,
+    values: VecSet<PropertyValue>,
+    ctx: &mut TxContext,
+) {
+    assert!(values.keys().length() > 0, EEmptyValueDictionary);
+
+    if (self.governance.value_dictionaries.contains(&name)) {
+        *self.governance.value_dictionaries.get_mut(&name) = values;
+    } else {
+        self.governance.value_dictionaries.insert(name, values);
     };
 
-    assert!(found, ERootAuthorityNotFound);
+    self.touch(ctx);
 
-    event::emit(RootAuthorityRevokedEvent {
+    event::emit(ValueDictionarySetEvent {
         federation_address: self.federation_id().to_address(),
-        account_id,
+        name,
+        values,
     });
 }
 
-/// Reinstates a previously revoked root authority to the federation.
-/// Only existing root authorities can perform this operation.
-/// The account must be in the revoked list to be reinstated.
-public fun reinstate_root_authority(
+/// Removes a value dictionary that is no longer referenced by any property.
+/// Only root authorities can perform this operation.
+public fun remove_value_dictionary(
     self: &mut Federation,
     cap: &RootAuthorityCap,
-    account_id: ID,
+    name: String,
     ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(self.governance.value_dictionaries.contains(&name), EValueDictionaryNotFound);
+    assert!(!self.is_value_dictionary_in_use(&name), EValueDictionaryInUse);
 
-    assert!(!self.is_root_authority(&account_id), EAlreadyRootAuthority);
+    self.governance.value_dictionaries.remove(&name);
 
-    let mut idx = 0;
-    let mut found = false;
-    while (idx < self.revoked_root_authorities.length()) {
-        if (self.revoked_root_authorities[idx] == account_id) {
-            vector::remove(&mut self.revoked_root_authorities, idx);
-            found = true;
-            break
-        };
-        idx = idx + 1;
-    };
+    self.touch(ctx);
 
-    assert!(found, ENotRevokedRootAuthority);
+    event::emit(ValueDictionaryRemovedEvent {
+        federation_address: self.federation_id().to_address(),
+        name,
+    });
+}
 
-    let root_authority = new_root_authority(account_id, ctx);
-    vector::push_back(&mut self.root_authorities, root_authority);
+/// Replaces the tags of a property, an empty set removes all tags.
+/// Only root authorities can perform this operation.
+public fun set_property_tags(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    property_name: PropertyName,
+    tags: vector<String>,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(self.is_property_in_federation(property_name), EPropertyNotInFederation);
+    let tags = vec_set::from_keys(tags);
+    assert_valid_tags(&tags);
 
-    event::emit(RootAuthorityReinstatedEvent {
+    if (self.governance.property_tags.contains(&property_name)) {
+        self.governance.property_tags.remove(&property_name);
+    };
+    if (tags.size() > 0) {
+        self.governance.property_tags.insert(property_name, tags);
+    };
+
+    self.touch(ctx);
+
+    event::emit(PropertyTagsSetEvent {
         federation_address: self.federation_id().to_address(),
-        account_id,
-        reinstated_by: ctx.sender().to_id(),
+        property_name,
+        tags,
     });
 }
 
-/// Grants accreditation rights to another entity.
-/// Allows the receiver to delegate accreditation permissions to others.
-public fun create_accreditation_to_accredit(
+/// Replaces the metadata of a property, e.g. its `display_name`, `description`, `unit`,
+/// `data_type` or `locale`. Empty keys and values remove all metadata.
+/// The metadata is informational only and never used by validation.
+/// Only root authorities can perform this operation.
+public fun set_property_metadata(
     self: &mut Federation,
-    cap: &AccreditCap,
-    receiver: ID,
-    want_properties: vector<FederationProperty>,
-    clock: &Clock,
+    cap: &RootAuthorityCap,
+    property_name: PropertyName,
+    keys: vector<String>,
+    values: vector<String>,
     ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
-    let current_time_ms = clock.timestamp_ms();
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(self.is_property_in_federation(property_name), EPropertyNotInFederation);
+    assert!(keys.length() <= MAX_PROPERTY_METADATA_ENTRIES, EInvalidMetadata);
+    let metadata = vec_map::from_keys_values(keys, values);
+    assert_valid_property_metadata(&metadata);
 
-    // Validate that all property names exist in federation and are not revoked
-    let mut idx = 0;
-    while (idx < want_properties.length()) {
-        let property = &want_properties[idx];
-        assert!(
-            self.is_property_in_federation(*property.property_name()),
-            EPropertyNotInFederation,
-        );
+    self.governance.properties.data_mut().get_mut(&property_name).set_metadata(metadata);
 
-        // Check if property is revoked
-        let federation_property = self.governance.properties.data().get(property.property_name());
-        assert!(federation_property.is_valid_at_time(current_time_ms), EPropertyRevoked);
+    self.touch(ctx);
 
-        idx = idx + 1;
+    event::emit(PropertyMetadataSetEvent {
+        federation_address: self.federation_id().to_address(),
+        property_name,
+        metadata,
+    });
+}
+
+/// Replaces the tags of an accreditation, an empty set removes all tags.
+/// Only the issuer of the accreditation or a root authority can perform this operation.
+public fun set_accreditation_tags(
+    self: &mut Federation,
+    accreditation_id: ID,
+    tags: vector<String>,
+    ctx: &mut TxContext,
+) {
+    let issuer = self.find_accreditation_issuer(&accreditation_id);
+    assert!(issuer.is_some(), EAccreditationNotFound);
+    assert!(
+        self.is_root_authority(&ctx.sender().to_id()) ||
+            *issuer.borrow() == ctx.sender().to_string(),
+        ENotAccreditationIssuer,
+    );
+    let tags = vec_set::from_keys(tags);
+    assert_valid_tags(&tags);
+
+    if (self.governance.accreditation_tags.contains(&accreditation_id)) {
+        self.governance.accreditation_tags.remove(&accreditation_id);
+    };
+    if (tags.size() > 0) {
+        self.governance.accreditation_tags.insert(accreditation_id, tags);
     };
 
-    // Check permissions only if sender is not a root authority
-    if (!self.is_root_authority(&ctx.sender().to_id())) {
-        assert!(
-            self.is_accreditor(&ctx.sender().to_id()),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
-        let accreditations_to_accredit = self.get_accreditations_to_accredit(
-            &ctx.sender().to_id(),
-        );
-        assert!(
-            accreditations_to_accredit.are_properties_compliant(
-                &want_properties,
-                current_time_ms,
-            ),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
+    self.touch(ctx);
+
+    event::emit(AccreditationTagsSetEvent {
+        federation_address: self.federation_id().to_address(),
+        accreditation_id,
+        tags,
+    });
+}
+
+/// Sets the defaults and limits applied to newly granted accreditations.
+/// Existing accreditations are not affected.
+/// A default validity must be positive and at most `MAX_DEFAULT_VALIDITY_MS`.
+/// Only root authorities can perform this operation.
+public fun set_grant_policy(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    default_validity_ms: Option<u64>,
+    allow_any_permitted: bool,
+    max_allowed_values: Option<u64>,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    if (default_validity_ms.is_some()) {
+        let validity_ms = *default_validity_ms.borrow();
+        assert!(validity_ms > 0 && validity_ms <= MAX_DEFAULT_VALIDITY_MS, EInvalidDefaultValidity);
     };
 
-    let accredited_property = accreditation::new_accreditation(want_properties, ctx);
-    if (self.governance.accreditations_to_accredit.contains(&receiver)) {
-        self
-            .governance
-            .accreditations_to_accredit
-            .get_mut(&receiver)
-            .add_accreditation(accredited_property);
-    } else {
-        let mut accreditations = accreditation::new_empty_accreditations();
-        accreditations.add_accreditation(accredited_property);
-        self.governance.accreditations_to_accredit.insert(receiver, accreditations);
+    let mut policy = new_grant_policy(default_validity_ms, allow_any_permitted, max_allowed_values);
+    // The objection window is set separately, see `set_objection_window`
+    policy.objection_window_ms = self.governance.grant_policy.objection_window_ms;
+    self.governance.grant_policy = policy;
+
+    self.touch(ctx);
+
+    event::emit(GrantPolicySetEvent {
+        federation_address: self.federation_id().to_address(),
+        policy,
+    });
+}
+
+/// Sets the delay between granting an accreditation and it becoming effective.
+/// Accreditations granted during the window are stored but ignored by validation until it ends,
+/// unless a root authority activates them early. Existing accreditations are not affected.
+/// Only root authorities can perform this operation.
+public fun set_objection_window(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    objection_window_ms: Option<u64>,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+
+    self.governance.grant_policy.objection_window_ms = objection_window_ms;
+
+    self.touch(ctx);
+
+    event::emit(GrantPolicySetEvent {
+        federation_address: self.federation_id().to_address(),
+        policy: self.governance.grant_policy,
+    });
+}
+
+/// Sets the metadata of the federation, replacing the previous one.
+/// Only root authorities can perform this operation.
+public fun set_federation_metadata(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    name: Option<String>,
+    description: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+
+    let metadata = new_federation_metadata(name, description, logo_uri, contact);
+    assert_valid_metadata(&metadata);
+    self.metadata = metadata;
+
+    self.touch(ctx);
+
+    event::emit(FederationMetadataSetEvent {
+        federation_address: self.federation_id().to_address(),
+        metadata,
+    });
+}
+
+/// Recognizes `child_federation_id` as a child federation.
+/// Trust chains ending at a root authority of the child federation are trusted like chains of
+/// this federation, so large consortiums can delegate to federations of their own.
+/// Only root authorities can perform this operation.
+public fun link_federation(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    child_federation_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(child_federation_id != self.federation_id(), ECannotLinkSelf);
+    assert!(!self.is_federation_recognized(&child_federation_id), EFederationAlreadyLinked);
+
+    self.governance.recognized_federations.insert(child_federation_id);
+
+    self.touch(ctx);
+
+    event::emit(FederationLinkedEvent {
+        federation_address: self.federation_id().to_address(),
+        child_federation_id,
+    });
+}
+
+/// Stops recognizing `child_federation_id` as a child federation.
+/// While a quorum is required, child federations are unlinked through `execute_action`.
+/// Only root authorities can perform this operation.
+public fun unlink_federation(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    child_federation_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+    self.remove_federation_link(child_federation_id, ctx);
+}
+
+fun remove_federation_link(self: &mut Federation, child_federation_id: ID, ctx: &mut TxContext) {
+    assert!(self.is_federation_recognized(&child_federation_id), EFederationNotLinked);
+
+    self.governance.recognized_federations.remove(&child_federation_id);
+
+    self.touch(ctx);
+
+    event::emit(FederationUnlinkedEvent {
+        federation_address: self.federation_id().to_address(),
+        child_federation_id,
+    });
+}
+
+/// Ends the objection window of an accreditation, making it effective immediately.
+/// Only root authorities can perform this operation.
+public fun activate_accreditation(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    accreditation_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+
+    let found =
+        activate_in(&mut self.governance.accreditations_to_attest, &accreditation_id) ||
+        activate_in(&mut self.governance.accreditations_to_accredit, &accreditation_id);
+    assert!(found, EAccreditationNotFound);
+
+    self.touch(ctx);
+
+    event::emit(AccreditationActivatedEvent {
+        federation_address: self.federation_id().to_address(),
+        accreditation_id,
+        activated_by: cap.account_id,
+    });
+}
+
+/// Adds a new root authority to the federation.
+/// Only existing root authorities can perform this operation.
+/// While a quorum is required, root authorities are added through `execute_action`.
+public fun add_root_authority(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    account_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+
+    self.insert_root_authority(account_id, ctx);
+}
+
+fun insert_root_authority(self: &mut Federation, account_id: ID, ctx: &mut TxContext) {
+    assert!(!self.is_root_authority(&account_id), EAlreadyRootAuthority);
+
+    let root_authority = new_root_authority(account_id, ctx);
+    vector::push_back(&mut self.root_authorities, root_authority);
+
+    let cap = new_root_authority_cap(self, account_id, ctx);
+    transfer::transfer(cap, account_id.to_address());
+
+    self.touch(ctx);
+
+    event::emit(RootAuthorityAddedEvent {
+        federation_address: self.federation_id().to_address(),
+        account_id,
+    });
+}
+
+/// Revokes a root authority from the federation.
+/// Only root authorities can perform this operation.
+/// Cannot revoke the last root authority to prevent lockout.
+public fun revoke_root_authority(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    account_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+    self.remove_root_authority(account_id, ctx);
+}
+
+/// Revokes a root authority like `revoke_root_authority` and records the reason of the revocation
+public fun revoke_root_authority_with_reason(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    account_id: ID,
+    reason_code: u64,
+    annotation: String,
+    ctx: &mut TxContext,
+) {
+    self.revoke_root_authority(cap, account_id, ctx);
+    self.emit_revocation_reason(reason_code, annotation, ctx);
+}
+
+/// Hands the root authority of `old_account_id` over to `new_account_id`.
+/// Adds the new root authority, sends it a new `RootAuthorityCap` and revokes the old one,
+/// so the number of root authorities is the same before and after the rotation.
+/// Only root authorities can perform this operation, including the rotated one itself.
+/// A revoked root authority is brought back with `reinstate_root_authority`, not by a rotation.
+public fun rotate_root_authority(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    old_account_id: ID,
+    new_account_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+    assert!(self.is_root_authority(&old_account_id), ERootAuthorityNotFound);
+    // A revoked account stays on the revocation list when added, so its capability would be rejected
+    assert!(!self.is_revoked_root_authority(&new_account_id), ERevokedRootAuthority);
+
+    self.add_root_authority(cap, new_account_id, ctx);
+    self.remove_root_authority(old_account_id, ctx);
+}
+
+fun remove_root_authority(self: &mut Federation, account_id: ID, ctx: &TxContext) {
+    assert!(self.is_root_authority(&account_id), ERootAuthorityNotFound);
+
+    assert!(self.root_authorities.length() > 1, ECannotRevokeLastRootAuthority);
+
+    // Find and revoke the root authority
+    let mut idx = 0;
+    let mut found = false;
+    while (idx < self.root_authorities.length()) {
+        if (self.root_authorities[idx].account_id == account_id) {
+            let RootAuthority { id, account_id: removed_id } = vector::remove(
+                &mut self.root_authorities,
+                idx,
+            );
+            object::delete(id);
+
+            // Add to revocation list
+            vector::push_back(&mut self.revoked_root_authorities, removed_id);
+
+            found = true;
+            break
+        };
+        idx = idx + 1;
+    };
+
+    assert!(found, ERootAuthorityNotFound);
+
+    self.touch(ctx);
+
+    event::emit(RootAuthorityRevokedEvent {
+        federation_address: self.federation_id().to_address(),
+        account_id,
+    });
+}
+
+/// Reinstates a previously revoked root authority to the federation.
+/// Only existing root authorities can perform this operation.
+/// The account must be in the revoked list to be reinstated.
+/// While a quorum is required, root authorities are reinstated through `execute_action`.
+public fun reinstate_root_authority(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    account_id: ID,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+
+    self.restore_root_authority(account_id, ctx);
+}
+
+fun restore_root_authority(self: &mut Federation, account_id: ID, ctx: &mut TxContext) {
+    assert!(!self.is_root_authority(&account_id), EAlreadyRootAuthority);
+
+    let mut idx = 0;
+    let mut found = false;
+    while (idx < self.revoked_root_authorities.length()) {
+        if (self.revoked_root_authorities[idx] == account_id) {
+            vector::remove(&mut self.revoked_root_authorities, idx);
+            found = true;
+            break
+        };
+        idx = idx + 1;
+    };
+
+    assert!(found, ENotRevokedRootAuthority);
+
+    let root_authority = new_root_authority(account_id, ctx);
+    vector::push_back(&mut self.root_authorities, root_authority);
+
+    self.touch(ctx);
+
+    event::emit(RootAuthorityReinstatedEvent {
+        federation_address: self.federation_id().to_address(),
+        account_id,
+        reinstated_by: ctx.sender().to_id(),
+    });
+}
+
+/// Grants accreditation rights to another entity.
+/// Allows the receiver to delegate accreditation permissions to others.
+public fun create_accreditation_to_accredit(
+    self: &mut Federation,
+    cap: &AccreditCap,
+    receiver: ID,
+    mut want_properties: vector<FederationProperty>,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    let current_time_ms = clock.timestamp_ms();
+
+    // Validate that all property names exist in federation and are not revoked
+    let mut idx = 0;
+    while (idx < want_properties.length()) {
+        let property = &want_properties[idx];
+        // Wildcards cover properties added later on, the properties are checked on validation
+        if (property.property_name().is_wildcard()) {
+            assert!(property.value_dictionary().is_none(), EValueDictionaryMismatch);
+            idx = idx + 1;
+            continue
+        };
+        assert!(
+            self.is_property_in_federation(*property.property_name()),
+            EPropertyNotInFederation,
+        );
+
+        // Check if property is revoked
+        let federation_property = self.governance.properties.data().get(property.property_name());
+        assert!(federation_property.is_valid_at_time(current_time_ms), EPropertyRevoked);
+        assert!(
+            property.value_dictionary().is_none() ||
+                property.value_dictionary() == federation_property.value_dictionary(),
+            EValueDictionaryMismatch,
+        );
+
+        idx = idx + 1;
+    };
+
+    // Check permissions only if sender is not a root authority
+    if (!self.is_root_authority(&ctx.sender().to_id())) {
+        assert!(
+            self.is_accreditor(&ctx.sender().to_id()),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+        let accreditations_to_accredit = self.get_accreditations_to_accredit(
+            &ctx.sender().to_id(),
+        );
+        assert!(
+            accreditations_to_accredit.are_properties_compliant(
+                &want_properties,
+                current_time_ms,
+            ),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+    };
+
+    self.apply_grant_policy(&mut want_properties, current_time_ms);
+
+    let mut accredited_property = accreditation::new_accreditation(want_properties, ctx);
+    self.apply_objection_window(&mut accredited_property, current_time_ms);
+    if (self.governance.accreditations_to_accredit.contains(&receiver)) {
+        self
+            .governance
+            .accreditations_to_accredit
+            .get_mut(&receiver)
+            .add_accreditation(accredited_property);
+    } else {
+        let mut accreditations = accreditation::new_empty_accreditations();
+        accreditations.add_accreditation(accredited_property);
+        self.governance.accreditations_to_accredit.insert(receiver, accreditations);
+
+        // Create and transfer capability
+        transfer::transfer(self.new_cap_accredit(ctx), receiver.to_address());
+    };
+
+    self.stats.accreditation_to_accredit_count = self.stats.accreditation_to_accredit_count + 1;
+    self.touch(ctx);
+
+    event::emit(AccreditationToAccreditCreatedEvent {
+        federation_address: self.federation_id().to_address(),
+        receiver,
+        accreditor: ctx.sender().to_id(),
+    });
+}
+
+/// Grants attestation rights to another entity.
+/// Allows the receiver to create trusted attestations.
+public fun create_accreditation_to_attest(
+    self: &mut Federation,
+    cap: &AccreditCap,
+    receiver: ID,
+    mut wanted_properties: vector<FederationProperty>,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    let current_time_ms = clock.timestamp_ms();
+
+    // Validate that all property names exist in federation and are not revoked
+    let mut idx = 0;
+    while (idx < wanted_properties.length()) {
+        let property = &wanted_properties[idx];
+        // Wildcards cover properties added later on, the properties are checked on validation
+        if (property.property_name().is_wildcard()) {
+            assert!(property.value_dictionary().is_none(), EValueDictionaryMismatch);
+            idx = idx + 1;
+            continue
+        };
+        assert!(
+            self.is_property_in_federation(*property.property_name()),
+            EPropertyNotInFederation,
+        );
+
+        // Check if property is revoked
+        let federation_property = self.governance.properties.data().get(property.property_name());
+        assert!(federation_property.is_valid_at_time(current_time_ms), EPropertyRevoked);
+        assert!(
+            property.value_dictionary().is_none() ||
+                property.value_dictionary() == federation_property.value_dictionary(),
+            EValueDictionaryMismatch,
+        );
+
+        idx = idx + 1;
+    };
+
+    // Check permissions only if sender is not a root authority
+    if (!self.is_root_authority(&ctx.sender().to_id())) {
+        assert!(
+            self.is_accreditor(&ctx.sender().to_id()),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+        let accreditations_to_accredit = self.get_accreditations_to_accredit(
+            &ctx.sender().to_id(),
+        );
+        assert!(
+            accreditations_to_accredit.are_properties_compliant(
+                &wanted_properties,
+                current_time_ms,
+            ),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+    };
+
+    self.apply_grant_policy(&mut wanted_properties, current_time_ms);
+
+    let mut accredited_property = accreditation::new_accreditation(wanted_properties, ctx);
+    self.apply_objection_window(&mut accredited_property, current_time_ms);
+
+    if (self.governance.accreditations_to_attest.contains(&receiver)) {
+        self
+            .governance
+            .accreditations_to_attest
+            .get_mut(&receiver)
+            .add_accreditation(accredited_property);
+    } else {
+        let mut accreditations_to_attest = accreditation::new_empty_accreditations();
+        accreditations_to_attest.add_accreditation(accredited_property);
+        self.governance.accreditations_to_attest.insert(receiver, accreditations_to_attest);
+    };
+
+    self.stats.accreditation_to_attest_count = self.stats.accreditation_to_attest_count + 1;
+    self.touch(ctx);
+
+    event::emit(AccreditationToAttestCreatedEvent {
+        federation_address: self.federation_id().to_address(),
+        receiver,
+        accreditor: ctx.sender().to_id(),
+    });
+}
+
+/// Revokes attestation rights from an entity
+public fun revoke_accreditation_to_attest(
+    self: &mut Federation,
+    cap: &AccreditCap,
+    entity_id: &ID,
+    permission_id: &ID,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    let current_time_ms = clock.timestamp_ms();
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+
+    // Check revocation permissions
+    if (!self.is_root_authority(&ctx.sender().to_id())) {
+        assert!(
+            self.is_accreditor(&ctx.sender().to_id()),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+    };
+
+    // Check if entity has attestation permissions
+    assert!(self.is_attester(entity_id), EAccreditationNotFound);
+
+    let entities_attest_permissions = self.get_accreditations_to_attest(entity_id);
+    let mut accreditation_to_revoke_idx = entities_attest_permissions.find_accredited_property_id(
+        permission_id,
+    );
+    assert!(accreditation_to_revoke_idx.is_some(), EAccreditationNotFound);
+
+    // Check revocation permissions
+    if (!self.is_root_authority(&ctx.sender().to_id())) {
+        let accreditation_to_revoke =
+            &entities_attest_permissions.accredited_properties()[
+                accreditation_to_revoke_idx.extract(),
+            ];
+        let (_, properties) = (*accreditation_to_revoke.properties()).into_keys_values();
+        let remover_accreditations = self.get_accreditations_to_accredit(&ctx.sender().to_id());
+        assert!(
+            remover_accreditations.are_properties_compliant(&properties, current_time_ms),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+    };
+
+    let entities_attest_permissions = self.governance.accreditations_to_attest.get_mut(entity_id);
+    entities_attest_permissions.remove_accredited_property(permission_id);
+    if (self.governance.accreditation_tags.contains(permission_id)) {
+        self.governance.accreditation_tags.remove(permission_id);
+    };
+
+    self.stats.accreditation_to_attest_count = self.stats.accreditation_to_attest_count - 1;
+    self.touch(ctx);
+
+    event::emit(AccreditationToAttestRevokedEvent {
+        federation_address: self.federation_id().to_address(),
+        entity_id: *entity_id,
+        permission_id: *permission_id,
+        revoker: ctx.sender().to_id(),
+    });
+}
+
+/// Revokes accreditation rights from an entity
+public fun revoke_accreditation_to_accredit(
+    self: &mut Federation,
+    cap: &AccreditCap,
+    entity_id: &ID,
+    permission_id: &ID,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+
+    // Check if sender has accreditation permissions
+    if (!self.is_root_authority(&ctx.sender().to_id())) {
+        assert!(
+            self.is_accreditor(&ctx.sender().to_id()),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+    };
+
+    // Check if entity has accreditation permissions
+    assert!(self.is_accreditor(entity_id), EAccreditationNotFound);
+
+    let entities_accredit_permissions = self.get_accreditations_to_accredit(entity_id);
+    let mut accreditation_to_revoke_idx = entities_accredit_permissions.find_accredited_property_id(
+        permission_id,
+    );
+    assert!(accreditation_to_revoke_idx.is_some(), EAccreditationNotFound);
+
+    // Check revocation permissions
+    if (!self.is_root_authority(&ctx.sender().to_id())) {
+        let accreditation_to_revoke =
+            &entities_accredit_permissions.accredited_properties()[
+                accreditation_to_revoke_idx.extract(),
+            ];
+        let current_time_ms = clock.timestamp_ms();
+        let (_, properties) = (*accreditation_to_revoke.properties()).into_keys_values();
+        let remover_permissions = self.get_accreditations_to_accredit(&ctx.sender().to_id());
+        assert!(
+            remover_permissions.are_properties_compliant(&properties, current_time_ms),
+            EUnauthorizedInsufficientAccreditationToAccredit,
+        );
+    };
+
+    let entities_accredit_permissions = self
+        .governance
+        .accreditations_to_accredit
+        .get_mut(entity_id);
+    entities_accredit_permissions.remove_accredited_property(permission_id);
+    if (self.governance.accreditation_tags.contains(permission_id)) {
+        self.governance.accreditation_tags.remove(permission_id);
+    };
+
+    self.stats.accreditation_to_accredit_count = self.stats.accreditation_to_accredit_count - 1;
+    self.touch(ctx);
+
+    event::emit(AccreditationToAccreditRevokedEvent {
+        federation_address: self.federation_id().to_address(),
+        entity_id: *entity_id,
+        permission_id: *permission_id,
+        revoker: ctx.sender().to_id(),
+    });
+}
+
+/// Revokes attestation rights like `revoke_accreditation_to_attest` and records the reason of the revocation
+public fun revoke_accreditation_to_attest_with_reason(
+    self: &mut Federation,
+    cap: &AccreditCap,
+    entity_id: &ID,
+    permission_id: &ID,
+    reason_code: u64,
+    annotation: String,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    self.revoke_accreditation_to_attest(cap, entity_id, permission_id, clock, ctx);
+    self.emit_revocation_reason(reason_code, annotation, ctx);
+}
+
+/// Revokes accreditation rights like `revoke_accreditation_to_accredit` and records the reason of the revocation
+public fun revoke_accreditation_to_accredit_with_reason(
+    self: &mut Federation,
+    cap: &AccreditCap,
+    entity_id: &ID,
+    permission_id: &ID,
+    reason_code: u64,
+    annotation: String,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    self.revoke_accreditation_to_accredit(cap, entity_id, permission_id, clock, ctx);
+    self.emit_revocation_reason(reason_code, annotation, ctx);
+}
+
+fun emit_revocation_reason(self: &Federation, reason_code: u64, annotation: String, ctx: &TxContext) {
+    assert!(annotation.length() <= MAX_ANNOTATION_LENGTH, EAnnotationTooLong);
+
+    event::emit(RevocationReasonEvent {
+        federation_address: self.federation_id().to_address(),
+        reason_code,
+        annotation,
+        revoked_by: ctx.sender().to_id(),
+    });
+}
+
+// ===== Attestations =====
+
+/// Issues an attestation of `properties` to `receiver`.
+/// The sender must be allowed to attest all properties, see `validate_properties`.
+/// The attestation is valid from `valid_from_ms`, or right away, until `valid_until_ms`, or until revoked.
+public fun create_attestation(
+    self: &mut Federation,
+    receiver: address,
+    properties: VecMap<PropertyName, PropertyValue>,
+    valid_from_ms: Option<u64>,
+    valid_until_ms: Option<u64>,
+    clock: &Clock,
+    ctx: &mut TxContext,
+) {
+    let issuer = ctx.sender().to_id();
+    assert!(!properties.is_empty(), EEmptyAttestation);
+    assert!(self.validate_properties(&issuer, properties, clock), EUnauthorizedAttester);
+    assert!(
+        valid_from_ms.is_none() || valid_until_ms.is_none() ||
+            *valid_from_ms.borrow() < *valid_until_ms.borrow(),
+        EInvalidValidityWindow,
+    );
+
+    let attestation = attestation::new_attestation(
+        self.federation_id(),
+        properties,
+        valid_from_ms,
+        valid_until_ms,
+        clock.timestamp_ms(),
+        ctx,
+    );
+    let attestation_id = object::id(&attestation);
+
+    dynamic_field::add(&mut self.id, attestation::new_key(attestation_id), attestation::new_record(issuer));
+    self.touch(ctx);
+
+    event::emit(AttestationCreatedEvent {
+        federation_address: self.federation_id().to_address(),
+        attestation_id,
+        issuer,
+        receiver,
+    });
+
+    transfer::transfer(attestation, receiver);
+}
+
+/// Revokes an attestation. Only its issuer and root authorities can revoke it.
+public fun revoke_attestation(self: &mut Federation, attestation_id: ID, ctx: &mut TxContext) {
+    let key = attestation::new_key(attestation_id);
+    assert!(
+        dynamic_field::exists_with_type<AttestationKey, AttestationRecord>(&self.id, key),
+        EAttestationNotFound,
+    );
+
+    let revoker = ctx.sender().to_id();
+    let is_root_authority = self.is_root_authority(&revoker);
+    let record: &mut AttestationRecord = dynamic_field::borrow_mut(&mut self.id, key);
+    assert!(!record.is_revoked(), EAttestationRevoked);
+    assert!(is_root_authority || record.record_issuer() == revoker, EUnauthorizedAttester);
+    record.set_revoked();
+    self.touch(ctx);
+
+    event::emit(AttestationRevokedEvent {
+        federation_address: self.federation_id().to_address(),
+        attestation_id,
+        revoker,
+    });
+}
+
+/// Checks if an attestation issued in the federation is revoked.
+/// Returns false for attestations not issued in the federation.
+public fun is_attestation_revoked(self: &Federation, attestation_id: ID): bool {
+    let key = attestation::new_key(attestation_id);
+    if (!dynamic_field::exists_with_type<AttestationKey, AttestationRecord>(&self.id, key)) {
+        return false
+    };
+    let record: &AttestationRecord = dynamic_field::borrow(&self.id, key);
+    record.is_revoked()
+}
+
+/// Validates an attestation.
+/// Returns true if it was issued in the federation, is not revoked, is within its validity window
+/// and its issuer is still allowed to attest all of its properties, false otherwise
+public fun validate_attestation(self: &Federation, attestation: &Attestation, clock: &Clock): bool {
+    let attestation_id = object::id(attestation);
+    let key = attestation::new_key(attestation_id);
+    if (attestation.federation_id() != self.federation_id()) {
+        return false
+    };
+    if (!dynamic_field::exists_with_type<AttestationKey, AttestationRecord>(&self.id, key)) {
+        return false
+    };
+    if (self.is_attestation_revoked(attestation_id)) {
+        return false
+    };
+    if (!attestation.is_valid_at_time(clock.timestamp_ms())) {
+        return false
+    };
+
+    self.validate_properties(&attestation.issuer(), *attestation.properties(), clock)
+}
+
+// ===== Quorum Governance =====
+
+/// Creates the action revoking the root authority `account_id`.
+public fun new_action_revoke_root_authority(account_id: ID): GovernanceAction {
+    GovernanceAction::RevokeRootAuthority { account_id }
+}
+
+/// Creates the action revoking `property_name` at `valid_to_ms`, or on execution if none.
+public fun new_action_revoke_property(
+    property_name: PropertyName,
+    valid_to_ms: Option<u64>,
+): GovernanceAction {
+    GovernanceAction::RevokeProperty { property_name, valid_to_ms }
+}
+
+/// Creates the action changing the quorum threshold.
+public fun new_action_set_quorum_threshold(threshold: u64): GovernanceAction {
+    GovernanceAction::SetQuorumThreshold { threshold }
+}
+
+/// Creates the action adding the root authority `account_id`.
+public fun new_action_add_root_authority(account_id: ID): GovernanceAction {
+    GovernanceAction::AddRootAuthority { account_id }
+}
+
+/// Creates the action reinstating the revoked root authority `account_id`.
+public fun new_action_reinstate_root_authority(account_id: ID): GovernanceAction {
+    GovernanceAction::ReinstateRootAuthority { account_id }
+}
+
+/// Creates the action setting the value dictionary `name` to `values`.
+public fun new_action_set_value_dictionary(
+    name: String,
+    values: VecSet<PropertyValue>,
+): GovernanceAction {
+    GovernanceAction::SetValueDictionary { name, values }
+}
+
+/// Creates the action deprecating `property_name`, optionally naming its replacement.
+public fun new_action_deprecate_property(
+    property_name: PropertyName,
+    replacement: Option<PropertyName>,
+): GovernanceAction {
+    GovernanceAction::DeprecateProperty { property_name, replacement }
+}
+
+/// Creates the action unlinking the child federation `child_federation_id`.
+public fun new_action_unlink_federation(child_federation_id: ID): GovernanceAction {
+    GovernanceAction::UnlinkFederation { child_federation_id }
+}
+
+/// Sets the number of root authority approvals required for destructive actions.
+/// With a threshold above 1, `add_root_authority`, `revoke_root_authority`,
+/// `reinstate_root_authority`, `revoke_property`, `revoke_property_at`, `set_value_dictionary`,
+/// `deprecate_property` and `unlink_federation` are rejected and the actions go through
+/// `propose_action`, `approve_action` and `execute_action`.
+/// Once a quorum is required, the threshold itself is changed through a proposal.
+/// Only root authorities can perform this operation.
+public fun set_quorum_threshold(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    threshold: u64,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(!self.is_quorum_required(), EQuorumRequired);
+
+    self.apply_quorum_threshold(threshold);
+    self.touch(ctx);
+}
+
+/// Proposes a governance action, approved by the proposer.
+/// Returns the ID of the proposal.
+public fun propose_action(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    action: GovernanceAction,
+    ctx: &mut TxContext,
+): u64 {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(self.is_root_authority(&cap.account_id), ERevokedRootAuthority);
+
+    let proposal_id = self.governance.quorum.next_proposal_id;
+    self.governance.quorum.next_proposal_id = proposal_id + 1;
+    self
+        .governance
+        .quorum
+        .proposals
+        .insert(
+            proposal_id,
+            Proposal {
+                action,
+                proposed_by: cap.account_id,
+                approvals: vec_set::singleton(cap.account_id),
+            },
+        );
+
+    self.touch(ctx);
+
+    event::emit(ActionProposedEvent {
+        federation_address: self.federation_id().to_address(),
+        proposal_id,
+        action,
+        proposed_by: cap.account_id,
+    });
+    proposal_id
+}
+
+/// Approves a proposed governance action.
+public fun approve_action(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    proposal_id: u64,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(self.is_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(self.governance.quorum.proposals.contains(&proposal_id), EProposalNotFound);
+
+    let proposal = self.governance.quorum.proposals.get_mut(&proposal_id);
+    assert!(!proposal.approvals.contains(&cap.account_id), EAlreadyApproved);
+    proposal.approvals.insert(cap.account_id);
+
+    self.touch(ctx);
 
-        // Create and transfer capability
-        transfer::transfer(self.new_cap_accredit(ctx), receiver.to_address());
-    };
-    event::emit(AccreditationToAccreditCreatedEvent {
+    event::emit(ActionApprovedEvent {
         federation_address: self.federation_id().to_address(),
-        receiver,
-        accreditor: ctx.sender().to_id(),
+        proposal_id,
+        approved_by: cap.account_id,
     });
 }
 
-/// Grants attestation rights to another entity.
-/// Allows the receiver to create trusted attestations.
-public fun create_accreditation_to_attest(
+/// Executes a governance action approved by at least `threshold` active root authorities.
+/// Approvals of root authorities revoked since do not count.
+public fun execute_action(
     self: &mut Federation,
-    cap: &AccreditCap,
-    receiver: ID,
-    wanted_properties: vector<FederationProperty>,
+    cap: &RootAuthorityCap,
+    proposal_id: u64,
     clock: &Clock,
     ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
-    let current_time_ms = clock.timestamp_ms();
+    assert!(self.is_root_authority(&cap.account_id), ERevokedRootAuthority);
+    assert!(self.governance.quorum.proposals.contains(&proposal_id), EProposalNotFound);
+    assert!(
+        self.count_active_approvals(&proposal_id) >= self.governance.quorum.threshold,
+        EQuorumNotReached,
+    );
 
-    // Validate that all property names exist in federation and are not revoked
-    let mut idx = 0;
-    while (idx < wanted_properties.length()) {
-        let property = &wanted_properties[idx];
-        assert!(
-            self.is_property_in_federation(*property.property_name()),
-            EPropertyNotInFederation,
-        );
+    let (_, proposal) = self.governance.quorum.proposals.remove(&proposal_id);
+    match (proposal.action) {
+        GovernanceAction::RevokeRootAuthority { account_id } => {
+            // Keep enough root authorities to reach the quorum
+            assert!(
+                self.root_authorities.length() > self.governance.quorum.threshold,
+                EInvalidQuorumThreshold,
+            );
+            self.remove_root_authority(account_id, ctx);
+        },
+        GovernanceAction::RevokeProperty { property_name, valid_to_ms } => {
+            let now_ms = clock.timestamp_ms();
+            if (valid_to_ms.is_some()) {
+                assert!(
+                    *valid_to_ms.borrow() > now_ms + TIME_BUFFER_MS,
+                    ETimestampMustBeInTheFuture,
+                );
+            };
+            self.revoke_property_until(property_name, valid_to_ms.get_with_default(now_ms), ctx);
+        },
+        GovernanceAction::SetQuorumThreshold { threshold } => {
+            self.apply_quorum_threshold(threshold);
+            self.touch(ctx);
+        },
+        GovernanceAction::AddRootAuthority { account_id } => {
+            self.insert_root_authority(account_id, ctx);
+        },
+        GovernanceAction::ReinstateRootAuthority { account_id } => {
+            self.restore_root_authority(account_id, ctx);
+        },
+        GovernanceAction::SetValueDictionary { name, values } => {
+            self.apply_value_dictionary(name, values, ctx);
+        },
+        GovernanceAction::DeprecateProperty { property_name, replacement } => {
+            self.apply_property_deprecation(property_name, replacement, ctx);
+        },
+        GovernanceAction::UnlinkFederation { child_federation_id } => {
+            self.remove_federation_link(child_federation_id, ctx);
+        },
+    };
 
-        // Check if property is revoked
-        let federation_property = self.governance.properties.data().get(property.property_name());
-        assert!(federation_property.is_valid_at_time(current_time_ms), EPropertyRevoked);
+    event::emit(ActionExecutedEvent {
+        federation_address: self.federation_id().to_address(),
+        proposal_id,
+        action: proposal.action,
+        executed_by: cap.account_id,
+    });
+}
+
+fun apply_quorum_threshold(self: &mut Federation, threshold: u64) {
+    assert!(
+        threshold > 0 && threshold <= self.root_authorities.length(),
+        EInvalidQuorumThreshold,
+    );
+    self.governance.quorum.threshold = threshold;
+
+    event::emit(QuorumThresholdSetEvent {
+        federation_address: self.federation_id().to_address(),
+        threshold,
+    });
+}
 
+fun count_active_approvals(self: &Federation, proposal_id: &u64): u64 {
+    let approvals = self.governance.quorum.proposals.get(proposal_id).approvals.keys();
+    let mut count = 0;
+    let mut idx = 0;
+    while (idx < approvals.length()) {
+        if (self.is_root_authority(&approvals[idx])) {
+            count = count + 1;
+        };
         idx = idx + 1;
     };
+    count
+}
 
-    // Check permissions only if sender is not a root authority
-    if (!self.is_root_authority(&ctx.sender().to_id())) {
-        assert!(
-            self.is_accreditor(&ctx.sender().to_id()),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
-        let accreditations_to_accredit = self.get_accreditations_to_accredit(
-            &ctx.sender().to_id(),
-        );
-        assert!(
-            accreditations_to_accredit.are_properties_compliant(
-                &wanted_properties,
-                current_time_ms,
-            ),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
-    };
+// ===== Capability Transfer =====
 
-    let accredited_property = accreditation::new_accreditation(wanted_properties, ctx);
+/// Confirms the transfer of the capability `capability_id` to `recipient`.
+/// While a quorum is required, capabilities can only be transferred with the confirmation
+/// of a root authority other than the holder. A later confirmation replaces an earlier one.
+public fun confirm_capability_transfer(
+    self: &mut Federation,
+    cap: &RootAuthorityCap,
+    capability_id: ID,
+    recipient: address,
+    ctx: &mut TxContext,
+) {
+    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(self.is_root_authority(&cap.account_id), ERevokedRootAuthority);
 
-    if (self.governance.accreditations_to_attest.contains(&receiver)) {
-        self
-            .governance
-            .accreditations_to_attest
-            .get_mut(&receiver)
-            .add_accreditation(accredited_property);
+    let confirmation = TransferConfirmation { recipient, confirmed_by: cap.account_id };
+    let confirmations = &mut self.governance.quorum.transfer_confirmations;
+    if (confirmations.contains(&capability_id)) {
+        *confirmations.get_mut(&capability_id) = confirmation;
     } else {
-        let mut accreditations_to_attest = accreditation::new_empty_accreditations();
-        accreditations_to_attest.add_accreditation(accredited_property);
-        self.governance.accreditations_to_attest.insert(receiver, accreditations_to_attest);
+        confirmations.insert(capability_id, confirmation);
     };
 
-    event::emit(AccreditationToAttestCreatedEvent {
+    self.touch(ctx);
+
+    event::emit(CapabilityTransferConfirmedEvent {
         federation_address: self.federation_id().to_address(),
-        receiver,
-        accreditor: ctx.sender().to_id(),
+        capability_id,
+        recipient,
+        confirmed_by: cap.account_id,
     });
 }
 
-/// Revokes attestation rights from an entity
-public fun revoke_accreditation_to_attest(
+/// Transfers a root authority capability to `recipient`, e.g. to rotate the key of a root authority.
+/// The root authority and the accreditations of the holder move to the recipient along with the
+/// capability, so the old account loses its rights.
+public fun transfer_root_authority_cap(
     self: &mut Federation,
-    cap: &AccreditCap,
-    entity_id: &ID,
-    permission_id: &ID,
-    clock: &Clock,
+    mut cap: RootAuthorityCap,
+    recipient: address,
     ctx: &mut TxContext,
 ) {
-    let current_time_ms = clock.timestamp_ms();
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
+    assert!(self.is_root_authority(&cap.account_id), ERevokedRootAuthority);
 
-    // Check revocation permissions
-    if (!self.is_root_authority(&ctx.sender().to_id())) {
-        assert!(
-            self.is_accreditor(&ctx.sender().to_id()),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
-    };
-
-    // Check if entity has attestation permissions
-    assert!(self.is_attester(entity_id), EAccreditationNotFound);
-
-    let entities_attest_permissions = self.get_accreditations_to_attest(entity_id);
-    let mut accreditation_to_revoke_idx = entities_attest_permissions.find_accredited_property_id(
-        permission_id,
+    let from = cap.account_id;
+    let to = recipient.to_id();
+    assert!(
+        to != from && !self.is_root_authority(&to) && !self.is_revoked_root_authority(&to),
+        EInvalidTransferRecipient,
     );
-    assert!(accreditation_to_revoke_idx.is_some(), EAccreditationNotFound);
+    self.consume_transfer_confirmation(object::id(&cap), recipient, &from);
 
-    // Check revocation permissions
-    if (!self.is_root_authority(&ctx.sender().to_id())) {
-        let accreditation_to_revoke =
-            &entities_attest_permissions.accredited_properties()[
-                accreditation_to_revoke_idx.extract(),
-            ];
-        let (_, properties) = (*accreditation_to_revoke.properties()).into_keys_values();
-        let remover_accreditations = self.get_accreditations_to_accredit(&ctx.sender().to_id());
-        assert!(
-            remover_accreditations.are_properties_compliant(&properties, current_time_ms),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
+    let mut idx = 0;
+    while (idx < self.root_authorities.length()) {
+        if (self.root_authorities[idx].account_id == from) {
+            self.root_authorities[idx].account_id = to;
+        };
+        idx = idx + 1;
     };
+    self.move_accreditations(from, to);
 
-    let entities_attest_permissions = self.governance.accreditations_to_attest.get_mut(entity_id);
-    entities_attest_permissions.remove_accredited_property(permission_id);
+    let capability_id = object::id(&cap);
+    cap.account_id = to;
+    transfer::transfer(cap, recipient);
 
-    event::emit(AccreditationToAttestRevokedEvent {
+    self.touch(ctx);
+
+    event::emit(CapabilityTransferredEvent {
         federation_address: self.federation_id().to_address(),
-        entity_id: *entity_id,
-        permission_id: *permission_id,
-        revoker: ctx.sender().to_id(),
+        capability_id,
+        from: from.to_address(),
+        to: recipient,
     });
 }
 
-/// Revokes accreditation rights from an entity
-public fun revoke_accreditation_to_accredit(
+/// Transfers an accreditation capability to `recipient`, e.g. to rotate the key of an accreditor.
+/// The accreditations of the sender move to the recipient along with the capability.
+public fun transfer_accredit_cap(
     self: &mut Federation,
-    cap: &AccreditCap,
-    entity_id: &ID,
-    permission_id: &ID,
-    clock: &Clock,
+    cap: AccreditCap,
+    recipient: address,
     ctx: &mut TxContext,
 ) {
     assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
 
-    // Check if sender has accreditation permissions
-    if (!self.is_root_authority(&ctx.sender().to_id())) {
-        assert!(
-            self.is_accreditor(&ctx.sender().to_id()),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
-    };
-
-    // Check if entity has accreditation permissions
-    assert!(self.is_accreditor(entity_id), EAccreditationNotFound);
+    let from = ctx.sender().to_id();
+    let to = recipient.to_id();
+    assert!(to != from && !self.is_root_authority(&to), EInvalidTransferRecipient);
+    self.consume_transfer_confirmation(object::id(&cap), recipient, &from);
 
-    let entities_accredit_permissions = self.get_accreditations_to_accredit(entity_id);
-    let mut accreditation_to_revoke_idx = entities_accredit_permissions.find_accredited_property_id(
-        permission_id,
-    );
-    assert!(accreditation_to_revoke_idx.is_some(), EAccreditationNotFound);
+    self.move_accreditations(from, to);
 
-    // Check revocation permissions
-    if (!self.is_root_authority(&ctx.sender().to_id())) {
-        let accreditation_to_revoke =
-            &entities_accredit_permissions.accredited_properties()[
-                accreditation_to_revoke_idx.extract(),
-            ];
-        let current_time_ms = clock.timestamp_ms();
-        let (_, properties) = (*accreditation_to_revoke.properties()).into_keys_values();
-        let remover_permissions = self.get_accreditations_to_accredit(&ctx.sender().to_id());
-        assert!(
-            remover_permissions.are_properties_compliant(&properties, current_time_ms),
-            EUnauthorizedInsufficientAccreditationToAccredit,
-        );
-    };
+    let capability_id = object::id(&cap);
+    transfer::transfer(cap, recipient);
 
-    let entities_accredit_permissions = self
-        .governance
-        .accreditations_to_accredit
-        .get_mut(entity_id);
-    entities_accredit_permissions.remove_accredited_property(permission_id);
+    self.touch(ctx);
 
-    event::emit(AccreditationToAccreditRevokedEvent {
+    event::emit(CapabilityTransferredEvent {
         federation_address: self.federation_id().to_address(),
-        entity_id: *entity_id,
-        permission_id: *permission_id,
-        revoker: ctx.sender().to_id(),
+        capability_id,
+        from: from.to_address(),
+        to: recipient,
     });
 }
 
+/// Removes the confirmation of a transfer, asserting it exists while a quorum is required
+fun consume_transfer_confirmation(self: &mut Federation, capability_id: ID, recipient: address, holder: &ID) {
+    let confirmations = &mut self.governance.quorum.transfer_confirmations;
+    let confirmed = if (confirmations.contains(&capability_id)) {
+        let (_, confirmation) = confirmations.remove(&capability_id);
+        confirmation.recipient == recipient && confirmation.confirmed_by != *holder &&
+            self.is_root_authority(&confirmation.confirmed_by)
+    } else {
+        false
+    };
+    assert!(confirmed || !self.is_quorum_required(), ETransferNotConfirmed);
+}
+
+/// Moves the accreditations of `from` to `to`, which must not hold any
+fun move_accreditations(self: &mut Federation, from: ID, to: ID) {
+    let governance = &mut self.governance;
+    if (governance.accreditations_to_accredit.contains(&from)) {
+        assert!(!governance.accreditations_to_accredit.contains(&to), EInvalidTransferRecipient);
+        let (_, accreditations) = governance.accreditations_to_accredit.remove(&from);
+        governance.accreditations_to_accredit.insert(to, accreditations);
+    };
+    if (governance.accreditations_to_attest.contains(&from)) {
+        assert!(!governance.accreditations_to_attest.contains(&to), EInvalidTransferRecipient);
+        let (_, accreditations) = governance.accreditations_to_attest.remove(&from);
+        governance.accreditations_to_attest.insert(to, accreditations);
+    };
+}
+
 // ===== Validation Functions =====
 
 /// Validates a single property from an attester
@@ -727,6 +2595,11 @@ public fun validate_property(
         return false
     };
 
+    // Check if the value is in the value dictionary referenced by the property
+    if (!self.is_value_in_dictionary(federation_property, &property_value)) {
+        return false
+    };
+
     // Check if attester has permissions for the property
     let accreditations = self.get_accreditations_to_attest(attester_id);
     if (!accreditations.is_property_allowed(&property_name, &property_value, current_time_ms)) {
@@ -736,6 +2609,58 @@ public fun validate_property(
     true
 }
 
+/// Validates a set of values attested for a single property by an attester.
+/// With `require_all` the attester must be accredited for every value (superset semantics),
+/// otherwise it is enough to be accredited for at least one of the values (contains semantics).
+/// Returns true if validation passes, false otherwise
+public fun validate_property_values(
+    self: &Federation,
+    attester_id: &ID,
+    property_name: PropertyName,
+    mut property_values: vector<PropertyValue>,
+    require_all: bool,
+    clock: &Clock,
+): bool {
+    let current_time_ms = clock.timestamp_ms();
+
+    // Check if property is trusted by the federation
+    if (!self.is_property_in_federation(property_name)) {
+        return false
+    };
+
+    // Check if the federation's property is still valid (not revoked)
+    let federation_property = self.governance.properties.data().get(&property_name);
+    if (!federation_property.is_valid_at_time(current_time_ms)) {
+        return false
+    };
+
+    // Check if attester has accreditation permissions
+    if (!self.is_attester(attester_id)) {
+        return false
+    };
+
+    // Values outside the referenced value dictionary are never allowed
+    let mut idx = 0;
+    while (idx < property_values.length()) {
+        if (self.is_value_in_dictionary(federation_property, &property_values[idx])) {
+            idx = idx + 1;
+        } else if (require_all) {
+            return false
+        } else {
+            property_values.remove(idx);
+        };
+    };
+
+    // Check if attester has permissions for the set of values
+    let accreditations = self.get_accreditations_to_attest(attester_id);
+    accreditations.are_property_values_allowed(
+        &property_name,
+        &property_values,
+        require_all,
+        current_time_ms,
+    )
+}
+
 /// Validates multiple properties from an issuer
 /// Returns true if all validations pass, false otherwise
 public fun validate_properties(
@@ -761,6 +2686,11 @@ public fun validate_properties(
             return false
         };
 
+        // Check if the value is in the value dictionary referenced by the property
+        if (!self.is_value_in_dictionary(federation_property, properties.get(&property_name))) {
+            return false
+        };
+
         idx = idx + 1;
     };
 
@@ -778,6 +2708,53 @@ public fun validate_properties(
     true
 }
 
+// ===== Permission Checks =====
+//
+// The functions below are meant to be composed by other packages and PTBs that gate their own logic on
+// Hierarchies permissions. Their signatures are part of the package's stable interface:
+// - `entity_id` is the `ID` of the checked account, i.e. `object::id_from_address(addr)` for an address;
+// - `property_name` is built with `property_name::new_property_name` or `new_property_name_from_vector`;
+// - `property_value` is built with `property_value::new_property_value_string` or `new_property_value_number`;
+// - `clock` is the shared `Clock` object at `0x6`.
+// They never abort for unknown entities or properties and return false instead.
+
+/// Checks if an entity is allowed to attest the given value for a property.
+public fun is_allowed_to_attest(
+    self: &Federation,
+    entity_id: &ID,
+    property_name: PropertyName,
+    property_value: PropertyValue,
+    clock: &Clock,
+): bool {
+    self.validate_property(entity_id, property_name, property_value, clock)
+}
+
+/// Checks if an entity is allowed to delegate accreditations for a property.
+public fun is_allowed_to_accredit(
+    self: &Federation,
+    entity_id: &ID,
+    property_name: PropertyName,
+    clock: &Clock,
+): bool {
+    let current_time_ms = clock.timestamp_ms();
+
+    // Check if property is trusted by the federation and still valid
+    if (!self.is_property_in_federation(property_name)) {
+        return false
+    };
+    let federation_property = self.governance.properties.data().get(&property_name);
+    if (!federation_property.is_valid_at_time(current_time_ms)) {
+        return false
+    };
+
+    if (!self.is_accreditor(entity_id)) {
+        return false
+    };
+
+    let accreditations = self.get_accreditations_to_accredit(entity_id);
+    accreditations.is_property_accredited(&property_name, current_time_ms)
+}
+
 /// Checks if an entity is a root authority in the federation
 public fun is_root_authority(self: &Federation, id: &ID): bool {
     let mut idx = 0;
@@ -799,16 +2776,3 @@ fun is_revoked_root_authority(self: &Federation, id: &ID): bool {
     vector::contains(&self.revoked_root_authorities, id)
 }
 
-// ===== Test Functions =====
-#[test_only]
-public(package) fun transfer_root_authority_cap(
-    self: &Federation,
-    cap: RootAuthorityCap,
-    account_id: ID,
-    _: &mut TxContext,
-) {
-    assert!(cap.federation_id == self.federation_id(), EUnauthorizedWrongFederation);
-    assert!(!self.is_revoked_root_authority(&cap.account_id), ERevokedRootAuthority);
-
-    transfer::transfer(cap, account_id.to_address());
-}
@@ -5,14 +5,15 @@ use hierarchies::{
     property_shape::PropertyShape,
     property_value::PropertyValue
 };
-use iota::{vec_map::{Self, VecMap}, vec_set::VecSet};
+use iota::{vec_map::{Self, VecMap}, vec_set::{Self, VecSet}};
+use std::string::String;
 
 // FederationProperties is a struct that contains a map of PropertyName to FederationProperty
 public struct FederationProperties has store {
     data: VecMap<PropertyName, FederationProperty>,
 }
 
-// The evaluation order: allow_any => shape => allowed_values
+// The evaluation order: allow_any => shape => allowed_values => value_dictionary
 // The evaluation order is determined by the possible size of the set of values
 // that match the shape.
 public struct FederationProperty has copy, drop, store {
@@ -25,6 +26,12 @@ public struct FederationProperty has copy, drop, store {
     allow_any: bool,
     // The time span of the property
     timespan: Timespan,
+    // Allow only values of the named federation value dictionary. The dictionary
+    // is resolved by the federation, see `main::validate_property`.
+    value_dictionary: Option<String>,
+    // Human-readable schema information, e.g. `description` or `unit`. Informational
+    // only and never used by validation, see `main::set_property_metadata`.
+    metadata: VecMap<String, String>,
 }
 
 /// Creates a new Property
@@ -40,6 +47,25 @@ public fun new_property(
         shape,
         allow_any,
         timespan: new_empty_timespan(),
+        value_dictionary: option::none(),
+        metadata: vec_map::empty(),
+    }
+}
+
+/// Creates a new Property whose allowed values are the values of a federation
+/// value dictionary
+public fun new_property_with_value_dictionary(
+    name: PropertyName,
+    value_dictionary: String,
+): FederationProperty {
+    FederationProperty {
+        name,
+        allowed_values: vec_set::empty(),
+        shape: option::none(),
+        allow_any: false,
+        timespan: new_empty_timespan(),
+        value_dictionary: option::some(value_dictionary),
+        metadata: vec_map::empty(),
     }
 }
 
@@ -84,6 +110,23 @@ public(package) fun timespan(self: &FederationProperty): &Timespan {
     &self.timespan
 }
 
+public(package) fun value_dictionary(self: &FederationProperty): &Option<String> {
+    &self.value_dictionary
+}
+
+/// Checks if the property allows every value of the given value dictionary
+public(package) fun covers_value_dictionary(
+    self: &FederationProperty,
+    value_dictionary: &String,
+    current_time_ms: u64,
+): bool {
+    if (!self.timespan.timestamp_matches(current_time_ms)) {
+        return false
+    };
+    self.allow_any ||
+        (self.value_dictionary.is_some() && self.value_dictionary.borrow() == value_dictionary)
+}
+
 public(package) fun matches_name_value(
     self: &FederationProperty,
     name: &PropertyName,
@@ -94,6 +137,9 @@ public(package) fun matches_name_value(
 }
 
 public(package) fun matches_name(self: &FederationProperty, name: &PropertyName): bool {
+    if (self.name.is_wildcard()) {
+        return self.name.covers(name)
+    };
     // considering the property name is a.b.c
     // the allowed name should be equal a.b.c or longer
     let len_property = self.name.names().length();
@@ -133,13 +179,28 @@ public(package) fun matches_value(
             return true
         }
     };
-    self.allowed_values.contains(value)
+    // Membership in a value dictionary is checked by the federation, which owns the dictionaries
+    self.allowed_values.contains(value) || self.value_dictionary.is_some()
+}
+
+public(package) fun metadata(self: &FederationProperty): &VecMap<String, String> {
+    &self.metadata
+}
+
+/// Replaces the metadata of the property
+public(package) fun set_metadata(self: &mut FederationProperty, metadata: VecMap<String, String>) {
+    self.metadata = metadata
 }
 
 public(package) fun revoke(self: &mut FederationProperty, valid_to_ms: u64) {
     self.timespan.valid_until_ms = option::some(valid_to_ms)
 }
 
+/// Sets the end of the property's validity
+public(package) fun set_valid_until(self: &mut FederationProperty, valid_until_ms: u64) {
+    self.timespan.valid_until_ms = option::some(valid_until_ms)
+}
+
 /// Checks if a property is valid (not revoked) at the given time
 public(package) fun is_valid_at_time(self: &FederationProperty, current_time_ms: u64): bool {
     self.timespan.timestamp_matches(current_time_ms)
@@ -158,6 +219,40 @@ public(package) fun to_map_of_properties(
     return map
 }
 
+// ===== Paged Views =====
+
+/// A page of properties and the cursor of the next page, if any.
+public struct PropertyPage has copy, drop {
+    properties: vector<FederationProperty>,
+    next_cursor: Option<u64>,
+}
+
+/// Returns up to `limit` properties in insertion order, starting at index `cursor`.
+public(package) fun page(self: &FederationProperties, cursor: u64, limit: u64): PropertyPage {
+    let mut properties = vector::empty();
+    let mut idx = cursor;
+    while (idx < self.data.size() && properties.length() < limit) {
+        let (_, property) = self.data.get_entry_by_idx(idx);
+        properties.push_back(*property);
+        idx = idx + 1;
+    };
+
+    let next_cursor = if (idx < self.data.size()) {
+        option::some(idx)
+    } else {
+        option::none()
+    };
+    PropertyPage { properties, next_cursor }
+}
+
+public(package) fun page_properties(self: &PropertyPage): &vector<FederationProperty> {
+    &self.properties
+}
+
+public(package) fun page_next_cursor(self: &PropertyPage): Option<u64> {
+    self.next_cursor
+}
+
 /// Represents a time property. The valid_from_ms and valid_until_ms are
 /// optional, if they are not set, the property is valid for all time.
 public struct Timespan has copy, drop, store {
@@ -182,6 +277,14 @@ public(package) fun new_empty_timespan(): Timespan {
     }
 }
 
+public(package) fun valid_from_ms(self: &Timespan): Option<u64> {
+    self.valid_from_ms
+}
+
+public(package) fun valid_until_ms(self: &Timespan): Option<u64> {
+    self.valid_until_ms
+}
+
 public(package) fun timestamp_matches(self: &Timespan, now_ms: u64): bool {
     if (self.valid_from_ms.is_some() && *self.valid_from_ms.borrow() > now_ms) {
         return false
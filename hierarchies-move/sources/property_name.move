@@ -1,6 +1,9 @@
 module hierarchies::property_name;
 
-use std::string::String;
+use std::string::{Self, String};
+
+/// The last name of a wildcard property name, e.g. `degree.*`
+const WILDCARD: vector<u8> = b"*";
 
 /// PropertyName represents a name of a Property. It can be a single name or a vector of names.
 public struct PropertyName has copy, drop, store {
@@ -21,6 +24,57 @@ public fun new_property_name_from_vector(v: vector<String>): PropertyName {
     }
 }
 
+/// Creates the wildcard name covering all properties in the namespace `prefix`, e.g. `degree.*`
+public fun new_property_name_prefix(prefix: vector<String>): PropertyName {
+    let mut names = prefix;
+    names.push_back(string::utf8(WILDCARD));
+    PropertyName {
+        names,
+    }
+}
+
 public fun names(self: &PropertyName): &vector<String> {
     &self.names
 }
+
+/// Returns true if the last name is the wildcard `*`
+public fun is_wildcard(self: &PropertyName): bool {
+    !self.names.is_empty() && self.names[self.names.length() - 1] == string::utf8(WILDCARD)
+}
+
+/// Returns true if a grant for `self` covers `name`.
+/// A wildcard `a.b.*` covers every name below `a.b`, including narrower wildcards like `a.b.c.*`,
+/// any other name covers only itself.
+public fun covers(self: &PropertyName, name: &PropertyName): bool {
+    if (!self.is_wildcard()) {
+        return self == name
+    };
+    let len_prefix = self.names.length() - 1;
+    if (name.names.length() <= len_prefix) {
+        return false
+    };
+    let mut idx = 0;
+    while (idx < len_prefix) {
+        if (self.names[idx] != name.names[idx]) {
+            return false
+        };
+        idx = idx + 1;
+    };
+    true
+}
+
+/// Returns true if the leading names of `self` are equal to the names of `prefix`
+public fun starts_with(self: &PropertyName, prefix: &PropertyName): bool {
+    let len = prefix.names.length();
+    if (len > self.names.length()) {
+        return false
+    };
+    let mut idx = 0;
+    while (idx < len) {
+        if (self.names[idx] != prefix.names[idx]) {
+            return false
+        };
+        idx = idx + 1;
+    };
+    true
+}
@@ -3,6 +3,26 @@ module hierarchies::property_shape;
 use hierarchies::property_value::PropertyValue;
 use std::string::String;
 
+// ===== Errors =====
+/// Error when the lower bound of a range is greater than its upper bound
+const EInvalidRange: u64 = 1;
+/// Error when the nodes of a composite shape don't form a single expression
+const EInvalidComposite: u64 = 2;
+/// Error when a composite shape is used as a leaf of another composite shape
+const ENestedComposite: u64 = 3;
+/// Error when a pattern is not valid, see `new_property_shape_matches_pattern`
+const EInvalidPattern: u64 = 4;
+
+// ===== Pattern syntax =====
+const PATTERN_ANY_SEQUENCE: u8 = 42; // '*'
+const PATTERN_ANY_CHAR: u8 = 63; // '?'
+const PATTERN_DIGIT: u8 = 35; // '#'
+const PATTERN_LETTER: u8 = 64; // '@'
+const PATTERN_ESCAPE: u8 = 92; // '\'
+const PATTERN_CLASS_START: u8 = 91; // '['
+const PATTERN_CLASS_END: u8 = 93; // ']'
+const PATTERN_RANGE: u8 = 45; // '-'
+
 /// PropertyShape defines the shape of a property.
 public enum PropertyShape has copy, drop, store {
     StartsWith(String),
@@ -10,6 +30,32 @@ public enum PropertyShape has copy, drop, store {
     Contains(String),
     GreaterThan(u64),
     LowerThan(u64),
+    Between(u64, u64),
+    EqualTo(PropertyValue),
+    NotEqualTo(PropertyValue),
+    // Logical composition of shapes, stored in postfix order
+    Composite(vector<ShapeNode>),
+    MatchesPattern(String),
+}
+
+/// ShapeNode is a node of a composite PropertyShape.
+///
+/// Types can't be recursive, so composite shapes are stored as a flat list of nodes in postfix order:
+/// leaf nodes push their result, `All(n)` and `Any(n)` combine the last `n` results and `Not` negates
+/// the last result.
+public enum ShapeNode has copy, drop, store {
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    GreaterThan(u64),
+    LowerThan(u64),
+    Between(u64, u64),
+    EqualTo(PropertyValue),
+    NotEqualTo(PropertyValue),
+    All(u64),
+    Any(u64),
+    Not,
+    MatchesPattern(String),
 }
 
 /// Creates a new PropertyShape that checks if the value starts with the given text.
@@ -27,16 +73,85 @@ public fun new_property_shape_contains(text: String): PropertyShape {
     PropertyShape::Contains(text)
 }
 
-/// Creates a new PropertyShape that checks if the value is greater than the given number.
+/// Creates a new PropertyShape that checks if the value is a number or timestamp greater than the given number.
 public fun new_property_shape_greater_than(value: u64): PropertyShape {
     PropertyShape::GreaterThan(value)
 }
 
-/// Creates a new PropertyShape that checks if the value is lower than the given number.
+/// Creates a new PropertyShape that checks if the value is a number or timestamp lower than the given number.
 public fun new_property_shape_lower_than(value: u64): PropertyShape {
     PropertyShape::LowerThan(value)
 }
 
+/// Creates a new PropertyShape that checks if the value is a number or timestamp between `min` and `max`, both
+/// inclusive.
+public fun new_property_shape_between(min: u64, max: u64): PropertyShape {
+    assert!(min <= max, EInvalidRange);
+    PropertyShape::Between(min, max)
+}
+
+/// Creates a new PropertyShape that checks if the value is equal to the given value.
+public fun new_property_shape_equal_to(value: PropertyValue): PropertyShape {
+    PropertyShape::EqualTo(value)
+}
+
+/// Creates a new PropertyShape that checks if the value is not equal to the given value.
+public fun new_property_shape_not_equal_to(value: PropertyValue): PropertyShape {
+    PropertyShape::NotEqualTo(value)
+}
+
+/// Creates a new PropertyShape that checks if the value is a text matching the given pattern.
+///
+/// Patterns are ASCII and matched against the whole value:
+/// - `*` matches any sequence of characters, including none
+/// - `?` matches a single ASCII character
+/// - `#` matches an ASCII digit
+/// - `@` matches an ASCII letter
+/// - `[...]` matches one of the listed characters or ranges, e.g. `[A-Z]` or `[a-z0-9_]`
+/// - `\` matches the following character literally
+/// - any other character matches itself
+public fun new_property_shape_matches_pattern(pattern: String): PropertyShape {
+    assert!(is_valid_pattern(pattern.as_bytes()), EInvalidPattern);
+    PropertyShape::MatchesPattern(pattern)
+}
+
+/// Creates a new PropertyShape that combines the given nodes, in postfix order.
+public fun new_property_shape_composite(nodes: vector<ShapeNode>): PropertyShape {
+    assert!(is_well_formed(&nodes), EInvalidComposite);
+    PropertyShape::Composite(nodes)
+}
+
+/// Creates a new leaf ShapeNode from a non-composite PropertyShape.
+public fun new_shape_node(shape: PropertyShape): ShapeNode {
+    match (shape) {
+        PropertyShape::StartsWith(text) => ShapeNode::StartsWith(text),
+        PropertyShape::EndsWith(text) => ShapeNode::EndsWith(text),
+        PropertyShape::Contains(text) => ShapeNode::Contains(text),
+        PropertyShape::GreaterThan(value) => ShapeNode::GreaterThan(value),
+        PropertyShape::LowerThan(value) => ShapeNode::LowerThan(value),
+        PropertyShape::Between(min, max) => ShapeNode::Between(min, max),
+        PropertyShape::EqualTo(value) => ShapeNode::EqualTo(value),
+        PropertyShape::NotEqualTo(value) => ShapeNode::NotEqualTo(value),
+        PropertyShape::MatchesPattern(pattern) => ShapeNode::MatchesPattern(pattern),
+        PropertyShape::Composite(_) => abort ENestedComposite,
+    }
+}
+
+/// Creates a new ShapeNode that matches if all of the last `count` results match.
+public fun new_shape_node_all(count: u64): ShapeNode {
+    ShapeNode::All(count)
+}
+
+/// Creates a new ShapeNode that matches if any of the last `count` results matches.
+public fun new_shape_node_any(count: u64): ShapeNode {
+    ShapeNode::Any(count)
+}
+
+/// Creates a new ShapeNode that negates the last result.
+public fun new_shape_node_not(): ShapeNode {
+    ShapeNode::Not
+}
+
 /// Checks if the condition matches the value.
 public fun property_shape_matches(self: &PropertyShape, value: &PropertyValue): bool {
     match (self) {
@@ -75,7 +190,7 @@ public fun property_shape_matches(self: &PropertyShape, value: &PropertyValue):
             return index < value_string.length()
         },
         PropertyShape::GreaterThan(ref_value) => {
-            let maybe_value_number = value.as_number();
+            let maybe_value_number = value.as_ordered();
             if (maybe_value_number.is_none()) {
                 return false
             };
@@ -83,12 +198,222 @@ public fun property_shape_matches(self: &PropertyShape, value: &PropertyValue):
             return *value_number > *ref_value
         },
         PropertyShape::LowerThan(ref_value) => {
-            let maybe_value_number = value.as_number();
+            let maybe_value_number = value.as_ordered();
             if (maybe_value_number.is_none()) {
                 return false
             };
             let value_number = maybe_value_number.borrow();
             return *value_number < *ref_value
         },
+        PropertyShape::Between(min, max) => {
+            let maybe_value_number = value.as_ordered();
+            if (maybe_value_number.is_none()) {
+                return false
+            };
+            let value_number = maybe_value_number.borrow();
+            return *value_number >= *min && *value_number <= *max
+        },
+        PropertyShape::EqualTo(ref_value) => {
+            return value == ref_value
+        },
+        PropertyShape::NotEqualTo(ref_value) => {
+            return value != ref_value
+        },
+        PropertyShape::MatchesPattern(pattern) => {
+            let maybe_value_string = value.as_string();
+            if (maybe_value_string.is_none()) {
+                return false
+            };
+            let value_string = maybe_value_string.borrow();
+            return pattern_matches(pattern.as_bytes(), value_string.as_bytes())
+        },
+        PropertyShape::Composite(nodes) => {
+            let mut results = vector<bool>[];
+            let mut i = 0;
+            while (i < nodes.length()) {
+                let result = match (&nodes[i]) {
+                    ShapeNode::All(count) => {
+                        let mut result = true;
+                        let mut j = 0;
+                        while (j < *count) {
+                            let operand = results.pop_back();
+                            result = result && operand;
+                            j = j + 1;
+                        };
+                        result
+                    },
+                    ShapeNode::Any(count) => {
+                        let mut result = false;
+                        let mut j = 0;
+                        while (j < *count) {
+                            let operand = results.pop_back();
+                            result = result || operand;
+                            j = j + 1;
+                        };
+                        result
+                    },
+                    ShapeNode::Not => !results.pop_back(),
+                    node => node.to_shape().property_shape_matches(value),
+                };
+                results.push_back(result);
+                i = i + 1;
+            };
+            return results.pop_back()
+        },
     }
 }
+
+/// Converts a leaf node back into the shape it was created from.
+fun to_shape(self: &ShapeNode): PropertyShape {
+    match (self) {
+        ShapeNode::StartsWith(text) => PropertyShape::StartsWith(*text),
+        ShapeNode::EndsWith(text) => PropertyShape::EndsWith(*text),
+        ShapeNode::Contains(text) => PropertyShape::Contains(*text),
+        ShapeNode::GreaterThan(value) => PropertyShape::GreaterThan(*value),
+        ShapeNode::LowerThan(value) => PropertyShape::LowerThan(*value),
+        ShapeNode::Between(min, max) => PropertyShape::Between(*min, *max),
+        ShapeNode::EqualTo(value) => PropertyShape::EqualTo(*value),
+        ShapeNode::NotEqualTo(value) => PropertyShape::NotEqualTo(*value),
+        ShapeNode::MatchesPattern(pattern) => PropertyShape::MatchesPattern(*pattern),
+        _ => abort EInvalidComposite,
+    }
+}
+
+/// Checks that the nodes evaluate to exactly one result without running out of operands.
+fun is_well_formed(nodes: &vector<ShapeNode>): bool {
+    let mut depth = 0;
+    let mut i = 0;
+    while (i < nodes.length()) {
+        let operands = match (&nodes[i]) {
+            ShapeNode::All(count) => *count,
+            ShapeNode::Any(count) => *count,
+            ShapeNode::Not => 1,
+            _ => 0,
+        };
+        if (depth < operands) {
+            return false
+        };
+        // Every node pushes a single result
+        depth = depth - operands + 1;
+        i = i + 1;
+    };
+    depth == 1
+}
+
+/// Checks that the pattern is ASCII, escapes are followed by a character and classes are closed,
+/// non-empty and have ordered ranges.
+fun is_valid_pattern(pattern: &vector<u8>): bool {
+    let length = pattern.length();
+    let mut p = 0;
+    while (p < length) {
+        let token = pattern[p];
+        if (token >= 128) {
+            return false
+        };
+        if (token == PATTERN_ESCAPE) {
+            if (p + 1 >= length || pattern[p + 1] >= 128) {
+                return false
+            };
+            p = p + 2;
+        } else if (token == PATTERN_CLASS_START) {
+            let mut i = p + 1;
+            if (i < length && pattern[i] == PATTERN_CLASS_END) {
+                return false
+            };
+            while (i < length && pattern[i] != PATTERN_CLASS_END) {
+                if (pattern[i] >= 128) {
+                    return false
+                };
+                if (is_class_range(pattern, i)) {
+                    if (pattern[i + 2] >= 128 || pattern[i] > pattern[i + 2]) {
+                        return false
+                    };
+                    i = i + 3;
+                } else {
+                    i = i + 1;
+                };
+            };
+            if (i >= length) {
+                return false
+            };
+            p = i + 1;
+        } else {
+            p = p + 1;
+        };
+    };
+    true
+}
+
+/// Checks if a valid pattern matches the whole value.
+///
+/// Every token except `*` matches a single byte, so a mismatch backtracks to the last `*` only,
+/// which bounds the cost by the product of both lengths.
+fun pattern_matches(pattern: &vector<u8>, value: &vector<u8>): bool {
+    let mut p = 0;
+    let mut v = 0;
+    let mut has_star = false;
+    let mut star_p = 0;
+    let mut star_v = 0;
+    while (v < value.length()) {
+        if (p < pattern.length() && pattern[p] == PATTERN_ANY_SEQUENCE) {
+            p = p + 1;
+            has_star = true;
+            star_p = p;
+            star_v = v;
+            continue
+        };
+        if (p < pattern.length()) {
+            let (matched, next) = match_token(pattern, p, value[v]);
+            if (matched) {
+                p = next;
+                v = v + 1;
+                continue
+            };
+        };
+        if (!has_star) {
+            return false
+        };
+        // Let the last `*` consume one more byte
+        star_v = star_v + 1;
+        p = star_p;
+        v = star_v;
+    };
+    while (p < pattern.length() && pattern[p] == PATTERN_ANY_SEQUENCE) {
+        p = p + 1;
+    };
+    p == pattern.length()
+}
+
+/// Checks if the byte matches the token starting at `p`, returning the start of the next token.
+fun match_token(pattern: &vector<u8>, p: u64, byte: u8): (bool, u64) {
+    let token = pattern[p];
+    if (token == PATTERN_ANY_CHAR) {
+        (byte < 128, p + 1)
+    } else if (token == PATTERN_DIGIT) {
+        (byte >= 48 && byte <= 57, p + 1)
+    } else if (token == PATTERN_LETTER) {
+        ((byte >= 65 && byte <= 90) || (byte >= 97 && byte <= 122), p + 1)
+    } else if (token == PATTERN_ESCAPE) {
+        (byte == pattern[p + 1], p + 2)
+    } else if (token == PATTERN_CLASS_START) {
+        let mut matched = false;
+        let mut i = p + 1;
+        while (pattern[i] != PATTERN_CLASS_END) {
+            if (is_class_range(pattern, i)) {
+                matched = matched || (byte >= pattern[i] && byte <= pattern[i + 2]);
+                i = i + 3;
+            } else {
+                matched = matched || byte == pattern[i];
+                i = i + 1;
+            };
+        };
+        (matched, i + 1)
+    } else {
+        (byte == token, p + 1)
+    }
+}
+
+/// Checks if a range such as `a-z` starts at `i` inside a class. A `-` before the end of the class is literal.
+fun is_class_range(pattern: &vector<u8>, i: u64): bool {
+    i + 2 < pattern.length() && pattern[i + 1] == PATTERN_RANGE && pattern[i + 2] != PATTERN_CLASS_END
+}
@@ -2,10 +2,13 @@ module hierarchies::property_value;
 
 use std::string::String;
 
-/// PropertyValue can be a String or a Number.
+/// PropertyValue can be a String, a Number, a Bool, a Timestamp in milliseconds or Bytes.
 public enum PropertyValue has copy, drop, store {
     String(String),
     Number(u64),
+    Bool(bool),
+    Timestamp(u64),
+    Bytes(vector<u8>),
 }
 
 /// Creates a new PropertyValue from a String.
@@ -18,16 +21,40 @@ public fun new_property_value_number(v: u64): PropertyValue {
     PropertyValue::Number(v)
 }
 
+/// Creates a new PropertyValue from a bool.
+public fun new_property_value_bool(v: bool): PropertyValue {
+    PropertyValue::Bool(v)
+}
+
+/// Creates a new PropertyValue from a timestamp in milliseconds since the Unix epoch.
+public fun new_property_value_timestamp(v: u64): PropertyValue {
+    PropertyValue::Timestamp(v)
+}
+
+/// Creates a new PropertyValue from bytes.
+public fun new_property_value_bytes(v: vector<u8>): PropertyValue {
+    PropertyValue::Bytes(v)
+}
+
 public(package) fun as_string(self: &PropertyValue): Option<String> {
     match (self) {
         PropertyValue::String(text) => option::some(*text),
-        PropertyValue::Number(_) => option::none(),
+        _ => option::none(),
     }
 }
 
 public(package) fun as_number(self: &PropertyValue): Option<u64> {
     match (self) {
-        PropertyValue::String(_) => option::none(),
         PropertyValue::Number(number) => option::some(*number),
+        _ => option::none(),
+    }
+}
+
+/// Returns the value to compare against numeric bounds, i.e. a Number or a Timestamp.
+public(package) fun as_ordered(self: &PropertyValue): Option<u64> {
+    match (self) {
+        PropertyValue::Number(number) => option::some(*number),
+        PropertyValue::Timestamp(timestamp) => option::some(*timestamp),
+        _ => option::none(),
     }
 }
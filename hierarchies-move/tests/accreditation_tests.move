@@ -99,6 +99,26 @@ fun test_is_property_allowed_no_match() {
     scenario.end();
 }
 
+#[test]
+fun test_is_property_allowed_skips_unrelated_accreditations() {
+    let (mut scenario, accreditation) = test_accreditation_creation();
+    let unrelated = accreditation::new_accreditation(
+        vector[create_test_property_simple(b"department", b"engineering")],
+        scenario.ctx(),
+    );
+    let mut accreditations = accreditation::new_empty_accreditations();
+    accreditation::add_accreditation(&mut accreditations, unrelated);
+    accreditation::add_accreditation(&mut accreditations, accreditation);
+
+    let name = property_name::new_property_name(string::utf8(b"role"));
+    let value = property_value::new_property_value_string(string::utf8(b"admin"));
+
+    assert!(accreditation::is_property_allowed(&accreditations, &name, &value, 1000), 0);
+    assert!(accreditation::is_property_accredited(&accreditations, &name, 1000), 1);
+    accreditation::destroy_accreditations(accreditations);
+    scenario.end();
+}
+
 #[test]
 fun test_are_properties_allowed_all_match() {
     let (scenario, accreditation) = test_accreditation_creation();
@@ -477,3 +497,100 @@ fun test_is_property_compliant_name_matching_success() {
     accreditation::destroy_accreditations(accreditations);
     scenario.end();
 }
+
+#[test]
+fun test_page_returns_next_cursor() {
+    let mut scenario = test_scenario::begin(@0x1);
+    let mut accreditations = accreditation::new_empty_accreditations();
+    let mut idx = 0;
+    while (idx < 3) {
+        let property = create_test_property_simple(b"role", b"admin");
+        let accreditation = accreditation::new_accreditation(vector[property], scenario.ctx());
+        accreditation::add_accreditation(&mut accreditations, accreditation);
+        idx = idx + 1;
+    };
+
+    let first = accreditation::page(&accreditations, 0, 2, &option::none(), &option::none(), option::none());
+    assert!(first.page_accreditations().length() == 2, 0);
+    assert!(first.page_next_cursor() == option::some(2), 1);
+
+    let second = accreditation::page(&accreditations, 2, 2, &option::none(), &option::none(), option::none());
+    assert!(second.page_accreditations().length() == 1, 2);
+    assert!(second.page_next_cursor().is_none(), 3);
+
+    accreditation::destroy_accreditations(accreditations);
+    scenario.end();
+}
+
+#[test]
+fun test_page_filters_by_property_and_accreditor() {
+    let mut scenario = test_scenario::begin(@0x1);
+    let role = create_test_property_simple(b"role", b"admin");
+    let department = create_test_property_simple(b"department", b"engineering");
+    let role_accreditation = accreditation::new_accreditation(vector[role], scenario.ctx());
+    let role_id = object::id(&role_accreditation);
+    let mut accreditations = accreditation::new_accreditations(vector[
+        accreditation::new_accreditation(vector[department], scenario.ctx()),
+        role_accreditation,
+    ]);
+
+    let role_name = option::some(property_name::new_property_name(string::utf8(b"role")));
+    let page = accreditation::page(&accreditations, 0, 10, &role_name, &option::none(), option::none());
+    assert!(page.page_accreditations().length() == 1, 0);
+    assert!(page.page_accreditations()[0].view_id() == role_id, 1);
+    assert!(page.page_next_cursor().is_none(), 2);
+
+    let other_accreditor = option::some((@0x2).to_string());
+    let page = accreditation::page(&accreditations, 0, 10, &option::none(), &other_accreditor, option::none());
+    assert!(page.page_accreditations().is_empty(), 3);
+
+    let mut pending = accreditation::new_accreditation(
+        vector[create_test_property_simple(b"role", b"admin")],
+        scenario.ctx(),
+    );
+    pending.set_pending_until_ms(2000);
+    accreditation::add_accreditation(&mut accreditations, pending);
+
+    let page = accreditation::page(&accreditations, 0, 10, &role_name, &option::none(), option::some(1000));
+    assert!(page.page_accreditations().length() == 1, 4);
+    let page = accreditation::page(&accreditations, 0, 10, &role_name, &option::none(), option::some(3000));
+    assert!(page.page_accreditations().length() == 2, 5);
+
+    accreditation::destroy_accreditations(accreditations);
+    scenario.end();
+}
+
+#[test]
+fun test_page_filters_by_property_covered_by_wildcard() {
+    let mut scenario = test_scenario::begin(@0x1);
+    let degrees = property::new_property(
+        property_name::new_property_name_prefix(vector[string::utf8(b"degree")]),
+        vec_set::empty(),
+        true,
+        option::none(),
+    );
+    let degrees_accreditation = accreditation::new_accreditation(vector[degrees], scenario.ctx());
+    let degrees_id = object::id(&degrees_accreditation);
+    let role = create_test_property_simple(b"role", b"admin");
+    let accreditations = accreditation::new_accreditations(vector[
+        accreditation::new_accreditation(vector[role], scenario.ctx()),
+        degrees_accreditation,
+    ]);
+
+    let bachelor = option::some(
+        property_name::new_property_name_from_vector(vector[
+            string::utf8(b"degree"),
+            string::utf8(b"bachelor"),
+        ]),
+    );
+    let page = accreditation::page(&accreditations, 0, 10, &bachelor, &option::none(), option::none());
+    assert!(page.page_accreditations().length() == 1, 0);
+    assert!(page.page_accreditations()[0].view_id() == degrees_id, 1);
+
+    let page = accreditation::page(&accreditations, 0, 10, &bachelor, &option::none(), option::some(1000));
+    assert!(page.page_accreditations().length() == 1, 2);
+    assert!(page.page_accreditations()[0].view_id() == degrees_id, 3);
+
+    accreditation::destroy_accreditations(accreditations);
+    scenario.end();
+}
@@ -2,6 +2,7 @@
 module hierarchies::main_tests;
 
 use hierarchies::{
+    attestation::Attestation,
     main::{
         new_federation,
         RootAuthorityCap,
@@ -15,10 +16,17 @@ use hierarchies::{
         add_root_authority,
         revoke_root_authority,
         is_root_authority,
-        revoke_property
+        revoke_property,
+        new_action_revoke_root_authority,
+        new_action_set_quorum_threshold,
+        new_action_add_root_authority,
+        new_action_reinstate_root_authority,
+        new_action_set_value_dictionary,
+        new_action_deprecate_property,
+        new_action_unlink_federation
     },
     property,
-    property_name::new_property_name,
+    property_name::{new_property_name, new_property_name_from_vector, new_property_name_prefix},
     property_value::new_property_value_number
 };
 use iota::{clock, test_scenario, vec_map, vec_set};
@@ -517,6 +525,158 @@ fun test_revoke_root_authority_success() {
     let _ = scenario.end();
 }
 
+#[test]
+fun test_rotate_root_authority() {
+    let alice = @0x1;
+    let bob = @0x2;
+
+    let mut scenario = test_scenario::begin(alice);
+
+    // Create a new federation
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    // Alice hands her root authority over to Bob
+    fed.rotate_root_authority(&alice_cap, alice.to_id(), bob.to_id(), scenario.ctx());
+
+    assert!(!fed.is_root_authority(&alice.to_id()), 0);
+    assert!(fed.is_root_authority(&bob.to_id()), 1);
+    assert!(fed.get_stats().root_authority_count() == 1, 2);
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    scenario.next_tx(bob);
+
+    // Bob received a capability
+    let bob_cap: RootAuthorityCap = scenario.take_from_address(bob);
+    test_scenario::return_to_address(bob, bob_cap);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EAlreadyRootAuthority)]
+fun test_rotate_root_authority_to_existing() {
+    let alice = @0x1;
+    let bob = @0x2;
+
+    let mut scenario = test_scenario::begin(alice);
+
+    // Create a new federation
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+
+    // Rotating onto an active root authority would leave one root authority less
+    fed.rotate_root_authority(&alice_cap, alice.to_id(), bob.to_id(), scenario.ctx());
+
+    // Cleanup - won't be reached due to expected failure
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::ERevokedRootAuthority)]
+fun test_rotate_root_authority_to_revoked() {
+    let alice = @0x1;
+    let xavier = @0x2;
+
+    let mut scenario = test_scenario::begin(alice);
+
+    // Create a new federation
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, xavier.to_id(), scenario.ctx());
+    fed.revoke_root_authority(&alice_cap, xavier.to_id(), scenario.ctx());
+
+    // Xavier would be active and revoked at once, leaving no usable root authority
+    fed.rotate_root_authority(&alice_cap, alice.to_id(), xavier.to_id(), scenario.ctx());
+
+    // Cleanup - won't be reached due to expected failure
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_link_federation() {
+    let alice = @0x1;
+    let child = @0x100.to_id();
+
+    let mut scenario = test_scenario::begin(alice);
+
+    // Create a new federation
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    fed.link_federation(&alice_cap, child, scenario.ctx());
+    assert!(fed.is_federation_recognized(&child), 0);
+    assert!(fed.get_recognized_federations() == vector[child], 1);
+
+    fed.unlink_federation(&alice_cap, child, scenario.ctx());
+    assert!(!fed.is_federation_recognized(&child), 2);
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::ECannotLinkSelf)]
+fun test_link_federation_to_itself() {
+    let alice = @0x1;
+
+    let mut scenario = test_scenario::begin(alice);
+
+    // Create a new federation
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    fed.link_federation(&alice_cap, object::id(&fed), scenario.ctx());
+
+    // Cleanup - won't be reached due to expected failure
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EFederationNotLinked)]
+fun test_unlink_federation_not_linked() {
+    let alice = @0x1;
+
+    let mut scenario = test_scenario::begin(alice);
+
+    // Create a new federation
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    fed.unlink_federation(&alice_cap, @0x100.to_id(), scenario.ctx());
+
+    // Cleanup - won't be reached due to expected failure
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
 #[test]
 #[expected_failure(abort_code = hierarchies::main::ERootAuthorityNotFound)]
 fun test_revoke_root_authority_not_found() {
@@ -1148,7 +1308,7 @@ fun test_transferred_capability_from_revoked_authority_fails() {
     scenario.next_tx(bob);
 
     // Bob transfers his capability to Charlie
-    fed.transfer_root_authority_cap(bob_cap, charlie.to_id(), scenario.ctx());
+    fed.transfer_root_authority_cap(bob_cap, charlie, scenario.ctx());
 
     scenario.next_tx(charlie);
     let transferred_cap: RootAuthorityCap = scenario.take_from_address(charlie);
@@ -1296,3 +1456,1509 @@ fun test_validate_properties_fails_for_revoked_property() {
     clock.destroy_for_testing();
     let _ = scenario.end();
 }
+
+#[test]
+fun test_validate_property_values_set_semantics() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    // Add a property allowing values 1, 2 and 3
+    let property_name = new_property_name(utf8(b"compliance"));
+    let mut allowed_values = vec_set::empty();
+    allowed_values.insert(new_property_value_number(1));
+    allowed_values.insert(new_property_value_number(2));
+    allowed_values.insert(new_property_value_number(3));
+    let property = property::new_property(property_name, allowed_values, false, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    // Bob may only attest values 1 and 2
+    let bob_id = @0x2.to_id();
+    let mut bob_values = vec_set::empty();
+    bob_values.insert(new_property_value_number(1));
+    bob_values.insert(new_property_value_number(2));
+    let property = property::new_property(property_name, bob_values, false, option::none());
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob_id,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+
+    let covered = vector[new_property_value_number(1), new_property_value_number(2)];
+    let partially_covered = vector[new_property_value_number(2), new_property_value_number(3)];
+    let not_covered = vector[new_property_value_number(3)];
+
+    // Superset semantics: every value must be accredited
+    assert!(fed.validate_property_values(&bob_id, property_name, covered, true, &clock), 0);
+    assert!(!fed.validate_property_values(&bob_id, property_name, partially_covered, true, &clock), 1);
+
+    // Contains semantics: a single accredited value is enough
+    assert!(fed.validate_property_values(&bob_id, property_name, partially_covered, false, &clock), 2);
+    assert!(!fed.validate_property_values(&bob_id, property_name, not_covered, false, &clock), 3);
+
+    // An empty set never validates
+    assert!(!fed.validate_property_values(&bob_id, property_name, vector[], true, &clock), 4);
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_set_property_critical() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"kyc"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&cap, property, scenario.ctx());
+
+    assert!(!fed.is_property_critical(property_name), 0);
+
+    fed.set_property_critical(&cap, property_name, true, scenario.ctx());
+    assert!(fed.is_property_critical(property_name), 1);
+    assert!(fed.get_critical_properties() == vector[property_name], 2);
+
+    // Marking twice is idempotent
+    fed.set_property_critical(&cap, property_name, true, scenario.ctx());
+    assert!(fed.get_critical_properties().length() == 1, 3);
+
+    fed.set_property_critical(&cap, property_name, false, scenario.ctx());
+    assert!(!fed.is_property_critical(property_name), 4);
+
+    test_scenario::return_to_address(alice, cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EPropertyNotInFederation)]
+fun test_set_property_critical_fails_for_unknown_property() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    fed.set_property_critical(&cap, new_property_name(utf8(b"unknown")), true, scenario.ctx());
+
+    test_scenario::return_to_address(alice, cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_deprecate_property() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let old_name = new_property_name(utf8(b"iso.22000"));
+    let new_name = new_property_name(utf8(b"iso.22000:2018"));
+    fed.add_property(&cap, property::new_property(old_name, vec_set::empty(), true, option::none()), scenario.ctx());
+    fed.add_property(&cap, property::new_property(new_name, vec_set::empty(), true, option::none()), scenario.ctx());
+
+    fed.deprecate_property(&cap, old_name, option::none(), scenario.ctx());
+    assert!(fed.is_property_deprecated(old_name), 0);
+    assert!(*fed.get_deprecated_properties().get(&old_name) == option::none(), 1);
+
+    // Deprecating again records the replacement
+    fed.deprecate_property(&cap, old_name, option::some(new_name), scenario.ctx());
+    assert!(*fed.get_deprecated_properties().get(&old_name) == option::some(new_name), 2);
+    assert!(fed.get_deprecated_properties().size() == 1, 3);
+    assert!(!fed.is_property_deprecated(new_name), 4);
+    // The deprecated property is still part of the federation
+    assert!(fed.is_property_in_federation(old_name), 5);
+
+    test_scenario::return_to_address(alice, cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EInvalidPropertyReplacement)]
+fun test_deprecate_property_fails_for_unknown_replacement() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"iso.22000"));
+    fed.add_property(&cap, property::new_property(property_name, vec_set::empty(), true, option::none()), scenario.ctx());
+    fed.deprecate_property(
+        &cap,
+        property_name,
+        option::some(new_property_name(utf8(b"unknown"))),
+        scenario.ctx(),
+    );
+
+    test_scenario::return_to_address(alice, cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EWildcardProperty)]
+fun test_add_property_fails_for_wildcard_name() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let wildcard = new_property_name_prefix(vector[utf8(b"degree")]);
+    fed.add_property(&cap, property::new_property(wildcard, vec_set::empty(), true, option::none()), scenario.ctx());
+
+    test_scenario::return_to_address(alice, cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_permission_checks_for_composition() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let mut allowed_values = vec_set::empty();
+    allowed_values.insert(new_property_value_number(1));
+    let property = property::new_property(property_name, allowed_values, false, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    let bob_id = @0x2.to_id();
+    let carol_id = @0x3.to_id();
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob_id,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+    fed.create_accreditation_to_accredit(
+        &accredit_cap,
+        carol_id,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+
+    let one = new_property_value_number(1);
+    let two = new_property_value_number(2);
+    assert!(fed.is_allowed_to_attest(&bob_id, property_name, one, &clock), 0);
+    assert!(!fed.is_allowed_to_attest(&bob_id, property_name, two, &clock), 1);
+    assert!(!fed.is_allowed_to_attest(&carol_id, property_name, one, &clock), 2);
+
+    assert!(fed.is_allowed_to_accredit(&carol_id, property_name, &clock), 3);
+    assert!(!fed.is_allowed_to_accredit(&bob_id, property_name, &clock), 4);
+    assert!(!fed.is_allowed_to_accredit(&carol_id, new_property_name(utf8(b"unknown")), &clock), 5);
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_value_dictionary_resolution() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let mut countries = vec_set::empty();
+    countries.insert(new_property_value_number(1));
+    countries.insert(new_property_value_number(2));
+    fed.set_value_dictionary(&root_cap, utf8(b"countries"), countries, scenario.ctx());
+
+    let property_name = new_property_name(utf8(b"country"));
+    let property = property::new_property_with_value_dictionary(
+        property_name,
+        utf8(b"countries"),
+    );
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    let bob_id = @0x2.to_id();
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob_id,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+
+    let one = new_property_value_number(1);
+    let three = new_property_value_number(3);
+    assert!(fed.validate_property(&bob_id, property_name, one, &clock), 0);
+    assert!(!fed.validate_property(&bob_id, property_name, three, &clock), 1);
+
+    // Updating the dictionary applies to all referencing properties
+    let mut countries = vec_set::empty();
+    countries.insert(new_property_value_number(3));
+    fed.set_value_dictionary(&root_cap, utf8(b"countries"), countries, scenario.ctx());
+    assert!(!fed.validate_property(&bob_id, property_name, one, &clock), 2);
+    assert!(fed.validate_property(&bob_id, property_name, three, &clock), 3);
+    assert!(fed.get_value_dictionary_names() == vector[utf8(b"countries")], 4);
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EValueDictionaryInUse)]
+fun test_remove_referenced_value_dictionary_fails() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let mut values = vec_set::empty();
+    values.insert(new_property_value_number(1));
+    fed.set_value_dictionary(&root_cap, utf8(b"flags"), values, scenario.ctx());
+    let property = property::new_property_with_value_dictionary(
+        new_property_name(utf8(b"flag")),
+        utf8(b"flags"),
+    );
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    fed.remove_value_dictionary(&root_cap, utf8(b"flags"), scenario.ctx());
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_grant_policy_applies_default_validity() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let mut allowed_values = vec_set::empty();
+    allowed_values.insert(new_property_value_number(1));
+    let property = property::new_property(property_name, allowed_values, false, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+    fed.set_grant_policy(&root_cap, option::some(500), true, option::some(1), scenario.ctx());
+    scenario.next_tx(alice);
+
+    let new_id = scenario.new_object();
+    let bob = new_id.uid_to_inner();
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+    scenario.next_tx(alice);
+
+    let accreditation = &fed.get_accreditations_to_attest(&bob).accredited_properties()[0];
+    let granted = accreditation.properties().get(&property_name);
+    assert!(granted.timespan().valid_until_ms() == option::some(1500), 0);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    new_id.delete();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EInvalidDefaultValidity)]
+fun test_grant_policy_rejects_zero_default_validity() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    // Granted properties would expire the moment they become valid
+    fed.set_grant_policy(&root_cap, option::some(0), true, option::none(), scenario.ctx());
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EInvalidDefaultValidity)]
+fun test_grant_policy_rejects_overflowing_default_validity() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    // Adding the validity to the grant time would overflow and block every grant
+    fed.set_grant_policy(
+        &root_cap,
+        option::some(18446744073709551615),
+        true,
+        option::none(),
+        scenario.ctx(),
+    );
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EAllowAnyNotPermitted)]
+fun test_grant_policy_rejects_allow_any() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+    fed.set_grant_policy(&root_cap, option::none(), false, option::none(), scenario.ctx());
+    scenario.next_tx(alice);
+
+    let new_id = scenario.new_object();
+    let bob = new_id.uid_to_inner();
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    new_id.delete();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_get_properties_by_prefix() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let iso_9001 = new_property_name_from_vector(
+        vector[utf8(b"compliance"), utf8(b"iso"), utf8(b"9001")],
+    );
+    let gdpr = new_property_name_from_vector(vector[utf8(b"compliance"), utf8(b"gdpr")]);
+    let role = new_property_name(utf8(b"role"));
+    let mut names = vector[iso_9001, gdpr, role];
+    while (!names.is_empty()) {
+        let property = property::new_property(names.remove(0), vec_set::empty(), true, option::none());
+        fed.add_property(&root_cap, property, scenario.ctx());
+    };
+
+    let compliance = new_property_name(utf8(b"compliance"));
+    assert!(fed.get_properties_by_prefix(&compliance) == vector[iso_9001, gdpr], 0);
+    let iso = new_property_name_from_vector(vector[utf8(b"compliance"), utf8(b"iso")]);
+    assert!(fed.get_properties_by_prefix(&iso) == vector[iso_9001], 1);
+    assert!(fed.get_properties_by_prefix(&iso_9001) == vector[iso_9001], 2);
+    // Prefixes match whole names, not partial ones
+    assert!(fed.get_properties_by_prefix(&new_property_name(utf8(b"comp"))).is_empty(), 3);
+    assert!(fed.get_properties_by_prefix(&new_property_name_from_vector(vector[])).length() == 3, 4);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_get_properties_page() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let mut names = vector[utf8(b"role"), utf8(b"department"), utf8(b"level")];
+    while (!names.is_empty()) {
+        let name = new_property_name(names.remove(0));
+        let property = property::new_property(name, vec_set::empty(), true, option::none());
+        fed.add_property(&root_cap, property, scenario.ctx());
+    };
+
+    let first = fed.get_properties_page(0, 2);
+    assert!(first.page_properties().length() == 2, 0);
+    assert!(first.page_next_cursor() == option::some(2), 1);
+    assert!(*first.page_properties()[0].property_name() == new_property_name(utf8(b"role")), 2);
+
+    let second = fed.get_properties_page(2, 2);
+    assert!(second.page_properties().length() == 1, 3);
+    assert!(second.page_next_cursor().is_none(), 4);
+    assert!(fed.get_properties_page(3, 2).page_properties().is_empty(), 5);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_property_and_accreditation_tags() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    let tag = utf8(b"food-safety");
+    fed.set_property_tags(&root_cap, property_name, vector[tag], scenario.ctx());
+    assert!(fed.get_property_tags(&property_name) == vector[tag], 0);
+    assert!(fed.get_properties_by_tag(&tag) == vector[property_name], 1);
+    scenario.next_tx(alice);
+
+    let new_id = scenario.new_object();
+    let bob = new_id.uid_to_inner();
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+    scenario.next_tx(alice);
+
+    let accreditation_id = fed
+        .get_accreditations_to_attest(&bob)
+        .accredited_properties()[0]
+        .id()
+        .uid_to_inner();
+    fed.set_accreditation_tags(accreditation_id, vector[tag], scenario.ctx());
+    assert!(fed.get_accreditations_by_tag(&tag) == vector[accreditation_id], 2);
+
+    // Revoking the accreditation drops its tags
+    fed.revoke_accreditation_to_attest(
+        &accredit_cap,
+        &bob,
+        &accreditation_id,
+        &clock,
+        scenario.ctx(),
+    );
+    assert!(fed.get_accreditation_tags(&accreditation_id).is_empty(), 3);
+
+    // An empty set removes the property tags
+    fed.set_property_tags(&root_cap, property_name, vector[], scenario.ctx());
+    assert!(fed.get_properties_by_tag(&tag).is_empty(), 4);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    new_id.delete();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::ENotAccreditationIssuer)]
+fun test_accreditation_tags_require_issuer() {
+    let alice = @0x1;
+    let charlie = @0x3;
+    let mut scenario = test_scenario::begin(alice);
+
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    let new_id = scenario.new_object();
+    let bob = new_id.uid_to_inner();
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob,
+        vector[property],
+        &clock,
+        scenario.ctx(),
+    );
+    scenario.next_tx(charlie);
+
+    let accreditation_id = fed
+        .get_accreditations_to_attest(&bob)
+        .accredited_properties()[0]
+        .id()
+        .uid_to_inner();
+    fed.set_accreditation_tags(accreditation_id, vector[utf8(b"x")], scenario.ctx());
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    new_id.delete();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_federation_stats() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let stats = fed.get_stats();
+    assert!(stats.property_count() == 0, 0);
+    assert!(stats.root_authority_count() == 1, 0);
+    assert!(stats.modification_count() == 0, 0);
+
+    let property_name = new_property_name(utf8(b"property_name"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&cap, property, scenario.ctx());
+
+    let new_id = scenario.new_object();
+    let bob = new_id.uid_to_inner();
+    fed.add_root_authority(&cap, bob, scenario.ctx());
+    fed.create_accreditation_to_accredit(&accredit_cap, bob, vector[property], &clock, scenario.ctx());
+    fed.create_accreditation_to_attest(&accredit_cap, bob, vector[property], &clock, scenario.ctx());
+    fed.create_accreditation_to_attest(&accredit_cap, bob, vector[property], &clock, scenario.ctx());
+
+    let permission_id = fed
+        .get_accreditations_to_attest(&bob)
+        .accredited_properties()[0]
+        .id()
+        .uid_to_inner();
+    fed.revoke_accreditation_to_attest(&accredit_cap, &bob, &permission_id, &clock, scenario.ctx());
+    fed.revoke_root_authority(&cap, bob, scenario.ctx());
+
+    let stats = fed.get_stats();
+    assert!(stats.property_count() == 1, 0);
+    assert!(stats.root_authority_count() == 1, 0);
+    assert!(stats.accreditation_to_accredit_count() == 1, 0);
+    assert!(stats.accreditation_to_attest_count() == 1, 0);
+    assert!(stats.modification_count() == 7, 0);
+
+    scenario.next_tx(alice);
+    let bob_cap: RootAuthorityCap = scenario.take_from_address(bob.to_address());
+    let bob_accredit_cap: AccreditCap = scenario.take_from_address(bob.to_address());
+
+    test_scenario::return_to_address(alice, cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    test_scenario::return_to_address(bob.to_address(), bob_cap);
+    test_scenario::return_to_address(bob.to_address(), bob_accredit_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    new_id.delete();
+
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_objection_window() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let value = new_property_value_number(1);
+    let mut allowed_values = vec_set::empty();
+    allowed_values.insert(value);
+    let property = property::new_property(property_name, allowed_values, false, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+    fed.set_objection_window(&root_cap, option::some(500), scenario.ctx());
+    // Setting the grant policy keeps the objection window
+    fed.set_grant_policy(&root_cap, option::none(), true, option::none(), scenario.ctx());
+    assert!(fed.get_grant_policy().objection_window_ms() == option::some(500), 0);
+    scenario.next_tx(alice);
+
+    let bob_uid = scenario.new_object();
+    let bob = bob_uid.uid_to_inner();
+    let carol_uid = scenario.new_object();
+    let carol = carol_uid.uid_to_inner();
+    fed.create_accreditation_to_attest(&accredit_cap, bob, vector[property], &clock, scenario.ctx());
+    fed.create_accreditation_to_attest(&accredit_cap, carol, vector[property], &clock, scenario.ctx());
+    scenario.next_tx(alice);
+
+    let accreditation = &fed.get_accreditations_to_attest(&bob).accredited_properties()[0];
+    assert!(accreditation.pending_until_ms() == option::some(1500), 1);
+    assert!(!fed.validate_property(&bob, property_name, value, &clock), 2);
+
+    // A root authority can end the window early
+    let carol_accreditation_id = fed
+        .get_accreditations_to_attest(&carol)
+        .accredited_properties()[0]
+        .id()
+        .to_inner();
+    fed.activate_accreditation(&root_cap, carol_accreditation_id, scenario.ctx());
+    assert!(fed.validate_property(&carol, property_name, value, &clock), 3);
+    assert!(!fed.validate_property(&bob, property_name, value, &clock), 4);
+
+    // The accreditation is effective from the end of the window
+    clock.set_for_testing(1500);
+    assert!(fed.validate_property(&bob, property_name, value, &clock), 5);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    bob_uid.delete();
+    carol_uid.delete();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_wildcard_accreditation_to_attest() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let bachelor = new_property_name_from_vector(vector[utf8(b"degree"), utf8(b"bachelor")]);
+    let master = new_property_name_from_vector(vector[utf8(b"degree"), utf8(b"master")]);
+    let role = new_property_name(utf8(b"role"));
+    let value = new_property_value_number(1);
+    fed.add_property(
+        &root_cap,
+        property::new_property(bachelor, vec_set::empty(), true, option::none()),
+        scenario.ctx(),
+    );
+    fed.add_property(
+        &root_cap,
+        property::new_property(role, vec_set::empty(), true, option::none()),
+        scenario.ctx(),
+    );
+
+    // The wildcard is granted although `degree.*` is not a property of the federation
+    let bob_id = @0x2.to_id();
+    let degrees = property::new_property(
+        new_property_name_prefix(vector[utf8(b"degree")]),
+        vec_set::empty(),
+        true,
+        option::none(),
+    );
+    fed.create_accreditation_to_attest(
+        &accredit_cap,
+        bob_id,
+        vector[degrees],
+        &clock,
+        scenario.ctx(),
+    );
+    assert!(fed.validate_property(&bob_id, bachelor, value, &clock), 0);
+    assert!(!fed.validate_property(&bob_id, role, value, &clock), 1);
+
+    // Properties added to the namespace later on are covered too, once they are in the federation
+    assert!(!fed.validate_property(&bob_id, master, value, &clock), 2);
+    fed.add_property(
+        &root_cap,
+        property::new_property(master, vec_set::empty(), true, option::none()),
+        scenario.ctx(),
+    );
+    assert!(fed.validate_property(&bob_id, master, value, &clock), 3);
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_quorum_revoke_root_authority() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let charlie = @0x3;
+    let mut scenario = test_scenario::begin(alice);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.add_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+    assert!(fed.get_quorum_threshold() == 2, 0);
+    assert!(fed.is_quorum_required(), 1);
+
+    scenario.next_tx(bob);
+    let bob_cap: RootAuthorityCap = scenario.take_from_address(bob);
+
+    scenario.next_tx(alice);
+    let proposal_id = fed.propose_action(
+        &alice_cap,
+        new_action_revoke_root_authority(charlie.to_id()),
+        scenario.ctx(),
+    );
+    assert!(fed.get_proposal_ids() == vector[proposal_id], 2);
+
+    scenario.next_tx(bob);
+    fed.approve_action(&bob_cap, proposal_id, scenario.ctx());
+    fed.execute_action(&bob_cap, proposal_id, &clock, scenario.ctx());
+
+    assert!(!fed.is_root_authority(&charlie.to_id()), 3);
+    assert!(fed.get_proposal_ids().is_empty(), 4);
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_to_address(bob, bob_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumRequired)]
+fun test_quorum_rejects_direct_revocation() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    fed.revoke_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumRequired)]
+fun test_quorum_rejects_direct_addition() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let charlie = @0x3;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    fed.add_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumRequired)]
+fun test_quorum_rejects_direct_reinstatement() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let charlie = @0x3;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.add_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+    fed.revoke_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    fed.reinstate_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_quorum_governs_configuration_changes() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let child = @0x100.to_id();
+    let mut scenario = test_scenario::begin(alice);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let property_name = new_property_name(utf8(b"iso.22000"));
+    fed.add_property(&alice_cap, property::new_property(property_name, vec_set::empty(), true, option::none()), scenario.ctx());
+    fed.link_federation(&alice_cap, child, scenario.ctx());
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    scenario.next_tx(bob);
+    let bob_cap: RootAuthorityCap = scenario.take_from_address(bob);
+
+    let mut countries = vec_set::empty();
+    countries.insert(new_property_value_number(1));
+    let actions = vector[
+        new_action_set_value_dictionary(utf8(b"countries"), countries),
+        new_action_deprecate_property(property_name, option::none()),
+        new_action_unlink_federation(child),
+    ];
+    let mut idx = 0;
+    while (idx < actions.length()) {
+        scenario.next_tx(alice);
+        let proposal_id = fed.propose_action(&alice_cap, actions[idx], scenario.ctx());
+
+        scenario.next_tx(bob);
+        fed.approve_action(&bob_cap, proposal_id, scenario.ctx());
+        fed.execute_action(&bob_cap, proposal_id, &clock, scenario.ctx());
+        idx = idx + 1;
+    };
+
+    assert!(fed.get_value_dictionary_names() == vector[utf8(b"countries")], 0);
+    assert!(fed.is_property_deprecated(property_name), 1);
+    assert!(!fed.is_federation_recognized(&child), 2);
+    assert!(fed.get_proposal_ids().is_empty(), 3);
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_to_address(bob, bob_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumRequired)]
+fun test_quorum_rejects_direct_value_dictionary() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    let mut countries = vec_set::empty();
+    countries.insert(new_property_value_number(1));
+    fed.set_value_dictionary(&alice_cap, utf8(b"countries"), countries, scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumRequired)]
+fun test_quorum_rejects_direct_deprecation() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let property_name = new_property_name(utf8(b"iso.22000"));
+    fed.add_property(&alice_cap, property::new_property(property_name, vec_set::empty(), true, option::none()), scenario.ctx());
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    fed.deprecate_property(&alice_cap, property_name, option::none(), scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumRequired)]
+fun test_quorum_rejects_direct_unlink() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let child = @0x100.to_id();
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.link_federation(&alice_cap, child, scenario.ctx());
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    fed.unlink_federation(&alice_cap, child, scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_quorum_add_and_reinstate_root_authority() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let charlie = @0x3;
+    let dave = @0x4;
+    let mut scenario = test_scenario::begin(alice);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.add_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+    fed.revoke_root_authority(&alice_cap, charlie.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    scenario.next_tx(bob);
+    let bob_cap: RootAuthorityCap = scenario.take_from_address(bob);
+
+    scenario.next_tx(alice);
+    let add_id = fed.propose_action(
+        &alice_cap,
+        new_action_add_root_authority(dave.to_id()),
+        scenario.ctx(),
+    );
+    let reinstate_id = fed.propose_action(
+        &alice_cap,
+        new_action_reinstate_root_authority(charlie.to_id()),
+        scenario.ctx(),
+    );
+
+    scenario.next_tx(bob);
+    fed.approve_action(&bob_cap, add_id, scenario.ctx());
+    fed.approve_action(&bob_cap, reinstate_id, scenario.ctx());
+    fed.execute_action(&bob_cap, add_id, &clock, scenario.ctx());
+    fed.execute_action(&bob_cap, reinstate_id, &clock, scenario.ctx());
+
+    assert!(fed.is_root_authority(&dave.to_id()), 0);
+    assert!(fed.is_root_authority(&charlie.to_id()), 1);
+    assert!(fed.get_proposal_ids().is_empty(), 2);
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_to_address(bob, bob_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EQuorumNotReached)]
+fun test_quorum_not_reached() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    // Lowering the threshold needs the quorum as well
+    let proposal_id = fed.propose_action(
+        &alice_cap,
+        new_action_set_quorum_threshold(1),
+        scenario.ctx(),
+    );
+    fed.execute_action(&alice_cap, proposal_id, &clock, scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_set_federation_metadata() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    assert!(fed.get_metadata().metadata_name().is_none(), 0);
+
+    fed.set_federation_metadata(
+        &root_cap,
+        option::some(utf8(b"International Standards Consortium")),
+        option::none(),
+        option::some(utf8(b"https://example.org/logo.png")),
+        option::some(utf8(b"admin@example.org")),
+        scenario.ctx(),
+    );
+
+    let metadata = fed.get_metadata();
+    assert!(metadata.metadata_name() == option::some(utf8(b"International Standards Consortium")), 1);
+    assert!(metadata.metadata_description().is_none(), 2);
+    assert!(metadata.metadata_logo_uri() == option::some(utf8(b"https://example.org/logo.png")), 3);
+    assert!(metadata.metadata_contact() == option::some(utf8(b"admin@example.org")), 4);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EInvalidMetadata)]
+fun test_set_federation_metadata_rejects_empty_field() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    fed.set_federation_metadata(
+        &root_cap,
+        option::some(utf8(b"")),
+        option::none(),
+        option::none(),
+        option::none(),
+        scenario.ctx(),
+    );
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_set_property_metadata() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"gpa"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+    assert!(fed.get_property_metadata(&property_name).is_empty(), 0);
+
+    fed.set_property_metadata(
+        &root_cap,
+        property_name,
+        vector[utf8(b"display_name"), utf8(b"unit")],
+        vector[utf8(b"Grade point average"), utf8(b"hundredths")],
+        scenario.ctx(),
+    );
+    let metadata = fed.get_property_metadata(&property_name);
+    assert!(metadata.size() == 2, 1);
+    assert!(*metadata.get(&utf8(b"unit")) == utf8(b"hundredths"), 2);
+
+    // Empty keys and values remove the metadata
+    fed.set_property_metadata(&root_cap, property_name, vector[], vector[], scenario.ctx());
+    assert!(fed.get_property_metadata(&property_name).is_empty(), 3);
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EInvalidMetadata)]
+fun test_set_property_metadata_rejects_empty_value() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"gpa"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    fed.set_property_metadata(&root_cap, property_name, vector[utf8(b"unit")], vector[utf8(b"")], scenario.ctx());
+
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_transfer_root_authority_cap() {
+    let alice = @0x1;
+    let dave = @0x4;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.transfer_root_authority_cap(alice_cap, dave, scenario.ctx());
+
+    assert!(!fed.is_root_authority(&alice.to_id()), 0);
+    assert!(fed.is_root_authority(&dave.to_id()), 1);
+    assert!(fed.is_accreditor(&dave.to_id()), 2);
+    assert!(!fed.is_accreditor(&alice.to_id()), 3);
+
+    scenario.next_tx(dave);
+    let dave_cap: RootAuthorityCap = scenario.take_from_address(dave);
+    let property_name = new_property_name(utf8(b"rotated"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&dave_cap, property, scenario.ctx());
+
+    test_scenario::return_to_address(dave, dave_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::ETransferNotConfirmed)]
+fun test_transfer_requires_confirmation_with_quorum() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let dave = @0x4;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    fed.transfer_root_authority_cap(alice_cap, dave, scenario.ctx());
+
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_confirmed_transfer_with_quorum() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let dave = @0x4;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+    fed.set_quorum_threshold(&alice_cap, 2, scenario.ctx());
+
+    scenario.next_tx(bob);
+    let bob_cap: RootAuthorityCap = scenario.take_from_address(bob);
+    fed.confirm_capability_transfer(&bob_cap, object::id(&alice_cap), dave, scenario.ctx());
+
+    scenario.next_tx(alice);
+    fed.transfer_root_authority_cap(alice_cap, dave, scenario.ctx());
+
+    assert!(fed.is_root_authority(&dave.to_id()), 0);
+    assert!(!fed.is_root_authority(&alice.to_id()), 1);
+
+    test_scenario::return_to_address(bob, bob_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_revoke_property_with_reason() {
+    let alice = @0x1;
+    let mut scenario = test_scenario::begin(alice);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    fed.revoke_property_with_reason(
+        &root_cap,
+        property_name,
+        2,
+        utf8(b"recalled after misconduct"),
+        &clock,
+        scenario.ctx(),
+    );
+    let effects = scenario.next_tx(alice);
+    // PropertyRevokedEvent and RevocationReasonEvent
+    assert!(effects.num_user_events() == 2, 0);
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EAnnotationTooLong)]
+fun test_revoke_root_authority_with_too_long_annotation() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let alice_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    fed.add_root_authority(&alice_cap, bob.to_id(), scenario.ctx());
+
+    let mut annotation = vector::empty();
+    let mut i = 0;
+    while (i < 257) {
+        annotation.push_back(0x61);
+        i = i + 1;
+    };
+    fed.revoke_root_authority_with_reason(&alice_cap, bob.to_id(), 1, utf8(annotation), scenario.ctx());
+
+    test_scenario::return_to_address(alice, alice_cap);
+    test_scenario::return_shared(fed);
+    let _ = scenario.end();
+}
+
+#[test]
+fun test_create_and_revoke_attestation() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+    let mut clock = clock::create_for_testing(scenario.ctx());
+    clock.set_for_testing(1000);
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+    let accredit_cap: AccreditCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    // Attesting requires an accreditation to attest, also for root authorities
+    fed.create_accreditation_to_attest(&accredit_cap, alice.to_id(), vector[property], &clock, scenario.ctx());
+
+    let mut properties = vec_map::empty();
+    properties.insert(property_name, new_property_value_number(1));
+    fed.create_attestation(bob, properties, option::none(), option::some(5000), &clock, scenario.ctx());
+    scenario.next_tx(bob);
+
+    let attestation: Attestation = scenario.take_from_address(bob);
+    assert!(attestation.issuer() == alice.to_id(), 0);
+    assert!(fed.validate_attestation(&attestation, &clock), 0);
+
+    // Attestations are only valid within their validity window
+    clock.set_for_testing(5000);
+    assert!(!fed.validate_attestation(&attestation, &clock), 0);
+    clock.set_for_testing(1000);
+
+    scenario.next_tx(alice);
+    fed.revoke_attestation(object::id(&attestation), scenario.ctx());
+    assert!(fed.is_attestation_revoked(object::id(&attestation)), 0);
+    assert!(!fed.validate_attestation(&attestation, &clock), 0);
+
+    test_scenario::return_to_address(bob, attestation);
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    test_scenario::return_to_address(alice, accredit_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EEmptyAttestation)]
+fun test_create_attestation_without_properties() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+
+    // Anyone passes the validation of no properties
+    scenario.next_tx(bob);
+    fed.create_attestation(alice, vec_map::empty(), option::none(), option::none(), &clock, scenario.ctx());
+
+    test_scenario::return_shared(fed);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::main::EUnauthorizedAttester)]
+fun test_create_attestation_without_accreditation() {
+    let alice = @0x1;
+    let bob = @0x2;
+    let mut scenario = test_scenario::begin(alice);
+    let clock = clock::create_for_testing(scenario.ctx());
+
+    new_federation(scenario.ctx());
+    scenario.next_tx(alice);
+
+    let mut fed: Federation = scenario.take_shared();
+    let root_cap: RootAuthorityCap = scenario.take_from_address(alice);
+
+    let property_name = new_property_name(utf8(b"role"));
+    let property = property::new_property(property_name, vec_set::empty(), true, option::none());
+    fed.add_property(&root_cap, property, scenario.ctx());
+
+    scenario.next_tx(bob);
+    let mut properties = vec_map::empty();
+    properties.insert(property_name, new_property_value_number(1));
+    fed.create_attestation(alice, properties, option::none(), option::none(), &clock, scenario.ctx());
+
+    test_scenario::return_shared(fed);
+    test_scenario::return_to_address(alice, root_cap);
+    clock.destroy_for_testing();
+    let _ = scenario.end();
+}
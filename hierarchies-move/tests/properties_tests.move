@@ -122,6 +122,26 @@ fun test_matches_name_value() {
     assert!(property::matches_name_value(&property, &name, &value, 1000u64), 0);
 }
 
+#[test]
+fun test_matches_name_wildcard() {
+    let degree = vector[string::utf8(b"degree")];
+    let property = property::new_property(
+        property_name::new_property_name_prefix(degree),
+        vec_set::empty(),
+        true,
+        option::none(),
+    );
+    let bachelor = property_name::new_property_name_from_vector(
+        vector[string::utf8(b"degree"), string::utf8(b"bachelor")],
+    );
+
+    assert!(property::matches_name(&property, &bachelor), 0);
+    assert!(property::matches_name(&property, property::property_name(&property)), 1);
+    // The wildcard covers the names below the prefix, not the prefix itself
+    assert!(!property::matches_name(&property, &create_test_property_name_simple(b"degree")), 2);
+    assert!(!property::matches_name(&property, &create_test_property_name_simple(b"role")), 3);
+}
+
 #[test]
 fun test_revoke_property() {
     let mut property = create_simple_property(b"test", b"value", false);
@@ -1,7 +1,7 @@
 #[test_only]
 module hierarchies::property_shape_tests;
 
-use hierarchies::{property_shape, property_value};
+use hierarchies::{property_shape, property_value::{Self, PropertyValue}};
 use std::string;
 
 #[test]
@@ -139,3 +139,188 @@ fun test_contains_empty_string() {
 
     assert!(property_shape::property_shape_matches(&condition, &value), 0);
 }
+
+#[test]
+fun test_between_inclusive_bounds() {
+    let condition = property_shape::new_property_shape_between(200, 400);
+
+    assert!(property_shape::property_shape_matches(&condition, &number(200)), 0);
+    assert!(property_shape::property_shape_matches(&condition, &number(320)), 0);
+    assert!(property_shape::property_shape_matches(&condition, &number(400)), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &number(199)), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &number(401)), 0);
+}
+
+#[test]
+fun test_between_with_string_value() {
+    let condition = property_shape::new_property_shape_between(0, 10);
+
+    assert!(!property_shape::property_shape_matches(&condition, &text(b"5")), 0);
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::EInvalidRange)]
+fun test_between_invalid_range() {
+    property_shape::new_property_shape_between(10, 5);
+}
+
+#[test]
+fun test_equal_to() {
+    let condition = property_shape::new_property_shape_equal_to(text(b"active"));
+
+    assert!(property_shape::property_shape_matches(&condition, &text(b"active")), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &text(b"inactive")), 0);
+}
+
+#[test]
+fun test_not_equal_to() {
+    let condition = property_shape::new_property_shape_not_equal_to(number(0));
+
+    assert!(property_shape::property_shape_matches(&condition, &number(1)), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &number(0)), 0);
+    // Values of a different type are never equal
+    assert!(property_shape::property_shape_matches(&condition, &text(b"0")), 0);
+}
+
+#[test]
+fun test_composite_all() {
+    // starts_with "HARV-" AND contains "-"
+    let condition = property_shape::new_property_shape_composite(vector[
+        property_shape::new_shape_node(property_shape::new_property_shape_starts_with(string::utf8(b"HARV-"))),
+        property_shape::new_shape_node(property_shape::new_property_shape_ends_with(string::utf8(b"-2025"))),
+        property_shape::new_shape_node_all(2),
+    ]);
+
+    assert!(property_shape::property_shape_matches(&condition, &text(b"HARV-CS-2025")), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &text(b"HARV-CS-2024")), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &text(b"MIT-CS-2025")), 0);
+}
+
+#[test]
+fun test_composite_any_and_not() {
+    // NOT (value < 10 OR value > 20)
+    let condition = property_shape::new_property_shape_composite(vector[
+        property_shape::new_shape_node(property_shape::new_property_shape_lower_than(10)),
+        property_shape::new_shape_node(property_shape::new_property_shape_greater_than(20)),
+        property_shape::new_shape_node_any(2),
+        property_shape::new_shape_node_not(),
+    ]);
+
+    assert!(property_shape::property_shape_matches(&condition, &number(15)), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &number(5)), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &number(25)), 0);
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::EInvalidComposite)]
+fun test_composite_missing_operand() {
+    property_shape::new_property_shape_composite(vector[
+        property_shape::new_shape_node(property_shape::new_property_shape_lower_than(10)),
+        property_shape::new_shape_node_all(2),
+    ]);
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::EInvalidComposite)]
+fun test_composite_dangling_result() {
+    property_shape::new_property_shape_composite(vector[
+        property_shape::new_shape_node(property_shape::new_property_shape_lower_than(10)),
+        property_shape::new_shape_node(property_shape::new_property_shape_greater_than(20)),
+    ]);
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::ENestedComposite)]
+fun test_composite_nested() {
+    let inner = property_shape::new_property_shape_composite(vector[
+        property_shape::new_shape_node(property_shape::new_property_shape_lower_than(10)),
+    ]);
+    property_shape::new_shape_node(inner);
+}
+
+#[test]
+fun test_matches_pattern() {
+    let country = property_shape::new_property_shape_matches_pattern(string::utf8(b"[A-Z][A-Z]"));
+    assert!(property_shape::property_shape_matches(&country, &text(b"CH")), 0);
+    assert!(!property_shape::property_shape_matches(&country, &text(b"ch")), 0);
+    assert!(!property_shape::property_shape_matches(&country, &text(b"CHE")), 0);
+    assert!(!property_shape::property_shape_matches(&country, &number(1)), 0);
+
+    let student_id = property_shape::new_property_shape_matches_pattern(string::utf8(b"S-####-@?"));
+    assert!(property_shape::property_shape_matches(&student_id, &text(b"S-2024-ab")), 0);
+    assert!(!property_shape::property_shape_matches(&student_id, &text(b"S-20x4-ab")), 0);
+
+    let wildcard = property_shape::new_property_shape_matches_pattern(string::utf8(b"*.edu\\*"));
+    assert!(property_shape::property_shape_matches(&wildcard, &text(b"mit.edu*")), 0);
+    assert!(property_shape::property_shape_matches(&wildcard, &text(b"a.edu.b.edu*")), 0);
+    assert!(!property_shape::property_shape_matches(&wildcard, &text(b"mit.edu")), 0);
+
+    let class = property_shape::new_property_shape_matches_pattern(string::utf8(b"[a-c_-]*"));
+    assert!(property_shape::property_shape_matches(&class, &text(b"-x")), 0);
+    assert!(!property_shape::property_shape_matches(&class, &text(b"d")), 0);
+}
+
+#[test]
+fun test_matches_pattern_in_composite() {
+    let condition = property_shape::new_property_shape_composite(vector[
+        property_shape::new_shape_node(
+            property_shape::new_property_shape_matches_pattern(string::utf8(b"HARV-*")),
+        ),
+        property_shape::new_shape_node_not(),
+    ]);
+    assert!(property_shape::property_shape_matches(&condition, &text(b"MIT-CS")), 0);
+    assert!(!property_shape::property_shape_matches(&condition, &text(b"HARV-CS")), 0);
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::EInvalidPattern)]
+fun test_matches_pattern_unclosed_class() {
+    property_shape::new_property_shape_matches_pattern(string::utf8(b"[A-Z"));
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::EInvalidPattern)]
+fun test_matches_pattern_reversed_range() {
+    property_shape::new_property_shape_matches_pattern(string::utf8(b"[z-a]"));
+}
+
+#[test]
+#[expected_failure(abort_code = hierarchies::property_shape::EInvalidPattern)]
+fun test_matches_pattern_trailing_escape() {
+    property_shape::new_property_shape_matches_pattern(string::utf8(b"abc\\"));
+}
+
+#[test]
+fun test_numeric_shapes_match_timestamps() {
+    let condition = property_shape::new_property_shape_between(1_000, 2_000);
+    let timestamp = property_value::new_property_value_timestamp(1_500);
+    assert!(property_shape::property_shape_matches(&condition, &timestamp), 0);
+
+    let condition = property_shape::new_property_shape_greater_than(2_000);
+    assert!(!property_shape::property_shape_matches(&condition, &timestamp), 0);
+}
+
+#[test]
+fun test_typed_values() {
+    let verified = property_value::new_property_value_bool(true);
+    let condition = property_shape::new_property_shape_equal_to(verified);
+    assert!(property_shape::property_shape_matches(&condition, &verified), 0);
+    assert!(
+        !property_shape::property_shape_matches(&condition, &property_value::new_property_value_bool(false)),
+        0,
+    );
+    // Values of different types are never equal
+    assert!(!property_shape::property_shape_matches(&condition, &number(1)), 0);
+
+    let bytes = property_value::new_property_value_bytes(b"abc");
+    let condition = property_shape::new_property_shape_contains(string::utf8(b"b"));
+    assert!(!property_shape::property_shape_matches(&condition, &bytes), 0);
+}
+
+fun number(value: u64): PropertyValue {
+    property_value::new_property_value_number(value)
+}
+
+fun text(value: vector<u8>): PropertyValue {
+    property_value::new_property_value_string(string::utf8(value))
+}
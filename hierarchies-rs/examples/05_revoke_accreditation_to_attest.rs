@@ -5,6 +5,7 @@ use std::collections::HashSet;
 
 use anyhow::Context;
 use hierarchies::core::types::Federation;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
 use hierarchies::core::types::property::FederationProperty;
 use hierarchies::core::types::property_name::PropertyName;
 use hierarchies::core::types::property_value::PropertyValue;
@@ -68,6 +69,8 @@ async fn main() -> anyhow::Result<()> {
         shape: None,
         allow_any: false,
         timespan: Timespan::default(),
+        value_dictionary: None,
+        metadata: Default::default(),
     };
 
     // Let us issue an accreditation to attest to the Property
@@ -79,11 +82,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Issue an accreditation to the original account
     hierarchies_client
-        .create_accreditation_to_attest(
-            federation_id,
-            hierarchies_client.sender_address().into(),
-            vec![properties],
-        )
+        .create_accreditation_to_attest(federation_id, hierarchies_client.sender_address(), vec![properties])
         .build_and_execute(&hierarchies_client)
         .await
         .context("Failed to issue permission to attest")?;
@@ -99,12 +98,15 @@ async fn main() -> anyhow::Result<()> {
     assert!(can_attest);
 
     // Revoke the accreditation
+    let query = AccreditationQuery::new()
+        .for_entity(receiver)
+        .scope(AccreditationScope::Attest);
     let accreditations = hierarchies_client
-        .get_accreditations_to_attest(federation_id, receiver)
+        .query_accreditations(federation_id, &query)
         .await
         .context("Failed to find accreditation to attest")?;
 
-    let accreditation_id = accreditations.accreditations[0].id.object_id();
+    let accreditation_id = accreditations[0].accreditation.id.object_id();
 
     hierarchies_client
         .revoke_accreditation_to_attest(federation_id, receiver, *accreditation_id)
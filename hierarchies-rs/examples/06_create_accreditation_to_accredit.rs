@@ -67,6 +67,8 @@ async fn main() -> anyhow::Result<()> {
         shape: None,
         allow_any: false,
         timespan: Timespan::default(),
+        value_dictionary: None,
+        metadata: Default::default(),
     };
 
     // Let us issue an accreditation to accredit to the Property
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Context;
+use hierarchies::core::transactions::Destructive;
 use hierarchies::core::types::Federation;
 use hierarchies_examples::get_funded_client;
 use iota_sdk::types::base_types::ObjectID;
@@ -56,6 +57,7 @@ async fn main() -> anyhow::Result<()> {
     println!("Revoking second root authority: {second_root_authority:#?}");
     hierarchies_client
         .revoke_root_authority(federation_id, second_root_authority)
+        .confirm_irreversible()
         .build_and_execute(&hierarchies_client)
         .await
         .context("Failed to revoke root authority")?;
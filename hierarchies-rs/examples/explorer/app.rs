@@ -0,0 +1,150 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Explorer state and key handling.
+
+use std::collections::HashMap;
+
+use iota_sdk::types::base_types::ObjectID;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::widgets::ListState;
+
+use crate::data::{Explorer, FederationDetails};
+
+/// The detail views of a federation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Properties,
+    Attesters,
+    Accreditors,
+    Events,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Properties, Tab::Attesters, Tab::Accreditors, Tab::Events];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Properties => "Properties",
+            Tab::Attesters => "Attesters",
+            Tab::Accreditors => "Accreditors",
+            Tab::Events => "Events",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|tab| *tab == self).expect("tab is listed")
+    }
+
+    fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// What the main loop should do after a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Load,
+    Quit,
+}
+
+/// The state of the explorer.
+pub struct App {
+    pub federations: Vec<ObjectID>,
+    pub selection: ListState,
+    pub tab: Tab,
+    pub details: HashMap<ObjectID, FederationDetails>,
+    pub detail_scroll: u16,
+    pub status: String,
+}
+
+impl App {
+    pub fn new(federations: Vec<ObjectID>) -> Self {
+        let mut selection = ListState::default();
+        if !federations.is_empty() {
+            selection.select(Some(0));
+        }
+        let status = format!("{} federation(s) found", federations.len());
+
+        Self {
+            federations,
+            selection,
+            tab: Tab::Properties,
+            details: HashMap::new(),
+            detail_scroll: 0,
+            status,
+        }
+    }
+
+    pub fn selected_federation(&self) -> Option<ObjectID> {
+        self.selection
+            .selected()
+            .and_then(|idx| self.federations.get(idx))
+            .copied()
+    }
+
+    pub fn selected_details(&self) -> Option<&FederationDetails> {
+        self.selected_federation().and_then(|id| self.details.get(&id))
+    }
+
+    pub fn tab_index(&self) -> usize {
+        self.tab.index()
+    }
+
+    /// Updates the state for a key press.
+    pub fn on_key(&mut self, key: KeyCode) -> Action {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('r') => {
+                if let Some(id) = self.selected_federation() {
+                    self.details.remove(&id);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selection.select_previous();
+                self.detail_scroll = 0;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selection.select_next();
+                self.detail_scroll = 0;
+            }
+            KeyCode::Tab | KeyCode::Right => self.tab = self.tab.next(),
+            KeyCode::BackTab | KeyCode::Left => self.tab = self.tab.previous(),
+            KeyCode::PageDown => self.detail_scroll = self.detail_scroll.saturating_add(10),
+            KeyCode::PageUp => self.detail_scroll = self.detail_scroll.saturating_sub(10),
+            _ => return Action::None,
+        }
+
+        self.clamp_selection();
+        match self.selected_federation() {
+            Some(id) if !self.details.contains_key(&id) => Action::Load,
+            _ => Action::None,
+        }
+    }
+
+    /// Loads the details of the selected federation.
+    pub async fn load_selected(&mut self, explorer: &Explorer) {
+        let Some(id) = self.selected_federation() else {
+            return;
+        };
+        match explorer.load_federation(id).await {
+            Ok(details) => {
+                self.status = format!("loaded {id}");
+                self.details.insert(id, details);
+            }
+            Err(err) => self.status = format!("{err:#}"),
+        }
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.federations.len();
+        if self.selection.selected().is_some_and(|idx| idx >= len) {
+            self.selection.select(len.checked_sub(1));
+        }
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Network queries backing the explorer views.
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use anyhow::Context;
+use hierarchies::client::HierarchiesClientReadOnly;
+use hierarchies::core::types::{AccreditCap, Accreditations, Federation, RootAuthorityCap};
+use iota_sdk::IotaClient;
+use iota_sdk::rpc_types::{
+    EventFilter, IotaObjectDataFilter, IotaObjectDataOptions, IotaObjectResponse, IotaObjectResponseQuery,
+};
+use iota_sdk::types::Identifier;
+use iota_sdk::types::base_types::{IotaAddress, ObjectID, TypeTag};
+use serde::de::DeserializeOwned;
+
+/// Maximum number of events shown per federation.
+const EVENT_LIMIT: usize = 50;
+
+/// Everything the explorer shows about a single federation.
+#[derive(Debug, Clone)]
+pub struct FederationDetails {
+    pub federation: Federation,
+    pub events: Vec<EventRow>,
+}
+
+/// A Hierarchies event, reduced to what the event list displays.
+#[derive(Debug, Clone)]
+pub struct EventRow {
+    pub timestamp_ms: Option<u64>,
+    pub event_type: String,
+    pub json: serde_json::Value,
+}
+
+/// Clients used by the explorer.
+pub struct Explorer {
+    pub iota_client: IotaClient,
+    pub hierarchies: HierarchiesClientReadOnly,
+    pub package_id: ObjectID,
+}
+
+impl Explorer {
+    /// Lists the federations `address` holds a root authority or accreditation capability for.
+    pub async fn federations_for_address(&self, address: IotaAddress) -> anyhow::Result<Vec<ObjectID>> {
+        let mut federations = BTreeSet::new();
+
+        let root_caps: Vec<RootAuthorityCap> = self.owned_objects(address, "RootAuthorityCap").await?;
+        federations.extend(root_caps.into_iter().map(|cap| cap.federation_id));
+
+        let accredit_caps: Vec<AccreditCap> = self.owned_objects(address, "AccreditCap").await?;
+        federations.extend(accredit_caps.into_iter().map(|cap| cap.federation_id));
+
+        Ok(federations.into_iter().collect())
+    }
+
+    /// Loads a federation and its most recent events.
+    pub async fn load_federation(&self, federation_id: ObjectID) -> anyhow::Result<FederationDetails> {
+        let federation = self
+            .hierarchies
+            .get_federation_by_id(federation_id)
+            .await
+            .with_context(|| format!("failed to load federation {federation_id}"))?;
+        let events = self.federation_events(federation_id).await?;
+
+        Ok(FederationDetails { federation, events })
+    }
+
+    /// Returns the most recent events of the Hierarchies package that refer to `federation_id`.
+    async fn federation_events(&self, federation_id: ObjectID) -> anyhow::Result<Vec<EventRow>> {
+        let filter = EventFilter::MoveEventModule {
+            package: self.package_id,
+            module: Identifier::new("main")?,
+        };
+        let federation = federation_id.to_string();
+
+        let mut rows = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .iota_client
+                .event_api()
+                .query_events(filter.clone(), cursor, None, true)
+                .await
+                .context("failed to query events")?;
+
+            rows.extend(
+                page.data
+                    .into_iter()
+                    .filter(|event| event.parsed_json["federation_address"].as_str() == Some(federation.as_str()))
+                    .map(|event| EventRow {
+                        timestamp_ms: event.timestamp_ms,
+                        event_type: event.type_.name.to_string(),
+                        json: event.parsed_json,
+                    }),
+            );
+
+            if rows.len() >= EVENT_LIMIT || !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        rows.truncate(EVENT_LIMIT);
+
+        Ok(rows)
+    }
+
+    /// Returns all objects of the Hierarchies type `type_name` owned by `address`.
+    async fn owned_objects<T: DeserializeOwned>(
+        &self,
+        address: IotaAddress,
+        type_name: &str,
+    ) -> anyhow::Result<Vec<T>> {
+        let TypeTag::Struct(struct_tag) = TypeTag::from_str(&format!("{}::main::{type_name}", self.package_id))? else {
+            anyhow::bail!("`{type_name}` is not a struct type");
+        };
+        let query = IotaObjectResponseQuery::new(
+            Some(IotaObjectDataFilter::StructType(*struct_tag)),
+            Some(IotaObjectDataOptions::bcs_lossless()),
+        );
+
+        let mut objects = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .iota_client
+                .read_api()
+                .get_owned_objects(address, Some(query.clone()), cursor, None)
+                .await
+                .with_context(|| format!("failed to list `{type_name}` objects"))?;
+
+            for response in page.data {
+                objects.push(decode(response)?);
+            }
+
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        Ok(objects)
+    }
+}
+
+/// Decodes the BCS content of an object response.
+fn decode<T: DeserializeOwned>(response: IotaObjectResponse) -> anyhow::Result<T> {
+    response
+        .data
+        .and_then(|data| data.bcs)
+        .and_then(|bcs| bcs.try_into_move())
+        .context("object has no Move content")?
+        .deserialize()
+}
+
+/// Counts the accreditations held across all entities of a scope.
+pub fn count_accreditations<'a>(by_entity: impl IntoIterator<Item = &'a Accreditations>) -> usize {
+    by_entity.into_iter().map(Accreditations::len).sum()
+}
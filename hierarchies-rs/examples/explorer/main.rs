@@ -0,0 +1,128 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies explorer
+//!
+//! A terminal UI that lists the federations an address holds capabilities for and
+//! lets you browse their properties, accreditations and event history.
+//!
+//! ```bash
+//! IOTA_HIERARCHIES_PKG_ID=0x... cargo run --features explorer --bin explorer -- --address 0x...
+//! ```
+
+mod app;
+mod data;
+mod ui;
+
+use std::time::Duration;
+
+use anyhow::{Context, bail};
+use hierarchies::client::HierarchiesClientReadOnly;
+use hierarchies_examples::{get_iota_client, get_package_id};
+use iota_sdk::types::base_types::{IotaAddress, ObjectID};
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+use crate::app::{Action, App};
+use crate::data::Explorer;
+
+/// Command line configuration of the explorer.
+#[derive(Debug, Default)]
+struct Config {
+    addresses: Vec<IotaAddress>,
+    federations: Vec<ObjectID>,
+}
+
+impl Config {
+    fn from_args(args: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut config = Config::default();
+        let mut args = args.into_iter();
+
+        while let Some(flag) = args.next() {
+            if flag == "--help" || flag == "-h" {
+                print_usage();
+                std::process::exit(0);
+            }
+            let value = args.next().with_context(|| format!("missing value for `{flag}`"))?;
+            match flag.as_str() {
+                "--address" => config
+                    .addresses
+                    .push(value.parse().with_context(|| format!("invalid address `{value}`"))?),
+                "--federation" => config.federations.push(
+                    value
+                        .parse()
+                        .with_context(|| format!("invalid federation id `{value}`"))?,
+                ),
+                _ => bail!("unknown argument `{flag}`, see `--help`"),
+            }
+        }
+
+        if config.addresses.is_empty() && config.federations.is_empty() {
+            bail!("at least one `--address` or `--federation` is required, see `--help`");
+        }
+
+        Ok(config)
+    }
+}
+
+fn print_usage() {
+    println!("Usage: explorer [--address ADDRESS]... [--federation FEDERATION_ID]...");
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = Config::from_args(std::env::args().skip(1))?;
+
+    let iota_client = get_iota_client().await?;
+    let package_id = get_package_id()?;
+    let hierarchies = HierarchiesClientReadOnly::new_with_pkg_id(iota_client.clone(), package_id)
+        .await
+        .context("failed to create a read-only HierarchiesClient")?;
+    let explorer = Explorer {
+        iota_client,
+        hierarchies,
+        package_id,
+    };
+
+    let mut federations = config.federations;
+    for address in config.addresses {
+        federations.extend(explorer.federations_for_address(address).await?);
+    }
+    federations.sort();
+    federations.dedup();
+
+    let mut app = App::new(federations);
+    app.load_selected(&explorer).await;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app, &explorer).await;
+    ratatui::restore();
+
+    result
+}
+
+async fn run(terminal: &mut DefaultTerminal, app: &mut App, explorer: &Explorer) -> anyhow::Result<()> {
+    loop {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match app.on_key(key.code) {
+            Action::Quit => return Ok(()),
+            Action::Load => {
+                // Show the loading state before blocking on the network.
+                terminal.draw(|frame| ui::draw(frame, app))?;
+                app.load_selected(explorer).await;
+            }
+            Action::None => {}
+        }
+    }
+}
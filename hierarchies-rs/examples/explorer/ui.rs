@@ -0,0 +1,171 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rendering of the explorer views.
+
+use std::collections::HashMap;
+
+use hierarchies::core::types::Accreditations;
+use hierarchies::core::types::property::FederationProperty;
+use iota_sdk::types::base_types::ObjectID;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, Paragraph, Tabs, Wrap};
+
+use crate::app::{App, Tab};
+use crate::data::{FederationDetails, count_accreditations};
+
+const HELP: &str = "↑/↓ federation  ←/→ tab  PgUp/PgDn scroll  r reload  q quit";
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [main, status] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [federations, details] = Layout::horizontal([Constraint::Length(70), Constraint::Min(0)]).areas(main);
+
+    draw_federations(frame, app, federations);
+    draw_details(frame, app, details);
+
+    frame.render_widget(Line::from(format!(" {} | {HELP}", app.status)).dim(), status);
+}
+
+fn draw_federations(frame: &mut Frame, app: &mut App, area: Rect) {
+    let items = app.federations.iter().map(ObjectID::to_string);
+    let list = List::new(items)
+        .block(Block::bordered().title(" Federations "))
+        .highlight_style(Style::new().reversed())
+        .highlight_symbol("> ");
+
+    frame.render_stateful_widget(list, area, &mut app.selection);
+}
+
+fn draw_details(frame: &mut Frame, app: &App, area: Rect) {
+    let [tabs, body] = Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+    let titles = Tab::ALL.iter().map(|tab| tab.title());
+    frame.render_widget(
+        Tabs::new(titles)
+            .select(app.tab_index())
+            .highlight_style(Style::new().bold().reversed())
+            .block(Block::bordered()),
+        tabs,
+    );
+
+    let lines = match app.selected_details() {
+        Some(details) => detail_lines(details, app.tab),
+        None if app.selected_federation().is_some() => vec![Line::from("loading...")],
+        None => vec![Line::from("no federation selected")],
+    };
+    let title = app
+        .selected_federation()
+        .map(|id| format!(" {id} "))
+        .unwrap_or_default();
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::bordered().title(title))
+            .wrap(Wrap { trim: false })
+            .scroll((app.detail_scroll, 0)),
+        body,
+    );
+}
+
+fn detail_lines(details: &FederationDetails, tab: Tab) -> Vec<Line<'static>> {
+    let governance = &details.federation.governance;
+    match tab {
+        Tab::Properties => {
+            let mut properties: Vec<_> = governance.properties.data.values().collect();
+            properties.sort_by(|a, b| a.name.cmp(&b.name));
+            let mut lines = vec![
+                Line::from(format!(
+                    "{} properties, {} root authorities, {} critical",
+                    properties.len(),
+                    details.federation.root_authorities.len(),
+                    governance.critical_properties.len()
+                ))
+                .bold(),
+                Line::default(),
+            ];
+            lines.extend(properties.into_iter().map(|property| {
+                let critical = governance.critical_properties.contains(&property.name);
+                property_line(property, critical)
+            }));
+            lines
+        }
+        Tab::Attesters => accreditation_lines(&governance.accreditations_to_attest),
+        Tab::Accreditors => accreditation_lines(&governance.accreditations_to_accredit),
+        Tab::Events if details.events.is_empty() => vec![Line::from("no events found")],
+        Tab::Events => details
+            .events
+            .iter()
+            .map(|event| {
+                Line::from(format!(
+                    "{:>13}  {:<36}  {}",
+                    event.timestamp_ms.map(|ms| ms.to_string()).unwrap_or_default(),
+                    event.event_type,
+                    event.json
+                ))
+            })
+            .collect(),
+    }
+}
+
+fn property_line(property: &FederationProperty, critical: bool) -> Line<'static> {
+    let mut values: Vec<_> = property
+        .allowed_values
+        .iter()
+        .map(|value| format!("{value:?}"))
+        .collect();
+    values.sort();
+    let constraint = if property.allow_any {
+        "any value".to_string()
+    } else if let Some(dictionary) = &property.value_dictionary {
+        format!("dictionary {dictionary}")
+    } else if let Some(shape) = &property.shape {
+        format!("shape {shape:?}")
+    } else {
+        format!("values [{}]", values.join(", "))
+    };
+
+    let line = Line::from(format!(
+        "{}{}  {constraint}  valid {:?}..{:?}",
+        property.name.names().join("."),
+        if critical { " (critical)" } else { "" },
+        property.timespan.valid_from_ms,
+        property.timespan.valid_until_ms,
+    ));
+    if critical { line.red() } else { line }
+}
+
+fn accreditation_lines(by_entity: &HashMap<ObjectID, Accreditations>) -> Vec<Line<'static>> {
+    let mut entities: Vec<_> = by_entity.iter().collect();
+    entities.sort_by_key(|(id, _)| **id);
+
+    let mut lines = vec![
+        Line::from(format!(
+            "{} entities, {} accreditations",
+            entities.len(),
+            count_accreditations(by_entity.values())
+        ))
+        .bold(),
+    ];
+    for (entity_id, accreditations) in entities {
+        lines.push(Line::default());
+        lines.push(Line::from(entity_id.to_string()).bold());
+        for accreditation in accreditations.iter() {
+            let mut names: Vec<_> = accreditation
+                .properties
+                .keys()
+                .map(|name| name.names().join("."))
+                .collect();
+            names.sort();
+            lines.push(Line::from(format!(
+                "  {} by {}: {}",
+                accreditation.id.object_id(),
+                accreditation.accredited_by,
+                names.join(", ")
+            )));
+        }
+    }
+    lines
+}
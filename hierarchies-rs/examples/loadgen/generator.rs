@@ -0,0 +1,121 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use hierarchies::core::types::property::FederationProperty;
+use hierarchies::core::types::property_name::PropertyName;
+use hierarchies::core::types::property_value::PropertyValue;
+use iota_sdk::types::base_types::ObjectID;
+
+/// Number of allowed values generated for every property.
+const VALUES_PER_PROPERTY: usize = 4;
+
+/// Size of the synthetic federation.
+#[derive(Debug, Clone, Copy)]
+pub struct FederationShape {
+    /// Number of properties in the federation.
+    pub properties: usize,
+    /// Number of entities receiving accreditations.
+    pub entities: usize,
+    /// Number of accreditations to attest, spread over the entities.
+    pub accreditations: usize,
+}
+
+/// A single accreditation to attest of the synthetic federation.
+#[derive(Debug, Clone)]
+pub struct Grant {
+    pub receiver: ObjectID,
+    pub property: FederationProperty,
+}
+
+/// A synthetic federation layout that can be replayed onto a network.
+#[derive(Debug, Clone)]
+pub struct SyntheticFederation {
+    pub properties: Vec<FederationProperty>,
+    pub entities: Vec<ObjectID>,
+    pub grants: Vec<Grant>,
+}
+
+impl SyntheticFederation {
+    /// Generates a federation of the given shape.
+    ///
+    /// The layout only depends on `seed`, so runs can be repeated with the same data.
+    pub fn generate(shape: FederationShape, seed: u64) -> Self {
+        let mut rng = XorShift::new(seed);
+
+        let properties: Vec<_> = (0..shape.properties)
+            .map(|idx| {
+                let allowed_values = (0..VALUES_PER_PROPERTY)
+                    .map(|value| property_value(idx, value))
+                    .collect();
+                FederationProperty::new(PropertyName::new([
+                    "loadgen".to_string(),
+                    "property".to_string(),
+                    idx.to_string(),
+                ]))
+                .with_allowed_values(allowed_values)
+            })
+            .collect();
+
+        let entities: Vec<_> = (0..shape.entities).map(|_| ObjectID::random()).collect();
+
+        let grants = (0..shape.accreditations)
+            .map(|idx| {
+                let property_idx = rng.next_index(properties.len());
+                let granted_value = property_value(property_idx, rng.next_index(VALUES_PER_PROPERTY));
+                Grant {
+                    receiver: entities[idx % entities.len()],
+                    property: FederationProperty::new(properties[property_idx].name.clone())
+                        .with_allowed_values(HashSet::from([granted_value])),
+                }
+            })
+            .collect();
+
+        Self {
+            properties,
+            entities,
+            grants,
+        }
+    }
+
+    /// Picks a grant whose validation is expected to succeed.
+    pub fn pick_valid(&self, rng: &mut XorShift) -> (ObjectID, PropertyName, PropertyValue) {
+        let grant = &self.grants[rng.next_index(self.grants.len())];
+        let value = grant
+            .property
+            .allowed_values
+            .iter()
+            .next()
+            .cloned()
+            .expect("generated grants always have a value");
+        (grant.receiver, grant.property.name.clone(), value)
+    }
+}
+
+fn property_value(property_idx: usize, value_idx: usize) -> PropertyValue {
+    PropertyValue::Text(format!("value-{property_idx}-{value_idx}"))
+}
+
+/// A small deterministic pseudo random generator, good enough to spread load.
+#[derive(Debug, Clone)]
+pub struct XorShift(u64);
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    pub fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::time::Duration;
+
+/// Upper bounds (in milliseconds) of the histogram buckets.
+const BUCKETS_MS: [u64; 12] = [5, 10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, u64::MAX];
+
+/// Records latencies of a workload and summarizes them.
+#[derive(Debug, Default, Clone)]
+pub struct LatencyHistogram {
+    samples: Vec<Duration>,
+    errors: usize,
+}
+
+impl LatencyHistogram {
+    /// Records the latency of a successful request.
+    pub fn record(&mut self, latency: Duration) {
+        self.samples.push(latency);
+    }
+
+    /// Records a failed request.
+    pub fn record_error(&mut self) {
+        self.errors += 1;
+    }
+
+    /// Merges the samples of another histogram into this one.
+    pub fn merge(&mut self, other: LatencyHistogram) {
+        self.samples.extend(other.samples);
+        self.errors += other.errors;
+    }
+
+    /// Returns the latency below which `percentile` percent of the samples fall.
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        let rank = ((percentile / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+
+    fn mean(&self) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        Some(self.samples.iter().sum::<Duration>() / self.samples.len() as u32)
+    }
+
+    fn bucket_counts(&self) -> [usize; BUCKETS_MS.len()] {
+        let mut counts = [0; BUCKETS_MS.len()];
+        for sample in &self.samples {
+            let ms = sample.as_millis() as u64;
+            let idx = BUCKETS_MS
+                .iter()
+                .position(|bound| ms <= *bound)
+                .unwrap_or(BUCKETS_MS.len() - 1);
+            counts[idx] += 1;
+        }
+        counts
+    }
+}
+
+impl fmt::Display for LatencyHistogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = |d: Option<Duration>| {
+            d.map(|d| format!("{:.1}ms", d.as_secs_f64() * 1_000.0))
+                .unwrap_or("-".into())
+        };
+
+        writeln!(f, "  requests: {} ok, {} failed", self.samples.len(), self.errors)?;
+        writeln!(
+            f,
+            "  mean: {}  p50: {}  p90: {}  p99: {}  max: {}",
+            ms(self.mean()),
+            ms(self.percentile(50.0)),
+            ms(self.percentile(90.0)),
+            ms(self.percentile(99.0)),
+            ms(self.percentile(100.0)),
+        )?;
+
+        let counts = self.bucket_counts();
+        let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+        for (bound, count) in BUCKETS_MS.iter().zip(counts) {
+            if count == 0 {
+                continue;
+            }
+            let label = if *bound == u64::MAX {
+                "   > 10000ms".to_string()
+            } else {
+                format!("  <= {bound:>5}ms")
+            };
+            let bar = "#".repeat(count * 40 / max_count);
+            writeln!(f, "{label} | {bar} {count}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies load generator
+//!
+//! Generates a synthetic federation of configurable size, populates it on a network
+//! (usually a localnet) and drives validation workloads against it while recording
+//! latency histograms for every phase.
+//!
+//! ```bash
+//! IOTA_HIERARCHIES_PKG_ID=0x... cargo run --release --features loadgen --bin loadgen -- \
+//!     --properties 20 --entities 50 --accreditations 200 --validations 2000 --concurrency 16
+//! ```
+
+mod generator;
+mod histogram;
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, bail};
+use hierarchies::client::HierarchiesClientReadOnly;
+use hierarchies_examples::get_funded_client;
+use tokio::task::JoinSet;
+
+use crate::generator::{FederationShape, SyntheticFederation, XorShift};
+use crate::histogram::LatencyHistogram;
+
+/// Command line configuration of a load test run.
+#[derive(Debug, Clone)]
+struct Config {
+    shape: FederationShape,
+    validations: usize,
+    concurrency: usize,
+    seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            shape: FederationShape {
+                properties: 10,
+                entities: 20,
+                accreditations: 50,
+            },
+            validations: 500,
+            concurrency: 8,
+            seed: 42,
+        }
+    }
+}
+
+impl Config {
+    fn from_args(args: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut config = Config::default();
+        let mut args = args.into_iter();
+
+        while let Some(flag) = args.next() {
+            if flag == "--help" || flag == "-h" {
+                print_usage();
+                std::process::exit(0);
+            }
+            let value = args.next().with_context(|| format!("missing value for `{flag}`"))?;
+            let parse = |value: &str| -> anyhow::Result<usize> {
+                value
+                    .parse()
+                    .with_context(|| format!("invalid value `{value}` for `{flag}`"))
+            };
+            match flag.as_str() {
+                "--properties" => config.shape.properties = parse(&value)?,
+                "--entities" => config.shape.entities = parse(&value)?,
+                "--accreditations" => config.shape.accreditations = parse(&value)?,
+                "--validations" => config.validations = parse(&value)?,
+                "--concurrency" => config.concurrency = parse(&value)?,
+                "--seed" => config.seed = parse(&value)? as u64,
+                _ => bail!("unknown argument `{flag}`, see `--help`"),
+            }
+        }
+
+        if config.shape.properties == 0 || config.shape.entities == 0 || config.shape.accreditations == 0 {
+            bail!("`--properties`, `--entities` and `--accreditations` must be greater than zero");
+        }
+        config.concurrency = config.concurrency.max(1);
+
+        Ok(config)
+    }
+}
+
+fn print_usage() {
+    println!(
+        "Usage: loadgen [--properties N] [--entities M] [--accreditations K] \
+         [--validations V] [--concurrency C] [--seed S]"
+    );
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = Config::from_args(std::env::args().skip(1))?;
+    println!("Running load test with {config:?}");
+
+    let synthetic = SyntheticFederation::generate(config.shape, config.seed);
+    println!(
+        "Generated {} properties, {} entities and {} accreditations",
+        synthetic.properties.len(),
+        synthetic.entities.len(),
+        synthetic.grants.len()
+    );
+    let hierarchies_client = get_funded_client().await?;
+
+    // Phase 1: create the federation and its properties
+    let federation = hierarchies_client
+        .create_new_federation()
+        .build_and_execute(&hierarchies_client)
+        .await
+        .context("failed to create federation")?;
+    let federation_id = *federation.output.id.object_id();
+    println!("Federation created: {federation_id}");
+
+    let mut add_property_latencies = LatencyHistogram::default();
+    for property in &synthetic.properties {
+        let start = Instant::now();
+        let result = hierarchies_client
+            .add_property(federation_id, property.clone())
+            .build_and_execute(&hierarchies_client)
+            .await;
+        record(&mut add_property_latencies, start.elapsed(), result.is_ok());
+    }
+    println!("\nadd_property\n{add_property_latencies}");
+
+    // Phase 2: issuance workload
+    let mut issuance_latencies = LatencyHistogram::default();
+    for grant in &synthetic.grants {
+        let start = Instant::now();
+        let result = hierarchies_client
+            .create_accreditation_to_attest(federation_id, grant.receiver, [grant.property.clone()])
+            .build_and_execute(&hierarchies_client)
+            .await;
+        record(&mut issuance_latencies, start.elapsed(), result.is_ok());
+    }
+    println!("create_accreditation_to_attest\n{issuance_latencies}");
+
+    // Phase 3: validation workload
+    let read_only: HierarchiesClientReadOnly = (*hierarchies_client).clone();
+    let started = Instant::now();
+    let mut workers = JoinSet::new();
+    for worker in 0..config.concurrency {
+        let client = read_only.clone();
+        let synthetic = synthetic.clone();
+        let requests =
+            config.validations / config.concurrency + usize::from(worker < config.validations % config.concurrency);
+        let mut rng = XorShift::new(config.seed.wrapping_add(worker as u64 + 1));
+
+        workers.spawn(async move {
+            let mut latencies = LatencyHistogram::default();
+            for _ in 0..requests {
+                let (attester, name, value) = synthetic.pick_valid(&mut rng);
+                let start = Instant::now();
+                let result = client.validate_property(federation_id, attester, name, value).await;
+                record(&mut latencies, start.elapsed(), matches!(result, Ok(true)));
+            }
+            latencies
+        });
+    }
+
+    let mut validation_latencies = LatencyHistogram::default();
+    while let Some(latencies) = workers.join_next().await {
+        validation_latencies.merge(latencies.context("validation worker panicked")?);
+    }
+    let elapsed = started.elapsed();
+    println!(
+        "validate_property ({} workers)\n{validation_latencies}",
+        config.concurrency
+    );
+    println!(
+        "  throughput: {:.1} validations/s",
+        config.validations as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+    );
+
+    Ok(())
+}
+
+fn record(histogram: &mut LatencyHistogram, latency: Duration, success: bool) {
+    if success {
+        histogram.record(latency);
+    } else {
+        histogram.record_error();
+    }
+}
@@ -47,6 +47,7 @@
 use std::collections::HashSet;
 
 use hierarchies::core::types::Accreditation;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
 use hierarchies::core::types::property::FederationProperty;
 use hierarchies::core::types::property_name::PropertyName;
 use hierarchies::core::types::property_shape::PropertyShape;
@@ -137,10 +138,7 @@ async fn main() -> anyhow::Result<()> {
     println!("✅ Degree PhD property added!");
 
     // Add field of study properties (boolean - true if student studied this field)
-    let boolean_values = HashSet::from([
-        PropertyValue::Text("true".to_owned()),
-        PropertyValue::Text("false".to_owned()),
-    ]);
+    let boolean_values = HashSet::from([PropertyValue::Bool(true), PropertyValue::Bool(false)]);
 
     hierarchies_client
         .add_property(
@@ -176,16 +174,8 @@ async fn main() -> anyhow::Result<()> {
         .add_property(
             *university_consortium.id.object_id(),
             FederationProperty::new(grade_gpa.clone())
-                .with_expression(PropertyShape::GreaterThan(200)) // GPA > 2.0 (stored as 200 for precision)
-                .with_allowed_values(HashSet::from([
-                    PropertyValue::Number(200),
-                    PropertyValue::Number(250),
-                    PropertyValue::Number(300),
-                    PropertyValue::Number(320),
-                    PropertyValue::Number(350),
-                    PropertyValue::Number(380),
-                    PropertyValue::Number(400), // Common GPA ranges: 2.0, 2.5, 3.0, 3.2, 3.5, 3.8, 4.0
-                ])),
+                // 2.0 <= GPA <= 4.0 (stored as 200-400 for precision)
+                .with_expression(PropertyShape::Between(200, 400)),
         )
         .build_and_execute(&hierarchies_client)
         .await?;
@@ -218,12 +208,12 @@ async fn main() -> anyhow::Result<()> {
 
     println!("✅ Graduation year property added!");
 
-    // Add student ID property with format validation (must start with university code)
+    // Add student ID property with format validation (university code, dash and six digits)
     hierarchies_client
         .add_property(
             *university_consortium.id.object_id(),
             FederationProperty::new(student_id.clone())
-                .with_expression(PropertyShape::Contains("-".to_string()))
+                .with_expression(PropertyShape::MatchesPattern("@*-######".to_string()))
                 .with_allow_any(true),
         )
         .build_and_execute(&hierarchies_client)
@@ -314,7 +304,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_accredit(
             *university_consortium.id.object_id(),
-            harvard_cs_faculty.into(),
+            harvard_cs_faculty,
             cs_faculty_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -337,7 +327,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_attest(
             *university_consortium.id.object_id(),
-            harvard_cs_registrar.into(),
+            harvard_cs_registrar,
             cs_faculty_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -361,10 +351,10 @@ async fn main() -> anyhow::Result<()> {
     // Create Alice's degree attestation data with advanced property shapes validation
     let alice_properties = std::collections::HashMap::from([
         (degree_bachelor.clone(), PropertyValue::Text("completed".to_owned())),
-        (field_cs.clone(), PropertyValue::Text("true".to_owned())),
+        (field_cs.clone(), PropertyValue::Bool(true)),
         (grade_gpa.clone(), PropertyValue::Number(385)), // 3.85 GPA (stored as 385 for precision)
         (graduation_year.clone(), PropertyValue::Number(2024)),
-        (student_verified.clone(), PropertyValue::Text("true".to_owned())),
+        (student_verified.clone(), PropertyValue::Bool(true)),
         (student_id.clone(), PropertyValue::Text("HARV-123456".to_owned())), // University code + student number
         (honors_level.clone(), PropertyValue::Text("magna_cum_laude".to_owned())),
     ]);
@@ -377,7 +367,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_attest(
             *university_consortium.id.object_id(),
-            alice_student.into(),
+            alice_student,
             alice_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -385,10 +375,10 @@ async fn main() -> anyhow::Result<()> {
 
     // Check if the accreditation to attest was issued
     let accreditations = hierarchies_client
-        .get_accreditations_to_attest(*university_consortium.id.object_id(), alice_student.into())
+        .query_accreditations(*university_consortium.id.object_id(), &attest_query(alice_student))
         .await?;
 
-    assert!(accreditations.accreditations.len() == 1);
+    assert!(accreditations.len() == 1);
 
     // Use the helper function to format and display Alice's degree information
     let property_names = DegreePropertyNames {
@@ -408,7 +398,7 @@ async fn main() -> anyhow::Result<()> {
     format_degree_info(
         "Alice",
         &alice_student,
-        &accreditations.accreditations[0],
+        &accreditations[0].accreditation,
         &property_names,
     );
 
@@ -416,10 +406,10 @@ async fn main() -> anyhow::Result<()> {
 
     let bob_properties = std::collections::HashMap::from([
         (degree_master.clone(), PropertyValue::Text("completed".to_owned())),
-        (field_cs.clone(), PropertyValue::Text("true".to_owned())),
+        (field_cs.clone(), PropertyValue::Bool(true)),
         (grade_gpa.clone(), PropertyValue::Number(392)), // 3.92 GPA (stored as 392 for precision)
         (graduation_year.clone(), PropertyValue::Number(2023)),
-        (student_verified.clone(), PropertyValue::Text("true".to_owned())),
+        (student_verified.clone(), PropertyValue::Bool(true)),
         (student_id.clone(), PropertyValue::Text("MIT-789012".to_owned())), // MIT student ID format
         (honors_level.clone(), PropertyValue::Text("summa_cum_laude".to_owned())), // Highest honors
     ]);
@@ -427,7 +417,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_attest(
             *university_consortium.id.object_id(),
-            bob_student.into(),
+            bob_student,
             bob_properties
                 .into_iter()
                 .map(|(name, value)| FederationProperty::new(name).with_allowed_values(HashSet::from([value])))
@@ -438,16 +428,16 @@ async fn main() -> anyhow::Result<()> {
 
     // Check if the accreditation to attest was issued
     let bob_accreditations = hierarchies_client
-        .get_accreditations_to_attest(*university_consortium.id.object_id(), bob_student.into())
+        .query_accreditations(*university_consortium.id.object_id(), &attest_query(bob_student))
         .await?;
 
-    assert!(bob_accreditations.accreditations.len() == 1);
+    assert!(bob_accreditations.len() == 1);
 
     // Use the helper function to format and display Bob's degree information
     format_degree_info(
         "Bob",
         &bob_student,
-        &bob_accreditations.accreditations[0],
+        &bob_accreditations[0].accreditation,
         &property_names,
     );
 
@@ -461,14 +451,14 @@ async fn main() -> anyhow::Result<()> {
 
     let validation_properties = std::collections::HashMap::from([
         (degree_bachelor.clone(), PropertyValue::Text("completed".to_owned())),
-        (field_cs.clone(), PropertyValue::Text("true".to_owned())),
+        (field_cs.clone(), PropertyValue::Bool(true)),
     ]);
 
     // Validate that Alice has the required credentials
     let is_valid = hierarchies_client
         .validate_properties(
             *university_consortium.id.object_id(),
-            alice_student.into(),
+            alice_student,
             validation_properties,
         )
         .await?;
@@ -486,13 +476,13 @@ async fn main() -> anyhow::Result<()> {
 
     let grad_validation = std::collections::HashMap::from([
         (degree_master.clone(), PropertyValue::Text("completed".to_owned())),
-        (field_cs.clone(), PropertyValue::Text("true".to_owned())),
+        (field_cs.clone(), PropertyValue::Bool(true)),
     ]);
 
     let is_master_valid = hierarchies_client
         .validate_properties(
             *university_consortium.id.object_id(),
-            bob_student.into(), // Validate Bob's credentials, not the registrar's
+            bob_student, // Validate Bob's credentials, not the registrar's
             grad_validation,
         )
         .await?;
@@ -518,17 +508,17 @@ async fn main() -> anyhow::Result<()> {
 
     // First, get Alice's current accreditations to find the ID we need to revoke
     let alice_accreditations_before_revocation = hierarchies_client
-        .get_accreditations_to_attest(*university_consortium.id.object_id(), alice_student.into())
+        .query_accreditations(*university_consortium.id.object_id(), &attest_query(alice_student))
         .await?;
 
     println!(
         "🔍 Found {} accreditation(s) for Alice",
-        alice_accreditations_before_revocation.accreditations.len()
+        alice_accreditations_before_revocation.len()
     );
 
-    if !alice_accreditations_before_revocation.accreditations.is_empty() {
+    if !alice_accreditations_before_revocation.is_empty() {
         // Get the accreditation ID to revoke
-        let accreditation_to_revoke = &alice_accreditations_before_revocation.accreditations[0];
+        let accreditation_to_revoke = &alice_accreditations_before_revocation[0].accreditation;
         let accreditation_id = *accreditation_to_revoke.id.object_id();
 
         hierarchies_client
@@ -547,22 +537,20 @@ async fn main() -> anyhow::Result<()> {
 
         // Verify the revocation worked by checking accreditations again
         let alice_accreditations_after_revocation = hierarchies_client
-            .get_accreditations_to_attest(*university_consortium.id.object_id(), alice_student.into())
+            .query_accreditations(*university_consortium.id.object_id(), &attest_query(alice_student))
             .await?;
 
         println!("\n🔍 Step 8b: Verifying revocation...");
         println!(
             "   - Accreditations before revocation: {}",
-            alice_accreditations_before_revocation.accreditations.len()
+            alice_accreditations_before_revocation.len()
         );
         println!(
             "   - Accreditations after revocation: {}",
-            alice_accreditations_after_revocation.accreditations.len()
+            alice_accreditations_after_revocation.len()
         );
 
-        if alice_accreditations_after_revocation.accreditations.len()
-            < alice_accreditations_before_revocation.accreditations.len()
-        {
+        if alice_accreditations_after_revocation.len() < alice_accreditations_before_revocation.len() {
             println!("   ✅ Revocation successful - Alice's degree is no longer valid");
         }
 
@@ -570,13 +558,13 @@ async fn main() -> anyhow::Result<()> {
         println!("\n🧪 Step 8c: Testing validation after revocation...");
         let validation_after_revocation = std::collections::HashMap::from([
             (degree_bachelor.clone(), PropertyValue::Text("completed".to_owned())),
-            (field_cs.clone(), PropertyValue::Text("true".to_owned())),
+            (field_cs.clone(), PropertyValue::Bool(true)),
         ]);
 
         let is_still_valid = hierarchies_client
             .validate_properties(
                 *university_consortium.id.object_id(),
-                alice_student.into(),
+                alice_student,
                 validation_after_revocation,
             )
             .await?;
@@ -632,6 +620,13 @@ struct DegreePropertyNames<'a> {
     honors_level: &'a PropertyName,
 }
 
+/// Helper function to query the accreditations to attest held by `subject`
+fn attest_query(subject: IotaAddress) -> AccreditationQuery {
+    AccreditationQuery::new()
+        .for_entity(subject.into())
+        .scope(AccreditationScope::Attest)
+}
+
 /// Helper function to format and display degree information from an accreditation response
 fn format_degree_info(
     student_name: &str,
@@ -672,24 +667,20 @@ fn format_degree_info(
     // Extract field of study
     let field_of_study = {
         if let Some(prop) = accreditation_properties.get(properties.field_cs) {
-            if let Some(PropertyValue::Text(text)) = prop.allowed_values.iter().next() {
-                if text == "true" {
-                    "Computer Science"
-                } else {
-                    "Unknown Field"
-                }
+            if let Some(PropertyValue::Bool(true)) = prop.allowed_values.iter().next() {
+                "Computer Science"
             } else {
                 "Unknown Field"
             }
         } else if let Some(prop) = accreditation_properties.get(properties.field_engineering) {
-            if let Some(PropertyValue::Text(text)) = prop.allowed_values.iter().next() {
-                if text == "true" { "Engineering" } else { "Unknown Field" }
+            if let Some(PropertyValue::Bool(true)) = prop.allowed_values.iter().next() {
+                "Engineering"
             } else {
                 "Unknown Field"
             }
         } else if let Some(prop) = accreditation_properties.get(properties.field_mathematics) {
-            if let Some(PropertyValue::Text(text)) = prop.allowed_values.iter().next() {
-                if text == "true" { "Mathematics" } else { "Unknown Field" }
+            if let Some(PropertyValue::Bool(true)) = prop.allowed_values.iter().next() {
+                "Mathematics"
             } else {
                 "Unknown Field"
             }
@@ -705,6 +696,7 @@ fn format_degree_info(
         .map(|v| match v {
             PropertyValue::Number(num) => format!("{:.2}", (*num as f64) / 100.0), // Convert back to decimal
             PropertyValue::Text(text) => text.clone(),
+            _ => "N/A".to_string(),
         })
         .unwrap_or_else(|| "N/A".to_string());
 
@@ -715,6 +707,7 @@ fn format_degree_info(
         .map(|v| match v {
             PropertyValue::Number(year) => year.to_string(),
             PropertyValue::Text(text) => text.clone(),
+            _ => "N/A".to_string(),
         })
         .unwrap_or_else(|| "N/A".to_string());
 
@@ -749,8 +742,8 @@ fn format_degree_info(
         .get(properties.student_verified)
         .and_then(|p| p.allowed_values.iter().next())
         .map(|v| match v {
-            PropertyValue::Text(text) if text == "true" => "Verified",
-            PropertyValue::Text(text) if text == "false" => "Not Verified",
+            PropertyValue::Bool(true) => "Verified",
+            PropertyValue::Bool(false) => "Not Verified",
             _ => "Unknown",
         })
         .unwrap_or_else(|| "Unknown");
@@ -758,7 +751,7 @@ fn format_degree_info(
     println!("   - Degree: {} in {}", degree_type, field_of_study);
     println!("   - GPA: {} (validated: > 2.0)", gpa);
     println!("   - Graduation Year: {} (validated: > 1950)", grad_year);
-    println!("   - Student ID: {} (validated: contains dash)", student_id);
+    println!("   - Student ID: {} (validated: matches @*-######)", student_id);
     println!("   - Honors: {}", honors);
     println!("   - Verification Status: {}", verification_status);
     println!("   - Accreditation ID: {:?}", accreditation.id);
@@ -53,6 +53,7 @@ use std::collections::HashSet;
 
 use chrono::{DateTime, Duration, Utc};
 use hierarchies::core::types::Accreditation;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
 use hierarchies::core::types::property::FederationProperty;
 use hierarchies::core::types::property_name::PropertyName;
 use hierarchies::core::types::property_value::PropertyValue;
@@ -74,6 +75,13 @@ struct CertificationPropertyNames<'a> {
     expiry_date: &'a PropertyName,
 }
 
+/// Helper function to query the accreditations to attest held by `subject`
+fn attest_query(subject: IotaAddress) -> AccreditationQuery {
+    AccreditationQuery::new()
+        .for_entity(subject.into())
+        .scope(AccreditationScope::Attest)
+}
+
 /// Helper function to format and display certification information from an accreditation response
 fn format_certification_info(
     product_name: &str,
@@ -91,8 +99,8 @@ fn format_certification_info(
         .get(properties.product_organic)
         .and_then(|p| p.allowed_values.iter().next())
         .map(|v| match v {
-            PropertyValue::Text(text) if text == "true" => "Certified Organic",
-            PropertyValue::Text(text) if text == "false" => "Not Organic",
+            PropertyValue::Bool(true) => "Certified Organic",
+            PropertyValue::Bool(false) => "Not Organic",
             _ => "Unknown",
         })
         .unwrap_or_else(|| "Unknown");
@@ -133,8 +141,8 @@ fn format_certification_info(
         .get(properties.compliance_eu)
         .and_then(|p| p.allowed_values.iter().next())
         .map(|v| match v {
-            PropertyValue::Text(text) if text == "true" => "Yes",
-            PropertyValue::Text(text) if text == "false" => "No",
+            PropertyValue::Bool(true) => "Yes",
+            PropertyValue::Bool(false) => "No",
             _ => "Unknown",
         })
         .unwrap_or_else(|| "N/A");
@@ -144,8 +152,8 @@ fn format_certification_info(
         .get(properties.compliance_fda)
         .and_then(|p| p.allowed_values.iter().next())
         .map(|v| match v {
-            PropertyValue::Text(text) if text == "true" => "Yes",
-            PropertyValue::Text(text) if text == "false" => "No",
+            PropertyValue::Bool(true) => "Yes",
+            PropertyValue::Bool(false) => "No",
             _ => "Unknown",
         })
         .unwrap_or_else(|| "N/A");
@@ -167,14 +175,9 @@ fn format_certification_info(
         .get(properties.expiry_date)
         .and_then(|p| p.allowed_values.iter().next())
         .map(|v| match v {
-            PropertyValue::Text(text) => {
-                // Try to parse and reformat the date for better display
-                if let Ok(parsed_date) = DateTime::parse_from_rfc3339(text) {
-                    parsed_date.format("%Y-%m-%d").to_string()
-                } else {
-                    text.clone()
-                }
-            }
+            PropertyValue::Timestamp(ms) => DateTime::from_timestamp_millis(*ms as i64)
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "Unknown".to_string()),
             _ => "Unknown".to_string(),
         })
         .unwrap_or_else(|| "N/A".to_string());
@@ -279,10 +282,7 @@ async fn main() -> anyhow::Result<()> {
         .await?;
 
     // Boolean certifications (certified/not certified)
-    let boolean_values = HashSet::from([
-        PropertyValue::Text("true".to_owned()),
-        PropertyValue::Text("false".to_owned()),
-    ]);
+    let boolean_values = HashSet::from([PropertyValue::Bool(true), PropertyValue::Bool(false)]);
 
     hierarchies_client
         .add_property(
@@ -412,7 +412,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_accredit(
             *standards_consortium.id.object_id(),
-            german_testing_institute.into(),
+            german_testing_institute,
             european_cert_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -434,7 +434,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_accredit(
             *standards_consortium.id.object_id(),
-            us_fda_regional.into(),
+            us_fda_regional,
             americas_cert_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -468,7 +468,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_attest(
             *standards_consortium.id.object_id(),
-            berlin_food_lab.into(),
+            berlin_food_lab,
             food_safety_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -480,7 +480,7 @@ async fn main() -> anyhow::Result<()> {
     hierarchies_client
         .create_accreditation_to_attest(
             *standards_consortium.id.object_id(),
-            california_ag_lab.into(),
+            california_ag_lab,
             americas_cert_properties.clone(),
         )
         .build_and_execute(&hierarchies_client)
@@ -510,21 +510,21 @@ async fn main() -> anyhow::Result<()> {
     // Berlin lab certifies organic apples from German orchard
     let apple_certification = [
         FederationProperty::new(product_organic.clone())
-            .with_allowed_values(HashSet::from([PropertyValue::Text("true".to_owned())])),
+            .with_allowed_values(HashSet::from([PropertyValue::Bool(true)])),
         FederationProperty::new(origin_verified.clone())
             .with_allowed_values(HashSet::from([PropertyValue::Text("DE".to_owned())])),
         FederationProperty::new(batch_tested.clone())
             .with_allowed_values(HashSet::from([PropertyValue::Text("passed".to_owned())])),
-        FederationProperty::new(compliance_eu.clone())
-            .with_allowed_values(HashSet::from([PropertyValue::Text("true".to_owned())])),
-        FederationProperty::new(expiry_date.clone())
-            .with_allowed_values(HashSet::from([PropertyValue::Text(expiry.to_rfc3339())])),
+        FederationProperty::new(compliance_eu.clone()).with_allowed_values(HashSet::from([PropertyValue::Bool(true)])),
+        FederationProperty::new(expiry_date.clone()).with_allowed_values(HashSet::from([PropertyValue::Timestamp(
+            expiry.timestamp_millis() as u64,
+        )])),
     ];
 
     hierarchies_client
         .create_accreditation_to_attest(
             *standards_consortium.id.object_id(),
-            organic_apples_batch.into(),
+            organic_apples_batch,
             apple_certification.to_vec(),
         )
         .build_and_execute(&hierarchies_client)
@@ -532,16 +532,19 @@ async fn main() -> anyhow::Result<()> {
 
     // Check if the accreditation to attest was issued
     let apple_accreditations = hierarchies_client
-        .get_accreditations_to_attest(*standards_consortium.id.object_id(), organic_apples_batch.into())
+        .query_accreditations(
+            *standards_consortium.id.object_id(),
+            &attest_query(organic_apples_batch),
+        )
         .await?;
 
-    assert!(apple_accreditations.accreditations.len() == 1);
+    assert!(apple_accreditations.len() == 1);
 
     // Use helper function to format and display apple certification information
     format_certification_info(
         "Organic Apples",
         &format!("Batch #{}", &organic_apples_batch.to_string()[0..8]),
-        &apple_accreditations.accreditations[0],
+        &apple_accreditations[0].accreditation,
         &CertificationPropertyNames {
             iso_9001: &iso_9001,
             iso_14001: &iso_14001,
@@ -566,16 +569,16 @@ async fn main() -> anyhow::Result<()> {
             .with_allowed_values(HashSet::from([PropertyValue::Text("US".to_owned())])),
         FederationProperty::new(batch_tested.clone())
             .with_allowed_values(HashSet::from([PropertyValue::Text("passed".to_owned())])),
-        FederationProperty::new(compliance_fda.clone())
-            .with_allowed_values(HashSet::from([PropertyValue::Text("true".to_owned())])),
-        FederationProperty::new(expiry_date.clone())
-            .with_allowed_values(HashSet::from([PropertyValue::Text(expiry.to_rfc3339())])),
+        FederationProperty::new(compliance_fda.clone()).with_allowed_values(HashSet::from([PropertyValue::Bool(true)])),
+        FederationProperty::new(expiry_date.clone()).with_allowed_values(HashSet::from([PropertyValue::Timestamp(
+            expiry.timestamp_millis() as u64,
+        )])),
     ];
 
     hierarchies_client
         .create_accreditation_to_attest(
             *standards_consortium.id.object_id(),
-            processed_food_batch.into(),
+            processed_food_batch,
             processed_food_cert.to_vec(),
         )
         .build_and_execute(&hierarchies_client)
@@ -583,16 +586,19 @@ async fn main() -> anyhow::Result<()> {
 
     // Check if the accreditation to attest was issued
     let processed_food_accreditations = hierarchies_client
-        .get_accreditations_to_attest(*standards_consortium.id.object_id(), processed_food_batch.into())
+        .query_accreditations(
+            *standards_consortium.id.object_id(),
+            &attest_query(processed_food_batch),
+        )
         .await?;
 
-    assert!(processed_food_accreditations.accreditations.len() == 1);
+    assert!(processed_food_accreditations.len() == 1);
 
     // Use helper function to format and display processed food certification information
     format_certification_info(
         "Processed Food",
         &format!("Batch #{}", &processed_food_batch.to_string()[0..8]),
-        &processed_food_accreditations.accreditations[0],
+        &processed_food_accreditations[0].accreditation,
         &CertificationPropertyNames {
             iso_9001: &iso_9001,
             iso_14001: &iso_14001,
@@ -616,15 +622,15 @@ async fn main() -> anyhow::Result<()> {
     println!("🛒 Scenario: EU supermarket chain validating organic apple import");
 
     let import_requirements = std::collections::HashMap::from([
-        (product_organic.clone(), PropertyValue::Text("true".to_owned())),
-        (compliance_eu.clone(), PropertyValue::Text("true".to_owned())),
+        (product_organic.clone(), PropertyValue::Bool(true)),
+        (compliance_eu.clone(), PropertyValue::Bool(true)),
         (batch_tested.clone(), PropertyValue::Text("passed".to_owned())),
     ]);
 
     let import_valid = hierarchies_client
         .validate_properties(
             *standards_consortium.id.object_id(),
-            organic_apples_batch.into(), // Validate the organic apples product, not the lab
+            organic_apples_batch, // Validate the organic apples product, not the lab
             import_requirements,
         )
         .await?;
@@ -645,14 +651,14 @@ async fn main() -> anyhow::Result<()> {
 
     let fda_requirements = std::collections::HashMap::from([
         (iso_22000.clone(), PropertyValue::Text("certified".to_owned())),
-        (compliance_fda.clone(), PropertyValue::Text("true".to_owned())),
+        (compliance_fda.clone(), PropertyValue::Bool(true)),
         (batch_tested.clone(), PropertyValue::Text("passed".to_owned())),
     ]);
 
     let fda_valid = hierarchies_client
         .validate_properties(
             *standards_consortium.id.object_id(),
-            processed_food_batch.into(), // Validate the processed food product, not the lab
+            processed_food_batch, // Validate the processed food product, not the lab
             fda_requirements,
         )
         .await?;
@@ -672,14 +678,14 @@ async fn main() -> anyhow::Result<()> {
 
     // Consumer app validates organic claim
     let consumer_verification = std::collections::HashMap::from([
-        (product_organic.clone(), PropertyValue::Text("true".to_owned())),
+        (product_organic.clone(), PropertyValue::Bool(true)),
         (origin_verified.clone(), PropertyValue::Text("DE".to_owned())),
     ]);
 
     let consumer_valid = hierarchies_client
         .validate_properties(
             *standards_consortium.id.object_id(),
-            organic_apples_batch.into(), // Validate the organic apples product, not the lab
+            organic_apples_batch, // Validate the organic apples product, not the lab
             consumer_verification,
         )
         .await?;
@@ -704,11 +710,14 @@ async fn main() -> anyhow::Result<()> {
 
     // Real implementation: Revoke the specific attestation
     let processed_food_accreditations = hierarchies_client
-        .get_accreditations_to_attest(*standards_consortium.id.object_id(), processed_food_batch.into())
+        .query_accreditations(
+            *standards_consortium.id.object_id(),
+            &attest_query(processed_food_batch),
+        )
         .await?;
 
-    if !processed_food_accreditations.accreditations.is_empty() {
-        let accreditation_id = *processed_food_accreditations.accreditations[0].id.object_id();
+    if !processed_food_accreditations.is_empty() {
+        let accreditation_id = *processed_food_accreditations[0].accreditation.id.object_id();
 
         hierarchies_client
             .revoke_accreditation_to_attest(
@@ -724,10 +733,13 @@ async fn main() -> anyhow::Result<()> {
 
         // Verify revocation by checking accreditations again
         let revoked_check = hierarchies_client
-            .get_accreditations_to_attest(*standards_consortium.id.object_id(), processed_food_batch.into())
+            .query_accreditations(
+                *standards_consortium.id.object_id(),
+                &attest_query(processed_food_batch),
+            )
             .await?;
 
-        if revoked_check.accreditations.is_empty() {
+        if revoked_check.is_empty() {
             println!("✅ Revocation confirmed - no active certifications remain");
         }
 
@@ -735,7 +747,7 @@ async fn main() -> anyhow::Result<()> {
         let post_revocation_validation = hierarchies_client
             .validate_properties(
                 *standards_consortium.id.object_id(),
-                processed_food_batch.into(),
+                processed_food_batch,
                 std::collections::HashMap::from([(iso_22000.clone(), PropertyValue::Text("true".to_owned()))]),
             )
             .await?;
@@ -747,6 +759,16 @@ async fn main() -> anyhow::Result<()> {
 
     println!("📋 Recall process completed:");
     println!("   - Certification revoked");
+
+    // The audit trail of the recall is reconstructed from the events of the federation
+    let history = hierarchies_client
+        .get_federation_history(*standards_consortium.id.object_id(), None, None)
+        .await?;
+    println!("\n🧾 Compliance audit trail ({} changes):", history.entries().len());
+    for entry in history.entries() {
+        println!("   {entry}");
+    }
+
     // =============================================================================
     // SUMMARY
     // =============================================================================
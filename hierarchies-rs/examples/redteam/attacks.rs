@@ -0,0 +1,263 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Known abuse patterns against a federation.
+//!
+//! Every attack sets up the preconditions it needs with the root authority of the
+//! arena federation, then attempts the abuse with the attacker's own client. An
+//! attack is blocked if the attempt is rejected on-chain or by the client.
+
+use std::fmt::Display;
+
+use anyhow::Context;
+use hierarchies::client::HierarchiesClient;
+use hierarchies::core::transactions::Destructive;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
+use hierarchies::core::types::property::FederationProperty;
+use hierarchies::core::types::property_name::PropertyName;
+use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::timespan::Timespan;
+use hierarchies_examples::get_funded_client;
+use iota_sdk::types::base_types::ObjectID;
+use product_common::core_client::CoreClient;
+use product_common::test_utils::InMemSigner;
+
+use crate::report::{Finding, Verdict};
+
+/// Name of the property all attacks target.
+const PROPERTY: &str = "redteam.role";
+
+/// A federation owned by the red team, shared by all attacks.
+pub struct Arena {
+    pub root: HierarchiesClient<InMemSigner>,
+    pub federation_id: ObjectID,
+}
+
+impl Arena {
+    /// Creates a federation with a single property allowing `basic` and `admin`.
+    pub async fn setup() -> anyhow::Result<Self> {
+        let root = get_funded_client().await?;
+        let federation_id = *root
+            .create_new_federation()
+            .build_and_execute(&root)
+            .await
+            .context("failed to create federation")?
+            .output
+            .id
+            .object_id();
+
+        root.add_property(
+            federation_id,
+            FederationProperty::new(property_name()).with_allowed_values([basic(), admin()]),
+        )
+        .build_and_execute(&root)
+        .await
+        .context("failed to add property")?;
+
+        Ok(Self { root, federation_id })
+    }
+
+    async fn grant_attest(&self, receiver: ObjectID, property: FederationProperty) -> anyhow::Result<()> {
+        self.root
+            .create_accreditation_to_attest(self.federation_id, receiver, [property])
+            .build_and_execute(&self.root)
+            .await
+            .context("failed to grant accreditation to attest")?;
+        Ok(())
+    }
+
+    async fn grant_accredit(&self, receiver: ObjectID, property: FederationProperty) -> anyhow::Result<()> {
+        self.root
+            .create_accreditation_to_accredit(self.federation_id, receiver, vec![property])
+            .build_and_execute(&self.root)
+            .await
+            .context("failed to grant accreditation to accredit")?;
+        Ok(())
+    }
+}
+
+fn property_name() -> PropertyName {
+    PropertyName::from(PROPERTY)
+}
+
+fn basic() -> PropertyValue {
+    PropertyValue::Text("basic".to_string())
+}
+
+fn admin() -> PropertyValue {
+    PropertyValue::Text("admin".to_string())
+}
+
+fn scoped(value: PropertyValue) -> FederationProperty {
+    FederationProperty::new(property_name()).with_allowed_values([value])
+}
+
+fn account_id(client: &HierarchiesClient<InMemSigner>) -> ObjectID {
+    client.sender_address().into()
+}
+
+/// Maps the result of an abuse attempt that must be rejected to a verdict.
+fn expect_rejected<T, E: Display>(result: Result<T, E>) -> (Verdict, String) {
+    match result {
+        Ok(_) => (Verdict::Breached, "the attempt was accepted".to_string()),
+        Err(err) => (Verdict::Blocked, err.to_string()),
+    }
+}
+
+/// Maps the result of a validation that must not succeed to a verdict.
+fn expect_invalid<E: Display>(result: Result<bool, E>) -> (Verdict, String) {
+    match result {
+        Ok(true) => (Verdict::Breached, "the attestation was accepted".to_string()),
+        Ok(false) => (Verdict::Blocked, "the attestation was rejected".to_string()),
+        Err(err) => (Verdict::Blocked, err.to_string()),
+    }
+}
+
+/// An accreditor scoped to `basic` tries to delegate attestation rights for `admin`.
+pub async fn delegation_beyond_scope(arena: &Arena) -> anyhow::Result<Finding> {
+    let attacker = get_funded_client().await?;
+    arena.grant_accredit(account_id(&attacker), scoped(basic())).await?;
+
+    let result = attacker
+        .create_accreditation_to_attest(arena.federation_id, ObjectID::random(), [scoped(admin())])
+        .build_and_execute(&attacker)
+        .await;
+
+    Ok(Finding::new(
+        "delegation-beyond-scope",
+        "accreditor delegates a value outside its granted scope",
+        expect_rejected(result),
+    ))
+}
+
+/// An attester keeps attesting after its accreditation was revoked.
+pub async fn attestation_after_revocation(arena: &Arena) -> anyhow::Result<Finding> {
+    let attester = ObjectID::random();
+    arena.grant_attest(attester, scoped(basic())).await?;
+
+    let query = AccreditationQuery::new()
+        .for_entity(attester)
+        .scope(AccreditationScope::Attest);
+    let accreditations = arena.root.query_accreditations(arena.federation_id, &query).await?;
+    let accreditation_id = *accreditations
+        .first()
+        .context("granted accreditation not found")?
+        .accreditation
+        .id
+        .object_id();
+    arena
+        .root
+        .revoke_accreditation_to_attest(arena.federation_id, attester, accreditation_id)
+        .build_and_execute(&arena.root)
+        .await
+        .context("failed to revoke accreditation to attest")?;
+
+    let result = arena
+        .root
+        .validate_property(arena.federation_id, attester, property_name(), basic())
+        .await;
+
+    Ok(Finding::new(
+        "attestation-after-revocation",
+        "attester is still trusted after its accreditation was revoked",
+        expect_invalid(result),
+    ))
+}
+
+/// An attester relies on an accreditation whose timespan has ended.
+pub async fn expired_timespan(arena: &Arena) -> anyhow::Result<Finding> {
+    let attester = ObjectID::random();
+    let expired = scoped(basic()).with_timespan(Timespan {
+        valid_from_ms: None,
+        valid_until_ms: Some(1),
+    });
+
+    // Rejecting the expired grant up front blocks the attack as well.
+    if let Err(err) = arena.grant_attest(attester, expired).await {
+        return Ok(Finding::new(
+            "expired-timespan",
+            "attester is trusted outside the timespan of its accreditation",
+            (Verdict::Blocked, format!("{err:#}")),
+        ));
+    }
+
+    let result = arena
+        .root
+        .validate_property(arena.federation_id, attester, property_name(), basic())
+        .await;
+
+    Ok(Finding::new(
+        "expired-timespan",
+        "attester is trusted outside the timespan of its accreditation",
+        expect_invalid(result),
+    ))
+}
+
+/// A former accreditor replays its `AccreditCap` after its accreditation was revoked.
+pub async fn stale_accredit_cap(arena: &Arena) -> anyhow::Result<Finding> {
+    let attacker = get_funded_client().await?;
+    let attacker_id = account_id(&attacker);
+    arena.grant_accredit(attacker_id, scoped(basic())).await?;
+
+    let query = AccreditationQuery::new()
+        .for_entity(attacker_id)
+        .scope(AccreditationScope::Accredit);
+    let accreditations = arena.root.query_accreditations(arena.federation_id, &query).await?;
+    let accreditation_id = *accreditations
+        .first()
+        .context("granted accreditation not found")?
+        .accreditation
+        .id
+        .object_id();
+    arena
+        .root
+        .revoke_accreditation_to_accredit(arena.federation_id, attacker_id, accreditation_id)
+        .build_and_execute(&arena.root)
+        .await
+        .context("failed to revoke accreditation to accredit")?;
+
+    let result = attacker
+        .create_accreditation_to_attest(arena.federation_id, ObjectID::random(), [scoped(basic())])
+        .build_and_execute(&attacker)
+        .await;
+
+    Ok(Finding::new(
+        "stale-accredit-cap",
+        "revoked accreditor replays its AccreditCap",
+        expect_rejected(result),
+    ))
+}
+
+/// A revoked root authority replays its `RootAuthorityCap`.
+pub async fn stale_root_authority_cap(arena: &Arena) -> anyhow::Result<Finding> {
+    let attacker = get_funded_client().await?;
+    let attacker_id = account_id(&attacker);
+
+    arena
+        .root
+        .add_root_authority(arena.federation_id, attacker_id)
+        .build_and_execute(&arena.root)
+        .await
+        .context("failed to add root authority")?;
+    arena
+        .root
+        .revoke_root_authority(arena.federation_id, attacker_id)
+        .confirm_irreversible()
+        .build_and_execute(&arena.root)
+        .await
+        .context("failed to revoke root authority")?;
+
+    let result = attacker
+        .add_property(
+            arena.federation_id,
+            FederationProperty::new("redteam.injected").with_allow_any(true),
+        )
+        .build_and_execute(&attacker)
+        .await;
+
+    Ok(Finding::new(
+        "stale-root-authority-cap",
+        "revoked root authority replays its RootAuthorityCap",
+        expect_rejected(result),
+    ))
+}
@@ -0,0 +1,50 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies red team
+//!
+//! Attempts known trust-chain abuse patterns against a freshly created federation
+//! of the configured package and asserts they fail:
+//!
+//! - delegating beyond the granted scope
+//! - attesting after the accreditation was revoked
+//! - attesting outside the timespan of the accreditation
+//! - replaying the capability of a revoked accreditor or root authority
+//!
+//! The process exits with `1` if any attack succeeded and with `2` if an attack
+//! could not be set up, so it can run continuously against a deployed package.
+//!
+//! ```bash
+//! IOTA_HIERARCHIES_PKG_ID=0x... cargo run --release --features redteam --bin redteam
+//! ```
+
+mod attacks;
+mod report;
+
+use crate::attacks::Arena;
+use crate::report::Report;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let arena = Arena::setup().await?;
+    println!("Red team federation: {}\n", arena.federation_id);
+
+    let mut report = Report::default();
+    report.record(
+        "delegation-beyond-scope",
+        attacks::delegation_beyond_scope(&arena).await,
+    );
+    report.record(
+        "attestation-after-revocation",
+        attacks::attestation_after_revocation(&arena).await,
+    );
+    report.record("expired-timespan", attacks::expired_timespan(&arena).await);
+    report.record("stale-accredit-cap", attacks::stale_accredit_cap(&arena).await);
+    report.record(
+        "stale-root-authority-cap",
+        attacks::stale_root_authority_cap(&arena).await,
+    );
+
+    println!("{report}");
+    std::process::exit(report.exit_code());
+}
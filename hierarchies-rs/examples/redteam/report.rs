@@ -0,0 +1,105 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+/// Outcome of a single attack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The abuse attempt was rejected.
+    Blocked,
+    /// The abuse attempt succeeded.
+    Breached,
+    /// The preconditions of the attack could not be set up.
+    Inconclusive,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self {
+            Verdict::Blocked => "BLOCKED",
+            Verdict::Breached => "BREACHED",
+            Verdict::Inconclusive => "INCONCLUSIVE",
+        };
+        f.pad(verdict)
+    }
+}
+
+/// The result of an attack.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub attack: &'static str,
+    pub description: &'static str,
+    pub verdict: Verdict,
+    pub detail: String,
+}
+
+impl Finding {
+    pub fn new(attack: &'static str, description: &'static str, (verdict, detail): (Verdict, String)) -> Self {
+        Self {
+            attack,
+            description,
+            verdict,
+            detail,
+        }
+    }
+}
+
+/// The findings of a red team run.
+#[derive(Debug, Default)]
+pub struct Report {
+    findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Records the result of an attack, setup failures are recorded as inconclusive.
+    pub fn record(&mut self, attack: &'static str, result: anyhow::Result<Finding>) {
+        let finding = result.unwrap_or_else(|err| Finding {
+            attack,
+            description: "setup failed",
+            verdict: Verdict::Inconclusive,
+            detail: format!("{err:#}"),
+        });
+        self.findings.push(finding);
+    }
+
+    /// Counts the findings with `verdict`.
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| finding.verdict == verdict)
+            .count()
+    }
+
+    /// Returns the process exit code of the run: `1` if an attack succeeded,
+    /// `2` if an attack was inconclusive and `0` otherwise.
+    pub fn exit_code(&self) -> i32 {
+        if self.count(Verdict::Breached) > 0 {
+            1
+        } else if self.count(Verdict::Inconclusive) > 0 {
+            2
+        } else {
+            0
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for finding in &self.findings {
+            writeln!(
+                f,
+                "[{:<12}] {:<30} {}",
+                finding.verdict, finding.attack, finding.description
+            )?;
+            writeln!(f, "{:>16}{}", "", finding.detail)?;
+        }
+        write!(
+            f,
+            "\n{} blocked, {} breached, {} inconclusive",
+            self.count(Verdict::Blocked),
+            self.count(Verdict::Breached),
+            self.count(Verdict::Inconclusive)
+        )
+    }
+}
@@ -3,21 +3,29 @@
 
 use anyhow::Context;
 use hierarchies::client::{HierarchiesClient, HierarchiesClientReadOnly};
-use iota_sdk::{IOTA_LOCAL_NETWORK_URL, IotaClientBuilder};
+use iota_sdk::types::base_types::ObjectID;
+use iota_sdk::{IOTA_LOCAL_NETWORK_URL, IotaClient, IotaClientBuilder};
 use product_common::test_utils::{InMemSigner, request_funds};
 
-pub async fn get_read_only_client() -> anyhow::Result<HierarchiesClientReadOnly> {
+pub async fn get_iota_client() -> anyhow::Result<IotaClient> {
     let api_endpoint = std::env::var("API_ENDPOINT").unwrap_or_else(|_| IOTA_LOCAL_NETWORK_URL.to_string());
-    let iota_client = IotaClientBuilder::default()
+    IotaClientBuilder::default()
         .build(&api_endpoint)
         .await
-        .map_err(|err| anyhow::anyhow!(format!("failed to connect to network; {}", err)))?;
+        .map_err(|err| anyhow::anyhow!(format!("failed to connect to network; {}", err)))
+}
 
-    let package_id = std::env::var("IOTA_HIERARCHIES_PKG_ID")
+pub fn get_package_id() -> anyhow::Result<ObjectID> {
+    std::env::var("IOTA_HIERARCHIES_PKG_ID")
         .map_err(|e| {
             anyhow::anyhow!("env variable IOTA_HIERARCHIES_PKG_ID must be set in order to run the examples").context(e)
         })
-        .and_then(|pkg_str| pkg_str.parse().context("invalid package id"))?;
+        .and_then(|pkg_str| pkg_str.parse().context("invalid package id"))
+}
+
+pub async fn get_read_only_client() -> anyhow::Result<HierarchiesClientReadOnly> {
+    let iota_client = get_iota_client().await?;
+    let package_id = get_package_id()?;
 
     HierarchiesClientReadOnly::new_with_pkg_id(iota_client, package_id)
         .await
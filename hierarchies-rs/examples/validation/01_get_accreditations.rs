@@ -4,6 +4,7 @@
 use std::collections::HashSet;
 
 use anyhow::Context;
+use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
 use hierarchies::core::types::property::FederationProperty;
 use hierarchies::core::types::property_name::PropertyName;
 use hierarchies::core::types::property_value::PropertyValue;
@@ -32,9 +33,10 @@ async fn main() -> anyhow::Result<()> {
 
     let user_id = hierarchies_client.sender_address().into();
 
-    let accreditations = hierarchies_client
-        .get_accreditations_to_attest(*federation_id, user_id)
-        .await?;
+    let query = AccreditationQuery::new()
+        .for_entity(user_id)
+        .scope(AccreditationScope::Attest);
+    let accreditations = hierarchies_client.query_accreditations(*federation_id, &query).await?;
 
     println!("Accreditations to attest: {accreditations:#?}");
 
@@ -62,6 +64,8 @@ async fn main() -> anyhow::Result<()> {
         shape: None,
         allow_any: false,
         timespan: Timespan::default(),
+        value_dictionary: None,
+        metadata: Default::default(),
     };
 
     // Let us issue a accreditation to attest to the Property
@@ -74,12 +78,15 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Check if the accreditation to attest was issued
+    let query = AccreditationQuery::new()
+        .for_entity(receiver)
+        .scope(AccreditationScope::Attest);
     let accreditations = hierarchies_client
-        .get_accreditations_to_attest(*federation_id, receiver)
+        .query_accreditations(*federation_id, &query)
         .await
         .context("Failed to find accreditation to attest")?;
 
-    assert!(accreditations.accreditations.len() == 1);
+    assert!(accreditations.len() == 1);
 
     println!("Accreditations to attest: {accreditations:#?}");
 
@@ -93,12 +100,15 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Check if the accreditation to accredit was issued
+    let query = AccreditationQuery::new()
+        .for_entity(receiver)
+        .scope(AccreditationScope::Accredit);
     let accreditations = hierarchies_client
-        .get_accreditations_to_accredit(*federation_id, receiver)
+        .query_accreditations(*federation_id, &query)
         .await
         .context("Failed to find accreditation to accredit")?;
 
-    assert!(accreditations.accreditations.len() == 1);
+    assert!(accreditations.len() == 1);
 
     Ok(())
 }
@@ -0,0 +1,182 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between the protobuf messages and the types of the Hierarchies library.
+//!
+//! Malformed requests are rejected with [`Status::invalid_argument`].
+
+use hierarchies::core::types::Accreditation;
+use hierarchies::core::types::accreditation_query::AccreditationScope;
+use hierarchies::core::types::property::FederationProperty;
+use hierarchies::core::types::property_name::PropertyName;
+use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::timespan::Timespan;
+use hierarchies::core::types::trust_chain::{TrustChain, TrustLink};
+use iota_sdk::types::base_types::ObjectID;
+use tonic::Status;
+
+use crate::proto;
+use crate::proto::property_value::Value;
+
+/// Parses the object ID held by the request field `field`.
+pub(crate) fn parse_object_id(field: &str, value: &str) -> Result<ObjectID, Status> {
+    value
+        .parse()
+        .map_err(|_| Status::invalid_argument(format!("`{field}` is not a valid object ID: '{value}'")))
+}
+
+/// Converts the property name held by the request field `field`.
+pub(crate) fn parse_property_name(field: &str, name: Option<proto::PropertyName>) -> Result<PropertyName, Status> {
+    match name {
+        Some(name) if !name.names.is_empty() => Ok(PropertyName::new(name.names)),
+        _ => Err(Status::invalid_argument(format!("`{field}` is required"))),
+    }
+}
+
+/// Converts the property value held by the request field `field`.
+pub(crate) fn parse_property_value(field: &str, value: Option<proto::PropertyValue>) -> Result<PropertyValue, Status> {
+    let value = value
+        .and_then(|value| value.value)
+        .ok_or_else(|| Status::invalid_argument(format!("`{field}` is required")))?;
+
+    Ok(match value {
+        Value::Text(text) => PropertyValue::Text(text),
+        Value::Number(number) => PropertyValue::Number(number),
+        Value::Bool(flag) => PropertyValue::Bool(flag),
+        Value::TimestampMs(timestamp_ms) => PropertyValue::Timestamp(timestamp_ms),
+        Value::Bytes(bytes) => PropertyValue::Bytes(bytes),
+    })
+}
+
+/// Converts the scope held by the request field `field`.
+pub(crate) fn parse_scope(field: &str, scope: i32) -> Result<AccreditationScope, Status> {
+    match proto::AccreditationScope::try_from(scope) {
+        Ok(proto::AccreditationScope::Attest) => Ok(AccreditationScope::Attest),
+        Ok(proto::AccreditationScope::Accredit) => Ok(AccreditationScope::Accredit),
+        _ => Err(Status::invalid_argument(format!(
+            "`{field}` must be ATTEST or ACCREDIT"
+        ))),
+    }
+}
+
+impl From<&PropertyName> for proto::PropertyName {
+    fn from(name: &PropertyName) -> Self {
+        Self {
+            names: name.names().clone(),
+        }
+    }
+}
+
+impl From<&PropertyValue> for proto::PropertyValue {
+    fn from(value: &PropertyValue) -> Self {
+        let value = match value {
+            PropertyValue::Text(text) => Value::Text(text.clone()),
+            PropertyValue::Number(number) => Value::Number(*number),
+            PropertyValue::Bool(flag) => Value::Bool(*flag),
+            PropertyValue::Timestamp(timestamp_ms) => Value::TimestampMs(*timestamp_ms),
+            PropertyValue::Bytes(bytes) => Value::Bytes(bytes.clone()),
+        };
+
+        Self { value: Some(value) }
+    }
+}
+
+impl From<&Timespan> for proto::Timespan {
+    fn from(timespan: &Timespan) -> Self {
+        Self {
+            valid_from_ms: timespan.valid_from_ms,
+            valid_until_ms: timespan.valid_until_ms,
+        }
+    }
+}
+
+impl From<&FederationProperty> for proto::FederationProperty {
+    fn from(property: &FederationProperty) -> Self {
+        Self {
+            name: Some((&property.name).into()),
+            allowed_values: property.allowed_values.iter().map(Into::into).collect(),
+            allow_any: property.allow_any,
+            timespan: Some((&property.timespan).into()),
+            value_dictionary: property.value_dictionary.clone(),
+            has_shape: property.shape.is_some(),
+            metadata: property.metadata.entries().clone(),
+        }
+    }
+}
+
+impl From<&Accreditation> for proto::Accreditation {
+    fn from(accreditation: &Accreditation) -> Self {
+        Self {
+            id: accreditation.id.object_id().to_string(),
+            accredited_by: accreditation.accredited_by.clone(),
+            properties: accreditation.properties.values().map(Into::into).collect(),
+            pending_until_ms: accreditation.pending_until_ms,
+        }
+    }
+}
+
+impl From<AccreditationScope> for proto::AccreditationScope {
+    fn from(scope: AccreditationScope) -> Self {
+        match scope {
+            AccreditationScope::Attest => Self::Attest,
+            AccreditationScope::Accredit => Self::Accredit,
+        }
+    }
+}
+
+impl From<&TrustLink> for proto::TrustLink {
+    fn from(link: &TrustLink) -> Self {
+        Self {
+            issuer: link.issuer.to_string(),
+            entity_id: link.entity_id.to_string(),
+            scope: proto::AccreditationScope::from(link.scope).into(),
+            accreditation_id: link.accreditation_id.to_string(),
+            property: Some((&link.property).into()),
+        }
+    }
+}
+
+impl From<&TrustChain> for proto::TrustChain {
+    fn from(chain: &TrustChain) -> Self {
+        Self {
+            root_authority: chain.root_authority.to_string(),
+            links: chain.links.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_property_value_round_trip() {
+        let values = [
+            PropertyValue::Text("msc".to_string()),
+            PropertyValue::Number(42),
+            PropertyValue::Bool(true),
+            PropertyValue::Timestamp(1_700_000_000_000),
+            PropertyValue::Bytes(vec![0xde, 0xad]),
+        ];
+
+        for value in values {
+            let message = proto::PropertyValue::from(&value);
+            assert_eq!(parse_property_value("value", Some(message)).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed_requests() {
+        assert_eq!(
+            parse_object_id("federation_id", "federation").unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert!(parse_property_name("property_name", Some(proto::PropertyName { names: vec![] })).is_err());
+        assert!(parse_property_value("property_value", Some(proto::PropertyValue { value: None })).is_err());
+        assert!(parse_scope("scope", proto::AccreditationScope::Unspecified.into()).is_err());
+        assert_eq!(
+            parse_scope("scope", proto::AccreditationScope::Accredit.into()).unwrap(),
+            AccreditationScope::Accredit
+        );
+    }
+}
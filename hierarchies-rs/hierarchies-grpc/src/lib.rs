@@ -0,0 +1,25 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies gRPC
+//!
+//! A gRPC surface over the read-only operations of the Hierarchies library, for verifiers that
+//! integrate from languages without a Hierarchies SDK.
+//!
+//! The service is defined in `proto/hierarchies/v1/validation.proto`. Clients for other languages
+//! can be generated from it with the usual protobuf tooling. [`ValidationService`] implements
+//! the service by delegating to a [`HierarchiesClientReadOnly`](hierarchies::client::HierarchiesClientReadOnly).
+//!
+//! [`serve_metrics`] exposes the metrics of the client for Prometheus to scrape.
+
+mod convert;
+mod metrics;
+mod service;
+
+pub use metrics::serve_metrics;
+pub use service::ValidationService;
+
+/// The messages and service traits generated from the protobuf definition.
+pub mod proto {
+    tonic::include_proto!("hierarchies.v1");
+}
@@ -0,0 +1,58 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serves the `HierarchiesValidation` gRPC service.
+//!
+//! Configured through environment variables:
+//! - `API_ENDPOINT`: the RPC endpoint of the IOTA node, the local network by default
+//! - `IOTA_HIERARCHIES_PKG_ID`: the Hierarchies package, resolved from the network if not set
+//! - `GRPC_ADDRESS`: the address to listen on, `127.0.0.1:50051` by default
+//! - `METRICS_ADDRESS`: the address Prometheus metrics are served on, not served if not set
+
+use std::net::SocketAddr;
+
+use anyhow::Context;
+use hierarchies::client::HierarchiesClientReadOnly;
+use hierarchies_grpc::{ValidationService, serve_metrics};
+use iota_sdk::IOTA_LOCAL_NETWORK_URL;
+use tonic::transport::Server;
+
+const DEFAULT_GRPC_ADDRESS: &str = "127.0.0.1:50051";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let api_endpoint = std::env::var("API_ENDPOINT").unwrap_or_else(|_| IOTA_LOCAL_NETWORK_URL.to_string());
+    let address: SocketAddr = std::env::var("GRPC_ADDRESS")
+        .unwrap_or_else(|_| DEFAULT_GRPC_ADDRESS.to_string())
+        .parse()
+        .context("invalid GRPC_ADDRESS")?;
+
+    let package_id = std::env::var("IOTA_HIERARCHIES_PKG_ID")
+        .ok()
+        .map(|package_id| package_id.parse())
+        .transpose()
+        .context("invalid IOTA_HIERARCHIES_PKG_ID")?;
+    let client = HierarchiesClientReadOnly::new_from_url(&api_endpoint, package_id)
+        .await
+        .with_context(|| format!("failed to create a read-only HierarchiesClient for {api_endpoint}"))?;
+
+    let metrics_address: Option<SocketAddr> = std::env::var("METRICS_ADDRESS")
+        .ok()
+        .map(|address| address.parse())
+        .transpose()
+        .context("invalid METRICS_ADDRESS")?;
+    if let Some(metrics_address) = metrics_address {
+        let registry = prometheus::Registry::new();
+        hierarchies::metrics::register(&registry).context("failed to register the metrics")?;
+        println!("serving metrics on {metrics_address}");
+        tokio::spawn(serve_metrics(metrics_address, registry));
+    }
+
+    println!("serving hierarchies gRPC on {address}");
+    Server::builder()
+        .add_service(ValidationService::new(client).into_server())
+        .serve(address)
+        .await?;
+
+    Ok(())
+}
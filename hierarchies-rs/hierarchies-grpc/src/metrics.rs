@@ -0,0 +1,45 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::SocketAddr;
+
+use prometheus::{Encoder, Registry, TextEncoder};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Serves the metrics gathered by `registry` in the Prometheus text format on `address`.
+///
+/// Every request is answered with the current metrics, regardless of its path.
+pub async fn serve_metrics(address: SocketAddr, registry: Registry) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(address).await?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let registry = registry.clone();
+        tokio::spawn(async move {
+            if let Err(error) = respond(stream, &registry).await {
+                eprintln!("failed to serve metrics: {error}");
+            }
+        });
+    }
+}
+
+async fn respond(mut stream: TcpStream, registry: &Registry) -> anyhow::Result<()> {
+    // The request is not interpreted, reading it only drains the socket.
+    let mut request = [0; 1024];
+    let _ = stream.read(&mut request).await?;
+
+    let encoder = TextEncoder::new();
+    let mut body = Vec::new();
+    encoder.encode(&registry.gather(), &mut body)?;
+
+    let header = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        encoder.format_type(),
+        body.len()
+    );
+    stream.write_all(header.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.shutdown().await?;
+
+    Ok(())
+}
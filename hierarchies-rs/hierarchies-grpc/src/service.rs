@@ -0,0 +1,103 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use hierarchies::client::{ClientError, HierarchiesClientReadOnly};
+use hierarchies::core::types::accreditation_query::AccreditationQuery;
+use hierarchies::error::ObjectError;
+use tonic::{Request, Response, Status};
+
+use crate::convert::{parse_object_id, parse_property_name, parse_property_value, parse_scope};
+use crate::proto;
+use crate::proto::hierarchies_validation_server::HierarchiesValidation;
+
+/// The `HierarchiesValidation` gRPC service, answering requests with a [`HierarchiesClientReadOnly`].
+pub struct ValidationService {
+    client: HierarchiesClientReadOnly,
+}
+
+impl ValidationService {
+    /// Creates a new [`ValidationService`] reading through `client`.
+    pub fn new(client: HierarchiesClientReadOnly) -> Self {
+        Self { client }
+    }
+
+    /// Wraps the service into the tonic server it is served with.
+    pub fn into_server(self) -> proto::hierarchies_validation_server::HierarchiesValidationServer<Self> {
+        proto::hierarchies_validation_server::HierarchiesValidationServer::new(self)
+    }
+}
+
+/// Maps a client error to the gRPC status reported to the caller.
+fn to_status(error: ClientError) -> Status {
+    match error {
+        ClientError::Object(ObjectError::NotFound { .. }) => Status::not_found(error.to_string()),
+        ClientError::InvalidInput { .. } => Status::invalid_argument(error.to_string()),
+        ClientError::Network(_) => Status::unavailable(error.to_string()),
+        error => Status::internal(error.to_string()),
+    }
+}
+
+#[tonic::async_trait]
+impl HierarchiesValidation for ValidationService {
+    async fn validate_property(
+        &self,
+        request: Request<proto::ValidatePropertyRequest>,
+    ) -> Result<Response<proto::ValidatePropertyResponse>, Status> {
+        let request = request.into_inner();
+        let federation_id = parse_object_id("federation_id", &request.federation_id)?;
+        let attester_id = parse_object_id("attester_id", &request.attester_id)?;
+        let property_name = parse_property_name("property_name", request.property_name)?;
+        let property_value = parse_property_value("property_value", request.property_value)?;
+
+        let valid = self
+            .client
+            .validate_property(federation_id, attester_id, property_name, property_value)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(proto::ValidatePropertyResponse { valid }))
+    }
+
+    async fn get_accreditations(
+        &self,
+        request: Request<proto::GetAccreditationsRequest>,
+    ) -> Result<Response<proto::GetAccreditationsResponse>, Status> {
+        let request = request.into_inner();
+        let federation_id = parse_object_id("federation_id", &request.federation_id)?;
+        let entity_id = parse_object_id("entity_id", &request.entity_id)?;
+
+        let query = AccreditationQuery::new()
+            .for_entity(entity_id)
+            .scope(parse_scope("scope", request.scope)?);
+
+        let matches = self
+            .client
+            .query_accreditations(federation_id, &query)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(proto::GetAccreditationsResponse {
+            accreditations: matches.iter().map(|found| (&found.accreditation).into()).collect(),
+        }))
+    }
+
+    async fn resolve_trust_chain(
+        &self,
+        request: Request<proto::ResolveTrustChainRequest>,
+    ) -> Result<Response<proto::ResolveTrustChainResponse>, Status> {
+        let request = request.into_inner();
+        let federation_id = parse_object_id("federation_id", &request.federation_id)?;
+        let entity_id = parse_object_id("entity_id", &request.entity_id)?;
+        let property_name = parse_property_name("property_name", request.property_name)?;
+
+        let chain = self
+            .client
+            .resolve_trust_chain(federation_id, entity_id, &property_name)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(proto::ResolveTrustChainResponse {
+            chain: chain.as_ref().map(Into::into),
+        }))
+    }
+}
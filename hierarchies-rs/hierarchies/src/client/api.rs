@@ -0,0 +1,365 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies API
+//!
+//! [`HierarchiesApi`] is the common interface of [`HierarchiesClient`] and the in-memory
+//! `MockHierarchiesClient` of the `testing` module. Integration logic written against the
+//! trait can be unit-tested without a node and run unchanged against the network.
+//!
+//! Unlike the transaction builders of [`HierarchiesClient`], every operation is executed
+//! right away. Move aborts are reported as typed [`TransactionError`]s, see
+//! [`MoveAbortMapper`].
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+
+use async_trait::async_trait;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use product_common::core_client::CoreClient;
+use secret_storage::Signer;
+
+use crate::client::{ClientError, HierarchiesClient, HierarchiesClientReadOnly};
+use crate::core::offline::ValidationReport;
+use crate::core::transactions::{Destructive, MoveAbortMapper};
+use crate::core::types::Federation;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+
+/// Operations on federations, executed on behalf of an account.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait HierarchiesApi {
+    /// The ID of the account operations are executed with.
+    fn account_id(&self) -> ObjectID;
+
+    /// Creates a new federation with the account as its first root authority.
+    async fn create_federation(&self) -> Result<ObjectID, ClientError>;
+
+    /// Retrieves a federation by its ID.
+    async fn get_federation(&self, federation_id: ObjectID) -> Result<Federation, ClientError>;
+
+    /// Adds a property to the federation.
+    async fn add_property(&self, federation_id: ObjectID, property: FederationProperty) -> Result<(), ClientError>;
+
+    /// Revokes a property, immediately or at `valid_to_ms`.
+    async fn revoke_property(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        valid_to_ms: Option<u64>,
+    ) -> Result<(), ClientError>;
+
+    /// Adds a root authority to the federation.
+    async fn add_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<(), ClientError>;
+
+    /// Revokes a root authority of the federation.
+    async fn revoke_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<(), ClientError>;
+
+    /// Hands the root authority of `old_account_id` over to `new_account_id` in a single transaction.
+    async fn rotate_root_authority(
+        &self,
+        federation_id: ObjectID,
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+    ) -> Result<(), ClientError>;
+
+    /// Grants `receiver` an accreditation to attest `properties`.
+    async fn create_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    ) -> Result<(), ClientError>;
+
+    /// Grants `receiver` an accreditation to accredit `properties`.
+    async fn create_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    ) -> Result<(), ClientError>;
+
+    /// Revokes an accreditation to attest of `entity_id`.
+    async fn revoke_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<(), ClientError>;
+
+    /// Revokes an accreditation to accredit of `entity_id`.
+    async fn revoke_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<(), ClientError>;
+
+    /// Checks if `account_id` is a root authority of the federation.
+    async fn is_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<bool, ClientError>;
+
+    /// Validates a property attested by `attester_id`.
+    async fn validate_property(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<bool, ClientError>;
+
+    /// Validates multiple properties attested by `attester_id`.
+    async fn validate_properties(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+    ) -> Result<bool, ClientError>;
+
+    /// Validates multiple properties attested by `attester_id` and reports which rule failed
+    /// for each of them.
+    async fn validate_properties_report(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+    ) -> Result<ValidationReport, ClientError>;
+}
+
+/// Maps the error of an executed transaction, preferring the typed error of a Move abort.
+pub(crate) fn execution_error<E>(mapper: MoveAbortMapper, error: E) -> ClientError
+where
+    E: Debug + Display,
+{
+    mapper
+        .map_error(&error)
+        .map(ClientError::from)
+        .unwrap_or_else(|| ClientError::ExecutionFailed {
+            reason: error.to_string(),
+        })
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<S> HierarchiesApi for HierarchiesClient<S>
+where
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    fn account_id(&self) -> ObjectID {
+        ObjectID::from_address(self.sender_address())
+    }
+
+    async fn create_federation(&self) -> Result<ObjectID, ClientError> {
+        let federation = self
+            .create_new_federation()
+            .build_and_execute(self)
+            .await
+            .map_err(|e| execution_error(MoveAbortMapper::new(), e))?
+            .output;
+
+        Ok(*federation.id.object_id())
+    }
+
+    async fn get_federation(&self, federation_id: ObjectID) -> Result<Federation, ClientError> {
+        self.read_only().get_federation_by_id(federation_id).await
+    }
+
+    async fn add_property(&self, federation_id: ObjectID, property: FederationProperty) -> Result<(), ClientError> {
+        let mapper = MoveAbortMapper::new().with_property_name(property.name.clone());
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::add_property(self, federation_id, property.clone())
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(mapper.clone(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_property(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        valid_to_ms: Option<u64>,
+    ) -> Result<(), ClientError> {
+        let mapper = MoveAbortMapper::new().with_property_name(property_name.clone());
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::revoke_property(self, federation_id, property_name.clone(), valid_to_ms)
+                    .confirm_irreversible()
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(mapper.clone(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::add_root_authority(self, federation_id, account_id)
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::revoke_root_authority(self, federation_id, account_id)
+                    .confirm_irreversible()
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn rotate_root_authority(
+        &self,
+        federation_id: ObjectID,
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+    ) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::rotate_root_authority(self, federation_id, old_account_id, new_account_id)
+                    .confirm_irreversible()
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    ) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::create_accreditation_to_attest(self, federation_id, receiver, properties.clone())
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn create_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    ) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::create_accreditation_to_accredit(self, federation_id, receiver, properties.clone())
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::revoke_accreditation_to_attest(self, federation_id, entity_id, accreditation_id)
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<(), ClientError> {
+        self.retry_policy()
+            .run_transaction(|| async {
+                HierarchiesClient::revoke_accreditation_to_accredit(self, federation_id, entity_id, accreditation_id)
+                    .build_and_execute(self)
+                    .await
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn is_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<bool, ClientError> {
+        self.read_only().is_root_authority(federation_id, account_id).await
+    }
+
+    async fn validate_property(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<bool, ClientError> {
+        self.read_only()
+            .validate_property(federation_id, attester_id, property_name, property_value)
+            .await
+    }
+
+    async fn validate_properties(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+    ) -> Result<bool, ClientError> {
+        self.read_only()
+            .validate_properties(federation_id, attester_id, properties)
+            .await
+    }
+
+    async fn validate_properties_report(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+    ) -> Result<ValidationReport, ClientError> {
+        self.read_only()
+            .validate_properties_report(federation_id, attester_id, properties)
+            .await
+    }
+}
+
+impl<S> HierarchiesClient<S> {
+    /// The read-only client, used to call its methods where [`HierarchiesApi`] shadows them.
+    fn read_only(&self) -> &HierarchiesClientReadOnly {
+        self
+    }
+}
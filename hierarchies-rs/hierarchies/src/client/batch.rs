@@ -0,0 +1,173 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Failure semantics for batch operations.
+//!
+//! Batch APIs take a [`PartialFailurePolicy`] and report the outcome of every item as a
+//! [`BatchItemResult`]:
+//!
+//! - batch grants and revocations, e.g.
+//!   [`batch_create_accreditations_to_attest`](super::HierarchiesClient::batch_create_accreditations_to_attest)
+//! - bulk grants,
+//!   [`create_accreditations_to_attest_bulk`](super::HierarchiesClient::create_accreditations_to_attest_bulk), which
+//!   pack their items into as few transactions as the [`BulkLimits`] allow
+//! - transaction batches, [`execute_transaction_batch`](super::HierarchiesClient::execute_transaction_batch)
+//! - batch validations, [`batch_validate_properties`](super::HierarchiesClientReadOnly::batch_validate_properties)
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::client::error::ClientError;
+use crate::core::operations::GrantFootprint;
+
+/// Controls how a batch operation behaves when some of its items fail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PartialFailurePolicy {
+    /// All items are packed into a single programmable transaction.
+    /// Either every item is applied or none is.
+    #[default]
+    Atomic,
+    /// Every item is executed in its own transaction, one after another.
+    /// Failing items do not prevent the remaining items from being applied.
+    ContinueAndReport,
+}
+
+/// The outcome of a single item of a batch operation, the validity of a property for validations.
+#[derive(Debug)]
+pub struct BatchItemResult<T = ()> {
+    /// The position of the item in the batch input.
+    pub index: usize,
+    /// The outcome of the item.
+    ///
+    /// With [`PartialFailurePolicy::Atomic`] all items share the outcome, and the error, of the batch transaction.
+    pub result: Result<T, Arc<ClientError>>,
+}
+
+impl<T> BatchItemResult<T> {
+    /// Returns true if the item was applied.
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Builds the per-item results of an atomic batch from the outcome of its single transaction.
+pub(crate) fn atomic_results(len: usize, outcome: Result<(), ClientError>) -> Vec<BatchItemResult> {
+    shared_results(0..len, outcome.map_err(Arc::new))
+}
+
+/// Reports the same outcome for every item of `indices`.
+pub(crate) fn shared_results<T: Clone>(
+    indices: impl IntoIterator<Item = usize>,
+    outcome: Result<T, Arc<ClientError>>,
+) -> Vec<BatchItemResult<T>> {
+    indices
+        .into_iter()
+        .map(|index| BatchItemResult {
+            index,
+            result: outcome.clone(),
+        })
+        .collect()
+}
+
+/// Size limits for the transactions of a bulk operation.
+///
+/// The defaults stay below the protocol limits of 1024 commands and 128 KiB per transaction,
+/// leaving room for the gas data and the shared inputs of every transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkLimits {
+    /// The maximum number of commands per transaction.
+    pub max_commands: usize,
+    /// The maximum number of serialized bytes the items of a transaction may take up.
+    pub max_tx_bytes: usize,
+}
+
+impl Default for BulkLimits {
+    fn default() -> Self {
+        Self {
+            max_commands: 1000,
+            max_tx_bytes: 120 * 1024,
+        }
+    }
+}
+
+/// Greedily packs consecutive items into chunks that stay within `limits`.
+///
+/// An item that exceeds the limits on its own gets a chunk of its own.
+pub(crate) fn pack_chunks(footprints: &[GrantFootprint], limits: BulkLimits) -> Vec<Range<usize>> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    let mut used = GrantFootprint::default();
+
+    for (index, footprint) in footprints.iter().enumerate() {
+        let exceeds = used.commands + footprint.commands > limits.max_commands
+            || used.bytes + footprint.bytes > limits.max_tx_bytes;
+        if exceeds && index > start {
+            chunks.push(start..index);
+            start = index;
+            used = GrantFootprint::default();
+        }
+        used.commands += footprint.commands;
+        used.bytes += footprint.bytes;
+    }
+    if start < footprints.len() {
+        chunks.push(start..footprints.len());
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn footprint(commands: usize, bytes: usize) -> GrantFootprint {
+        GrantFootprint { commands, bytes }
+    }
+
+    #[test]
+    fn packs_items_within_limits() {
+        let limits = BulkLimits {
+            max_commands: 10,
+            max_tx_bytes: 100,
+        };
+        let footprints = [
+            footprint(4, 10),
+            footprint(4, 10),
+            footprint(4, 10),
+            footprint(1, 95),
+            footprint(1, 5),
+        ];
+
+        assert_eq!(pack_chunks(&footprints, limits), vec![0..2, 2..3, 3..5]);
+    }
+
+    #[test]
+    fn oversized_item_gets_its_own_chunk() {
+        let limits = BulkLimits {
+            max_commands: 10,
+            max_tx_bytes: 100,
+        };
+        let footprints = [footprint(1, 10), footprint(20, 10), footprint(1, 10)];
+
+        assert_eq!(pack_chunks(&footprints, limits), vec![0..1, 1..2, 2..3]);
+        assert!(pack_chunks(&[], limits).is_empty());
+    }
+
+    #[test]
+    fn atomic_items_share_the_typed_error() {
+        let error = ClientError::InvalidInput {
+            details: "bad grant".to_string(),
+        };
+        let results = atomic_results(3, Err(error));
+
+        assert_eq!(results.len(), 3);
+        let first = results[0].result.as_ref().unwrap_err();
+        assert!(matches!(**first, ClientError::InvalidInput { .. }));
+        assert!(
+            results
+                .iter()
+                .all(|item| Arc::ptr_eq(item.result.as_ref().unwrap_err(), first))
+        );
+        assert!(atomic_results(2, Ok(())).iter().all(BatchItemResult::is_ok));
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Environment-driven configuration for services embedding the SDK.
+//!
+//! [`HierarchiesConfig::from_env`] reads the settings below, so every service running in a
+//! container configures the SDK the same way. Unset variables fall back to the SDK defaults.
+//!
+//! | Variable | Description |
+//! |----------|-------------|
+//! | `HIERARCHIES_NETWORK` | `mainnet`, `testnet`, `devnet` or `localnet` |
+//! | `HIERARCHIES_NODE_URL` | URL of the IOTA node, overrides the node of the network |
+//! | `HIERARCHIES_PACKAGE_ID` | ID of the Hierarchies package, overrides the package registry |
+//! | `HIERARCHIES_PACKAGE_REGISTRY` | Path of a JSON file with package histories per network, see [`PackageOverrides`] |
+//! | `HIERARCHIES_GAS_BUDGET` | Gas budget of transactions, estimated if unset |
+//! | `HIERARCHIES_REQUEST_TIMEOUT_MS` | Time to wait for a single RPC response |
+//! | `HIERARCHIES_MAX_CONCURRENT_REQUESTS` | Number of RPC requests that may be in flight at once |
+//! | `HIERARCHIES_WS_PING_INTERVAL_MS` | Interval of WebSocket keep-alive pings, `0` disables them |
+//! | `HIERARCHIES_TEMPLATE_CACHE_CAPACITY` | Capacity of the transaction template cache, `0` disables it |
+//! | `HIERARCHIES_INVARIANT_CHECKS` | `true` to check governance transactions after execution |
+//!
+//! At least one of `HIERARCHIES_NETWORK` and `HIERARCHIES_NODE_URL` must be set.
+
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use iota_interaction::IotaKeySignature;
+use iota_interaction::types::base_types::ObjectID;
+use secret_storage::Signer;
+
+use crate::client::{ClientError, ConnectionOptions, HierarchiesClient, HierarchiesClientReadOnly};
+use crate::core::ptb_template::PtbTemplateCache;
+use crate::error::ConfigError;
+use crate::package::PackageOverrides;
+
+/// Name of the network.
+pub const ENV_NETWORK: &str = "HIERARCHIES_NETWORK";
+/// URL of the IOTA node.
+pub const ENV_NODE_URL: &str = "HIERARCHIES_NODE_URL";
+/// ID of the Hierarchies package.
+pub const ENV_PACKAGE_ID: &str = "HIERARCHIES_PACKAGE_ID";
+/// Path of the package registry overrides.
+pub const ENV_PACKAGE_REGISTRY: &str = "HIERARCHIES_PACKAGE_REGISTRY";
+/// Gas budget of transactions.
+pub const ENV_GAS_BUDGET: &str = "HIERARCHIES_GAS_BUDGET";
+/// Time to wait for a single RPC response in milliseconds.
+pub const ENV_REQUEST_TIMEOUT_MS: &str = "HIERARCHIES_REQUEST_TIMEOUT_MS";
+/// Number of RPC requests that may be in flight at once.
+pub const ENV_MAX_CONCURRENT_REQUESTS: &str = "HIERARCHIES_MAX_CONCURRENT_REQUESTS";
+/// Interval of WebSocket keep-alive pings in milliseconds.
+pub const ENV_WS_PING_INTERVAL_MS: &str = "HIERARCHIES_WS_PING_INTERVAL_MS";
+/// Capacity of the transaction template cache.
+pub const ENV_TEMPLATE_CACHE_CAPACITY: &str = "HIERARCHIES_TEMPLATE_CACHE_CAPACITY";
+/// Whether governance transactions are checked after execution.
+pub const ENV_INVARIANT_CHECKS: &str = "HIERARCHIES_INVARIANT_CHECKS";
+
+/// A known IOTA network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkProfile {
+    Mainnet,
+    Testnet,
+    Devnet,
+    Localnet,
+}
+
+impl NetworkProfile {
+    /// Returns the URL of the public node of the network.
+    pub const fn node_url(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "https://api.mainnet.iota.cafe",
+            Self::Testnet => "https://api.testnet.iota.cafe",
+            Self::Devnet => "https://api.devnet.iota.cafe",
+            Self::Localnet => "http://127.0.0.1:9000",
+        }
+    }
+}
+
+impl FromStr for NetworkProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" | "iota" => Ok(Self::Mainnet),
+            "testnet" => Ok(Self::Testnet),
+            "devnet" => Ok(Self::Devnet),
+            "localnet" => Ok(Self::Localnet),
+            _ => Err("expected one of mainnet, testnet, devnet, localnet".to_string()),
+        }
+    }
+}
+
+/// SDK settings shared by the services embedding the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HierarchiesConfig {
+    /// The network to connect to, if configured by name
+    pub network: Option<NetworkProfile>,
+    /// The URL of the IOTA node
+    pub node_url: String,
+    /// Overrides the package ID of the package registry
+    pub package_id: Option<ObjectID>,
+    /// A file of [`PackageOverrides`] applied to the package registry before connecting
+    pub package_registry: Option<PathBuf>,
+    /// Gas budget for transactions, `None` estimates the budget
+    pub gas_budget: Option<u64>,
+    /// Options for the connection to the node
+    pub connection: ConnectionOptions,
+    /// Capacity of the transaction template cache, `None` disables the cache
+    pub template_cache_capacity: Option<usize>,
+    /// Whether governance transactions are checked after execution
+    pub invariant_checks: bool,
+}
+
+impl HierarchiesConfig {
+    /// Reads the configuration from the environment, see the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidEnv`] naming the variable if a value is invalid or
+    /// neither a network nor a node URL is configured.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Reads the configuration from the variables returned by `lookup`.
+    ///
+    /// Useful for configuration sources other than the process environment.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let var = |name: &str| {
+            lookup(name)
+                .map(|value| value.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+
+        let network = parse_var::<NetworkProfile>(ENV_NETWORK, var(ENV_NETWORK))?;
+        let node_url = match (var(ENV_NODE_URL), network) {
+            (Some(url), _) => url,
+            (None, Some(network)) => network.node_url().to_string(),
+            (None, None) => {
+                return Err(invalid_env(
+                    ENV_NETWORK,
+                    format!("either {ENV_NETWORK} or {ENV_NODE_URL} must be set"),
+                ));
+            }
+        };
+        if !node_url.starts_with("http://") && !node_url.starts_with("https://") {
+            return Err(invalid_env(ENV_NODE_URL, "expected an http(s) URL"));
+        }
+
+        let package_id = parse_var::<ObjectID>(ENV_PACKAGE_ID, var(ENV_PACKAGE_ID))?;
+        let package_registry = var(ENV_PACKAGE_REGISTRY).map(PathBuf::from);
+        let gas_budget = parse_var::<u64>(ENV_GAS_BUDGET, var(ENV_GAS_BUDGET))?;
+        if gas_budget == Some(0) {
+            return Err(invalid_env(ENV_GAS_BUDGET, "must be greater than 0"));
+        }
+
+        let mut connection = ConnectionOptions::default();
+        if let Some(timeout_ms) = parse_var::<u64>(ENV_REQUEST_TIMEOUT_MS, var(ENV_REQUEST_TIMEOUT_MS))? {
+            if timeout_ms == 0 {
+                return Err(invalid_env(ENV_REQUEST_TIMEOUT_MS, "must be greater than 0"));
+            }
+            connection.request_timeout = Duration::from_millis(timeout_ms);
+        }
+        if let Some(max) = parse_var::<usize>(ENV_MAX_CONCURRENT_REQUESTS, var(ENV_MAX_CONCURRENT_REQUESTS))? {
+            if max == 0 {
+                return Err(invalid_env(ENV_MAX_CONCURRENT_REQUESTS, "must be greater than 0"));
+            }
+            connection.max_concurrent_requests = max;
+        }
+        if let Some(interval_ms) = parse_var::<u64>(ENV_WS_PING_INTERVAL_MS, var(ENV_WS_PING_INTERVAL_MS))? {
+            connection.ws_ping_interval = (interval_ms > 0).then(|| Duration::from_millis(interval_ms));
+        }
+
+        let template_cache_capacity =
+            parse_var::<usize>(ENV_TEMPLATE_CACHE_CAPACITY, var(ENV_TEMPLATE_CACHE_CAPACITY))?
+                .filter(|capacity| *capacity > 0);
+
+        let invariant_checks = match var(ENV_INVARIANT_CHECKS).map(|v| v.to_ascii_lowercase()).as_deref() {
+            None | Some("false" | "0") => false,
+            Some("true" | "1") => true,
+            Some(_) => return Err(invalid_env(ENV_INVARIANT_CHECKS, "expected true, false, 1 or 0")),
+        };
+
+        Ok(Self {
+            network,
+            node_url,
+            package_id,
+            package_registry,
+            gas_budget,
+            connection,
+            template_cache_capacity,
+            invariant_checks,
+        })
+    }
+
+    /// Connects a read-only client to the configured node and package.
+    ///
+    /// The package registry overrides, if any, are applied first.
+    pub async fn connect_read_only(&self) -> Result<HierarchiesClientReadOnly, ClientError> {
+        if let Some(path) = &self.package_registry {
+            PackageOverrides::from_file(path)?.apply().await;
+        }
+        HierarchiesClientReadOnly::new_from_url_with_options(&self.node_url, self.package_id, &self.connection).await
+    }
+
+    /// Connects a client signing with `signer` and applies the client settings.
+    ///
+    /// The gas budget is not applied here, pass [`Self::gas_budget`] to the transaction builders.
+    pub async fn connect<S>(&self, signer: S) -> Result<HierarchiesClient<S>, ClientError>
+    where
+        S: Signer<IotaKeySignature>,
+    {
+        let client = HierarchiesClient::new(self.connect_read_only().await?, signer).await?;
+        Ok(self.configure(client))
+    }
+
+    /// Applies the template cache and invariant check settings to `client`.
+    pub fn configure<S>(&self, mut client: HierarchiesClient<S>) -> HierarchiesClient<S>
+    where
+        S: Signer<IotaKeySignature>,
+    {
+        if let Some(capacity) = self.template_cache_capacity {
+            client = client.with_ptb_template_cache(PtbTemplateCache::with_capacity(capacity));
+        }
+        if self.invariant_checks {
+            client = client.with_invariant_checks();
+        }
+        client
+    }
+}
+
+fn parse_var<T>(name: &str, value: Option<String>) -> Result<Option<T>, ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|e| invalid_env(name, format!("cannot parse `{value}`: {e}")))
+        })
+        .transpose()
+}
+
+fn invalid_env(variable: &str, reason: impl Into<String>) -> ConfigError {
+    ConfigError::InvalidEnv {
+        variable: variable.to_string(),
+        reason: reason.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn config(vars: &[(&str, &str)]) -> Result<HierarchiesConfig, ConfigError> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        HierarchiesConfig::from_lookup(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_config_from_env() {
+        let config = config(&[
+            (ENV_NETWORK, "Testnet"),
+            (ENV_PACKAGE_REGISTRY, "/etc/hierarchies/packages.json"),
+            (ENV_GAS_BUDGET, "50000000"),
+            (ENV_MAX_CONCURRENT_REQUESTS, "64"),
+            (ENV_WS_PING_INTERVAL_MS, "0"),
+            (ENV_INVARIANT_CHECKS, "true"),
+        ])
+        .unwrap();
+
+        assert_eq!(config.network, Some(NetworkProfile::Testnet));
+        assert_eq!(config.node_url, NetworkProfile::Testnet.node_url());
+        assert_eq!(
+            config.package_registry,
+            Some(PathBuf::from("/etc/hierarchies/packages.json"))
+        );
+        assert_eq!(config.gas_budget, Some(50_000_000));
+        assert_eq!(config.connection.max_concurrent_requests, 64);
+        assert_eq!(config.connection.ws_ping_interval, None);
+        assert_eq!(config.template_cache_capacity, None);
+        assert!(config.invariant_checks);
+    }
+
+    #[test]
+    fn test_config_from_env_errors() {
+        let variable = |result: Result<HierarchiesConfig, ConfigError>| match result {
+            Err(ConfigError::InvalidEnv { variable, .. }) => variable,
+            other => panic!("expected an invalid variable, got {other:?}"),
+        };
+
+        assert_eq!(variable(config(&[])), ENV_NETWORK);
+        assert_eq!(variable(config(&[(ENV_NETWORK, "moon")])), ENV_NETWORK);
+        assert_eq!(variable(config(&[(ENV_NODE_URL, "localhost")])), ENV_NODE_URL);
+        assert_eq!(
+            variable(config(&[(ENV_NETWORK, "devnet"), (ENV_GAS_BUDGET, "lots")])),
+            ENV_GAS_BUDGET
+        );
+        assert_eq!(
+            variable(config(&[(ENV_NETWORK, "devnet"), (ENV_PACKAGE_ID, "0xzz")])),
+            ENV_PACKAGE_ID
+        );
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Connection tuning for the IOTA node a client talks to.
+//!
+//! Read-heavy workloads such as property validation issue one dev-inspect call per
+//! check, so throughput is bound by how many requests may be in flight on the
+//! connection rather than by the node. [`ConnectionOptions`] exposes the knobs of the
+//! underlying [`IotaClientBuilder`] with defaults sized for such workloads.
+//!
+//! HTTP connection pooling and keep-alive are handled by the RPC transport, which
+//! reuses connections for concurrent requests and is not configurable through
+//! [`IotaClientBuilder`].
+
+use std::time::Duration;
+
+use iota_interaction::{IotaClient, IotaClientBuilder};
+
+use crate::error::NetworkError;
+
+/// Default time to wait for a single RPC response.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Default number of RPC requests that may be in flight at once.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 1024;
+/// Default interval of keep-alive pings on WebSocket subscriptions.
+pub const DEFAULT_WS_PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Options for the connection to an IOTA node.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use hierarchies::client::{ConnectionOptions, HierarchiesClientReadOnly};
+/// # async fn example() -> anyhow::Result<()> {
+/// let iota_client = ConnectionOptions::default()
+///     .with_max_concurrent_requests(2048)
+///     .with_request_timeout(Duration::from_secs(10))
+///     .connect("https://api.testnet.iota.cafe")
+///     .await?;
+/// let client = HierarchiesClientReadOnly::new(iota_client).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionOptions {
+    /// Time to wait for a single RPC response
+    pub request_timeout: Duration,
+    /// Number of RPC requests that may be in flight at once, further requests wait for a free slot
+    pub max_concurrent_requests: usize,
+    /// Interval of keep-alive pings on WebSocket subscriptions, `None` disables them
+    pub ws_ping_interval: Option<Duration>,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            ws_ping_interval: Some(DEFAULT_WS_PING_INTERVAL),
+        }
+    }
+}
+
+impl ConnectionOptions {
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+
+    pub fn with_ws_ping_interval(mut self, ws_ping_interval: Option<Duration>) -> Self {
+        self.ws_ping_interval = ws_ping_interval;
+        self
+    }
+
+    /// Returns an [`IotaClientBuilder`] configured with these options.
+    pub fn client_builder(&self) -> IotaClientBuilder {
+        let builder = IotaClientBuilder::default()
+            .request_timeout(self.request_timeout)
+            .max_concurrent_requests(self.max_concurrent_requests);
+
+        match self.ws_ping_interval {
+            Some(interval) => builder.ws_ping_interval(interval),
+            None => builder,
+        }
+    }
+
+    /// Connects to the IOTA node at `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the node cannot be reached.
+    pub async fn connect(&self, url: impl AsRef<str>) -> Result<IotaClient, NetworkError> {
+        self.client_builder()
+            .build(url)
+            .await
+            .map_err(|e| NetworkError::RpcFailed { source: Box::new(e) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_options() {
+        let options = ConnectionOptions::default()
+            .with_max_concurrent_requests(16)
+            .with_ws_ping_interval(None);
+
+        assert_eq!(options.request_timeout, DEFAULT_REQUEST_TIMEOUT);
+        assert_eq!(options.max_concurrent_requests, 16);
+        assert_eq!(options.ws_ping_interval, None);
+    }
+}
@@ -6,6 +6,7 @@
 use thiserror::Error;
 
 use crate::core::error::OperationError;
+use crate::core::transactions::TransactionError;
 use crate::error::{ConfigError, NetworkError, ObjectError};
 
 /// Errors specific to read-only client operations
@@ -36,7 +37,16 @@ pub enum ClientError {
     #[error(transparent)]
     Operation(#[from] OperationError),
 
+    /// Transaction error, e.g. a Move abort mapped by [`MoveAbortMapper`](crate::error::MoveAbortMapper)
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+
     /// Invalid input
     #[error("invalid input: {details}")]
     InvalidInput { details: String },
+
+    /// Sponsored execution through a gas station failed
+    #[cfg(feature = "gas-station")]
+    #[error(transparent)]
+    Sponsorship(#[from] crate::client::SponsorshipError),
 }
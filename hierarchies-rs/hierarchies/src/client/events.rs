@@ -0,0 +1,272 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Live streams of the events of a federation.
+//!
+//! [`HierarchiesClientReadOnly::subscribe_events`] yields the typed events of a federation as they
+//! happen. Events are received over a WebSocket subscription if the node connection has one, see
+//! [`IotaClientBuilder::ws_url`](iota_interaction::IotaClientBuilder::ws_url), and are polled
+//! otherwise. Polling resumes from an [`EventID`] cursor, so a consumer that stores the
+//! [`FederationEvent::id`] of the last processed event can continue after a restart without gaps.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use iota_interaction::rpc_types::{EventFilter, IotaEvent};
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::event::EventID;
+use iota_interaction::{IotaClient, ident_str};
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::client::{ClientError, HierarchiesClientReadOnly};
+use crate::core::types::events::HierarchiesEvent;
+use crate::core::types::move_names;
+use crate::error::NetworkError;
+
+/// Default interval between two polls for new events.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Default number of events fetched per poll.
+pub const DEFAULT_EVENT_PAGE_SIZE: usize = 50;
+
+/// A typed event of a federation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationEvent {
+    /// The cursor of the event, resume polling after it with [`SubscribeOptions::after`]
+    pub id: EventID,
+    /// The time of the checkpoint that included the event, if known
+    pub timestamp_ms: Option<u64>,
+    /// The decoded event
+    pub event: HierarchiesEvent,
+}
+
+/// How events are received.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SubscriptionMode {
+    /// Use a WebSocket subscription, falling back to polling if the node connection has none
+    #[default]
+    Auto,
+    /// Use a WebSocket subscription only
+    WebSocket,
+    /// Poll for new events only
+    Polling,
+}
+
+/// Options of an event subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscribeOptions {
+    /// How events are received
+    pub mode: SubscriptionMode,
+    /// Poll for events after this cursor instead of only for new ones
+    pub after: Option<EventID>,
+    /// Interval between two polls that found no new events
+    pub poll_interval: Duration,
+    /// Number of events fetched per poll
+    pub page_size: usize,
+}
+
+impl Default for SubscribeOptions {
+    fn default() -> Self {
+        Self {
+            mode: SubscriptionMode::default(),
+            after: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            page_size: DEFAULT_EVENT_PAGE_SIZE,
+        }
+    }
+}
+
+impl SubscribeOptions {
+    pub fn with_mode(mut self, mode: SubscriptionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_after(mut self, after: EventID) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+}
+
+impl HierarchiesClientReadOnly {
+    /// Subscribes to the events of `federation_id`, see the [module docs](self).
+    pub async fn subscribe_events(
+        &self,
+        federation_id: ObjectID,
+    ) -> Result<BoxStream<'static, Result<FederationEvent, ClientError>>, ClientError> {
+        self.subscribe_events_with(federation_id, SubscribeOptions::default())
+            .await
+    }
+
+    /// Subscribes to the events of `federation_id` with custom `options`.
+    ///
+    /// A cursor in `options` always selects polling, as WebSocket subscriptions cannot replay
+    /// past events.
+    ///
+    /// # Errors
+    ///
+    /// Fails if [`SubscriptionMode::WebSocket`] is requested and the subscription cannot be set up.
+    /// Errors while receiving events are yielded by the stream, which ends after a failed poll.
+    pub async fn subscribe_events_with(
+        &self,
+        federation_id: ObjectID,
+        options: SubscribeOptions,
+    ) -> Result<BoxStream<'static, Result<FederationEvent, ClientError>>, ClientError> {
+        let filter = EventFilter::MoveEventModule {
+            package: self.package_id(),
+            module: ident_str!(move_names::MODULE_MAIN).to_owned(),
+        };
+        let package_history: Arc<[ObjectID]> = self.package_history().into();
+
+        if options.mode != SubscriptionMode::Polling && options.after.is_none() {
+            match self.iota_client.event_api().subscribe_event(filter.clone()).await {
+                Ok(events) => {
+                    let events = events.filter_map(move |event| {
+                        let package_history = package_history.clone();
+                        async move {
+                            event
+                                .map_err(rpc_error)
+                                .and_then(|event| decode(event, federation_id, &package_history))
+                                .transpose()
+                        }
+                    });
+                    return Ok(events.boxed());
+                }
+                Err(e) if options.mode == SubscriptionMode::WebSocket => return Err(rpc_error(e)),
+                Err(_) => {}
+            }
+        }
+
+        Ok(poll_events(
+            self.iota_client.clone(),
+            filter,
+            federation_id,
+            package_history,
+            options,
+        )
+        .await)
+    }
+}
+
+/// State of a polling subscription.
+struct Poller {
+    client: IotaClient,
+    filter: EventFilter,
+    federation_id: ObjectID,
+    package_history: Arc<[ObjectID]>,
+    cursor: Option<EventID>,
+    buffer: VecDeque<FederationEvent>,
+    options: SubscribeOptions,
+    failed: bool,
+}
+
+async fn poll_events(
+    client: IotaClient,
+    filter: EventFilter,
+    federation_id: ObjectID,
+    package_history: Arc<[ObjectID]>,
+    options: SubscribeOptions,
+) -> BoxStream<'static, Result<FederationEvent, ClientError>> {
+    // Without a cursor, only events emitted after subscribing are yielded
+    let cursor = match options.after {
+        Some(after) => Some(after),
+        None => client
+            .event_api()
+            .query_events(filter.clone(), None, Some(1), true)
+            .await
+            .ok()
+            .and_then(|page| page.data.first().map(|event| event.id)),
+    };
+
+    let poller = Poller {
+        client,
+        filter,
+        federation_id,
+        package_history,
+        cursor,
+        buffer: VecDeque::new(),
+        options,
+        failed: false,
+    };
+
+    stream::unfold(poller, |mut poller| async move {
+        loop {
+            if let Some(event) = poller.buffer.pop_front() {
+                return Some((Ok(event), poller));
+            }
+            if poller.failed {
+                return None;
+            }
+
+            let page = poller
+                .client
+                .event_api()
+                .query_events(
+                    poller.filter.clone(),
+                    poller.cursor,
+                    Some(poller.options.page_size),
+                    false,
+                )
+                .await;
+            let page = match page {
+                Ok(page) => page,
+                Err(e) => {
+                    poller.failed = true;
+                    return Some((Err(rpc_error(e)), poller));
+                }
+            };
+
+            poller.cursor = page.next_cursor.or(poller.cursor);
+            for event in page.data {
+                match decode(event, poller.federation_id, &poller.package_history) {
+                    Ok(Some(event)) => poller.buffer.push_back(event),
+                    Ok(None) => {}
+                    Err(e) => {
+                        poller.failed = true;
+                        return Some((Err(e), poller));
+                    }
+                }
+            }
+
+            if !page.has_next_page && poller.buffer.is_empty() {
+                tokio::time::sleep(poller.options.poll_interval).await;
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Decodes `event` if it is a typed event of `federation_id`.
+fn decode(
+    event: IotaEvent,
+    federation_id: ObjectID,
+    package_history: &[ObjectID],
+) -> Result<Option<FederationEvent>, ClientError> {
+    let decoded =
+        HierarchiesEvent::from_iota_event(&event, package_history).map_err(|e| ClientError::InvalidResponse {
+            reason: format!("failed to decode {}: {e}", event.type_.name),
+        })?;
+
+    Ok(decoded
+        .filter(|decoded| decoded.federation_address() == federation_id)
+        .map(|decoded| FederationEvent {
+            id: event.id,
+            timestamp_ms: event.timestamp_ms,
+            event: decoded,
+        }))
+}
+
+pub(super) fn rpc_error(e: impl std::error::Error + Send + Sync + 'static) -> ClientError {
+    ClientError::Network(NetworkError::RpcFailed { source: Box::new(e) })
+}
@@ -0,0 +1,310 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Federation Builder
+//!
+//! A declarative setup of a new federation.
+//!
+//! A [`FederationBuilder`] collects the initial state of a federation, i.e. its grant policy, value
+//! dictionaries, properties, root authorities and accreditations, and creates it with the minimal
+//! number of transactions:
+//! 1. creating the federation;
+//! 2. setting the grant policy, if any, so it applies to the initial accreditations;
+//! 3. a single [`TransactionBatch`](crate::core::transactions::TransactionBatch) with every other operation.
+//!
+//! ```no_run
+//! # use hierarchies::client::HierarchiesClient;
+//! # use hierarchies::core::types::property::FederationProperty;
+//! # use hierarchies::core::types::property_shape::PropertyShape;
+//! # use iota_interaction::types::base_types::ObjectID;
+//! # async fn example(
+//! #     client: &HierarchiesClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature> + iota_interaction::OptionalSync>,
+//! #     lab: ObjectID,
+//! # ) -> anyhow::Result<()> {
+//! let organic = FederationProperty::new("product.organic").with_allow_any(true);
+//! let country = FederationProperty::new("origin.country")
+//!     .with_expression(PropertyShape::MatchesPattern("[A-Z][A-Z]".into()));
+//! let setup = client
+//!     .federation_builder()
+//!     .add_property(organic.clone())
+//!     .add_property(country)
+//!     .create_accreditation_to_attest(lab, [organic])
+//!     .execute()
+//!     .await?;
+//! println!("created federation {}", setup.federation_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use secret_storage::Signer;
+
+use crate::client::HierarchiesClient;
+use crate::client::error::ClientError;
+use crate::core::transactions::BatchOperation;
+use crate::core::types::Accreditations;
+use crate::core::types::grant_policy::GrantPolicy;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+
+/// A declarative description of a new federation, created by [`FederationBuilder::execute`].
+pub struct FederationBuilder<'c, S> {
+    client: &'c HierarchiesClient<S>,
+    grant_policy: Option<GrantPolicy>,
+    operations: Vec<BatchOperation>,
+}
+
+/// The objects created by [`FederationBuilder::execute`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationSetup {
+    /// The ID of the created federation
+    pub federation_id: ObjectID,
+    /// The properties added to the federation
+    pub properties: Vec<PropertyName>,
+    /// The root authorities added besides the creator
+    pub root_authorities: Vec<ObjectID>,
+    /// The IDs of the accreditations to attest granted, by receiver
+    pub accreditations_to_attest: HashMap<ObjectID, Vec<ObjectID>>,
+    /// The IDs of the accreditations to accredit granted, by receiver
+    pub accreditations_to_accredit: HashMap<ObjectID, Vec<ObjectID>>,
+    /// The number of transactions executed
+    pub transaction_count: usize,
+}
+
+impl<'c, S> FederationBuilder<'c, S> {
+    pub(crate) fn new(client: &'c HierarchiesClient<S>) -> Self {
+        Self {
+            client,
+            grant_policy: None,
+            operations: Vec::new(),
+        }
+    }
+
+    /// Sets the grant policy, applied before the initial accreditations are granted.
+    pub fn grant_policy(mut self, policy: GrantPolicy) -> Self {
+        self.grant_policy = Some(policy);
+        self
+    }
+
+    /// Adds the value dictionary `name`.
+    pub fn set_value_dictionary(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = PropertyValue>,
+    ) -> Self {
+        self.operations.push(BatchOperation::SetValueDictionary {
+            name: name.into(),
+            values: values.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Adds `property` to the federation.
+    pub fn add_property(mut self, property: FederationProperty) -> Self {
+        self.operations.push(BatchOperation::AddProperty(property));
+        self
+    }
+
+    /// Marks `property_name` as critical.
+    pub fn critical_property(mut self, property_name: PropertyName) -> Self {
+        self.operations.push(BatchOperation::SetPropertyCritical {
+            property_name,
+            critical: true,
+        });
+        self
+    }
+
+    /// Adds `account_id` as a root authority.
+    pub fn add_root_authority(mut self, account_id: ObjectID) -> Self {
+        self.operations.push(BatchOperation::AddRootAuthority(account_id));
+        self
+    }
+
+    /// Grants `receiver` an accreditation to attest `properties`.
+    pub fn create_accreditation_to_attest(
+        mut self,
+        receiver: ObjectID,
+        properties: impl IntoIterator<Item = FederationProperty>,
+    ) -> Self {
+        self.operations.push(BatchOperation::CreateAccreditationToAttest {
+            receiver,
+            properties: properties.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Grants `receiver` an accreditation to accredit `properties`.
+    pub fn create_accreditation_to_accredit(
+        mut self,
+        receiver: ObjectID,
+        properties: impl IntoIterator<Item = FederationProperty>,
+    ) -> Self {
+        self.operations.push(BatchOperation::CreateAccreditationToAccredit {
+            receiver,
+            properties: properties.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Returns the operations executed after creating the federation, in execution order.
+    ///
+    /// Value dictionaries come first so properties can reference them, accreditations come last
+    /// so they can grant the properties added before.
+    pub fn operations(&self) -> Vec<BatchOperation> {
+        execution_order(self.operations.clone())
+    }
+
+    /// Returns the number of transactions [`FederationBuilder::execute`] submits.
+    pub fn transaction_count(&self) -> usize {
+        1 + usize::from(self.grant_policy.is_some()) + usize::from(!self.operations.is_empty())
+    }
+}
+
+impl<S> FederationBuilder<'_, S>
+where
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    /// Creates the federation and applies the description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a transaction fails. Transactions executed before remain applied,
+    /// in particular the federation exists once the first transaction succeeded.
+    pub async fn execute(self) -> Result<FederationSetup, ClientError> {
+        let client = self.client;
+
+        let federation = client
+            .create_new_federation()
+            .build_and_execute(client)
+            .await
+            .map_err(|e| ClientError::ExecutionFailed { reason: e.to_string() })?
+            .output;
+        let federation_id = *federation.id.object_id();
+
+        if let Some(policy) = self.grant_policy.clone() {
+            client
+                .set_grant_policy(federation_id, policy)
+                .build_and_execute(client)
+                .await
+                .map_err(|e| ClientError::ExecutionFailed { reason: e.to_string() })?;
+        }
+
+        let operations = self.operations();
+        if !operations.is_empty() {
+            operations
+                .iter()
+                .cloned()
+                .fold(client.transaction_batch(federation_id), |batch, operation| {
+                    batch.push(operation)
+                })
+                .into_builder()
+                .build_and_execute(client)
+                .await
+                .map_err(|e| ClientError::ExecutionFailed { reason: e.to_string() })?;
+        }
+
+        let mut setup = FederationSetup {
+            federation_id,
+            properties: vec![],
+            root_authorities: vec![],
+            accreditations_to_attest: HashMap::new(),
+            accreditations_to_accredit: HashMap::new(),
+            transaction_count: self.transaction_count(),
+        };
+        for operation in operations {
+            match operation {
+                BatchOperation::AddProperty(property) => setup.properties.push(property.name),
+                BatchOperation::AddRootAuthority(account_id) => setup.root_authorities.push(account_id),
+                BatchOperation::CreateAccreditationToAttest { receiver, .. } => {
+                    setup.accreditations_to_attest.insert(receiver, vec![]);
+                }
+                BatchOperation::CreateAccreditationToAccredit { receiver, .. } => {
+                    setup.accreditations_to_accredit.insert(receiver, vec![]);
+                }
+                _ => {}
+            }
+        }
+
+        if !setup.accreditations_to_attest.is_empty() || !setup.accreditations_to_accredit.is_empty() {
+            let governance = client.get_federation_by_id(federation_id).await?.governance;
+            for (receiver, ids) in setup.accreditations_to_attest.iter_mut() {
+                *ids = accreditation_ids(&governance.accreditations_to_attest, receiver);
+            }
+            for (receiver, ids) in setup.accreditations_to_accredit.iter_mut() {
+                *ids = accreditation_ids(&governance.accreditations_to_accredit, receiver);
+            }
+        }
+
+        Ok(setup)
+    }
+}
+
+/// Orders `operations` so value dictionaries come before the properties referencing them and
+/// accreditations after the properties they grant. Operations of the same kind keep their order.
+fn execution_order(mut operations: Vec<BatchOperation>) -> Vec<BatchOperation> {
+    operations.sort_by_key(|operation| match operation {
+        BatchOperation::SetValueDictionary { .. } => 0,
+        BatchOperation::AddProperty(_) => 1,
+        BatchOperation::SetPropertyCritical { .. } | BatchOperation::AddRootAuthority(_) => 2,
+        BatchOperation::CreateAccreditationToAttest { .. } | BatchOperation::CreateAccreditationToAccredit { .. } => 3,
+    });
+    operations
+}
+
+/// Returns the IDs of the accreditations granted to `receiver`.
+fn accreditation_ids(granted: &HashMap<ObjectID, Accreditations>, receiver: &ObjectID) -> Vec<ObjectID> {
+    granted
+        .get(receiver)
+        .map(|accreditations| {
+            accreditations
+                .accreditations
+                .iter()
+                .map(|accreditation| *accreditation.id.object_id())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_execution_order() {
+        let role = FederationProperty::new("role").with_value_dictionary("roles");
+        let operations = execution_order(vec![
+            BatchOperation::CreateAccreditationToAttest {
+                receiver: ObjectID::ZERO,
+                properties: vec![role.clone()],
+            },
+            BatchOperation::AddProperty(role.clone()),
+            BatchOperation::SetPropertyCritical {
+                property_name: role.name.clone(),
+                critical: true,
+            },
+            BatchOperation::SetValueDictionary {
+                name: "roles".to_string(),
+                values: HashSet::new(),
+            },
+            BatchOperation::AddProperty(FederationProperty::new("level")),
+        ]);
+
+        assert!(matches!(operations[0], BatchOperation::SetValueDictionary { .. }));
+        assert_eq!(operations[1], BatchOperation::AddProperty(role));
+        assert_eq!(
+            operations[2],
+            BatchOperation::AddProperty(FederationProperty::new("level"))
+        );
+        assert!(matches!(operations[3], BatchOperation::SetPropertyCritical { .. }));
+        assert!(matches!(
+            operations[4],
+            BatchOperation::CreateAccreditationToAttest { .. }
+        ));
+    }
+}
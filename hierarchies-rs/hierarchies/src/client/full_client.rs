@@ -44,6 +44,9 @@
 //! - `with_sender(address)` - Override transaction sender
 //! - `with_sponsor(callback)` - Have another party pay for gas
 //!
+//! With the `gas-station` feature, any builder can also be executed through a gas station with
+//! [`SponsoredExecution::build_and_execute_sponsored`](crate::client::SponsoredExecution::build_and_execute_sponsored).
+//!
 //! ## Example: Complete Hierarchies Workflow
 //!
 //! ```rust,ignore
@@ -69,7 +72,9 @@
 //! # }
 //! ```
 
+use std::collections::HashSet;
 use std::ops::Deref;
+use std::sync::Arc;
 
 use iota_interaction::types::base_types::{IotaAddress, ObjectID};
 use iota_interaction::types::crypto::PublicKey;
@@ -80,17 +85,52 @@ use product_common::transaction::transaction_builder::TransactionBuilder;
 use secret_storage::Signer;
 
 use super::HierarchiesClientReadOnly;
+use crate::client::api::execution_error;
+use crate::client::batch::{
+    BatchItemResult, BulkLimits, PartialFailurePolicy, atomic_results, pack_chunks, shared_results,
+};
 use crate::client::error::ClientError;
+use crate::client::federation_builder::FederationBuilder;
+use crate::client::retry::RetryPolicy;
+use crate::client::sequencer::TransactionSequencer;
+use crate::client::validation_config::ValidationConfig;
+use crate::core::capability_cache::{CapabilityKey, capability_cache};
+use crate::core::operations::HierarchiesImpl;
+use crate::core::ptb_template::PtbTemplateCache;
 use crate::core::transactions::add_root_authority::AddRootAuthority;
+use crate::core::transactions::create_attestation::CreateAttestation;
+use crate::core::transactions::federation_links::{LinkFederation, UnlinkFederation};
 use crate::core::transactions::properties::add_property::AddProperty;
+use crate::core::transactions::properties::deprecate_property::DeprecateProperty;
+use crate::core::transactions::properties::remove_value_dictionary::RemoveValueDictionary;
 use crate::core::transactions::properties::revoke_property::RevokeProperty;
+use crate::core::transactions::properties::set_property_critical::SetPropertyCritical;
+use crate::core::transactions::properties::set_property_metadata::SetPropertyMetadata;
+use crate::core::transactions::properties::set_property_tags::SetPropertyTags;
+use crate::core::transactions::properties::set_value_dictionary::SetValueDictionary;
+use crate::core::transactions::quorum::{ApproveAction, ExecuteAction, ProposeAction, SetQuorumThreshold};
+use crate::core::transactions::revoke_attestation::RevokeAttestation;
 use crate::core::transactions::revoke_root_authority::RevokeRootAuthority;
+use crate::core::transactions::rotate_root_authority::RotateRootAuthority;
+use crate::core::transactions::set_federation_metadata::SetFederationMetadata;
+use crate::core::transactions::set_grant_policy::SetGrantPolicy;
+use crate::core::transactions::transfer_capability::{ConfirmCapabilityTransfer, TransferCapability};
 use crate::core::transactions::{
-    CreateAccreditation, CreateAccreditationToAttest, CreateFederation, ReinstateRootAuthority,
-    RevokeAccreditationToAccredit, RevokeAccreditationToAttest,
+    ActivateAccreditation, BatchOperation, CreateAccreditation, CreateAccreditationToAttest,
+    CreateAccreditationsToAttest, CreateFederation, Destructive, InvariantChecked, MoveAbortMapper,
+    ReinstateRootAuthority, RevokeAccreditationToAccredit, RevokeAccreditationToAttest, RevokeAccreditationsToAttest,
+    SetAccreditationTags, TransactionBatch,
 };
-use crate::core::types::property::FederationProperty;
+use crate::core::types::credential_anchor::{CredentialHash, anchored_credential};
+use crate::core::types::grant_policy::GrantPolicy;
+use crate::core::types::property::{FederationProperty, PropertyMetadata};
 use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::quorum::GovernanceAction;
+use crate::core::types::selective_disclosure::{self, Disclosure};
+use crate::core::types::subject_id::SubjectId;
+use crate::core::types::timespan::Timespan;
+use crate::core::types::{CapabilityKind, FederationMetadata, OwnedCapability};
 use crate::iota_interaction_adapter::IotaClientAdapter;
 
 /// The `HierarchiesClient` struct is responsible for managing the connection to the
@@ -101,6 +141,12 @@ pub struct HierarchiesClient<S> {
     public_key: PublicKey,
     /// The signer of the client.
     signer: S,
+    /// Templates reused when building accreditation transactions.
+    ptb_templates: Option<PtbTemplateCache>,
+    /// Whether governance transactions verify their outcome after execution.
+    invariant_checks: bool,
+    /// Queues the operations submitted with [`HierarchiesClient::submit_sequenced`].
+    sequencer: TransactionSequencer,
 }
 
 impl<S> HierarchiesClient<S>
@@ -138,8 +184,57 @@ where
             public_key,
             read_client: client,
             signer,
+            ptb_templates: None,
+            invariant_checks: false,
+            sequencer: TransactionSequencer::new(),
         })
     }
+
+    /// Enables reuse of built transactions through `cache`.
+    ///
+    /// Accreditations to attest that only differ in their receiver are then built
+    /// by substituting the receiver in a cached template, see [`PtbTemplateCache`].
+    pub fn with_ptb_template_cache(mut self, cache: PtbTemplateCache) -> Self {
+        self.ptb_templates = Some(cache);
+        self
+    }
+
+    /// Enables post-execution invariant checks for governance transactions.
+    ///
+    /// After execution, the federation is re-fetched and checked against the intent of the
+    /// transaction, see [`InvariantChecked`]. A mismatch fails with
+    /// [`OperationError::InvariantViolation`](crate::core::OperationError::InvariantViolation).
+    pub fn with_invariant_checks(mut self) -> Self {
+        self.invariant_checks = true;
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] of the client, see [`HierarchiesClientReadOnly::with_retry_policy`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.read_client = self.read_client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Sets the [`ValidationConfig`] of the client, see [`HierarchiesClientReadOnly::with_validation_config`].
+    pub fn with_validation_config(mut self, validation_config: ValidationConfig) -> Self {
+        self.read_client = self.read_client.with_validation_config(validation_config);
+        self
+    }
+
+    /// Replaces the [`TransactionSequencer`] used by [`HierarchiesClient::submit_sequenced`].
+    pub fn with_transaction_sequencer(mut self, sequencer: TransactionSequencer) -> Self {
+        self.sequencer = sequencer;
+        self
+    }
+
+    /// Enables the invariant checks of `tx` if they are enabled for this client.
+    fn checked<T: InvariantChecked>(&self, tx: T) -> T {
+        if self.invariant_checks {
+            tx.with_invariant_checks()
+        } else {
+            tx
+        }
+    }
 }
 
 impl<S> HierarchiesClient<S>
@@ -151,29 +246,67 @@ where
         TransactionBuilder::new(CreateFederation::new())
     }
 
+    /// Creates a [`FederationBuilder`] describing a new federation and its initial state.
+    ///
+    /// [`FederationBuilder::execute`] creates the federation with the minimal number of transactions.
+    pub fn federation_builder(&self) -> FederationBuilder<'_, S> {
+        FederationBuilder::new(self)
+    }
+
     /// Creates a [`TransactionBuilder`] for adding a root authority to a federation.
     pub fn add_root_authority(
         &self,
         federation_id: ObjectID,
         account_id: ObjectID,
     ) -> TransactionBuilder<AddRootAuthority> {
-        TransactionBuilder::new(AddRootAuthority::new(federation_id, account_id, self.sender_address()))
+        TransactionBuilder::new(self.checked(AddRootAuthority::new(federation_id, account_id, self.sender_address())))
     }
 
     /// Creates a [`TransactionBuilder`] for revoking a root authority from a federation.
     ///
     /// Only existing root authorities can revoke other root authorities.
     /// Cannot revoke the last root authority to prevent lockout.
+    ///
+    /// The revocation takes effect immediately and can only be reversed with
+    /// [`reinstate_root_authority`](Self::reinstate_root_authority), so it must be confirmed with
+    /// [`Destructive::confirm_irreversible`] before it can be built.
+    ///
+    /// The reason of the revocation can be recorded with
+    /// [`WithReason::with_reason`](crate::core::transactions::WithReason::with_reason).
     pub fn revoke_root_authority(
         &self,
         federation_id: ObjectID,
         account_id: ObjectID,
     ) -> TransactionBuilder<RevokeRootAuthority> {
-        TransactionBuilder::new(RevokeRootAuthority::new(
+        TransactionBuilder::new(self.checked(RevokeRootAuthority::new(
             federation_id,
             account_id,
             self.sender_address(),
-        ))
+        )))
+    }
+
+    /// Creates a [`TransactionBuilder`] for handing the root authority of `old_account_id` over
+    /// to `new_account_id`.
+    ///
+    /// The new root authority is added, receives a `RootAuthorityCap` and the old one is revoked
+    /// in the same transaction, so the federation never ends up with both or neither of them.
+    /// The signer may be the old root authority itself.
+    ///
+    /// The old root authority is revoked immediately and can only be brought back with
+    /// [`reinstate_root_authority`](Self::reinstate_root_authority), so the rotation must be
+    /// confirmed with [`Destructive::confirm_irreversible`] before it can be built.
+    pub fn rotate_root_authority(
+        &self,
+        federation_id: ObjectID,
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+    ) -> TransactionBuilder<RotateRootAuthority> {
+        TransactionBuilder::new(self.checked(RotateRootAuthority::new(
+            federation_id,
+            old_account_id,
+            new_account_id,
+            self.sender_address(),
+        )))
     }
 
     /// Creates a [`TransactionBuilder`] for reinstating a revoked root authority to a federation.
@@ -185,11 +318,11 @@ where
         federation_id: ObjectID,
         account_id: ObjectID,
     ) -> TransactionBuilder<ReinstateRootAuthority> {
-        TransactionBuilder::new(ReinstateRootAuthority::new(
+        TransactionBuilder::new(self.checked(ReinstateRootAuthority::new(
             federation_id,
             account_id,
             self.sender_address(),
-        ))
+        )))
     }
 
     /// Creates a new [`AddProperty`] transaction builder.
@@ -198,83 +331,752 @@ where
         federation_id: ObjectID,
         property: FederationProperty,
     ) -> TransactionBuilder<AddProperty> {
-        TransactionBuilder::new(AddProperty::new(federation_id, property, self.sender_address()))
+        TransactionBuilder::new(self.checked(AddProperty::new(federation_id, property, self.sender_address())))
     }
 
     /// Creates a new [`RevokeProperty`] transaction builder.
+    ///
+    /// Revoking without `valid_to_ms` takes effect immediately and must be confirmed with
+    /// [`Destructive::confirm_irreversible`] before it can be built.
+    ///
+    /// The reason of the revocation can be recorded with
+    /// [`WithReason::with_reason`](crate::core::transactions::WithReason::with_reason).
     pub fn revoke_property(
         &self,
         federation_id: ObjectID,
         property_name: PropertyName,
         valid_to_ms: Option<u64>,
     ) -> TransactionBuilder<RevokeProperty> {
-        TransactionBuilder::new(RevokeProperty::new(
+        TransactionBuilder::new(self.checked(RevokeProperty::new(
             federation_id,
             property_name,
             valid_to_ms,
             self.sender_address(),
+        )))
+    }
+
+    /// Creates a new [`SetPropertyCritical`] transaction builder.
+    ///
+    /// Marks (`critical = true`) or unmarks a property as critical, so that
+    /// event consumers can prioritize changes to it.
+    pub fn set_property_critical(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        critical: bool,
+    ) -> TransactionBuilder<SetPropertyCritical> {
+        TransactionBuilder::new(SetPropertyCritical::new(
+            federation_id,
+            property_name,
+            critical,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`DeprecateProperty`] transaction builder.
+    ///
+    /// Marks a property as deprecated, optionally naming the property replacing it. The
+    /// property stays valid, but validation reports warn about it.
+    pub fn deprecate_property(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        replacement: Option<PropertyName>,
+    ) -> TransactionBuilder<DeprecateProperty> {
+        TransactionBuilder::new(DeprecateProperty::new(
+            federation_id,
+            property_name,
+            replacement,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`SetPropertyMetadata`] transaction builder.
+    ///
+    /// Replaces the human-readable schema information of a property, empty metadata removes all entries.
+    pub fn set_property_metadata(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        metadata: PropertyMetadata,
+    ) -> TransactionBuilder<SetPropertyMetadata> {
+        TransactionBuilder::new(SetPropertyMetadata::new(
+            federation_id,
+            property_name,
+            metadata,
+            self.sender_address(),
         ))
     }
 
+    /// Creates a new [`SetPropertyTags`] transaction builder.
+    ///
+    /// Replaces the tags of a property, an empty set removes all tags.
+    pub fn set_property_tags(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        tags: impl IntoIterator<Item = impl Into<String>>,
+    ) -> TransactionBuilder<SetPropertyTags> {
+        TransactionBuilder::new(SetPropertyTags::new(
+            federation_id,
+            property_name,
+            tags.into_iter().map(Into::into).collect(),
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`SetAccreditationTags`] transaction builder.
+    ///
+    /// Replaces the tags of an accreditation, an empty set removes all tags.
+    /// Only the issuer of the accreditation or a root authority can set its tags.
+    pub fn set_accreditation_tags(
+        &self,
+        federation_id: ObjectID,
+        accreditation_id: ObjectID,
+        tags: impl IntoIterator<Item = impl Into<String>>,
+    ) -> TransactionBuilder<SetAccreditationTags> {
+        TransactionBuilder::new(SetAccreditationTags::new(
+            federation_id,
+            accreditation_id,
+            tags.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Creates a new [`SetValueDictionary`] transaction builder.
+    ///
+    /// Creates or replaces the value dictionary `name`. Properties created with
+    /// [`FederationProperty::with_value_dictionary`] accept exactly its values.
+    pub fn set_value_dictionary(
+        &self,
+        federation_id: ObjectID,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = PropertyValue>,
+    ) -> TransactionBuilder<SetValueDictionary> {
+        TransactionBuilder::new(SetValueDictionary::new(
+            federation_id,
+            name.into(),
+            values.into_iter().collect::<HashSet<_>>(),
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`RemoveValueDictionary`] transaction builder.
+    ///
+    /// Only dictionaries that are no longer referenced by a property can be removed.
+    pub fn remove_value_dictionary(
+        &self,
+        federation_id: ObjectID,
+        name: impl Into<String>,
+    ) -> TransactionBuilder<RemoveValueDictionary> {
+        TransactionBuilder::new(RemoveValueDictionary::new(
+            federation_id,
+            name.into(),
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`SetGrantPolicy`] transaction builder.
+    ///
+    /// Sets the defaults and limits applied to accreditations granted afterwards,
+    /// see [`GrantPolicy`].
+    pub fn set_grant_policy(&self, federation_id: ObjectID, policy: GrantPolicy) -> TransactionBuilder<SetGrantPolicy> {
+        TransactionBuilder::new(SetGrantPolicy::new(federation_id, policy, self.sender_address()))
+    }
+
+    /// Creates a new [`SetFederationMetadata`] transaction builder.
+    ///
+    /// Replaces the human-readable description of the federation shown by wallets and explorers.
+    pub fn set_federation_metadata(
+        &self,
+        federation_id: ObjectID,
+        metadata: FederationMetadata,
+    ) -> TransactionBuilder<SetFederationMetadata> {
+        TransactionBuilder::new(SetFederationMetadata::new(
+            federation_id,
+            metadata,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`LinkFederation`] transaction builder.
+    ///
+    /// Recognizes `child_federation_id` as a child federation, so trust chains ending at one of
+    /// its root authorities are trusted like chains of `federation_id`.
+    pub fn link_federation(
+        &self,
+        federation_id: ObjectID,
+        child_federation_id: ObjectID,
+    ) -> TransactionBuilder<LinkFederation> {
+        TransactionBuilder::new(LinkFederation::new(
+            federation_id,
+            child_federation_id,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`UnlinkFederation`] transaction builder.
+    ///
+    /// Stops recognizing `child_federation_id` as a child federation.
+    pub fn unlink_federation(
+        &self,
+        federation_id: ObjectID,
+        child_federation_id: ObjectID,
+    ) -> TransactionBuilder<UnlinkFederation> {
+        TransactionBuilder::new(UnlinkFederation::new(
+            federation_id,
+            child_federation_id,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`SetQuorumThreshold`] transaction builder.
+    ///
+    /// With a threshold above 1, revoking root authorities and properties requires a
+    /// proposal approved by `threshold` root authorities, see [`Self::propose_action`].
+    pub fn set_quorum_threshold(
+        &self,
+        federation_id: ObjectID,
+        threshold: u64,
+    ) -> TransactionBuilder<SetQuorumThreshold> {
+        TransactionBuilder::new(SetQuorumThreshold::new(federation_id, threshold, self.sender_address()))
+    }
+
+    /// Creates a new [`ProposeAction`] transaction builder.
+    ///
+    /// The proposal is approved by the sender; its ID is the output of the transaction.
+    pub fn propose_action(
+        &self,
+        federation_id: ObjectID,
+        action: GovernanceAction,
+    ) -> TransactionBuilder<ProposeAction> {
+        TransactionBuilder::new(ProposeAction::new(federation_id, action, self.sender_address()))
+    }
+
+    /// Creates a new [`ApproveAction`] transaction builder.
+    pub fn approve_action(&self, federation_id: ObjectID, proposal_id: u64) -> TransactionBuilder<ApproveAction> {
+        TransactionBuilder::new(ApproveAction::new(federation_id, proposal_id, self.sender_address()))
+    }
+
+    /// Creates a new [`ExecuteAction`] transaction builder.
+    ///
+    /// The proposal must be approved by the quorum of active root authorities.
+    pub fn execute_action(&self, federation_id: ObjectID, proposal_id: u64) -> TransactionBuilder<ExecuteAction> {
+        TransactionBuilder::new(ExecuteAction::new(federation_id, proposal_id, self.sender_address()))
+    }
+
+    /// Creates a new [`TransferCapability`] transaction builder.
+    ///
+    /// Transfers the sender's capability of the given `kind` to `recipient`, e.g. to rotate a
+    /// compromised key. The root authority and accreditations of the sender move to `recipient`.
+    /// While a quorum is required, the transfer must first be confirmed by another root authority
+    /// with [`HierarchiesClient::confirm_capability_transfer`].
+    pub fn transfer_capability(
+        &self,
+        federation_id: ObjectID,
+        kind: CapabilityKind,
+        recipient: IotaAddress,
+    ) -> TransactionBuilder<TransferCapability> {
+        TransactionBuilder::new(TransferCapability::new(
+            federation_id,
+            kind,
+            recipient,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`ConfirmCapabilityTransfer`] transaction builder.
+    ///
+    /// Confirms the transfer of the capability `capability_id` to `recipient`.
+    pub fn confirm_capability_transfer(
+        &self,
+        federation_id: ObjectID,
+        capability_id: ObjectID,
+        recipient: IotaAddress,
+    ) -> TransactionBuilder<ConfirmCapabilityTransfer> {
+        TransactionBuilder::new(ConfirmCapabilityTransfer::new(
+            federation_id,
+            capability_id,
+            recipient,
+            self.sender_address(),
+        ))
+    }
+
+    /// Lists the capabilities owned by the sender across all federations.
+    ///
+    /// See [`HierarchiesClientReadOnly::get_owned_capabilities`].
+    pub async fn list_my_capabilities(&self) -> Result<Vec<OwnedCapability>, ClientError> {
+        self.get_owned_capabilities(self.sender_address()).await
+    }
+
+    /// Drops the cached capabilities of the sender and repopulates the cache from the
+    /// capabilities the sender currently owns.
+    ///
+    /// Transactions look up the sender's capabilities in the process-wide
+    /// [`capability_cache`](crate::core::capability_cache::capability_cache) instead of scanning
+    /// the owned objects each time. Call this after capabilities were transferred outside this client.
+    pub async fn refresh_capabilities(&self) -> Result<Vec<OwnedCapability>, ClientError> {
+        let cache = capability_cache();
+        cache.invalidate_owner(self.sender_address());
+        let capabilities = self.list_my_capabilities().await?;
+        for capability in &capabilities {
+            cache.insert(
+                CapabilityKey::new(self.sender_address(), capability.federation_id, capability.kind),
+                capability.object_ref.0,
+            );
+        }
+        Ok(capabilities)
+    }
+
+    /// Creates an empty [`TransactionBatch`] for `federation_id`.
+    ///
+    /// Operations added to the batch are executed in a single transaction, see
+    /// [`TransactionBatch::into_builder`], or one by one with
+    /// [`execute_transaction_batch`](Self::execute_transaction_batch).
+    pub fn transaction_batch(&self, federation_id: ObjectID) -> TransactionBatch {
+        TransactionBatch::new(federation_id, self.sender_address())
+    }
+
+    /// Executes the operations of `batch` and reports the outcome of every operation.
+    ///
+    /// With [`PartialFailurePolicy::Atomic`] the batch is executed as a single transaction, with
+    /// [`PartialFailurePolicy::ContinueAndReport`] every operation is executed in its own transaction,
+    /// in the order they were added.
+    pub async fn execute_transaction_batch(
+        &self,
+        batch: TransactionBatch,
+        policy: PartialFailurePolicy,
+    ) -> Vec<BatchItemResult> {
+        if batch.is_empty() {
+            return vec![];
+        }
+
+        match policy {
+            PartialFailurePolicy::Atomic => {
+                let len = batch.len();
+                let outcome = batch
+                    .into_builder()
+                    .build_and_execute(self)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e));
+                atomic_results(len, outcome)
+            }
+            PartialFailurePolicy::ContinueAndReport => {
+                let mut results = Vec::with_capacity(batch.len());
+                for (index, operation) in batch.operations().iter().cloned().enumerate() {
+                    let result = self
+                        .transaction_batch(batch.federation_id())
+                        .push(operation)
+                        .into_builder()
+                        .build_and_execute(self)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| Arc::new(execution_error(MoveAbortMapper::new(), e)));
+                    results.push(BatchItemResult { index, result });
+                }
+                results
+            }
+        }
+    }
+
+    /// Executes `operation` on the federation through the client's [`TransactionSequencer`].
+    ///
+    /// Operations submitted concurrently for the same federation are serialized and
+    /// batched instead of racing for the federation object, see the
+    /// [`sequencer`](crate::client::TransactionSequencer) docs. Resolves once the
+    /// transaction containing the operation was executed.
+    ///
+    /// The error of a failed transaction is shared by every operation it contained.
+    pub async fn submit_sequenced(
+        &self,
+        federation_id: ObjectID,
+        operation: BatchOperation,
+    ) -> Result<(), Arc<ClientError>> {
+        self.sequencer.submit(self, federation_id, operation).await
+    }
+
     /// Creates a new [`CreateAccreditationToAttest`] transaction builder.
+    ///
+    /// The receiver can be identified by object ID or IOTA DID, see [`SubjectId`].
     pub fn create_accreditation_to_attest(
         &self,
         federation_id: ObjectID,
-        receiver: ObjectID,
+        receiver: impl Into<SubjectId>,
         want_properties: impl IntoIterator<Item = FederationProperty>,
     ) -> TransactionBuilder<CreateAccreditationToAttest> {
-        TransactionBuilder::new(CreateAccreditationToAttest::new(
+        let tx = self.checked(CreateAccreditationToAttest::new(
             federation_id,
             receiver,
             want_properties,
             self.sender_address(),
+        ));
+        match &self.ptb_templates {
+            Some(cache) => TransactionBuilder::new(tx.with_template_cache(cache.clone())),
+            None => TransactionBuilder::new(tx),
+        }
+    }
+
+    /// Anchors the hash of an externally issued `credential` in the federation.
+    ///
+    /// Grants `issuer` an accreditation to attest the [`CredentialHash`] of `credential` for
+    /// `property_name`, which should be a property dedicated to anchored credentials, see
+    /// [`anchor_property`](crate::core::types::credential_anchor::anchor_property). Verifiers
+    /// check the credential with [`HierarchiesClientReadOnly::verify_anchored_credential`].
+    pub fn anchor_credential(
+        &self,
+        federation_id: ObjectID,
+        issuer: ObjectID,
+        property_name: PropertyName,
+        credential: impl AsRef<[u8]>,
+    ) -> TransactionBuilder<CreateAccreditationToAttest> {
+        let grant = anchored_credential(property_name, CredentialHash::of(credential));
+        self.create_accreditation_to_attest(federation_id, issuer, [grant])
+    }
+
+    /// Creates a new [`ActivateAccreditation`] transaction builder.
+    ///
+    /// Ends the objection window of an accreditation, see [`GrantPolicy::with_objection_window_ms`].
+    /// Only root authorities can activate accreditations.
+    pub fn activate_accreditation(
+        &self,
+        federation_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> TransactionBuilder<ActivateAccreditation> {
+        TransactionBuilder::new(ActivateAccreditation::new(
+            federation_id,
+            accreditation_id,
+            self.sender_address(),
         ))
     }
 
     /// Creates a new [`RevokeAccreditationToAttest`] transaction builder.
+    ///
+    /// The reason of the revocation can be recorded with
+    /// [`WithReason::with_reason`](crate::core::transactions::WithReason::with_reason).
     pub fn revoke_accreditation_to_attest(
         &self,
         federation_id: ObjectID,
         user_id: ObjectID,
         permission_id: ObjectID,
     ) -> TransactionBuilder<RevokeAccreditationToAttest> {
-        TransactionBuilder::new(RevokeAccreditationToAttest::new(
+        TransactionBuilder::new(self.checked(RevokeAccreditationToAttest::new(
             federation_id,
             user_id,
             permission_id,
             self.sender_address(),
+        )))
+    }
+
+    /// Creates a new [`CreateAccreditationsToAttest`] transaction builder.
+    ///
+    /// All grants are applied in a single transaction.
+    pub fn create_accreditations_to_attest(
+        &self,
+        federation_id: ObjectID,
+        grants: impl IntoIterator<Item = (ObjectID, Vec<FederationProperty>)>,
+    ) -> TransactionBuilder<CreateAccreditationsToAttest> {
+        TransactionBuilder::new(CreateAccreditationsToAttest::new(
+            federation_id,
+            grants,
+            self.sender_address(),
+        ))
+    }
+
+    /// Creates a new [`RevokeAccreditationsToAttest`] transaction builder.
+    ///
+    /// Each revocation is an `(entity_id, accreditation_id)` pair. All revocations
+    /// are applied in a single transaction, which has to be confirmed with
+    /// [`Destructive::confirm_irreversible`] if it revokes more than one accreditation.
+    pub fn revoke_accreditations_to_attest(
+        &self,
+        federation_id: ObjectID,
+        revocations: impl IntoIterator<Item = (ObjectID, ObjectID)>,
+    ) -> TransactionBuilder<RevokeAccreditationsToAttest> {
+        TransactionBuilder::new(RevokeAccreditationsToAttest::new(
+            federation_id,
+            revocations,
+            self.sender_address(),
         ))
     }
 
+    /// Grants accreditations to attest to several receivers and reports the outcome of every grant.
+    ///
+    /// With [`PartialFailurePolicy::Atomic`] all grants are executed in one transaction,
+    /// with [`PartialFailurePolicy::ContinueAndReport`] every grant is executed in its own transaction.
+    pub async fn batch_create_accreditations_to_attest(
+        &self,
+        federation_id: ObjectID,
+        grants: impl IntoIterator<Item = (ObjectID, Vec<FederationProperty>)>,
+        policy: PartialFailurePolicy,
+    ) -> Vec<BatchItemResult> {
+        let grants: Vec<_> = grants.into_iter().collect();
+        if grants.is_empty() {
+            return vec![];
+        }
+
+        match policy {
+            PartialFailurePolicy::Atomic => {
+                let len = grants.len();
+                let outcome = self
+                    .create_accreditations_to_attest(federation_id, grants)
+                    .build_and_execute(self)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e));
+                atomic_results(len, outcome)
+            }
+            PartialFailurePolicy::ContinueAndReport => {
+                let mut results = Vec::with_capacity(grants.len());
+                for (index, (receiver, want_properties)) in grants.into_iter().enumerate() {
+                    let result = self
+                        .create_accreditation_to_attest(federation_id, receiver, want_properties)
+                        .build_and_execute(self)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| Arc::new(execution_error(MoveAbortMapper::new(), e)));
+                    results.push(BatchItemResult { index, result });
+                }
+                results
+            }
+        }
+    }
+
+    /// Grants accreditations to attest to many receivers, packed into as few transactions as possible.
+    ///
+    /// Uses the default [`BulkLimits`], see [`HierarchiesClient::create_accreditations_to_attest_bulk_with_limits`].
+    pub async fn create_accreditations_to_attest_bulk(
+        &self,
+        federation_id: ObjectID,
+        grants: Vec<(ObjectID, Vec<FederationProperty>)>,
+        policy: PartialFailurePolicy,
+    ) -> Vec<BatchItemResult> {
+        self.create_accreditations_to_attest_bulk_with_limits(federation_id, grants, policy, BulkLimits::default())
+            .await
+    }
+
+    /// Grants accreditations to attest to many receivers, packed into transactions that stay within `limits`.
+    ///
+    /// With [`PartialFailurePolicy::Atomic`] all grants must fit into a single transaction, otherwise
+    /// none is submitted. With [`PartialFailurePolicy::ContinueAndReport`] consecutive grants share a
+    /// transaction and the following transactions are still executed if one fails. The grants of a
+    /// transaction that aborted are retried one by one, so only the failing grants are reported. Other
+    /// failures leave it open whether the grants were applied, they are reported for the whole transaction.
+    /// The outcome is reported for every grant, in input order.
+    pub async fn create_accreditations_to_attest_bulk_with_limits(
+        &self,
+        federation_id: ObjectID,
+        grants: Vec<(ObjectID, Vec<FederationProperty>)>,
+        policy: PartialFailurePolicy,
+        limits: BulkLimits,
+    ) -> Vec<BatchItemResult> {
+        let len = grants.len();
+        let mut results = Vec::with_capacity(len);
+        let mut footprints = Vec::with_capacity(len);
+        let mut packable = Vec::with_capacity(len);
+
+        for (index, (receiver, want_properties)) in grants.into_iter().enumerate() {
+            match HierarchiesImpl::grant_footprint(self.package_id(), receiver, want_properties.clone()) {
+                Ok(footprint) => {
+                    footprints.push(footprint);
+                    packable.push((index, (receiver, want_properties)));
+                }
+                Err(e) => results.push(BatchItemResult {
+                    index,
+                    result: Err(Arc::new(ClientError::InvalidInput { details: e.to_string() })),
+                }),
+            }
+        }
+
+        let chunks = pack_chunks(&footprints, limits);
+        if policy == PartialFailurePolicy::Atomic {
+            if !results.is_empty() {
+                // Nothing is submitted, the invalid grants report why
+                let skipped = Err(Arc::new(ClientError::InvalidInput {
+                    details: "the batch contains invalid grants".to_string(),
+                }));
+                results.extend(shared_results(packable.into_iter().map(|(index, _)| index), skipped));
+                results.sort_by_key(|item| item.index);
+                return results;
+            }
+            if chunks.len() > 1 {
+                let error = ClientError::InvalidInput {
+                    details: "the grants exceed the limits of a single transaction".to_string(),
+                };
+                return atomic_results(len, Err(error));
+            }
+
+            let grants = packable.into_iter().map(|(_, grant)| grant).collect::<Vec<_>>();
+            let outcome = self
+                .create_accreditations_to_attest(federation_id, grants)
+                .build_and_execute(self)
+                .await
+                .map(|_| ())
+                .map_err(|e| execution_error(MoveAbortMapper::new(), e));
+            return atomic_results(len, outcome);
+        }
+
+        let mut packable = packable.into_iter();
+        for chunk in chunks {
+            let (indices, chunk): (Vec<_>, Vec<_>) = packable.by_ref().take(chunk.len()).unzip();
+            let outcome = self
+                .create_accreditations_to_attest(federation_id, chunk.clone())
+                .build_and_execute(self)
+                .await
+                .map(|_| ())
+                .map_err(|e| execution_error(MoveAbortMapper::new(), e));
+
+            // The abort mapper only yields a transaction error for Move aborts, which revert the whole
+            // transaction. Any other failure may have happened after the grants were applied.
+            let retry = chunk.len() > 1 && matches!(outcome, Err(ClientError::Transaction(_)));
+            if !retry {
+                results.extend(shared_results(indices, outcome.map_err(Arc::new)));
+                continue;
+            }
+            for (index, (receiver, want_properties)) in indices.into_iter().zip(chunk) {
+                let result = self
+                    .create_accreditation_to_attest(federation_id, receiver, want_properties)
+                    .build_and_execute(self)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| Arc::new(execution_error(MoveAbortMapper::new(), e)));
+                results.push(BatchItemResult { index, result });
+            }
+        }
+
+        results.sort_by_key(|item| item.index);
+        results
+    }
+
+    /// Revokes several accreditations to attest and reports the outcome of every revocation.
+    ///
+    /// See [`HierarchiesClient::batch_create_accreditations_to_attest`] for the semantics of `policy`.
+    /// Calling this method confirms the revocations, see [`Destructive`].
+    pub async fn batch_revoke_accreditations_to_attest(
+        &self,
+        federation_id: ObjectID,
+        revocations: impl IntoIterator<Item = (ObjectID, ObjectID)>,
+        policy: PartialFailurePolicy,
+    ) -> Vec<BatchItemResult> {
+        let revocations: Vec<_> = revocations.into_iter().collect();
+        if revocations.is_empty() {
+            return vec![];
+        }
+
+        match policy {
+            PartialFailurePolicy::Atomic => {
+                let len = revocations.len();
+                let outcome = self
+                    .revoke_accreditations_to_attest(federation_id, revocations)
+                    .confirm_irreversible()
+                    .build_and_execute(self)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e));
+                atomic_results(len, outcome)
+            }
+            PartialFailurePolicy::ContinueAndReport => {
+                let mut results = Vec::with_capacity(revocations.len());
+                for (index, (entity_id, accreditation_id)) in revocations.into_iter().enumerate() {
+                    let result = self
+                        .revoke_accreditation_to_attest(federation_id, entity_id, accreditation_id)
+                        .build_and_execute(self)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| Arc::new(execution_error(MoveAbortMapper::new(), e)));
+                    results.push(BatchItemResult { index, result });
+                }
+                results
+            }
+        }
+    }
+
     /// Creates a new [`CreateAccreditation`] transaction builder.
+    ///
+    /// The receiver can be identified by object ID or IOTA DID, see [`SubjectId`].
     pub fn create_accreditation_to_accredit(
         &self,
         federation_id: ObjectID,
-        receiver: ObjectID,
+        receiver: impl Into<SubjectId>,
         properties: impl IntoIterator<Item = FederationProperty>,
     ) -> TransactionBuilder<CreateAccreditation> {
-        TransactionBuilder::new(CreateAccreditation::new(
+        TransactionBuilder::new(self.checked(CreateAccreditation::new(
             federation_id,
             receiver,
             properties.into_iter().collect(),
             self.sender_address(),
-        ))
+        )))
     }
 
     /// Creates a new [`RevokeAccreditationToAccredit`] transaction builder.
+    ///
+    /// The reason of the revocation can be recorded with
+    /// [`WithReason::with_reason`](crate::core::transactions::WithReason::with_reason).
     pub fn revoke_accreditation_to_accredit(
         &self,
         federation_id: ObjectID,
         user_id: ObjectID,
         permission_id: ObjectID,
     ) -> TransactionBuilder<RevokeAccreditationToAccredit> {
-        TransactionBuilder::new(RevokeAccreditationToAccredit::new(
+        TransactionBuilder::new(self.checked(RevokeAccreditationToAccredit::new(
             federation_id,
             user_id,
             permission_id,
             self.sender_address(),
+        )))
+    }
+
+    /// Creates a new [`CreateAttestation`] transaction builder.
+    ///
+    /// Issues an attestation of `properties` to `receiver`, valid within `timespan`. The sender
+    /// must be accredited to attest all properties. Verifiers check the attestation with
+    /// [`HierarchiesClientReadOnly::validate_attestation`].
+    pub fn create_attestation(
+        &self,
+        federation_id: ObjectID,
+        receiver: IotaAddress,
+        properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
+        timespan: Timespan,
+    ) -> TransactionBuilder<CreateAttestation> {
+        TransactionBuilder::new(CreateAttestation::new(
+            federation_id,
+            receiver,
+            properties.into_iter().collect(),
+            timespan,
         ))
     }
+
+    /// Creates a new [`CreateAttestation`] transaction builder attesting blinded `properties`.
+    ///
+    /// Only the digests of the values are stored on-chain, see
+    /// [`selective_disclosure`](crate::core::types::selective_disclosure). The returned disclosures
+    /// must be handed to `receiver`, who reveals individual values with a
+    /// [`DisclosureProof`](crate::core::types::selective_disclosure::DisclosureProof). The sender must be
+    /// accredited to attest the digests, e.g. with
+    /// [`disclosure_property`](crate::core::types::selective_disclosure::disclosure_property).
+    pub fn create_blinded_attestation(
+        &self,
+        federation_id: ObjectID,
+        receiver: IotaAddress,
+        properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
+        timespan: Timespan,
+    ) -> (TransactionBuilder<CreateAttestation>, Vec<Disclosure>) {
+        let (blinded, disclosures) = selective_disclosure::blind(properties);
+        (
+            self.create_attestation(federation_id, receiver, blinded, timespan),
+            disclosures,
+        )
+    }
+
+    /// Creates a new [`RevokeAttestation`] transaction builder.
+    ///
+    /// Only the issuer of the attestation and root authorities can revoke it.
+    pub fn revoke_attestation(
+        &self,
+        federation_id: ObjectID,
+        attestation_id: ObjectID,
+    ) -> TransactionBuilder<RevokeAttestation> {
+        TransactionBuilder::new(RevokeAttestation::new(federation_id, attestation_id))
+    }
 }
 
 impl<S> Deref for HierarchiesClient<S> {
@@ -0,0 +1,424 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Audit logs of the past changes of a federation and validation against past states.
+//!
+//! [`HierarchiesClientReadOnly::get_federation_history`] queries the events a federation emitted
+//! between two checkpoints and returns them in chronological order as [`AuditEntry`]s, telling
+//! who changed what and when.
+//!
+//! [`HierarchiesClientReadOnly::validate_property_at`] answers whether an attester was accredited
+//! at a past instant, e.g. when a credential was issued. It loads the version of the federation
+//! object that was current at that instant and evaluates it with [`offline`]. Past object versions
+//! are only served by nodes that keep the object history, pruning nodes may fail these queries.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let history = client.get_federation_history(federation_id, None, None).await?;
+//! for entry in history.entries() {
+//!     println!("{entry}");
+//! }
+//!
+//! let was_valid = client
+//!     .validate_property_at(federation_id, issuer, property_name, property_value, issued_at_ms)
+//!     .await?;
+//! ```
+
+use std::fmt;
+
+use iota_interaction::ident_str;
+use iota_interaction::rpc_types::{
+    CheckpointId, EventFilter, IotaObjectDataOptions, IotaTransactionBlockEffectsAPI,
+    IotaTransactionBlockResponseOptions, IotaTransactionBlockResponseQuery, TransactionFilter,
+};
+use iota_interaction::types::base_types::{IotaAddress, ObjectID, SequenceNumber};
+use iota_interaction::types::event::EventID;
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::client::events::rpc_error;
+use crate::client::{ClientError, HierarchiesClientReadOnly, decode_object_bcs};
+use crate::core::offline;
+use crate::core::types::events::{HierarchiesEvent, RevocationReasonEvent};
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::subject_id::SubjectId;
+use crate::core::types::{Federation, move_names};
+use crate::error::ObjectError;
+
+/// Number of events or transactions fetched per query while reconstructing a history.
+const HISTORY_PAGE_SIZE: usize = 50;
+
+/// A change of a federation, as recorded by one of its events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    /// The ID of the event
+    pub id: EventID,
+    /// The time of the checkpoint that included the event, if known
+    pub timestamp_ms: Option<u64>,
+    /// The sender of the transaction that emitted the event
+    pub sender: IotaAddress,
+    /// The decoded event
+    pub event: HierarchiesEvent,
+}
+
+impl AuditEntry {
+    /// Returns a short human-readable description of the change.
+    pub fn summary(&self) -> String {
+        self.event.summary()
+    }
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.timestamp_ms {
+            Some(timestamp_ms) => write!(f, "[{timestamp_ms}] ")?,
+            None => write!(f, "[unknown] ")?,
+        }
+        write!(f, "{}: {}", self.sender, self.summary())
+    }
+}
+
+/// The chronological audit log of a federation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationHistory {
+    /// The federation the log belongs to
+    pub federation_id: ObjectID,
+    /// The changes, oldest first
+    pub entries: Vec<AuditEntry>,
+}
+
+impl FederationHistory {
+    /// Returns the changes, oldest first.
+    pub fn entries(&self) -> &[AuditEntry] {
+        &self.entries
+    }
+
+    /// Returns the changes made by transactions of `sender`.
+    pub fn by_sender(&self, sender: IotaAddress) -> impl Iterator<Item = &AuditEntry> {
+        self.entries.iter().filter(move |entry| entry.sender == sender)
+    }
+
+    /// Returns the reason recorded with the revocation of `entry`, if any.
+    ///
+    /// The reason is emitted by the same transaction, right after the revocation event.
+    pub fn revocation_reason(&self, entry: &AuditEntry) -> Option<&RevocationReasonEvent> {
+        let position = self.entries.iter().position(|candidate| candidate.id == entry.id)?;
+        self.entries[position + 1..]
+            .iter()
+            .take_while(|next| next.id.tx_digest == entry.id.tx_digest)
+            .find_map(|next| match &next.event {
+                HierarchiesEvent::RevocationReason(reason) => Some(reason),
+                _ => None,
+            })
+    }
+
+    /// Returns the changes that mention `property_name`.
+    pub fn of_property<'a>(&'a self, property_name: &'a PropertyName) -> impl Iterator<Item = &'a AuditEntry> {
+        self.entries.iter().filter(move |entry| match &entry.event {
+            HierarchiesEvent::PropertyAdded(event) => &event.property.name == property_name,
+            HierarchiesEvent::PropertyRevoked(event) => &event.property_name == property_name,
+            HierarchiesEvent::PropertyCriticalityChanged(event) => &event.property_name == property_name,
+            HierarchiesEvent::PropertyDeprecated(event) => {
+                &event.property_name == property_name || event.replacement.as_ref() == Some(property_name)
+            }
+            HierarchiesEvent::PropertyMetadataSet(event) => &event.property_name == property_name,
+            HierarchiesEvent::PropertyTagsSet(event) => &event.property_name == property_name,
+            _ => false,
+        })
+    }
+}
+
+impl HierarchiesClientReadOnly {
+    /// Reconstructs the audit log of `federation_id` from its events.
+    ///
+    /// Only events of checkpoints between `from_checkpoint` and `to_checkpoint`, both inclusive,
+    /// are returned. Without bounds the log covers the whole history of the federation. As events
+    /// can't be queried by checkpoint, the bounds are applied through the checkpoint timestamps.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a checkpoint doesn't exist, or if the events can't be queried or decoded.
+    pub async fn get_federation_history(
+        &self,
+        federation_id: ObjectID,
+        from_checkpoint: Option<u64>,
+        to_checkpoint: Option<u64>,
+    ) -> Result<FederationHistory, ClientError> {
+        let from_ms = match from_checkpoint {
+            Some(checkpoint) => Some(self.checkpoint_timestamp_ms(checkpoint).await?),
+            None => None,
+        };
+        let to_ms = match to_checkpoint {
+            Some(checkpoint) => Some(self.checkpoint_timestamp_ms(checkpoint).await?),
+            None => None,
+        };
+
+        let filter = EventFilter::MoveEventModule {
+            package: self.package_id(),
+            module: ident_str!(move_names::MODULE_MAIN).to_owned(),
+        };
+
+        let package_history = self.package_history();
+
+        let mut entries = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .iota_client
+                .event_api()
+                .query_events(filter.clone(), cursor, Some(HISTORY_PAGE_SIZE), false)
+                .await
+                .map_err(rpc_error)?;
+
+            for event in page.data {
+                let timestamp_ms = event.timestamp_ms;
+                // Events are returned in ascending order, so the first later event ends the log
+                if timestamp_ms
+                    .zip(to_ms)
+                    .is_some_and(|(timestamp_ms, to_ms)| timestamp_ms > to_ms)
+                {
+                    return Ok(FederationHistory { federation_id, entries });
+                }
+                if timestamp_ms
+                    .zip(from_ms)
+                    .is_some_and(|(timestamp_ms, from_ms)| timestamp_ms < from_ms)
+                {
+                    continue;
+                }
+
+                let decoded = HierarchiesEvent::from_iota_event(&event, &package_history).map_err(|e| {
+                    ClientError::InvalidResponse {
+                        reason: format!("failed to decode {}: {e}", event.type_.name),
+                    }
+                })?;
+                if let Some(decoded) = decoded.filter(|decoded| decoded.federation_address() == federation_id) {
+                    entries.push(AuditEntry {
+                        id: event.id,
+                        timestamp_ms,
+                        sender: event.sender,
+                        event: decoded,
+                    });
+                }
+            }
+
+            if !page.has_next_page {
+                return Ok(FederationHistory { federation_id, entries });
+            }
+            cursor = page.next_cursor;
+        }
+    }
+
+    /// Retrieves the state of `federation_id` as of `timestamp_ms`.
+    ///
+    /// Returns the version of the federation object written by the last transaction executed at
+    /// or before `timestamp_ms`, or `None` if the federation didn't exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the transactions of the federation can't be queried, or if the node doesn't serve
+    /// the past version of the federation object.
+    pub async fn get_federation_at(
+        &self,
+        federation_id: ObjectID,
+        timestamp_ms: u64,
+    ) -> Result<Option<Federation>, ClientError> {
+        let Some(version) = self.federation_version_at(federation_id, timestamp_ms).await? else {
+            return Ok(None);
+        };
+
+        let object = self
+            .iota_client
+            .read_api()
+            .try_get_parsed_past_object(federation_id, version, IotaObjectDataOptions::bcs_lossless())
+            .await
+            .map_err(rpc_error)?
+            .into_object()
+            .map_err(|_| ObjectError::NotFound {
+                id: format!("{federation_id} at version {version}"),
+            })?;
+        let bytes = object
+            .bcs
+            .and_then(|bcs| bcs.try_into_move())
+            .ok_or_else(|| ObjectError::WrongType {
+                expected: "Move object".to_string(),
+                actual: "other".to_string(),
+            })?
+            .bcs_bytes;
+
+        Ok(Some(decode_object_bcs(&federation_id, &bytes)?))
+    }
+
+    /// Validates an attestation against the state of the federation as of `timestamp_ms`.
+    ///
+    /// Unlike [`validate_property`](Self::validate_property), accreditations and timespans are
+    /// evaluated at `timestamp_ms`, so an attester revoked since then still validates. Timespans
+    /// tolerate the clock skew of the client's [`ValidationConfig`](crate::client::ValidationConfig).
+    /// Returns false if the federation didn't exist at `timestamp_ms`.
+    pub async fn validate_property_at(
+        &self,
+        federation_id: ObjectID,
+        attester_id: impl Into<SubjectId>,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+        timestamp_ms: u64,
+    ) -> Result<bool, ClientError> {
+        let attester_id = self.resolve_subject(attester_id)?;
+        let Some(federation) = self.get_federation_at(federation_id, timestamp_ms).await? else {
+            return Ok(false);
+        };
+        let federation = self.validation_config().apply(federation);
+
+        Ok(offline::validate_property(
+            &federation,
+            &attester_id,
+            &property_name,
+            &property_value,
+            timestamp_ms,
+        ))
+    }
+
+    /// Returns the version of `federation_id` written by the last transaction at or before `timestamp_ms`.
+    async fn federation_version_at(
+        &self,
+        federation_id: ObjectID,
+        timestamp_ms: u64,
+    ) -> Result<Option<SequenceNumber>, ClientError> {
+        let query = IotaTransactionBlockResponseQuery::new(
+            Some(TransactionFilter::ChangedObject(federation_id)),
+            Some(IotaTransactionBlockResponseOptions::new().with_effects()),
+        );
+
+        let mut cursor = None;
+        loop {
+            // Newest first, so the first transaction at or before the instant wrote the version
+            let page = self
+                .iota_client
+                .read_api()
+                .query_transaction_blocks(query.clone(), cursor, Some(HISTORY_PAGE_SIZE), true)
+                .await
+                .map_err(rpc_error)?;
+
+            for response in page.data {
+                if response
+                    .timestamp_ms
+                    .is_none_or(|executed_at_ms| executed_at_ms > timestamp_ms)
+                {
+                    continue;
+                }
+                let version = response.effects.and_then(|effects| {
+                    effects
+                        .created()
+                        .iter()
+                        .chain(effects.mutated().iter())
+                        .find(|object| object.reference.object_id == federation_id)
+                        .map(|object| object.reference.version)
+                });
+                if version.is_some() {
+                    return Ok(version);
+                }
+            }
+
+            if !page.has_next_page {
+                return Ok(None);
+            }
+            cursor = page.next_cursor;
+        }
+    }
+
+    async fn checkpoint_timestamp_ms(&self, checkpoint: u64) -> Result<u64, ClientError> {
+        let checkpoint = self
+            .iota_client
+            .read_api()
+            .get_checkpoint(CheckpointId::SequenceNumber(checkpoint))
+            .await
+            .map_err(rpc_error)?;
+
+        Ok(checkpoint.timestamp_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::digests::TransactionDigest;
+
+    use super::*;
+    use crate::core::types::events::{PropertyRevokedEvent, RootAuthorityAddedEvent, RootAuthorityRevokedEvent};
+
+    fn entry(event: HierarchiesEvent) -> AuditEntry {
+        AuditEntry {
+            id: EventID {
+                tx_digest: TransactionDigest::ZERO,
+                event_seq: 0,
+            },
+            timestamp_ms: Some(10),
+            sender: IotaAddress::ZERO,
+            event,
+        }
+    }
+
+    #[test]
+    fn test_audit_entry_display() {
+        let revoked = entry(HierarchiesEvent::PropertyRevoked(PropertyRevokedEvent {
+            federation_address: ObjectID::ZERO,
+            property_name: PropertyName::new(["kyc", "level"]),
+            valid_to_ms: 20,
+            critical: false,
+        }));
+
+        assert_eq!(revoked.summary(), "revoked property kyc.level at 20");
+        assert_eq!(
+            revoked.to_string(),
+            format!("[10] {}: revoked property kyc.level at 20", IotaAddress::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_history_of_property() {
+        let property_name = PropertyName::from("kyc");
+        let history = FederationHistory {
+            federation_id: ObjectID::ZERO,
+            entries: vec![
+                entry(HierarchiesEvent::RootAuthorityAdded(RootAuthorityAddedEvent {
+                    federation_address: ObjectID::ZERO,
+                    account_id: ObjectID::ZERO,
+                })),
+                entry(HierarchiesEvent::PropertyRevoked(PropertyRevokedEvent {
+                    federation_address: ObjectID::ZERO,
+                    property_name: property_name.clone(),
+                    valid_to_ms: 20,
+                    critical: false,
+                })),
+            ],
+        };
+
+        assert_eq!(history.of_property(&property_name).count(), 1);
+        assert_eq!(history.by_sender(IotaAddress::ZERO).count(), 2);
+    }
+
+    #[test]
+    fn test_revocation_reason() {
+        let reason = RevocationReasonEvent {
+            federation_address: ObjectID::ZERO,
+            reason_code: 2,
+            annotation: "misconduct".to_string(),
+            revoked_by: ObjectID::ZERO,
+        };
+        let revoked = entry(HierarchiesEvent::RootAuthorityRevoked(RootAuthorityRevokedEvent {
+            federation_address: ObjectID::ZERO,
+            account_id: ObjectID::ZERO,
+        }));
+        let mut annotated = entry(HierarchiesEvent::RevocationReason(reason.clone()));
+        annotated.id.event_seq = 1;
+        let mut unannotated = revoked.clone();
+        unannotated.id.tx_digest = TransactionDigest::random();
+        let history = FederationHistory {
+            federation_id: ObjectID::ZERO,
+            entries: vec![revoked.clone(), annotated.clone(), unannotated.clone()],
+        };
+
+        assert_eq!(history.revocation_reason(&revoked), Some(&reason));
+        assert_eq!(history.revocation_reason(&unannotated), None);
+        assert_eq!(annotated.summary(), "gave reason 2 for the revocation: misconduct");
+    }
+}
@@ -10,20 +10,55 @@
 //!   The client is represented by the [`HierarchiesClient`] struct.
 //! - ReadOnlyClient: A client that can only perform off-chain operations. It doesn't require a signer with a private
 //!   key. The client is represented by the [`HierarchiesClientReadOnly`] struct.
+mod api;
+mod batch;
+#[cfg(not(target_arch = "wasm32"))]
+mod config;
+#[cfg(not(target_arch = "wasm32"))]
+mod connection;
 pub mod error;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod events;
+mod federation_builder;
 mod full_client;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod history;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod monitor;
+mod query_batcher;
 mod read_only;
+mod retry;
+mod scoped;
+mod sequencer;
+#[cfg(feature = "gas-station")]
+mod sponsor;
+mod validation_config;
 
+pub use api::*;
+pub use batch::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use config::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use connection::*;
 pub use error::ClientError;
+pub use federation_builder::*;
 pub use full_client::*;
 use iota_interaction::IotaClientTrait;
 use iota_interaction::rpc_types::{IotaData, IotaObjectDataOptions};
 use iota_interaction::types::base_types::ObjectID;
 use product_common::core_client::CoreClientReadOnly;
 use product_common::network_name::NetworkName;
+pub use query_batcher::*;
 pub use read_only::*;
+pub use retry::*;
+pub use scoped::*;
+pub use sequencer::*;
 use serde::de::DeserializeOwned;
+#[cfg(feature = "gas-station")]
+pub use sponsor::*;
+pub use validation_config::*;
 
+use crate::core::diagnostics;
 use crate::error::{NetworkError, ObjectError};
 use crate::iota_interaction_adapter::IotaClientAdapter;
 
@@ -40,11 +75,34 @@ async fn network_id(iota_client: &IotaClientAdapter) -> Result<NetworkName, Netw
 /// Get an object by its ID and deserialize it using BCS.
 ///
 /// This function is used to retrieve an object from the IOTA network and deserialize it using BCS.
+/// If the object doesn't match the layout of `T`, e.g. after a package upgrade, the returned
+/// [`ObjectError::Decoding`] points to the first field that could not be decoded.
 pub async fn get_object_ref_by_id_with_bcs<T: DeserializeOwned>(
     client: &impl CoreClientReadOnly,
     object_id: &ObjectID,
 ) -> Result<T, ObjectError> {
-    let hierarchies_client = client
+    let bytes = get_object_bcs_bytes(client, object_id).await?;
+
+    decode_object_bcs(object_id, &bytes)
+}
+
+/// Deserializes the BCS content of the object `object_id`, diagnosing layout mismatches.
+pub(crate) fn decode_object_bcs<T: DeserializeOwned>(object_id: &ObjectID, bytes: &[u8]) -> Result<T, ObjectError> {
+    bcs::from_bytes(bytes).map_err(|err| match diagnostics::decode::<T>(bytes) {
+        Err(diagnostic) => ObjectError::Decoding {
+            id: object_id.to_string(),
+            diagnostic: Box::new(diagnostic),
+        },
+        Ok(_) => ObjectError::RetrievalFailed { source: err.into() },
+    })
+}
+
+/// Get the raw BCS content of a Move object by its ID.
+pub async fn get_object_bcs_bytes(
+    client: &impl CoreClientReadOnly,
+    object_id: &ObjectID,
+) -> Result<Vec<u8>, ObjectError> {
+    let object = client
         .client_adapter()
         .read_api()
         .get_object_with_options(*object_id, IotaObjectDataOptions::bcs_lossless())
@@ -64,9 +122,7 @@ pub async fn get_object_ref_by_id_with_bcs<T: DeserializeOwned>(
         .ok_or_else(|| ObjectError::WrongType {
             expected: "Move object".to_string(),
             actual: "other".to_string(),
-        })?
-        .deserialize()
-        .map_err(|err| ObjectError::RetrievalFailed { source: err.into() })?;
+        })?;
 
-    Ok(hierarchies_client)
+    Ok(object.bcs_bytes)
 }
@@ -0,0 +1,361 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Watches the accreditations of a set of entities for expiry and revocation.
+//!
+//! An [`AccreditationMonitor`] periodically reads a federation and compares the accreditations of
+//! the watched entities with the previous check. Registered callbacks are invoked once per
+//! accredited property and event:
+//! - [`AccreditationMonitor::on_expiring_within`] when a property expires within a duration,
+//! - [`AccreditationMonitor::on_expired`] when a property has expired,
+//! - [`AccreditationMonitor::on_revoked`] when an accreditation was revoked since the last check.
+//!
+//! Renewing a property, i.e. granting it again with a later end, re-arms its notifications.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! client
+//!     .monitor(federation_id, [supplier_id])
+//!     .with_interval(Duration::from_secs(60))
+//!     .on_expiring_within(Duration::from_secs(30 * 24 * 3600), |alert| println!("renew soon: {alert:?}"))
+//!     .on_revoked(|alert| println!("revoked: {alert:?}"))
+//!     .run()
+//!     .await?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use iota_interaction::types::base_types::ObjectID;
+
+use crate::client::{ClientError, HierarchiesClientReadOnly};
+use crate::core::types::Federation;
+use crate::core::types::accreditation_query::AccreditationScope;
+use crate::core::types::property_name::PropertyName;
+
+/// Default interval between two checks of an [`AccreditationMonitor`].
+pub const DEFAULT_MONITOR_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A property granted to a watched entity by an accreditation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WatchedGrant {
+    /// The entity holding the accreditation.
+    pub entity_id: ObjectID,
+    /// The scope the accreditation was granted for.
+    pub scope: AccreditationScope,
+    /// The ID of the accreditation.
+    pub accreditation_id: ObjectID,
+    /// The accredited property.
+    pub property_name: PropertyName,
+}
+
+/// A change of a [`WatchedGrant`] detected by an [`AccreditationMonitor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonitorAlert {
+    /// The property expires within the `within` duration of a callback.
+    Expiring {
+        grant: WatchedGrant,
+        expires_at_ms: u64,
+        within: Duration,
+    },
+    /// The property has expired.
+    Expired { grant: WatchedGrant, expired_at_ms: u64 },
+    /// The accreditation holding the property was revoked since the last check.
+    Revoked { grant: WatchedGrant },
+}
+
+impl MonitorAlert {
+    /// The grant the alert is about.
+    pub fn grant(&self) -> &WatchedGrant {
+        match self {
+            Self::Expiring { grant, .. } | Self::Expired { grant, .. } | Self::Revoked { grant } => grant,
+        }
+    }
+}
+
+/// A callback invoked with the alerts of an [`AccreditationMonitor`].
+pub type AlertCallback = Box<dyn FnMut(&MonitorAlert) + Send>;
+
+/// The grants seen by the previous check and the alerts already raised for them.
+#[derive(Debug, Default)]
+struct MonitorState {
+    /// The end of the validity of every known grant
+    known: HashMap<WatchedGrant, Option<u64>>,
+    expiring_raised: HashSet<(WatchedGrant, Duration)>,
+    expired_raised: HashSet<WatchedGrant>,
+    initialized: bool,
+}
+
+impl MonitorState {
+    /// Compares `grants` with the previous check and returns the new alerts.
+    fn check(
+        &mut self,
+        grants: HashMap<WatchedGrant, Option<u64>>,
+        now_ms: u64,
+        thresholds: &[Duration],
+    ) -> Vec<MonitorAlert> {
+        let mut alerts = vec![];
+
+        // Revocations can only be told apart from unknown grants after the first check
+        if self.initialized {
+            alerts.extend(
+                self.known
+                    .keys()
+                    .filter(|grant| !grants.contains_key(*grant))
+                    .map(|grant| MonitorAlert::Revoked { grant: grant.clone() }),
+            );
+        }
+        self.initialized = true;
+
+        for (grant, valid_until_ms) in &grants {
+            if self.known.get(grant).is_some_and(|known| known != valid_until_ms) {
+                // The grant was renewed, so its notifications are re-armed
+                self.expired_raised.remove(grant);
+                self.expiring_raised.retain(|(raised, _)| raised != grant);
+            }
+            let Some(valid_until_ms) = *valid_until_ms else {
+                continue;
+            };
+
+            if valid_until_ms <= now_ms {
+                if self.expired_raised.insert(grant.clone()) {
+                    alerts.push(MonitorAlert::Expired {
+                        grant: grant.clone(),
+                        expired_at_ms: valid_until_ms,
+                    });
+                }
+                continue;
+            }
+
+            let remaining_ms = valid_until_ms - now_ms;
+            for within in thresholds {
+                if u128::from(remaining_ms) <= within.as_millis()
+                    && self.expiring_raised.insert((grant.clone(), *within))
+                {
+                    alerts.push(MonitorAlert::Expiring {
+                        grant: grant.clone(),
+                        expires_at_ms: valid_until_ms,
+                        within: *within,
+                    });
+                }
+            }
+        }
+
+        // Forget the notifications of revoked grants
+        self.expired_raised.retain(|grant| grants.contains_key(grant));
+        self.expiring_raised.retain(|(grant, _)| grants.contains_key(grant));
+        self.known = grants;
+
+        alerts
+    }
+}
+
+/// Collects the grants of `entities` held in `federation`, with the end of their validity.
+fn collect_grants(federation: &Federation, entities: &[ObjectID]) -> HashMap<WatchedGrant, Option<u64>> {
+    let governance = &federation.governance;
+    let scopes = [
+        (AccreditationScope::Attest, &governance.accreditations_to_attest),
+        (AccreditationScope::Accredit, &governance.accreditations_to_accredit),
+    ];
+
+    let mut grants = HashMap::new();
+    for (scope, by_entity) in scopes {
+        for entity_id in entities {
+            let Some(accreditations) = by_entity.get(entity_id) else {
+                continue;
+            };
+            for accreditation in accreditations.iter() {
+                for (property_name, property) in &accreditation.properties {
+                    let grant = WatchedGrant {
+                        entity_id: *entity_id,
+                        scope,
+                        accreditation_id: *accreditation.id.object_id(),
+                        property_name: property_name.clone(),
+                    };
+                    grants.insert(grant, property.timespan.valid_until_ms);
+                }
+            }
+        }
+    }
+
+    grants
+}
+
+/// Watches the accreditations of a set of entities, see the [module docs](self).
+pub struct AccreditationMonitor {
+    client: HierarchiesClientReadOnly,
+    federation_id: ObjectID,
+    entities: Vec<ObjectID>,
+    interval: Duration,
+    on_expiring: Vec<(Duration, AlertCallback)>,
+    on_expired: Vec<AlertCallback>,
+    on_revoked: Vec<AlertCallback>,
+    state: MonitorState,
+}
+
+impl AccreditationMonitor {
+    /// Creates a monitor of the accreditations `entities` hold in `federation_id`.
+    pub fn new(
+        client: HierarchiesClientReadOnly,
+        federation_id: ObjectID,
+        entities: impl IntoIterator<Item = ObjectID>,
+    ) -> Self {
+        Self {
+            client,
+            federation_id,
+            entities: entities.into_iter().collect(),
+            interval: DEFAULT_MONITOR_INTERVAL,
+            on_expiring: vec![],
+            on_expired: vec![],
+            on_revoked: vec![],
+            state: MonitorState::default(),
+        }
+    }
+
+    /// Sets the interval between two checks of [`AccreditationMonitor::run`].
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Invokes `callback` once for every property that expires within `within`.
+    pub fn on_expiring_within(
+        mut self,
+        within: Duration,
+        callback: impl FnMut(&MonitorAlert) + Send + 'static,
+    ) -> Self {
+        self.on_expiring.push((within, Box::new(callback)));
+        self
+    }
+
+    /// Invokes `callback` once for every property that has expired.
+    pub fn on_expired(mut self, callback: impl FnMut(&MonitorAlert) + Send + 'static) -> Self {
+        self.on_expired.push(Box::new(callback));
+        self
+    }
+
+    /// Invokes `callback` for every property of an accreditation revoked since the last check.
+    pub fn on_revoked(mut self, callback: impl FnMut(&MonitorAlert) + Send + 'static) -> Self {
+        self.on_revoked.push(Box::new(callback));
+        self
+    }
+
+    /// Checks the accreditations once, invokes the callbacks and returns the raised alerts.
+    ///
+    /// Validity is evaluated against the system time.
+    pub async fn check(&mut self) -> Result<Vec<MonitorAlert>, ClientError> {
+        let federation = self.client.get_federation_by_id(self.federation_id).await?;
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+
+        let thresholds: Vec<Duration> = self.on_expiring.iter().map(|(within, _)| *within).collect();
+        let alerts = self
+            .state
+            .check(collect_grants(&federation, &self.entities), now_ms, &thresholds);
+
+        for alert in &alerts {
+            match alert {
+                MonitorAlert::Expiring { within, .. } => self
+                    .on_expiring
+                    .iter_mut()
+                    .filter(|(threshold, _)| threshold == within)
+                    .for_each(|(_, callback)| callback(alert)),
+                MonitorAlert::Expired { .. } => self.on_expired.iter_mut().for_each(|callback| callback(alert)),
+                MonitorAlert::Revoked { .. } => self.on_revoked.iter_mut().for_each(|callback| callback(alert)),
+            }
+        }
+
+        Ok(alerts)
+    }
+
+    /// Checks the accreditations every interval until a check fails.
+    pub async fn run(mut self) -> Result<(), ClientError> {
+        loop {
+            self.check().await?;
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
+
+impl HierarchiesClientReadOnly {
+    /// Creates an [`AccreditationMonitor`] of the accreditations `entities` hold in `federation_id`.
+    pub fn monitor(
+        &self,
+        federation_id: ObjectID,
+        entities: impl IntoIterator<Item = ObjectID>,
+    ) -> AccreditationMonitor {
+        AccreditationMonitor::new(self.clone(), federation_id, entities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(24 * 3600);
+    const DAY_MS: u64 = 24 * 3600 * 1000;
+
+    fn grant(name: &str) -> WatchedGrant {
+        WatchedGrant {
+            entity_id: ObjectID::ZERO,
+            scope: AccreditationScope::Attest,
+            accreditation_id: ObjectID::ZERO,
+            property_name: PropertyName::from(name),
+        }
+    }
+
+    #[test]
+    fn test_expiring_and_expired_are_raised_once() {
+        let mut state = MonitorState::default();
+        let grants = HashMap::from([(grant("organic"), Some(10 * DAY_MS)), (grant("iso"), None)]);
+        let thresholds = [30 * DAY, 7 * DAY];
+
+        let alerts = state.check(grants.clone(), 0, &thresholds);
+        assert!(matches!(alerts.as_slice(), [MonitorAlert::Expiring { within, .. }] if *within == 30 * DAY));
+        assert!(state.check(grants.clone(), DAY_MS, &thresholds).is_empty());
+
+        let alerts = state.check(grants.clone(), 4 * DAY_MS, &thresholds);
+        assert!(matches!(alerts.as_slice(), [MonitorAlert::Expiring { within, .. }] if *within == 7 * DAY));
+
+        let alerts = state.check(grants.clone(), 10 * DAY_MS, &thresholds);
+        assert!(
+            matches!(alerts.as_slice(), [MonitorAlert::Expired { expired_at_ms, .. }] if *expired_at_ms == 10 * DAY_MS)
+        );
+        assert!(state.check(grants, 11 * DAY_MS, &thresholds).is_empty());
+    }
+
+    #[test]
+    fn test_renewal_rearms_notifications() {
+        let mut state = MonitorState::default();
+        let thresholds = [DAY];
+
+        let alerts = state.check(HashMap::from([(grant("organic"), Some(DAY_MS))]), 0, &thresholds);
+        assert_eq!(alerts.len(), 1);
+
+        let renewed = HashMap::from([(grant("organic"), Some(3 * DAY_MS))]);
+        assert!(state.check(renewed.clone(), DAY_MS, &thresholds).is_empty());
+        assert_eq!(state.check(renewed, 2 * DAY_MS, &thresholds).len(), 1);
+    }
+
+    #[test]
+    fn test_revocations_are_raised_after_the_first_check() {
+        let mut state = MonitorState::default();
+
+        assert!(state.check(HashMap::new(), 0, &[]).is_empty());
+        assert!(
+            state
+                .check(HashMap::from([(grant("organic"), None)]), 0, &[])
+                .is_empty()
+        );
+
+        let alerts = state.check(HashMap::new(), 0, &[]);
+        assert_eq!(
+            alerts,
+            vec![MonitorAlert::Revoked {
+                grant: grant("organic")
+            }]
+        );
+    }
+}
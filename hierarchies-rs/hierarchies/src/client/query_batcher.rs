@@ -0,0 +1,209 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Query Batching
+//!
+//! Every read query of [`HierarchiesClientReadOnly`] is a dev-inspect round-trip, so a dashboard
+//! issuing twenty queries per page waits for twenty RPC calls. A [`QueryBatcher`] collects
+//! independent queries and runs them as the commands of a single read-only transaction, then
+//! hands out the result of each query through the [`QueryHandle`] returned when it was added.
+//!
+//! ```rust,ignore
+//! let mut batch = client.query_batcher();
+//! let is_attester = batch.is_attester(federation_id, entity_id);
+//! let properties = batch.get_properties(federation_id);
+//!
+//! let results = batch.execute().await?;
+//! if results.get(is_attester)? {
+//!     println!("{:?}", results.get(properties)?);
+//! }
+//! ```
+
+use std::marker::PhantomData;
+
+use iota_interaction::types::base_types::ObjectID;
+use serde::de::DeserializeOwned;
+
+use crate::client::error::ClientError;
+use crate::client::read_only::{HierarchiesClientReadOnly, decode_return_value};
+use crate::core::operations::{BatchedQuery, HierarchiesImpl, HierarchiesOperations};
+use crate::core::types::Accreditations;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::subject_id::SubjectId;
+
+/// Collects read queries to run them in a single read-only transaction.
+///
+/// Created with [`HierarchiesClientReadOnly::query_batcher`].
+pub struct QueryBatcher<'c> {
+    client: &'c HierarchiesClientReadOnly,
+    queries: Vec<BatchedQuery>,
+    /// The first subject that couldn't be resolved, reported by [`QueryBatcher::execute`]
+    invalid_subject: Option<ClientError>,
+}
+
+impl<'c> QueryBatcher<'c> {
+    pub(crate) fn new(client: &'c HierarchiesClientReadOnly) -> Self {
+        Self {
+            client,
+            queries: Vec::new(),
+            invalid_subject: None,
+        }
+    }
+
+    /// Checks if `user_id` has accreditations to attest, see [`HierarchiesClientReadOnly::is_attester`].
+    pub fn is_attester(&mut self, federation_id: ObjectID, user_id: impl Into<SubjectId>) -> QueryHandle<bool> {
+        let user_id = self.resolve_subject(user_id);
+        self.push(BatchedQuery::IsAttester { federation_id, user_id })
+    }
+
+    /// Checks if `user_id` has accreditations to accredit, see [`HierarchiesClientReadOnly::is_accreditor`].
+    pub fn is_accreditor(&mut self, federation_id: ObjectID, user_id: impl Into<SubjectId>) -> QueryHandle<bool> {
+        let user_id = self.resolve_subject(user_id);
+        self.push(BatchedQuery::IsAccreditor { federation_id, user_id })
+    }
+
+    /// Checks if `user_id` is a root authority, see [`HierarchiesClientReadOnly::is_root_authority`].
+    pub fn is_root_authority(&mut self, federation_id: ObjectID, user_id: ObjectID) -> QueryHandle<bool> {
+        self.push(BatchedQuery::IsRootAuthority { federation_id, user_id })
+    }
+
+    /// Retrieves the accreditations to attest of `user_id`, see
+    /// [`HierarchiesClientReadOnly::get_accreditations_to_attest`].
+    pub fn get_accreditations_to_attest(
+        &mut self,
+        federation_id: ObjectID,
+        user_id: impl Into<SubjectId>,
+    ) -> QueryHandle<Accreditations> {
+        let user_id = self.resolve_subject(user_id);
+        self.push(BatchedQuery::GetAccreditationsToAttest { federation_id, user_id })
+    }
+
+    /// Retrieves the accreditations to accredit of `user_id`, see
+    /// [`HierarchiesClientReadOnly::get_accreditations_to_accredit`].
+    pub fn get_accreditations_to_accredit(
+        &mut self,
+        federation_id: ObjectID,
+        user_id: impl Into<SubjectId>,
+    ) -> QueryHandle<Accreditations> {
+        let user_id = self.resolve_subject(user_id);
+        self.push(BatchedQuery::GetAccreditationsToAccredit { federation_id, user_id })
+    }
+
+    /// Retrieves the names of all properties, see [`HierarchiesClientReadOnly::get_properties`].
+    pub fn get_properties(&mut self, federation_id: ObjectID) -> QueryHandle<Vec<PropertyName>> {
+        self.push(BatchedQuery::GetProperties { federation_id })
+    }
+
+    /// Checks if `property_name` is registered, see [`HierarchiesClientReadOnly::is_property_in_federation`].
+    pub fn is_property_in_federation(
+        &mut self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    ) -> QueryHandle<bool> {
+        self.push(BatchedQuery::IsPropertyInFederation {
+            federation_id,
+            property_name,
+        })
+    }
+
+    /// Returns the number of collected queries.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Runs the collected queries in a single read-only transaction.
+    ///
+    /// The transaction is retried according to the [`RetryPolicy`](crate::client::RetryPolicy)
+    /// of the client. An empty batch returns without a network call.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a subject couldn't be resolved, or if any query fails, e.g. because a federation
+    /// doesn't exist. Queries aren't isolated from each other, so one failing query fails the batch.
+    pub async fn execute(self) -> Result<BatchResults, ClientError> {
+        if let Some(error) = self.invalid_subject {
+            return Err(error);
+        }
+        if self.queries.is_empty() {
+            return Ok(BatchResults {
+                return_values: Vec::new(),
+            });
+        }
+
+        let return_values = self
+            .client
+            .retry_policy()
+            .run(|| async {
+                let tx = HierarchiesImpl::batched_queries(&self.queries, self.client).await?;
+                self.client.execute_read_only_transaction_raw(tx).await
+            })
+            .await?;
+
+        if return_values.len() != self.queries.len() {
+            return Err(ClientError::InvalidResponse {
+                reason: format!(
+                    "expected {} execution results, got {}",
+                    self.queries.len(),
+                    return_values.len()
+                ),
+            });
+        }
+
+        Ok(BatchResults { return_values })
+    }
+
+    fn push<T>(&mut self, query: BatchedQuery) -> QueryHandle<T> {
+        self.queries.push(query);
+        QueryHandle {
+            index: self.queries.len() - 1,
+            _result: PhantomData,
+        }
+    }
+
+    fn resolve_subject(&mut self, subject: impl Into<SubjectId>) -> ObjectID {
+        self.client.resolve_subject(subject).unwrap_or_else(|error| {
+            self.invalid_subject.get_or_insert(error);
+            ObjectID::ZERO
+        })
+    }
+}
+
+/// Refers to the result of a query added to a [`QueryBatcher`].
+///
+/// A handle is only meaningful for the [`BatchResults`] of the batch it was returned by.
+#[derive(Debug)]
+pub struct QueryHandle<T> {
+    index: usize,
+    _result: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for QueryHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for QueryHandle<T> {}
+
+/// The results of the queries of a [`QueryBatcher`].
+#[derive(Debug, Clone)]
+pub struct BatchResults {
+    return_values: Vec<Option<Vec<u8>>>,
+}
+
+impl BatchResults {
+    /// Returns the result of the query referred to by `handle`.
+    pub fn get<T: DeserializeOwned>(&self, handle: QueryHandle<T>) -> Result<T, ClientError> {
+        let bytes = self
+            .return_values
+            .get(handle.index)
+            .ok_or_else(|| ClientError::InvalidInput {
+                details: format!("query {} is not part of the batch", handle.index),
+            })?;
+        decode_return_value(bytes.as_deref())
+    }
+}
@@ -6,30 +6,70 @@
 //! This client provides methods to query the state and metadata of Hierarchies objects
 //! on the IOTA network without requiring signing capabilities.
 
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::ops::Deref;
+use std::sync::Arc;
 
+#[cfg(not(target_arch = "wasm32"))]
+use futures::stream::{self, Stream, TryStreamExt};
 #[cfg(not(target_arch = "wasm32"))]
 use iota_interaction::IotaClient;
-use iota_interaction::IotaClientTrait;
-use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::rpc_types::{IotaObjectDataFilter, IotaObjectDataOptions, IotaObjectResponseQuery};
+use iota_interaction::types::IOTA_CLOCK_OBJECT_ID;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID, TypeTag};
+use iota_interaction::types::digests::ObjectDigest;
+use iota_interaction::types::id::UID;
 use iota_interaction::types::transaction::{ProgrammableTransaction, TransactionKind};
+use iota_interaction::{IotaClientTrait, MoveType};
 #[cfg(target_arch = "wasm32")]
 use iota_interaction_ts::bindings::WasmIotaClient;
 use product_common::core_client::CoreClientReadOnly;
 use product_common::network_name::NetworkName;
 use product_common::package_registry::Env;
+use serde::Deserialize;
 use serde::de::DeserializeOwned;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::client::ConnectionOptions;
+use crate::client::batch::{BatchItemResult, PartialFailurePolicy, shared_results};
 use crate::client::error::ClientError;
-use crate::client::{get_object_ref_by_id_with_bcs, network_id};
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::client::query_batcher::QueryBatcher;
+use crate::client::retry::RetryPolicy;
+use crate::client::validation_config::ValidationConfig;
+use crate::client::{decode_object_bcs, get_object_bcs_bytes, get_object_ref_by_id_with_bcs, network_id};
+use crate::compat::{self, FeatureFlag};
+use crate::core::diagnostics::{self, PartialDecode};
+use crate::core::error::OperationError;
+use crate::core::offline::{self, ValidationReport};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::{FederationRef, PrefetchedInputs};
+use crate::core::transactions::TransactionError;
+use crate::core::types::accreditation_query::{
+    AccreditationFilter, AccreditationMatch, AccreditationPage, AccreditationQuery, AccreditationScope,
+};
+use crate::core::types::attestation::Attestation;
+use crate::core::types::credential_anchor::CredentialHash;
+use crate::core::types::federation_set::{FederationSet, FederationSetValidation};
+use crate::core::types::grant_policy::GrantPolicy;
+use crate::core::types::property::{FederationProperty, PropertyMetadata, PropertyPage};
 use crate::core::types::property_name::PropertyName;
-use crate::core::types::property_value::PropertyValue;
-use crate::core::types::{Accreditations, Federation};
-use crate::error::ConfigError;
+use crate::core::types::property_value::{PropertyValue, ValueSetMatch};
+use crate::core::types::subject_id::SubjectId;
+use crate::core::types::trust_chain::{LinkedTrustChain, TrustChain};
+use crate::core::types::{
+    AccreditCap, Accreditations, CapabilityKind, Federation, FederationMetadata, FederationStats, OwnedCapability,
+    RootAuthorityCap,
+};
+use crate::error::{ConfigError, NetworkError, ObjectError};
 use crate::iota_interaction_adapter::IotaClientAdapter;
 use crate::package;
 
+/// Number of owned objects fetched per query while listing capabilities.
+const CAPABILITY_PAGE_SIZE: usize = 50;
+/// Maximum number of items returned by a paged on-chain view.
+const MAX_PAGE_SIZE: u64 = 100;
+
 /// A read-only client for the Hierarchies.
 ///
 /// This client is used for communicating with the Hierarchies in a read-only manner.
@@ -39,12 +79,21 @@ use crate::package;
 pub struct HierarchiesClientReadOnly {
     /// The underlying IOTA client adapter used for communication.
     client: IotaClientAdapter,
+    /// The native IOTA client, used for event subscriptions.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(super) iota_client: IotaClient,
     /// The [`ObjectID`] of the deployed Hierarchies package (smart contract).
     /// All interactions go through this package ID.
     hierarchies_package_id: ObjectID,
     /// The name of the network this client is connected to (e.g., "mainnet", "testnet").
     network_name: NetworkName,
     chain_id: String,
+    /// The retry behaviour of queries.
+    retry_policy: RetryPolicy,
+    /// The tolerance of validations evaluated by the client.
+    validation_config: ValidationConfig,
+    /// The schema version of the package, see [`compat`].
+    schema_version: u64,
 }
 
 impl Deref for HierarchiesClientReadOnly {
@@ -74,6 +123,35 @@ impl HierarchiesClientReadOnly {
         &self.chain_id
     }
 
+    /// Returns the [`RetryPolicy`] of the client.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Sets the [`RetryPolicy`] applied to queries and to the operations of the
+    /// [`HierarchiesApi`](crate::client::HierarchiesApi).
+    ///
+    /// Defaults to [`RetryPolicy::default`]; pass [`RetryPolicy::none`] to disable retries.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the [`ValidationConfig`] of the client.
+    pub fn validation_config(&self) -> &ValidationConfig {
+        &self.validation_config
+    }
+
+    /// Sets the [`ValidationConfig`] applied to point-in-time validations, like
+    /// [`validate_property_at`](Self::validate_property_at).
+    ///
+    /// Validations at the network clock, like [`validate_property`](Self::validate_property) and
+    /// [`validate_properties_report`](Self::validate_properties_report), are not affected.
+    pub fn with_validation_config(mut self, validation_config: ValidationConfig) -> Self {
+        self.validation_config = validation_config;
+        self
+    }
+
     /// Attempts to create a new [`HierarchiesClientReadOnly`] from a given IOTA client.
     ///
     /// # Failures
@@ -81,6 +159,10 @@ impl HierarchiesClientReadOnly {
     /// network for which the Hierarchies package ID is not known in the internal
     /// package registry.
     ///
+    /// A package of an older schema than this SDK is accepted. Operations relying on a feature the
+    /// package lacks fail with [`ConfigError::IncompatibleSchema`], see
+    /// [`supports`](Self::supports).
+    ///
     /// # Arguments
     ///
     /// * `iota_client`: The IOTA client instance to use for communication. This can be either a native `IotaClient` or
@@ -94,16 +176,28 @@ impl HierarchiesClientReadOnly {
         #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
         #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
     ) -> Result<Self, ClientError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let native_client = iota_client.clone();
         let client = IotaClientAdapter::new(iota_client);
         let network = network_id(&client).await?;
-        Self::new_internal(client, network).await
+        Self::new_internal(
+            client,
+            #[cfg(not(target_arch = "wasm32"))]
+            native_client,
+            network,
+        )
+        .await
     }
 
     /// Internal helper function to create a new [`HierarchiesClientReadOnly`].
     ///
     /// This function looks up the Hierarchies package ID based on the provided network name
     /// using the internal package registry.
-    async fn new_internal(iota_client: IotaClientAdapter, network: NetworkName) -> Result<Self, ClientError> {
+    async fn new_internal(
+        iota_client: IotaClientAdapter,
+        #[cfg(not(target_arch = "wasm32"))] native_client: IotaClient,
+        network: NetworkName,
+    ) -> Result<Self, ClientError> {
         let chain_id = network.as_ref().to_string();
         let (network, hierarchies_pkg_id) = {
             let package_registry = package::hierarchies_package_registry().await;
@@ -124,12 +218,69 @@ impl HierarchiesClientReadOnly {
 
             (network, package_id)
         };
-        Ok(HierarchiesClientReadOnly {
+        let mut client = HierarchiesClientReadOnly {
             client: iota_client,
+            #[cfg(not(target_arch = "wasm32"))]
+            iota_client: native_client,
             hierarchies_package_id: hierarchies_pkg_id,
             network_name: network,
             chain_id,
-        })
+            retry_policy: RetryPolicy::default(),
+            validation_config: ValidationConfig::default(),
+            schema_version: u64::from(compat::schema_version()),
+        };
+        client.schema_version = client.fetch_schema_version().await?;
+
+        Ok(client)
+    }
+
+    /// Reads the schema version of the package of the client.
+    ///
+    /// Packages lacking the `schema_version` view are of the published schema, see
+    /// [`compat::PUBLISHED_SCHEMA_VERSION`].
+    async fn fetch_schema_version(&self) -> Result<u64, ClientError> {
+        let package_id = self.package_id();
+        match self
+            .query(move || async move { HierarchiesImpl::get_schema_version(package_id) })
+            .await
+        {
+            // The view doesn't exist, so the transaction has no results
+            Err(ClientError::InvalidResponse { .. }) => Ok(u64::from(compat::PUBLISHED_SCHEMA_VERSION)),
+            result => result,
+        }
+    }
+
+    /// Returns the schema version of the package of the client, see [`compat`].
+    pub fn schema_version(&self) -> u64 {
+        self.schema_version
+    }
+
+    /// Returns true if the package of the client supports `feature`.
+    ///
+    /// Packages of an older schema than this SDK, like the packages published on mainnet, testnet
+    /// and devnet, lack the features introduced since. Their federations can't be decoded, but
+    /// the Move functions they share with this SDK, e.g. `validate_property`, keep working.
+    pub fn supports(&self, feature: FeatureFlag) -> bool {
+        u64::from(feature.since()) <= self.schema_version
+    }
+
+    /// Fails with [`ConfigError::IncompatibleSchema`] unless the package supports `feature`.
+    fn require(&self, feature: FeatureFlag) -> Result<(), ClientError> {
+        self.require_schema(feature.since())
+    }
+
+    /// Fails with [`ConfigError::IncompatibleSchema`] unless the package is at least of schema `required`.
+    fn require_schema(&self, required: u32) -> Result<(), ClientError> {
+        if self.schema_version < u64::from(required) {
+            return Err(ConfigError::IncompatibleSchema {
+                package_id: self.package_id().to_string(),
+                required,
+                actual: self.schema_version,
+            }
+            .into());
+        }
+
+        Ok(())
     }
 
     /// Creates a new [`HierarchiesClientReadOnly`] with a specific Hierarchies package ID.
@@ -143,6 +294,8 @@ impl HierarchiesClientReadOnly {
         #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
         package_id: ObjectID,
     ) -> Result<Self, ClientError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let native_client = iota_client.clone();
         let client = IotaClientAdapter::new(iota_client);
         let network = network_id(&client).await?;
 
@@ -152,28 +305,263 @@ impl HierarchiesClientReadOnly {
             registry.insert_env_history(Env::new(network.as_ref()), vec![package_id]);
         }
 
-        Self::new_internal(client, network).await
+        Self::new_internal(
+            client,
+            #[cfg(not(target_arch = "wasm32"))]
+            native_client,
+            network,
+        )
+        .await
+    }
+
+    /// Creates a [`HierarchiesClientReadOnly`] connected to the IOTA node at `url`.
+    ///
+    /// With a `package_id`, the client uses that package like [`new_with_pkg_id`](Self::new_with_pkg_id).
+    /// Without one, the package ID is discovered from the package registry by the chain identifier
+    /// of the node; nodes of a network without a known deployment fail with
+    /// [`ConfigError::PackageNotFound`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// # use hierarchies::client::HierarchiesClientReadOnly;
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let client =
+    ///     HierarchiesClientReadOnly::new_from_url("https://api.testnet.iota.cafe", None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn new_from_url(url: impl AsRef<str>, package_id: Option<ObjectID>) -> Result<Self, ClientError> {
+        Self::new_from_url_with_options(url, package_id, &ConnectionOptions::default()).await
+    }
+
+    /// Creates a [`HierarchiesClientReadOnly`] connected to the IOTA node at `url` with `options`.
+    ///
+    /// See [`new_from_url`](Self::new_from_url).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn new_from_url_with_options(
+        url: impl AsRef<str>,
+        package_id: Option<ObjectID>,
+        options: &ConnectionOptions,
+    ) -> Result<Self, ClientError> {
+        let iota_client = options.connect(url).await?;
+        match package_id {
+            Some(package_id) => Self::new_with_pkg_id(iota_client, package_id).await,
+            None => Self::new(iota_client).await,
+        }
+    }
+
+    /// Verifies that the package deployed at the configured package ID matches a pinned digest.
+    ///
+    /// Intended to be called once at startup: the check fails closed, returning
+    /// [`ConfigError::PackageIntegrityMismatch`] if a different package is deployed at the configured ID
+    /// and [`ObjectError::NotFound`] if the package cannot be fetched at all.
+    pub async fn verify_package_integrity(&self, expected_digest: ObjectDigest) -> Result<(), ClientError> {
+        let package_id = self.package_id();
+        let package = self
+            .retry_policy
+            .run(|| async {
+                self.client
+                    .read_api()
+                    .get_object_with_options(package_id, IotaObjectDataOptions::new())
+                    .await
+                    .map_err(|err| ClientError::from(NetworkError::RpcFailed { source: Box::new(err) }))
+            })
+            .await?
+            .data
+            .ok_or_else(|| ObjectError::NotFound {
+                id: package_id.to_string(),
+            })?;
+
+        if package.digest != expected_digest {
+            return Err(ConfigError::PackageIntegrityMismatch {
+                package_id: package_id.to_string(),
+                expected: expected_digest.to_string(),
+                actual: package.digest.to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
     }
 
     /// Retrieves a federation by its ID.
+    ///
+    /// Federations are decoded with the layout of this SDK, so packages of an older schema fail
+    /// with [`ConfigError::IncompatibleSchema`].
     pub async fn get_federation_by_id(&self, federation_id: ObjectID) -> Result<Federation, ClientError> {
-        let fed = get_object_ref_by_id_with_bcs(self, &federation_id).await?;
+        self.require_schema(compat::schema_version())?;
+        self.retry_policy
+            .run(|| async { Ok(get_object_ref_by_id_with_bcs(self, &federation_id).await?) })
+            .await
+    }
 
-        Ok(fed)
+    /// Decodes as much of a federation as possible.
+    ///
+    /// Use this to investigate a federation that [`get_federation_by_id`](Self::get_federation_by_id)
+    /// fails to decode, e.g. after a package upgrade changed its layout. The result lists the
+    /// fields decoded before the first mismatch and a
+    /// [`BcsDiagnostic`](crate::core::diagnostics::BcsDiagnostic) describing the mismatch.
+    pub async fn try_parse_partial_federation(
+        &self,
+        federation_id: ObjectID,
+    ) -> Result<PartialDecode<Federation>, ClientError> {
+        let bytes = self
+            .retry_policy
+            .run(|| async { Ok(get_object_bcs_bytes(self, &federation_id).await?) })
+            .await?;
+
+        Ok(diagnostics::try_parse_partial(&bytes))
+    }
+
+    /// Resolves `subject` to its object ID on the network of this client.
+    ///
+    /// Every query taking a [`SubjectId`] resolves it this way.
+    pub fn resolve_subject(&self, subject: impl Into<SubjectId>) -> Result<ObjectID, ClientError> {
+        subject
+            .into()
+            .resolve(self.network_name.as_ref())
+            .map_err(|err| ClientError::InvalidInput {
+                details: err.to_string(),
+            })
+    }
+
+    /// Returns a [`QueryBatcher`] running several read queries in a single transaction.
+    pub fn query_batcher(&self) -> QueryBatcher<'_> {
+        QueryBatcher::new(self)
     }
 
     /// Check if root authority is in the federation.
     pub async fn is_root_authority(&self, federation_id: ObjectID, user_id: ObjectID) -> Result<bool, ClientError> {
-        let tx = HierarchiesImpl::is_root_authority(federation_id, user_id, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+        self.query(|| HierarchiesImpl::is_root_authority(federation_id, user_id, self))
+            .await
     }
 
     /// Retrieves all property names registered in the federation.
+    ///
+    /// Deprecated properties are included, see
+    /// [`get_deprecated_properties`](Self::get_deprecated_properties) for their replacements.
     pub async fn get_properties(&self, federation_id: ObjectID) -> Result<Vec<PropertyName>, ClientError> {
-        let tx = HierarchiesImpl::get_properties(federation_id, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+        self.query(|| HierarchiesImpl::get_properties(federation_id, self))
+            .await
+    }
+
+    /// Retrieves a page of the properties registered in the federation.
+    ///
+    /// Starts at `cursor`, or at the first property if `None`, and returns up to `limit`
+    /// properties. The limit is capped at 100 on-chain. Pass the `next_cursor` of the returned
+    /// page to get the following page. Unlike [`get_properties`](Self::get_properties), a single
+    /// call stays bounded for federations with thousands of properties.
+    pub async fn get_properties_paged(
+        &self,
+        federation_id: ObjectID,
+        cursor: Option<u64>,
+        limit: u64,
+    ) -> Result<PropertyPage, ClientError> {
+        self.require(FeatureFlag::PagedProperties)?;
+        self.query(|| HierarchiesImpl::get_properties_page(federation_id, cursor.unwrap_or_default(), limit, self))
+            .await
+    }
+
+    /// Streams the properties registered in the federation, fetching them `page_size` at a time.
+    ///
+    /// The next page is only requested once the properties of the current one have been
+    /// consumed. The stream ends after the first error.
+    ///
+    /// ```rust,ignore
+    /// let mut properties = pin!(client.stream_properties(federation_id, 100));
+    /// while let Some(property) = properties.try_next().await? {
+    ///     println!("{:?}", property.name);
+    /// }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn stream_properties(
+        &self,
+        federation_id: ObjectID,
+        page_size: u64,
+    ) -> impl Stream<Item = Result<FederationProperty, ClientError>> + '_ {
+        stream::try_unfold(Some(0), move |cursor| async move {
+            let Some(cursor) = cursor else {
+                return Ok::<_, ClientError>(None);
+            };
+            let page = self
+                .get_properties_paged(federation_id, Some(cursor), page_size)
+                .await?;
+            let properties = stream::iter(page.properties.into_iter().map(Ok::<_, ClientError>));
+            Ok(Some((properties, page.next_cursor)))
+        })
+        .try_flatten()
+    }
+
+    /// Retrieves the names of all properties labelled with `tag`.
+    pub async fn get_properties_by_tag(
+        &self,
+        federation_id: ObjectID,
+        tag: impl Into<String>,
+    ) -> Result<Vec<PropertyName>, ClientError> {
+        self.require(FeatureFlag::Tags)?;
+        let tag: String = tag.into();
+        self.query(|| HierarchiesImpl::get_properties_by_tag(federation_id, tag.clone(), self))
+            .await
+    }
+
+    /// Retrieves the names of all properties in the namespace `prefix`.
+    ///
+    /// A property is in the namespace if the leading segments of its name are those of `prefix`,
+    /// e.g. `compliance.iso.9001` is in `compliance` and `compliance.iso`, but not in `comp`.
+    pub async fn get_properties_by_prefix(
+        &self,
+        federation_id: ObjectID,
+        prefix: impl Into<PropertyName>,
+    ) -> Result<Vec<PropertyName>, ClientError> {
+        self.require(FeatureFlag::PropertyPrefixQueries)?;
+        let prefix = prefix.into();
+        self.query(|| HierarchiesImpl::get_properties_by_prefix(federation_id, prefix.clone(), self))
+            .await
+    }
+
+    /// Retrieves the tags of a property.
+    pub async fn get_property_tags(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<Vec<String>, ClientError> {
+        self.require(FeatureFlag::Tags)?;
+        self.query(|| HierarchiesImpl::get_property_tags(federation_id, property_name.clone(), self))
+            .await
+    }
+
+    /// Retrieves the metadata of a property, empty if none is set.
+    pub async fn get_property_metadata(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<PropertyMetadata, ClientError> {
+        let federation = self.get_federation_by_id(federation_id).await?;
+        federation
+            .governance
+            .properties
+            .data
+            .get(&property_name)
+            .map(|property| property.metadata.clone())
+            .ok_or_else(|| {
+                TransactionError::PropertyNotInFederation {
+                    name: Some(property_name),
+                }
+                .into()
+            })
+    }
+
+    /// Retrieves the tags of an accreditation.
+    pub async fn get_accreditation_tags(
+        &self,
+        federation_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<Vec<String>, ClientError> {
+        self.require(FeatureFlag::Tags)?;
+        self.query(|| HierarchiesImpl::get_accreditation_tags(federation_id, accreditation_id, self))
+            .await
     }
 
     /// Checks if a property is registered in the federation.
@@ -182,78 +570,774 @@ impl HierarchiesClientReadOnly {
         federation_id: ObjectID,
         property_name: PropertyName,
     ) -> Result<bool, ClientError> {
-        let tx = HierarchiesImpl::is_property_in_federation(federation_id, property_name, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+        self.query(|| HierarchiesImpl::is_property_in_federation(federation_id, property_name.clone(), self))
+            .await
+    }
+
+    /// Checks if a property is marked as critical in the federation.
+    pub async fn is_property_critical(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<bool, ClientError> {
+        self.require(FeatureFlag::CriticalProperties)?;
+        self.query(|| HierarchiesImpl::is_property_critical(federation_id, property_name.clone(), self))
+            .await
+    }
+
+    /// Retrieves all property names marked as critical in the federation.
+    pub async fn get_critical_properties(&self, federation_id: ObjectID) -> Result<Vec<PropertyName>, ClientError> {
+        self.require(FeatureFlag::CriticalProperties)?;
+        self.query(|| HierarchiesImpl::get_critical_properties(federation_id, self))
+            .await
+    }
+
+    /// Checks if a property is deprecated in the federation.
+    pub async fn is_property_deprecated(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<bool, ClientError> {
+        self.require(FeatureFlag::PropertyDeprecation)?;
+        self.query(|| HierarchiesImpl::is_property_deprecated(federation_id, property_name.clone(), self))
+            .await
+    }
+
+    /// Retrieves the deprecated properties of the federation, mapped to the property replacing
+    /// them if one was recorded.
+    pub async fn get_deprecated_properties(
+        &self,
+        federation_id: ObjectID,
+    ) -> Result<HashMap<PropertyName, Option<PropertyName>>, ClientError> {
+        self.require(FeatureFlag::PropertyDeprecation)?;
+        // A `VecMap` is encoded like the vector of its entries.
+        let entries: Vec<(PropertyName, Option<PropertyName>)> = self
+            .query(|| HierarchiesImpl::get_deprecated_properties(federation_id, self))
+            .await?;
+        Ok(entries.into_iter().collect())
+    }
+
+    /// Retrieves the summary statistics of the federation.
+    ///
+    /// Only the statistics are returned by the on-chain view, the federation itself is not decoded.
+    pub async fn get_federation_stats(&self, federation_id: ObjectID) -> Result<FederationStats, ClientError> {
+        self.require(FeatureFlag::FederationStats)?;
+        self.query(|| HierarchiesImpl::get_federation_stats(federation_id, self))
+            .await
+    }
+
+    /// Retrieves the names of all value dictionaries of the federation.
+    pub async fn get_value_dictionary_names(&self, federation_id: ObjectID) -> Result<Vec<String>, ClientError> {
+        self.require(FeatureFlag::ValueDictionaries)?;
+        self.query(|| HierarchiesImpl::get_value_dictionary_names(federation_id, self))
+            .await
+    }
+
+    /// Retrieves the values of a value dictionary of the federation.
+    pub async fn get_value_dictionary(
+        &self,
+        federation_id: ObjectID,
+        name: impl Into<String>,
+    ) -> Result<Vec<PropertyValue>, ClientError> {
+        self.require(FeatureFlag::ValueDictionaries)?;
+        let name: String = name.into();
+        self.query(|| HierarchiesImpl::get_value_dictionary(federation_id, name.clone(), self))
+            .await
+    }
+
+    /// Retrieves the grant policy of the federation.
+    pub async fn get_grant_policy(&self, federation_id: ObjectID) -> Result<GrantPolicy, ClientError> {
+        let federation = self.get_federation_by_id(federation_id).await?;
+        Ok(federation.governance.grant_policy)
+    }
+
+    /// Retrieves the metadata of the federation.
+    pub async fn get_federation_metadata(&self, federation_id: ObjectID) -> Result<FederationMetadata, ClientError> {
+        let federation = self.get_federation_by_id(federation_id).await?;
+        Ok(federation.metadata)
+    }
+
+    /// Retrieves the capabilities owned by `owner` across all federations.
+    ///
+    /// Capabilities created by any version of the Hierarchies package are listed, root authority
+    /// capabilities first. The owned objects of `owner` are queried page by page.
+    pub async fn get_owned_capabilities(&self, owner: IotaAddress) -> Result<Vec<OwnedCapability>, ClientError> {
+        let mut capabilities = self
+            .owned_capabilities(owner, CapabilityKind::RootAuthority, |cap: RootAuthorityCap| {
+                cap.federation_id
+            })
+            .await?;
+        capabilities.extend(
+            self.owned_capabilities(owner, CapabilityKind::Accredit, |cap: AccreditCap| cap.federation_id)
+                .await?,
+        );
+
+        Ok(capabilities)
+    }
+
+    /// Fetches the network inputs of an administrative transaction of `owner` on a federation.
+    ///
+    /// The returned [`PrefetchedInputs`] hold the federation's initial shared version and the
+    /// current reference of `owner`'s capability of the given `kind`. Applied to a transaction with
+    /// [`WithPrefetchedInputs`](crate::core::prefetch::WithPrefetchedInputs), its programmable
+    /// transaction is built without RPC calls, e.g. on an air-gapped machine.
+    pub async fn prefetch_inputs(
+        &self,
+        federation_id: ObjectID,
+        owner: IotaAddress,
+        kind: CapabilityKind,
+    ) -> Result<PrefetchedInputs, ClientError> {
+        let initial_shared_version = HierarchiesImpl::initial_shared_version(self, &federation_id).await?;
+        let owner = CapabilityOwner::from(owner);
+        let capability = match kind {
+            CapabilityKind::RootAuthority => HierarchiesImpl::get_root_authority_cap(self, owner, federation_id).await,
+            CapabilityKind::Accredit => HierarchiesImpl::get_accredit_cap(self, owner, federation_id).await,
+        }
+        .map_err(OperationError::from)?;
+
+        Ok(PrefetchedInputs {
+            federation: FederationRef {
+                federation_id,
+                initial_shared_version,
+            },
+            kind,
+            capability,
+        })
+    }
+
+    async fn owned_capabilities<T, F>(
+        &self,
+        owner: IotaAddress,
+        kind: CapabilityKind,
+        federation_id: F,
+    ) -> Result<Vec<OwnedCapability>, ClientError>
+    where
+        T: MoveType + DeserializeOwned,
+        F: Fn(T) -> ObjectID,
+    {
+        let filters = self
+            .package_history()
+            .into_iter()
+            .filter_map(|package_id| match T::move_type(package_id) {
+                TypeTag::Struct(struct_tag) => Some(IotaObjectDataFilter::StructType(*struct_tag)),
+                _ => None,
+            })
+            .collect();
+        let query = IotaObjectResponseQuery::new(
+            Some(IotaObjectDataFilter::MatchAny(filters)),
+            Some(IotaObjectDataOptions::bcs_lossless()),
+        );
+
+        let mut capabilities = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self
+                .client
+                .read_api()
+                .get_owned_objects(owner, Some(query.clone()), cursor, Some(CAPABILITY_PAGE_SIZE))
+                .await
+                .map_err(|e| NetworkError::RpcFailed { source: Box::new(e) })?;
+
+            for data in page.data.into_iter().filter_map(|response| response.data) {
+                let object_ref = data.object_ref();
+                let bytes = data
+                    .bcs
+                    .and_then(|bcs| bcs.try_into_move())
+                    .ok_or_else(|| ObjectError::WrongType {
+                        expected: "Move object".to_string(),
+                        actual: "other".to_string(),
+                    })?
+                    .bcs_bytes;
+                let cap = decode_object_bcs(&object_ref.0, &bytes)?;
+                capabilities.push(OwnedCapability {
+                    federation_id: federation_id(cap),
+                    kind,
+                    object_ref,
+                });
+            }
+
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        Ok(capabilities)
     }
 
     /// Retrieves attestation accreditations for a specific user.
+    ///
+    /// Fails for users without accreditations to attest.
+    #[deprecated(note = "use `query_accreditations` with `AccreditationScope::Attest`")]
     pub async fn get_accreditations_to_attest(
         &self,
         federation_id: ObjectID,
-        user_id: ObjectID,
+        user_id: impl Into<SubjectId>,
     ) -> Result<Accreditations, ClientError> {
-        let tx = HierarchiesImpl::get_accreditations_to_attest(federation_id, user_id, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+        let user_id = self.resolve_subject(user_id)?;
+        self.query(|| HierarchiesImpl::get_accreditations_to_attest(federation_id, user_id, self))
+            .await
     }
 
     /// Checks if a user has attestation permissions.
-    pub async fn is_attester(&self, federation_id: ObjectID, user_id: ObjectID) -> Result<bool, ClientError> {
-        let tx = HierarchiesImpl::is_attester(federation_id, user_id, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+    pub async fn is_attester(
+        &self,
+        federation_id: ObjectID,
+        user_id: impl Into<SubjectId>,
+    ) -> Result<bool, ClientError> {
+        let user_id = self.resolve_subject(user_id)?;
+        self.query(|| HierarchiesImpl::is_attester(federation_id, user_id, self))
+            .await
     }
 
     /// Retrieves accreditations to accredit for a specific user.
+    ///
+    /// Fails for users without accreditations to accredit.
+    #[deprecated(note = "use `query_accreditations` with `AccreditationScope::Accredit`")]
     pub async fn get_accreditations_to_accredit(
         &self,
         federation_id: ObjectID,
-        user_id: ObjectID,
+        user_id: impl Into<SubjectId>,
     ) -> Result<Accreditations, ClientError> {
-        let tx = HierarchiesImpl::get_accreditations_to_accredit(federation_id, user_id, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+        let user_id = self.resolve_subject(user_id)?;
+        self.query(|| HierarchiesImpl::get_accreditations_to_accredit(federation_id, user_id, self))
+            .await
+    }
+
+    /// Retrieves a page of the accreditations to attest of a user.
+    ///
+    /// Starts at `cursor`, or at the first accreditation if `None`, and returns up to `limit`
+    /// accreditations passing `filter`. The limit is capped at 100 on-chain. Pass the
+    /// `next_cursor` of the returned page to get the following page.
+    pub async fn get_accreditations_to_attest_paged(
+        &self,
+        federation_id: ObjectID,
+        user_id: impl Into<SubjectId>,
+        cursor: Option<u64>,
+        limit: u64,
+        filter: &AccreditationFilter,
+    ) -> Result<AccreditationPage, ClientError> {
+        let user_id = self.resolve_subject(user_id)?;
+        self.get_accreditations_paged(
+            federation_id,
+            user_id,
+            AccreditationScope::Attest,
+            cursor,
+            limit,
+            filter,
+        )
+        .await
+    }
+
+    /// Retrieves a page of the accreditations to accredit of a user.
+    ///
+    /// See [`HierarchiesClientReadOnly::get_accreditations_to_attest_paged`].
+    pub async fn get_accreditations_to_accredit_paged(
+        &self,
+        federation_id: ObjectID,
+        user_id: impl Into<SubjectId>,
+        cursor: Option<u64>,
+        limit: u64,
+        filter: &AccreditationFilter,
+    ) -> Result<AccreditationPage, ClientError> {
+        let user_id = self.resolve_subject(user_id)?;
+        self.get_accreditations_paged(
+            federation_id,
+            user_id,
+            AccreditationScope::Accredit,
+            cursor,
+            limit,
+            filter,
+        )
+        .await
+    }
+
+    async fn get_accreditations_paged(
+        &self,
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        scope: AccreditationScope,
+        cursor: Option<u64>,
+        limit: u64,
+        filter: &AccreditationFilter,
+    ) -> Result<AccreditationPage, ClientError> {
+        self.require(FeatureFlag::PagedAccreditations)?;
+        self.query(|| {
+            HierarchiesImpl::get_accreditations_page(
+                federation_id,
+                user_id,
+                scope,
+                cursor.unwrap_or_default(),
+                limit,
+                filter,
+                self,
+            )
+        })
+        .await
+    }
+
+    /// Retrieves the accreditations matching `query`.
+    ///
+    /// If the query is restricted to a single entity and scope without a tag filter, the accreditations of the
+    /// entity are read page by page from the on-chain view. Otherwise the federation object is fetched and the
+    /// query is evaluated against it. Both paths return the same accreditations, and no accreditations for entities
+    /// the federation doesn't know.
+    ///
+    /// ```rust,ignore
+    /// let query = AccreditationQuery::new()
+    ///     .for_entity(entity_id)
+    ///     .scope(AccreditationScope::Attest)
+    ///     .active_at(now_ms);
+    /// let accreditations = client.query_accreditations(federation_id, &query).await?;
+    /// ```
+    pub async fn query_accreditations(
+        &self,
+        federation_id: ObjectID,
+        query: &AccreditationQuery,
+    ) -> Result<Vec<AccreditationMatch>, ClientError> {
+        let (Some(entity_id), Some(scope), None) = (query.entity_id(), query.scope_filter(), query.tag_filter()) else {
+            let federation = self.get_federation_by_id(federation_id).await?;
+            return Ok(query.evaluate(&federation));
+        };
+
+        // The paged views return an empty page for unknown entities, where the plain views abort
+        let filter = AccreditationFilter::new();
+        let mut accreditations = Vec::new();
+        let mut cursor = Some(0);
+        while let Some(next) = cursor {
+            let page = self
+                .get_accreditations_paged(federation_id, entity_id, scope, Some(next), MAX_PAGE_SIZE, &filter)
+                .await?;
+            accreditations.extend(page.accreditations);
+            cursor = page.next_cursor;
+        }
+
+        Ok(query.filter(entity_id, scope, &Accreditations::new(accreditations)))
+    }
+
+    /// Resolves the delegation path from a root authority to `entity_id` for `property_name`.
+    ///
+    /// The federation object is fetched and the path is resolved with [`TrustChain::resolve`].
+    /// Returns `None` if the entity doesn't hold the property through a chain ending at an active
+    /// root authority.
+    pub async fn resolve_trust_chain(
+        &self,
+        federation_id: ObjectID,
+        entity_id: impl Into<SubjectId>,
+        property_name: &PropertyName,
+    ) -> Result<Option<TrustChain>, ClientError> {
+        let entity_id = self.resolve_subject(entity_id)?;
+        let federation = self.get_federation_by_id(federation_id).await?;
+
+        Ok(TrustChain::resolve(&federation, entity_id, property_name))
+    }
+
+    /// Resolves the delegation path of `entity_id` for `property_name` in `federation_id` or one of
+    /// the child federations it recognizes, see [`LinkedTrustChain::resolve`].
+    ///
+    /// The queried federation is fetched first. Only if it has no chain, the federations it
+    /// recognizes are fetched, level by level up to [`LinkedTrustChain::MAX_LINK_DEPTH`] links away.
+    /// Recognized IDs that don't refer to a federation are skipped.
+    pub async fn resolve_linked_trust_chain(
+        &self,
+        federation_id: ObjectID,
+        entity_id: impl Into<SubjectId>,
+        property_name: &PropertyName,
+    ) -> Result<Option<LinkedTrustChain>, ClientError> {
+        self.require(FeatureFlag::FederationLinks)?;
+        let entity_id = self.resolve_subject(entity_id)?;
+        let mut federations = HashMap::new();
+        let mut level = vec![federation_id];
+
+        for depth in 0..=LinkedTrustChain::MAX_LINK_DEPTH {
+            let mut next = Vec::new();
+            for id in level {
+                let federation = match self.get_federation_by_id(id).await {
+                    Ok(federation) => federation,
+                    Err(ClientError::Object(_)) if depth > 0 => continue,
+                    Err(e) => return Err(e),
+                };
+                next.extend(
+                    federation
+                        .governance
+                        .recognized_federations
+                        .iter()
+                        .filter(|child| !federations.contains_key(*child) && **child != id),
+                );
+                federations.insert(id, federation);
+            }
+
+            if let Some(chain) = LinkedTrustChain::resolve(&federations, federation_id, entity_id, property_name) {
+                return Ok(Some(chain));
+            }
+            next.retain(|child| !federations.contains_key(child));
+            next.sort();
+            next.dedup();
+            if next.is_empty() {
+                break;
+            }
+            level = next;
+        }
+
+        Ok(None)
+    }
+
+    /// Retrieves the IDs of the child federations recognized by the federation.
+    pub async fn get_recognized_federations(&self, federation_id: ObjectID) -> Result<Vec<ObjectID>, ClientError> {
+        self.require(FeatureFlag::FederationLinks)?;
+        self.query(|| HierarchiesImpl::get_recognized_federations(federation_id, self))
+            .await
     }
 
     /// Checks if a user has accreditations to accredit.
-    pub async fn is_accreditor(&self, federation_id: ObjectID, user_id: ObjectID) -> Result<bool, ClientError> {
-        let tx = HierarchiesImpl::is_accreditor(federation_id, user_id, self).await?;
-        let result = self.execute_read_only_transaction(tx).await?;
-        Ok(result)
+    pub async fn is_accreditor(
+        &self,
+        federation_id: ObjectID,
+        user_id: impl Into<SubjectId>,
+    ) -> Result<bool, ClientError> {
+        let user_id = self.resolve_subject(user_id)?;
+        self.query(|| HierarchiesImpl::is_accreditor(federation_id, user_id, self))
+            .await
     }
 
     /// Validates an attestation
     pub async fn validate_property(
         &self,
         federation_id: ObjectID,
-        attester_id: ObjectID,
+        attester_id: impl Into<SubjectId>,
         property_name: PropertyName,
         property_value: PropertyValue,
     ) -> Result<bool, ClientError> {
-        let tx =
-            HierarchiesImpl::validate_property(federation_id, attester_id, property_name, property_value, self).await?;
+        let attester_id = self.resolve_subject(attester_id)?;
+        self.timed_validation(self.query(|| {
+            HierarchiesImpl::validate_property(
+                federation_id,
+                attester_id,
+                property_name.clone(),
+                property_value.clone(),
+                self,
+            )
+        }))
+        .await
+    }
 
-        let response = self.execute_read_only_transaction(tx).await?;
-        Ok(response)
+    /// Verifies a credential anchored with
+    /// [`HierarchiesClient::anchor_credential`](crate::client::HierarchiesClient::anchor_credential).
+    ///
+    /// Recomputes the [`CredentialHash`] of `credential` and returns true if `issuer` is
+    /// currently accredited to attest it for `property_name`. A modified credential, a revoked
+    /// or expired accreditation, or a different issuer all fail the check.
+    pub async fn verify_anchored_credential(
+        &self,
+        federation_id: ObjectID,
+        issuer: ObjectID,
+        property_name: PropertyName,
+        credential: impl AsRef<[u8]>,
+    ) -> Result<bool, ClientError> {
+        let value = CredentialHash::of(credential).to_property_value();
+        self.validate_property(federation_id, issuer, property_name, value)
+            .await
+    }
+
+    /// Checks if an entity is allowed to attest a value for a property
+    pub async fn is_allowed_to_attest(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<bool, ClientError> {
+        self.query(|| {
+            HierarchiesImpl::is_allowed_to_attest(
+                federation_id,
+                entity_id,
+                property_name.clone(),
+                property_value.clone(),
+                self,
+            )
+        })
+        .await
+    }
+
+    /// Checks if an entity is allowed to delegate accreditations for a property
+    pub async fn is_allowed_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<bool, ClientError> {
+        self.query(|| HierarchiesImpl::is_allowed_to_accredit(federation_id, entity_id, property_name.clone(), self))
+            .await
+    }
+
+    /// Validates a set of values attested for a single property
+    pub async fn validate_property_values(
+        &self,
+        federation_id: ObjectID,
+        attester_id: impl Into<SubjectId>,
+        property_name: PropertyName,
+        property_values: impl IntoIterator<Item = PropertyValue>,
+        value_match: ValueSetMatch,
+    ) -> Result<bool, ClientError> {
+        let attester_id = self.resolve_subject(attester_id)?;
+        let property_values: HashSet<_> = property_values.into_iter().collect();
+        self.timed_validation(self.query(|| {
+            HierarchiesImpl::validate_property_values(
+                federation_id,
+                attester_id,
+                property_name.clone(),
+                property_values.clone(),
+                value_match,
+                self,
+            )
+        }))
+        .await
     }
 
     /// Validates an attestations
     pub async fn validate_properties(
         &self,
         federation_id: ObjectID,
-        entity_id: ObjectID,
+        entity_id: impl Into<SubjectId>,
         properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
     ) -> Result<bool, ClientError> {
-        let tx = HierarchiesImpl::validate_properties(federation_id, entity_id, properties.into_iter().collect(), self)
+        let entity_id = self.resolve_subject(entity_id)?;
+        let properties: HashMap<_, _> = properties.into_iter().collect();
+        self.timed_validation(
+            self.query(|| HierarchiesImpl::validate_properties(federation_id, entity_id, properties.clone(), self)),
+        )
+        .await
+    }
+
+    /// Validates properties attested by `entity_id` in several federations with a single query.
+    ///
+    /// Returns the result of every federation, in the order of `checks`.
+    pub async fn validate_properties_multi(
+        &self,
+        checks: Vec<(ObjectID, HashMap<PropertyName, PropertyValue>)>,
+        entity_id: impl Into<SubjectId>,
+    ) -> Result<Vec<bool>, ClientError> {
+        let entity_id = self.resolve_subject(entity_id)?;
+        if checks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.timed_validation(self.retry_policy.run(|| async {
+            let tx = HierarchiesImpl::validate_properties_multi(&checks, entity_id, self).await?;
+            self.execute_read_only_transaction_tail(tx, checks.len()).await
+        }))
+        .await
+    }
+
+    /// Validates properties attested by `entity_id` in several federations and reports the outcome
+    /// of every check.
+    ///
+    /// With [`PartialFailurePolicy::Atomic`] the checks are evaluated in a single query, like
+    /// [`validate_properties_multi`](Self::validate_properties_multi), and an aborting check fails all
+    /// of them. With [`PartialFailurePolicy::ContinueAndReport`] every check is queried on its own, so
+    /// e.g. a deleted federation only fails its own check.
+    pub async fn batch_validate_properties(
+        &self,
+        checks: Vec<(ObjectID, HashMap<PropertyName, PropertyValue>)>,
+        entity_id: impl Into<SubjectId>,
+        policy: PartialFailurePolicy,
+    ) -> Vec<BatchItemResult<bool>> {
+        let entity_id = match self.resolve_subject(entity_id) {
+            Ok(entity_id) => entity_id,
+            Err(e) => return shared_results(0..checks.len(), Err(Arc::new(e))),
+        };
+
+        match policy {
+            PartialFailurePolicy::Atomic => {
+                let len = checks.len();
+                match self.validate_properties_multi(checks, entity_id).await {
+                    Ok(valid) => valid
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, valid)| BatchItemResult {
+                            index,
+                            result: Ok(valid),
+                        })
+                        .collect(),
+                    Err(e) => shared_results(0..len, Err(Arc::new(e))),
+                }
+            }
+            PartialFailurePolicy::ContinueAndReport => {
+                let mut results = Vec::with_capacity(checks.len());
+                for (index, (federation_id, properties)) in checks.into_iter().enumerate() {
+                    let result = self
+                        .validate_properties(federation_id, entity_id, properties)
+                        .await
+                        .map_err(Arc::new);
+                    results.push(BatchItemResult { index, result });
+                }
+                results
+            }
+        }
+    }
+
+    /// Validates `entity_id` against every federation of `set` with a single query.
+    pub async fn validate_federation_set(
+        &self,
+        set: &FederationSet,
+        entity_id: impl Into<SubjectId>,
+    ) -> Result<FederationSetValidation, ClientError> {
+        let federation_ids: Vec<ObjectID> = set.federation_ids().collect();
+        let results = self
+            .validate_properties_multi(set.clone().into_checks(), entity_id)
             .await?;
 
-        let response = self.execute_read_only_transaction(tx).await?;
-        Ok(response)
+        Ok(FederationSetValidation {
+            results: federation_ids.into_iter().zip(results).collect(),
+        })
+    }
+
+    /// Validates a property of an attester like [`validate_property`](Self::validate_property), but
+    /// reports which rule failed instead of returning a bare bool.
+    ///
+    /// The rules are evaluated against the current state of the federation at the time of the
+    /// network clock, see [`offline::diagnose_property`]. Like the on-chain validation, timespans
+    /// are compared with the network clock as they are, without the clock skew tolerance of the
+    /// client's [`ValidationConfig`].
+    pub async fn validate_property_report(
+        &self,
+        federation_id: ObjectID,
+        attester_id: impl Into<SubjectId>,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<ValidationReport, ClientError> {
+        self.validate_properties_report(federation_id, attester_id, [(property_name, property_value)])
+            .await
+    }
+
+    /// Validates a set of values of a property like
+    /// [`validate_property_values`](Self::validate_property_values), but reports which rule failed
+    /// for each value instead of returning a bare bool.
+    pub async fn validate_property_values_report(
+        &self,
+        federation_id: ObjectID,
+        attester_id: impl Into<SubjectId>,
+        property_name: PropertyName,
+        property_values: impl IntoIterator<Item = PropertyValue>,
+        value_match: ValueSetMatch,
+    ) -> Result<ValidationReport, ClientError> {
+        let attester_id = self.resolve_subject(attester_id)?;
+        let property_values: Vec<_> = property_values.into_iter().collect();
+        let federation = self.get_federation_by_id(federation_id).await?;
+        let now_ms = self.network_time_ms().await?;
+
+        Ok(offline::diagnose_property_values(
+            &federation,
+            &attester_id,
+            &property_name,
+            &property_values,
+            value_match,
+            now_ms,
+        ))
+    }
+
+    /// Validates attested properties like [`validate_properties`](Self::validate_properties), but
+    /// reports which rule failed for each property instead of returning a bare bool.
+    pub async fn validate_properties_report(
+        &self,
+        federation_id: ObjectID,
+        attester_id: impl Into<SubjectId>,
+        properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
+    ) -> Result<ValidationReport, ClientError> {
+        let attester_id = self.resolve_subject(attester_id)?;
+        let properties: HashMap<_, _> = properties.into_iter().collect();
+        let federation = self.get_federation_by_id(federation_id).await?;
+        let now_ms = self.network_time_ms().await?;
+
+        Ok(offline::diagnose_properties(
+            &federation,
+            &attester_id,
+            &properties,
+            now_ms,
+        ))
+    }
+
+    /// Returns the time of the network clock, as seen by on-chain validation.
+    pub async fn network_time_ms(&self) -> Result<u64, ClientError> {
+        #[derive(Deserialize)]
+        struct Clock {
+            #[allow(dead_code)]
+            id: UID,
+            timestamp_ms: u64,
+        }
+
+        let clock: Clock = self
+            .retry_policy
+            .run(|| async { Ok(get_object_ref_by_id_with_bcs(self, &IOTA_CLOCK_OBJECT_ID).await?) })
+            .await?;
+        Ok(clock.timestamp_ms)
+    }
+
+    /// Retrieves an attestation by its ID.
+    pub async fn get_attestation(&self, attestation_id: ObjectID) -> Result<Attestation, ClientError> {
+        self.require(FeatureFlag::Attestations)?;
+        self.retry_policy
+            .run(|| async { Ok(get_object_ref_by_id_with_bcs(self, &attestation_id).await?) })
+            .await
+    }
+
+    /// Checks if an attestation issued in the federation is revoked.
+    pub async fn is_attestation_revoked(
+        &self,
+        federation_id: ObjectID,
+        attestation_id: ObjectID,
+    ) -> Result<bool, ClientError> {
+        self.require(FeatureFlag::Attestations)?;
+        self.query(|| HierarchiesImpl::is_attestation_revoked(federation_id, attestation_id, self))
+            .await
+    }
+
+    /// Validates an attestation.
+    ///
+    /// Returns true if the attestation was issued in the federation, is not revoked, is within its
+    /// validity window and its issuer is still accredited to attest all of its properties.
+    pub async fn validate_attestation(
+        &self,
+        federation_id: ObjectID,
+        attestation_id: ObjectID,
+    ) -> Result<bool, ClientError> {
+        self.require(FeatureFlag::Attestations)?;
+        self.timed_validation(self.query(|| HierarchiesImpl::validate_attestation(federation_id, attestation_id, self)))
+            .await
     }
 }
 
 impl HierarchiesClientReadOnly {
+    /// Awaits the validation query `validation`, recording its latency.
+    #[cfg(feature = "metrics")]
+    async fn timed_validation<T>(&self, validation: impl Future<Output = T>) -> T {
+        crate::metrics::time_validation(validation).await
+    }
+
+    /// Awaits the validation query `validation`.
+    #[cfg(not(feature = "metrics"))]
+    async fn timed_validation<T>(&self, validation: impl Future<Output = T>) -> T {
+        validation.await
+    }
+
+    /// Builds a read-only transaction with `build` and executes it.
+    ///
+    /// Both steps are retried according to the [`RetryPolicy`] of the client, so every
+    /// attempt references the current version of the federation.
+    async fn query<T, F, Fut>(&self, build: F) -> Result<T, ClientError>
+    where
+        T: DeserializeOwned,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<ProgrammableTransaction, OperationError>>,
+    {
+        self.retry_policy
+            .run(|| async {
+                let tx = build().await?;
+                self.execute_read_only_transaction(tx).await
+            })
+            .await
+    }
+
     /// A helper function to execute a read-only transaction and deserialize
     /// the result into the specified type `T`.
     ///
@@ -281,9 +1365,7 @@ impl HierarchiesClientReadOnly {
             .read_api()
             .dev_inspect_transaction_block(IotaAddress::ZERO, TransactionKind::Programmable(tx), None, None, None)
             .await
-            .map_err(|err| ClientError::ExecutionFailed {
-                reason: format!("Failed to inspect transaction block: {err}"),
-            })?;
+            .map_err(|err| NetworkError::RpcFailed { source: Box::new(err) })?;
 
         let execution_results = inspection_result.results.ok_or_else(|| ClientError::InvalidResponse {
             reason: "DevInspectResults missing 'results' field".to_string(),
@@ -320,6 +1402,67 @@ impl HierarchiesClientReadOnly {
             ),
         })
     }
+
+    /// Executes a read-only transaction and deserializes the return values of its last `count` commands.
+    async fn execute_read_only_transaction_tail<T: DeserializeOwned>(
+        &self,
+        tx: ProgrammableTransaction,
+        count: usize,
+    ) -> Result<Vec<T>, ClientError> {
+        let return_values = self.execute_read_only_transaction_raw(tx).await?;
+        if return_values.len() < count {
+            return Err(ClientError::InvalidResponse {
+                reason: format!(
+                    "expected at least {count} execution results, got {}",
+                    return_values.len()
+                ),
+            });
+        }
+
+        return_values[return_values.len() - count..]
+            .iter()
+            .map(|bytes| decode_return_value(bytes.as_deref()))
+            .collect()
+    }
+
+    /// Executes a read-only transaction and returns the BCS bytes of the first return value of
+    /// every command, in the order of the commands, or `None` for commands returning nothing.
+    pub(crate) async fn execute_read_only_transaction_raw(
+        &self,
+        tx: ProgrammableTransaction,
+    ) -> Result<Vec<Option<Vec<u8>>>, ClientError> {
+        let inspection_result = self
+            .client
+            .read_api()
+            .dev_inspect_transaction_block(IotaAddress::ZERO, TransactionKind::Programmable(tx), None, None, None)
+            .await
+            .map_err(|err| NetworkError::RpcFailed { source: Box::new(err) })?;
+
+        let execution_results = inspection_result.results.ok_or_else(|| ClientError::InvalidResponse {
+            reason: "DevInspectResults missing 'results' field".to_string(),
+        })?;
+
+        Ok(execution_results
+            .into_iter()
+            .map(|result| {
+                result
+                    .return_values
+                    .into_iter()
+                    .next()
+                    .map(|(return_value_bytes, _)| return_value_bytes)
+            })
+            .collect())
+    }
+}
+
+/// Deserializes the BCS bytes of a return value of a read-only transaction.
+pub(crate) fn decode_return_value<T: DeserializeOwned>(bytes: Option<&[u8]>) -> Result<T, ClientError> {
+    let bytes = bytes.ok_or_else(|| ClientError::InvalidResponse {
+        reason: "execution result has no return value".to_string(),
+    })?;
+    bcs::from_bytes(bytes).map_err(|e| ClientError::InvalidResponse {
+        reason: format!("failed to deserialize return value: {e}"),
+    })
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,346 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Retries of transient failures.
+//!
+//! Busy federations see transient RPC failures and object-version conflicts on the shared
+//! `Federation` object. A [`RetryPolicy`] re-runs an operation with exponential backoff as long
+//! as its error is classified as retryable.
+//!
+//! The policy of a client is set with
+//! [`HierarchiesClientReadOnly::with_retry_policy`](super::HierarchiesClientReadOnly::with_retry_policy)
+//! and applied to every query of the read-only client and to the operations of the
+//! [`HierarchiesApi`](super::HierarchiesApi) on existing federations. Transactions built with the
+//! [`HierarchiesClient`](super::HierarchiesClient) are retried with [`RetryPolicy::run_transaction`]:
+//!
+//! ```rust,ignore
+//! let policy = client.retry_policy().clone();
+//! policy
+//!     .run_transaction(|| async {
+//!         client
+//!             .add_root_authority(federation_id, account_id)
+//!             .build_and_execute(&client)
+//!             .await
+//!             .map_err(|e| ClientError::ExecutionFailed { reason: e.to_string() })
+//!     })
+//!     .await?;
+//! ```
+//!
+//! Every attempt rebuilds the transaction, so it references the current version of the
+//! federation.
+//!
+//! Queries don't change any state, so they are retried after any transient failure. Transactions
+//! are only retried after failures that prove they weren't executed, see
+//! [`is_rejected_before_execution`]. A transaction failing for another reason, e.g. a network
+//! error while waiting for its effects, may have been executed already. Its error is returned
+//! as is, so check the effects of the transaction, e.g. by looking it up by its digest or
+//! querying the federation, before submitting it again.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::client::error::ClientError;
+use crate::core::transactions::MoveAbort;
+use crate::core::{CapabilityError, OperationError};
+use crate::error::{NetworkError, ObjectError};
+
+/// Default number of attempts, including the first one.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+/// Default delay before the first retry.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+/// Default upper bound of the delay between attempts.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Failures of executed transactions that go away once the transaction is rebuilt or resubmitted.
+const TRANSIENT_EXECUTION_FAILURES: &[&str] = &[
+    "ObjectVersionUnavailableForConsumption",
+    "ObjectLockConflict",
+    "ObjectsDoubleUsed",
+    "SharedObjectCongestion",
+];
+
+/// Decides whether a failed attempt is retried.
+pub type RetryClassifier = fn(&ClientError) -> bool;
+
+/// Retry behaviour of a client.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use hierarchies::client::{HierarchiesClientReadOnly, RetryPolicy};
+/// # fn example(client: HierarchiesClientReadOnly) {
+/// let client = client.with_retry_policy(
+///     RetryPolicy::default()
+///         .with_max_attempts(5)
+///         .with_initial_backoff(Duration::from_millis(50)),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between attempts
+    pub max_backoff: Duration,
+    /// Factor the delay grows by after every retry
+    pub multiplier: u32,
+    /// Decides which errors of queries are retried, [`is_transient`] by default
+    pub classifier: RetryClassifier,
+    /// Decides which errors of transactions are retried, [`is_rejected_before_execution`] by default
+    pub transaction_classifier: RetryClassifier,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            multiplier: 2,
+            classifier: is_transient,
+            transaction_classifier: is_rejected_before_execution,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that runs every operation exactly once.
+    pub fn none() -> Self {
+        Self::default().with_max_attempts(1)
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_classifier(mut self, classifier: RetryClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    pub fn with_transaction_classifier(mut self, transaction_classifier: RetryClassifier) -> Self {
+        self.transaction_classifier = transaction_classifier;
+        self
+    }
+
+    /// Returns the delay before the `retry`-th retry, counting from 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+
+    /// Runs the query `operation` until it succeeds, fails with an error that isn't retryable
+    /// or the attempts are used up. The error of the last attempt is returned.
+    pub async fn run<T, F, Fut>(&self, operation: F) -> Result<T, ClientError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        self.run_with(self.classifier, operation).await
+    }
+
+    /// Runs `operation`, which builds and executes a transaction, like [`run`](Self::run), but
+    /// only retries errors accepted by the [`transaction_classifier`](Self::transaction_classifier).
+    pub async fn run_transaction<T, F, Fut>(&self, operation: F) -> Result<T, ClientError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        self.run_with(self.transaction_classifier, operation).await
+    }
+
+    async fn run_with<T, F, Fut>(&self, classifier: RetryClassifier, mut operation: F) -> Result<T, ClientError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(error) if attempt < self.max_attempts && classifier(&error) => {
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record_retry();
+                    sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// The default [`RetryClassifier`] of queries.
+///
+/// Failed RPC calls are transient, as are the failures accepted by
+/// [`is_rejected_before_execution`]. Move aborts and all other errors are not.
+pub fn is_transient(error: &ClientError) -> bool {
+    matches!(error, ClientError::Network(_)) || is_rejected_before_execution(error)
+}
+
+/// The default [`RetryClassifier`] of transactions.
+///
+/// Only failures that prove the transaction wasn't executed are retried: objects that couldn't
+/// be read before the transaction was built, and executions rejected because of an
+/// object-version conflict, a lock conflict or congestion on a shared object. Failed RPC calls
+/// are not, as they may have happened after the transaction was submitted.
+pub fn is_rejected_before_execution(error: &ClientError) -> bool {
+    match error {
+        ClientError::Object(error) | ClientError::Operation(OperationError::Object(error)) => {
+            is_transient_object_error(error)
+        }
+        ClientError::Operation(OperationError::Capability(CapabilityError::Rpc { .. })) => true,
+        ClientError::ExecutionFailed { .. } | ClientError::Transaction(_) => {
+            is_transient_execution_failure(&format!("{error:?}"))
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if an object couldn't be retrieved because of a failed RPC call.
+fn is_transient_object_error(error: &ObjectError) -> bool {
+    matches!(error, ObjectError::RetrievalFailed { source } if source.is::<NetworkError>())
+}
+
+/// Returns true if the failure message of an execution names a transient failure.
+fn is_transient_execution_failure(message: &str) -> bool {
+    MoveAbort::parse(message).is_none()
+        && TRANSIENT_EXECUTION_FAILURES
+            .iter()
+            .any(|failure| message.contains(failure))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn rpc_failure() -> ClientError {
+        ClientError::Network(NetworkError::RpcFailed {
+            source: "connection reset".into(),
+        })
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_bound() {
+        let policy = RetryPolicy::default()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(350));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn classifies_transient_errors() {
+        assert!(is_transient(&rpc_failure()));
+        assert!(is_transient(&ClientError::ExecutionFailed {
+            reason: "ObjectVersionUnavailableForConsumption { provided_obj_ref: .. }".to_string(),
+        }));
+        assert!(!is_transient(&ClientError::ExecutionFailed {
+            reason: "MoveAbort(MoveLocation { module: ModuleId { address: 0x2, name: Identifier(\"main\") }, \
+                     function: 3, instruction: 7, function_name: None }, 8) in command 1"
+                .to_string(),
+        }));
+        assert!(!is_transient(&ClientError::InvalidInput {
+            details: "not a DID".to_string(),
+        }));
+    }
+
+    #[test]
+    fn transactions_are_not_retried_after_network_errors() {
+        assert!(!is_rejected_before_execution(&rpc_failure()));
+        assert!(is_rejected_before_execution(&ClientError::ExecutionFailed {
+            reason: "ObjectLockConflict { obj_ref: .. }".to_string(),
+        }));
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors_until_success() {
+        let policy = RetryPolicy::default().with_initial_backoff(Duration::ZERO);
+        let attempts = Cell::new(0);
+
+        let result = policy
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 3 {
+                    Err(rpc_failure())
+                } else {
+                    Ok(attempts.get())
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn stops_at_permanent_errors_and_max_attempts() {
+        let policy = RetryPolicy::default()
+            .with_initial_backoff(Duration::ZERO)
+            .with_max_attempts(2);
+        let attempts = Cell::new(0);
+
+        let result: Result<(), _> = policy
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                Err(rpc_failure())
+            })
+            .await;
+        assert!(matches!(result, Err(ClientError::Network(_))));
+        assert_eq!(attempts.get(), 2);
+
+        attempts.set(0);
+        let result: Result<(), _> = policy
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                Err(ClientError::InvalidInput {
+                    details: "bad".to_string(),
+                })
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+
+        attempts.set(0);
+        let result: Result<(), _> = policy
+            .run_transaction(|| async {
+                attempts.set(attempts.get() + 1);
+                Err(rpc_failure())
+            })
+            .await;
+        assert!(matches!(result, Err(ClientError::Network(_))));
+        assert_eq!(attempts.get(), 1);
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Scoped Clients
+//!
+//! Teams sharing a federation usually own a namespace of property names, e.g. everything under `iso`.
+//! A scoped client prefixes every property name passed to it with that namespace and only returns
+//! properties and accreditations within it, so a team can't accidentally add, grant or validate a
+//! property of another team.
+//!
+//! Names passed to a scoped client are relative to its scope, names returned by it are the full
+//! names stored in the federation.
+//!
+//! ```rust,ignore
+//! let iso = client.scoped("iso");
+//!
+//! // Adds `iso.9001`
+//! iso.add_property(federation_id, FederationProperty::new("9001").with_allow_any(true))
+//!     .build_and_execute(&client)
+//!     .await?;
+//!
+//! // Validates `iso.9001`
+//! iso.validate_property(federation_id, attester_id, "9001".into(), value).await?;
+//! ```
+
+use std::ops::Deref;
+
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+
+use crate::client::error::ClientError;
+use crate::client::{HierarchiesClient, HierarchiesClientReadOnly};
+use crate::core::transactions::properties::add_property::AddProperty;
+use crate::core::transactions::properties::deprecate_property::DeprecateProperty;
+use crate::core::transactions::properties::revoke_property::RevokeProperty;
+use crate::core::transactions::properties::set_property_critical::SetPropertyCritical;
+use crate::core::transactions::properties::set_property_tags::SetPropertyTags;
+use crate::core::transactions::{CreateAccreditation, CreateAccreditationToAttest};
+use crate::core::types::accreditation_query::{AccreditationMatch, AccreditationQuery};
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::{PropertyValue, ValueSetMatch};
+
+/// A read-only client restricted to the property names under a scope.
+///
+/// Created with [`HierarchiesClientReadOnly::scoped`].
+#[derive(Clone)]
+pub struct ScopedClientReadOnly<'a> {
+    client: &'a HierarchiesClientReadOnly,
+    scope: PropertyName,
+}
+
+impl ScopedClientReadOnly<'_> {
+    /// Returns the scope prefixed to property names.
+    pub fn scope(&self) -> &PropertyName {
+        &self.scope
+    }
+
+    /// Returns the full name of `relative_name` within the scope.
+    pub fn full_name(&self, relative_name: impl Into<PropertyName>) -> PropertyName {
+        self.scope.join(&relative_name.into())
+    }
+
+    /// Returns true if `name` lies within the scope.
+    pub fn contains(&self, name: &PropertyName) -> bool {
+        name.starts_with(&self.scope)
+    }
+
+    fn scoped_property(&self, mut property: FederationProperty) -> FederationProperty {
+        property.name = self.full_name(property.name);
+        property
+    }
+
+    /// Retrieves the full names of the federation's properties within the scope.
+    pub async fn get_properties(&self, federation_id: ObjectID) -> Result<Vec<PropertyName>, ClientError> {
+        let mut properties = self.client.get_properties(federation_id).await?;
+        properties.retain(|name| self.contains(name));
+
+        Ok(properties)
+    }
+
+    /// Checks if a property within the scope exists in the federation.
+    pub async fn is_property_in_federation(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<bool, ClientError> {
+        self.client
+            .is_property_in_federation(federation_id, self.full_name(property_name))
+            .await
+    }
+
+    /// Retrieves the accreditations matching `query`, reduced to the properties within the scope.
+    ///
+    /// Accreditations without any property within the scope are dropped.
+    pub async fn query_accreditations(
+        &self,
+        federation_id: ObjectID,
+        query: &AccreditationQuery,
+    ) -> Result<Vec<AccreditationMatch>, ClientError> {
+        let mut matches = self.client.query_accreditations(federation_id, query).await?;
+        for found in &mut matches {
+            found.accreditation.properties.retain(|name, _| self.contains(name));
+        }
+        matches.retain(|found| !found.accreditation.properties.is_empty());
+
+        Ok(matches)
+    }
+
+    /// Validates an attestation of a property within the scope.
+    pub async fn validate_property(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<bool, ClientError> {
+        self.client
+            .validate_property(
+                federation_id,
+                attester_id,
+                self.full_name(property_name),
+                property_value,
+            )
+            .await
+    }
+
+    /// Validates an attestation of several values of a property within the scope.
+    pub async fn validate_property_values(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_values: impl IntoIterator<Item = PropertyValue>,
+        value_match: ValueSetMatch,
+    ) -> Result<bool, ClientError> {
+        self.client
+            .validate_property_values(
+                federation_id,
+                attester_id,
+                self.full_name(property_name),
+                property_values,
+                value_match,
+            )
+            .await
+    }
+
+    /// Validates attestations of several properties within the scope.
+    pub async fn validate_properties(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
+    ) -> Result<bool, ClientError> {
+        let properties = properties
+            .into_iter()
+            .map(|(name, value)| (self.full_name(name), value));
+        self.client
+            .validate_properties(federation_id, entity_id, properties)
+            .await
+    }
+
+    /// Checks if an entity is allowed to attest a value for a property within the scope.
+    pub async fn is_allowed_to_attest(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<bool, ClientError> {
+        self.client
+            .is_allowed_to_attest(federation_id, entity_id, self.full_name(property_name), property_value)
+            .await
+    }
+
+    /// Checks if an entity is allowed to accredit a property within the scope.
+    pub async fn is_allowed_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: PropertyName,
+    ) -> Result<bool, ClientError> {
+        self.client
+            .is_allowed_to_accredit(federation_id, entity_id, self.full_name(property_name))
+            .await
+    }
+}
+
+impl HierarchiesClientReadOnly {
+    /// Returns a client restricted to the property names under `scope`.
+    pub fn scoped(&self, scope: impl Into<PropertyName>) -> ScopedClientReadOnly<'_> {
+        ScopedClientReadOnly {
+            client: self,
+            scope: scope.into(),
+        }
+    }
+}
+
+/// A client restricted to the property names under a scope.
+///
+/// Created with [`HierarchiesClient::scoped`]. The read-only methods of [`ScopedClientReadOnly`]
+/// are available through `Deref`.
+pub struct ScopedClient<'a, S> {
+    client: &'a HierarchiesClient<S>,
+    read_only: ScopedClientReadOnly<'a>,
+}
+
+impl<'a, S> Deref for ScopedClient<'a, S> {
+    type Target = ScopedClientReadOnly<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.read_only
+    }
+}
+
+impl<S> HierarchiesClient<S> {
+    /// Returns a client restricted to the property names under `scope`.
+    pub fn scoped(&self, scope: impl Into<PropertyName>) -> ScopedClient<'_, S> {
+        ScopedClient {
+            client: self,
+            read_only: (**self).scoped(scope),
+        }
+    }
+}
+
+impl<S> ScopedClient<'_, S>
+where
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    /// Creates a builder adding `property` to the federation, named within the scope.
+    pub fn add_property(
+        &self,
+        federation_id: ObjectID,
+        property: FederationProperty,
+    ) -> TransactionBuilder<AddProperty> {
+        self.client.add_property(federation_id, self.scoped_property(property))
+    }
+
+    /// Creates a builder revoking a property within the scope.
+    pub fn revoke_property(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        valid_to_ms: Option<u64>,
+    ) -> TransactionBuilder<RevokeProperty> {
+        self.client
+            .revoke_property(federation_id, self.full_name(property_name), valid_to_ms)
+    }
+
+    /// Creates a builder marking or unmarking a property within the scope as critical.
+    pub fn set_property_critical(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        critical: bool,
+    ) -> TransactionBuilder<SetPropertyCritical> {
+        self.client
+            .set_property_critical(federation_id, self.full_name(property_name), critical)
+    }
+
+    /// Creates a builder deprecating a property within the scope in favour of `replacement`, also
+    /// within the scope.
+    pub fn deprecate_property(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        replacement: Option<PropertyName>,
+    ) -> TransactionBuilder<DeprecateProperty> {
+        self.client.deprecate_property(
+            federation_id,
+            self.full_name(property_name),
+            replacement.map(|replacement| self.full_name(replacement)),
+        )
+    }
+
+    /// Creates a builder replacing the tags of a property within the scope.
+    pub fn set_property_tags(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        tags: impl IntoIterator<Item = impl Into<String>>,
+    ) -> TransactionBuilder<SetPropertyTags> {
+        self.client
+            .set_property_tags(federation_id, self.full_name(property_name), tags)
+    }
+
+    /// Creates a builder granting `receiver` an accreditation to attest properties within the scope.
+    pub fn create_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        want_properties: impl IntoIterator<Item = FederationProperty>,
+    ) -> TransactionBuilder<CreateAccreditationToAttest> {
+        let want_properties = want_properties
+            .into_iter()
+            .map(|property| self.scoped_property(property));
+        self.client
+            .create_accreditation_to_attest(federation_id, receiver, want_properties)
+    }
+
+    /// Creates a builder granting `receiver` an accreditation to accredit properties within the scope.
+    pub fn create_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: impl IntoIterator<Item = FederationProperty>,
+    ) -> TransactionBuilder<CreateAccreditation> {
+        let properties = properties.into_iter().map(|property| self.scoped_property(property));
+        self.client
+            .create_accreditation_to_accredit(federation_id, receiver, properties)
+    }
+}
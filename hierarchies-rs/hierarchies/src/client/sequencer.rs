@@ -0,0 +1,276 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialized writes to shared federation objects.
+//!
+//! Every mutation of a federation consumes the current version of the shared `Federation`
+//! object. Transactions built concurrently from several tasks reference the same version,
+//! so all but one of them fail with an object-version conflict.
+//!
+//! The [`TransactionSequencer`] of a [`HierarchiesClient`] queues the operations submitted with
+//! [`HierarchiesClient::submit_sequenced`] per federation. Only one transaction per federation
+//! is in flight at a time; operations queued meanwhile are executed together in the next
+//! [`TransactionBatch`](crate::core::transactions::TransactionBatch). Batches rejected because
+//! of a version conflict are rebuilt and resubmitted according to the [`RetryPolicy`] of the
+//! sequencer, see [`RetryPolicy::run_transaction`]. The queue of a federation is dropped once it drains, so
+//! long-running services don't keep state for every federation they ever touched.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let client = Arc::new(client);
+//! let tasks = receivers.into_iter().map(|receiver| {
+//!     let client = client.clone();
+//!     tokio::spawn(async move {
+//!         client
+//!             .submit_sequenced(
+//!                 federation_id,
+//!                 BatchOperation::CreateAccreditationToAttest { receiver, properties: vec![role()] },
+//!             )
+//!             .await
+//!     })
+//! });
+//! futures::future::join_all(tasks).await;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use secret_storage::Signer;
+use tokio::sync::oneshot;
+
+use crate::client::api::execution_error;
+use crate::client::error::ClientError;
+use crate::client::full_client::HierarchiesClient;
+use crate::client::retry::RetryPolicy;
+use crate::core::transactions::{BatchOperation, MoveAbortMapper};
+
+/// Default number of queued operations executed in one transaction.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 64;
+
+/// An operation waiting for its transaction.
+struct Pending {
+    operation: BatchOperation,
+    reply: oneshot::Sender<Result<(), Arc<ClientError>>>,
+}
+
+/// The queue of a single federation, shared by the operations queued or running on it.
+#[derive(Default)]
+struct Lane {
+    pending: Mutex<Vec<Pending>>,
+    running: tokio::sync::Mutex<()>,
+}
+
+/// Serializes the mutations a client submits against the same federation.
+///
+/// See the [module docs](self).
+pub struct TransactionSequencer {
+    lanes: Mutex<HashMap<ObjectID, Weak<Lane>>>,
+    max_batch_size: usize,
+    retry_policy: RetryPolicy,
+}
+
+impl Default for TransactionSequencer {
+    fn default() -> Self {
+        Self {
+            lanes: Mutex::default(),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for TransactionSequencer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransactionSequencer")
+            .field("max_batch_size", &self.max_batch_size)
+            .field("retry_policy", &self.retry_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TransactionSequencer {
+    /// Creates a new [`TransactionSequencer`] with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of queued operations executed in one transaction.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size.max(1);
+        self
+    }
+
+    /// Sets the policy for resubmitting batches that failed with a transient error.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the queue of `federation_id`, creating it if needed.
+    fn lane(&self, federation_id: ObjectID) -> Arc<Lane> {
+        let mut lanes = self.lanes.lock().expect("sequencer lock is not poisoned");
+        if let Some(lane) = lanes.get(&federation_id).and_then(Weak::upgrade) {
+            return lane;
+        }
+
+        // Also drops queues left behind by cancelled submissions
+        lanes.retain(|_, lane| lane.strong_count() > 0);
+        let lane = Arc::new(Lane::default());
+        lanes.insert(federation_id, Arc::downgrade(&lane));
+        lane
+    }
+
+    /// Forgets the queue of `federation_id` once no operation is queued or running on it.
+    fn release(&self, federation_id: ObjectID, lane: Arc<Lane>) {
+        let mut lanes = self.lanes.lock().expect("sequencer lock is not poisoned");
+        drop(lane);
+        if lanes.get(&federation_id).is_some_and(|lane| lane.strong_count() == 0) {
+            lanes.remove(&federation_id);
+        }
+    }
+
+    /// Queues `operation` and waits until the transaction containing it was executed.
+    pub(crate) async fn submit<S>(
+        &self,
+        client: &HierarchiesClient<S>,
+        federation_id: ObjectID,
+        operation: BatchOperation,
+    ) -> Result<(), Arc<ClientError>>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let lane = self.lane(federation_id);
+        let (reply, outcome) = oneshot::channel();
+        lane.pending
+            .lock()
+            .expect("sequencer lock is not poisoned")
+            .push(Pending { operation, reply });
+
+        // Whoever holds the lane executes everything queued so far. Once we get hold of it,
+        // our operation has either been executed by a previous holder or is still queued.
+        {
+            let _running = lane.running.lock().await;
+            loop {
+                let batch = {
+                    let mut pending = lane.pending.lock().expect("sequencer lock is not poisoned");
+                    let len = pending.len().min(self.max_batch_size);
+                    pending.drain(..len).collect::<Vec<_>>()
+                };
+                if batch.is_empty() {
+                    break;
+                }
+                self.execute(client, federation_id, batch).await;
+            }
+        }
+        self.release(federation_id, lane);
+
+        outcome.await.unwrap_or_else(|_| {
+            Err(Arc::new(ClientError::ExecutionFailed {
+                reason: "sequenced operation was dropped before execution".to_string(),
+            }))
+        })
+    }
+
+    /// Executes `batch` in one transaction and replies to every queued operation.
+    ///
+    /// If a batch of several operations aborts in Move, none of them took effect and they are
+    /// executed one by one, so only the failing ones are reported as failed. Any other error,
+    /// e.g. a network error that may have occurred after the batch was submitted, is reported to
+    /// every operation of the batch without executing it again.
+    async fn execute<S>(&self, client: &HierarchiesClient<S>, federation_id: ObjectID, batch: Vec<Pending>)
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let operations: Vec<_> = batch.iter().map(|pending| pending.operation.clone()).collect();
+        let outcome = match self.execute_operations(client, federation_id, &operations).await {
+            Err(ClientError::Transaction(_)) if batch.len() > 1 => {
+                for pending in batch {
+                    let outcome = self
+                        .execute_operations(client, federation_id, std::slice::from_ref(&pending.operation))
+                        .await
+                        .map_err(Arc::new);
+                    let _ = pending.reply.send(outcome);
+                }
+                return;
+            }
+            outcome => outcome.map_err(Arc::new),
+        };
+
+        for pending in batch {
+            let _ = pending.reply.send(outcome.clone());
+        }
+    }
+
+    /// Executes `operations` in a single transaction, resubmitting it while it's certain that it
+    /// wasn't executed.
+    async fn execute_operations<S>(
+        &self,
+        client: &HierarchiesClient<S>,
+        federation_id: ObjectID,
+        operations: &[BatchOperation],
+    ) -> Result<(), ClientError>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        self.retry_policy
+            .run_transaction(|| async {
+                operations
+                    .iter()
+                    .cloned()
+                    .fold(client.transaction_batch(federation_id), |batch, operation| {
+                        batch.push(operation)
+                    })
+                    .into_builder()
+                    .build_and_execute(client)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| execution_error(MoveAbortMapper::new(), e))
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lanes_are_per_federation() {
+        let sequencer = TransactionSequencer::new().with_max_batch_size(0);
+        let federation_a = ObjectID::random();
+        let federation_b = ObjectID::random();
+
+        assert!(Arc::ptr_eq(
+            &sequencer.lane(federation_a),
+            &sequencer.lane(federation_a)
+        ));
+        assert!(!Arc::ptr_eq(
+            &sequencer.lane(federation_a),
+            &sequencer.lane(federation_b)
+        ));
+        assert_eq!(sequencer.max_batch_size, 1);
+    }
+
+    #[test]
+    fn drained_lanes_are_dropped() {
+        let sequencer = TransactionSequencer::new();
+        let federation_a = ObjectID::random();
+        let federation_b = ObjectID::random();
+
+        let lane = sequencer.lane(federation_a);
+        let other = sequencer.lane(federation_a);
+        sequencer.release(federation_a, other);
+        assert_eq!(sequencer.lanes.lock().unwrap().len(), 1);
+
+        sequencer.release(federation_a, lane);
+        assert!(sequencer.lanes.lock().unwrap().is_empty());
+
+        // A lane whose submission was cancelled is dropped when the next lane is created
+        drop(sequencer.lane(federation_a));
+        let _lane = sequencer.lane(federation_b);
+        assert_eq!(sequencer.lanes.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sponsored execution of transactions through an IOTA gas station.
+//!
+//! Receivers of accreditations often hold no IOTA to pay for gas. With the `gas-station` feature,
+//! every transaction builder returned by [`HierarchiesClient`] can be executed through a
+//! [`GasStation`], which reserves the gas coins and pays for the transaction while the client's
+//! signer remains its sender.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use hierarchies::client::{GasStation, SponsoredExecution};
+//!
+//! let gas_station = GasStation::new(gas_station_url, reqwest::Client::new());
+//! client
+//!     .create_accreditation_to_attest(federation_id, receiver, properties)
+//!     .build_and_execute_sponsored(&client, &gas_station)
+//!     .await?;
+//! ```
+
+use async_trait::async_trait;
+use iota_interaction::{IotaKeySignature, OptionalSend, OptionalSync};
+use product_common::gas_station::{GasStationError, GasStationOptions};
+use product_common::http_client::HttpClient;
+use product_common::transaction::TransactionOutput;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
+use thiserror::Error;
+use url::Url;
+
+use crate::client::{ClientError, HierarchiesClient};
+use crate::core::transactions::{MoveAbortMapper, TransactionError};
+
+/// A gas station paying for the transactions of a [`HierarchiesClient`].
+#[derive(Debug, Clone)]
+pub struct GasStation<H> {
+    url: Url,
+    http_client: H,
+    options: GasStationOptions,
+}
+
+impl<H> GasStation<H> {
+    /// Creates a [`GasStation`] reached at `url` through `http_client`, with default options.
+    pub fn new(url: Url, http_client: H) -> Self {
+        Self {
+            url,
+            http_client,
+            options: GasStationOptions::default(),
+        }
+    }
+
+    /// Sets the options of the gas reservations, e.g. their duration.
+    pub fn with_options(mut self, options: GasStationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the URL of the gas station.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+/// Errors of a sponsored execution.
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum SponsorshipError {
+    /// The gas station couldn't reserve gas for or execute the transaction
+    #[error("gas station sponsorship failed")]
+    GasStation(#[source] GasStationError),
+
+    /// The sponsored transaction was executed and aborted
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+}
+
+impl From<GasStationError> for SponsorshipError {
+    fn from(error: GasStationError) -> Self {
+        MoveAbortMapper::new()
+            .map_error(&error)
+            .map(Self::Transaction)
+            .unwrap_or(Self::GasStation(error))
+    }
+}
+
+/// Executes a transaction builder through a [`GasStation`].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait SponsoredExecution {
+    /// The output of the executed transaction.
+    type Output;
+
+    /// Builds the transaction with `client` as its sender, has `gas_station` pay for it and
+    /// executes it.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`ClientError::Sponsorship`] if the gas station rejects or fails the
+    /// transaction. Move aborts are reported as [`SponsorshipError::Transaction`].
+    async fn build_and_execute_sponsored<S, H>(
+        self,
+        client: &HierarchiesClient<S>,
+        gas_station: &GasStation<H>,
+    ) -> Result<TransactionOutput<Self::Output>, ClientError>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+        H: HttpClient + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> SponsoredExecution for TransactionBuilder<Tx>
+where
+    Tx: Transaction + OptionalSend,
+    Tx::Output: OptionalSend,
+{
+    type Output = Tx::Output;
+
+    async fn build_and_execute_sponsored<S, H>(
+        self,
+        client: &HierarchiesClient<S>,
+        gas_station: &GasStation<H>,
+    ) -> Result<TransactionOutput<Self::Output>, ClientError>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+        H: HttpClient + OptionalSync,
+    {
+        self.build_and_execute_with_gas_station(
+            client,
+            &gas_station.url,
+            &gas_station.http_client,
+            gas_station.options.clone(),
+        )
+        .await
+        .map_err(|error| ClientError::Sponsorship(error.into()))
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tolerance of validations evaluated by the client.
+//!
+//! On-chain validation compares timespans with the network clock. Point-in-time checks, like
+//! [`validate_property_at`](super::HierarchiesClientReadOnly::validate_property_at), and offline
+//! checks of [`crate::core::offline`] compare them with a time given by the caller that may be off
+//! by a few seconds, e.g. an attestation that became valid just now on the issuer's clock. A
+//! [`ValidationConfig`] widens every timespan by a clock skew tolerance for these checks, so such
+//! attestations don't fail spuriously.
+//!
+//! Checks at the network clock, like
+//! [`validate_properties_report`](super::HierarchiesClientReadOnly::validate_properties_report),
+//! use the same clock as on-chain validation and are not widened, so they agree with it.
+//!
+//! ```rust,ignore
+//! let client = client.with_validation_config(
+//!     ValidationConfig::default().with_clock_skew_tolerance(Duration::from_secs(5)),
+//! );
+//! ```
+//!
+//! The tolerance applies to both ends of a timespan, so a revoked property or an expired
+//! accreditation also stays valid for the tolerance. Objection windows are not affected.
+
+use std::time::Duration;
+
+use crate::core::types::timespan::Timespan;
+use crate::core::types::{Accreditations, Federation};
+
+/// The configuration of validations evaluated by the client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidationConfig {
+    /// The difference between clocks tolerated when comparing timestamps with timespans
+    pub clock_skew_tolerance: Duration,
+}
+
+impl ValidationConfig {
+    pub fn with_clock_skew_tolerance(mut self, clock_skew_tolerance: Duration) -> Self {
+        self.clock_skew_tolerance = clock_skew_tolerance;
+        self
+    }
+
+    /// Returns `timespan` widened by the clock skew tolerance at both ends.
+    pub fn tolerate(&self, timespan: &Timespan) -> Timespan {
+        let tolerance_ms = self.tolerance_ms();
+        Timespan {
+            valid_from_ms: timespan.valid_from_ms.map(|from| from.saturating_sub(tolerance_ms)),
+            valid_until_ms: timespan.valid_until_ms.map(|until| until.saturating_add(tolerance_ms)),
+        }
+    }
+
+    /// Returns `federation` with every timespan of its properties and accreditations widened by
+    /// the clock skew tolerance, ready to be validated by [`crate::core::offline`].
+    pub fn apply(&self, mut federation: Federation) -> Federation {
+        if self.clock_skew_tolerance.is_zero() {
+            return federation;
+        }
+
+        let governance = &mut federation.governance;
+        for property in governance.properties.data.values_mut() {
+            property.timespan = self.tolerate(&property.timespan);
+        }
+        for accreditations in governance
+            .accreditations_to_attest
+            .values_mut()
+            .chain(governance.accreditations_to_accredit.values_mut())
+        {
+            self.tolerate_accreditations(accreditations);
+        }
+        federation
+    }
+
+    fn tolerate_accreditations(&self, accreditations: &mut Accreditations) {
+        for accreditation in &mut accreditations.accreditations {
+            for property in accreditation.properties.values_mut() {
+                property.timespan = self.tolerate(&property.timespan);
+            }
+        }
+    }
+
+    fn tolerance_ms(&self) -> u64 {
+        u64::try_from(self.clock_skew_tolerance.as_millis()).unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tolerate_widens_both_ends() {
+        let config = ValidationConfig::default().with_clock_skew_tolerance(Duration::from_secs(2));
+        let timespan = Timespan {
+            valid_from_ms: Some(10_000),
+            valid_until_ms: Some(20_000),
+        };
+
+        let tolerated = config.tolerate(&timespan);
+        assert!(!timespan.timestamp_matches(9_000));
+        assert!(tolerated.timestamp_matches(8_000));
+        assert!(tolerated.timestamp_matches(21_999));
+        assert!(!tolerated.timestamp_matches(22_000));
+
+        let open = Timespan {
+            valid_from_ms: Some(1_000),
+            valid_until_ms: None,
+        };
+        assert_eq!(config.tolerate(&open).valid_from_ms, Some(0));
+        assert_eq!(config.tolerate(&open).valid_until_ms, None);
+    }
+}
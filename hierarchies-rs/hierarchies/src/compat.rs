@@ -0,0 +1,402 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Compatibility
+//!
+//! A machine-readable changelog of the SDK changes that affect the on-chain schema, i.e. the layout of
+//! the Move types the SDK decodes and the Move functions it calls.
+//!
+//! Every entry of [`changelog`] bumps the [`schema_version`] and introduces a [`FeatureFlag`].
+//! Applications and conformance suites can assert at runtime that the SDK build provides the features
+//! they rely on, and check the Move function of each feature against the deployed package, instead of
+//! discovering mismatches through BCS failures.
+//!
+//! ```rust
+//! use hierarchies::compat::{self, FeatureFlag};
+//!
+//! assert!(compat::supports(FeatureFlag::GrantPolicy));
+//! assert!(compat::schema_version() >= FeatureFlag::GrantPolicy.since());
+//! ```
+//!
+//! ## Deployment
+//!
+//! Most entries add fields to structs or variants to enums of the package, e.g. `Governance`,
+//! `GrantPolicy` or `PropertyRevokedEvent`. The layout of a published struct is fixed, so these
+//! changes are not upgrade-compatible: the package of this SDK can't be published as an upgrade of
+//! the packages of [`PUBLISHED_SCHEMA_VERSION`] listed in `hierarchies-move/Move.history.json`, and
+//! this SDK can't decode the federations and events of those packages.
+//!
+//! The package is therefore deployed fresh, with `hierarchies-move/scripts/publish_hierarchies.sh`,
+//! and registered as a history of its own, see [`PackageOverrides`](crate::package::PackageOverrides).
+//! Federations of the published packages keep working with the SDK release matching their schema
+//! and are migrated by recreating them:
+//!
+//! 1. Read the federation, its properties, root authorities and accreditations with the SDK release of the published
+//!    package.
+//! 2. Recreate it on the fresh package with a [`FederationBuilder`](crate::client::FederationBuilder) of this SDK.
+//! 3. Hand the new capabilities to the root authorities and attesters, e.g. by adding them as root authorities and
+//!    granting the accreditations to their accounts.
+//! 4. Point relying parties to the new federation ID. Attestations issued under the old federation are validated
+//!    against it until they are reissued.
+//!
+//! Clients read the schema of their package when they are created, from its `schema_version` view.
+//! The published packages lack the view and are taken to be of [`PUBLISHED_SCHEMA_VERSION`]. Clients
+//! of an older package keep working for the Move functions it shares with this SDK, while operations
+//! relying on a feature the package lacks, or on decoding its federations, fail with
+//! [`ConfigError::IncompatibleSchema`](crate::error::ConfigError::IncompatibleSchema) instead of
+//! failing on a missing Move function or a BCS mismatch, see
+//! [`HierarchiesClientReadOnly::supports`](crate::client::HierarchiesClientReadOnly::supports).
+//! Until a package of this schema is registered in `Move.history.json`, clients of mainnet, testnet
+//! and devnet need a package ID of their own to use every feature.
+//!
+//! Once a package of this schema is published, further schema changes have to keep the published
+//! layouts and store new state in dynamic fields of the federation, as attestations already do.
+
+/// A schema-affecting feature of the SDK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum FeatureFlag {
+    /// Properties can be marked as critical.
+    CriticalProperties,
+    /// Properties can reference federation-level value dictionaries.
+    ValueDictionaries,
+    /// Federations have a grant policy with defaults and limits for new accreditations.
+    GrantPolicy,
+    /// Properties and accreditations can be tagged.
+    Tags,
+    /// Federations keep summary statistics on-chain.
+    FederationStats,
+    /// `Between`, `EqualTo` and `NotEqualTo` property shapes.
+    RangeAndEqualityShapes,
+    /// `All`, `Any` and `Not` property shapes.
+    CompositeShapes,
+    /// New accreditations can be delayed by an objection window.
+    ObjectionWindow,
+    /// `MatchesPattern` property shape.
+    PatternShapes,
+    /// `Bool`, `Timestamp` and `Bytes` property values.
+    TypedValues,
+    /// Accreditations can be read page by page with filters.
+    PagedAccreditations,
+    /// Properties can be queried by name prefix.
+    PropertyPrefixQueries,
+    /// Accreditations can be granted for a property namespace with a wildcard name.
+    WildcardAccreditations,
+    /// Revocations can require the approval of a quorum of root authorities.
+    QuorumGovernance,
+    /// Federations carry a human-readable name, description, logo URI and contact.
+    FederationMetadata,
+    /// Properties carry human-readable schema information such as a description or unit.
+    PropertyMetadata,
+    /// Capabilities can be transferred to a new address, optionally confirmed by another root authority.
+    CapabilityTransfer,
+    /// Revocations can record a reason code and annotation.
+    RevocationReasons,
+    /// Attesters can issue attestations of properties as objects owned by the receiver.
+    Attestations,
+    /// Properties can be read page by page.
+    PagedProperties,
+    /// A root authority can be handed over to another account in a single transaction.
+    RootAuthorityRotation,
+    /// Federations can recognize child federations, whose trust chains are followed across the link.
+    FederationLinks,
+    /// Properties can be deprecated in favour of a replacement, validation reports warn about them.
+    PropertyDeprecation,
+    /// Value dictionaries, property deprecations and federation unlinks go through the quorum.
+    QuorumConfigurationActions,
+}
+
+impl FeatureFlag {
+    /// Returns the schema version that introduced the feature.
+    pub fn since(self) -> u32 {
+        change(self).version
+    }
+
+    /// Returns the Move function introduced with the feature, as `(module, function)`.
+    pub fn move_function(self) -> (&'static str, &'static str) {
+        change(self).move_function
+    }
+}
+
+/// An entry of the changelog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// The schema version introduced by the change.
+    pub version: u32,
+    /// The feature introduced by the change.
+    pub feature: FeatureFlag,
+    /// A Move function introduced by the change, as `(module, function)`.
+    ///
+    /// Its presence in the deployed package shows that the package supports the feature.
+    pub move_function: (&'static str, &'static str),
+    /// What changed.
+    pub description: &'static str,
+}
+
+/// The schema version of the initial package, before any entry of the changelog.
+pub const INITIAL_SCHEMA_VERSION: u32 = 1;
+
+/// The schema version of the packages published on mainnet, testnet and devnet, see
+/// [Deployment](self#deployment).
+///
+/// [`features_since`] of this version lists the features the published packages lack.
+pub const PUBLISHED_SCHEMA_VERSION: u32 = INITIAL_SCHEMA_VERSION;
+
+const CHANGELOG: &[SchemaChange] = &[
+    SchemaChange {
+        version: 2,
+        feature: FeatureFlag::CriticalProperties,
+        move_function: ("main", "set_property_critical"),
+        description: "Adds `critical_properties` to `Governance` and `critical` to `PropertyRevokedEvent`.",
+    },
+    SchemaChange {
+        version: 3,
+        feature: FeatureFlag::ValueDictionaries,
+        move_function: ("main", "set_value_dictionary"),
+        description: "Adds `value_dictionary` to `FederationProperty` and `value_dictionaries` to `Governance`.",
+    },
+    SchemaChange {
+        version: 4,
+        feature: FeatureFlag::GrantPolicy,
+        move_function: ("main", "set_grant_policy"),
+        description: "Adds `grant_policy` to `Governance`.",
+    },
+    SchemaChange {
+        version: 5,
+        feature: FeatureFlag::Tags,
+        move_function: ("main", "set_property_tags"),
+        description: "Adds `property_tags` and `accreditation_tags` to `Governance`.",
+    },
+    SchemaChange {
+        version: 6,
+        feature: FeatureFlag::FederationStats,
+        move_function: ("main", "get_stats"),
+        description: "Adds `stats` to `Federation`.",
+    },
+    SchemaChange {
+        version: 7,
+        feature: FeatureFlag::RangeAndEqualityShapes,
+        move_function: ("property_shape", "new_property_shape_between"),
+        description: "Appends `Between`, `EqualTo` and `NotEqualTo` to `PropertyShape`.",
+    },
+    SchemaChange {
+        version: 8,
+        feature: FeatureFlag::CompositeShapes,
+        move_function: ("property_shape", "new_property_shape_composite"),
+        description: "Appends `Composite` to `PropertyShape` and adds `ShapeNode`.",
+    },
+    SchemaChange {
+        version: 9,
+        feature: FeatureFlag::ObjectionWindow,
+        move_function: ("main", "activate_accreditation"),
+        description: "Adds `objection_window_ms` to `GrantPolicy` and `pending_until_ms` to `Accreditation`.",
+    },
+    SchemaChange {
+        version: 10,
+        feature: FeatureFlag::PatternShapes,
+        move_function: ("property_shape", "new_property_shape_matches_pattern"),
+        description: "Appends `MatchesPattern` to `PropertyShape` and `ShapeNode`.",
+    },
+    SchemaChange {
+        version: 11,
+        feature: FeatureFlag::TypedValues,
+        move_function: ("property_value", "new_property_value_bool"),
+        description: "Appends `Bool`, `Timestamp` and `Bytes` to `PropertyValue`.",
+    },
+    SchemaChange {
+        version: 12,
+        feature: FeatureFlag::PagedAccreditations,
+        move_function: ("main", "get_accreditations_to_attest_page"),
+        description: "Adds the `AccreditationPage` views of the accreditations of an entity.",
+    },
+    SchemaChange {
+        version: 13,
+        feature: FeatureFlag::PropertyPrefixQueries,
+        move_function: ("main", "get_properties_by_prefix"),
+        description: "Adds `property_name::starts_with` and the `get_properties_by_prefix` view.",
+    },
+    SchemaChange {
+        version: 14,
+        feature: FeatureFlag::WildcardAccreditations,
+        move_function: ("property_name", "new_property_name_prefix"),
+        description: "Accredited wildcard names like `degree.*` cover every property of their namespace.",
+    },
+    SchemaChange {
+        version: 15,
+        feature: FeatureFlag::QuorumGovernance,
+        move_function: ("main", "propose_action"),
+        description: "Adds `quorum` to `Governance`; destructive actions are proposed and approved by root authorities.",
+    },
+    SchemaChange {
+        version: 16,
+        feature: FeatureFlag::FederationMetadata,
+        move_function: ("main", "set_federation_metadata"),
+        description: "Adds `metadata` to `Federation`.",
+    },
+    SchemaChange {
+        version: 17,
+        feature: FeatureFlag::PropertyMetadata,
+        move_function: ("main", "set_property_metadata"),
+        description: "Adds `metadata` to `FederationProperty`.",
+    },
+    SchemaChange {
+        version: 18,
+        feature: FeatureFlag::CapabilityTransfer,
+        move_function: ("main", "transfer_root_authority_cap"),
+        description: "Adds `transfer_confirmations` to `Quorum`.",
+    },
+    SchemaChange {
+        version: 19,
+        feature: FeatureFlag::RevocationReasons,
+        move_function: ("main", "revoke_property_with_reason"),
+        description: "Adds `RevocationReasonEvent`.",
+    },
+    SchemaChange {
+        version: 20,
+        feature: FeatureFlag::Attestations,
+        move_function: ("main", "create_attestation"),
+        description: "Adds the `Attestation` object, `AttestationCreatedEvent` and `AttestationRevokedEvent`.",
+    },
+    SchemaChange {
+        version: 21,
+        feature: FeatureFlag::PagedProperties,
+        move_function: ("main", "get_properties_page"),
+        description: "Adds the `PropertyPage` view of the properties of a federation.",
+    },
+    SchemaChange {
+        version: 22,
+        feature: FeatureFlag::RootAuthorityRotation,
+        move_function: ("main", "rotate_root_authority"),
+        description: "Adds `rotate_root_authority`, handing a root authority over in a single call.",
+    },
+    SchemaChange {
+        version: 23,
+        feature: FeatureFlag::FederationLinks,
+        move_function: ("main", "link_federation"),
+        description: "Adds `recognized_federations` to `Governance`, `FederationLinkedEvent` and `FederationUnlinkedEvent`.",
+    },
+    SchemaChange {
+        version: 24,
+        feature: FeatureFlag::PropertyDeprecation,
+        move_function: ("main", "deprecate_property"),
+        description: "Adds `deprecated_properties` to `Governance` and `PropertyDeprecatedEvent`.",
+    },
+    SchemaChange {
+        version: 25,
+        feature: FeatureFlag::QuorumConfigurationActions,
+        move_function: ("main", "new_action_set_value_dictionary"),
+        description: "Appends `SetValueDictionary`, `DeprecateProperty` and `UnlinkFederation` to `GovernanceAction`.",
+    },
+];
+
+/// Returns the schema-affecting changes of the SDK, oldest first.
+pub fn changelog() -> &'static [SchemaChange] {
+    CHANGELOG
+}
+
+/// Returns the schema version this SDK build decodes and encodes.
+pub fn schema_version() -> u32 {
+    CHANGELOG.last().map_or(INITIAL_SCHEMA_VERSION, |change| change.version)
+}
+
+/// Returns true if this SDK build supports `feature`.
+pub fn supports(feature: FeatureFlag) -> bool {
+    CHANGELOG.iter().any(|change| change.feature == feature)
+}
+
+/// Returns the features introduced after `version`, i.e. those a package of that schema version lacks.
+pub fn features_since(version: u32) -> impl Iterator<Item = FeatureFlag> {
+    CHANGELOG
+        .iter()
+        .filter(move |change| change.version > version)
+        .map(|change| change.feature)
+}
+
+fn change(feature: FeatureFlag) -> &'static SchemaChange {
+    CHANGELOG
+        .iter()
+        .find(|change| change.feature == feature)
+        .expect("every feature has a changelog entry")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_changelog_versions_increase_by_one() {
+        let mut version = INITIAL_SCHEMA_VERSION;
+        for change in changelog() {
+            assert_eq!(change.version, version + 1, "{:?}", change.feature);
+            version = change.version;
+        }
+        assert_eq!(schema_version(), version);
+    }
+
+    #[test]
+    fn test_features_have_a_single_entry() {
+        let features: HashSet<FeatureFlag> = changelog().iter().map(|change| change.feature).collect();
+        assert_eq!(features.len(), changelog().len());
+        assert!(features.iter().all(|feature| supports(*feature)));
+    }
+
+    #[test]
+    fn test_move_functions_exist_in_package() {
+        let sources = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../hierarchies-move/sources");
+        for change in changelog() {
+            let (module, function) = change.move_function;
+            let file = if module == "main" { "hierarchies" } else { module };
+            let source = std::fs::read_to_string(sources.join(format!("{file}.move"))).unwrap();
+            assert!(
+                source.contains(&format!("public fun {function}(")),
+                "`{module}::{function}` of {:?} not found",
+                change.feature
+            );
+        }
+    }
+
+    #[test]
+    fn test_move_schema_version_matches_changelog() {
+        let source = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../hierarchies-move/sources/hierarchies.move"),
+        )
+        .unwrap();
+        assert!(source.contains(&format!("const SCHEMA_VERSION: u64 = {};", schema_version())));
+    }
+
+    #[test]
+    fn test_features_since() {
+        assert_eq!(
+            features_since(FeatureFlag::CompositeShapes.since()).collect::<Vec<_>>(),
+            [
+                FeatureFlag::ObjectionWindow,
+                FeatureFlag::PatternShapes,
+                FeatureFlag::TypedValues,
+                FeatureFlag::PagedAccreditations,
+                FeatureFlag::PropertyPrefixQueries,
+                FeatureFlag::WildcardAccreditations,
+                FeatureFlag::QuorumGovernance,
+                FeatureFlag::FederationMetadata,
+                FeatureFlag::PropertyMetadata,
+                FeatureFlag::CapabilityTransfer,
+                FeatureFlag::RevocationReasons,
+                FeatureFlag::Attestations,
+                FeatureFlag::PagedProperties,
+                FeatureFlag::RootAuthorityRotation,
+                FeatureFlag::FederationLinks,
+                FeatureFlag::PropertyDeprecation,
+                FeatureFlag::QuorumConfigurationActions
+            ]
+        );
+        assert_eq!(features_since(schema_version()).count(), 0);
+    }
+
+    #[test]
+    fn test_published_packages_need_a_fresh_deployment() {
+        // Every change since the published schema alters published layouts
+        assert!(schema_version() > PUBLISHED_SCHEMA_VERSION);
+        assert_eq!(features_since(PUBLISHED_SCHEMA_VERSION).count(), changelog().len());
+    }
+}
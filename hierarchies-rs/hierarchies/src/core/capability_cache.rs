@@ -0,0 +1,166 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Capability Cache
+//!
+//! Without a capability ID given up front, every transaction build scans the objects owned by
+//! the signer for its `RootAuthorityCap` or `AccreditCap`. The scan walks all package versions
+//! and dominates the build latency of busy issuers.
+//!
+//! The process-wide [`CapabilityCache`] remembers the ID of the capability found for an
+//! (owner, federation, kind) triple. Only IDs are cached: the object reference is fetched on
+//! every use, so the current version of the capability is always referenced. An entry is
+//! dropped and the scan repeated if the capability no longer exists or is no longer owned
+//! by the owner, e.g. after a transfer.
+//!
+//! The cache is enabled by default and can be disabled with [`CapabilityCache::set_enabled`].
+//! [`HierarchiesClient::refresh_capabilities`](crate::client::HierarchiesClient::refresh_capabilities)
+//! re-populates the entries of a signer.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+
+use crate::core::types::CapabilityKind;
+
+static CAPABILITY_CACHE: LazyLock<CapabilityCache> = LazyLock::new(CapabilityCache::new);
+
+/// Returns the process-wide capability cache.
+pub fn capability_cache() -> &'static CapabilityCache {
+    &CAPABILITY_CACHE
+}
+
+/// Identifies a cached capability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapabilityKey {
+    /// The address owning the capability
+    pub owner: IotaAddress,
+    /// The federation the capability belongs to
+    pub federation_id: ObjectID,
+    /// The kind of the capability
+    pub kind: CapabilityKind,
+}
+
+impl CapabilityKey {
+    pub fn new(owner: IotaAddress, federation_id: ObjectID, kind: CapabilityKind) -> Self {
+        Self {
+            owner,
+            federation_id,
+            kind,
+        }
+    }
+}
+
+/// Maps owners, federations and capability kinds to the IDs of the capability objects.
+#[derive(Debug)]
+pub struct CapabilityCache {
+    entries: Mutex<HashMap<CapabilityKey, ObjectID>>,
+    enabled: AtomicBool,
+}
+
+impl CapabilityCache {
+    fn new() -> Self {
+        Self {
+            entries: Mutex::default(),
+            enabled: AtomicBool::new(true),
+        }
+    }
+
+    /// Enables or disables the cache. Disabling it drops all entries.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+        if !enabled {
+            self.clear();
+        }
+    }
+
+    /// Returns true if capabilities are cached.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Returns the ID of the capability cached for `key`, if any.
+    pub fn get(&self, key: &CapabilityKey) -> Option<ObjectID> {
+        if !self.is_enabled() {
+            return None;
+        }
+        self.entries().get(key).copied()
+    }
+
+    /// Caches `capability_id` for `key`, unless the cache is disabled.
+    pub fn insert(&self, key: CapabilityKey, capability_id: ObjectID) {
+        if self.is_enabled() {
+            self.entries().insert(key, capability_id);
+        }
+    }
+
+    /// Drops the entry of `key`.
+    pub fn invalidate(&self, key: &CapabilityKey) {
+        self.entries().remove(key);
+    }
+
+    /// Drops all entries of `owner`.
+    pub fn invalidate_owner(&self, owner: IotaAddress) {
+        self.entries().retain(|key, _| key.owner != owner);
+    }
+
+    /// Drops all entries.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    /// Returns the number of cached capabilities.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Returns true if no capability is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<CapabilityKey, ObjectID>> {
+        self.entries.lock().expect("capability cache lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalidate_owner_keeps_other_owners() {
+        let cache = CapabilityCache::new();
+        let alice = IotaAddress::from(ObjectID::random());
+        let bob = IotaAddress::from(ObjectID::random());
+        let federation_id = ObjectID::random();
+        let alice_key = CapabilityKey::new(alice, federation_id, CapabilityKind::RootAuthority);
+        let bob_key = CapabilityKey::new(bob, federation_id, CapabilityKind::Accredit);
+
+        cache.insert(alice_key, ObjectID::random());
+        cache.insert(bob_key, ObjectID::random());
+        cache.invalidate_owner(alice);
+
+        assert_eq!(cache.get(&alice_key), None);
+        assert!(cache.get(&bob_key).is_some());
+    }
+
+    #[test]
+    fn test_disabled_cache_stores_nothing() {
+        let cache = CapabilityCache::new();
+        let key = CapabilityKey::new(
+            IotaAddress::from(ObjectID::random()),
+            ObjectID::random(),
+            CapabilityKind::RootAuthority,
+        );
+
+        cache.insert(key, ObjectID::random());
+        cache.set_enabled(false);
+        assert!(cache.is_empty());
+
+        cache.insert(key, ObjectID::random());
+        assert_eq!(cache.get(&key), None);
+    }
+}
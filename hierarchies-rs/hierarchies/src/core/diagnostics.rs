@@ -0,0 +1,717 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # BCS Diagnostics
+//!
+//! BCS is not self-describing, so when the on-chain layout of a Move type diverges
+//! from its Rust mirror (typically after a package upgrade) decoding fails with
+//! errors like `unexpected end of input` that don't tell which field is affected.
+//!
+//! This module decodes BCS with a deserializer that tracks the field path and byte
+//! offset of every value:
+//! - [`decode`] reports failures as a [`BcsDiagnostic`] with the path of the first field that could not be decoded and
+//!   hints about the expected and actual layout;
+//! - [`try_parse_partial`] additionally returns every field decoded before the failure.
+//!
+//! ```ignore
+//! let partial = try_parse_partial::<Federation>(&bytes);
+//! if let Some(diagnostic) = &partial.diagnostic {
+//!     eprintln!("{diagnostic}");
+//! }
+//! for field in &partial.fields {
+//!     println!("{} = {}", field.path, field.value);
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::de::value::U32Deserializer;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+
+/// The maximum nesting depth BCS allows for containers.
+const MAX_CONTAINER_DEPTH: usize = 500;
+
+/// The number of bytes shown as the actual layout of a failure.
+const ACTUAL_BYTES: usize = 16;
+
+/// Describes where and why decoding a BCS value failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BcsDiagnostic {
+    /// The Rust type that was decoded.
+    pub type_name: &'static str,
+    /// The path of the first field that could not be decoded,
+    /// e.g. `governance.properties.data.contents[2].value.shape`.
+    /// Empty if the failure is not inside a field.
+    pub path: String,
+    /// The byte offset at which decoding failed.
+    pub offset: usize,
+    /// The total number of bytes.
+    pub len: usize,
+    /// The layout the Rust type expected at `path`.
+    pub expected: String,
+    /// The hex encoded bytes found at `offset`, at most 16.
+    pub actual: String,
+    /// The decoding error.
+    pub message: String,
+}
+
+impl fmt::Display for BcsDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            self.path.as_str()
+        };
+        write!(
+            f,
+            "failed to decode `{}` at `{path}` (byte {} of {}): {}; expected {}, found {}",
+            self.type_name, self.offset, self.len, self.message, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for BcsDiagnostic {}
+
+/// A leaf value decoded by [`try_parse_partial`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedField {
+    /// The path of the value, e.g. `governance.critical_properties.contents[0].names[1]`.
+    pub path: String,
+    /// The decoded value, formatted for humans.
+    pub value: String,
+}
+
+/// The result of [`try_parse_partial`].
+#[derive(Debug, Clone)]
+pub struct PartialDecode<T> {
+    /// The decoded value, if decoding succeeded.
+    pub value: Option<T>,
+    /// The leaf values decoded before decoding stopped, in layout order.
+    pub fields: Vec<DecodedField>,
+    /// Why decoding failed, if it did.
+    pub diagnostic: Option<BcsDiagnostic>,
+}
+
+/// Decodes `bytes` as `T`, reporting failures as a [`BcsDiagnostic`].
+///
+/// Like [`bcs::from_bytes`], trailing bytes are an error. They usually mean that the
+/// on-chain type has more fields than `T`.
+pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, BcsDiagnostic> {
+    let mut walker = Walker::new(bytes, false);
+    let result = walker.run::<T>();
+    result.map_err(|err| walker.diagnostic::<T>(err))
+}
+
+/// Decodes as much of `bytes` as possible as `T`.
+///
+/// BCS values are laid out sequentially, so the fields decoded before the first
+/// failure are reliable while everything after it is not.
+pub fn try_parse_partial<T: DeserializeOwned>(bytes: &[u8]) -> PartialDecode<T> {
+    let mut walker = Walker::new(bytes, true);
+    let (value, diagnostic) = match walker.run::<T>() {
+        Ok(value) => (Some(value), None),
+        Err(err) => (None, Some(walker.diagnostic::<T>(err))),
+    };
+
+    PartialDecode {
+        value,
+        fields: walker.fields,
+        diagnostic,
+    }
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    Field(&'static str),
+    Index(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Failure {
+    path: String,
+    offset: usize,
+    expected: String,
+}
+
+/// A BCS deserializer tracking the position of every value.
+struct Walker<'de> {
+    input: &'de [u8],
+    offset: usize,
+    path: Vec<Segment>,
+    /// The layout requested last, used when a visitor rejects a decoded value.
+    expecting: &'static str,
+    record: bool,
+    fields: Vec<DecodedField>,
+    failure: Option<Failure>,
+}
+
+impl<'de> Walker<'de> {
+    fn new(input: &'de [u8], record: bool) -> Self {
+        Self {
+            input,
+            offset: 0,
+            path: Vec::new(),
+            expecting: "a value",
+            record,
+            fields: Vec::new(),
+            failure: None,
+        }
+    }
+
+    fn run<T: DeserializeOwned>(&mut self) -> Result<T, Error> {
+        let value = T::deserialize(&mut *self)?;
+        let remaining = self.input.len() - self.offset;
+        if remaining > 0 {
+            return Err(self.fail(
+                "end of input",
+                format!("{remaining} bytes remain after a complete value, the on-chain type likely has more fields"),
+            ));
+        }
+        Ok(value)
+    }
+
+    fn diagnostic<T>(&self, err: Error) -> BcsDiagnostic {
+        let failure = self.failure.clone().unwrap_or_else(|| Failure {
+            path: self.render_path(),
+            offset: self.offset,
+            expected: self.expecting.to_string(),
+        });
+        let start = failure.offset.min(self.input.len());
+        let end = (start + ACTUAL_BYTES).min(self.input.len());
+        let actual = if start == end {
+            "end of input".to_string()
+        } else {
+            let hex: String = self.input[start..end]
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            format!("0x{hex}{}", if end < self.input.len() { "..." } else { "" })
+        };
+
+        BcsDiagnostic {
+            type_name: std::any::type_name::<T>(),
+            path: failure.path,
+            offset: failure.offset,
+            len: self.input.len(),
+            expected: failure.expected,
+            actual,
+            message: err.0,
+        }
+    }
+
+    fn render_path(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                Segment::Field(name) if path.is_empty() => path.push_str(name),
+                Segment::Field(name) => {
+                    path.push('.');
+                    path.push_str(name);
+                }
+                Segment::Index(idx) => path.push_str(&format!("[{idx}]")),
+            }
+        }
+        path
+    }
+
+    /// Records the first failure and returns its error.
+    fn fail(&mut self, expected: impl Into<String>, message: impl Into<String>) -> Error {
+        if self.failure.is_none() {
+            self.failure = Some(Failure {
+                path: self.render_path(),
+                offset: self.offset,
+                expected: expected.into(),
+            });
+        }
+        Error(message.into())
+    }
+
+    /// Runs `f` for the value at `segment`, recording the failure of the innermost value.
+    fn scoped<R>(&mut self, segment: Segment, f: impl FnOnce(&mut Self) -> Result<R, Error>) -> Result<R, Error> {
+        if self.path.len() >= MAX_CONTAINER_DEPTH {
+            return Err(self.fail(
+                "a shallower value",
+                format!("exceeded the maximum container depth of {MAX_CONTAINER_DEPTH}"),
+            ));
+        }
+
+        self.path.push(segment);
+        let result = f(self);
+        if let Err(err) = &result {
+            let expecting = self.expecting;
+            let _ = self.fail(expecting, err.0.clone());
+        }
+        self.path.pop();
+        result
+    }
+
+    fn leaf(&mut self, value: impl FnOnce() -> String) {
+        if self.record {
+            self.fields.push(DecodedField {
+                path: self.render_path(),
+                value: value(),
+            });
+        }
+    }
+
+    fn take(&mut self, len: usize, expected: &'static str) -> Result<&'de [u8], Error> {
+        self.expecting = expected;
+        let Some(bytes) = self.input.get(self.offset..self.offset.saturating_add(len)) else {
+            return Err(self.fail(expected, "unexpected end of input"));
+        };
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn read_uleb(&mut self, expected: &'static str) -> Result<usize, Error> {
+        let start = self.offset;
+        let mut value: u64 = 0;
+        for shift in (0..35).step_by(7) {
+            let byte = self.take(1, expected)?[0];
+            let digit = u64::from(byte & 0x7f);
+            value |= digit << shift;
+            if byte & 0x80 == 0 {
+                if shift > 0 && digit == 0 {
+                    self.offset = start;
+                    return Err(self.fail(expected, "non-canonical ULEB128 encoding"));
+                }
+                if value > u64::from(u32::MAX) {
+                    self.offset = start;
+                    return Err(self.fail(expected, "ULEB128 value overflows u32"));
+                }
+                return Ok(value as usize);
+            }
+        }
+        self.offset = start;
+        Err(self.fail(expected, "ULEB128 value overflows u32"))
+    }
+
+    fn read_bytes(&mut self, expected: &'static str) -> Result<&'de [u8], Error> {
+        let len = self.read_uleb(expected)?;
+        self.take(len, expected)
+    }
+
+    fn unsupported(&mut self, what: &'static str) -> Error {
+        self.fail(
+            format!("a type BCS supports, not {what}"),
+            format!("BCS does not support {what}"),
+        )
+    }
+}
+
+macro_rules! deserialize_int {
+    ($method:ident, $ty:ty, $visit:ident) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            let bytes = self.take(std::mem::size_of::<$ty>(), stringify!($ty))?;
+            let value = <$ty>::from_le_bytes(bytes.try_into().expect("length was checked"));
+            self.leaf(|| value.to_string());
+            visitor.$visit(value)
+        }
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Walker<'de> {
+    type Error = Error;
+
+    deserialize_int!(deserialize_u8, u8, visit_u8);
+    deserialize_int!(deserialize_u16, u16, visit_u16);
+    deserialize_int!(deserialize_u32, u32, visit_u32);
+    deserialize_int!(deserialize_u64, u64, visit_u64);
+    deserialize_int!(deserialize_u128, u128, visit_u128);
+    deserialize_int!(deserialize_i8, i8, visit_i8);
+    deserialize_int!(deserialize_i16, i16, visit_i16);
+    deserialize_int!(deserialize_i32, i32, visit_i32);
+    deserialize_int!(deserialize_i64, i64, visit_i64);
+    deserialize_int!(deserialize_i128, i128, visit_i128);
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(self.unsupported("self-describing values"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = match self.take(1, "bool (0x00 or 0x01)")?[0] {
+            0 => false,
+            1 => true,
+            byte => {
+                self.offset -= 1;
+                return Err(self.fail("bool (0x00 or 0x01)", format!("invalid bool byte {byte:#04x}")));
+            }
+        };
+        self.leaf(|| value.to_string());
+        visitor.visit_bool(value)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(self.unsupported("f32"))
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(self.unsupported("f64"))
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(self.unsupported("char"))
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let start = self.offset;
+        let bytes = self.read_bytes("UTF-8 string")?;
+        let value = match std::str::from_utf8(bytes) {
+            Ok(value) => value,
+            Err(err) => {
+                self.offset = start;
+                return Err(self.fail("UTF-8 string", err.to_string()));
+            }
+        };
+        self.leaf(|| format!("{value:?}"));
+        visitor.visit_borrowed_str(value)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let value = self.read_bytes("byte vector")?;
+        self.leaf(|| format!("{value:02x?}"));
+        visitor.visit_borrowed_bytes(value)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.take(1, "option tag (0x00 or 0x01)")?[0] {
+            0 => {
+                self.leaf(|| "None".to_string());
+                visitor.visit_none()
+            }
+            1 => visitor.visit_some(self),
+            byte => {
+                self.offset -= 1;
+                Err(self.fail("option tag (0x00 or 0x01)", format!("invalid option tag {byte:#04x}")))
+            }
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.read_uleb("sequence length")?;
+        visitor.visit_seq(Elements::new(self, len, None))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements::new(self, len, None))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements::new(self, len, None))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.read_uleb("map length")?;
+        visitor.visit_map(Elements::new(self, len, None))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(Elements::new(self, fields.len(), Some(fields)))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(Enum { walker: self, variants })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(self.unsupported("identifiers"))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(self.unsupported("ignored values"))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The elements of a sequence, tuple, struct or map.
+struct Elements<'a, 'de> {
+    walker: &'a mut Walker<'de>,
+    len: usize,
+    index: usize,
+    fields: Option<&'static [&'static str]>,
+}
+
+impl<'a, 'de> Elements<'a, 'de> {
+    fn new(walker: &'a mut Walker<'de>, len: usize, fields: Option<&'static [&'static str]>) -> Self {
+        Self {
+            walker,
+            len,
+            index: 0,
+            fields,
+        }
+    }
+
+    fn segment(&self) -> Segment {
+        match self.fields {
+            Some(fields) => Segment::Field(fields.get(self.index).copied().unwrap_or("?")),
+            None => Segment::Index(self.index),
+        }
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        let segment = self.segment();
+        self.index += 1;
+        self.walker.scoped(segment, |walker| seed.deserialize(walker)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+impl<'de> de::MapAccess<'de> for Elements<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        self.walker
+            .scoped(Segment::Index(self.index), |walker| {
+                walker.scoped(Segment::Field("key"), |walker| seed.deserialize(walker))
+            })
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let index = self.index;
+        self.index += 1;
+        self.walker.scoped(Segment::Index(index), |walker| {
+            walker.scoped(Segment::Field("value"), |walker| seed.deserialize(walker))
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// An enum, before its variant is known.
+struct Enum<'a, 'de> {
+    walker: &'a mut Walker<'de>,
+    variants: &'static [&'static str],
+}
+
+impl<'a, 'de> de::EnumAccess<'de> for Enum<'a, 'de> {
+    type Error = Error;
+    type Variant = Variant<'a, 'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant), Error> {
+        let start = self.walker.offset;
+        let index = self.walker.read_uleb("enum variant index")?;
+        let Some(name) = self.variants.get(index).copied() else {
+            self.walker.offset = start;
+            return Err(self.walker.fail(
+                format!(
+                    "a variant index below {} ({})",
+                    self.variants.len(),
+                    self.variants.join(", ")
+                ),
+                format!("unknown variant index {index}"),
+            ));
+        };
+
+        let variant: U32Deserializer<Error> = (index as u32).into_deserializer();
+        let value = seed.deserialize(variant)?;
+
+        Ok((
+            value,
+            Variant {
+                walker: self.walker,
+                name,
+            },
+        ))
+    }
+}
+
+/// The content of an enum variant.
+struct Variant<'a, 'de> {
+    walker: &'a mut Walker<'de>,
+    name: &'static str,
+}
+
+impl<'de> de::VariantAccess<'de> for Variant<'_, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        let name = self.name;
+        self.walker.leaf(|| name.to_string());
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        self.walker
+            .scoped(Segment::Field(self.name), |walker| seed.deserialize(walker))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.walker.scoped(Segment::Field(self.name), |walker| {
+            visitor.visit_seq(Elements::new(walker, len, None))
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        self.walker.scoped(Segment::Field(self.name), |walker| {
+            visitor.visit_seq(Elements::new(walker, fields.len(), Some(fields)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Range { from: u64, to: u64 },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        name: String,
+        shape: Option<Shape>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        id: u32,
+        items: Vec<Inner>,
+        enabled: bool,
+    }
+
+    fn sample() -> Outer {
+        Outer {
+            id: 7,
+            items: vec![
+                Inner {
+                    name: "a".to_string(),
+                    shape: None,
+                },
+                Inner {
+                    name: "b".to_string(),
+                    shape: Some(Shape::Range { from: 1, to: 2 }),
+                },
+            ],
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_decode_matches_bcs() {
+        let bytes = bcs::to_bytes(&sample()).unwrap();
+        assert_eq!(decode::<Outer>(&bytes).unwrap(), sample());
+    }
+
+    #[test]
+    fn test_decode_reports_path_of_truncated_field() {
+        let bytes = bcs::to_bytes(&sample()).unwrap();
+        // Cut into the `to` field of the second item
+        let diagnostic = decode::<Outer>(&bytes[..bytes.len() - 5]).unwrap_err();
+
+        assert_eq!(diagnostic.path, "items[1].shape.Range.to");
+        assert_eq!(diagnostic.expected, "u64");
+        assert_eq!(diagnostic.message, "unexpected end of input");
+    }
+
+    #[test]
+    fn test_decode_reports_trailing_bytes() {
+        let mut bytes = bcs::to_bytes(&sample()).unwrap();
+        bytes.extend([0xaa, 0xbb]);
+        let diagnostic = decode::<Outer>(&bytes).unwrap_err();
+
+        assert_eq!(diagnostic.path, "");
+        assert_eq!(diagnostic.offset, bytes.len() - 2);
+        assert_eq!(diagnostic.actual, "0xaabb");
+    }
+
+    #[test]
+    fn test_try_parse_partial_keeps_decoded_fields() {
+        let mut bytes = bcs::to_bytes(&sample()).unwrap();
+        // Corrupt the `enabled` flag
+        *bytes.last_mut().unwrap() = 2;
+        let partial = try_parse_partial::<Outer>(&bytes);
+
+        assert!(partial.value.is_none());
+        let diagnostic = partial.diagnostic.unwrap();
+        assert_eq!(diagnostic.path, "enabled");
+        assert_eq!(diagnostic.actual, "0x02");
+
+        let fields: Vec<_> = partial.fields.iter().map(|field| field.path.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "id",
+                "items[0].name",
+                "items[0].shape",
+                "items[1].name",
+                "items[1].shape.Range.from",
+                "items[1].shape.Range.to",
+            ]
+        );
+    }
+}
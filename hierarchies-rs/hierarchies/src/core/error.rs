@@ -5,6 +5,7 @@
 
 use thiserror::Error;
 
+use crate::core::types::subject_id::SubjectIdError;
 use crate::error::ObjectError;
 
 /// Errors that can occur during Hierarchies operations
@@ -19,6 +20,30 @@ pub enum OperationError {
     #[error("object operation failed")]
     Object(#[from] ObjectError),
 
+    /// A destructive operation was built without being confirmed
+    #[error("destructive operation '{operation}' must be confirmed with `confirm_irreversible()` before it is built")]
+    DestructiveNotConfirmed { operation: String },
+
+    /// The properties to be granted violate the grant policy of the federation
+    #[error("grant policy violated")]
+    GrantPolicy(#[from] GrantPolicyViolation),
+
+    /// The chain state after execution doesn't reflect the intent of the operation
+    #[error("chain state after '{operation}' violates the expected post-condition: {condition}")]
+    InvariantViolation { operation: String, condition: String },
+
+    /// A subject couldn't be resolved to an object ID
+    #[error("subject couldn't be resolved")]
+    Subject(#[from] SubjectIdError),
+
+    /// A transaction batch without operations was built
+    #[error("transaction batch has no operations")]
+    EmptyBatch,
+
+    /// A property named like a wildcard was added, wildcards can only be granted as accreditations
+    #[error("property '{name}' is a wildcard and can only be granted as an accreditation")]
+    WildcardProperty { name: String },
+
     /// BCS serialization failed
     #[error("serialization failed")]
     Serialization {
@@ -66,3 +91,59 @@ pub enum CapabilityError {
         source: Box<dyn std::error::Error + Send + Sync>,
     },
 }
+
+/// Violations of the grant policy of a federation
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum GrantPolicyViolation {
+    /// The policy does not permit granting any value
+    #[error("property '{property}' allows any value, which the grant policy does not permit")]
+    AllowAnyNotPermitted { property: String },
+
+    /// The property allows more values than permitted by the policy
+    #[error("property '{property}' allows {count} values, the grant policy permits at most {max}")]
+    AllowedValuesLimitExceeded { property: String, count: usize, max: u64 },
+}
+
+/// Errors of an invalid property pattern, see [`crate::core::types::property_pattern`]
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum PatternError {
+    /// The pattern contains a non-ASCII character
+    #[error("pattern contains a non-ASCII character at byte {position}")]
+    NonAscii { position: usize },
+
+    /// The pattern ends with an escape
+    #[error("escape at byte {position} is not followed by a character")]
+    UnterminatedEscape { position: usize },
+
+    /// A character class is not closed
+    #[error("character class at byte {position} is not closed")]
+    UnterminatedClass { position: usize },
+
+    /// A character class lists no characters
+    #[error("character class at byte {position} is empty")]
+    EmptyClass { position: usize },
+
+    /// A range of a character class ends before it starts
+    #[error("range at byte {position} ends before it starts")]
+    InvalidRange { position: usize },
+}
+
+/// Errors of a disclosure proof that doesn't match the attested values, see
+/// [`crate::core::types::selective_disclosure`]
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum DisclosureError {
+    /// The proof was made for another attestation
+    #[error("disclosure proof is for attestation {expected}, not {actual}")]
+    WrongAttestation { expected: String, actual: String },
+
+    /// The disclosed property is not attested
+    #[error("property '{property}' is not attested")]
+    PropertyNotAttested { property: String },
+
+    /// The disclosed value doesn't hash to the attested digest
+    #[error("disclosed value of property '{property}' doesn't match the attested digest")]
+    DigestMismatch { property: String },
+}
@@ -0,0 +1,244 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Post-Execution Invariants
+//!
+//! Governance transactions can re-fetch the federation after execution and assert that the chain
+//! state reflects their intent, e.g. that an added property is present or a revoked root authority
+//! is listed as revoked. A [`PostCondition`] describes such an expectation; transactions opt into
+//! the check through [`InvariantChecked`](crate::core::transactions::InvariantChecked).
+
+use std::fmt;
+
+use iota_interaction::OptionalSync;
+use iota_interaction::types::base_types::ObjectID;
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::client::get_object_ref_by_id_with_bcs;
+use crate::core::OperationError;
+use crate::core::types::Federation;
+use crate::core::types::accreditation_query::AccreditationScope;
+use crate::core::types::property_name::PropertyName;
+
+/// The expected state of a federation after a governance transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostCondition {
+    /// The property is registered in the federation.
+    PropertyPresent(PropertyName),
+    /// The property is registered and has an end of validity.
+    PropertyRevoked(PropertyName),
+    /// The account is an active root authority.
+    RootAuthorityPresent(ObjectID),
+    /// The account is a revoked root authority.
+    RootAuthorityRevoked(ObjectID),
+    /// The root authority of `old_account_id` was handed over to `new_account_id`.
+    RootAuthorityRotated {
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+    },
+    /// The entity holds an accreditation covering all `properties`.
+    AccreditationGranted {
+        entity_id: ObjectID,
+        scope: AccreditationScope,
+        properties: Vec<PropertyName>,
+    },
+    /// The entity no longer holds the accreditation.
+    AccreditationRemoved {
+        entity_id: ObjectID,
+        scope: AccreditationScope,
+        accreditation_id: ObjectID,
+    },
+}
+
+impl PostCondition {
+    /// Returns true if `federation` satisfies the condition.
+    pub fn holds(&self, federation: &Federation) -> bool {
+        let governance = &federation.governance;
+        let accreditations = |scope: &AccreditationScope, entity_id: &ObjectID| match scope {
+            AccreditationScope::Attest => governance.accreditations_to_attest.get(entity_id),
+            AccreditationScope::Accredit => governance.accreditations_to_accredit.get(entity_id),
+        };
+
+        match self {
+            Self::PropertyPresent(name) => governance.properties.data.contains_key(name),
+            Self::PropertyRevoked(name) => governance
+                .properties
+                .data
+                .get(name)
+                .is_some_and(|property| property.timespan.valid_until_ms.is_some()),
+            Self::RootAuthorityPresent(account_id) => federation
+                .root_authorities
+                .iter()
+                .any(|authority| authority.account_id == *account_id),
+            Self::RootAuthorityRevoked(account_id) => {
+                federation.revoked_root_authorities.contains(account_id)
+                    && !Self::RootAuthorityPresent(*account_id).holds(federation)
+            }
+            Self::RootAuthorityRotated {
+                old_account_id,
+                new_account_id,
+            } => {
+                Self::RootAuthorityRevoked(*old_account_id).holds(federation)
+                    && Self::RootAuthorityPresent(*new_account_id).holds(federation)
+            }
+            Self::AccreditationGranted {
+                entity_id,
+                scope,
+                properties,
+            } => accreditations(scope, entity_id).is_some_and(|accreditations| {
+                accreditations.iter().any(|accreditation| {
+                    properties
+                        .iter()
+                        .all(|name| accreditation.properties.contains_key(name))
+                })
+            }),
+            Self::AccreditationRemoved {
+                entity_id,
+                scope,
+                accreditation_id,
+            } => accreditations(scope, entity_id).is_none_or(|accreditations| {
+                accreditations
+                    .iter()
+                    .all(|accreditation| accreditation.id.object_id() != accreditation_id)
+            }),
+        }
+    }
+}
+
+impl fmt::Display for PostCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PropertyPresent(name) => write!(f, "property {:?} is present", name.names()),
+            Self::PropertyRevoked(name) => write!(f, "property {:?} is revoked", name.names()),
+            Self::RootAuthorityPresent(account_id) => write!(f, "root authority {account_id} is present"),
+            Self::RootAuthorityRevoked(account_id) => write!(f, "root authority {account_id} is revoked"),
+            Self::RootAuthorityRotated {
+                old_account_id,
+                new_account_id,
+            } => write!(f, "root authority {old_account_id} is handed over to {new_account_id}"),
+            Self::AccreditationGranted { entity_id, scope, .. } => {
+                write!(f, "entity {entity_id} holds the granted accreditation ({scope:?})")
+            }
+            Self::AccreditationRemoved {
+                entity_id,
+                scope,
+                accreditation_id,
+            } => write!(
+                f,
+                "entity {entity_id} no longer holds accreditation {accreditation_id} ({scope:?})"
+            ),
+        }
+    }
+}
+
+/// Re-fetches the federation and checks that it satisfies `condition`.
+///
+/// # Errors
+///
+/// Returns [`OperationError::InvariantViolation`] if the condition doesn't hold, or an
+/// [`OperationError::Object`] if the federation cannot be fetched.
+pub(crate) async fn verify<C>(
+    client: &C,
+    federation_id: ObjectID,
+    operation: &str,
+    condition: PostCondition,
+) -> Result<(), OperationError>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let federation: Federation = get_object_ref_by_id_with_bcs(client, &federation_id).await?;
+
+    if condition.holds(&federation) {
+        Ok(())
+    } else {
+        Err(OperationError::InvariantViolation {
+            operation: operation.to_string(),
+            condition: condition.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use iota_interaction::types::id::UID;
+
+    use super::*;
+    use crate::core::types::grant_policy::GrantPolicy;
+    use crate::core::types::property::{FederationProperties, FederationProperty};
+    use crate::core::types::{FederationStats, Governance, RootAuthority};
+
+    fn federation(root: ObjectID, revoked: ObjectID) -> Federation {
+        let property = FederationProperty::new("role");
+        Federation {
+            id: UID::new(ObjectID::random()),
+            governance: Governance {
+                id: UID::new(ObjectID::random()),
+                properties: FederationProperties {
+                    data: HashMap::from([(property.name.clone(), property)]),
+                },
+                accreditations_to_accredit: HashMap::new(),
+                accreditations_to_attest: HashMap::new(),
+                critical_properties: HashSet::new(),
+                value_dictionaries: HashMap::new(),
+                grant_policy: GrantPolicy::default(),
+                property_tags: HashMap::new(),
+                accreditation_tags: HashMap::new(),
+                quorum: Default::default(),
+                recognized_federations: HashSet::new(),
+                deprecated_properties: HashMap::new(),
+            },
+            root_authorities: vec![RootAuthority {
+                id: UID::new(ObjectID::random()),
+                account_id: root,
+            }],
+            revoked_root_authorities: vec![revoked],
+            stats: FederationStats::default(),
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_post_conditions() {
+        let root = ObjectID::random();
+        let revoked = ObjectID::random();
+        let federation = federation(root, revoked);
+
+        assert!(PostCondition::PropertyPresent("role".into()).holds(&federation));
+        assert!(!PostCondition::PropertyRevoked("role".into()).holds(&federation));
+        assert!(PostCondition::RootAuthorityPresent(root).holds(&federation));
+        assert!(PostCondition::RootAuthorityRevoked(revoked).holds(&federation));
+        assert!(!PostCondition::RootAuthorityRevoked(root).holds(&federation));
+        assert!(
+            PostCondition::RootAuthorityRotated {
+                old_account_id: revoked,
+                new_account_id: root,
+            }
+            .holds(&federation)
+        );
+        assert!(
+            !PostCondition::RootAuthorityRotated {
+                old_account_id: root,
+                new_account_id: revoked,
+            }
+            .holds(&federation)
+        );
+        assert!(
+            !PostCondition::AccreditationGranted {
+                entity_id: root,
+                scope: AccreditationScope::Attest,
+                properties: vec!["role".into()],
+            }
+            .holds(&federation)
+        );
+        assert!(
+            PostCondition::AccreditationRemoved {
+                entity_id: root,
+                scope: AccreditationScope::Attest,
+                accreditation_id: ObjectID::random(),
+            }
+            .holds(&federation)
+        );
+    }
+}
@@ -5,13 +5,20 @@
 //!
 //! This module provides the core functionality for the Hierarchies (IOTA Trust Hierarchy) module.
 
+pub mod capability_cache;
+pub mod diagnostics;
 pub mod error;
+pub mod invariants;
+pub mod offline;
 pub mod operations;
+pub mod prefetch;
+pub mod ptb_template;
+pub mod semantics;
 pub mod transactions;
 pub mod types;
 
 // Re-export error types for convenience
-pub use error::{CapabilityError, OperationError};
+pub use error::{CapabilityError, DisclosureError, GrantPolicyViolation, OperationError, PatternError};
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
 use iota_interaction::types::transaction::{Argument, CallArg, SharedObjectRef};
 use iota_interaction::types::{IOTA_CLOCK_OBJECT_ID, IOTA_CLOCK_OBJECT_SHARED_VERSION};
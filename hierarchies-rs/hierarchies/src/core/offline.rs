@@ -0,0 +1,826 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Offline Validation
+//!
+//! Evaluates the validation rules of the Move package against a fetched [`Federation`] snapshot,
+//! without building a transaction or calling the node.
+//!
+//! Every function mirrors the on-chain function of the same name and returns the same result for
+//! the same federation state and time. Unlike the on-chain checks, the result is only as fresh as
+//! the snapshot, so revocations after fetching it are not taken into account.
+//!
+//! [`diagnose_property`], [`diagnose_property_values`] and [`diagnose_properties`] evaluate the
+//! same rules, but return a [`ValidationReport`] telling which rule failed for which property
+//! instead of a bare bool. The report also warns about deprecated properties, which don't fail
+//! validation.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use iota_interaction::types::base_types::ObjectID;
+
+use crate::core::types::Federation;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_shape::PropertyShape;
+use crate::core::types::property_value::{PropertyValue, ValueSetMatch};
+use crate::core::types::timespan::Timespan;
+
+/// Validates a single property attested by `attester_id` at `now_ms`.
+///
+/// Mirrors the on-chain `validate_property`.
+pub fn validate_property(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    property_value: &PropertyValue,
+    now_ms: u64,
+) -> bool {
+    let Some(federation_property) = valid_federation_property(federation, property_name, now_ms) else {
+        return false;
+    };
+
+    is_value_in_dictionary(federation, federation_property, property_value)
+        && is_property_allowed(federation, attester_id, property_name, property_value, now_ms)
+}
+
+/// Validates a set of values attested for a single property by `attester_id` at `now_ms`.
+///
+/// Mirrors the on-chain `validate_property_values`: values outside a referenced value dictionary
+/// fail [`ValueSetMatch::Superset`] and are ignored by [`ValueSetMatch::Contains`]. An empty set
+/// of values is never valid.
+pub fn validate_property_values(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    property_values: &[PropertyValue],
+    value_match: ValueSetMatch,
+    now_ms: u64,
+) -> bool {
+    let Some(federation_property) = valid_federation_property(federation, property_name, now_ms) else {
+        return false;
+    };
+    if !federation.governance.accreditations_to_attest.contains_key(attester_id) {
+        return false;
+    }
+
+    let values: Vec<&PropertyValue> = property_values
+        .iter()
+        .filter(|value| is_value_in_dictionary(federation, federation_property, value))
+        .collect();
+    if values.is_empty() || (value_match.require_all() && values.len() != property_values.len()) {
+        return false;
+    }
+
+    let mut allowed = values
+        .into_iter()
+        .map(|value| is_property_allowed(federation, attester_id, property_name, value, now_ms));
+    match value_match {
+        ValueSetMatch::Superset => allowed.all(|allowed| allowed),
+        ValueSetMatch::Contains => allowed.any(|allowed| allowed),
+    }
+}
+
+/// Validates multiple properties attested by `attester_id` at `now_ms`.
+///
+/// Mirrors the on-chain `validate_properties`, so an empty set of properties is valid for any attester.
+pub fn validate_properties(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    properties: &HashMap<PropertyName, PropertyValue>,
+    now_ms: u64,
+) -> bool {
+    let trusted = properties.iter().all(|(name, value)| {
+        valid_federation_property(federation, name, now_ms)
+            .is_some_and(|property| is_value_in_dictionary(federation, property, value))
+    });
+    if !trusted || !federation.governance.accreditations_to_attest.contains_key(attester_id) {
+        return false;
+    }
+
+    properties
+        .iter()
+        .all(|(name, value)| is_property_allowed(federation, attester_id, name, value, now_ms))
+}
+
+/// Why a property failed validation, in the order the rules are evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationFailure {
+    /// The federation doesn't define the property
+    NotInFederation,
+    /// The federation revoked the property, or it is not valid yet
+    PropertyNotValid { timespan: Timespan },
+    /// The attester holds no accreditation to attest at all
+    NotAnAttester,
+    /// The value is not in the value dictionary referenced by the property
+    NotInValueDictionary { value_dictionary: String },
+    /// None of the attester's accreditations covers the property
+    NotAccredited,
+    /// The accreditations covering the property are still in their objection window
+    AccreditationPending { pending_until_ms: u64 },
+    /// The timespans of the grants covering the property don't contain the validation time
+    AccreditationExpired { timespans: Vec<Timespan> },
+    /// The value doesn't match the shape of any grant covering the property
+    ShapeMismatch { shapes: Vec<PropertyShape> },
+    /// The value is not among the allowed values of any grant covering the property
+    ValueNotAllowed,
+}
+
+impl fmt::Display for ValidationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInFederation => write!(f, "the property is not part of the federation"),
+            Self::PropertyNotValid { timespan } => write!(
+                f,
+                "the property is revoked or not valid yet (valid from {:?} until {:?})",
+                timespan.valid_from_ms, timespan.valid_until_ms
+            ),
+            Self::NotInValueDictionary { value_dictionary } => {
+                write!(f, "the value is not in value dictionary '{value_dictionary}'")
+            }
+            Self::NotAnAttester => write!(f, "the attester holds no accreditation to attest"),
+            Self::NotAccredited => write!(f, "the attester is not accredited for the property"),
+            Self::AccreditationPending { pending_until_ms } => {
+                write!(f, "the accreditation is pending until {pending_until_ms}")
+            }
+            Self::AccreditationExpired { .. } => write!(f, "the accreditation is expired or not valid yet"),
+            Self::ShapeMismatch { shapes } => write!(f, "the value doesn't match the shapes {shapes:?}"),
+            Self::ValueNotAllowed => write!(f, "the value is not allowed by the accreditation"),
+        }
+    }
+}
+
+/// Why a property deserves attention even though it may pass validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// The federation deprecated the property, in favour of `replacement` if any
+    Deprecated { replacement: Option<PropertyName> },
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deprecated {
+                replacement: Some(replacement),
+            } => write!(
+                f,
+                "the property is deprecated, use {} instead",
+                replacement.names().join(".")
+            ),
+            Self::Deprecated { replacement: None } => write!(f, "the property is deprecated"),
+        }
+    }
+}
+
+/// The outcome of validating a single property value, see [`ValidationReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyCheck {
+    pub property_name: PropertyName,
+    pub property_value: PropertyValue,
+    /// The first rule the property failed, if any
+    pub failure: Option<ValidationFailure>,
+    /// A warning about the property, independent of its failure
+    pub warning: Option<ValidationWarning>,
+}
+
+/// The outcome of a validation, explaining every failed property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub attester_id: ObjectID,
+    /// The time the rules were evaluated at
+    pub evaluated_at_ms: u64,
+    /// How the checks of a set of values of a single property are combined, `None` if the checks
+    /// are of different properties
+    pub value_match: Option<ValueSetMatch>,
+    pub checks: Vec<PropertyCheck>,
+}
+
+impl ValidationReport {
+    /// Returns true if the validation passed, i.e. the bool validation returns.
+    ///
+    /// Like the on-chain `validate_properties`, a report of properties without any checks is
+    /// valid. Like `validate_property_values`, a report of a set of values needs at least one
+    /// passed check, and no failed one for [`ValueSetMatch::Superset`].
+    pub fn is_valid(&self) -> bool {
+        let mut passed = self.checks.iter().map(|check| check.failure.is_none());
+        match self.value_match {
+            None => passed.all(|passed| passed),
+            Some(ValueSetMatch::Superset) => !self.checks.is_empty() && passed.all(|passed| passed),
+            Some(ValueSetMatch::Contains) => passed.any(|passed| passed),
+        }
+    }
+
+    /// Returns the checks of the properties that failed.
+    ///
+    /// A report of a set of values matched with [`ValueSetMatch::Contains`] may be valid despite
+    /// failed checks.
+    pub fn failures(&self) -> impl Iterator<Item = &PropertyCheck> {
+        self.checks.iter().filter(|check| check.failure.is_some())
+    }
+
+    /// Returns the checks of the properties with a warning, e.g. deprecated ones.
+    pub fn warnings(&self) -> impl Iterator<Item = &PropertyCheck> {
+        self.checks.iter().filter(|check| check.warning.is_some())
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "validation of {} at {}: ", self.attester_id, self.evaluated_at_ms)?;
+        write!(f, "{}", if self.is_valid() { "valid" } else { "invalid" })?;
+        for check in self.failures() {
+            let failure = check.failure.as_ref().expect("failures have a failure");
+            write!(f, "\n  {}: {failure}", check.property_name.names().join("."))?;
+        }
+        for check in self.warnings() {
+            let warning = check.warning.as_ref().expect("warnings have a warning");
+            write!(f, "\n  {} (warning): {warning}", check.property_name.names().join("."))?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates a single property like [`validate_property`] and reports why it failed.
+pub fn diagnose_property(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    property_value: &PropertyValue,
+    now_ms: u64,
+) -> ValidationReport {
+    diagnose_properties(
+        federation,
+        attester_id,
+        &HashMap::from([(property_name.clone(), property_value.clone())]),
+        now_ms,
+    )
+}
+
+/// Validates a set of values of a single property like [`validate_property_values`] and reports
+/// why each value failed.
+///
+/// Every value is checked on its own, so values outside a referenced value dictionary fail with
+/// [`ValidationFailure::NotInValueDictionary`] whether `value_match` ignores them or not.
+pub fn diagnose_property_values(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    property_values: &[PropertyValue],
+    value_match: ValueSetMatch,
+    now_ms: u64,
+) -> ValidationReport {
+    let checks = property_values
+        .iter()
+        .map(|value| check_property(federation, attester_id, property_name, value, now_ms))
+        .collect();
+
+    ValidationReport {
+        attester_id: *attester_id,
+        evaluated_at_ms: now_ms,
+        value_match: Some(value_match),
+        checks,
+    }
+}
+
+/// Validates multiple properties like [`validate_properties`] and reports why each one failed.
+///
+/// Unlike the bool validation, evaluation doesn't stop at the first failed property.
+pub fn diagnose_properties(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    properties: &HashMap<PropertyName, PropertyValue>,
+    now_ms: u64,
+) -> ValidationReport {
+    let checks = properties
+        .iter()
+        .map(|(name, value)| check_property(federation, attester_id, name, value, now_ms))
+        .collect();
+
+    ValidationReport {
+        attester_id: *attester_id,
+        evaluated_at_ms: now_ms,
+        value_match: None,
+        checks,
+    }
+}
+
+fn check_property(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    value: &PropertyValue,
+    now_ms: u64,
+) -> PropertyCheck {
+    PropertyCheck {
+        property_name: property_name.clone(),
+        property_value: value.clone(),
+        failure: diagnose(federation, attester_id, property_name, value, now_ms).err(),
+        warning: federation
+            .governance
+            .deprecated_properties
+            .get(property_name)
+            .map(|replacement| ValidationWarning::Deprecated {
+                replacement: replacement.clone(),
+            }),
+    }
+}
+
+fn diagnose(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    value: &PropertyValue,
+    now_ms: u64,
+) -> Result<(), ValidationFailure> {
+    let federation_property = federation
+        .governance
+        .properties
+        .data
+        .get(property_name)
+        .ok_or(ValidationFailure::NotInFederation)?;
+    if !federation_property.timespan.timestamp_matches(now_ms) {
+        return Err(ValidationFailure::PropertyNotValid {
+            timespan: federation_property.timespan.clone(),
+        });
+    }
+    let accreditations = federation
+        .governance
+        .accreditations_to_attest
+        .get(attester_id)
+        .ok_or(ValidationFailure::NotAnAttester)?;
+    if !is_value_in_dictionary(federation, federation_property, value) {
+        return Err(ValidationFailure::NotInValueDictionary {
+            value_dictionary: federation_property.value_dictionary.clone().unwrap_or_default(),
+        });
+    }
+
+    let covering: Vec<_> = accreditations
+        .iter()
+        .filter_map(|accreditation| Some((accreditation, accreditation.find_property(property_name)?)))
+        .collect();
+    if covering.is_empty() {
+        return Err(ValidationFailure::NotAccredited);
+    }
+
+    let effective: Vec<&FederationProperty> = covering
+        .iter()
+        .filter(|(accreditation, _)| !accreditation.is_pending(now_ms))
+        .map(|(_, grant)| *grant)
+        .collect();
+    if effective.is_empty() {
+        let pending_until_ms = covering
+            .iter()
+            .filter_map(|(accreditation, _)| accreditation.pending_until_ms)
+            .min()
+            .unwrap_or(now_ms);
+        return Err(ValidationFailure::AccreditationPending { pending_until_ms });
+    }
+
+    let active: Vec<&FederationProperty> = effective
+        .iter()
+        .filter(|grant| grant.timespan.timestamp_matches(now_ms))
+        .copied()
+        .collect();
+    if active.is_empty() {
+        return Err(ValidationFailure::AccreditationExpired {
+            timespans: effective.iter().map(|grant| grant.timespan.clone()).collect(),
+        });
+    }
+    if active.iter().any(|grant| grant.matches_value(value, now_ms)) {
+        return Ok(());
+    }
+
+    let shapes: Vec<PropertyShape> = active.iter().filter_map(|grant| grant.shape.clone()).collect();
+    if shapes.is_empty() {
+        Err(ValidationFailure::ValueNotAllowed)
+    } else {
+        Err(ValidationFailure::ShapeMismatch { shapes })
+    }
+}
+
+/// Returns the federation's definition of `property_name` if it exists and is not revoked at `now_ms`.
+fn valid_federation_property<'a>(
+    federation: &'a Federation,
+    property_name: &PropertyName,
+    now_ms: u64,
+) -> Option<&'a FederationProperty> {
+    federation
+        .governance
+        .properties
+        .data
+        .get(property_name)
+        .filter(|property| property.timespan.timestamp_matches(now_ms))
+}
+
+/// Returns true if `value` is in the value dictionary referenced by `property`, if any.
+fn is_value_in_dictionary(federation: &Federation, property: &FederationProperty, value: &PropertyValue) -> bool {
+    property.value_dictionary.as_ref().is_none_or(|name| {
+        federation
+            .governance
+            .value_dictionaries
+            .get(name)
+            .is_some_and(|values| values.contains(value))
+    })
+}
+
+/// Returns true if any accreditation to attest of `attester_id` allows `value` at `now_ms`.
+fn is_property_allowed(
+    federation: &Federation,
+    attester_id: &ObjectID,
+    property_name: &PropertyName,
+    value: &PropertyValue,
+    now_ms: u64,
+) -> bool {
+    federation
+        .governance
+        .accreditations_to_attest
+        .get(attester_id)
+        .is_some_and(|accreditations| {
+            accreditations.iter().any(|accreditation| {
+                !accreditation.is_pending(now_ms)
+                    && accreditation
+                        .find_property(property_name)
+                        .is_some_and(|property| property.matches_value(value, now_ms))
+            })
+        })
+}
+
+/// Test vectors shared by the unit tests of this module and the end-to-end tests comparing
+/// offline and on-chain validation.
+#[cfg(any(test, feature = "testkit"))]
+pub mod vectors {
+    use std::collections::{HashMap, HashSet};
+
+    use iota_interaction::types::base_types::ObjectID;
+    use iota_interaction::types::id::UID;
+
+    use crate::core::types::grant_policy::GrantPolicy;
+    use crate::core::types::property::{FederationProperties, FederationProperty};
+    use crate::core::types::property_name::PropertyName;
+    use crate::core::types::property_shape::PropertyShape;
+    use crate::core::types::property_value::PropertyValue;
+    use crate::core::types::timespan::Timespan;
+    use crate::core::types::{Accreditation, Accreditations, Federation, FederationStats, Governance};
+
+    /// The name of the value dictionary used by the vectors.
+    pub const DICTIONARY: &str = "vectors";
+
+    /// A single `validate_property` case.
+    #[derive(Debug, Clone)]
+    pub struct ValidationVector {
+        pub name: &'static str,
+        /// The property as added to the federation
+        pub federation_property: FederationProperty,
+        /// The values of [`DICTIONARY`], if the federation property references it
+        pub dictionary: Option<Vec<PropertyValue>>,
+        /// Whether the federation property is revoked before validating
+        pub revoked: bool,
+        /// The accreditation to attest granted to the attester, if any
+        pub grant: Option<FederationProperty>,
+        /// The attested value
+        pub value: PropertyValue,
+        /// The expected result of the validation
+        pub expected: bool,
+    }
+
+    impl ValidationVector {
+        /// Returns the federation state described by the vector, as seen at `now_ms`.
+        pub fn snapshot(&self, attester_id: ObjectID, now_ms: u64) -> Federation {
+            let mut federation_property = self.federation_property.clone();
+            if self.revoked {
+                federation_property.timespan = Timespan {
+                    valid_from_ms: None,
+                    valid_until_ms: Some(now_ms),
+                };
+            }
+
+            let accreditations_to_attest = self
+                .grant
+                .iter()
+                .map(|grant| {
+                    let accreditation = Accreditation {
+                        id: UID::new(ObjectID::random()),
+                        accredited_by: "root".to_string(),
+                        properties: HashMap::from([(grant.name.clone(), grant.clone())]),
+                        pending_until_ms: None,
+                    };
+                    (attester_id, Accreditations::new(vec![accreditation]))
+                })
+                .collect();
+
+            Federation {
+                id: UID::new(ObjectID::random()),
+                governance: Governance {
+                    id: UID::new(ObjectID::random()),
+                    properties: FederationProperties {
+                        data: HashMap::from([(federation_property.name.clone(), federation_property)]),
+                    },
+                    accreditations_to_accredit: HashMap::new(),
+                    accreditations_to_attest,
+                    critical_properties: HashSet::new(),
+                    value_dictionaries: self
+                        .dictionary
+                        .iter()
+                        .map(|values| (DICTIONARY.to_string(), values.iter().cloned().collect()))
+                        .collect(),
+                    grant_policy: GrantPolicy::default(),
+                    property_tags: HashMap::new(),
+                    accreditation_tags: HashMap::new(),
+                    quorum: Default::default(),
+                    recognized_federations: HashSet::new(),
+                    deprecated_properties: HashMap::new(),
+                },
+                root_authorities: vec![],
+                revoked_root_authorities: vec![],
+                stats: FederationStats::default(),
+                metadata: Default::default(),
+            }
+        }
+    }
+
+    fn text(value: &str) -> PropertyValue {
+        PropertyValue::Text(value.to_string())
+    }
+
+    /// Returns the validation vectors, each using its own property named `name`.
+    pub fn validation_vectors(name: impl Fn(usize) -> PropertyName) -> Vec<ValidationVector> {
+        let any = |i: usize| FederationProperty::new(name(i)).with_allow_any(true);
+        let vector = |i: usize, vector_name: &'static str, grant: Option<FederationProperty>, value, expected| {
+            ValidationVector {
+                name: vector_name,
+                federation_property: any(i),
+                dictionary: None,
+                revoked: false,
+                grant,
+                value,
+                expected,
+            }
+        };
+
+        vec![
+            vector(0, "allow any", Some(any(0)), text("x"), true),
+            vector(
+                1,
+                "allowed value",
+                Some(FederationProperty::new(name(1)).with_allowed_values([text("a")])),
+                text("a"),
+                true,
+            ),
+            vector(
+                2,
+                "value not allowed",
+                Some(FederationProperty::new(name(2)).with_allowed_values([text("a")])),
+                text("b"),
+                false,
+            ),
+            vector(
+                3,
+                "shape matches",
+                Some(FederationProperty::new(name(3)).with_expression(PropertyShape::StartsWith("ab".to_string()))),
+                text("abc"),
+                true,
+            ),
+            vector(
+                4,
+                "shape does not match",
+                Some(FederationProperty::new(name(4)).with_expression(PropertyShape::StartsWith("ab".to_string()))),
+                text("xab"),
+                false,
+            ),
+            vector(
+                5,
+                "number above bound",
+                Some(FederationProperty::new(name(5)).with_expression(PropertyShape::GreaterThan(10))),
+                PropertyValue::Number(11),
+                true,
+            ),
+            vector(
+                6,
+                "text for number shape",
+                Some(FederationProperty::new(name(6)).with_expression(PropertyShape::GreaterThan(10))),
+                text("11"),
+                false,
+            ),
+            vector(7, "not an attester", None, text("x"), false),
+            ValidationVector {
+                federation_property: FederationProperty::new(name(8)).with_value_dictionary(DICTIONARY),
+                dictionary: Some(vec![text("a"), text("b")]),
+                ..vector(
+                    8,
+                    "value in dictionary",
+                    Some(FederationProperty::new(name(8)).with_value_dictionary(DICTIONARY)),
+                    text("a"),
+                    true,
+                )
+            },
+            ValidationVector {
+                federation_property: FederationProperty::new(name(9)).with_value_dictionary(DICTIONARY),
+                dictionary: Some(vec![text("a"), text("b")]),
+                ..vector(
+                    9,
+                    "value not in dictionary",
+                    Some(FederationProperty::new(name(9)).with_value_dictionary(DICTIONARY)),
+                    text("c"),
+                    false,
+                )
+            },
+            ValidationVector {
+                revoked: true,
+                ..vector(10, "revoked property", Some(any(10)), text("x"), false)
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vectors::validation_vectors;
+    use super::*;
+
+    #[test]
+    fn test_validation_vectors() {
+        let now_ms = 1_000;
+        let attester = ObjectID::random();
+
+        for vector in validation_vectors(|i| PropertyName::new(["vector".to_string(), i.to_string()])) {
+            let federation = vector.snapshot(attester, now_ms);
+            let name = &vector.federation_property.name;
+
+            assert_eq!(
+                validate_property(&federation, &attester, name, &vector.value, now_ms),
+                vector.expected,
+                "{}",
+                vector.name
+            );
+            assert_eq!(
+                validate_properties(
+                    &federation,
+                    &attester,
+                    &HashMap::from([(name.clone(), vector.value.clone())]),
+                    now_ms
+                ),
+                vector.expected,
+                "{}",
+                vector.name
+            );
+            assert_eq!(
+                diagnose_property(&federation, &attester, name, &vector.value, now_ms).is_valid(),
+                vector.expected,
+                "{}",
+                vector.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_diagnose_failures() {
+        let now_ms = 1_000;
+        let attester = ObjectID::random();
+        let vectors = validation_vectors(|i| PropertyName::new(["vector".to_string(), i.to_string()]));
+        let failure = |vector: &vectors::ValidationVector| {
+            let federation = vector.snapshot(attester, now_ms);
+            diagnose_property(
+                &federation,
+                &attester,
+                &vector.federation_property.name,
+                &vector.value,
+                now_ms,
+            )
+            .checks
+            .remove(0)
+            .failure
+        };
+
+        assert_eq!(failure(&vectors[0]), None);
+        assert_eq!(failure(&vectors[2]), Some(ValidationFailure::ValueNotAllowed));
+        assert!(matches!(
+            failure(&vectors[4]),
+            Some(ValidationFailure::ShapeMismatch { .. })
+        ));
+        assert_eq!(failure(&vectors[7]), Some(ValidationFailure::NotAnAttester));
+        assert!(matches!(
+            failure(&vectors[9]),
+            Some(ValidationFailure::NotInValueDictionary { .. })
+        ));
+        assert!(matches!(
+            failure(&vectors[10]),
+            Some(ValidationFailure::PropertyNotValid { .. })
+        ));
+
+        let federation = vectors[0].snapshot(attester, now_ms);
+        let report = diagnose_properties(
+            &federation,
+            &attester,
+            &HashMap::from([
+                (vectors[0].federation_property.name.clone(), vectors[0].value.clone()),
+                (PropertyName::from("unknown"), vectors[0].value.clone()),
+            ]),
+            now_ms,
+        );
+        assert!(!report.is_valid());
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].failure, Some(ValidationFailure::NotInFederation));
+    }
+
+    #[test]
+    fn test_diagnose_deprecated_property() {
+        let now_ms = 1_000;
+        let attester = ObjectID::random();
+        let vectors = validation_vectors(|i| PropertyName::new(["vector".to_string(), i.to_string()]));
+        let name = vectors[0].federation_property.name.clone();
+        let replacement = PropertyName::from("successor");
+        let mut federation = vectors[0].snapshot(attester, now_ms);
+        federation
+            .governance
+            .deprecated_properties
+            .insert(name.clone(), Some(replacement.clone()));
+
+        let report = diagnose_property(&federation, &attester, &name, &vectors[0].value, now_ms);
+
+        assert!(report.is_valid());
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].warning,
+            Some(ValidationWarning::Deprecated {
+                replacement: Some(replacement)
+            })
+        );
+        assert!(report.to_string().contains("use successor instead"));
+    }
+
+    #[test]
+    fn test_validate_property_values() {
+        let now_ms = 1_000;
+        let attester = ObjectID::random();
+        let vectors = validation_vectors(|_| PropertyName::from("role"));
+        // "allowed value": only `a` is granted
+        let federation = vectors[1].snapshot(attester, now_ms);
+        let name = PropertyName::from("role");
+        let values = [
+            PropertyValue::Text("a".to_string()),
+            PropertyValue::Text("b".to_string()),
+        ];
+
+        assert!(validate_property_values(
+            &federation,
+            &attester,
+            &name,
+            &values,
+            ValueSetMatch::Contains,
+            now_ms
+        ));
+        assert!(!validate_property_values(
+            &federation,
+            &attester,
+            &name,
+            &values,
+            ValueSetMatch::Superset,
+            now_ms
+        ));
+        assert!(!validate_property_values(
+            &federation,
+            &attester,
+            &name,
+            &[],
+            ValueSetMatch::Contains,
+            now_ms
+        ));
+    }
+
+    #[test]
+    fn test_diagnose_property_values() {
+        let now_ms = 1_000;
+        let attester = ObjectID::random();
+        let vectors = validation_vectors(|_| PropertyName::from("role"));
+        // "allowed value": only `a` is granted
+        let federation = vectors[1].snapshot(attester, now_ms);
+        let name = PropertyName::from("role");
+        let values = [
+            PropertyValue::Text("a".to_string()),
+            PropertyValue::Text("b".to_string()),
+        ];
+
+        for value_match in [ValueSetMatch::Contains, ValueSetMatch::Superset] {
+            for values in [&values[..], &values[..1], &values[1..], &values[..0]] {
+                let report = diagnose_property_values(&federation, &attester, &name, values, value_match, now_ms);
+                assert_eq!(
+                    report.is_valid(),
+                    validate_property_values(&federation, &attester, &name, values, value_match, now_ms),
+                    "{value_match:?} of {values:?}"
+                );
+            }
+        }
+
+        let report = diagnose_property_values(&federation, &attester, &name, &values, ValueSetMatch::Contains, now_ms);
+        assert_eq!(report.failures().count(), 1);
+        assert_eq!(
+            report.failures().next().unwrap().property_value,
+            PropertyValue::Text("b".to_string())
+        );
+    }
+}
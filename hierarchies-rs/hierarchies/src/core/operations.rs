@@ -15,24 +15,60 @@
 //! Capabilities are represented as owned objects in the IOTA network, ensuring
 //! secure and verifiable permission management.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
 use iota_interaction::rpc_types::IotaObjectDataOptions;
 use iota_interaction::types::base_types::{IotaAddress, ObjectID, ObjectRef, SequenceNumber};
 use iota_interaction::types::object::Owner;
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
-use iota_interaction::types::transaction::{CallArg, Command, ProgrammableTransaction, SharedObjectRef};
+use iota_interaction::types::transaction::{Argument, CallArg, Command, ProgrammableTransaction, SharedObjectRef};
 use iota_interaction::{IotaClientTrait, MoveType, OptionalSync, ident_str};
 use product_common::core_client::CoreClientReadOnly;
 
+use crate::client::get_object_ref_by_id_with_bcs;
+use crate::core::capability_cache::{CapabilityKey, capability_cache};
 use crate::core::error::OperationError;
-use crate::core::types::property::{FederationProperty, new_properties, new_property};
+use crate::core::prefetch::{CapabilityRef, known_initial_shared_version, remember_initial_shared_version};
+use crate::core::ptb_template::{PtbTemplate, PtbTemplateCache, accreditation_to_attest_key};
+use crate::core::transactions::BatchOperation;
+use crate::core::types::accreditation_query::{AccreditationFilter, AccreditationScope};
+use crate::core::types::grant_policy::GrantPolicy;
+use crate::core::types::property::{FederationProperty, PropertyMetadata, new_properties, new_property};
 use crate::core::types::property_name::PropertyName;
-use crate::core::types::property_value::PropertyValue;
-use crate::core::types::{ACCREDIT_CAP_TYPE, AccreditCap, ROOT_AUTHORITY_CAP_TYPE, RootAuthorityCap, move_names};
+use crate::core::types::property_value::{PropertyValue, ValueSetMatch, values_to_ptb};
+use crate::core::types::quorum::GovernanceAction;
+use crate::core::types::revocation_reason::RevocationReason;
+use crate::core::types::timespan::Timespan;
+use crate::core::types::{
+    ACCREDIT_CAP_TYPE, AccreditCap, CapabilityKind, Federation, FederationMetadata, ROOT_AUTHORITY_CAP_TYPE,
+    RootAuthorityCap, move_names,
+};
 use crate::core::{CapabilityError, get_clock_ref};
 use crate::error::{NetworkError, ObjectError};
+use crate::utils;
+
+/// The address whose capabilities authorize an operation.
+///
+/// When `capability` is known up front, it is used directly instead of scanning
+/// the objects owned by `address`. A full object reference is used without any lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CapabilityOwner {
+    pub(crate) address: IotaAddress,
+    pub(crate) capability: Option<CapabilityRef>,
+}
+
+impl CapabilityOwner {
+    pub(crate) fn new(address: IotaAddress, capability: Option<CapabilityRef>) -> Self {
+        Self { address, capability }
+    }
+}
+
+impl From<IotaAddress> for CapabilityOwner {
+    fn from(address: IotaAddress) -> Self {
+        Self::new(address, None)
+    }
+}
 
 /// Internal implementation of Hierarchies operations.
 ///
@@ -50,6 +86,62 @@ use crate::error::{NetworkError, ObjectError};
 #[derive(Debug, Clone)]
 pub(crate) struct HierarchiesImpl;
 
+/// The share of a programmable transaction taken up by a single grant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct GrantFootprint {
+    /// The number of commands the grant adds.
+    pub(crate) commands: usize,
+    /// The number of BCS bytes the grant adds.
+    pub(crate) bytes: usize,
+}
+
+/// A read query combined with others into a single transaction, see
+/// [`HierarchiesOperations::batched_queries`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BatchedQuery {
+    IsAttester {
+        federation_id: ObjectID,
+        user_id: ObjectID,
+    },
+    IsAccreditor {
+        federation_id: ObjectID,
+        user_id: ObjectID,
+    },
+    IsRootAuthority {
+        federation_id: ObjectID,
+        user_id: ObjectID,
+    },
+    GetAccreditationsToAttest {
+        federation_id: ObjectID,
+        user_id: ObjectID,
+    },
+    GetAccreditationsToAccredit {
+        federation_id: ObjectID,
+        user_id: ObjectID,
+    },
+    GetProperties {
+        federation_id: ObjectID,
+    },
+    IsPropertyInFederation {
+        federation_id: ObjectID,
+        property_name: PropertyName,
+    },
+}
+
+impl BatchedQuery {
+    fn federation_id(&self) -> ObjectID {
+        match self {
+            BatchedQuery::IsAttester { federation_id, .. }
+            | BatchedQuery::IsAccreditor { federation_id, .. }
+            | BatchedQuery::IsRootAuthority { federation_id, .. }
+            | BatchedQuery::GetAccreditationsToAttest { federation_id, .. }
+            | BatchedQuery::GetAccreditationsToAccredit { federation_id, .. }
+            | BatchedQuery::GetProperties { federation_id }
+            | BatchedQuery::IsPropertyInFederation { federation_id, .. } => *federation_id,
+        }
+    }
+}
+
 impl HierarchiesOperations for HierarchiesImpl {}
 
 impl HierarchiesImpl {
@@ -57,71 +149,121 @@ impl HierarchiesImpl {
     ///
     /// This method searches across all package versions in history to find
     /// a capability object owned by the sender, which is necessary after package upgrades.
+    /// The scan is skipped when the owner already names the capability object or the
+    /// capability is in the [`capability_cache`].
     ///
     /// # Errors
     ///
     /// Returns an error if the owner doesn't have a RootAuthorityCap.
     pub(crate) async fn get_root_authority_cap<C>(
         client: &C,
-        owner: IotaAddress,
+        owner: CapabilityOwner,
         federation_id: ObjectID,
     ) -> Result<ObjectRef, CapabilityError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let cap: RootAuthorityCap = client
-            .find_object_for_address(owner, |cap: &RootAuthorityCap| cap.federation_id == federation_id)
-            .await
-            .map_err(|e| CapabilityError::Rpc { source: e.into() })?
-            .ok_or_else(|| CapabilityError::NotFound {
-                cap_type: ROOT_AUTHORITY_CAP_TYPE.to_string(),
-            })?;
-
-        let object_id = *cap.id.object_id();
-
-        client
-            .get_object_ref_by_id(object_id)
-            .await
-            .map_err(|e| CapabilityError::Rpc { source: e.into() })?
-            .map(|owned_ref| owned_ref.reference)
-            .ok_or_else(|| CapabilityError::NotFound {
-                cap_type: ROOT_AUTHORITY_CAP_TYPE.to_string(),
-            })
+        Self::get_cap(client, owner, federation_id, CapabilityKind::RootAuthority, || async {
+            let cap: Option<RootAuthorityCap> = client
+                .find_object_for_address(owner.address, |cap: &RootAuthorityCap| {
+                    cap.federation_id == federation_id
+                })
+                .await
+                .map_err(|e| CapabilityError::Rpc { source: e.into() })?;
+            Ok(cap.map(|cap| *cap.id.object_id()))
+        })
+        .await
     }
 
     /// Retrieves an AccreditCap for the specified owner.
     ///
     /// This method searches across all package versions in history to find
     /// a capability object owned by the sender, which is necessary after package upgrades.
+    /// The scan is skipped when the owner already names the capability object or the
+    /// capability is in the [`capability_cache`].
     ///
     /// # Errors
     ///
     /// Returns an error if the owner doesn't have an AccreditCap.
     pub(crate) async fn get_accredit_cap<C>(
         client: &C,
-        owner: IotaAddress,
+        owner: CapabilityOwner,
         federation_id: ObjectID,
     ) -> Result<ObjectRef, CapabilityError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let cap: AccreditCap = client
-            .find_object_for_address(owner, |cap: &AccreditCap| cap.federation_id == federation_id)
-            .await
-            .map_err(|e| CapabilityError::Rpc { source: e.into() })?
-            .ok_or_else(|| CapabilityError::NotFound {
-                cap_type: ACCREDIT_CAP_TYPE.to_string(),
-            })?;
+        Self::get_cap(client, owner, federation_id, CapabilityKind::Accredit, || async {
+            let cap: Option<AccreditCap> = client
+                .find_object_for_address(owner.address, |cap: &AccreditCap| cap.federation_id == federation_id)
+                .await
+                .map_err(|e| CapabilityError::Rpc { source: e.into() })?;
+            Ok(cap.map(|cap| *cap.id.object_id()))
+        })
+        .await
+    }
 
-        let object_id = *cap.id.object_id();
-        client
-            .get_object_ref_by_id(object_id)
-            .await
-            .map_err(|e| CapabilityError::Rpc { source: e.into() })?
+    /// Resolves the reference of a capability of `owner`, scanning with `scan` if its ID is
+    /// neither given nor cached.
+    ///
+    /// A cached ID whose object is gone or owned by another address is dropped and the scan
+    /// repeated, so transferred capabilities are not referenced.
+    async fn get_cap<C, F, Fut>(
+        client: &C,
+        owner: CapabilityOwner,
+        federation_id: ObjectID,
+        kind: CapabilityKind,
+        scan: F,
+    ) -> Result<ObjectRef, CapabilityError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Option<ObjectID>, CapabilityError>>,
+    {
+        let not_found = || CapabilityError::NotFound {
+            cap_type: match kind {
+                CapabilityKind::RootAuthority => ROOT_AUTHORITY_CAP_TYPE,
+                CapabilityKind::Accredit => ACCREDIT_CAP_TYPE,
+            }
+            .to_string(),
+        };
+        let get_ref = |object_id: ObjectID| async move {
+            client
+                .get_object_ref_by_id(object_id)
+                .await
+                .map_err(|e| CapabilityError::Rpc { source: e.into() })
+        };
+
+        match owner.capability {
+            Some(CapabilityRef::Object(object_ref)) => return Ok(object_ref),
+            Some(CapabilityRef::Id(object_id)) => {
+                return get_ref(object_id)
+                    .await?
+                    .map(|owned_ref| owned_ref.reference)
+                    .ok_or_else(not_found);
+            }
+            None => {}
+        }
+
+        let cache = capability_cache();
+        let key = CapabilityKey::new(owner.address, federation_id, kind);
+        if let Some(object_id) = cache.get(&key) {
+            match get_ref(object_id).await? {
+                Some(owned_ref) if owned_ref.owner == Owner::AddressOwner(owner.address) => {
+                    return Ok(owned_ref.reference);
+                }
+                _ => cache.invalidate(&key),
+            }
+        }
+
+        let object_id = scan().await?.ok_or_else(not_found)?;
+        let object_ref = get_ref(object_id)
+            .await?
             .map(|owned_ref| owned_ref.reference)
-            .ok_or_else(|| CapabilityError::NotFound {
-                cap_type: ACCREDIT_CAP_TYPE.to_string(),
-            })
+            .ok_or_else(not_found)?;
+        cache.insert(key, object_id);
+
+        Ok(object_ref)
     }
 
     /// Creates a shared object reference for a federation.
@@ -148,10 +290,144 @@ impl HierarchiesImpl {
         Ok(fed_ref)
     }
 
+    /// Adds the call of a revocation function to `ptb`.
+    ///
+    /// `functions` names the function without and with a reason. With a `reason`, its code and
+    /// annotation are passed after `arguments` and before the `clock`, if any.
+    fn revocation_call(
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectID,
+        functions: (&'static str, &'static str),
+        mut arguments: Vec<Argument>,
+        clock: Option<Argument>,
+        reason: Option<RevocationReason>,
+    ) -> Result<(), OperationError> {
+        let function = match reason {
+            Some(reason) => {
+                arguments.push(ptb.pure(reason.code)?);
+                arguments.push(ptb.pure(reason.annotation)?);
+                functions.1
+            }
+            None => functions.0,
+        };
+        arguments.extend(clock);
+
+        ptb.programmable_move_call(
+            package_id,
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            function.into(),
+            vec![],
+            arguments,
+        );
+
+        Ok(())
+    }
+
+    /// Adds a `VecMap<PropertyName, PropertyValue>` of `properties` to `ptb`.
+    fn property_map(
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectID,
+        properties: &HashMap<PropertyName, PropertyValue>,
+    ) -> Result<Argument, OperationError> {
+        let mut property_names = vec![];
+        let mut property_values = vec![];
+
+        // Sorted by name so equal maps build the same transaction
+        let mut properties: Vec<_> = properties.iter().collect();
+        properties.sort_by_key(|(property_name, _)| *property_name);
+        for (property_name, property_value) in properties {
+            property_names.push(property_name.to_ptb(ptb, package_id)?);
+            property_values.push(property_value.to_ptb(ptb, package_id)?);
+        }
+
+        let property_name_tag = PropertyName::move_type(package_id);
+        let property_value_tag = PropertyValue::move_type(package_id);
+
+        let property_names_args = ptb.command(Command::new_make_move_vector(
+            Some(property_name_tag.clone()),
+            property_names,
+        ));
+        let property_values_args = ptb.command(Command::new_make_move_vector(
+            Some(property_value_tag.clone()),
+            property_values,
+        ));
+
+        Ok(ptb.programmable_move_call(
+            package_id,
+            ident_str!("utils").as_str().into(),
+            ident_str!("vec_map_from_keys_values").as_str().into(),
+            vec![property_name_tag, property_value_tag],
+            vec![property_names_args, property_values_args],
+        ))
+    }
+
+    /// Checks the properties to be granted against the grant policy of the federation.
+    ///
+    /// The policy is enforced on-chain as well; checking it up front reports
+    /// violations before a transaction is submitted. The check is skipped for an owner
+    /// with a prefetched capability reference, as those transactions are built offline.
+    async fn check_grant_policy<'a, C>(
+        client: &C,
+        owner: CapabilityOwner,
+        federation_id: ObjectID,
+        properties: impl IntoIterator<Item = &'a FederationProperty>,
+    ) -> Result<(), OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        if matches!(owner.capability, Some(CapabilityRef::Object(_))) {
+            return Ok(());
+        }
+
+        let federation: Federation = get_object_ref_by_id_with_bcs(client, &federation_id).await?;
+        let policy = federation.governance.grant_policy;
+
+        properties
+            .into_iter()
+            .try_for_each(|property| policy.check(property))
+            .map_err(OperationError::from)
+    }
+
+    /// Measures the footprint of a single grant of [`HierarchiesOperations::create_accreditations_to_attest`].
+    ///
+    /// The grant is built into a scratch transaction whose shared arguments are placeholders,
+    /// so no network access is needed.
+    pub(crate) fn grant_footprint(
+        package_id: ObjectID,
+        receiver: ObjectID,
+        want_properties: Vec<FederationProperty>,
+    ) -> Result<GrantFootprint, OperationError> {
+        let empty = bcs::to_bytes(&ProgrammableTransactionBuilder::new().finish())?.len();
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let receiver_arg = ptb.pure(receiver)?;
+        let want_properties = new_properties(package_id, &mut ptb, want_properties)?;
+        ptb.programmable_move_call(
+            package_id,
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("create_accreditation_to_attest").as_str().into(),
+            vec![],
+            vec![
+                Argument::GasCoin,
+                Argument::GasCoin,
+                receiver_arg,
+                want_properties,
+                Argument::GasCoin,
+            ],
+        );
+        let tx = ptb.finish();
+
+        Ok(GrantFootprint {
+            commands: tx.commands.len(),
+            bytes: bcs::to_bytes(&tx)?.len() - empty,
+        })
+    }
+
     /// Retrieves the initial shared version of a shared object.
     ///
     /// Required for properly referencing shared objects in IOTA transactions.
-    /// Returns an error if the object is not shared.
+    /// Returns an error if the object is not shared. The version never changes, so it is
+    /// fetched once per process, or not at all for prefetched federations.
     pub(crate) async fn initial_shared_version<C>(
         client: &C,
         object_id: &ObjectID,
@@ -159,6 +435,10 @@ impl HierarchiesImpl {
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if let Some(initial_shared_version) = known_initial_shared_version(object_id) {
+            return Ok(initial_shared_version);
+        }
+
         let owner = client
             .client_adapter()
             .read_api()
@@ -173,7 +453,10 @@ impl HierarchiesImpl {
             })?;
 
         match owner {
-            Owner::Shared(initial_shared_version) => Ok(initial_shared_version),
+            Owner::Shared(initial_shared_version) => {
+                remember_initial_shared_version(*object_id, initial_shared_version);
+                Ok(initial_shared_version)
+            }
             _ => Err(ObjectError::WrongType {
                 expected: "SharedObject".to_string(),
                 actual: "ImmOrOwnedObject".to_string(),
@@ -233,18 +516,20 @@ pub(crate) trait HierarchiesOperations {
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The property name is a wildcard, which can only be granted as an accreditation
     /// - The owner doesn't have `RootAuthorityCap`
     /// - The property name already exists in the federation
     /// - Network or transaction building fails
     async fn add_property<C>(
         federation_id: ObjectID,
         property: FederationProperty,
-        owner: IotaAddress,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        ensure_not_wildcard(&property)?;
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
@@ -275,7 +560,8 @@ pub(crate) trait HierarchiesOperations {
         federation_id: ObjectID,
         user_id: ObjectID,
         accreditation_id: ObjectID,
-        owner: IotaAddress,
+        reason: Option<RevocationReason>,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -293,13 +579,17 @@ pub(crate) trait HierarchiesOperations {
         let user_id_arg = ptb.pure(user_id)?;
         let permission_id = ptb.pure(accreditation_id)?;
         let clock = get_clock_ref(&mut ptb);
-        ptb.programmable_move_call(
+        HierarchiesImpl::revocation_call(
+            &mut ptb,
             client.package_id(),
-            ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("revoke_accreditation_to_attest").as_str().into(),
-            vec![],
-            vec![fed_ref, cap, user_id_arg, permission_id, clock],
-        );
+            (
+                "revoke_accreditation_to_attest",
+                "revoke_accreditation_to_attest_with_reason",
+            ),
+            vec![fed_ref, cap, user_id_arg, permission_id],
+            Some(clock),
+            reason,
+        )?;
 
         let tx = ptb.finish();
 
@@ -319,7 +609,7 @@ pub(crate) trait HierarchiesOperations {
     async fn add_root_authority<C>(
         federation_id: ObjectID,
         account_id: ObjectID,
-        owner: IotaAddress,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -361,12 +651,14 @@ pub(crate) trait HierarchiesOperations {
         federation_id: ObjectID,
         receiver: ObjectID,
         want_properties: Vec<FederationProperty>,
-        owner: IotaAddress,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        HierarchiesImpl::check_grant_policy(client, owner, federation_id, &want_properties).await?;
+
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let cap = HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?;
@@ -406,12 +698,14 @@ pub(crate) trait HierarchiesOperations {
         federation_id: ObjectID,
         receiver: ObjectID,
         want_properties: Vec<FederationProperty>,
-        owner: IotaAddress,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        HierarchiesImpl::check_grant_policy(client, owner, federation_id, &want_properties).await?;
+
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let cap = HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?;
@@ -438,6 +732,157 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
+    /// Creates an accreditation to attest from a cached [`PtbTemplate`].
+    ///
+    /// On a cache hit only the receiver and the capability reference are
+    /// substituted; otherwise the transaction is built and stored in `cache`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `AccreditCap`.
+    async fn create_accreditation_to_attest_from_template<C>(
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        want_properties: Vec<FederationProperty>,
+        owner: CapabilityOwner,
+        cache: &PtbTemplateCache,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let cap = HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?;
+
+        let key = accreditation_to_attest_key(client.package_id(), federation_id, owner.address, &want_properties)?;
+        if let Some(template) = cache.get(&key) {
+            return template.instantiate(receiver, cap);
+        }
+
+        HierarchiesImpl::check_grant_policy(client, owner, federation_id, &want_properties).await?;
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let clock = get_clock_ref(&mut ptb);
+        let cap_arg = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        // The receiver is substituted on reuse, so it must not be merged with an equal pure input.
+        let receiver_arg = ptb.pure_bytes(bcs::to_bytes(&receiver)?, true);
+
+        let want_properties = new_properties(client.package_id(), &mut ptb, want_properties)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("create_accreditation_to_attest").as_str().into(),
+            vec![],
+            vec![fed_ref, cap_arg, receiver_arg, want_properties, clock],
+        );
+
+        let tx = ptb.finish();
+        cache.insert(key, PtbTemplate::new(tx.clone(), receiver_arg, cap_arg)?);
+
+        Ok(tx)
+    }
+
+    /// Creates accreditations to attest for several receivers in a single transaction.
+    ///
+    /// The capability, federation and clock arguments are shared by all calls,
+    /// so either every accreditation is created or none is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `AccreditCap`.
+    async fn create_accreditations_to_attest<C>(
+        federation_id: ObjectID,
+        grants: Vec<(ObjectID, Vec<FederationProperty>)>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        HierarchiesImpl::check_grant_policy(
+            client,
+            owner,
+            federation_id,
+            grants.iter().flat_map(|(_, properties)| properties),
+        )
+        .await?;
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?;
+        let clock = get_clock_ref(&mut ptb);
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        for (receiver, want_properties) in grants {
+            let receiver_arg = ptb.pure(receiver)?;
+            let want_properties = new_properties(client.package_id(), &mut ptb, want_properties)?;
+
+            ptb.programmable_move_call(
+                client.package_id(),
+                ident_str!(move_names::MODULE_MAIN).as_str().into(),
+                ident_str!("create_accreditation_to_attest").as_str().into(),
+                vec![],
+                vec![fed_ref, cap, receiver_arg, want_properties, clock],
+            );
+        }
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Revokes several accreditations to attest in a single transaction.
+    ///
+    /// Each revocation is a `(user_id, accreditation_id)` pair. Either every
+    /// accreditation is revoked or none is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `AccreditCap`.
+    async fn revoke_accreditations_to_attest<C>(
+        federation_id: ObjectID,
+        revocations: Vec<(ObjectID, ObjectID)>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let clock = get_clock_ref(&mut ptb);
+
+        for (user_id, accreditation_id) in revocations {
+            let user_id_arg = ptb.pure(user_id)?;
+            let permission_id = ptb.pure(accreditation_id)?;
+            ptb.programmable_move_call(
+                client.package_id(),
+                ident_str!(move_names::MODULE_MAIN).as_str().into(),
+                ident_str!("revoke_accreditation_to_attest").as_str().into(),
+                vec![],
+                vec![fed_ref, cap, user_id_arg, permission_id, clock],
+            );
+        }
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
     /// Revokes a user's accreditation permissions.
     ///
     /// Removes specific accreditation rights from a user. The revoker must have
@@ -450,7 +895,8 @@ pub(crate) trait HierarchiesOperations {
         federation_id: ObjectID,
         user_id: ObjectID,
         accreditation_id: ObjectID,
-        owner: IotaAddress,
+        reason: Option<RevocationReason>,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -468,13 +914,17 @@ pub(crate) trait HierarchiesOperations {
         let user_id_arg = ptb.pure(user_id)?;
         let accreditation_id = ptb.pure(accreditation_id)?;
 
-        ptb.programmable_move_call(
+        HierarchiesImpl::revocation_call(
+            &mut ptb,
             client.package_id(),
-            ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("revoke_accreditation_to_accredit").as_str().into(),
-            vec![],
-            vec![fed_ref, cap, user_id_arg, accreditation_id, clock],
-        );
+            (
+                "revoke_accreditation_to_accredit",
+                "revoke_accreditation_to_accredit_with_reason",
+            ),
+            vec![fed_ref, cap, user_id_arg, accreditation_id],
+            Some(clock),
+            reason,
+        )?;
 
         let tx = ptb.finish();
 
@@ -552,23 +1002,19 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Retrieves attestation accreditations for a specific user.
-    ///
-    /// Returns the set of properties a user is authorized to attest, along with
-    /// any value constraints. This shows what properties the user can create
-    /// attestations for, but not what they can delegate to others.
+    /// Checks if a property is marked as critical in the federation.
     ///
     /// # Returns
     ///
-    /// A transaction that when executed returns the user's attestation
-    /// accreditations and their associated constraints.
+    /// A transaction that when executed returns true if the property is
+    /// marked as critical, false otherwise.
     ///
     /// # Errors
     ///
     /// Returns an error if the federation object is not found or not shared.
-    async fn get_accreditations_to_attest<C>(
+    async fn is_property_critical<C>(
         federation_id: ObjectID,
-        user_id: ObjectID,
+        property_name: PropertyName,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -577,14 +1023,14 @@ pub(crate) trait HierarchiesOperations {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
-        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+        let property_name = CallArg::Pure(bcs::to_bytes(&property_name)?);
 
         ptb.move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("get_accreditations_to_attest").as_str().into(),
+            ident_str!("is_property_critical").as_str().into(),
             vec![],
-            vec![fed_ref, user_id],
+            vec![fed_ref, property_name],
         )?;
 
         let tx = ptb.finish();
@@ -592,12 +1038,13 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Checks if a user has attestation permissions.
+    /// Retrieves all property names marked as critical in the federation.
     ///
-    /// Returns true if the user has any attestation accreditations in the federation.
-    async fn is_attester<C>(
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_critical_properties<C>(
         federation_id: ObjectID,
-        user_id: ObjectID,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -606,14 +1053,13 @@ pub(crate) trait HierarchiesOperations {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
-        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
 
         ptb.move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("is_attester").as_str().into(),
+            ident_str!("get_critical_properties").as_str().into(),
             vec![],
-            vec![fed_ref, user_id],
+            vec![fed_ref],
         )?;
 
         let tx = ptb.finish();
@@ -621,24 +1067,44 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Retrieves accreditation permissions for a specific user.
-    ///
-    /// Returns the set of properties a user is authorized to delegate to others
-    /// for accreditation purposes. This shows what properties the user can
-    /// grant others permission to further delegate (create_accreditation_to_accredit).
+    /// Checks if a property is deprecated in the federation.
     ///
+    /// # Errors
     ///
-    /// # Returns
-    ///
-    /// A transaction that when executed returns the user's accreditation
-    /// permissions and their associated constraints.
+    /// Returns an error if the federation object is not found or not shared.
+    async fn is_property_deprecated<C>(
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let property_name = CallArg::Pure(bcs::to_bytes(&property_name)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_property_deprecated").as_str().into(),
+            vec![],
+            vec![fed_ref, property_name],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the deprecated properties of the federation together with their replacements.
     ///
     /// # Errors
     ///
     /// Returns an error if the federation object is not found or not shared.
-    async fn get_accreditations_to_accredit<C>(
+    async fn get_deprecated_properties<C>(
         federation_id: ObjectID,
-        user_id: ObjectID,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -647,14 +1113,13 @@ pub(crate) trait HierarchiesOperations {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
-        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
 
         ptb.move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("get_accreditations_to_accredit").as_str().into(),
+            ident_str!("get_deprecated_properties").as_str().into(),
             vec![],
-            vec![fed_ref, user_id],
+            vec![fed_ref],
         )?;
 
         let tx = ptb.finish();
@@ -662,12 +1127,13 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Checks if a user has accreditation delegation permissions.
+    /// Retrieves the IDs of the child federations recognized by the federation.
     ///
-    /// Returns true if the user can grant accreditation rights to others.
-    async fn is_accreditor<C>(
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_recognized_federations<C>(
         federation_id: ObjectID,
-        user_id: ObjectID,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -676,14 +1142,13 @@ pub(crate) trait HierarchiesOperations {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
-        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
 
         ptb.move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("is_accreditor").as_str().into(),
+            ident_str!("get_recognized_federations").as_str().into(),
             vec![],
-            vec![fed_ref, user_id],
+            vec![fed_ref],
         )?;
 
         let tx = ptb.finish();
@@ -691,24 +1156,69 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Revokes a property immediately using the current timestamp.
+    /// Retrieves the schema version of the package.
     ///
-    /// Sets the property's validity expiration to the current time, effectively
-    /// revoking it immediately. After revocation, the property can no longer be
-    /// attested. Requires `RootAuthorityCap`.
+    /// Packages of the published schema lack the view, so the transaction fails to execute for them.
+    fn get_schema_version(package_id: ObjectID) -> Result<ProgrammableTransaction, OperationError> {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        ptb.move_call(
+            package_id,
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("schema_version").as_str().into(),
+            vec![],
+            vec![],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the summary statistics of the federation.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A transaction that when executed revokes the property.
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_federation_stats<C>(
+        federation_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_stats").as_str().into(),
+            vec![],
+            vec![fed_ref],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Marks or unmarks a property as critical.
+    ///
+    /// Critical properties let event consumers prioritize changes to them.
+    /// Requires `RootAuthorityCap`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the owner doesn't have `RootAuthorityCap` or the
-    /// property doesn't exist in the federation.
-    async fn revoke_property<C>(
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The property doesn't exist in the federation
+    async fn set_property_critical<C>(
         federation_id: ObjectID,
         property_name: PropertyName,
-        owner: IotaAddress,
+        critical: bool,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -717,22 +1227,20 @@ pub(crate) trait HierarchiesOperations {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
-
         let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
         let fed_ref = ptb.obj(fed_ref)?;
 
         let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
-
-        let clock = get_clock_ref(&mut ptb);
+        let critical = ptb.pure(critical)?;
 
         ptb.programmable_move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("revoke_property").as_str().into(),
+            ident_str!("set_property_critical").as_str().into(),
             vec![],
-            vec![fed_ref, cap, property_name, clock],
+            vec![fed_ref, cap, property_name, critical],
         );
 
         let tx = ptb.finish();
@@ -740,51 +1248,47 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Revokes a property at a specific future timestamp.
-    ///
-    /// Sets a specific time limit on when a property is considered valid. After
-    /// the specified time, the property can no longer be attested. This allows
-    /// for scheduled revocation. Requires `RootAuthorityCap`.
-    ///
+    /// Marks a property as deprecated, optionally naming the property replacing it.
     ///
-    /// # Returns
-    ///
-    /// A transaction that when executed revokes the property.
+    /// A deprecated property stays valid, attestations of it are still accepted, but
+    /// verifiers are warned to move to the replacement. Deprecating a property again
+    /// overwrites its replacement. Requires `RootAuthorityCap`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the owner doesn't have `RootAuthorityCap` or the
-    /// property doesn't exist in the federation.
-    async fn revoke_property_at<C>(
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The property doesn't exist in the federation
+    /// - The replacement is the property itself or doesn't exist in the federation
+    async fn deprecate_property<C>(
         federation_id: ObjectID,
         property_name: PropertyName,
-        valid_to_ms: u64,
-        owner: IotaAddress,
+        replacement: Option<PropertyName>,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        let package_id = client.package_id();
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
-
         let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
         let fed_ref = ptb.obj(fed_ref)?;
 
-        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
-
-        let valid_to_ms = ptb.pure(valid_to_ms)?;
-        let clock = get_clock_ref(&mut ptb);
+        let property_name = property_name.to_ptb(&mut ptb, package_id)?;
+        let replacement = replacement.map(|name| name.to_ptb(&mut ptb, package_id)).transpose()?;
+        let replacement = utils::option_to_move(replacement, PropertyName::move_type(package_id), &mut ptb)?;
 
         ptb.programmable_move_call(
-            client.package_id(),
+            package_id,
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("revoke_property_at").as_str().into(),
+            ident_str!("deprecate_property").as_str().into(),
             vec![],
-            vec![fed_ref, cap, property_name, valid_to_ms, clock],
+            vec![fed_ref, cap, property_name, replacement],
         );
 
         let tx = ptb.finish();
@@ -792,26 +1296,21 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Validates a single property against federation rules.
-    ///
-    /// Checks if the specified attester has permission to attest the given
-    /// property name and value combination according to their accreditations.
-    /// This creates a transaction that will return true if the attester can make
-    /// the attestation, false otherwise.
-    ///
-    /// # Returns
+    /// Creates or replaces a named value dictionary of the federation.
     ///
-    /// A transaction that when executed returns a boolean indicating whether
-    /// the attestation is valid according to federation rules.
+    /// Properties referencing the dictionary accept its new values right away.
+    /// Requires `RootAuthorityCap`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the federation object is not found or not shared.
-    async fn validate_property<C>(
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - `values` is empty
+    async fn set_value_dictionary<C>(
         federation_id: ObjectID,
-        attester_id: ObjectID,
-        property_name: PropertyName,
-        property_value: PropertyValue,
+        name: String,
+        values: HashSet<PropertyValue>,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -819,23 +1318,27 @@ pub(crate) trait HierarchiesOperations {
     {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
         let fed_ref = ptb.obj(fed_ref)?;
 
-        let attester_id = ptb.pure(attester_id)?;
-
-        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
-
-        let property_value = property_value.to_ptb(&mut ptb, client.package_id())?;
-
-        let clock = get_clock_ref(&mut ptb);
+        let name = ptb.pure(name)?;
+        let values = values_to_ptb(&values, &mut ptb, client.package_id())?;
+        let values = utils::create_vec_set_from_move_values(
+            values,
+            PropertyValue::move_type(client.package_id()),
+            &mut ptb,
+            client.package_id(),
+        );
 
         ptb.programmable_move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("validate_property").as_str().into(),
+            ident_str!("set_value_dictionary").as_str().into(),
             vec![],
-            vec![fed_ref, attester_id, property_name, property_value, clock],
+            vec![fed_ref, cap, name, values],
         );
 
         let tx = ptb.finish();
@@ -843,25 +1346,17 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Validates multiple properties against federation rules.
+    /// Sets the defaults and limits applied to accreditations granted in the federation.
     ///
-    /// Checks if the specified entity has permission to attest all provided
-    /// property name-value pairs according to their accreditations.
-    /// This is more efficient than multiple single-property validations.
-    ///
-    ///
-    /// # Returns
-    ///
-    /// A transaction that when executed returns true only if the entity
-    /// has permission to attest all provided properties.
+    /// Existing accreditations are not affected. Requires `RootAuthorityCap`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the federation object is not found or not shared.
-    async fn validate_properties<C>(
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn set_grant_policy<C>(
         federation_id: ObjectID,
-        entity_id: ObjectID,
-        properties: HashMap<PropertyName, PropertyValue>,
+        policy: GrantPolicy,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -869,49 +1364,38 @@ pub(crate) trait HierarchiesOperations {
     {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
         let fed_ref = ptb.obj(fed_ref)?;
 
-        let mut property_names = vec![];
-        let mut property_values = vec![];
-
-        for (property_name, property_value) in properties.iter() {
-            let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
-            property_names.push(property_name);
-
-            let property_value = property_value.to_ptb(&mut ptb, client.package_id())?;
-            property_values.push(property_value);
-        }
-
-        let property_name_tag = PropertyName::move_type(client.package_id());
-        let property_value_tag = PropertyValue::move_type(client.package_id());
-
-        let property_names_args = ptb.command(Command::new_make_move_vector(
-            Some(property_name_tag.clone()),
-            property_names,
-        ));
-        let property_values_args = ptb.command(Command::new_make_move_vector(
-            Some(property_value_tag.clone()),
-            property_values,
-        ));
+        let default_validity_ms = ptb.pure(policy.default_validity_ms)?;
+        let allow_any_permitted = ptb.pure(policy.allow_any_permitted)?;
+        let max_allowed_values = ptb.pure(policy.max_allowed_values)?;
 
-        let properties = ptb.programmable_move_call(
+        ptb.programmable_move_call(
             client.package_id(),
-            ident_str!("utils").as_str().into(),
-            ident_str!("vec_map_from_keys_values").as_str().into(),
-            vec![property_name_tag, property_value_tag],
-            vec![property_names_args, property_values_args],
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("set_grant_policy").as_str().into(),
+            vec![],
+            vec![
+                fed_ref,
+                cap,
+                default_validity_ms,
+                allow_any_permitted,
+                max_allowed_values,
+            ],
         );
 
-        let entity_id = ptb.pure(entity_id)?;
-        let clock = get_clock_ref(&mut ptb);
-
+        // The objection window is set by a separate call, see `set_objection_window` in the Move package
+        let objection_window_ms = ptb.pure(policy.objection_window_ms)?;
         ptb.programmable_move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("validate_properties").as_str().into(),
+            ident_str!("set_objection_window").as_str().into(),
             vec![],
-            vec![fed_ref, entity_id, properties, clock],
+            vec![fed_ref, cap, objection_window_ms],
         );
 
         let tx = ptb.finish();
@@ -919,10 +1403,17 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Check if root authority is in the federation.
-    async fn is_root_authority<C>(
+    /// Sets the metadata of the federation, replacing the previous one.
+    ///
+    /// Requires `RootAuthorityCap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn set_federation_metadata<C>(
         federation_id: ObjectID,
-        user_id: ObjectID,
+        metadata: FederationMetadata,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -930,38 +1421,41 @@ pub(crate) trait HierarchiesOperations {
     {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
-        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+        let fed_ref = ptb.obj(fed_ref)?;
 
-        ptb.move_call(
+        let name = ptb.pure(metadata.name)?;
+        let description = ptb.pure(metadata.description)?;
+        let logo_uri = ptb.pure(metadata.logo_uri)?;
+        let contact = ptb.pure(metadata.contact)?;
+
+        ptb.programmable_move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("is_root_authority").as_str().into(),
+            ident_str!("set_federation_metadata").as_str().into(),
             vec![],
-            vec![fed_ref, user_id],
-        )?;
+            vec![fed_ref, cap, name, description, logo_uri, contact],
+        );
 
         let tx = ptb.finish();
 
         Ok(tx)
     }
 
-    /// Revokes a root authority from the federation.
+    /// Ends the objection window of an accreditation, making it effective immediately.
     ///
-    /// Only existing root authorities can revoke other root authorities.
-    /// Cannot revoke the last root authority to prevent lockout.
-    /// The revoked authority's capability remains but becomes unusable.
+    /// Only root authorities can activate accreditations.
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The owner doesn't have `RootAuthorityCap`
-    /// - The account_id is not a root authority
-    /// - Attempting to revoke the last root authority
-    async fn revoke_root_authority<C>(
+    /// Returns an error if the federation object or the root authority capability is not found.
+    async fn activate_accreditation<C>(
         federation_id: ObjectID,
-        account_id: ObjectID,
-        owner: IotaAddress,
+        accreditation_id: ObjectID,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
@@ -970,20 +1464,19 @@ pub(crate) trait HierarchiesOperations {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
         let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
-
         let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
         let fed_ref = ptb.obj(fed_ref)?;
 
-        let account_id_arg = ptb.pure(account_id)?;
+        let accreditation_id = ptb.pure(accreditation_id)?;
 
         ptb.programmable_move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("revoke_root_authority").as_str().into(),
+            ident_str!("activate_accreditation").as_str().into(),
             vec![],
-            vec![fed_ref, cap, account_id_arg],
+            vec![fed_ref, cap, accreditation_id],
         );
 
         let tx = ptb.finish();
@@ -991,48 +1484,1807 @@ pub(crate) trait HierarchiesOperations {
         Ok(tx)
     }
 
-    /// Reinstates a previously revoked root authority to the federation.
+    /// Replaces the metadata of a property, empty metadata removes all entries.
     ///
-    /// This operation allows an existing root authority to restore a revoked root authority
-    /// back to active status. The target account must be in the federation's revoked list
-    /// and cannot already be an active root authority.
+    /// Requires `RootAuthorityCap`.
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The owner doesn't have `RootAuthorityCap`
-    /// - The account is not in the revoked root authorities list
-    /// - The account is already an active root authority
-    /// - Network communication fails
-    async fn reinstate_root_authority<C>(
+    /// - The property doesn't exist in the federation
+    async fn set_property_metadata<C>(
         federation_id: ObjectID,
-        account_id: ObjectID,
-        owner: IotaAddress,
+        property_name: PropertyName,
+        metadata: PropertyMetadata,
+        owner: CapabilityOwner,
         client: &C,
     ) -> Result<ProgrammableTransaction, OperationError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
         let mut ptb = ProgrammableTransactionBuilder::new();
-        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
 
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
         let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
 
         let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
         let fed_ref = ptb.obj(fed_ref)?;
 
-        let account_id_arg = ptb.pure(account_id)?;
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+        let (keys, values): (Vec<_>, Vec<_>) = metadata.entries().clone().into_iter().unzip();
+        let keys = ptb.pure(keys)?;
+        let values = ptb.pure(values)?;
 
         ptb.programmable_move_call(
             client.package_id(),
             ident_str!(move_names::MODULE_MAIN).as_str().into(),
-            ident_str!("reinstate_root_authority").as_str().into(),
+            ident_str!("set_property_metadata").as_str().into(),
             vec![],
-            vec![fed_ref, cap, account_id_arg],
+            vec![fed_ref, cap, property_name, keys, values],
         );
 
         let tx = ptb.finish();
 
         Ok(tx)
     }
+
+    /// Replaces the tags of a property, an empty set removes all tags.
+    ///
+    /// Requires `RootAuthorityCap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The property doesn't exist in the federation
+    async fn set_property_tags<C>(
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        tags: HashSet<String>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+        let tags = ptb.pure(tags.into_iter().collect::<Vec<_>>())?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("set_property_tags").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, property_name, tags],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Replaces the tags of an accreditation, an empty set removes all tags.
+    ///
+    /// Only the issuer of the accreditation or a root authority can set its tags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn set_accreditation_tags<C>(
+        federation_id: ObjectID,
+        accreditation_id: ObjectID,
+        tags: HashSet<String>,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let accreditation_id = ptb.pure(accreditation_id)?;
+        let tags = ptb.pure(tags.into_iter().collect::<Vec<_>>())?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("set_accreditation_tags").as_str().into(),
+            vec![],
+            vec![fed_ref, accreditation_id, tags],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the names of all properties labelled with `tag`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_properties_by_tag<C>(
+        federation_id: ObjectID,
+        tag: String,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let tag = CallArg::Pure(bcs::to_bytes(&tag)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_properties_by_tag").as_str().into(),
+            vec![],
+            vec![fed_ref, tag],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the names of all properties in the namespace `prefix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_properties_by_prefix<C>(
+        federation_id: ObjectID,
+        prefix: PropertyName,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let prefix = CallArg::Pure(bcs::to_bytes(&prefix)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_properties_by_prefix").as_str().into(),
+            vec![],
+            vec![fed_ref, prefix],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Gets a page of the properties of a federation.
+    ///
+    /// Returns up to `limit` properties starting at index `cursor`. The on-chain view caps
+    /// `limit` at 100.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns a `PropertyPage`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_properties_page<C>(
+        federation_id: ObjectID,
+        cursor: u64,
+        limit: u64,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let cursor = CallArg::Pure(bcs::to_bytes(&cursor)?);
+        let limit = CallArg::Pure(bcs::to_bytes(&limit)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_properties_page").as_str().into(),
+            vec![],
+            vec![fed_ref, cursor, limit],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the tags of a property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_property_tags<C>(
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let property_name = CallArg::Pure(bcs::to_bytes(&property_name)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_property_tags").as_str().into(),
+            vec![],
+            vec![fed_ref, property_name],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the tags of an accreditation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_accreditation_tags<C>(
+        federation_id: ObjectID,
+        accreditation_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let accreditation_id = CallArg::Pure(bcs::to_bytes(&accreditation_id)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_accreditation_tags").as_str().into(),
+            vec![],
+            vec![fed_ref, accreditation_id],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Removes a value dictionary of the federation.
+    ///
+    /// Requires `RootAuthorityCap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The dictionary doesn't exist or is still referenced by a property
+    async fn remove_value_dictionary<C>(
+        federation_id: ObjectID,
+        name: String,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let name = ptb.pure(name)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("remove_value_dictionary").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, name],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the names of all value dictionaries of the federation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_value_dictionary_names<C>(
+        federation_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_value_dictionary_names").as_str().into(),
+            vec![],
+            vec![fed_ref],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves the values of a value dictionary of the federation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    /// Executing the transaction fails if the dictionary doesn't exist.
+    async fn get_value_dictionary<C>(
+        federation_id: ObjectID,
+        name: String,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let name = CallArg::Pure(bcs::to_bytes(&name)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_value_dictionary").as_str().into(),
+            vec![],
+            vec![fed_ref, name],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves attestation accreditations for a specific user.
+    ///
+    /// Returns the set of properties a user is authorized to attest, along with
+    /// any value constraints. This shows what properties the user can create
+    /// attestations for, but not what they can delegate to others.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns the user's attestation
+    /// accreditations and their associated constraints.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_accreditations_to_attest<C>(
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_accreditations_to_attest").as_str().into(),
+            vec![],
+            vec![fed_ref, user_id],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Checks if a user has attestation permissions.
+    ///
+    /// Returns true if the user has any attestation accreditations in the federation.
+    async fn is_attester<C>(
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_attester").as_str().into(),
+            vec![],
+            vec![fed_ref, user_id],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Retrieves accreditation permissions for a specific user.
+    ///
+    /// Returns the set of properties a user is authorized to delegate to others
+    /// for accreditation purposes. This shows what properties the user can
+    /// grant others permission to further delegate (create_accreditation_to_accredit).
+    ///
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns the user's accreditation
+    /// permissions and their associated constraints.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_accreditations_to_accredit<C>(
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("get_accreditations_to_accredit").as_str().into(),
+            vec![],
+            vec![fed_ref, user_id],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Gets a page of the accreditations of a user.
+    ///
+    /// Scans the accreditations of `scope` starting at index `cursor` and returns up to
+    /// `limit` of those passing `filter`. The on-chain view caps `limit` at 100.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns an `AccreditationPage`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn get_accreditations_page<C>(
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        scope: AccreditationScope,
+        cursor: u64,
+        limit: u64,
+        filter: &AccreditationFilter,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let package_id = client.package_id();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+        let user_id = ptb.pure(user_id)?;
+        let cursor = ptb.pure(cursor)?;
+        let limit = ptb.pure(limit)?;
+
+        let property_name = filter
+            .property_name_filter()
+            .map(|name| name.to_ptb(&mut ptb, package_id))
+            .transpose()?;
+        let property_name = utils::option_to_move(property_name, PropertyName::move_type(package_id), &mut ptb)?;
+
+        let accredited_by = ptb.pure(filter.accredited_by_filter())?;
+        let active_only = ptb.pure(filter.is_active_only())?;
+        let clock = get_clock_ref(&mut ptb);
+
+        let function = match scope {
+            AccreditationScope::Attest => ident_str!("get_accreditations_to_attest_page"),
+            AccreditationScope::Accredit => ident_str!("get_accreditations_to_accredit_page"),
+        };
+
+        ptb.programmable_move_call(
+            package_id,
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            function.into(),
+            vec![],
+            vec![
+                fed_ref,
+                user_id,
+                cursor,
+                limit,
+                property_name,
+                accredited_by,
+                active_only,
+                clock,
+            ],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Checks if a user has accreditation delegation permissions.
+    ///
+    /// Returns true if the user can grant accreditation rights to others.
+    async fn is_accreditor<C>(
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_accreditor").as_str().into(),
+            vec![],
+            vec![fed_ref, user_id],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Revokes a property immediately using the current timestamp.
+    ///
+    /// Sets the property's validity expiration to the current time, effectively
+    /// revoking it immediately. After revocation, the property can no longer be
+    /// attested. Requires `RootAuthorityCap`.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed revokes the property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap` or the
+    /// property doesn't exist in the federation.
+    async fn revoke_property<C>(
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        reason: Option<RevocationReason>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+
+        let clock = get_clock_ref(&mut ptb);
+
+        HierarchiesImpl::revocation_call(
+            &mut ptb,
+            client.package_id(),
+            ("revoke_property", "revoke_property_with_reason"),
+            vec![fed_ref, cap, property_name],
+            Some(clock),
+            reason,
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Revokes a property at a specific future timestamp.
+    ///
+    /// Sets a specific time limit on when a property is considered valid. After
+    /// the specified time, the property can no longer be attested. This allows
+    /// for scheduled revocation. Requires `RootAuthorityCap`.
+    ///
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed revokes the property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap` or the
+    /// property doesn't exist in the federation.
+    async fn revoke_property_at<C>(
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        valid_to_ms: u64,
+        reason: Option<RevocationReason>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+
+        let valid_to_ms = ptb.pure(valid_to_ms)?;
+        let clock = get_clock_ref(&mut ptb);
+
+        HierarchiesImpl::revocation_call(
+            &mut ptb,
+            client.package_id(),
+            ("revoke_property_at", "revoke_property_at_with_reason"),
+            vec![fed_ref, cap, property_name, valid_to_ms],
+            Some(clock),
+            reason,
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Validates a single property against federation rules.
+    ///
+    /// Checks if the specified attester has permission to attest the given
+    /// property name and value combination according to their accreditations.
+    /// This creates a transaction that will return true if the attester can make
+    /// the attestation, false otherwise.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns a boolean indicating whether
+    /// the attestation is valid according to federation rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn validate_property<C>(
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let attester_id = ptb.pure(attester_id)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+
+        let property_value = property_value.to_ptb(&mut ptb, client.package_id())?;
+
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("validate_property").as_str().into(),
+            vec![],
+            vec![fed_ref, attester_id, property_name, property_value, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Checks if an entity is allowed to attest a value for a property.
+    ///
+    /// Calls the composable `is_allowed_to_attest` view of the package.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns a boolean indicating whether
+    /// the entity may attest the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn is_allowed_to_attest<C>(
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let entity_id = ptb.pure(entity_id)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+
+        let property_value = property_value.to_ptb(&mut ptb, client.package_id())?;
+
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_allowed_to_attest").as_str().into(),
+            vec![],
+            vec![fed_ref, entity_id, property_name, property_value, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Checks if an entity is allowed to delegate accreditations for a property.
+    ///
+    /// Calls the composable `is_allowed_to_accredit` view of the package.
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns a boolean indicating whether
+    /// the entity may accredit others for the property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn is_allowed_to_accredit<C>(
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: PropertyName,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let entity_id = ptb.pure(entity_id)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_allowed_to_accredit").as_str().into(),
+            vec![],
+            vec![fed_ref, entity_id, property_name, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Validates a set of values attested for a single property.
+    ///
+    /// Depending on `value_match`, the attester must either be accredited for
+    /// every value of the set ([`ValueSetMatch::Superset`]) or for at least one
+    /// of them ([`ValueSetMatch::Contains`]).
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns a boolean indicating whether
+    /// the set of values is valid according to federation rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn validate_property_values<C>(
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_values: HashSet<PropertyValue>,
+        value_match: ValueSetMatch,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let attester_id = ptb.pure(attester_id)?;
+
+        let property_name = property_name.to_ptb(&mut ptb, client.package_id())?;
+
+        let property_values = values_to_ptb(&property_values, &mut ptb, client.package_id())?;
+        let property_values = ptb.command(Command::new_make_move_vector(
+            Some(PropertyValue::move_type(client.package_id())),
+            property_values,
+        ));
+
+        let require_all = ptb.pure(value_match.require_all())?;
+
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("validate_property_values").as_str().into(),
+            vec![],
+            vec![fed_ref, attester_id, property_name, property_values, require_all, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Validates multiple properties against federation rules.
+    ///
+    /// Checks if the specified entity has permission to attest all provided
+    /// property name-value pairs according to their accreditations.
+    /// This is more efficient than multiple single-property validations.
+    ///
+    ///
+    /// # Returns
+    ///
+    /// A transaction that when executed returns true only if the entity
+    /// has permission to attest all provided properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation object is not found or not shared.
+    async fn validate_properties<C>(
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let properties = HierarchiesImpl::property_map(&mut ptb, client.package_id(), &properties)?;
+
+        let entity_id = ptb.pure(entity_id)?;
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("validate_properties").as_str().into(),
+            vec![],
+            vec![fed_ref, entity_id, properties, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Validates properties attested by `entity_id` in several federations with a single transaction.
+    ///
+    /// The `validate_properties` calls are the last commands of the transaction, in the order of
+    /// `checks`, so their results can be read from the end of the execution results.
+    async fn validate_properties_multi<C>(
+        checks: &[(ObjectID, HashMap<PropertyName, PropertyValue>)],
+        entity_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let mut arguments = Vec::with_capacity(checks.len());
+        for (federation_id, properties) in checks {
+            let fed_ref = HierarchiesImpl::get_fed_ref(client, *federation_id).await?;
+            let fed_ref = ptb.obj(fed_ref)?;
+            let properties = HierarchiesImpl::property_map(&mut ptb, client.package_id(), properties)?;
+            arguments.push((fed_ref, properties));
+        }
+
+        let entity_id = ptb.pure(entity_id)?;
+        let clock = get_clock_ref(&mut ptb);
+
+        for (fed_ref, properties) in arguments {
+            ptb.programmable_move_call(
+                client.package_id(),
+                ident_str!(move_names::MODULE_MAIN).as_str().into(),
+                ident_str!("validate_properties").as_str().into(),
+                vec![],
+                vec![fed_ref, entity_id, properties, clock],
+            );
+        }
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Runs several read queries in a single transaction.
+    ///
+    /// Every query adds exactly one command, so the result of the query at index `i` is the
+    /// return value of command `i`. Federations referenced by several queries are passed once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a federation object is not found or not shared.
+    async fn batched_queries<C>(queries: &[BatchedQuery], client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let mut fed_refs = HashMap::new();
+        for query in queries {
+            let federation_id = query.federation_id();
+            if !fed_refs.contains_key(&federation_id) {
+                let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+                fed_refs.insert(federation_id, ptb.obj(fed_ref)?);
+            }
+        }
+
+        for query in queries {
+            let fed_ref = fed_refs[&query.federation_id()];
+            let (function, argument) = match query {
+                BatchedQuery::IsAttester { user_id, .. } => (ident_str!("is_attester"), Some(ptb.pure(user_id)?)),
+                BatchedQuery::IsAccreditor { user_id, .. } => (ident_str!("is_accreditor"), Some(ptb.pure(user_id)?)),
+                BatchedQuery::IsRootAuthority { user_id, .. } => {
+                    (ident_str!("is_root_authority"), Some(ptb.pure(user_id)?))
+                }
+                BatchedQuery::GetAccreditationsToAttest { user_id, .. } => {
+                    (ident_str!("get_accreditations_to_attest"), Some(ptb.pure(user_id)?))
+                }
+                BatchedQuery::GetAccreditationsToAccredit { user_id, .. } => {
+                    (ident_str!("get_accreditations_to_accredit"), Some(ptb.pure(user_id)?))
+                }
+                BatchedQuery::GetProperties { .. } => (ident_str!("get_properties"), None),
+                BatchedQuery::IsPropertyInFederation { property_name, .. } => {
+                    (ident_str!("is_property_in_federation"), Some(ptb.pure(property_name)?))
+                }
+            };
+
+            ptb.programmable_move_call(
+                client.package_id(),
+                ident_str!(move_names::MODULE_MAIN).as_str().into(),
+                function.into(),
+                vec![],
+                std::iter::once(fed_ref).chain(argument).collect(),
+            );
+        }
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Issues an attestation of `properties` to `receiver`.
+    ///
+    /// The attestation is valid within `timespan`; an open start means it is valid right away.
+    /// The sender must be allowed to attest all properties, which is checked on-chain.
+    async fn create_attestation<C>(
+        federation_id: ObjectID,
+        receiver: IotaAddress,
+        properties: HashMap<PropertyName, PropertyValue>,
+        timespan: Timespan,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let receiver = ptb.pure(receiver)?;
+        let properties = HierarchiesImpl::property_map(&mut ptb, client.package_id(), &properties)?;
+        let valid_from_ms = ptb.pure(timespan.valid_from_ms)?;
+        let valid_until_ms = ptb.pure(timespan.valid_until_ms)?;
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("create_attestation").as_str().into(),
+            vec![],
+            vec![fed_ref, receiver, properties, valid_from_ms, valid_until_ms, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Revokes an attestation.
+    ///
+    /// Only the issuer of the attestation and root authorities can revoke it, which is checked on-chain.
+    async fn revoke_attestation<C>(
+        federation_id: ObjectID,
+        attestation_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let attestation_id = ptb.pure(attestation_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("revoke_attestation").as_str().into(),
+            vec![],
+            vec![fed_ref, attestation_id],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Checks if an attestation issued in the federation is revoked.
+    async fn is_attestation_revoked<C>(
+        federation_id: ObjectID,
+        attestation_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let attestation_id = ptb.pure(attestation_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_attestation_revoked").as_str().into(),
+            vec![],
+            vec![fed_ref, attestation_id],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Validates an attestation.
+    ///
+    /// The attestation is valid if it was issued in the federation, is not revoked, is within its
+    /// validity window and its issuer is still allowed to attest all of its properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attestation object is not found.
+    async fn validate_attestation<C>(
+        federation_id: ObjectID,
+        attestation_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let attestation = client
+            .get_object_ref_by_id(attestation_id)
+            .await
+            .map_err(|e| ObjectError::RetrievalFailed { source: e.into() })?
+            .ok_or_else(|| ObjectError::NotFound {
+                id: attestation_id.to_string(),
+            })?;
+        let attestation = ptb.obj(CallArg::ImmutableOrOwned(attestation.reference))?;
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("validate_attestation").as_str().into(),
+            vec![],
+            vec![fed_ref, attestation, clock],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Check if root authority is in the federation.
+    async fn is_root_authority<C>(
+        federation_id: ObjectID,
+        user_id: ObjectID,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let user_id = CallArg::Pure(bcs::to_bytes(&user_id)?);
+
+        ptb.move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("is_root_authority").as_str().into(),
+            vec![],
+            vec![fed_ref, user_id],
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Revokes a root authority from the federation.
+    ///
+    /// Only existing root authorities can revoke other root authorities.
+    /// Cannot revoke the last root authority to prevent lockout.
+    /// The revoked authority's capability remains but becomes unusable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The account_id is not a root authority
+    /// - Attempting to revoke the last root authority
+    async fn revoke_root_authority<C>(
+        federation_id: ObjectID,
+        account_id: ObjectID,
+        reason: Option<RevocationReason>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let account_id_arg = ptb.pure(account_id)?;
+
+        HierarchiesImpl::revocation_call(
+            &mut ptb,
+            client.package_id(),
+            ("revoke_root_authority", "revoke_root_authority_with_reason"),
+            vec![fed_ref, cap, account_id_arg],
+            None,
+            reason,
+        )?;
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Reinstates a previously revoked root authority to the federation.
+    ///
+    /// This operation allows an existing root authority to restore a revoked root authority
+    /// back to active status. The target account must be in the federation's revoked list
+    /// and cannot already be an active root authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The account is not in the revoked root authorities list
+    /// - The account is already an active root authority
+    /// - Network communication fails
+    async fn reinstate_root_authority<C>(
+        federation_id: ObjectID,
+        account_id: ObjectID,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let account_id_arg = ptb.pure(account_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("reinstate_root_authority").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, account_id_arg],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Hands the root authority of `old_account_id` over to `new_account_id` in a single call.
+    ///
+    /// The new root authority is added and receives a `RootAuthorityCap`, then the old one is
+    /// revoked. Both happen in the same transaction, so a failure leaves the federation unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - `old_account_id` is not a root authority
+    /// - `new_account_id` is already a root authority
+    /// - A quorum is required for destructive actions
+    async fn rotate_root_authority<C>(
+        federation_id: ObjectID,
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let old_account_id = ptb.pure(old_account_id)?;
+        let new_account_id = ptb.pure(new_account_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("rotate_root_authority").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, old_account_id, new_account_id],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Recognizes `child_federation_id` as a child federation of the federation.
+    ///
+    /// Trust chains ending at a root authority of the child federation are trusted like chains
+    /// of the federation itself. Requires `RootAuthorityCap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The child federation is the federation itself or already recognized
+    async fn link_federation<C>(
+        federation_id: ObjectID,
+        child_federation_id: ObjectID,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let child_federation_id = ptb.pure(child_federation_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("link_federation").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, child_federation_id],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Stops recognizing `child_federation_id` as a child federation of the federation.
+    ///
+    /// Requires `RootAuthorityCap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The owner doesn't have `RootAuthorityCap`
+    /// - The child federation is not recognized
+    async fn unlink_federation<C>(
+        federation_id: ObjectID,
+        child_federation_id: ObjectID,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let child_federation_id = ptb.pure(child_federation_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("unlink_federation").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, child_federation_id],
+        );
+
+        let tx = ptb.finish();
+
+        Ok(tx)
+    }
+
+    /// Transfers a capability of the owner to `recipient`, together with the root authority
+    /// and the accreditations of the owner.
+    ///
+    /// While a quorum is required, the transfer must be confirmed by another root authority
+    /// first, see [`Self::confirm_capability_transfer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have a capability of `kind`.
+    async fn transfer_capability<C>(
+        federation_id: ObjectID,
+        kind: CapabilityKind,
+        recipient: IotaAddress,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let (cap, function) = match kind {
+            CapabilityKind::RootAuthority => (
+                HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?,
+                ident_str!("transfer_root_authority_cap"),
+            ),
+            CapabilityKind::Accredit => (
+                HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?,
+                ident_str!("transfer_accredit_cap"),
+            ),
+        };
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let recipient = ptb.pure(recipient)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            function.into(),
+            vec![],
+            vec![fed_ref, cap, recipient],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Confirms the transfer of the capability `capability_id` to `recipient`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn confirm_capability_transfer<C>(
+        federation_id: ObjectID,
+        capability_id: ObjectID,
+        recipient: IotaAddress,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let capability_id = ptb.pure(capability_id)?;
+        let recipient = ptb.pure(recipient)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("confirm_capability_transfer").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, capability_id, recipient],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Sets the number of root authority approvals required for destructive actions.
+    ///
+    /// Once a threshold above 1 is set, it can only be changed through a proposal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn set_quorum_threshold<C>(
+        federation_id: ObjectID,
+        threshold: u64,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let threshold = ptb.pure(threshold)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("set_quorum_threshold").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, threshold],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Proposes a governance action, approved by the proposer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn propose_action<C>(
+        federation_id: ObjectID,
+        action: GovernanceAction,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let action = action.to_ptb(&mut ptb, client.package_id())?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("propose_action").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, action],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Approves a proposed governance action.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn approve_action<C>(
+        federation_id: ObjectID,
+        proposal_id: u64,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let proposal_id = ptb.pure(proposal_id)?;
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("approve_action").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, proposal_id],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Executes a governance action approved by the quorum of root authorities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+    async fn execute_action<C>(
+        federation_id: ObjectID,
+        proposal_id: u64,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+
+        let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+        let cap = ptb.obj(CallArg::ImmutableOrOwned(cap))?;
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+
+        let proposal_id = ptb.pure(proposal_id)?;
+        let clock = get_clock_ref(&mut ptb);
+
+        ptb.programmable_move_call(
+            client.package_id(),
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            ident_str!("execute_action").as_str().into(),
+            vec![],
+            vec![fed_ref, cap, proposal_id, clock],
+        );
+
+        Ok(ptb.finish())
+    }
+
+    /// Combines several operations on a federation into one transaction.
+    ///
+    /// The capabilities, the federation reference and the clock are resolved once and
+    /// shared by all operations, which are executed in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `operations` is empty
+    /// - The owner lacks the `RootAuthorityCap` or `AccreditCap` required by an operation
+    /// - A granted property violates the grant policy of the federation
+    async fn batch<C>(
+        federation_id: ObjectID,
+        operations: Vec<BatchOperation>,
+        owner: CapabilityOwner,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        if operations.is_empty() {
+            return Err(OperationError::EmptyBatch);
+        }
+
+        let granted = operations.iter().flat_map(|operation| match operation {
+            BatchOperation::CreateAccreditationToAttest { properties, .. }
+            | BatchOperation::CreateAccreditationToAccredit { properties, .. } => properties.as_slice(),
+            _ => &[],
+        });
+        if granted.clone().next().is_some() {
+            HierarchiesImpl::check_grant_policy(client, owner, federation_id, granted).await?;
+        }
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let package_id = client.package_id();
+
+        let root_cap = if operations.iter().any(BatchOperation::requires_root_authority) {
+            let cap = HierarchiesImpl::get_root_authority_cap(client, owner, federation_id).await?;
+            Some(ptb.obj(CallArg::ImmutableOrOwned(cap))?)
+        } else {
+            None
+        };
+        let accredit_cap = if operations.iter().any(|operation| !operation.requires_root_authority()) {
+            let cap = HierarchiesImpl::get_accredit_cap(client, owner, federation_id).await?;
+            Some(ptb.obj(CallArg::ImmutableOrOwned(cap))?)
+        } else {
+            None
+        };
+
+        let fed_ref = HierarchiesImpl::get_fed_ref(client, federation_id).await?;
+        let fed_ref = ptb.obj(fed_ref)?;
+        let clock = accredit_cap.map(|_| get_clock_ref(&mut ptb));
+
+        for operation in operations {
+            let (cap, clock) = if operation.requires_root_authority() {
+                (root_cap, None)
+            } else {
+                (accredit_cap, clock)
+            };
+            let cap = cap.expect("the capability of every operation is resolved");
+
+            let (function, args) = match operation {
+                BatchOperation::AddProperty(property) => {
+                    ensure_not_wildcard(&property)?;
+                    let property = new_property(package_id, &mut ptb, property)?;
+                    (ident_str!("add_property"), vec![property])
+                }
+                BatchOperation::SetPropertyCritical {
+                    property_name,
+                    critical,
+                } => {
+                    let property_name = property_name.to_ptb(&mut ptb, package_id)?;
+                    (
+                        ident_str!("set_property_critical"),
+                        vec![property_name, ptb.pure(critical)?],
+                    )
+                }
+                BatchOperation::SetValueDictionary { name, values } => {
+                    let name = ptb.pure(name)?;
+                    let values = values
+                        .iter()
+                        .map(|value| value.to_ptb(&mut ptb, package_id))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let values = utils::create_vec_set_from_move_values(
+                        values,
+                        PropertyValue::move_type(package_id),
+                        &mut ptb,
+                        package_id,
+                    );
+                    (ident_str!("set_value_dictionary"), vec![name, values])
+                }
+                BatchOperation::AddRootAuthority(account_id) => {
+                    (ident_str!("add_root_authority"), vec![ptb.pure(account_id)?])
+                }
+                BatchOperation::CreateAccreditationToAttest { receiver, properties } => {
+                    let receiver = ptb.pure(receiver)?;
+                    let properties = new_properties(package_id, &mut ptb, properties)?;
+                    (ident_str!("create_accreditation_to_attest"), vec![receiver, properties])
+                }
+                BatchOperation::CreateAccreditationToAccredit { receiver, properties } => {
+                    let receiver = ptb.pure(receiver)?;
+                    let properties = new_properties(package_id, &mut ptb, properties)?;
+                    (
+                        ident_str!("create_accreditation_to_accredit"),
+                        vec![receiver, properties],
+                    )
+                }
+            };
+
+            let mut arguments = vec![fed_ref, cap];
+            arguments.extend(args);
+            arguments.extend(clock);
+
+            ptb.programmable_move_call(
+                package_id,
+                ident_str!(move_names::MODULE_MAIN).as_str().into(),
+                function.into(),
+                vec![],
+                arguments,
+            );
+        }
+
+        Ok(ptb.finish())
+    }
+}
+
+/// Rejects adding `property` if it is named like a wildcard, as the Move package does.
+fn ensure_not_wildcard(property: &FederationProperty) -> Result<(), OperationError> {
+    if property.name.is_wildcard() {
+        return Err(OperationError::WildcardProperty {
+            name: property.name.names().join("."),
+        });
+    }
+    Ok(())
 }
@@ -0,0 +1,174 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Prefetched Inputs
+//!
+//! Building an administrative transaction looks up the federation's initial shared version and
+//! the signer's capability on the network. Cold-wallet workflows can't interleave these lookups
+//! with building the transaction on the air-gapped machine.
+//!
+//! [`HierarchiesClientReadOnly::prefetch_inputs`](crate::client::HierarchiesClientReadOnly::prefetch_inputs)
+//! fetches the inputs up front on an online machine. The [`PrefetchedInputs`] serialize, so they
+//! can be carried over and applied with [`WithPrefetchedInputs::with_prefetched_inputs`]; building
+//! the programmable transaction then needs no RPC round-trips.
+//!
+//! The capability reference includes the object version, so prefetched inputs are valid for a
+//! single transaction. The grant policy is not checked up front when building with them; it is
+//! still enforced on chain.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! // online
+//! let inputs = client.prefetch_inputs(federation_id, signer, CapabilityKind::RootAuthority).await?;
+//!
+//! // offline
+//! let tx = offline_client
+//!     .add_property(federation_id, property)
+//!     .with_prefetched_inputs(&inputs)
+//!     .into_inner()
+//!     .build_programmable_transaction(&offline_client)
+//!     .await?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use iota_interaction::types::base_types::{ObjectID, ObjectRef, SequenceNumber};
+use serde::{Deserialize, Serialize};
+
+use crate::core::transactions::WithCapability;
+use crate::core::types::CapabilityKind;
+
+/// Initial shared versions of shared objects, which never change once an object is shared.
+static INITIAL_SHARED_VERSIONS: LazyLock<Mutex<HashMap<ObjectID, SequenceNumber>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The capability authorizing a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CapabilityRef {
+    /// The object ID of the capability; its current version is fetched when building.
+    Id(ObjectID),
+    /// The full object reference of the capability, used as is.
+    Object(ObjectRef),
+}
+
+impl CapabilityRef {
+    /// Returns the object ID of the capability.
+    pub fn object_id(&self) -> ObjectID {
+        match self {
+            Self::Id(object_id) => *object_id,
+            Self::Object((object_id, _, _)) => *object_id,
+        }
+    }
+}
+
+impl From<ObjectID> for CapabilityRef {
+    fn from(object_id: ObjectID) -> Self {
+        Self::Id(object_id)
+    }
+}
+
+impl From<ObjectRef> for CapabilityRef {
+    fn from(object_ref: ObjectRef) -> Self {
+        Self::Object(object_ref)
+    }
+}
+
+/// A reference to a federation, as used by transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FederationRef {
+    /// The object ID of the federation.
+    pub federation_id: ObjectID,
+    /// The version at which the federation was shared.
+    pub initial_shared_version: SequenceNumber,
+}
+
+impl FederationRef {
+    /// Makes the initial shared version known to transactions built by this process.
+    pub fn remember(&self) {
+        remember_initial_shared_version(self.federation_id, self.initial_shared_version);
+    }
+}
+
+/// The network inputs of a single administrative transaction on a federation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefetchedInputs {
+    /// The federation the transaction operates on.
+    pub federation: FederationRef,
+    /// The kind of the signer's capability.
+    pub kind: CapabilityKind,
+    /// The signer's capability at its current version.
+    pub capability: ObjectRef,
+}
+
+/// A transaction that can be built from [`PrefetchedInputs`] without network access.
+pub trait WithPrefetchedInputs: Sized {
+    /// Uses the prefetched federation and capability instead of looking them up.
+    fn with_prefetched_inputs(self, inputs: &PrefetchedInputs) -> Self;
+}
+
+impl<T> WithPrefetchedInputs for T
+where
+    T: WithCapability,
+{
+    fn with_prefetched_inputs(self, inputs: &PrefetchedInputs) -> Self {
+        inputs.federation.remember();
+        self.with_capability(inputs.capability)
+    }
+}
+
+/// Returns the initial shared version of `object_id`, if known.
+pub(crate) fn known_initial_shared_version(object_id: &ObjectID) -> Option<SequenceNumber> {
+    INITIAL_SHARED_VERSIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(object_id)
+        .copied()
+}
+
+/// Records the initial shared version of `object_id`.
+pub(crate) fn remember_initial_shared_version(object_id: ObjectID, initial_shared_version: SequenceNumber) {
+    INITIAL_SHARED_VERSIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(object_id, initial_shared_version);
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::digests::ObjectDigest;
+
+    use super::*;
+
+    #[test]
+    fn test_prefetched_inputs_roundtrip() {
+        let inputs = PrefetchedInputs {
+            federation: FederationRef {
+                federation_id: ObjectID::random(),
+                initial_shared_version: SequenceNumber::from_u64(3),
+            },
+            kind: CapabilityKind::RootAuthority,
+            capability: (ObjectID::random(), SequenceNumber::from_u64(7), ObjectDigest::random()),
+        };
+
+        let json = serde_json::to_string(&inputs).unwrap();
+        assert_eq!(serde_json::from_str::<PrefetchedInputs>(&json).unwrap(), inputs);
+    }
+
+    #[test]
+    fn test_remembered_federation_is_known() {
+        let federation = FederationRef {
+            federation_id: ObjectID::random(),
+            initial_shared_version: SequenceNumber::from_u64(5),
+        };
+        assert_eq!(known_initial_shared_version(&federation.federation_id), None);
+
+        federation.remember();
+
+        assert_eq!(
+            known_initial_shared_version(&federation.federation_id),
+            Some(federation.initial_shared_version)
+        );
+    }
+}
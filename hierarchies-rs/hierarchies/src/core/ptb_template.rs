@@ -0,0 +1,215 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # PTB Templates
+//!
+//! Building the arguments of a programmable transaction (property names, values,
+//! shapes, ...) is the CPU heavy part of issuing accreditations. Issuance services
+//! often build many transactions that only differ in the receiver, so this module
+//! caches the built transaction as a [`PtbTemplate`] and substitutes the variable
+//! inputs on reuse.
+//!
+//! Templates are keyed by a SHA3-256 hash of the operation parameters without the
+//! variable fields, see [`PtbTemplateCache`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use iota_interaction::types::base_types::{IotaAddress, ObjectID, ObjectRef};
+use iota_interaction::types::transaction::{Argument, CallArg, ProgrammableTransaction};
+use sha3::{Digest, Sha3_256};
+
+use crate::core::error::OperationError;
+use crate::core::types::property::FederationProperty;
+
+/// The default number of templates a [`PtbTemplateCache`] holds.
+pub const DEFAULT_TEMPLATE_CACHE_CAPACITY: usize = 256;
+
+/// A content hash identifying a [`PtbTemplate`].
+pub type TemplateKey = [u8; 32];
+
+/// A built programmable transaction with the positions of its variable inputs.
+#[derive(Debug, Clone)]
+pub struct PtbTemplate {
+    tx: ProgrammableTransaction,
+    receiver_input: usize,
+    cap_input: usize,
+}
+
+impl PtbTemplate {
+    /// Creates a template from a built transaction.
+    ///
+    /// `receiver` and `cap` must be the arguments returned when the receiver and the
+    /// capability were added to the transaction. The receiver must have been added as a
+    /// separate pure input, so substituting it does not affect other arguments.
+    pub(crate) fn new(tx: ProgrammableTransaction, receiver: Argument, cap: Argument) -> Result<Self, OperationError> {
+        Ok(Self {
+            receiver_input: input_index(receiver)?,
+            cap_input: input_index(cap)?,
+            tx,
+        })
+    }
+
+    /// Instantiates the template for `receiver`, using the current reference of the capability.
+    pub fn instantiate(&self, receiver: ObjectID, cap: ObjectRef) -> Result<ProgrammableTransaction, OperationError> {
+        let mut tx = self.tx.clone();
+        tx.inputs[self.receiver_input] = CallArg::Pure(bcs::to_bytes(&receiver)?);
+        tx.inputs[self.cap_input] = CallArg::ImmutableOrOwned(cap);
+        Ok(tx)
+    }
+}
+
+fn input_index(arg: Argument) -> Result<usize, OperationError> {
+    match arg {
+        Argument::Input(idx) => Ok(idx as usize),
+        other => Err(anyhow::anyhow!("expected a transaction input, got {other:?}").into()),
+    }
+}
+
+/// A bounded, thread-safe cache of [`PtbTemplate`]s.
+///
+/// Cloning the cache is cheap; clones share the same templates. Once the capacity is
+/// reached, an arbitrary template is evicted to make room for a new one.
+#[derive(Debug, Clone)]
+pub struct PtbTemplateCache {
+    templates: Arc<Mutex<HashMap<TemplateKey, Arc<PtbTemplate>>>>,
+    capacity: usize,
+}
+
+impl Default for PtbTemplateCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_TEMPLATE_CACHE_CAPACITY)
+    }
+}
+
+impl PtbTemplateCache {
+    /// Creates a cache holding at most `capacity` templates.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            templates: Arc::default(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the template stored under `key`, if any.
+    pub fn get(&self, key: &TemplateKey) -> Option<Arc<PtbTemplate>> {
+        let template = self
+            .templates
+            .lock()
+            .expect("template cache lock poisoned")
+            .get(key)
+            .cloned();
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_template_lookup(template.is_some());
+
+        template
+    }
+
+    /// Stores `template` under `key` and returns it.
+    pub fn insert(&self, key: TemplateKey, template: PtbTemplate) -> Arc<PtbTemplate> {
+        let template = Arc::new(template);
+        let mut templates = self.templates.lock().expect("template cache lock poisoned");
+        if templates.len() >= self.capacity && !templates.contains_key(&key) {
+            let evicted = *templates.keys().next().expect("a full cache is not empty");
+            templates.remove(&evicted);
+        }
+        templates.insert(key, template.clone());
+        template
+    }
+
+    /// Returns the number of cached templates.
+    pub fn len(&self) -> usize {
+        self.templates.lock().expect("template cache lock poisoned").len()
+    }
+
+    /// Returns true if no template is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached templates.
+    pub fn clear(&self) {
+        self.templates.lock().expect("template cache lock poisoned").clear();
+    }
+}
+
+/// Computes the template key of a `create_accreditation_to_attest` transaction.
+///
+/// The receiver and the capability version are not part of the key. The allowed
+/// values of every property are hashed in a canonical order, so equal property sets
+/// map to the same template regardless of their in-memory ordering.
+pub fn accreditation_to_attest_key(
+    package_id: ObjectID,
+    federation_id: ObjectID,
+    owner: IotaAddress,
+    want_properties: &[FederationProperty],
+) -> Result<TemplateKey, OperationError> {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"create_accreditation_to_attest");
+    hasher.update(bcs::to_bytes(&package_id)?);
+    hasher.update(bcs::to_bytes(&federation_id)?);
+    hasher.update(bcs::to_bytes(&owner)?);
+
+    for property in want_properties {
+        hasher.update(bcs::to_bytes(&property.name)?);
+        hasher.update(bcs::to_bytes(&property.shape)?);
+        hasher.update(bcs::to_bytes(&property.allow_any)?);
+        hasher.update(bcs::to_bytes(&property.timespan)?);
+        hasher.update(bcs::to_bytes(&property.value_dictionary)?);
+
+        let mut values = property
+            .allowed_values
+            .iter()
+            .map(bcs::to_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        values.sort();
+        hasher.update(bcs::to_bytes(&values)?);
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::property_value::PropertyValue;
+
+    #[test]
+    fn test_key_ignores_value_order() {
+        let values = [
+            PropertyValue::Text("a".to_string()),
+            PropertyValue::Text("b".to_string()),
+            PropertyValue::Number(3),
+        ];
+        let forward = FederationProperty::new("role").with_allowed_values(values.clone());
+        let backward = FederationProperty::new("role").with_allowed_values(values.into_iter().rev());
+        let other = FederationProperty::new("other").with_allow_any(true);
+
+        let key = |property: FederationProperty| {
+            accreditation_to_attest_key(ObjectID::ZERO, ObjectID::ZERO, IotaAddress::ZERO, &[property]).unwrap()
+        };
+
+        assert_eq!(key(forward.clone()), key(backward));
+        assert_ne!(key(forward), key(other));
+    }
+
+    #[test]
+    fn test_cache_is_bounded() {
+        let cache = PtbTemplateCache::with_capacity(2);
+        let template = || PtbTemplate {
+            tx: ProgrammableTransaction {
+                inputs: vec![],
+                commands: vec![],
+            },
+            receiver_input: 0,
+            cap_input: 1,
+        };
+
+        for key in 0..3u8 {
+            cache.insert([key; 32], template());
+        }
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&[2; 32]).is_some());
+    }
+}
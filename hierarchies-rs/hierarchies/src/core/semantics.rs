@@ -0,0 +1,359 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Delegation Semantics
+//!
+//! An executable specification of the rules deciding whether an accreditor may delegate a property,
+//! i.e. grant it to another entity with `create_accreditation_to_attest` or
+//! `create_accreditation_to_accredit`.
+//!
+//! Each [`DelegationRule`] pairs the grants an accreditor holds with a requested property and the
+//! expected outcome. The table is checked against [`Accreditations::is_property_compliant`], which
+//! mirrors the on-chain check, and rendered to `docs/delegation-semantics.md` by [`render_markdown`].
+//! SDKs and third-party implementations can run the same table to stay in line with the Move package.
+//!
+//! Regenerate the document with `UPDATE_SEMANTICS_DOC=1 cargo test -p hierarchies semantics`.
+
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::id::UID;
+
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_shape::PropertyShape;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::timespan::Timespan;
+use crate::core::types::{Accreditation, Accreditations};
+
+/// The time at which every rule is evaluated.
+pub const EVALUATED_AT_MS: u64 = 1_000;
+
+/// A single case of the delegation specification.
+#[derive(Debug, Clone)]
+pub struct DelegationRule {
+    /// A stable identifier of the rule.
+    pub id: &'static str,
+    /// What the rule demonstrates.
+    pub description: &'static str,
+    /// The grants the accreditor holds, one accreditation each.
+    pub held: Vec<FederationProperty>,
+    /// The end of the objection window of the held accreditations, if any.
+    pub held_pending_until_ms: Option<u64>,
+    /// The property the accreditor wants to delegate.
+    pub requested: FederationProperty,
+    /// True if the delegation is allowed at [`EVALUATED_AT_MS`].
+    pub delegable: bool,
+}
+
+impl DelegationRule {
+    /// Returns the accreditations the accreditor holds.
+    pub fn accreditations(&self) -> Accreditations {
+        let accreditations = self
+            .held
+            .iter()
+            .map(|property| Accreditation {
+                id: UID::new(ObjectID::ZERO),
+                accredited_by: ObjectID::ZERO.to_string(),
+                properties: [(property.name.clone(), property.clone())].into(),
+                pending_until_ms: self.held_pending_until_ms,
+            })
+            .collect();
+        Accreditations::new(accreditations)
+    }
+}
+
+/// Returns the delegation rules, in the order they are documented.
+pub fn delegation_rules() -> Vec<DelegationRule> {
+    let text = |value: &str| PropertyValue::Text(value.to_string());
+    let degree =
+        |values: &[&str]| FederationProperty::new("degree").with_allowed_values(values.iter().copied().map(text));
+    let level = PropertyName::new(["degree", "level"]);
+    let timespan = |valid_from_ms, valid_until_ms| Timespan {
+        valid_from_ms,
+        valid_until_ms,
+    };
+
+    vec![
+        DelegationRule {
+            id: "values-subset",
+            description: "Requested values that are all allowed by the held grant can be delegated.",
+            held: vec![degree(&["bachelor", "master"])],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "values-not-subset",
+            description: "A single requested value not allowed by any held grant denies the delegation.",
+            held: vec![degree(&["bachelor"])],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor", "master"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "values-across-grants",
+            description: "Requested values may be allowed by different grants for the same property.",
+            held: vec![degree(&["bachelor"]), degree(&["master"])],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor", "master"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "held-allow-any",
+            description: "A held grant with `allow_any` allows delegating any value.",
+            held: vec![FederationProperty::new("degree").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: degree(&["doctorate"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "held-shape-matches",
+            description: "A held shape allows delegating the values matching it.",
+            held: vec![
+                FederationProperty::new("degree").with_expression(PropertyShape::StartsWith("HARV-".to_string())),
+            ],
+            held_pending_until_ms: None,
+            requested: degree(&["HARV-CS"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "held-shape-mismatches",
+            description: "A held shape doesn't allow delegating values not matching it.",
+            held: vec![
+                FederationProperty::new("degree").with_expression(PropertyShape::StartsWith("HARV-".to_string())),
+            ],
+            held_pending_until_ms: None,
+            requested: degree(&["MIT-CS"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "held-dictionary-allows-values",
+            description: "A held value dictionary allows delegating any value; membership is checked when attesting.",
+            held: vec![FederationProperty::new("degree").with_value_dictionary("degrees")],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "requested-dictionary-same",
+            description: "A requested value dictionary can be delegated by a grant holding the same dictionary.",
+            held: vec![FederationProperty::new("degree").with_value_dictionary("degrees")],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree").with_value_dictionary("degrees"),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "requested-dictionary-other",
+            description: "A requested value dictionary can't be delegated by a grant holding another dictionary.",
+            held: vec![FederationProperty::new("degree").with_value_dictionary("courses")],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree").with_value_dictionary("degrees"),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "requested-dictionary-allow-any",
+            description: "A requested value dictionary can be delegated by a grant with `allow_any`.",
+            held: vec![FederationProperty::new("degree").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree").with_value_dictionary("degrees"),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "requested-dictionary-values",
+            description: "A requested value dictionary can't be delegated by a grant holding only values.",
+            held: vec![degree(&["bachelor"])],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree").with_value_dictionary("degrees"),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "requested-allow-any-unchecked",
+            description: "A requested `allow_any` is not compared with the held grants; restrict it with the grant policy.",
+            held: vec![degree(&["bachelor"])],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree").with_allow_any(true),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "requested-shape-unchecked",
+            description: "A requested shape is not compared with the held grants.",
+            held: vec![degree(&["bachelor"])],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree").with_expression(PropertyShape::Contains("-".to_string())),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "requested-timespan-unchecked",
+            description: "A requested timespan may exceed the timespan of the held grants.",
+            held: vec![degree(&["bachelor"]).with_timespan(timespan(None, Some(2_000)))],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "nothing-requested",
+            description: "A request without values or dictionary can be delegated without holding the property.",
+            held: vec![],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree"),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "expired-grant",
+            description: "Grants whose timespan ended are ignored; the end is exclusive.",
+            held: vec![degree(&["bachelor"]).with_timespan(timespan(None, Some(EVALUATED_AT_MS)))],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "future-grant",
+            description: "Grants whose timespan has not started are ignored.",
+            held: vec![degree(&["bachelor"]).with_timespan(timespan(Some(2_000), None))],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "started-grant",
+            description: "Grants whose timespan starts at the evaluation time are active; the start is inclusive.",
+            held: vec![degree(&["bachelor"]).with_timespan(timespan(Some(EVALUATED_AT_MS), None))],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "pending-grant",
+            description: "Grants whose objection window is running are ignored.",
+            held: vec![degree(&["bachelor"])],
+            held_pending_until_ms: Some(2_000),
+            requested: degree(&["bachelor"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "other-property",
+            description: "Grants for other properties are ignored.",
+            held: vec![FederationProperty::new("role").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "parent-property",
+            description: "Grants for a parent name don't cover its children, only wildcards do.",
+            held: vec![FederationProperty::new("degree").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new("degree.level").with_allowed_values([text("bachelor")]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "wildcard-covers-namespace",
+            description: "A held wildcard `degree.*` covers every property below `degree`.",
+            held: vec![FederationProperty::new_prefix("degree").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new(level.clone()).with_allowed_values([text("bachelor")]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "wildcard-narrower-wildcard",
+            description: "A held wildcard allows delegating narrower wildcards of its namespace.",
+            held: vec![FederationProperty::new_prefix("degree").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new_prefix(level.clone()).with_allowed_values([text("bachelor")]),
+            delegable: true,
+        },
+        DelegationRule {
+            id: "wildcard-excludes-prefix",
+            description: "A held wildcard `degree.*` doesn't cover the property `degree` itself.",
+            held: vec![FederationProperty::new_prefix("degree").with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: degree(&["bachelor"]),
+            delegable: false,
+        },
+        DelegationRule {
+            id: "wildcard-not-held",
+            description: "Grants for single properties of a namespace don't allow delegating its wildcard.",
+            held: vec![FederationProperty::new(level).with_allow_any(true)],
+            held_pending_until_ms: None,
+            requested: FederationProperty::new_prefix("degree").with_allowed_values([text("bachelor")]),
+            delegable: false,
+        },
+    ]
+}
+
+const HEADER: &str = "# Delegation Semantics
+
+<!-- Generated from `hierarchies-rs/hierarchies/src/core/semantics.rs`, do not edit. -->
+
+An accreditor may grant a property to another entity, to attest or to accredit it further, if the
+accreditations to accredit it holds allow the requested constraints at the time of the transaction.
+Root authorities may grant any property of the federation.
+
+A request is checked against the active grants holding the same property name or, if an
+accreditation holds none, the most specific wildcard name covering it. A wildcard such as `degree.*`
+covers every name below `degree`. Grants of accreditations whose objection window is running are
+not active.
+
+1. Every requested allowed value must be allowed by one of the grants, through `allow_any`, its
+   shape, its allowed values or a value dictionary.
+2. A requested value dictionary must be held by one of the grants, or one must have `allow_any`.
+
+Independently of these rules, the requested property must exist in the federation and be valid,
+unless it is a wildcard, and the federation's grant policy is applied.
+
+Every rule is evaluated at `1000` ms.
+
+| Rule | Description | Delegable |
+| ---- | ----------- | --------- |
+";
+
+/// Renders the delegation rules as a markdown document.
+pub fn render_markdown() -> String {
+    let mut document = HEADER.to_string();
+    for rule in delegation_rules() {
+        let delegable = if rule.delegable { "yes" } else { "no" };
+        document.push_str(&format!("| `{}` | {} | {} |\n", rule.id, rule.description, delegable));
+    }
+    document
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_delegation_rules() {
+        for rule in delegation_rules() {
+            let compliant = rule
+                .accreditations()
+                .is_property_compliant(&rule.requested, EVALUATED_AT_MS);
+            assert_eq!(compliant, rule.delegable, "rule `{}`", rule.id);
+        }
+    }
+
+    #[test]
+    fn test_delegation_rule_ids_are_unique() {
+        let rules = delegation_rules();
+        let ids: HashSet<&str> = rules.iter().map(|rule| rule.id).collect();
+        assert_eq!(ids.len(), rules.len());
+    }
+
+    #[test]
+    fn test_semantics_document_is_up_to_date() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/delegation-semantics.md");
+        let rendered = render_markdown();
+        if std::env::var_os("UPDATE_SEMANTICS_DOC").is_some() {
+            std::fs::write(&path, &rendered).unwrap();
+            return;
+        }
+
+        let documented = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            documented == rendered,
+            "{} is outdated, regenerate it with UPDATE_SEMANTICS_DOC=1",
+            path.display()
+        );
+    }
+}
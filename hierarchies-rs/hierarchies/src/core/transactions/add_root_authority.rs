@@ -22,7 +22,10 @@ use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{InvariantChecked, WithCapability};
 use crate::error::TransactionError;
 
 /// A transaction that adds a new root authority to an existing federation.
@@ -34,10 +37,15 @@ use crate::error::TransactionError;
 /// ## Requirements
 /// - The signer must already possess a `RootAuthorityCap` for the federation
 /// - The target account must not already have root authority capabilities
+/// - No quorum is required; otherwise propose a [`GovernanceAction::AddRootAuthority`] instead
+///
+/// [`GovernanceAction::AddRootAuthority`]: crate::core::types::quorum::GovernanceAction::AddRootAuthority
 pub struct AddRootAuthority {
     federation_id: ObjectID,
     account_id: ObjectID,
     signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    check_invariants: bool,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -52,6 +60,8 @@ impl AddRootAuthority {
             federation_id,
             account_id,
             signer_address,
+            capability: None,
+            check_invariants: false,
             cached_ptb: OnceCell::new(),
         }
     }
@@ -72,13 +82,30 @@ impl AddRootAuthority {
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let ptb = HierarchiesImpl::add_root_authority(self.federation_id, self.account_id, self.signer_address, client)
-            .await?;
+        let ptb = HierarchiesImpl::add_root_authority(
+            self.federation_id,
+            self.account_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
 
         Ok(ptb)
     }
 }
 
+impl InvariantChecked for AddRootAuthority {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for AddRootAuthority {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 impl Transaction for AddRootAuthority {
@@ -93,10 +120,20 @@ impl Transaction for AddRootAuthority {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "add_root_authority",
+                PostCondition::RootAuthorityPresent(self.account_id),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,247 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Transaction Batch
+//!
+//! This module provides a transaction combining several operations on a federation into a
+//! single programmable transaction.
+//!
+//! ## Overview
+//!
+//! Setting up a federation usually takes a series of transactions: adding properties, value
+//! dictionaries and root authorities, then granting accreditations. A [`TransactionBatch`] executes
+//! them in one transaction, resolving the capabilities and the federation reference only once.
+//! The operations are applied in the order they were added, either all or none of them.
+//!
+//! Destructive operations such as revoking a property are not available in a batch, they must be
+//! confirmed on their own, see [`Destructive`](super::Destructive).
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+
+/// An operation of a [`TransactionBatch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOperation {
+    /// Adds a property, requires `RootAuthorityCap`
+    AddProperty(FederationProperty),
+    /// Marks or unmarks a property as critical, requires `RootAuthorityCap`
+    SetPropertyCritical {
+        property_name: PropertyName,
+        critical: bool,
+    },
+    /// Creates or replaces a value dictionary, requires `RootAuthorityCap`
+    SetValueDictionary {
+        name: String,
+        values: HashSet<PropertyValue>,
+    },
+    /// Adds a root authority, requires `RootAuthorityCap`
+    AddRootAuthority(ObjectID),
+    /// Grants an accreditation to attest, requires `AccreditCap`
+    CreateAccreditationToAttest {
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    },
+    /// Grants an accreditation to accredit, requires `AccreditCap`
+    CreateAccreditationToAccredit {
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    },
+}
+
+impl BatchOperation {
+    /// Returns true if the operation requires `RootAuthorityCap`.
+    pub(crate) fn requires_root_authority(&self) -> bool {
+        !matches!(
+            self,
+            Self::CreateAccreditationToAttest { .. } | Self::CreateAccreditationToAccredit { .. }
+        )
+    }
+}
+
+/// A transaction executing several operations on a federation at once.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` if the batch contains governance operations
+/// - The signer must possess `AccreditCap` if the batch grants accreditations
+///
+/// ## Example
+///
+/// ```no_run
+/// # use hierarchies::client::HierarchiesClient;
+/// # use hierarchies::core::types::property::FederationProperty;
+/// # use iota_interaction::types::base_types::ObjectID;
+/// # async fn example(
+/// #     client: &HierarchiesClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature> + iota_interaction::OptionalSync>,
+/// #     federation_id: ObjectID,
+/// #     attester: ObjectID,
+/// # ) -> anyhow::Result<()> {
+/// let role = FederationProperty::new("role").with_allow_any(true);
+/// client
+///     .transaction_batch(federation_id)
+///     .add_property(role.clone())
+///     .add_property(FederationProperty::new("level").with_allow_any(true))
+///     .create_accreditation_to_attest(attester, [role])
+///     .into_builder()
+///     .build_and_execute(client)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransactionBatch {
+    federation_id: ObjectID,
+    operations: Vec<BatchOperation>,
+    signer_address: IotaAddress,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl TransactionBatch {
+    /// Creates a new, empty [`TransactionBatch`] for `federation_id`.
+    pub fn new(federation_id: ObjectID, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            operations: Vec::new(),
+            signer_address,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Appends `operation` to the batch.
+    pub fn push(mut self, operation: BatchOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Appends adding `property` to the federation.
+    pub fn add_property(self, property: FederationProperty) -> Self {
+        self.push(BatchOperation::AddProperty(property))
+    }
+
+    /// Appends marking or unmarking `property_name` as critical.
+    pub fn set_property_critical(self, property_name: PropertyName, critical: bool) -> Self {
+        self.push(BatchOperation::SetPropertyCritical {
+            property_name,
+            critical,
+        })
+    }
+
+    /// Appends creating or replacing the value dictionary `name`.
+    pub fn set_value_dictionary(
+        self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = PropertyValue>,
+    ) -> Self {
+        self.push(BatchOperation::SetValueDictionary {
+            name: name.into(),
+            values: values.into_iter().collect(),
+        })
+    }
+
+    /// Appends adding `account_id` as a root authority.
+    pub fn add_root_authority(self, account_id: ObjectID) -> Self {
+        self.push(BatchOperation::AddRootAuthority(account_id))
+    }
+
+    /// Appends granting `receiver` an accreditation to attest `properties`.
+    pub fn create_accreditation_to_attest(
+        self,
+        receiver: ObjectID,
+        properties: impl IntoIterator<Item = FederationProperty>,
+    ) -> Self {
+        self.push(BatchOperation::CreateAccreditationToAttest {
+            receiver,
+            properties: properties.into_iter().collect(),
+        })
+    }
+
+    /// Appends granting `receiver` an accreditation to accredit `properties`.
+    pub fn create_accreditation_to_accredit(
+        self,
+        receiver: ObjectID,
+        properties: impl IntoIterator<Item = FederationProperty>,
+    ) -> Self {
+        self.push(BatchOperation::CreateAccreditationToAccredit {
+            receiver,
+            properties: properties.into_iter().collect(),
+        })
+    }
+
+    /// Returns the ID of the federation the batch operates on.
+    pub fn federation_id(&self) -> ObjectID {
+        self.federation_id
+    }
+
+    /// Returns the operations of the batch in execution order.
+    pub fn operations(&self) -> &[BatchOperation] {
+        &self.operations
+    }
+
+    /// Returns the number of operations in the batch.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns true if the batch has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Wraps the batch in a [`TransactionBuilder`] to build and execute it.
+    pub fn into_builder(self) -> TransactionBuilder<Self> {
+        TransactionBuilder::new(self)
+    }
+
+    /// Builds the programmable transaction executing the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch is empty or the signer lacks a required capability.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        HierarchiesImpl::batch(
+            self.federation_id,
+            self.operations.clone(),
+            self.signer_address.into(),
+            client,
+        )
+        .await
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for TransactionBatch {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Create Attestation Transaction
+//!
+//! This module provides the transaction implementation for issuing attestations
+//! in the Hierarchies system.
+//!
+//! ## Overview
+//!
+//! The `CreateAttestation` transaction creates an [`Attestation`] object owned by the
+//! receiver. The signer must be accredited to attest all of its properties.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::transactions::TransactionError;
+use crate::core::types::attestation::Attestation;
+use crate::core::types::events::{self, HierarchiesEvent};
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::timespan::Timespan;
+
+/// A transaction that issues an attestation to a receiver.
+///
+/// ## Requirements
+/// - The signer must be accredited to attest all properties
+/// - The validity window must end after it starts
+/// - At least one property must be attested
+pub struct CreateAttestation {
+    federation_id: ObjectID,
+    receiver: IotaAddress,
+    properties: HashMap<PropertyName, PropertyValue>,
+    timespan: Timespan,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl CreateAttestation {
+    /// Creates a new [`CreateAttestation`] instance.
+    ///
+    /// An open `valid_from_ms` makes the attestation valid right away, an open
+    /// `valid_until_ms` keeps it valid until it is revoked.
+    pub fn new(
+        federation_id: ObjectID,
+        receiver: IotaAddress,
+        properties: HashMap<PropertyName, PropertyValue>,
+        timespan: Timespan,
+    ) -> Self {
+        Self {
+            federation_id,
+            receiver,
+            properties,
+            timespan,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for issuing the attestation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation cannot be referenced.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::create_attestation(
+            self.federation_id,
+            self.receiver,
+            self.properties.clone(),
+            self.timespan.clone(),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for CreateAttestation {
+    type Error = TransactionError;
+
+    type Output = Attestation;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        _: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let attestation_id = events::decode_all(events, &client.package_history())?
+            .into_iter()
+            .find_map(|event| match event {
+                HierarchiesEvent::AttestationCreated(created) => Some(created.attestation_id),
+                _ => None,
+            })
+            .ok_or_else(|| TransactionError::EventProcessingFailed {
+                event_type: "AttestationCreatedEvent".to_string(),
+            })?;
+
+        let attestation =
+            client
+                .get_object_by_id(attestation_id)
+                .await
+                .map_err(|e| TransactionError::ExecutionFailed {
+                    reason: format!("Failed to retrieve attestation object: {e}"),
+                })?;
+
+        Ok(attestation)
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
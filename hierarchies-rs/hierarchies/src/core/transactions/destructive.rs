@@ -0,0 +1,51 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Destructive Transactions
+//!
+//! Some transactions take away rights immediately or in bulk, so a single mistaken call can
+//! lock authorities and holders out of a federation:
+//!
+//! - revoking or rotating a root authority, which only an explicit reinstatement reverses
+//! - revoking a property immediately, which cannot be reversed at all
+//! - revoking several accreditations at once
+//!
+//! These transactions refuse to be built until their effects have been confirmed explicitly via
+//! [`Destructive::confirm_irreversible`].
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! client
+//!     .revoke_root_authority(federation_id, account_id)
+//!     .confirm_irreversible()
+//!     .build_and_execute(&client)
+//!     .await?;
+//! ```
+
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+
+/// A transaction whose effects take away rights immediately or in bulk.
+///
+/// Building such a transaction fails with
+/// [`OperationError::DestructiveNotConfirmed`](crate::core::OperationError::DestructiveNotConfirmed)
+/// unless it has been confirmed.
+pub trait Destructive: Sized {
+    /// Confirms that the destructive effects of this transaction are intended, in place.
+    fn set_destructive_confirmed(&mut self);
+
+    /// Confirms that the destructive effects of this transaction are intended.
+    fn confirm_irreversible(mut self) -> Self {
+        self.set_destructive_confirmed();
+        self
+    }
+}
+
+impl<T> Destructive for TransactionBuilder<T>
+where
+    T: Transaction + Destructive,
+{
+    fn set_destructive_confirmed(&mut self) {
+        self.transaction_mut().set_destructive_confirmed();
+    }
+}
@@ -6,6 +6,7 @@
 use thiserror::Error;
 
 use crate::core::OperationError;
+use crate::core::types::property_name::PropertyName;
 
 /// Errors that can occur during transaction building and execution
 #[derive(Debug, Error, strum::IntoStaticStr)]
@@ -26,4 +27,179 @@ pub enum TransactionError {
     /// Operation error during transaction
     #[error("operation error during transaction")]
     Operation(#[from] OperationError),
+
+    /// The federation passed doesn't match the federation of the capability
+    #[error("capability belongs to another federation")]
+    WrongFederation,
+
+    /// The sender's accreditations don't cover the properties to accredit
+    #[error("insufficient accreditation to accredit the properties")]
+    InsufficientAccreditation,
+
+    /// The allowed values of a property contradict each other
+    #[error("invalid property value condition")]
+    InvalidPropertyValueCondition,
+
+    /// The accreditation doesn't exist in the federation
+    #[error("accreditation not found")]
+    AccreditationNotFound,
+
+    /// A timestamp that must lie in the future is in the past
+    #[error("timestamp must be in the future")]
+    TimestampInThePast,
+
+    /// The property isn't part of the federation
+    #[error("property {name:?} is not part of the federation")]
+    PropertyNotInFederation { name: Option<PropertyName> },
+
+    /// The account isn't a root authority of the federation
+    #[error("account is not a root authority of the federation")]
+    NotRootAuthority,
+
+    /// The last root authority of a federation can't be revoked
+    #[error("the last root authority can't be revoked")]
+    LastRootAuthority,
+
+    /// The root authority capability used has been revoked
+    #[error("root authority has been revoked")]
+    RevokedRootAuthority,
+
+    /// A property allows neither any value nor a non-empty set of values
+    #[error("allowed values are empty without allowing any value")]
+    EmptyAllowedValues,
+
+    /// The account is already a root authority of the federation
+    #[error("account is already a root authority")]
+    AlreadyRootAuthority,
+
+    /// The root authority to reinstate hasn't been revoked
+    #[error("root authority is not revoked")]
+    RootAuthorityNotRevoked,
+
+    /// The property has been revoked
+    #[error("property {name:?} has been revoked")]
+    PropertyRevoked { name: Option<PropertyName> },
+
+    /// The value dictionary doesn't exist in the federation
+    #[error("value dictionary not found")]
+    ValueDictionaryNotFound,
+
+    /// A value dictionary without values was set
+    #[error("value dictionary is empty")]
+    EmptyValueDictionary,
+
+    /// The value dictionary is still referenced by a property
+    #[error("value dictionary is still in use")]
+    ValueDictionaryInUse,
+
+    /// An accreditation references another value dictionary than its property
+    #[error("value dictionary doesn't match the federation property")]
+    ValueDictionaryMismatch,
+
+    /// The grant policy doesn't permit granting any value
+    #[error("grant policy doesn't permit allow_any grants")]
+    AllowAnyNotPermitted,
+
+    /// A granted property exceeds the allowed values limit of the grant policy
+    #[error("allowed values limit of the grant policy exceeded")]
+    AllowedValuesLimitExceeded,
+
+    /// More tags than permitted were set
+    #[error("too many tags")]
+    TooManyTags,
+
+    /// A tag is empty or too long
+    #[error("invalid tag")]
+    InvalidTag,
+
+    /// Only the issuer of an accreditation or a root authority can modify it
+    #[error("sender is not the issuer of the accreditation")]
+    NotAccreditationIssuer,
+
+    /// The action requires a quorum of root authorities and has to be proposed
+    #[error("the action requires a quorum of root authorities, propose it instead")]
+    QuorumRequired,
+
+    /// The quorum threshold is zero or exceeds the number of root authorities
+    #[error("quorum threshold must be between 1 and the number of root authorities")]
+    InvalidQuorumThreshold,
+
+    /// The governance proposal does not exist or was already executed
+    #[error("governance proposal not found")]
+    ProposalNotFound,
+
+    /// The root authority already approved the proposal
+    #[error("proposal already approved by this root authority")]
+    AlreadyApproved,
+
+    /// The proposal lacks approvals of active root authorities
+    #[error("proposal does not have enough approvals")]
+    QuorumNotReached,
+
+    /// A metadata field of the federation or a property is empty or too long, or a property has too many entries
+    #[error("metadata fields must be non-empty and within the length limits")]
+    InvalidMetadata,
+
+    /// The capability transfer wasn't confirmed by another root authority while a quorum is required
+    #[error("capability transfer must be confirmed by another root authority")]
+    TransferNotConfirmed,
+
+    /// The capability can't be transferred to its holder, a root authority or an account holding accreditations
+    #[error("invalid capability transfer recipient")]
+    InvalidTransferRecipient,
+
+    /// The annotation of a revocation reason is too long
+    #[error("revocation annotation must be at most 256 bytes")]
+    AnnotationTooLong,
+
+    /// The sender is neither allowed to attest the properties of an attestation nor its issuer or a root authority
+    #[error("sender is not allowed to issue or revoke the attestation")]
+    UnauthorizedAttester,
+
+    /// The validity window of an attestation ends before it starts
+    #[error("attestation validity must end after it starts")]
+    InvalidValidityWindow,
+
+    /// The attestation was not issued in the federation
+    #[error("attestation not found")]
+    AttestationNotFound,
+
+    /// The attestation is already revoked
+    #[error("attestation already revoked")]
+    AttestationRevoked,
+
+    /// A federation cannot recognize itself as a child federation
+    #[error("federation cannot be linked to itself")]
+    CannotLinkSelf,
+
+    /// The child federation is already recognized
+    #[error("federation already linked")]
+    FederationAlreadyLinked,
+
+    /// The child federation is not recognized
+    #[error("federation not linked")]
+    FederationNotLinked,
+
+    /// The replacement of a deprecated property is the property itself or not in the federation
+    #[error("invalid property replacement")]
+    InvalidPropertyReplacement,
+
+    /// A property named like a wildcard was added, wildcards can only be granted as accreditations
+    #[error("wildcard property names can only be granted as accreditations")]
+    WildcardProperty,
+
+    /// The default validity of a grant policy is zero or longer than the on-chain maximum
+    #[error(
+        "default validity must be between 1 ms and {} ms",
+        crate::core::types::grant_policy::GrantPolicy::MAX_DEFAULT_VALIDITY_MS
+    )]
+    InvalidDefaultValidity,
+
+    /// An attestation was created without properties
+    #[error("attestation must attest at least one property")]
+    EmptyAttestation,
+
+    /// A Move abort without a dedicated variant
+    #[error("transaction aborted in module '{module}' with code {code}")]
+    MoveAbort { module: String, code: u64 },
 }
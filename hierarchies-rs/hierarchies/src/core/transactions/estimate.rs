@@ -0,0 +1,212 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Transaction Estimates
+//!
+//! Every transaction builder can be dry-run before it is signed and submitted. The
+//! estimate builds the programmable transaction exactly as execution would, dev-inspects
+//! it with the signer as sender and reports the expected gas cost and effects.
+//!
+//! Nothing is executed and no gas is spent, so estimates are well suited to show the
+//! cost of an operation to a user or to check in advance whether the signer holds the
+//! capability the transaction requires.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let (builder, estimate) = client.add_property(federation_id, property).estimate(&client).await?;
+//!
+//! if let Some(failure) = estimate.failure() {
+//!     println!("the transaction would fail: {failure}");
+//! } else {
+//!     println!("expected gas usage: {:?}", estimate.net_gas_usage());
+//!     builder.build_and_execute(&client).await?;
+//! }
+//! ```
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::{IotaExecutionStatus, IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI};
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::gas::GasCostSummary;
+use iota_interaction::types::transaction::TransactionKind;
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
+use thiserror::Error;
+
+use crate::core::transactions::TransactionError;
+use crate::core::{CapabilityError, OperationError};
+
+/// The reason why an estimated transaction would not succeed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum EstimateFailure {
+    /// The sender doesn't own the capability required to build the transaction.
+    #[error("sender doesn't own a '{cap_type}'")]
+    MissingCapability { cap_type: String },
+    /// The transaction was built but aborted during the dry-run.
+    #[error("dry-run failed: {error}")]
+    Execution { error: String },
+}
+
+/// The outcome of dry-running a transaction.
+#[derive(Debug, Clone)]
+pub struct TransactionEstimate {
+    effects: Option<IotaTransactionBlockEffects>,
+    failure: Option<EstimateFailure>,
+}
+
+impl TransactionEstimate {
+    /// Returns `true` if the transaction would execute successfully.
+    pub fn succeeds(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// The reason why the transaction would fail, if any.
+    pub fn failure(&self) -> Option<&EstimateFailure> {
+        self.failure.as_ref()
+    }
+
+    /// The effects of the dry-run.
+    ///
+    /// Not available if the transaction couldn't be built.
+    pub fn effects(&self) -> Option<&IotaTransactionBlockEffects> {
+        self.effects.as_ref()
+    }
+
+    /// The gas cost of the dry-run.
+    pub fn gas_cost(&self) -> Option<&GasCostSummary> {
+        self.effects.as_ref().map(|effects| effects.gas_cost_summary())
+    }
+
+    /// The expected gas usage in NANOS, including the storage rebate.
+    pub fn net_gas_usage(&self) -> Option<i64> {
+        self.gas_cost().map(GasCostSummary::net_gas_usage)
+    }
+
+    /// Classifies an error raised while building the transaction.
+    ///
+    /// A missing capability is an expected outcome of an estimate, all other errors are returned.
+    pub(crate) fn from_build_error(error: TransactionError) -> Result<Self, TransactionError> {
+        match error {
+            TransactionError::Operation(OperationError::Capability(CapabilityError::NotFound { cap_type })) => {
+                Ok(Self {
+                    effects: None,
+                    failure: Some(EstimateFailure::MissingCapability { cap_type }),
+                })
+            }
+            error => Err(error),
+        }
+    }
+
+    /// Creates the estimate from the result of a dev-inspection.
+    pub(crate) fn from_inspection(effects: IotaTransactionBlockEffects, error: Option<String>) -> Self {
+        let failure = match (error, effects.status()) {
+            (Some(error), _) => Some(EstimateFailure::Execution { error }),
+            (None, IotaExecutionStatus::Failure { error }) => Some(EstimateFailure::Execution { error: error.clone() }),
+            (None, IotaExecutionStatus::Success) => None,
+        };
+
+        Self {
+            effects: Some(effects),
+            failure,
+        }
+    }
+}
+
+/// A transaction that can be dry-run without being executed.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait Estimate: Sized {
+    /// Builds the transaction and dev-inspects it with the client's sender address.
+    ///
+    /// Returns the transaction alongside its [`TransactionEstimate`]. A transaction that would
+    /// fail still yields an estimate; an error is only returned if the estimate couldn't be made.
+    async fn estimate<C, S>(self, client: &C) -> Result<(Self, TransactionEstimate), TransactionError>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<T> Estimate for TransactionBuilder<T>
+where
+    T: Transaction + Send + OptionalSync,
+    T::Error: Into<TransactionError>,
+{
+    async fn estimate<C, S>(self, client: &C) -> Result<(Self, TransactionEstimate), TransactionError>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let estimate = estimate_transaction(self.transaction(), client.sender_address(), client).await?;
+
+        Ok((self, estimate))
+    }
+}
+
+/// Builds `tx` and dev-inspects it with `sender` as the transaction sender.
+pub async fn estimate_transaction<T, C>(
+    tx: &T,
+    sender: IotaAddress,
+    client: &C,
+) -> Result<TransactionEstimate, TransactionError>
+where
+    T: Transaction,
+    T::Error: Into<TransactionError>,
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let ptb = match tx.build_programmable_transaction(client).await {
+        Ok(ptb) => ptb,
+        Err(error) => return TransactionEstimate::from_build_error(error.into()),
+    };
+
+    let inspection = client
+        .client_adapter()
+        .read_api()
+        .dev_inspect_transaction_block(sender, TransactionKind::Programmable(ptb), None, None, None)
+        .await
+        .map_err(|err| TransactionError::ExecutionFailed {
+            reason: format!("Failed to inspect transaction block: {err}"),
+        })?;
+
+    Ok(TransactionEstimate::from_inspection(
+        inspection.effects,
+        inspection.error,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_capability_is_a_failed_estimate() {
+        let error = TransactionError::Operation(OperationError::Capability(CapabilityError::NotFound {
+            cap_type: "RootAuthorityCap".to_string(),
+        }));
+
+        let estimate = TransactionEstimate::from_build_error(error).unwrap();
+
+        assert!(!estimate.succeeds());
+        assert!(estimate.effects().is_none());
+        assert_eq!(
+            estimate.failure(),
+            Some(&EstimateFailure::MissingCapability {
+                cap_type: "RootAuthorityCap".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn other_build_errors_are_returned() {
+        let error = TransactionError::Operation(OperationError::EmptyBatch);
+
+        assert!(matches!(
+            TransactionEstimate::from_build_error(error),
+            Err(TransactionError::Operation(OperationError::EmptyBatch))
+        ));
+    }
+}
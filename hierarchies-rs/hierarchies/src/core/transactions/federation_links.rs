@@ -0,0 +1,186 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Federation Link Transactions
+//!
+//! This module provides the transaction implementations for linking child federations to a
+//! parent federation.
+//!
+//! ## Overview
+//!
+//! A large consortium doesn't have to keep every member in one shared federation object, which
+//! would be the single point of contention of all its transactions. Regional bodies, e.g. ISO
+//! Europe, operate federations of their own, and the consortium recognizes them with
+//! [`LinkFederation`]. A trust chain ending at a root authority of a recognized federation is then
+//! trusted like a chain of the consortium itself, see
+//! [`resolve_linked_trust_chain`](crate::client::HierarchiesClientReadOnly::resolve_linked_trust_chain).
+//!
+//! [`UnlinkFederation`] withdraws the recognition. Accreditations of the child federation are
+//! not affected.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::WithCapability;
+
+/// A transaction that recognizes a child federation.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` for the parent federation
+/// - The child federation is neither the parent itself nor already recognized
+#[derive(Debug, Clone)]
+pub struct LinkFederation {
+    federation_id: ObjectID,
+    child_federation_id: ObjectID,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl LinkFederation {
+    /// Creates a new [`LinkFederation`] instance.
+    pub fn new(federation_id: ObjectID, child_federation_id: ObjectID, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            child_federation_id,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for linking the child federation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer doesn't have `RootAuthorityCap`.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::link_federation(
+            self.federation_id,
+            self.child_federation_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for LinkFederation {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for LinkFederation {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
+
+/// A transaction that stops recognizing a child federation.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` for the parent federation
+/// - The child federation is recognized
+/// - No quorum is required; otherwise propose a [`GovernanceAction::UnlinkFederation`] instead
+///
+/// [`GovernanceAction::UnlinkFederation`]: crate::core::types::quorum::GovernanceAction::UnlinkFederation
+#[derive(Debug, Clone)]
+pub struct UnlinkFederation {
+    federation_id: ObjectID,
+    child_federation_id: ObjectID,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl UnlinkFederation {
+    /// Creates a new [`UnlinkFederation`] instance.
+    pub fn new(federation_id: ObjectID, child_federation_id: ObjectID, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            child_federation_id,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for unlinking the child federation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer doesn't have `RootAuthorityCap`.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::unlink_federation(
+            self.federation_id,
+            self.child_federation_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for UnlinkFederation {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for UnlinkFederation {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Invariant-Checked Transactions
+//!
+//! Governance transactions can verify their outcome after execution by re-fetching the
+//! federation and asserting the expected [`PostCondition`](crate::core::invariants::PostCondition).
+//! If the chain state doesn't reflect the intent, applying the transaction fails with
+//! [`OperationError::InvariantViolation`](crate::core::OperationError::InvariantViolation).
+//!
+//! The checks cost an additional object read per transaction and are disabled by default.
+//! They can be enabled per transaction or for every transaction of a client with
+//! `HierarchiesClient::with_invariant_checks`.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! client
+//!     .add_property(federation_id, property)
+//!     .with_invariant_checks()
+//!     .build_and_execute(&client)
+//!     .await?;
+//! ```
+
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+
+/// A transaction that can verify its outcome after execution.
+pub trait InvariantChecked: Sized {
+    /// Enables the checks of this transaction, in place.
+    fn enable_invariant_checks(&mut self);
+
+    /// Re-fetches the federation after execution and checks the expected post-condition.
+    fn with_invariant_checks(mut self) -> Self {
+        self.enable_invariant_checks();
+        self
+    }
+}
+
+impl<T> InvariantChecked for TransactionBuilder<T>
+where
+    T: Transaction + InvariantChecked,
+{
+    fn enable_invariant_checks(&mut self) {
+        self.transaction_mut().enable_invariant_checks();
+    }
+}
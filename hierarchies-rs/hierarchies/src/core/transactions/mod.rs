@@ -8,17 +8,54 @@
 //! specific operations on the Hierarchies blockchain.
 
 pub mod add_root_authority;
+pub mod batch;
+pub mod create_attestation;
+pub mod destructive;
 pub mod error;
+pub mod estimate;
+pub mod federation_links;
+pub mod invariant_checked;
+pub mod move_abort;
+pub mod multisig;
 mod new_federation;
 pub mod permissions;
+pub mod preview;
 pub mod properties;
+pub mod quorum;
 pub mod reinstate_root_authority;
+pub mod revoke_attestation;
 pub mod revoke_root_authority;
+pub mod rotate_root_authority;
+pub mod set_federation_metadata;
+pub mod set_grant_policy;
+pub mod transfer_capability;
+pub mod with_capability;
+pub mod with_reason;
 
 // Re-export error types
 pub use add_root_authority::*;
+pub use batch::*;
+pub use create_attestation::*;
+pub use destructive::Destructive;
 pub use error::TransactionError;
+pub use estimate::{Estimate, EstimateFailure, TransactionEstimate};
+pub use federation_links::*;
+pub use invariant_checked::InvariantChecked;
+pub use move_abort::{MoveAbort, MoveAbortMapper};
+pub use multisig::{MultisigError, PreparedTransaction};
 pub use new_federation::*;
 pub use permissions::*;
+pub use preview::{
+    ChangeKind, FederationChange, Preview, TransactionEffectsPreview, preview_programmable_transaction,
+    preview_transaction,
+};
+pub use quorum::*;
 pub use reinstate_root_authority::*;
+pub use revoke_attestation::*;
 pub use revoke_root_authority::*;
+pub use rotate_root_authority::*;
+pub use set_federation_metadata::*;
+pub use set_grant_policy::*;
+pub use transfer_capability::*;
+pub use with_capability::WithCapability;
+pub use with_reason::WithReason;
@@ -0,0 +1,253 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Move Abort Mapping
+//!
+//! Failed transactions only report the raw abort code of the Move function that aborted,
+//! e.g. `MoveAbort(MoveLocation { module: ModuleId { .. name: Identifier("main") }, .. }, 6)`.
+//! [`MoveAbortMapper`] extracts the abort from such a failure and translates the error codes
+//! of the `main` module into typed [`TransactionError`] variants.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let result = client
+//!     .create_accreditation_to_attest(federation_id, receiver, properties)
+//!     .build_and_execute(&client)
+//!     .await;
+//!
+//! if let Err(err) = result {
+//!     match MoveAbortMapper::new().map_error(&err) {
+//!         Some(TransactionError::PropertyNotInFederation { .. }) => { /* ... */ }
+//!         _ => return Err(err.into()),
+//!     }
+//! }
+//! ```
+
+use std::fmt::Debug;
+
+use crate::core::transactions::TransactionError;
+use crate::core::types::move_names;
+use crate::core::types::property_name::PropertyName;
+
+/// A Move abort extracted from a failed transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveAbort {
+    /// The name of the module that aborted.
+    pub module: String,
+    /// The name of the function that aborted, if reported.
+    pub function: Option<String>,
+    /// The abort code.
+    pub code: u64,
+}
+
+impl MoveAbort {
+    /// Extracts the Move abort from the failure message of a transaction.
+    ///
+    /// Escaped quotes, as found in `Debug` output, are accepted. Returns `None` if the
+    /// message doesn't contain a Move abort.
+    pub fn parse(message: &str) -> Option<Self> {
+        let message = message.replace('\\', "");
+        let abort = &message[message.find("MoveAbort(")?..];
+
+        let module = quoted_after(abort, "name: Identifier(\"")?;
+        let function = quoted_after(abort, "function_name: Some(\"");
+
+        // The abort code follows the closing brace of the `MoveLocation`, which is the
+        // first one after the nested `ModuleId`.
+        let location = &abort[abort.find("function:")?..];
+        let code = location[location.find(" }, ")? + 4..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok()?;
+
+        Some(Self {
+            module: module.to_string(),
+            function: function.map(str::to_string),
+            code,
+        })
+    }
+}
+
+/// Returns the text between `prefix` and the next quote.
+fn quoted_after<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let start = text.find(prefix)? + prefix.len();
+    let len = text[start..].find('"')?;
+    Some(&text[start..start + len])
+}
+
+/// Translates Move aborts of the Hierarchies package into typed [`TransactionError`]s.
+///
+/// The abort codes don't carry the arguments of the failed call. If the transaction
+/// targets a single property, its name can be attached with [`MoveAbortMapper::with_property_name`].
+#[derive(Debug, Clone, Default)]
+pub struct MoveAbortMapper {
+    property_name: Option<PropertyName>,
+}
+
+impl MoveAbortMapper {
+    /// Creates a new [`MoveAbortMapper`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches the name of the property the transaction targets to property related errors.
+    pub fn with_property_name(mut self, name: PropertyName) -> Self {
+        self.property_name = Some(name);
+        self
+    }
+
+    /// Translates the abort into its [`TransactionError`].
+    ///
+    /// Aborts of other modules than `main` and unknown codes map to [`TransactionError::MoveAbort`].
+    pub fn map_abort(&self, abort: &MoveAbort) -> TransactionError {
+        if abort.module != move_names::MODULE_MAIN {
+            return TransactionError::MoveAbort {
+                module: abort.module.clone(),
+                code: abort.code,
+            };
+        }
+
+        match abort.code {
+            1 => TransactionError::WrongFederation,
+            2 => TransactionError::InsufficientAccreditation,
+            3 => TransactionError::InvalidPropertyValueCondition,
+            4 => TransactionError::AccreditationNotFound,
+            5 => TransactionError::TimestampInThePast,
+            6 => TransactionError::PropertyNotInFederation {
+                name: self.property_name.clone(),
+            },
+            7 => TransactionError::NotRootAuthority,
+            8 => TransactionError::LastRootAuthority,
+            9 => TransactionError::RevokedRootAuthority,
+            10 => TransactionError::EmptyAllowedValues,
+            11 => TransactionError::AlreadyRootAuthority,
+            12 => TransactionError::RootAuthorityNotRevoked,
+            13 => TransactionError::PropertyRevoked {
+                name: self.property_name.clone(),
+            },
+            14 => TransactionError::ValueDictionaryNotFound,
+            15 => TransactionError::EmptyValueDictionary,
+            16 => TransactionError::ValueDictionaryInUse,
+            17 => TransactionError::ValueDictionaryMismatch,
+            18 => TransactionError::AllowAnyNotPermitted,
+            19 => TransactionError::AllowedValuesLimitExceeded,
+            20 => TransactionError::TooManyTags,
+            21 => TransactionError::InvalidTag,
+            22 => TransactionError::NotAccreditationIssuer,
+            23 => TransactionError::QuorumRequired,
+            24 => TransactionError::InvalidQuorumThreshold,
+            25 => TransactionError::ProposalNotFound,
+            26 => TransactionError::AlreadyApproved,
+            27 => TransactionError::QuorumNotReached,
+            28 => TransactionError::InvalidMetadata,
+            29 => TransactionError::TransferNotConfirmed,
+            30 => TransactionError::InvalidTransferRecipient,
+            31 => TransactionError::AnnotationTooLong,
+            32 => TransactionError::UnauthorizedAttester,
+            33 => TransactionError::InvalidValidityWindow,
+            34 => TransactionError::AttestationNotFound,
+            35 => TransactionError::AttestationRevoked,
+            36 => TransactionError::CannotLinkSelf,
+            37 => TransactionError::FederationAlreadyLinked,
+            38 => TransactionError::FederationNotLinked,
+            39 => TransactionError::InvalidPropertyReplacement,
+            40 => TransactionError::WildcardProperty,
+            41 => TransactionError::InvalidDefaultValidity,
+            42 => TransactionError::EmptyAttestation,
+            code => TransactionError::MoveAbort {
+                module: abort.module.clone(),
+                code,
+            },
+        }
+    }
+
+    /// Translates the Move abort contained in a failure message.
+    ///
+    /// Returns `None` if the message doesn't contain a Move abort.
+    pub fn map_message(&self, message: &str) -> Option<TransactionError> {
+        MoveAbort::parse(message).map(|abort| self.map_abort(&abort))
+    }
+
+    /// Translates the Move abort that caused `error`.
+    ///
+    /// The `Debug` representation is inspected, as it includes the sources of the error.
+    /// Returns `None` if the error wasn't caused by a Move abort.
+    pub fn map_error<E: Debug + ?Sized>(&self, error: &E) -> Option<TransactionError> {
+        self.map_message(&format!("{error:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABORT: &str = "MoveAbort(MoveLocation { module: ModuleId { address: \
+                         0x1f6a2e0c9b3d4c5e, name: Identifier(\"main\") }, function: 12, instruction: 34, \
+                         function_name: Some(\"create_accreditation_to_attest\") }, 6) in command 0";
+
+    #[test]
+    fn parses_move_abort() {
+        assert_eq!(
+            MoveAbort::parse(ABORT),
+            Some(MoveAbort {
+                module: "main".to_string(),
+                function: Some("create_accreditation_to_attest".to_string()),
+                code: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_escaped_move_abort() {
+        let debug = format!("ExecutionFailed {{ reason: {ABORT:?} }}");
+
+        assert_eq!(MoveAbort::parse(&debug).map(|abort| abort.code), Some(6));
+    }
+
+    #[test]
+    fn parses_move_abort_without_function_name() {
+        let message = "MoveAbort(MoveLocation { module: ModuleId { address: 0x2, name: Identifier(\"main\") }, \
+                       function: 3, instruction: 7, function_name: None }, 8) in command 1";
+
+        let abort = MoveAbort::parse(message).unwrap();
+        assert_eq!(abort.function, None);
+        assert_eq!(abort.code, 8);
+    }
+
+    #[test]
+    fn ignores_other_failures() {
+        assert_eq!(MoveAbort::parse("InsufficientGas"), None);
+        assert!(MoveAbortMapper::new().map_message("InsufficientGas").is_none());
+    }
+
+    #[test]
+    fn maps_main_module_codes() {
+        let mapper = MoveAbortMapper::new().with_property_name(PropertyName::from("degree"));
+
+        assert!(matches!(
+            mapper.map_message(ABORT),
+            Some(TransactionError::PropertyNotInFederation { name: Some(name) }) if name == PropertyName::from("degree")
+        ));
+        assert!(matches!(
+            mapper.map_message(&ABORT.replace("}, 6)", "}, 8)")),
+            Some(TransactionError::LastRootAuthority)
+        ));
+        assert!(matches!(
+            mapper.map_message(&ABORT.replace("}, 6)", "}, 99)")),
+            Some(TransactionError::MoveAbort { code: 99, .. })
+        ));
+    }
+
+    #[test]
+    fn maps_other_modules_to_move_abort() {
+        let message = ABORT.replace("Identifier(\"main\")", "Identifier(\"property_shape\")");
+
+        assert!(matches!(
+            MoveAbortMapper::new().map_message(&message),
+            Some(TransactionError::MoveAbort { module, code: 6 }) if module == "property_shape"
+        ));
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Multi-Signature Transactions
+//!
+//! Consortium governance usually requires more than one person to approve a change. A
+//! `RootAuthorityCap` can be held by a multisig address, so that its transactions need the
+//! signatures of several root authority members, each of whom signs offline with their own key.
+//! Unlike the on-chain [quorum](super::quorum), no proposal or approval transactions are needed.
+//!
+//! A [`PreparedTransaction`] is the bundle passed between the signers: the transaction data with
+//! the multisig sender, the multisig public key and the signatures collected so far.
+//!
+//! 1. A coordinator prepares the transaction with [`PreparedTransaction::prepare`] and shares
+//!    [`to_bytes`](PreparedTransaction::to_bytes).
+//! 2. Every signer signs [`to_signable_bytes`](PreparedTransaction::to_signable_bytes), e.g. with `iota keytool sign`
+//!    or a hardware wallet, and attaches the signature with
+//!    [`attach_signature`](PreparedTransaction::attach_signature).
+//! 3. The coordinator merges the bundles with [`combine`](PreparedTransaction::combine) and, once the threshold is met,
+//!    submits the transaction signed by [`into_signed`](PreparedTransaction::into_signed).
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let multisig = MultiSigPublicKey::new(vec![alice_pk, bob_pk, carol_pk], vec![1, 1, 1], 2)?;
+//! let sender = IotaAddress::from(&multisig);
+//!
+//! let tx = AddProperty::new(federation_id, property, sender);
+//! let prepared = PreparedTransaction::prepare(&tx, multisig, 50_000_000, &read_only_client).await?;
+//!
+//! // alice and bob, offline
+//! let alice = prepared.clone().attach_signature(alice_signature)?;
+//! let bob = prepared.attach_signature(bob_signature)?;
+//!
+//! let (tx_data, signature) = alice.combine(bob)?.into_signed()?;
+//! ```
+
+use iota_interaction::types::base_types::{IotaAddress, ObjectRef};
+use iota_interaction::types::crypto::{PublicKey, Signature};
+use iota_interaction::types::digests::TransactionDigest;
+use iota_interaction::types::multisig::{MultiSig, MultiSigPublicKey};
+use iota_interaction::types::signature::GenericSignature;
+use iota_interaction::types::transaction::{TransactionData, TransactionDataAPI};
+use iota_interaction::{IotaClientTrait, OptionalSync};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::OperationError;
+use crate::core::transactions::TransactionError;
+use crate::error::NetworkError;
+
+/// Errors of assembling multi-signature transactions
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum MultisigError {
+    /// The transaction isn't sent by the multisig address
+    #[error("transaction sender {sender} is not the multisig address {multisig_address}")]
+    WrongSender {
+        sender: IotaAddress,
+        multisig_address: IotaAddress,
+    },
+
+    /// The signature was made by a key that isn't part of the multisig
+    #[error("signer is not a member of the multisig")]
+    NotAMember,
+
+    /// The member already signed the transaction
+    #[error("member already signed the transaction")]
+    AlreadySigned,
+
+    /// The bundles to combine are for different transactions or multisig keys
+    #[error("bundles of different transactions can't be combined")]
+    Mismatch,
+
+    /// The weight of the signatures doesn't reach the threshold of the multisig
+    #[error("signatures weigh {weight}, the multisig requires {threshold}")]
+    ThresholdNotReached { weight: u16, threshold: u16 },
+
+    /// The multisig address owns too few gas coins for the gas budget
+    #[error("multisig address {address} has less than {budget} NANOS in gas coins")]
+    InsufficientGas { address: IotaAddress, budget: u64 },
+
+    /// A signature, key or bundle is malformed
+    #[error("invalid multisig data: {reason}")]
+    Invalid { reason: String },
+
+    /// The network couldn't be queried for gas coins or the gas price
+    #[error(transparent)]
+    Network(#[from] NetworkError),
+
+    /// The transaction couldn't be built
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+}
+
+/// A transaction of a multisig address together with the signatures collected so far.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreparedTransaction {
+    tx_data: TransactionData,
+    multisig: MultiSigPublicKey,
+    signatures: Vec<Signature>,
+}
+
+impl PreparedTransaction {
+    /// Creates a bundle for `tx_data`, which must be sent by the address of `multisig`.
+    pub fn new(tx_data: TransactionData, multisig: MultiSigPublicKey) -> Result<Self, MultisigError> {
+        let multisig_address = IotaAddress::from(&multisig);
+        if tx_data.sender() != multisig_address {
+            return Err(MultisigError::WrongSender {
+                sender: tx_data.sender(),
+                multisig_address,
+            });
+        }
+
+        Ok(Self {
+            tx_data,
+            multisig,
+            signatures: Vec::new(),
+        })
+    }
+
+    /// Builds `tx` with the address of `multisig` as sender and paying up to `gas_budget` NANOS.
+    ///
+    /// The transaction must have been created with the multisig address as signer, so that the
+    /// capability of the multisig is used. Gas is paid with coins of the multisig address at the
+    /// reference gas price. The gas coins are locked in the transaction, so they shouldn't be
+    /// spent until it is submitted.
+    pub async fn prepare<T, C>(
+        tx: &T,
+        multisig: MultiSigPublicKey,
+        gas_budget: u64,
+        client: &C,
+    ) -> Result<Self, MultisigError>
+    where
+        T: Transaction,
+        T::Error: Into<TransactionError>,
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let sender = IotaAddress::from(&multisig);
+        let ptb = tx.build_programmable_transaction(client).await.map_err(Into::into)?;
+        let gas_payment = select_gas(sender, gas_budget, client).await?;
+        let gas_price = client
+            .client_adapter()
+            .read_api()
+            .get_reference_gas_price()
+            .await
+            .map_err(rpc_error)?;
+
+        let tx_data = TransactionData::new_programmable(sender, gas_payment, ptb, gas_budget, gas_price);
+        Self::new(tx_data, multisig)
+    }
+
+    /// Returns the transaction data.
+    pub fn tx_data(&self) -> &TransactionData {
+        &self.tx_data
+    }
+
+    /// Returns the digest of the transaction, identical for all signers.
+    pub fn digest(&self) -> TransactionDigest {
+        self.tx_data.digest()
+    }
+
+    /// Returns the multisig public key.
+    pub fn multisig(&self) -> &MultiSigPublicKey {
+        &self.multisig
+    }
+
+    /// Returns the BCS bytes of the transaction data, which every member signs.
+    ///
+    /// Signers wrap them in a transaction intent before signing, as `iota keytool sign` and
+    /// wallets do.
+    pub fn to_signable_bytes(&self) -> Result<Vec<u8>, MultisigError> {
+        bcs::to_bytes(&self.tx_data).map_err(|err| OperationError::from(err).into())
+    }
+
+    /// Adds the signature of a member.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the signer is not a member of the multisig or already signed.
+    pub fn attach_signature(mut self, signature: Signature) -> Result<Self, MultisigError> {
+        let signer = public_key(&signature)?;
+        if self.multisig.get_index(&signer).is_none() {
+            return Err(MultisigError::NotAMember);
+        }
+        for attached in &self.signatures {
+            if public_key(attached)? == signer {
+                return Err(MultisigError::AlreadySigned);
+            }
+        }
+
+        self.signatures.push(signature);
+        Ok(self)
+    }
+
+    /// Merges the signatures of `other`, a bundle of the same transaction signed by other members.
+    ///
+    /// Signatures present in both bundles are kept once.
+    pub fn combine(mut self, other: PreparedTransaction) -> Result<Self, MultisigError> {
+        if self.digest() != other.digest() || self.multisig != other.multisig {
+            return Err(MultisigError::Mismatch);
+        }
+
+        for signature in other.signatures {
+            self = match self.attach_signature(signature) {
+                Err(MultisigError::AlreadySigned) => continue,
+                result => result?,
+            };
+        }
+        Ok(self)
+    }
+
+    /// Returns the signatures collected so far.
+    pub fn signatures(&self) -> &[Signature] {
+        &self.signatures
+    }
+
+    /// Returns the summed weight of the members who signed.
+    pub fn signed_weight(&self) -> Result<u16, MultisigError> {
+        let mut weight = 0u16;
+        for signature in &self.signatures {
+            let signer = public_key(signature)?;
+            weight += self
+                .multisig
+                .pubkeys()
+                .iter()
+                .find(|(member, _)| *member == signer)
+                .map_or(0, |(_, weight)| u16::from(*weight));
+        }
+        Ok(weight)
+    }
+
+    /// Returns true if enough members signed to submit the transaction.
+    pub fn is_complete(&self) -> Result<bool, MultisigError> {
+        Ok(self.signed_weight()? >= *self.multisig.threshold())
+    }
+
+    /// Returns the transaction data and the multisig signature to submit it with, e.g. with
+    /// `iota client execute-signed-tx` or `Transaction::from_generic_sig_data` of the IOTA SDK.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`MultisigError::ThresholdNotReached`] if too few members signed.
+    pub fn into_signed(self) -> Result<(TransactionData, GenericSignature), MultisigError> {
+        let weight = self.signed_weight()?;
+        let threshold = *self.multisig.threshold();
+        if weight < threshold {
+            return Err(MultisigError::ThresholdNotReached { weight, threshold });
+        }
+
+        let multisig = MultiSig::combine(self.signatures, self.multisig).map_err(|err| MultisigError::Invalid {
+            reason: err.to_string(),
+        })?;
+        Ok((self.tx_data, GenericSignature::MultiSig(multisig)))
+    }
+
+    /// Serializes the bundle to pass it to the next signer.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, MultisigError> {
+        bcs::to_bytes(self).map_err(|err| OperationError::from(err).into())
+    }
+
+    /// Deserializes a bundle serialized with [`to_bytes`](Self::to_bytes).
+    ///
+    /// The sender and the signers of the bundle are checked again, as when attaching the
+    /// signatures one by one. Signatures themselves are verified by the network on submission.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MultisigError> {
+        let bundle: Self = bcs::from_bytes(bytes).map_err(|err| MultisigError::Invalid {
+            reason: err.to_string(),
+        })?;
+        let signatures = bundle.signatures;
+        let bundle = Self::new(bundle.tx_data, bundle.multisig)?;
+
+        signatures
+            .into_iter()
+            .try_fold(bundle, |bundle, signature| bundle.attach_signature(signature))
+    }
+}
+
+impl From<OperationError> for MultisigError {
+    fn from(error: OperationError) -> Self {
+        Self::Transaction(error.into())
+    }
+}
+
+fn public_key(signature: &Signature) -> Result<PublicKey, MultisigError> {
+    signature.to_public_key().map_err(|err| MultisigError::Invalid {
+        reason: err.to_string(),
+    })
+}
+
+fn rpc_error(error: impl std::error::Error + Send + Sync + 'static) -> MultisigError {
+    NetworkError::RpcFailed {
+        source: Box::new(error),
+    }
+    .into()
+}
+
+/// Selects gas coins of `owner` worth at least `budget` NANOS.
+async fn select_gas<C>(owner: IotaAddress, budget: u64, client: &C) -> Result<Vec<ObjectRef>, MultisigError>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let mut gas_payment = Vec::new();
+    let mut total = 0u64;
+    let mut cursor = None;
+    loop {
+        let page = client
+            .client_adapter()
+            .coin_read_api()
+            .get_coins(owner, None, cursor, None)
+            .await
+            .map_err(rpc_error)?;
+        for coin in page.data {
+            total = total.saturating_add(coin.balance);
+            gas_payment.push(coin.object_ref());
+            if total >= budget {
+                return Ok(gas_payment);
+            }
+        }
+        if !page.has_next_page {
+            return Err(MultisigError::InsufficientGas { address: owner, budget });
+        }
+        cursor = page.next_cursor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_bundles_are_rejected() {
+        let error = PreparedTransaction::from_bytes(&[0xff, 0x01]).unwrap_err();
+
+        assert!(matches!(error, MultisigError::Invalid { .. }));
+    }
+}
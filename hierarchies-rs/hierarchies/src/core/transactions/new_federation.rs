@@ -25,7 +25,7 @@ use tokio::sync::OnceCell;
 use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
 use crate::core::transactions::TransactionError;
 use crate::core::types::Federation;
-use crate::core::types::events::FederationCreatedEvent;
+use crate::core::types::events::{self, HierarchiesEvent};
 
 /// A transaction that creates a new federation.
 #[derive(Debug, Clone)]
@@ -86,20 +86,16 @@ impl Transaction for CreateFederation {
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let events = events
-            .data
-            .first()
-            .ok_or_else(|| TransactionError::InvalidResponse)?
-            .parsed_json
-            .clone();
-
-        let event: FederationCreatedEvent =
-            serde_json::from_value(events).map_err(|_e| TransactionError::EventProcessingFailed {
+        let federation_address = events::decode_all(events, &client.package_history())?
+            .into_iter()
+            .find_map(|event| match event {
+                HierarchiesEvent::FederationCreated(created) => Some(created.federation_address),
+                _ => None,
+            })
+            .ok_or_else(|| TransactionError::EventProcessingFailed {
                 event_type: "FederationCreatedEvent".to_string(),
             })?;
 
-        let federation_address = event.federation_address;
-
         let federation =
             client
                 .get_object_by_id(federation_address)
@@ -0,0 +1,106 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Activate Accreditation Transaction
+//!
+//! This module provides the transaction implementation for ending the objection window
+//! of an accreditation early.
+//!
+//! ## Overview
+//!
+//! If the grant policy of a federation sets an objection window, new accreditations only
+//! become effective once the window has ended. Root authorities can activate an accreditation
+//! before, e.g. once the objection period has been waived.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::WithCapability;
+
+/// A transaction that ends the objection window of an accreditation, making it effective immediately.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` for the federation
+/// - The accreditation must exist in the federation
+#[derive(Debug, Clone)]
+pub struct ActivateAccreditation {
+    federation_id: ObjectID,
+    accreditation_id: ObjectID,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl ActivateAccreditation {
+    /// Creates a new [`ActivateAccreditation`] instance.
+    ///
+    /// # Returns
+    ///
+    /// A new `ActivateAccreditation` transaction instance ready for execution.
+    pub fn new(federation_id: ObjectID, accreditation_id: ObjectID, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            accreditation_id,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for activating the accreditation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer doesn't have `RootAuthorityCap`.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::activate_accreditation(
+            self.federation_id,
+            self.accreditation_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for ActivateAccreditation {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ActivateAccreditation {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -20,8 +20,13 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use crate::core::OperationError;
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{InvariantChecked, WithCapability};
+use crate::core::types::accreditation_query::AccreditationScope;
 use crate::core::types::property::FederationProperty;
+use crate::core::types::subject_id::SubjectId;
 
 /// Transaction for creating accreditation to accredit.
 ///
@@ -30,12 +35,16 @@ use crate::core::types::property::FederationProperty;
 pub struct CreateAccreditation {
     /// The ID of the federation where the accreditation will be granted
     federation_id: ObjectID,
-    /// The ID of the user who will receive the accreditation permissions
-    receiver: ObjectID,
+    /// The user who will receive the accreditation permissions, by object ID or DID
+    receiver: SubjectId,
     /// The properties for which accreditation permissions are being granted
     want_properties: Vec<FederationProperty>,
     /// The address of the signer (used for capability verification)
     signer_address: IotaAddress,
+    /// Capability object to use instead of looking it up
+    capability: Option<CapabilityRef>,
+    /// Whether the outcome is verified after execution
+    check_invariants: bool,
     /// Cached programmable transaction
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
@@ -44,15 +53,17 @@ impl CreateAccreditation {
     /// Creates a new [`CreateAccreditation`] instance.
     pub fn new(
         federation_id: ObjectID,
-        receiver: ObjectID,
+        receiver: impl Into<SubjectId>,
         want_properties: Vec<FederationProperty>,
         signer_address: IotaAddress,
     ) -> Self {
         Self {
             federation_id,
-            receiver,
+            receiver: receiver.into(),
             want_properties,
             signer_address,
+            capability: None,
+            check_invariants: false,
             cached_ptb: OnceCell::new(),
         }
     }
@@ -62,11 +73,12 @@ impl CreateAccreditation {
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        let receiver = self.receiver.resolve(client.network_name().as_ref())?;
         let ptb = HierarchiesImpl::create_accreditation_to_accredit(
             self.federation_id,
-            self.receiver,
+            receiver,
             self.want_properties.clone(),
-            self.signer_address,
+            CapabilityOwner::new(self.signer_address, self.capability),
             client,
         )
         .await?;
@@ -75,6 +87,18 @@ impl CreateAccreditation {
     }
 }
 
+impl InvariantChecked for CreateAccreditation {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for CreateAccreditation {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 impl Transaction for CreateAccreditation {
@@ -88,10 +112,28 @@ impl Transaction for CreateAccreditation {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "create_accreditation_to_accredit",
+                PostCondition::AccreditationGranted {
+                    entity_id: self.receiver.object_id(),
+                    scope: AccreditationScope::Accredit,
+                    properties: self
+                        .want_properties
+                        .iter()
+                        .map(|property| property.name.clone())
+                        .collect(),
+                },
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
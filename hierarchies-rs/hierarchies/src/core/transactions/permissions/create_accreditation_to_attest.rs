@@ -20,8 +20,14 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use crate::core::OperationError;
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::ptb_template::PtbTemplateCache;
+use crate::core::transactions::{InvariantChecked, WithCapability};
+use crate::core::types::accreditation_query::AccreditationScope;
 use crate::core::types::property::FederationProperty;
+use crate::core::types::subject_id::SubjectId;
 
 /// Transaction for creating accreditation to attest.
 ///
@@ -30,12 +36,18 @@ use crate::core::types::property::FederationProperty;
 pub struct CreateAccreditationToAttest {
     /// The ID of the federation where the accreditation will be granted
     federation_id: ObjectID,
-    /// The ID of the user who will receive the attestation
-    receiver: ObjectID,
+    /// The user who will receive the attestation, by object ID or DID
+    receiver: SubjectId,
     /// The properties for which attestation is being granted
     want_properties: Vec<FederationProperty>,
     /// The address of the signer (used for capability verification)
     signer_address: IotaAddress,
+    /// Capability object to use instead of looking it up
+    capability: Option<CapabilityRef>,
+    /// Optional cache of transaction templates shared between transactions
+    template_cache: Option<PtbTemplateCache>,
+    /// Whether the outcome is verified after execution
+    check_invariants: bool,
     /// Cached programmable transaction
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
@@ -44,29 +56,51 @@ impl CreateAccreditationToAttest {
     /// Creates a new [`CreateAccreditationToAttest`] instance.
     pub fn new(
         federation_id: ObjectID,
-        receiver: ObjectID,
+        receiver: impl Into<SubjectId>,
         want_properties: impl IntoIterator<Item = FederationProperty>,
         signer_address: IotaAddress,
     ) -> Self {
         Self {
             federation_id,
-            receiver,
+            receiver: receiver.into(),
             want_properties: want_properties.into_iter().collect(),
             signer_address,
+            capability: None,
+            template_cache: None,
+            check_invariants: false,
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Builds the transaction from a template in `cache`, see [`PtbTemplateCache`].
+    pub fn with_template_cache(mut self, cache: PtbTemplateCache) -> Self {
+        self.template_cache = Some(cache);
+        self
+    }
+
     /// Makes a [`ProgrammableTransaction`] for the [`CreateAccreditationToAttest`] instance.
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        let receiver = self.receiver.resolve(client.network_name().as_ref())?;
+        if let Some(cache) = &self.template_cache {
+            return HierarchiesImpl::create_accreditation_to_attest_from_template(
+                self.federation_id,
+                receiver,
+                self.want_properties.clone(),
+                CapabilityOwner::new(self.signer_address, self.capability),
+                cache,
+                client,
+            )
+            .await;
+        }
+
         let ptb = HierarchiesImpl::create_accreditation_to_attest(
             self.federation_id,
-            self.receiver,
+            receiver,
             self.want_properties.clone(),
-            self.signer_address,
+            CapabilityOwner::new(self.signer_address, self.capability),
             client,
         )
         .await?;
@@ -74,6 +108,18 @@ impl CreateAccreditationToAttest {
     }
 }
 
+impl InvariantChecked for CreateAccreditationToAttest {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for CreateAccreditationToAttest {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 impl Transaction for CreateAccreditationToAttest {
@@ -87,10 +133,28 @@ impl Transaction for CreateAccreditationToAttest {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "create_accreditation_to_attest",
+                PostCondition::AccreditationGranted {
+                    entity_id: self.receiver.object_id(),
+                    scope: AccreditationScope::Attest,
+                    properties: self
+                        .want_properties
+                        .iter()
+                        .map(|property| property.name.clone())
+                        .collect(),
+                },
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,100 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Create Accreditations to Attest
+//!
+//! This module defines the batched create accreditation to attest transaction.
+//!
+//! ## Overview
+//!
+//! This transaction grants attestation permissions to several users at once.
+//! All grants are packed into a single programmable transaction, so they
+//! either all succeed or all fail.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::WithCapability;
+use crate::core::types::property::FederationProperty;
+
+/// Transaction for creating several accreditations to attest atomically.
+pub struct CreateAccreditationsToAttest {
+    /// The ID of the federation where the accreditations will be granted
+    federation_id: ObjectID,
+    /// The receivers and the properties granted to each of them
+    grants: Vec<(ObjectID, Vec<FederationProperty>)>,
+    /// The address of the signer (used for capability verification)
+    signer_address: IotaAddress,
+    /// Capability object to use instead of looking it up
+    capability: Option<CapabilityRef>,
+    /// Cached programmable transaction
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl CreateAccreditationsToAttest {
+    /// Creates a new [`CreateAccreditationsToAttest`] instance.
+    pub fn new(
+        federation_id: ObjectID,
+        grants: impl IntoIterator<Item = (ObjectID, Vec<FederationProperty>)>,
+        signer_address: IotaAddress,
+    ) -> Self {
+        Self {
+            federation_id,
+            grants: grants.into_iter().collect(),
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Makes a [`ProgrammableTransaction`] for the [`CreateAccreditationsToAttest`] instance.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::create_accreditations_to_attest(
+            self.federation_id,
+            self.grants.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for CreateAccreditationsToAttest {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for CreateAccreditationsToAttest {
+    type Error = OperationError;
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -7,24 +7,39 @@
 //!
 //! ## Permissions
 //!
+//! - `activate_accreditation`: End the objection window of an accreditation early
 //! - `create_accreditation_to_accredit`: Create accreditation to accredit
 //! - `create_accreditation_to_attest`: Create accreditation to attest
+//! - `create_accreditations_to_attest`: Create several accreditations to attest atomically
 //! - `revoke_accreditation_to_accredit`: Revoke accreditation to accredit
 //! - `revoke_accreditation_to_attest`: Revoke accreditation to attest
+//! - `set_accreditation_tags`: Replace the tags of an accreditation
 //!
 //! ## Transactions
 //!
+//! - `ActivateAccreditation`: End the objection window of an accreditation early
 //! - `CreateAccreditationToAccredit`: Create accreditation to accredit
 //! - `CreateAccreditationToAttest`: Create accreditation to attest
+//! - `CreateAccreditationsToAttest`: Create several accreditations to attest atomically
 //! - `RevokeAccreditationToAccredit`: Revoke accreditation to accredit
 //! - `RevokeAccreditationToAttest`: Revoke accreditation to attest
+//! - `RevokeAccreditationsToAttest`: Revoke several accreditations to attest atomically
+//! - `SetAccreditationTags`: Replace the tags of an accreditation
 
+mod activate_accreditation;
 mod create_accreditation_to_accredit;
 mod create_accreditation_to_attest;
+mod create_accreditations_to_attest;
 mod revoke_accreditation_to_accredit;
 mod revoke_accreditation_to_attest;
+mod revoke_accreditations_to_attest;
+mod set_accreditation_tags;
 
+pub use activate_accreditation::*;
 pub use create_accreditation_to_accredit::*;
 pub use create_accreditation_to_attest::*;
+pub use create_accreditations_to_attest::*;
 pub use revoke_accreditation_to_accredit::*;
 pub use revoke_accreditation_to_attest::*;
+pub use revoke_accreditations_to_attest::*;
+pub use set_accreditation_tags::*;
@@ -20,7 +20,12 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use crate::core::OperationError;
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{InvariantChecked, WithCapability, WithReason};
+use crate::core::types::accreditation_query::AccreditationScope;
+use crate::core::types::revocation_reason::RevocationReason;
 
 /// Transaction for revoking accreditation to accredit.
 ///
@@ -35,6 +40,12 @@ pub struct RevokeAccreditationToAccredit {
     accreditation_id: ObjectID,
     /// The address of the signer (used for capability verification)
     signer_address: IotaAddress,
+    /// Capability object to use instead of looking it up
+    capability: Option<CapabilityRef>,
+    /// Reason recorded with the revocation
+    reason: Option<RevocationReason>,
+    /// Whether the outcome is verified after execution
+    check_invariants: bool,
     /// Cached programmable transaction
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
@@ -52,6 +63,9 @@ impl RevokeAccreditationToAccredit {
             user_id,
             accreditation_id,
             signer_address,
+            capability: None,
+            reason: None,
+            check_invariants: false,
             cached_ptb: OnceCell::new(),
         }
     }
@@ -65,7 +79,8 @@ impl RevokeAccreditationToAccredit {
             self.federation_id,
             self.user_id,
             self.accreditation_id,
-            self.signer_address,
+            self.reason.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
             client,
         )
         .await?;
@@ -74,6 +89,24 @@ impl RevokeAccreditationToAccredit {
     }
 }
 
+impl InvariantChecked for RevokeAccreditationToAccredit {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for RevokeAccreditationToAccredit {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+impl WithReason for RevokeAccreditationToAccredit {
+    fn set_reason(&mut self, reason: RevocationReason) {
+        self.reason = Some(reason);
+    }
+}
+
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 impl Transaction for RevokeAccreditationToAccredit {
@@ -87,10 +120,24 @@ impl Transaction for RevokeAccreditationToAccredit {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "revoke_accreditation_to_accredit",
+                PostCondition::AccreditationRemoved {
+                    entity_id: self.user_id,
+                    scope: AccreditationScope::Accredit,
+                    accreditation_id: self.accreditation_id,
+                },
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,117 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Revoke Accreditations to Attest
+//!
+//! This module defines the batched revoke accreditation to attest transaction.
+//!
+//! ## Overview
+//!
+//! This transaction revokes several attestation permissions at once. All
+//! revocations are packed into a single programmable transaction, so they
+//! either all succeed or all fail.
+//!
+//! Revoking more than one accreditation has to be confirmed with
+//! [`Destructive::confirm_irreversible`] before the transaction can be built.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{Destructive, WithCapability};
+
+/// Transaction for revoking several accreditations to attest atomically.
+pub struct RevokeAccreditationsToAttest {
+    /// The ID of the federation where the accreditations will be revoked
+    federation_id: ObjectID,
+    /// The `(entity_id, accreditation_id)` pairs to revoke
+    revocations: Vec<(ObjectID, ObjectID)>,
+    /// The address of the signer (used for capability verification)
+    signer_address: IotaAddress,
+    /// Capability object to use instead of looking it up
+    capability: Option<CapabilityRef>,
+    /// Whether revoking several accreditations was confirmed
+    confirmed: bool,
+    /// Cached programmable transaction
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl RevokeAccreditationsToAttest {
+    /// Creates a new [`RevokeAccreditationsToAttest`] instance.
+    pub fn new(
+        federation_id: ObjectID,
+        revocations: impl IntoIterator<Item = (ObjectID, ObjectID)>,
+        signer_address: IotaAddress,
+    ) -> Self {
+        Self {
+            federation_id,
+            revocations: revocations.into_iter().collect(),
+            signer_address,
+            capability: None,
+            confirmed: false,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Makes a [`ProgrammableTransaction`] for the [`RevokeAccreditationsToAttest`] instance.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        if self.revocations.len() > 1 && !self.confirmed {
+            return Err(OperationError::DestructiveNotConfirmed {
+                operation: "revoke_accreditations_to_attest".to_string(),
+            });
+        }
+
+        let ptb = HierarchiesImpl::revoke_accreditations_to_attest(
+            self.federation_id,
+            self.revocations.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+        Ok(ptb)
+    }
+}
+
+impl Destructive for RevokeAccreditationsToAttest {
+    fn set_destructive_confirmed(&mut self) {
+        self.confirmed = true;
+    }
+}
+
+impl WithCapability for RevokeAccreditationsToAttest {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for RevokeAccreditationsToAttest {
+    type Error = OperationError;
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
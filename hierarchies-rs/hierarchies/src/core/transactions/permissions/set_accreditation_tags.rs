@@ -0,0 +1,82 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Set Accreditation Tags
+//!
+//! This module defines the transaction replacing the tags of an accreditation.
+//!
+//! ## Overview
+//!
+//! Tags are free-form labels grouping accreditations, e.g. by program or audit.
+//! They can be set by the issuer of the accreditation or by a root authority.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+
+/// Transaction for replacing the tags of an accreditation.
+///
+/// An empty set removes all tags of the accreditation.
+pub struct SetAccreditationTags {
+    /// The ID of the federation holding the accreditation
+    federation_id: ObjectID,
+    /// The ID of the accreditation to tag
+    accreditation_id: ObjectID,
+    /// The new tags of the accreditation
+    tags: HashSet<String>,
+    /// Cached programmable transaction
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl SetAccreditationTags {
+    /// Creates a new [`SetAccreditationTags`] instance.
+    pub fn new(federation_id: ObjectID, accreditation_id: ObjectID, tags: HashSet<String>) -> Self {
+        Self {
+            federation_id,
+            accreditation_id,
+            tags,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Makes a [`ProgrammableTransaction`] for the [`SetAccreditationTags`] instance.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        HierarchiesImpl::set_accreditation_tags(self.federation_id, self.accreditation_id, self.tags.clone(), client)
+            .await
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for SetAccreditationTags {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
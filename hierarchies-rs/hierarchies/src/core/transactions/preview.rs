@@ -0,0 +1,300 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Transaction Previews
+//!
+//! A preview tells what a transaction would change in a federation before it is signed, e.g. for
+//! a governance board reviewing a change before approving it. Like an
+//! [estimate](super::estimate), the programmable transaction is built exactly as execution
+//! would build it and dev-inspected with the signer as sender.
+//!
+//! The Move package emits an event for every change of a federation, so the events of the
+//! dev-inspection are decoded into a diff of the federation state: properties added or revoked,
+//! accreditations granted or revoked, root authorities changed and so on.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let (builder, preview) = client
+//!     .create_accreditation_to_attest(federation_id, receiver, properties)
+//!     .preview(&client)
+//!     .await?;
+//!
+//! println!("{preview}");
+//! // + granted 0x…42 an accreditation to attest
+//! ```
+
+use std::fmt;
+
+use async_trait::async_trait;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::{ProgrammableTransaction, TransactionKind};
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
+
+use crate::core::OperationError;
+use crate::core::transactions::{TransactionError, TransactionEstimate};
+use crate::core::types::events::{HierarchiesEvent, decode_all};
+
+/// How a change affects the state of a federation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// Something is added to the federation, e.g. a property or an accreditation
+    Added,
+    /// Something is removed from the federation or revoked
+    Removed,
+    /// Something of the federation is changed in place
+    Modified,
+}
+
+impl ChangeKind {
+    /// Classifies the change recorded by `event`.
+    pub fn of(event: &HierarchiesEvent) -> Self {
+        match event {
+            HierarchiesEvent::FederationCreated(_)
+            | HierarchiesEvent::FederationLinked(_)
+            | HierarchiesEvent::PropertyAdded(_)
+            | HierarchiesEvent::RootAuthorityAdded(_)
+            | HierarchiesEvent::RootAuthorityReinstated(_)
+            | HierarchiesEvent::AccreditationToAccreditCreated(_)
+            | HierarchiesEvent::AccreditationToAttestCreated(_)
+            | HierarchiesEvent::ActionProposed(_)
+            | HierarchiesEvent::AttestationCreated(_) => Self::Added,
+            HierarchiesEvent::FederationUnlinked(_)
+            | HierarchiesEvent::PropertyRevoked(_)
+            | HierarchiesEvent::ValueDictionaryRemoved(_)
+            | HierarchiesEvent::RootAuthorityRevoked(_)
+            | HierarchiesEvent::AccreditationToAttestRevoked(_)
+            | HierarchiesEvent::AccreditationToAccreditRevoked(_)
+            | HierarchiesEvent::AttestationRevoked(_) => Self::Removed,
+            _ => Self::Modified,
+        }
+    }
+
+    /// The marker of the change in a diff.
+    pub fn marker(self) -> char {
+        match self {
+            Self::Added => '+',
+            Self::Removed => '-',
+            Self::Modified => '~',
+        }
+    }
+}
+
+/// A single change of a federation a transaction would make.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationChange {
+    /// The federation that would change
+    pub federation_id: ObjectID,
+    pub kind: ChangeKind,
+    /// The event recording the change
+    pub event: HierarchiesEvent,
+}
+
+impl From<HierarchiesEvent> for FederationChange {
+    fn from(event: HierarchiesEvent) -> Self {
+        Self {
+            federation_id: event.federation_address(),
+            kind: ChangeKind::of(&event),
+            event,
+        }
+    }
+}
+
+impl fmt::Display for FederationChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind.marker(), self.event.summary())
+    }
+}
+
+/// The changes a transaction would make, as found by dry-running it.
+#[derive(Debug, Clone)]
+pub struct TransactionEffectsPreview {
+    estimate: TransactionEstimate,
+    changes: Vec<FederationChange>,
+}
+
+impl TransactionEffectsPreview {
+    /// Returns `true` if the transaction would execute successfully.
+    pub fn succeeds(&self) -> bool {
+        self.estimate.succeeds()
+    }
+
+    /// The estimate of the dry-run, e.g. its gas cost or why it would fail.
+    pub fn estimate(&self) -> &TransactionEstimate {
+        &self.estimate
+    }
+
+    /// The changes in the order the transaction would make them.
+    ///
+    /// Empty if the transaction would fail, as a failed transaction changes nothing.
+    pub fn changes(&self) -> &[FederationChange] {
+        &self.changes
+    }
+
+    /// The changes of the federation `federation_id`.
+    pub fn changes_of(&self, federation_id: ObjectID) -> impl Iterator<Item = &FederationChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.federation_id == federation_id)
+    }
+
+    /// Creates the preview of a transaction that would fail or couldn't be built.
+    fn failed(estimate: TransactionEstimate) -> Self {
+        Self {
+            estimate,
+            changes: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for TransactionEffectsPreview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(failure) = self.estimate.failure() {
+            return write!(f, "the transaction would fail: {failure}");
+        }
+        if self.changes.is_empty() {
+            return write!(f, "no changes");
+        }
+
+        let mut federation_id = None;
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if federation_id != Some(change.federation_id) {
+                federation_id = Some(change.federation_id);
+                writeln!(f, "federation {}:", change.federation_id)?;
+            }
+            write!(f, "  {change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A transaction whose changes can be previewed without executing it.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait Preview: Sized {
+    /// Builds the transaction and dry-runs it with the client's sender address.
+    ///
+    /// Returns the transaction alongside its [`TransactionEffectsPreview`]. A transaction that
+    /// would fail still yields a preview; an error is only returned if it couldn't be made.
+    async fn preview<C, S>(self, client: &C) -> Result<(Self, TransactionEffectsPreview), TransactionError>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<T> Preview for TransactionBuilder<T>
+where
+    T: Transaction + Send + OptionalSync,
+    T::Error: Into<TransactionError>,
+{
+    async fn preview<C, S>(self, client: &C) -> Result<(Self, TransactionEffectsPreview), TransactionError>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let preview = preview_transaction(self.transaction(), client.sender_address(), client).await?;
+
+        Ok((self, preview))
+    }
+}
+
+/// Builds `tx` and previews its changes with `sender` as the transaction sender.
+pub async fn preview_transaction<T, C>(
+    tx: &T,
+    sender: IotaAddress,
+    client: &C,
+) -> Result<TransactionEffectsPreview, TransactionError>
+where
+    T: Transaction,
+    T::Error: Into<TransactionError>,
+    C: CoreClientReadOnly + OptionalSync,
+{
+    match tx.build_programmable_transaction(client).await {
+        Ok(ptb) => preview_programmable_transaction(ptb, sender, client).await,
+        Err(error) => TransactionEstimate::from_build_error(error.into()).map(TransactionEffectsPreview::failed),
+    }
+}
+
+/// Previews the changes of the BCS-encoded programmable transaction `tx_bcs`.
+///
+/// Meant for bindings, which only hand over built transactions, see
+/// [`preview_programmable_transaction`].
+pub async fn preview_programmable_transaction_bcs<C>(
+    tx_bcs: &[u8],
+    sender: IotaAddress,
+    client: &C,
+) -> Result<TransactionEffectsPreview, TransactionError>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let ptb: ProgrammableTransaction = bcs::from_bytes(tx_bcs).map_err(OperationError::from)?;
+    preview_programmable_transaction(ptb, sender, client).await
+}
+
+/// Dev-inspects the built transaction `ptb` with `sender` as the transaction sender and decodes
+/// the changes it would make.
+pub async fn preview_programmable_transaction<C>(
+    ptb: ProgrammableTransaction,
+    sender: IotaAddress,
+    client: &C,
+) -> Result<TransactionEffectsPreview, TransactionError>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let inspection = client
+        .client_adapter()
+        .read_api()
+        .dev_inspect_transaction_block(sender, TransactionKind::Programmable(ptb), None, None, None)
+        .await
+        .map_err(|err| TransactionError::ExecutionFailed {
+            reason: format!("Failed to inspect transaction block: {err}"),
+        })?;
+
+    let estimate = TransactionEstimate::from_inspection(inspection.effects, inspection.error);
+    if !estimate.succeeds() {
+        return Ok(TransactionEffectsPreview::failed(estimate));
+    }
+    let changes = decode_all(&inspection.events, &client.package_history())?
+        .into_iter()
+        .map(FederationChange::from)
+        .collect();
+
+    Ok(TransactionEffectsPreview { estimate, changes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::events::{PropertyAddedEvent, PropertyRevokedEvent};
+    use crate::core::types::property::FederationProperty;
+    use crate::core::types::property_name::PropertyName;
+
+    #[test]
+    fn changes_are_classified_by_their_event() {
+        let federation_address = ObjectID::random();
+        let added = FederationChange::from(HierarchiesEvent::PropertyAdded(PropertyAddedEvent {
+            federation_address,
+            property: FederationProperty::new(PropertyName::from("kyc.level")),
+        }));
+        let revoked = FederationChange::from(HierarchiesEvent::PropertyRevoked(PropertyRevokedEvent {
+            federation_address,
+            property_name: PropertyName::from("kyc.level"),
+            valid_to_ms: 1_000,
+            critical: false,
+        }));
+
+        assert_eq!(added.federation_id, federation_address);
+        assert_eq!(added.kind, ChangeKind::Added);
+        assert_eq!(revoked.kind, ChangeKind::Removed);
+        assert_eq!(added.to_string(), "+ added property kyc.level");
+        assert_eq!(revoked.to_string(), "- revoked property kyc.level at 1000");
+    }
+}
@@ -17,7 +17,10 @@ use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
 use crate::core::OperationError;
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{Destructive, InvariantChecked, WithCapability};
 use crate::core::types::property_name::PropertyName;
 
 /// Transaction for adding new property types to federations.
@@ -40,6 +43,8 @@ pub mod add_property {
         federation_id: ObjectID,
         property: FederationProperty,
         owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        check_invariants: bool,
         cached_ptb: OnceCell<ProgrammableTransaction>,
     }
 
@@ -54,6 +59,8 @@ pub mod add_property {
                 federation_id,
                 property,
                 owner,
+                capability: None,
+                check_invariants: false,
                 cached_ptb: OnceCell::new(),
             }
         }
@@ -75,13 +82,30 @@ pub mod add_property {
         where
             C: CoreClientReadOnly + OptionalSync,
         {
-            let ptb =
-                HierarchiesImpl::add_property(self.federation_id, self.property.clone(), self.owner, client).await?;
+            let ptb = HierarchiesImpl::add_property(
+                self.federation_id,
+                self.property.clone(),
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
 
             Ok(ptb)
         }
     }
 
+    impl InvariantChecked for AddProperty {
+        fn enable_invariant_checks(&mut self) {
+            self.check_invariants = true;
+        }
+    }
+
+    impl WithCapability for AddProperty {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
     #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
     #[cfg_attr(feature = "send-sync", async_trait)]
     impl Transaction for AddProperty {
@@ -96,10 +120,24 @@ pub mod add_property {
             self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
         }
 
-        async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+        async fn apply<C>(
+            mut self,
+            _: &mut IotaTransactionBlockEffects,
+            client: &C,
+        ) -> Result<Self::Output, Self::Error>
         where
             C: CoreClientReadOnly + OptionalSync,
         {
+            if self.check_invariants {
+                invariants::verify(
+                    client,
+                    self.federation_id,
+                    "add_property",
+                    PostCondition::PropertyPresent(self.property.name.clone()),
+                )
+                .await?;
+            }
+
             Ok(())
         }
     }
@@ -108,6 +146,8 @@ pub mod add_property {
 /// Transaction for revoking property types from federations.
 pub mod revoke_property {
     use super::*;
+    use crate::core::transactions::WithReason;
+    use crate::core::types::revocation_reason::RevocationReason;
 
     /// A transaction that revokes a property type from a federation.
     ///
@@ -119,12 +159,17 @@ pub mod revoke_property {
     ///
     /// - The owner must possess `RootAuthorityCap` for the federation
     /// - The property must exist in the federation
+    /// - An immediate revocation (without `valid_to_ms`) must be confirmed with [`Destructive::confirm_irreversible`]
     #[derive(Debug, Clone)]
     pub struct RevokeProperty {
         federation_id: ObjectID,
         property_name: PropertyName,
         valid_to_ms: Option<u64>,
         owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        reason: Option<RevocationReason>,
+        confirmed: bool,
+        check_invariants: bool,
         cached_ptb: OnceCell<ProgrammableTransaction>,
     }
 
@@ -145,6 +190,10 @@ pub mod revoke_property {
                 property_name,
                 valid_to_ms,
                 owner,
+                capability: None,
+                reason: None,
+                confirmed: false,
+                check_invariants: false,
                 cached_ptb: OnceCell::new(),
             }
         }
@@ -160,26 +209,40 @@ pub mod revoke_property {
         ///
         /// # Errors
         ///
-        /// Returns an error if the owner doesn't have `RootAuthorityCap` or if
-        /// the property doesn't exist in the federation.
+        /// Returns an error if the owner doesn't have `RootAuthorityCap`, if
+        /// the property doesn't exist in the federation or if an immediate
+        /// revocation has not been confirmed.
         async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
         where
             C: CoreClientReadOnly + OptionalSync,
         {
+            if self.valid_to_ms.is_none() && !self.confirmed {
+                return Err(OperationError::DestructiveNotConfirmed {
+                    operation: "revoke_property".to_string(),
+                });
+            }
+
             let ptb = match self.valid_to_ms {
                 Some(valid_to_ms) => {
                     HierarchiesImpl::revoke_property_at(
                         self.federation_id,
                         self.property_name.clone(),
                         valid_to_ms,
-                        self.owner,
+                        self.reason.clone(),
+                        CapabilityOwner::new(self.owner, self.capability),
                         client,
                     )
                     .await?
                 }
                 None => {
-                    HierarchiesImpl::revoke_property(self.federation_id, self.property_name.clone(), self.owner, client)
-                        .await?
+                    HierarchiesImpl::revoke_property(
+                        self.federation_id,
+                        self.property_name.clone(),
+                        self.reason.clone(),
+                        CapabilityOwner::new(self.owner, self.capability),
+                        client,
+                    )
+                    .await?
                 }
             };
 
@@ -187,6 +250,30 @@ pub mod revoke_property {
         }
     }
 
+    impl Destructive for RevokeProperty {
+        fn set_destructive_confirmed(&mut self) {
+            self.confirmed = true;
+        }
+    }
+
+    impl InvariantChecked for RevokeProperty {
+        fn enable_invariant_checks(&mut self) {
+            self.check_invariants = true;
+        }
+    }
+
+    impl WithCapability for RevokeProperty {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    impl WithReason for RevokeProperty {
+        fn set_reason(&mut self, reason: RevocationReason) {
+            self.reason = Some(reason);
+        }
+    }
+
     #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
     #[cfg_attr(feature = "send-sync", async_trait)]
     impl Transaction for RevokeProperty {
@@ -201,6 +288,588 @@ pub mod revoke_property {
             self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
         }
 
+        async fn apply<C>(
+            mut self,
+            _: &mut IotaTransactionBlockEffects,
+            client: &C,
+        ) -> Result<Self::Output, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            if self.check_invariants {
+                invariants::verify(
+                    client,
+                    self.federation_id,
+                    "revoke_property",
+                    PostCondition::PropertyRevoked(self.property_name.clone()),
+                )
+                .await?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Transaction for marking properties as critical.
+pub mod set_property_critical {
+    use super::*;
+
+    /// A transaction that marks or unmarks a property as critical.
+    ///
+    /// Critical properties are flagged on-chain so that event consumers can
+    /// prioritize changes to them, e.g. revocations.
+    ///
+    /// ## Requirements
+    ///
+    /// - The owner must possess `RootAuthorityCap` for the federation
+    /// - The property must exist in the federation
+    #[derive(Debug, Clone)]
+    pub struct SetPropertyCritical {
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        critical: bool,
+        owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        cached_ptb: OnceCell<ProgrammableTransaction>,
+    }
+
+    impl SetPropertyCritical {
+        /// Creates a new [`SetPropertyCritical`] instance.
+        ///
+        /// # Returns
+        ///
+        /// A new `SetPropertyCritical` transaction instance ready for execution.
+        pub fn new(federation_id: ObjectID, property_name: PropertyName, critical: bool, owner: IotaAddress) -> Self {
+            Self {
+                federation_id,
+                property_name,
+                critical,
+                owner,
+                capability: None,
+                cached_ptb: OnceCell::new(),
+            }
+        }
+
+        /// Builds the programmable transaction for changing the criticality of a property.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the owner doesn't have `RootAuthorityCap` or if
+        /// the property doesn't exist in the federation.
+        async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            let ptb = HierarchiesImpl::set_property_critical(
+                self.federation_id,
+                self.property_name.clone(),
+                self.critical,
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
+
+            Ok(ptb)
+        }
+    }
+
+    impl WithCapability for SetPropertyCritical {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl Transaction for SetPropertyCritical {
+        type Error = OperationError;
+
+        type Output = ();
+
+        async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+        }
+
+        async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            Ok(())
+        }
+    }
+}
+
+/// Transaction for deprecating properties.
+pub mod deprecate_property {
+    use super::*;
+
+    /// A transaction that marks a property as deprecated.
+    ///
+    /// A deprecated property stays valid, but validation reports warn about it
+    /// and point to its replacement, if one is recorded.
+    ///
+    /// ## Requirements
+    ///
+    /// - The owner must possess `RootAuthorityCap` for the federation
+    /// - The property must exist in the federation
+    /// - The replacement, if any, must be another property of the federation
+    /// - No quorum is required; otherwise propose a [`GovernanceAction::DeprecateProperty`] instead
+    ///
+    /// [`GovernanceAction::DeprecateProperty`]: crate::core::types::quorum::GovernanceAction::DeprecateProperty
+    #[derive(Debug, Clone)]
+    pub struct DeprecateProperty {
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        replacement: Option<PropertyName>,
+        owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        cached_ptb: OnceCell<ProgrammableTransaction>,
+    }
+
+    impl DeprecateProperty {
+        /// Creates a new [`DeprecateProperty`] instance.
+        ///
+        /// # Returns
+        ///
+        /// A new `DeprecateProperty` transaction instance ready for execution.
+        pub fn new(
+            federation_id: ObjectID,
+            property_name: PropertyName,
+            replacement: Option<PropertyName>,
+            owner: IotaAddress,
+        ) -> Self {
+            Self {
+                federation_id,
+                property_name,
+                replacement,
+                owner,
+                capability: None,
+                cached_ptb: OnceCell::new(),
+            }
+        }
+
+        /// Builds the programmable transaction for deprecating a property.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+        async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            let ptb = HierarchiesImpl::deprecate_property(
+                self.federation_id,
+                self.property_name.clone(),
+                self.replacement.clone(),
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
+
+            Ok(ptb)
+        }
+    }
+
+    impl WithCapability for DeprecateProperty {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl Transaction for DeprecateProperty {
+        type Error = OperationError;
+
+        type Output = ();
+
+        async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+        }
+
+        async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            Ok(())
+        }
+    }
+}
+
+/// Transaction for labelling properties with tags.
+pub mod set_property_tags {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// A transaction that replaces the tags of a property.
+    ///
+    /// Tags are free-form labels grouping properties, e.g. `food-safety`.
+    /// An empty set removes all tags of the property.
+    ///
+    /// ## Requirements
+    ///
+    /// - The owner must possess `RootAuthorityCap` for the federation
+    /// - The property must exist in the federation
+    #[derive(Debug, Clone)]
+    pub struct SetPropertyTags {
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        tags: HashSet<String>,
+        owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        cached_ptb: OnceCell<ProgrammableTransaction>,
+    }
+
+    impl SetPropertyTags {
+        /// Creates a new [`SetPropertyTags`] instance.
+        ///
+        /// # Returns
+        ///
+        /// A new `SetPropertyTags` transaction instance ready for execution.
+        pub fn new(
+            federation_id: ObjectID,
+            property_name: PropertyName,
+            tags: HashSet<String>,
+            owner: IotaAddress,
+        ) -> Self {
+            Self {
+                federation_id,
+                property_name,
+                tags,
+                owner,
+                capability: None,
+                cached_ptb: OnceCell::new(),
+            }
+        }
+
+        /// Builds the programmable transaction for replacing the tags of a property.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+        async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            let ptb = HierarchiesImpl::set_property_tags(
+                self.federation_id,
+                self.property_name.clone(),
+                self.tags.clone(),
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
+
+            Ok(ptb)
+        }
+    }
+
+    impl WithCapability for SetPropertyTags {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl Transaction for SetPropertyTags {
+        type Error = OperationError;
+
+        type Output = ();
+
+        async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+        }
+
+        async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            Ok(())
+        }
+    }
+}
+
+/// Transaction for creating or replacing value dictionaries.
+pub mod set_property_metadata {
+    use super::*;
+    use crate::core::types::property::PropertyMetadata;
+
+    /// A transaction that replaces the metadata of a property.
+    ///
+    /// The metadata is human-readable schema information, e.g. a display name or unit,
+    /// used by verifier UIs. Empty metadata removes all entries of the property.
+    ///
+    /// ## Requirements
+    ///
+    /// - The owner must possess `RootAuthorityCap` for the federation
+    /// - The property must exist in the federation
+    #[derive(Debug, Clone)]
+    pub struct SetPropertyMetadata {
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        metadata: PropertyMetadata,
+        owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        cached_ptb: OnceCell<ProgrammableTransaction>,
+    }
+
+    impl SetPropertyMetadata {
+        /// Creates a new [`SetPropertyMetadata`] instance.
+        ///
+        /// # Returns
+        ///
+        /// A new `SetPropertyMetadata` transaction instance ready for execution.
+        pub fn new(
+            federation_id: ObjectID,
+            property_name: PropertyName,
+            metadata: PropertyMetadata,
+            owner: IotaAddress,
+        ) -> Self {
+            Self {
+                federation_id,
+                property_name,
+                metadata,
+                owner,
+                capability: None,
+                cached_ptb: OnceCell::new(),
+            }
+        }
+
+        /// Builds the programmable transaction for replacing the metadata of a property.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+        async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            let ptb = HierarchiesImpl::set_property_metadata(
+                self.federation_id,
+                self.property_name.clone(),
+                self.metadata.clone(),
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
+
+            Ok(ptb)
+        }
+    }
+
+    impl WithCapability for SetPropertyMetadata {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl Transaction for SetPropertyMetadata {
+        type Error = OperationError;
+
+        type Output = ();
+
+        async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+        }
+
+        async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            Ok(())
+        }
+    }
+}
+
+pub mod set_value_dictionary {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::core::types::property_value::PropertyValue;
+
+    /// A transaction that creates or replaces a named value dictionary.
+    ///
+    /// Properties referencing the dictionary by name accept its values, so a
+    /// shared set of values (e.g. country codes) is maintained in one place.
+    ///
+    /// ## Requirements
+    ///
+    /// - The owner must possess `RootAuthorityCap` for the federation
+    /// - The set of values must not be empty
+    /// - No quorum is required; otherwise propose a [`GovernanceAction::SetValueDictionary`] instead
+    ///
+    /// [`GovernanceAction::SetValueDictionary`]: crate::core::types::quorum::GovernanceAction::SetValueDictionary
+    #[derive(Debug, Clone)]
+    pub struct SetValueDictionary {
+        federation_id: ObjectID,
+        name: String,
+        values: HashSet<PropertyValue>,
+        owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        cached_ptb: OnceCell<ProgrammableTransaction>,
+    }
+
+    impl SetValueDictionary {
+        /// Creates a new [`SetValueDictionary`] instance.
+        ///
+        /// # Returns
+        ///
+        /// A new `SetValueDictionary` transaction instance ready for execution.
+        pub fn new(federation_id: ObjectID, name: String, values: HashSet<PropertyValue>, owner: IotaAddress) -> Self {
+            Self {
+                federation_id,
+                name,
+                values,
+                owner,
+                capability: None,
+                cached_ptb: OnceCell::new(),
+            }
+        }
+
+        /// Builds the programmable transaction for setting the value dictionary.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+        async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            let ptb = HierarchiesImpl::set_value_dictionary(
+                self.federation_id,
+                self.name.clone(),
+                self.values.clone(),
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
+
+            Ok(ptb)
+        }
+    }
+
+    impl WithCapability for SetValueDictionary {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl Transaction for SetValueDictionary {
+        type Error = OperationError;
+
+        type Output = ();
+
+        async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+        }
+
+        async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            Ok(())
+        }
+    }
+}
+
+/// Transaction for removing value dictionaries.
+pub mod remove_value_dictionary {
+    use super::*;
+
+    /// A transaction that removes a named value dictionary.
+    ///
+    /// ## Requirements
+    ///
+    /// - The owner must possess `RootAuthorityCap` for the federation
+    /// - No property of the federation may reference the dictionary
+    #[derive(Debug, Clone)]
+    pub struct RemoveValueDictionary {
+        federation_id: ObjectID,
+        name: String,
+        owner: IotaAddress,
+        capability: Option<CapabilityRef>,
+        cached_ptb: OnceCell<ProgrammableTransaction>,
+    }
+
+    impl RemoveValueDictionary {
+        /// Creates a new [`RemoveValueDictionary`] instance.
+        ///
+        /// # Returns
+        ///
+        /// A new `RemoveValueDictionary` transaction instance ready for execution.
+        pub fn new(federation_id: ObjectID, name: String, owner: IotaAddress) -> Self {
+            Self {
+                federation_id,
+                name,
+                owner,
+                capability: None,
+                cached_ptb: OnceCell::new(),
+            }
+        }
+
+        /// Builds the programmable transaction for removing the value dictionary.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the owner doesn't have `RootAuthorityCap`.
+        async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            let ptb = HierarchiesImpl::remove_value_dictionary(
+                self.federation_id,
+                self.name.clone(),
+                CapabilityOwner::new(self.owner, self.capability),
+                client,
+            )
+            .await?;
+
+            Ok(ptb)
+        }
+    }
+
+    impl WithCapability for RemoveValueDictionary {
+        fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+            self.capability = Some(capability.into());
+        }
+    }
+
+    #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync", async_trait)]
+    impl Transaction for RemoveValueDictionary {
+        type Error = OperationError;
+
+        type Output = ();
+
+        async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+        where
+            C: CoreClientReadOnly + OptionalSync,
+        {
+            self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+        }
+
         async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
         where
             C: CoreClientReadOnly + OptionalSync,
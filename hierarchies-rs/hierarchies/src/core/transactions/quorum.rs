@@ -0,0 +1,333 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Quorum Governance Transactions
+//!
+//! This module provides the transactions of the proposal lifecycle described in
+//! [`crate::core::types::quorum`].
+//!
+//! ## Overview
+//!
+//! - [`SetQuorumThreshold`] configures how many root authorities have to approve destructive actions. It is rejected
+//!   once a quorum is required; the threshold is then changed through a [`GovernanceAction::SetQuorumThreshold`]
+//!   proposal.
+//! - [`ProposeAction`] proposes a [`GovernanceAction`] and returns the ID of the proposal.
+//! - [`ApproveAction`] adds the approval of the signer to a proposal.
+//! - [`ExecuteAction`] executes a proposal approved by the quorum.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{TransactionError, WithCapability};
+use crate::core::types::events::{self, HierarchiesEvent};
+use crate::core::types::quorum::GovernanceAction;
+
+/// A transaction that sets the number of root authority approvals required for destructive actions.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` for the federation
+/// - No quorum is required yet, i.e. the current threshold is 1
+/// - The threshold is between 1 and the number of root authorities
+#[derive(Debug, Clone)]
+pub struct SetQuorumThreshold {
+    federation_id: ObjectID,
+    threshold: u64,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl SetQuorumThreshold {
+    /// Creates a new [`SetQuorumThreshold`] instance.
+    pub fn new(federation_id: ObjectID, threshold: u64, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            threshold,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::set_quorum_threshold(
+            self.federation_id,
+            self.threshold,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for SetQuorumThreshold {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for SetQuorumThreshold {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
+
+/// A transaction that proposes a [`GovernanceAction`], approved by the signer.
+///
+/// The output is the ID of the proposal, to be passed to [`ApproveAction`] and [`ExecuteAction`].
+///
+/// ## Requirements
+/// - The signer must be an active root authority of the federation
+#[derive(Debug, Clone)]
+pub struct ProposeAction {
+    federation_id: ObjectID,
+    action: GovernanceAction,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl ProposeAction {
+    /// Creates a new [`ProposeAction`] instance.
+    pub fn new(federation_id: ObjectID, action: GovernanceAction, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            action,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::propose_action(
+            self.federation_id,
+            self.action.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for ProposeAction {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ProposeAction {
+    type Error = TransactionError;
+
+    type Output = u64;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        _: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        events::decode_all(events, &client.package_history())?
+            .into_iter()
+            .find_map(|event| match event {
+                HierarchiesEvent::ActionProposed(proposed) => Some(proposed.proposal_id),
+                _ => None,
+            })
+            .ok_or_else(|| TransactionError::EventProcessingFailed {
+                event_type: "ActionProposedEvent".to_string(),
+            })
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
+
+/// A transaction that approves a proposed [`GovernanceAction`].
+///
+/// ## Requirements
+/// - The signer must be an active root authority of the federation
+/// - The signer hasn't approved the proposal yet
+#[derive(Debug, Clone)]
+pub struct ApproveAction {
+    federation_id: ObjectID,
+    proposal_id: u64,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl ApproveAction {
+    /// Creates a new [`ApproveAction`] instance.
+    pub fn new(federation_id: ObjectID, proposal_id: u64, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            proposal_id,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::approve_action(
+            self.federation_id,
+            self.proposal_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for ApproveAction {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ApproveAction {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
+
+/// A transaction that executes a [`GovernanceAction`] approved by the quorum.
+///
+/// ## Requirements
+/// - The signer must be an active root authority of the federation
+/// - The proposal is approved by at least `threshold` active root authorities
+#[derive(Debug, Clone)]
+pub struct ExecuteAction {
+    federation_id: ObjectID,
+    proposal_id: u64,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl ExecuteAction {
+    /// Creates a new [`ExecuteAction`] instance.
+    pub fn new(federation_id: ObjectID, proposal_id: u64, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            proposal_id,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::execute_action(
+            self.federation_id,
+            self.proposal_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for ExecuteAction {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ExecuteAction {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -22,7 +22,10 @@ use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{InvariantChecked, WithCapability};
 use crate::error::TransactionError;
 
 /// A transaction that reinstates a previously revoked root authority to the federation.
@@ -35,10 +38,15 @@ use crate::error::TransactionError;
 /// - The signer must already possess a `RootAuthorityCap` for the federation
 /// - The target account must be in the revoked root authorities list
 /// - The target account must not already be an active root authority
+/// - No quorum is required; otherwise propose a [`GovernanceAction::ReinstateRootAuthority`] instead
+///
+/// [`GovernanceAction::ReinstateRootAuthority`]: crate::core::types::quorum::GovernanceAction::ReinstateRootAuthority
 pub struct ReinstateRootAuthority {
     federation_id: ObjectID,
     account_id: ObjectID,
     signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    check_invariants: bool,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -53,6 +61,8 @@ impl ReinstateRootAuthority {
             federation_id,
             account_id,
             signer_address,
+            capability: None,
+            check_invariants: false,
             cached_ptb: OnceCell::new(),
         }
     }
@@ -75,14 +85,30 @@ impl ReinstateRootAuthority {
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let ptb =
-            HierarchiesImpl::reinstate_root_authority(self.federation_id, self.account_id, self.signer_address, client)
-                .await?;
+        let ptb = HierarchiesImpl::reinstate_root_authority(
+            self.federation_id,
+            self.account_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
 
         Ok(ptb)
     }
 }
 
+impl InvariantChecked for ReinstateRootAuthority {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for ReinstateRootAuthority {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 impl Transaction for ReinstateRootAuthority {
@@ -97,10 +123,20 @@ impl Transaction for ReinstateRootAuthority {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "reinstate_root_authority",
+                PostCondition::RootAuthorityPresent(self.account_id),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
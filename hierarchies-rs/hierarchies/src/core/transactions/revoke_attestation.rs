@@ -0,0 +1,82 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Revoke Attestation Transaction
+//!
+//! This module provides the transaction implementation for revoking attestations
+//! in the Hierarchies system.
+//!
+//! ## Overview
+//!
+//! The `RevokeAttestation` transaction marks an attestation as revoked in its federation.
+//! The attestation object stays with its receiver, but no longer validates.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::error::TransactionError;
+
+/// A transaction that revokes an attestation.
+///
+/// ## Requirements
+/// - The signer must be the issuer of the attestation or a root authority
+/// - The attestation must not be revoked already
+pub struct RevokeAttestation {
+    federation_id: ObjectID,
+    attestation_id: ObjectID,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl RevokeAttestation {
+    /// Creates a new [`RevokeAttestation`] instance.
+    pub fn new(federation_id: ObjectID, attestation_id: ObjectID) -> Self {
+        Self {
+            federation_id,
+            attestation_id,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for revoking the attestation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the federation cannot be referenced.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::revoke_attestation(self.federation_id, self.attestation_id, client).await?;
+
+        Ok(ptb)
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for RevokeAttestation {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -11,6 +11,10 @@
 //! The `RevokeRootAuthority` transaction removes root authority capabilities from an
 //! account within a federation. The revoked authority's capability remains but becomes
 //! unusable as they are added to a revocation list.
+//!
+//! The revocation takes effect immediately and can only be reversed by an explicit
+//! [`ReinstateRootAuthority`](super::ReinstateRootAuthority), so the transaction has to be
+//! confirmed with [`Destructive::confirm_irreversible`] before it can be built.
 
 use async_trait::async_trait;
 use iota_interaction::OptionalSync;
@@ -21,7 +25,12 @@ use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::OnceCell;
 
-use crate::core::operations::{HierarchiesImpl, HierarchiesOperations};
+use crate::core::OperationError;
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{Destructive, InvariantChecked, WithCapability, WithReason};
+use crate::core::types::revocation_reason::RevocationReason;
 use crate::error::TransactionError;
 
 /// A transaction that revokes a root authority from an existing federation.
@@ -34,10 +43,15 @@ use crate::error::TransactionError;
 /// - The signer must already possess a `RootAuthorityCap` for the federation
 /// - The target account must be an existing root authority
 /// - Cannot revoke the last root authority (to prevent lockout)
+/// - The revocation must be confirmed with [`Destructive::confirm_irreversible`]
 pub struct RevokeRootAuthority {
     federation_id: ObjectID,
     account_id: ObjectID,
     signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    reason: Option<RevocationReason>,
+    confirmed: bool,
+    check_invariants: bool,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -52,6 +66,10 @@ impl RevokeRootAuthority {
             federation_id,
             account_id,
             signer_address,
+            capability: None,
+            reason: None,
+            confirmed: false,
+            check_invariants: false,
             cached_ptb: OnceCell::new(),
         }
     }
@@ -71,18 +89,55 @@ impl RevokeRootAuthority {
     /// - The signer doesn't have the required `RootAuthorityCap`
     /// - The target account is not a root authority
     /// - Attempting to revoke the last root authority
+    /// - The revocation has not been confirmed
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let ptb =
-            HierarchiesImpl::revoke_root_authority(self.federation_id, self.account_id, self.signer_address, client)
-                .await?;
+        if !self.confirmed {
+            return Err(OperationError::DestructiveNotConfirmed {
+                operation: "revoke_root_authority".to_string(),
+            }
+            .into());
+        }
+
+        let ptb = HierarchiesImpl::revoke_root_authority(
+            self.federation_id,
+            self.account_id,
+            self.reason.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
 
         Ok(ptb)
     }
 }
 
+impl Destructive for RevokeRootAuthority {
+    fn set_destructive_confirmed(&mut self) {
+        self.confirmed = true;
+    }
+}
+
+impl InvariantChecked for RevokeRootAuthority {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for RevokeRootAuthority {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+impl WithReason for RevokeRootAuthority {
+    fn set_reason(&mut self, reason: RevocationReason) {
+        self.reason = Some(reason);
+    }
+}
+
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 impl Transaction for RevokeRootAuthority {
@@ -97,10 +152,20 @@ impl Transaction for RevokeRootAuthority {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "revoke_root_authority",
+                PostCondition::RootAuthorityRevoked(self.account_id),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
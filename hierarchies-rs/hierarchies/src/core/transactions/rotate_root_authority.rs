@@ -0,0 +1,163 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Rotate Root Authority Transaction
+//!
+//! This module provides the transaction implementation for handing a root authority over to
+//! another account in the Hierarchies system.
+//!
+//! ## Overview
+//!
+//! The `RotateRootAuthority` transaction adds the new root authority, sends it a new
+//! `RootAuthorityCap` and revokes the old root authority in a single Move call. Running
+//! [`AddRootAuthority`](super::AddRootAuthority) and
+//! [`RevokeRootAuthority`](super::RevokeRootAuthority) one after the other may leave the
+//! federation with both or, when the old account performs the revocation, none of them active if
+//! the second transaction fails. A rotation is applied entirely or not at all.
+//!
+//! The old root authority is revoked immediately and can only be brought back by an explicit
+//! [`ReinstateRootAuthority`](super::ReinstateRootAuthority), so the rotation has to be confirmed
+//! with [`Destructive::confirm_irreversible`] before it can be built.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::invariants::{self, PostCondition};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{Destructive, InvariantChecked, WithCapability};
+use crate::error::TransactionError;
+
+/// A transaction that hands the root authority of an account over to another account.
+///
+/// ## Requirements
+/// - The signer must possess a `RootAuthorityCap` for the federation, e.g. the old root authority
+/// - The old account must be an active root authority
+/// - The new account must be neither an active nor a revoked root authority
+/// - No quorum is required for destructive actions
+/// - The rotation must be confirmed with [`Destructive::confirm_irreversible`]
+pub struct RotateRootAuthority {
+    federation_id: ObjectID,
+    old_account_id: ObjectID,
+    new_account_id: ObjectID,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    confirmed: bool,
+    check_invariants: bool,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl RotateRootAuthority {
+    /// Creates a new [`RotateRootAuthority`] instance.
+    pub fn new(
+        federation_id: ObjectID,
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+        signer_address: IotaAddress,
+    ) -> Self {
+        Self {
+            federation_id,
+            old_account_id,
+            new_account_id,
+            signer_address,
+            capability: None,
+            confirmed: false,
+            check_invariants: false,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for rotating a root authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The rotation has not been confirmed
+    /// - Both accounts are the same
+    /// - The signer doesn't have the required `RootAuthorityCap`
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        if !self.confirmed {
+            return Err(OperationError::DestructiveNotConfirmed {
+                operation: "rotate_root_authority".to_string(),
+            }
+            .into());
+        }
+        if self.old_account_id == self.new_account_id {
+            return Err(TransactionError::AlreadyRootAuthority);
+        }
+
+        let ptb = HierarchiesImpl::rotate_root_authority(
+            self.federation_id,
+            self.old_account_id,
+            self.new_account_id,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl Destructive for RotateRootAuthority {
+    fn set_destructive_confirmed(&mut self) {
+        self.confirmed = true;
+    }
+}
+
+impl InvariantChecked for RotateRootAuthority {
+    fn enable_invariant_checks(&mut self) {
+        self.check_invariants = true;
+    }
+}
+
+impl WithCapability for RotateRootAuthority {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for RotateRootAuthority {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        if self.check_invariants {
+            invariants::verify(
+                client,
+                self.federation_id,
+                "rotate_root_authority",
+                PostCondition::RootAuthorityRotated {
+                    old_account_id: self.old_account_id,
+                    new_account_id: self.new_account_id,
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
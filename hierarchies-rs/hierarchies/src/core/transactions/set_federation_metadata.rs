@@ -0,0 +1,103 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Set Federation Metadata Transaction
+//!
+//! This module provides the transaction implementation for setting the metadata
+//! of a federation.
+//!
+//! ## Overview
+//!
+//! The metadata is a human-readable description of the federation, i.e. its display name,
+//! description, logo URI and contact, shown by wallets and explorers instead of the bare
+//! object ID. It is informational only and never used by validation.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::WithCapability;
+use crate::core::types::FederationMetadata;
+
+/// A transaction that sets the metadata of a federation, replacing the previous one.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` for the federation
+/// - Every set field is non-empty and at most [`FederationMetadata::MAX_FIELD_LENGTH`] bytes long
+#[derive(Debug, Clone)]
+pub struct SetFederationMetadata {
+    federation_id: ObjectID,
+    metadata: FederationMetadata,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl SetFederationMetadata {
+    /// Creates a new [`SetFederationMetadata`] instance.
+    pub fn new(federation_id: ObjectID, metadata: FederationMetadata, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            metadata,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for setting the metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer doesn't have `RootAuthorityCap`.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::set_federation_metadata(
+            self.federation_id,
+            self.metadata.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for SetFederationMetadata {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for SetFederationMetadata {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
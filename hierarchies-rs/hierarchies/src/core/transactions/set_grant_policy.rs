@@ -0,0 +1,110 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Set Grant Policy Transaction
+//!
+//! This module provides the transaction implementation for setting the grant policy
+//! of a federation.
+//!
+//! ## Overview
+//!
+//! The grant policy holds federation-wide defaults and limits for new accreditations:
+//! a default validity length for granted properties without an explicit end, whether
+//! `allow_any` grants are permitted, the maximum number of allowed values, and an objection
+//! window delaying new accreditations from becoming effective.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::OperationError;
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::WithCapability;
+use crate::core::types::grant_policy::GrantPolicy;
+
+/// A transaction that sets the grant policy of a federation.
+///
+/// The policy is applied to accreditations created afterwards; existing
+/// accreditations are not affected.
+///
+/// ## Requirements
+/// - The signer must possess `RootAuthorityCap` for the federation
+#[derive(Debug, Clone)]
+pub struct SetGrantPolicy {
+    federation_id: ObjectID,
+    policy: GrantPolicy,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl SetGrantPolicy {
+    /// Creates a new [`SetGrantPolicy`] instance.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetGrantPolicy` transaction instance ready for execution.
+    pub fn new(federation_id: ObjectID, policy: GrantPolicy, signer_address: IotaAddress) -> Self {
+        Self {
+            federation_id,
+            policy,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Builds the programmable transaction for setting the grant policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer doesn't have `RootAuthorityCap`.
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, OperationError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::set_grant_policy(
+            self.federation_id,
+            self.policy.clone(),
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for SetGrantPolicy {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for SetGrantPolicy {
+    type Error = OperationError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Capability Transfer Transactions
+//!
+//! This module provides the transactions to rotate the key of a capability holder.
+//!
+//! ## Overview
+//!
+//! - [`TransferCapability`] transfers a `RootAuthorityCap` or `AccreditCap` to a new address. The root authority and
+//!   the accreditations of the holder move along, so the old address loses its rights.
+//! - [`ConfirmCapabilityTransfer`] confirms a transfer. While a quorum is required, see [`crate::core::types::quorum`],
+//!   a transfer needs the confirmation of a root authority other than the holder.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use crate::core::capability_cache::{CapabilityKey, capability_cache};
+use crate::core::operations::{CapabilityOwner, HierarchiesImpl, HierarchiesOperations};
+use crate::core::prefetch::CapabilityRef;
+use crate::core::transactions::{TransactionError, WithCapability};
+use crate::core::types::CapabilityKind;
+
+/// A transaction that transfers a capability of the signer to a new address.
+///
+/// ## Requirements
+/// - The signer must possess a capability of the given kind for the federation
+/// - The recipient is neither a root authority nor holds accreditations
+/// - While a quorum is required, the transfer was confirmed with [`ConfirmCapabilityTransfer`]
+#[derive(Debug, Clone)]
+pub struct TransferCapability {
+    federation_id: ObjectID,
+    kind: CapabilityKind,
+    recipient: IotaAddress,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl TransferCapability {
+    /// Creates a new [`TransferCapability`] instance.
+    pub fn new(
+        federation_id: ObjectID,
+        kind: CapabilityKind,
+        recipient: IotaAddress,
+        signer_address: IotaAddress,
+    ) -> Self {
+        Self {
+            federation_id,
+            kind,
+            recipient,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::transfer_capability(
+            self.federation_id,
+            self.kind,
+            self.recipient,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for TransferCapability {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for TransferCapability {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        capability_cache().invalidate(&CapabilityKey::new(self.signer_address, self.federation_id, self.kind));
+        Ok(())
+    }
+}
+
+/// A transaction that confirms the transfer of a capability to a recipient.
+///
+/// ## Requirements
+/// - The signer must be an active root authority of the federation
+/// - The signer isn't the holder of the capability, otherwise the confirmation is ignored by the transfer
+#[derive(Debug, Clone)]
+pub struct ConfirmCapabilityTransfer {
+    federation_id: ObjectID,
+    capability_id: ObjectID,
+    recipient: IotaAddress,
+    signer_address: IotaAddress,
+    capability: Option<CapabilityRef>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl ConfirmCapabilityTransfer {
+    /// Creates a new [`ConfirmCapabilityTransfer`] instance.
+    pub fn new(
+        federation_id: ObjectID,
+        capability_id: ObjectID,
+        recipient: IotaAddress,
+        signer_address: IotaAddress,
+    ) -> Self {
+        Self {
+            federation_id,
+            capability_id,
+            recipient,
+            signer_address,
+            capability: None,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, TransactionError>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let ptb = HierarchiesImpl::confirm_capability_transfer(
+            self.federation_id,
+            self.capability_id,
+            self.recipient,
+            CapabilityOwner::new(self.signer_address, self.capability),
+            client,
+        )
+        .await?;
+
+        Ok(ptb)
+    }
+}
+
+impl WithCapability for ConfirmCapabilityTransfer {
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.capability = Some(capability.into());
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ConfirmCapabilityTransfer {
+    type Error = TransactionError;
+
+    type Output = ();
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        Ok(())
+    }
+}
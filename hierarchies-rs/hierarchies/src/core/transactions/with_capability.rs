@@ -0,0 +1,48 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Known Capabilities
+//!
+//! Administrative transactions look up the signer's `RootAuthorityCap` or `AccreditCap`
+//! by scanning the objects owned by the signer. For addresses owning many objects this
+//! scan pages through the whole wallet on every transaction.
+//!
+//! Callers that already know the capability's object ID can pass it with
+//! [`WithCapability::with_capability`] to skip the scan. The ID is not checked up front;
+//! a wrong capability makes the transaction fail on chain. Passing the full object reference
+//! also skips fetching the capability's current version, see [`crate::core::prefetch`].
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! client
+//!     .add_property(federation_id, property)
+//!     .with_capability(root_authority_cap_id)
+//!     .build_and_execute(&client)
+//!     .await?;
+//! ```
+
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+
+use crate::core::prefetch::CapabilityRef;
+
+/// A transaction that can use a known capability object instead of looking it up.
+pub trait WithCapability: Sized {
+    /// Sets the capability of this transaction, in place.
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>);
+
+    /// Uses the capability with the given object ID or reference instead of scanning the signer's objects.
+    fn with_capability(mut self, capability: impl Into<CapabilityRef>) -> Self {
+        self.set_capability(capability);
+        self
+    }
+}
+
+impl<T> WithCapability for TransactionBuilder<T>
+where
+    T: Transaction + WithCapability,
+{
+    fn set_capability(&mut self, capability: impl Into<CapabilityRef>) {
+        self.transaction_mut().set_capability(capability);
+    }
+}
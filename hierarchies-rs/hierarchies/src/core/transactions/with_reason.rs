@@ -0,0 +1,43 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Revocation Reasons
+//!
+//! Revocations can record why they were made with [`WithReason::with_reason`]. The reason is
+//! emitted as a [`RevocationReasonEvent`](crate::core::types::events::RevocationReasonEvent) next to
+//! the revocation event, see [`crate::core::types::revocation_reason`].
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! client
+//!     .revoke_accreditation_to_attest(federation_id, entity_id, accreditation_id)
+//!     .with_reason(RevocationReason::new(RevocationReason::MISCONDUCT, "forged lab reports"))
+//!     .build_and_execute(&client)
+//!     .await?;
+//! ```
+
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+
+use crate::core::types::revocation_reason::RevocationReason;
+
+/// A revocation that can record its reason.
+pub trait WithReason: Sized {
+    /// Sets the reason of this revocation, in place.
+    fn set_reason(&mut self, reason: RevocationReason);
+
+    /// Records `reason` along with the revocation.
+    fn with_reason(mut self, reason: RevocationReason) -> Self {
+        self.set_reason(reason);
+        self
+    }
+}
+
+impl<T> WithReason for TransactionBuilder<T>
+where
+    T: Transaction + WithReason,
+{
+    fn set_reason(&mut self, reason: RevocationReason) {
+        self.transaction_mut().set_reason(reason);
+    }
+}
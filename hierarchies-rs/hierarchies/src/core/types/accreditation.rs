@@ -1,16 +1,21 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::id::UID;
 use serde::{Deserialize, Serialize};
 
 use crate::core::types::property::FederationProperty;
 use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_shape::PropertyShape;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::timespan::Timespan;
 use crate::utils::deserialize_vec_map;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct Accreditations {
     pub accreditations: Vec<Accreditation>,
 }
@@ -28,15 +33,445 @@ impl Accreditations {
     pub fn is_empty(&self) -> bool {
         self.accreditations.is_empty()
     }
+
+    /// Returns true if the accreditations allow delegating `property` at `at_ms`.
+    ///
+    /// Mirrors the on-chain `is_property_compliant` check: every requested value must be allowed
+    /// by an active grant covering the property name, and a requested value dictionary must be
+    /// covered by one. See [`crate::core::semantics`] for the complete rules.
+    pub fn is_property_compliant(&self, property: &FederationProperty, at_ms: u64) -> bool {
+        let held: Vec<&FederationProperty> = self
+            .iter()
+            .filter(|accreditation| !accreditation.is_pending(at_ms))
+            .filter_map(|accreditation| accreditation.find_property(&property.name))
+            .collect();
+
+        let dictionary_covered = property.value_dictionary.as_ref().is_none_or(|dictionary| {
+            held.iter()
+                .any(|grant| grant.covers_value_dictionary(dictionary, at_ms))
+        });
+        dictionary_covered
+            && property
+                .allowed_values
+                .iter()
+                .all(|value| held.iter().any(|grant| grant.matches_value(value, at_ms)))
+    }
+
+    /// Returns true if the accreditations allow delegating all of `properties` at `at_ms`.
+    ///
+    /// Mirrors the on-chain `are_properties_compliant` check.
+    pub fn are_properties_compliant(&self, properties: &[FederationProperty], at_ms: u64) -> bool {
+        properties
+            .iter()
+            .all(|property| self.is_property_compliant(property, at_ms))
+    }
+
+    /// Merges the accreditations into the permissions that are effective at `at_ms`.
+    ///
+    /// Follows the on-chain semantics: a value is allowed for a property if any
+    /// accreditation holding the property allows it, the property's timespan
+    /// contains `at_ms` and the accreditation's objection window has ended. The permission of each property is the
+    /// union of these grants and stays the same for the intersection of their timespans, see
+    /// [`EffectivePermission::timespan`].
+    pub fn effective_permissions(&self, at_ms: u64) -> EffectivePermissions {
+        let mut properties: HashMap<PropertyName, EffectivePermission> = HashMap::new();
+        let mut windows: HashMap<&PropertyName, Timespan> = HashMap::new();
+
+        for accreditation in &self.accreditations {
+            for (name, property) in &accreditation.properties {
+                // A grant becomes effective at the later of its start and the end of the objection window
+                let timespan = Timespan {
+                    valid_from_ms: property.timespan.valid_from_ms.max(accreditation.pending_until_ms),
+                    valid_until_ms: property.timespan.valid_until_ms,
+                };
+
+                // Every bound of a grant is a point at which the merged permission may change
+                let window = windows.entry(name).or_default();
+                let bounds = [timespan.valid_from_ms, timespan.valid_until_ms];
+                for bound in bounds.into_iter().flatten() {
+                    if bound <= at_ms {
+                        window.valid_from_ms = window.valid_from_ms.max(Some(bound));
+                    } else {
+                        window.valid_until_ms = Some(window.valid_until_ms.map_or(bound, |until| until.min(bound)));
+                    }
+                }
+
+                if timespan.timestamp_matches(at_ms) {
+                    properties
+                        .entry(name.clone())
+                        .or_default()
+                        .merge(*accreditation.id.object_id(), property);
+                }
+            }
+        }
+
+        for (name, permission) in &mut properties {
+            permission.timespan = windows.remove(name).unwrap_or_default();
+        }
+
+        EffectivePermissions { at_ms, properties }
+    }
+}
+
+/// The permissions an entity effectively holds at a point in time,
+/// see [`Accreditations::effective_permissions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectivePermissions {
+    /// The time the permissions were computed for.
+    pub at_ms: u64,
+    /// The permission for every property with at least one active grant.
+    pub properties: HashMap<PropertyName, EffectivePermission>,
+}
+
+impl EffectivePermissions {
+    /// Returns the permission for `property_name`, if any grant is active.
+    ///
+    /// Without an active grant for the name itself, the permission of the most specific
+    /// wildcard covering it is returned, see [`PropertyName::covers`].
+    pub fn get(&self, property_name: &PropertyName) -> Option<&EffectivePermission> {
+        self.properties.get(property_name).or_else(|| {
+            self.properties
+                .iter()
+                .filter(|(name, _)| name.covers(property_name))
+                .max_by_key(|(name, _)| name.names().len())
+                .map(|(_, permission)| permission)
+        })
+    }
+
+    /// Returns true if any grant for `property_name` is active.
+    ///
+    /// Mirrors the on-chain `is_property_accredited` check.
+    pub fn is_accredited(&self, property_name: &PropertyName) -> bool {
+        self.get(property_name).is_some()
+    }
+
+    /// Returns true if `value` is allowed for `property_name`.
+    ///
+    /// Mirrors the on-chain `is_property_allowed` check. See [`EffectivePermission::allows`]
+    /// for properties referencing a value dictionary.
+    pub fn allows(&self, property_name: &PropertyName, value: &PropertyValue) -> bool {
+        self.get(property_name)
+            .is_some_and(|permission| permission.allows(value))
+    }
+}
+
+/// The union of all active grants for a single property.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectivePermission {
+    /// True if any grant allows any value.
+    pub allow_any: bool,
+    /// The values allowed by any grant.
+    pub allowed_values: HashSet<PropertyValue>,
+    /// The shapes of all grants, a value matching one of them is allowed.
+    pub shapes: HashSet<PropertyShape>,
+    /// The value dictionaries referenced by any grant.
+    pub value_dictionaries: HashSet<String>,
+    /// The accreditations contributing a grant.
+    pub accreditation_ids: Vec<ObjectID>,
+    /// The period around the evaluation time during which the permission does not change.
+    /// Unbounded sides mean no grant starts or expires in that direction.
+    pub timespan: Timespan,
+}
+
+impl EffectivePermission {
+    /// Returns true if `value` is allowed by any grant.
+    ///
+    /// Membership in a referenced value dictionary is checked by the federation, so
+    /// a permission referencing a dictionary allows every value here.
+    pub fn allows(&self, value: &PropertyValue) -> bool {
+        self.allow_any
+            || self.shapes.iter().any(|shape| shape.matches(value))
+            || self.allowed_values.contains(value)
+            || !self.value_dictionaries.is_empty()
+    }
+
+    fn merge(&mut self, accreditation_id: ObjectID, property: &FederationProperty) {
+        self.allow_any |= property.allow_any;
+        self.allowed_values.extend(property.allowed_values.iter().cloned());
+        self.shapes.extend(property.shape.clone());
+        self.value_dictionaries.extend(property.value_dictionary.clone());
+        self.accreditation_ids.push(accreditation_id);
+    }
+}
+
+/// Computes the grants missing from `current` to hold every property in `desired`.
+///
+/// A desired property is dropped if a grant for the same name covers its whole timespan and
+/// allows everything it allows. Otherwise it is returned with only the parts not yet covered:
+/// allowed values already allowed by a covering grant are removed, as are the shape and value
+/// dictionary if a covering grant holds the same. Requesting only the delta avoids overlapping
+/// grants that bloat the governance state.
+pub fn compute_delegation_delta(current: &Accreditations, desired: &[FederationProperty]) -> Vec<FederationProperty> {
+    desired
+        .iter()
+        .filter_map(|property| {
+            let covering: Vec<&FederationProperty> = current
+                .iter()
+                .filter_map(|accreditation| accreditation.properties.get(&property.name))
+                .filter(|held| covers_timespan(&held.timespan, &property.timespan))
+                .collect();
+            missing_part(property, &covering)
+        })
+        .collect()
+}
+
+/// Returns the part of `desired` not allowed by any of the `covering` grants.
+fn missing_part(desired: &FederationProperty, covering: &[&FederationProperty]) -> Option<FederationProperty> {
+    if covering.iter().any(|held| held.allow_any) {
+        return None;
+    }
+    if desired.allow_any {
+        return Some(desired.clone());
+    }
+
+    let grants_nothing =
+        desired.allowed_values.is_empty() && desired.shape.is_none() && desired.value_dictionary.is_none();
+    if grants_nothing {
+        return covering.is_empty().then(|| desired.clone());
+    }
+
+    let mut missing = desired.clone();
+    if missing.value_dictionary.as_ref().is_some_and(|dictionary| {
+        covering
+            .iter()
+            .any(|held| held.value_dictionary.as_ref() == Some(dictionary))
+    }) {
+        missing.value_dictionary = None;
+    }
+    if missing
+        .shape
+        .as_ref()
+        .is_some_and(|shape| covering.iter().any(|held| held.shape.as_ref() == Some(shape)))
+    {
+        missing.shape = None;
+    }
+    missing.allowed_values.retain(|value| {
+        !covering.iter().any(|held| {
+            held.allowed_values.contains(value) || held.shape.as_ref().is_some_and(|shape| shape.matches(value))
+        })
+    });
+
+    let covered = missing.allowed_values.is_empty() && missing.shape.is_none() && missing.value_dictionary.is_none();
+    (!covered).then_some(missing)
+}
+
+/// Returns true if `held` is valid during all of `desired`.
+fn covers_timespan(held: &Timespan, desired: &Timespan) -> bool {
+    let from_covered = match (held.valid_from_ms, desired.valid_from_ms) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(held), Some(desired)) => held <= desired,
+    };
+    let until_covered = match (held.valid_until_ms, desired.valid_until_ms) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(held), Some(desired)) => held >= desired,
+    };
+    from_covered && until_covered
 }
 
 /// Represents an accreditation that can be granted to an account. An accreditation
 /// consists of a set of properties that must be satisfied by the account in
 /// order to be granted the accreditation.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct Accreditation {
     pub id: UID,
     pub accredited_by: String,
     #[serde(deserialize_with = "deserialize_vec_map")]
     pub properties: HashMap<PropertyName, FederationProperty>,
+    /// The end of the objection window set by the grant policy. The accreditation is not effective before.
+    pub pending_until_ms: Option<u64>,
+}
+
+impl Accreditation {
+    /// Returns true if the objection window of the accreditation is still running at `at_ms`.
+    ///
+    /// Mirrors the on-chain `is_pending` check: the accreditation is effective from the end of the window.
+    pub fn is_pending(&self, at_ms: u64) -> bool {
+        self.pending_until_ms.is_some_and(|until| at_ms < until)
+    }
+
+    /// Returns the property covering `property_name`: the property of that name or, if there
+    /// is none, the most specific wildcard covering it.
+    ///
+    /// Mirrors the on-chain `find_property` lookup.
+    pub fn find_property(&self, property_name: &PropertyName) -> Option<&FederationProperty> {
+        self.properties.get(property_name).or_else(|| {
+            self.properties
+                .iter()
+                .filter(|(name, _)| name.covers(property_name))
+                .max_by_key(|(name, _)| name.names().len())
+                .map(|(_, property)| property)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accreditation(properties: impl IntoIterator<Item = FederationProperty>) -> Accreditation {
+        Accreditation {
+            id: UID::new(ObjectID::random()),
+            accredited_by: "root".to_string(),
+            properties: properties.into_iter().map(|p| (p.name.clone(), p)).collect(),
+            pending_until_ms: None,
+        }
+    }
+
+    fn timespan(valid_from_ms: Option<u64>, valid_until_ms: Option<u64>) -> Timespan {
+        Timespan {
+            valid_from_ms,
+            valid_until_ms,
+        }
+    }
+
+    #[test]
+    fn test_effective_permissions_union_active_grants() {
+        let role = PropertyName::from("role");
+        let admin = PropertyValue::Text("admin".to_string());
+        let user = PropertyValue::Text("user".to_string());
+        let guest = PropertyValue::Text("guest-1".to_string());
+
+        let accreditations = Accreditations::new(vec![
+            accreditation([FederationProperty::new(role.clone())
+                .with_allowed_values([admin.clone()])
+                .with_timespan(timespan(Some(100), Some(200)))]),
+            accreditation([FederationProperty::new(role.clone())
+                .with_allowed_values([user.clone()])
+                .with_timespan(timespan(None, Some(150)))]),
+            accreditation([FederationProperty::new(role.clone())
+                .with_expression(PropertyShape::StartsWith("guest".to_string()))
+                .with_timespan(timespan(Some(300), None))]),
+        ]);
+
+        let permissions = accreditations.effective_permissions(120);
+        let permission = permissions.get(&role).unwrap();
+        assert!(permissions.allows(&role, &admin));
+        assert!(permissions.allows(&role, &user));
+        assert!(!permissions.allows(&role, &guest));
+        assert_eq!(permission.accreditation_ids.len(), 2);
+        // Changes at 150 when the `user` grant expires
+        assert_eq!(permission.timespan, timespan(Some(100), Some(150)));
+
+        let permissions = accreditations.effective_permissions(200);
+        // Upper bounds are exclusive
+        assert!(!permissions.is_accredited(&role));
+
+        let permissions = accreditations.effective_permissions(300);
+        assert!(permissions.allows(&role, &guest));
+        assert!(!permissions.allows(&role, &admin));
+        assert_eq!(permissions.get(&role).unwrap().timespan, timespan(Some(300), None));
+    }
+
+    #[test]
+    fn test_effective_permissions_skip_pending_accreditations() {
+        let role = PropertyName::from("role");
+        let admin = PropertyValue::Text("admin".to_string());
+        let mut pending = accreditation([FederationProperty::new(role.clone()).with_allowed_values([admin.clone()])]);
+        pending.pending_until_ms = Some(500);
+        let accreditations = Accreditations::new(vec![pending]);
+
+        assert!(!accreditations.effective_permissions(100).is_accredited(&role));
+        let property = FederationProperty::new(role.clone()).with_allowed_values([admin.clone()]);
+        assert!(!accreditations.is_property_compliant(&property, 100));
+
+        let permissions = accreditations.effective_permissions(500);
+        assert!(permissions.allows(&role, &admin));
+        assert_eq!(permissions.get(&role).unwrap().timespan, timespan(Some(500), None));
+    }
+
+    #[test]
+    fn test_effective_permissions_require_exact_names() {
+        let accreditations = Accreditations::new(vec![accreditation([FederationProperty::new(PropertyName::new([
+            "a", "b",
+        ]))
+        .with_allow_any(true)])]);
+
+        let permissions = accreditations.effective_permissions(0);
+        let value = PropertyValue::Number(1);
+        assert!(permissions.allows(&PropertyName::new(["a", "b"]), &value));
+        assert!(!permissions.allows(&PropertyName::new(["a", "b", "c"]), &value));
+        assert!(!permissions.allows(&PropertyName::from("a"), &value));
+    }
+
+    #[test]
+    fn test_compute_delegation_delta() {
+        let role = PropertyName::from("role");
+        let level = PropertyName::from("level");
+        let region = PropertyName::from("region");
+        let admin = PropertyValue::Text("admin".to_string());
+        let user = PropertyValue::Text("user".to_string());
+        let guest = PropertyValue::Text("guest-1".to_string());
+
+        let current = Accreditations::new(vec![
+            accreditation([
+                FederationProperty::new(role.clone())
+                    .with_allowed_values([admin.clone()])
+                    .with_expression(PropertyShape::StartsWith("guest".to_string())),
+                FederationProperty::new(region.clone()).with_allow_any(true),
+            ]),
+            accreditation([FederationProperty::new(level.clone())
+                .with_allow_any(true)
+                .with_timespan(timespan(None, Some(100)))]),
+        ]);
+
+        let desired = [
+            FederationProperty::new(role.clone()).with_allowed_values([admin, user.clone(), guest]),
+            FederationProperty::new(region).with_allowed_values([PropertyValue::Number(1)]),
+            FederationProperty::new(level.clone()).with_allowed_values([PropertyValue::Number(1)]),
+        ];
+
+        let delta = compute_delegation_delta(&current, &desired);
+        assert_eq!(
+            delta,
+            vec![
+                FederationProperty::new(role).with_allowed_values([user]),
+                // The held grant expires before the desired one
+                FederationProperty::new(level).with_allowed_values([PropertyValue::Number(1)]),
+            ]
+        );
+
+        assert_eq!(
+            compute_delegation_delta(&Accreditations::new(vec![]), &desired),
+            desired
+        );
+    }
+
+    #[test]
+    fn test_wildcard_grants_cover_namespace() {
+        let bachelor = PropertyName::new(["degree", "bachelor"]);
+        let one = PropertyValue::Number(1);
+        let two = PropertyValue::Number(2);
+
+        let grant = accreditation([
+            FederationProperty::new_prefix("degree").with_allowed_values([one.clone()]),
+            FederationProperty::new_prefix(bachelor.clone()).with_allowed_values([two.clone()]),
+        ]);
+        // The most specific wildcard is used
+        let held = grant
+            .find_property(&PropertyName::new(["degree", "bachelor", "cs"]))
+            .unwrap();
+        assert_eq!(held.name, PropertyName::wildcard(bachelor.clone()));
+        assert_eq!(
+            grant
+                .find_property(&PropertyName::new(["degree", "master"]))
+                .unwrap()
+                .name,
+            PropertyName::wildcard("degree")
+        );
+        assert!(grant.find_property(&bachelor).is_some());
+        assert!(grant.find_property(&PropertyName::from("degree")).is_none());
+
+        let accreditations = Accreditations::new(vec![grant]);
+        let requested = FederationProperty::new(PropertyName::new(["degree", "master"])).with_allowed_values([one]);
+        assert!(accreditations.is_property_compliant(&requested, 0));
+        assert!(!accreditations.is_property_compliant(&requested.with_allowed_values([two.clone()]), 0));
+
+        let permissions = accreditations.effective_permissions(0);
+        assert!(permissions.is_accredited(&PropertyName::new(["degree", "master"])));
+        assert!(permissions.allows(&PropertyName::new(["degree", "bachelor", "cs"]), &two));
+        assert!(!permissions.is_accredited(&PropertyName::from("role")));
+    }
 }
@@ -0,0 +1,441 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies Accreditation Query
+//!
+//! This module provides a builder for filtering accreditations by entity, scope, validity, property name and tag.
+//!
+//! The same [`AccreditationQuery`] can be evaluated against a locally held [`Federation`] with
+//! [`AccreditationQuery::evaluate`], or against the on-chain views through
+//! `HierarchiesClientReadOnly::query_accreditations`.
+//!
+//! Entities holding many accreditations can be read page by page with an [`AccreditationFilter`], see
+//! `HierarchiesClientReadOnly::get_accreditations_to_attest_paged`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use iota_interaction::types::base_types::ObjectID;
+use serde::{Deserialize, Serialize};
+
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::{Accreditation, Accreditations, Federation};
+
+/// The kind of permission an accreditation grants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AccreditationScope {
+    /// The accreditation allows creating attestations.
+    Attest,
+    /// The accreditation allows delegating accreditations to other entities.
+    Accredit,
+}
+
+/// An accreditation returned by an [`AccreditationQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccreditationMatch {
+    /// The entity holding the accreditation.
+    pub entity_id: ObjectID,
+    /// The scope the accreditation was granted for.
+    pub scope: AccreditationScope,
+    /// The accreditation, reduced to the properties matching the query.
+    pub accreditation: Accreditation,
+}
+
+/// A page of accreditations returned by the paged on-chain views.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct AccreditationPage {
+    /// The accreditations of the page, in the order they were granted.
+    pub accreditations: Vec<Accreditation>,
+    /// The cursor to request the next page with, `None` if this is the last page.
+    pub next_cursor: Option<u64>,
+}
+
+/// Filters applied on-chain by the paged accreditation views.
+///
+/// Unlike an [`AccreditationQuery`], the filters only select accreditations; the returned accreditations hold all
+/// of their properties.
+///
+/// ```
+/// use hierarchies::core::types::accreditation_query::AccreditationFilter;
+/// use iota_interaction::types::base_types::ObjectID;
+///
+/// let filter = AccreditationFilter::new()
+///     .property_name("role")
+///     .accredited_by(ObjectID::ZERO)
+///     .active_only();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccreditationFilter {
+    property_name: Option<PropertyName>,
+    accredited_by: Option<ObjectID>,
+    active_only: bool,
+}
+
+impl AccreditationFilter {
+    /// Creates a filter matching every accreditation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches accreditations covering the property `name`, e.g. through a wildcard name.
+    pub fn property_name(mut self, name: impl Into<PropertyName>) -> Self {
+        self.property_name = Some(name.into());
+        self
+    }
+
+    /// Only matches accreditations granted by `accreditor`.
+    pub fn accredited_by(mut self, accreditor: ObjectID) -> Self {
+        self.accredited_by = Some(accreditor);
+        self
+    }
+
+    /// Only matches accreditations whose objection window has ended and that hold a property valid at the time
+    /// of the query. With a property name filter, that property must be valid.
+    pub fn active_only(mut self) -> Self {
+        self.active_only = true;
+        self
+    }
+
+    /// Returns the property name filter, if any.
+    pub fn property_name_filter(&self) -> Option<&PropertyName> {
+        self.property_name.as_ref()
+    }
+
+    /// Returns the accreditor filter, if any.
+    pub fn accredited_by_filter(&self) -> Option<ObjectID> {
+        self.accredited_by
+    }
+
+    /// Returns true if only active accreditations are matched.
+    pub fn is_active_only(&self) -> bool {
+        self.active_only
+    }
+
+    /// Returns true if `accreditation` passes the filter at `now_ms`.
+    ///
+    /// Mirrors the on-chain filter of the paged views.
+    pub fn matches(&self, accreditation: &Accreditation, now_ms: u64) -> bool {
+        let accredited_by_matches = self.accredited_by.is_none_or(|accreditor| {
+            ObjectID::from_str(&accreditation.accredited_by).is_ok_and(|issuer| issuer == accreditor)
+        });
+        let property = self
+            .property_name
+            .as_ref()
+            .map(|name| accreditation.find_property(name));
+        if !accredited_by_matches || property.is_some_and(|property| property.is_none()) {
+            return false;
+        }
+        if !self.active_only {
+            return true;
+        }
+
+        !accreditation.is_pending(now_ms)
+            && match property.flatten() {
+                Some(property) => property.timespan.timestamp_matches(now_ms),
+                None => accreditation
+                    .properties
+                    .values()
+                    .any(|property| property.timespan.timestamp_matches(now_ms)),
+            }
+    }
+}
+
+/// A builder for filtering accreditations.
+///
+/// Filters that are not set do not restrict the result. An accreditation matches if at least one of its
+/// properties passes the property filters; the returned accreditation only contains those properties.
+///
+/// ```
+/// use hierarchies::core::types::accreditation_query::{AccreditationQuery, AccreditationScope};
+/// use iota_interaction::types::base_types::ObjectID;
+///
+/// let query = AccreditationQuery::new()
+///     .for_entity(ObjectID::ZERO)
+///     .scope(AccreditationScope::Attest)
+///     .active_at(1_700_000_000_000)
+///     .property_prefix("iso");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccreditationQuery {
+    entity_id: Option<ObjectID>,
+    scope: Option<AccreditationScope>,
+    active_at_ms: Option<u64>,
+    pending_at_ms: Option<u64>,
+    property_prefix: Option<PropertyName>,
+    tag: Option<String>,
+}
+
+impl AccreditationQuery {
+    /// Creates a query matching every accreditation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches accreditations held by `entity_id`.
+    pub fn for_entity(mut self, entity_id: ObjectID) -> Self {
+        self.entity_id = Some(entity_id);
+        self
+    }
+
+    /// Only matches accreditations granted for `scope`.
+    pub fn scope(mut self, scope: AccreditationScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Only matches properties whose timespan is valid at `timestamp_ms`, of accreditations whose objection
+    /// window has ended by then.
+    pub fn active_at(mut self, timestamp_ms: u64) -> Self {
+        self.active_at_ms = Some(timestamp_ms);
+        self
+    }
+
+    /// Only matches accreditations whose objection window is still running at `timestamp_ms`.
+    pub fn pending_at(mut self, timestamp_ms: u64) -> Self {
+        self.pending_at_ms = Some(timestamp_ms);
+        self
+    }
+
+    /// Only matches properties whose name starts with the segments of `prefix`, and wildcards covering
+    /// `prefix`.
+    pub fn property_prefix(mut self, prefix: impl Into<PropertyName>) -> Self {
+        self.property_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Only matches accreditations labelled with `tag`.
+    ///
+    /// Tags are kept by the federation, so this filter is only applied by [`AccreditationQuery::evaluate`].
+    pub fn tagged(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    /// Returns the entity filter, if any.
+    pub fn entity_id(&self) -> Option<ObjectID> {
+        self.entity_id
+    }
+
+    /// Returns the scope filter, if any.
+    pub fn scope_filter(&self) -> Option<AccreditationScope> {
+        self.scope
+    }
+
+    /// Returns the tag filter, if any.
+    pub fn tag_filter(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// Evaluates the query against a locally held federation.
+    pub fn evaluate(&self, federation: &Federation) -> Vec<AccreditationMatch> {
+        let governance = &federation.governance;
+        let scopes = [
+            (AccreditationScope::Attest, &governance.accreditations_to_attest),
+            (AccreditationScope::Accredit, &governance.accreditations_to_accredit),
+        ];
+
+        scopes
+            .into_iter()
+            .filter(|(scope, _)| self.scope.is_none_or(|wanted| wanted == *scope))
+            .flat_map(|(scope, by_entity)| {
+                by_entity
+                    .iter()
+                    .filter(|(entity_id, _)| self.entity_id.is_none_or(|wanted| wanted == **entity_id))
+                    .flat_map(move |(entity_id, accreditations)| self.filter(*entity_id, scope, accreditations))
+            })
+            .filter(|found| {
+                self.tag.as_ref().is_none_or(|tag| {
+                    governance
+                        .accreditation_tags
+                        .get(found.accreditation.id.object_id())
+                        .is_some_and(|tags| tags.contains(tag))
+                })
+            })
+            .collect()
+    }
+
+    /// Filters the accreditations `entity_id` holds for `scope`, e.g. as read page by page through
+    /// `get_accreditations_to_attest_paged` or `get_accreditations_to_accredit_paged`.
+    ///
+    /// The entity and scope filters of the query are not applied here, as they are implied by the source of
+    /// `accreditations`. The tag filter is not applied either, as tags are kept by the federation.
+    pub fn filter(
+        &self,
+        entity_id: ObjectID,
+        scope: AccreditationScope,
+        accreditations: &Accreditations,
+    ) -> Vec<AccreditationMatch> {
+        accreditations
+            .iter()
+            .filter(|accreditation| {
+                self.active_at_ms.is_none_or(|now_ms| !accreditation.is_pending(now_ms))
+                    && self.pending_at_ms.is_none_or(|now_ms| accreditation.is_pending(now_ms))
+            })
+            .filter_map(|accreditation| {
+                let properties: HashMap<_, _> = accreditation
+                    .properties
+                    .iter()
+                    .filter(|(name, property)| {
+                        self.property_prefix
+                            .as_ref()
+                            .is_none_or(|prefix| name.starts_with(prefix) || name.covers(prefix))
+                            && self
+                                .active_at_ms
+                                .is_none_or(|now_ms| property.timespan.timestamp_matches(now_ms))
+                    })
+                    .map(|(name, property)| (name.clone(), property.clone()))
+                    .collect();
+
+                (!properties.is_empty()).then(|| AccreditationMatch {
+                    entity_id,
+                    scope,
+                    accreditation: Accreditation {
+                        properties,
+                        ..accreditation.clone()
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::id::UID;
+
+    use super::*;
+    use crate::core::types::property::FederationProperty;
+    use crate::core::types::timespan::Timespan;
+
+    fn accreditation(properties: impl IntoIterator<Item = FederationProperty>) -> Accreditation {
+        Accreditation {
+            id: UID::new(ObjectID::random()),
+            accredited_by: "root".to_string(),
+            properties: properties.into_iter().map(|p| (p.name.clone(), p)).collect(),
+            pending_until_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_by_prefix_and_validity() {
+        let expired = FederationProperty::new(PropertyName::new(["iso", "9001"])).with_timespan(Timespan {
+            valid_from_ms: None,
+            valid_until_ms: Some(100),
+        });
+        let active = FederationProperty::new(PropertyName::new(["iso", "27001"]));
+        let other = FederationProperty::new("role");
+        let accreditations = Accreditations::new(vec![
+            accreditation([expired, active.clone(), other]),
+            accreditation([FederationProperty::new("role")]),
+        ]);
+
+        let matches = AccreditationQuery::new().property_prefix("iso").active_at(100).filter(
+            ObjectID::ZERO,
+            AccreditationScope::Attest,
+            &accreditations,
+        );
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].accreditation.properties,
+            HashMap::from([(active.name.clone(), active)])
+        );
+    }
+
+    #[test]
+    fn test_filter_by_prefix_covered_by_wildcard() {
+        let degrees = FederationProperty::new_prefix("degree");
+        let accreditations = Accreditations::new(vec![
+            accreditation([degrees.clone()]),
+            accreditation([FederationProperty::new("role")]),
+        ]);
+
+        let matches = AccreditationQuery::new()
+            .property_prefix(PropertyName::new(["degree", "bachelor"]))
+            .active_at(100)
+            .filter(ObjectID::ZERO, AccreditationScope::Attest, &accreditations);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].accreditation.properties,
+            HashMap::from([(degrees.name.clone(), degrees)])
+        );
+    }
+
+    #[test]
+    fn test_accreditation_filter() {
+        let accreditor = ObjectID::random();
+        let mut granted = accreditation([
+            FederationProperty::new("role"),
+            FederationProperty::new("degree").with_timespan(Timespan {
+                valid_from_ms: None,
+                valid_until_ms: Some(100),
+            }),
+        ]);
+        granted.accredited_by = accreditor.to_string();
+        granted.pending_until_ms = Some(50);
+
+        assert!(AccreditationFilter::new().matches(&granted, 0));
+        assert!(
+            AccreditationFilter::new()
+                .accredited_by(accreditor)
+                .matches(&granted, 0)
+        );
+        assert!(
+            !AccreditationFilter::new()
+                .accredited_by(ObjectID::ZERO)
+                .matches(&granted, 0)
+        );
+        assert!(!AccreditationFilter::new().property_name("name").matches(&granted, 0));
+
+        let active_degree = AccreditationFilter::new().property_name("degree").active_only();
+        assert!(!active_degree.matches(&granted, 10));
+        assert!(active_degree.matches(&granted, 60));
+        assert!(!active_degree.matches(&granted, 100));
+        assert!(AccreditationFilter::new().active_only().matches(&granted, 100));
+    }
+
+    #[test]
+    fn test_accreditation_filter_covers_wildcards() {
+        let degrees = accreditation([FederationProperty::new_prefix("degree")]);
+        let bachelor = PropertyName::new(["degree", "bachelor"]);
+
+        assert!(
+            AccreditationFilter::new()
+                .property_name(bachelor.clone())
+                .matches(&degrees, 0)
+        );
+        assert!(
+            AccreditationFilter::new()
+                .property_name(bachelor)
+                .active_only()
+                .matches(&degrees, 0)
+        );
+        assert!(!AccreditationFilter::new().property_name("role").matches(&degrees, 0));
+    }
+
+    #[test]
+    fn test_filter_by_objection_window() {
+        let mut pending = accreditation([FederationProperty::new("role")]);
+        pending.pending_until_ms = Some(500);
+        let effective = accreditation([FederationProperty::new("role")]);
+        let accreditations = Accreditations::new(vec![pending.clone(), effective.clone()]);
+
+        let filter = |query: AccreditationQuery| {
+            query
+                .filter(ObjectID::ZERO, AccreditationScope::Attest, &accreditations)
+                .into_iter()
+                .map(|found| found.accreditation.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            filter(AccreditationQuery::new().active_at(100)),
+            vec![effective.id.clone()]
+        );
+        assert_eq!(filter(AccreditationQuery::new().pending_at(100)), vec![pending.id]);
+        assert_eq!(filter(AccreditationQuery::new().pending_at(500)), vec![]);
+        assert_eq!(filter(AccreditationQuery::new().active_at(500)).len(), 2);
+    }
+}
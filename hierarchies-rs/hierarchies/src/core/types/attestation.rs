@@ -0,0 +1,91 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Attestations
+//!
+//! An [`Attestation`] is an on-chain credential: properties of a receiver, attested by an attester
+//! of a federation. It is owned by the receiver and can't be transferred.
+//!
+//! Issuing an attestation requires the same accreditation to attest as
+//! [`validate_properties`](crate::client::HierarchiesClientReadOnly::validate_properties). The federation
+//! records its issuer, so the issuer and root authorities can revoke it later on. An attestation is valid
+//! while it is not revoked, within its validity window and its issuer is still accredited for all of its
+//! properties.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use iota_interaction::MoveType;
+use iota_interaction::types::base_types::{ObjectID, TypeTag};
+use iota_interaction::types::id::UID;
+use serde::{Deserialize, Serialize};
+
+use super::move_names;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::timespan::Timespan;
+use crate::utils::deserialize_vec_map;
+
+pub(crate) const ATTESTATION_TYPE: &str = "Attestation";
+
+/// Properties of a receiver, attested by an attester of a federation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct Attestation {
+    pub id: UID,
+    pub federation_id: ObjectID,
+    /// The attester that issued the attestation
+    pub issuer: ObjectID,
+    #[serde(deserialize_with = "deserialize_vec_map")]
+    pub properties: HashMap<PropertyName, PropertyValue>,
+    /// The validity window of the attestation
+    pub timespan: Timespan,
+    pub issued_at_ms: u64,
+}
+
+impl Attestation {
+    /// Returns the object ID of the attestation.
+    pub fn id(&self) -> ObjectID {
+        *self.id.object_id()
+    }
+
+    /// Returns true if `now_ms` falls within the validity window of the attestation.
+    ///
+    /// Mirrors the on-chain `is_valid_at_time` check. Revocation and the accreditation of the
+    /// issuer are only checked by
+    /// [`validate_attestation`](crate::client::HierarchiesClientReadOnly::validate_attestation).
+    pub fn is_valid_at(&self, now_ms: u64) -> bool {
+        self.timespan.timestamp_matches(now_ms)
+    }
+}
+
+impl MoveType for Attestation {
+    fn move_type(package: ObjectID) -> TypeTag {
+        TypeTag::from_str(format!("{package}::{}::{}", move_names::MODULE_ATTESTATION, ATTESTATION_TYPE).as_str())
+            .expect("Failed to create type tag")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validity_window() {
+        let attestation = Attestation {
+            id: UID::new(ObjectID::random()),
+            federation_id: ObjectID::random(),
+            issuer: ObjectID::random(),
+            properties: HashMap::new(),
+            timespan: Timespan {
+                valid_from_ms: Some(1000),
+                valid_until_ms: Some(2000),
+            },
+            issued_at_ms: 500,
+        };
+
+        assert!(!attestation.is_valid_at(999));
+        assert!(attestation.is_valid_at(1000));
+        assert!(!attestation.is_valid_at(2000));
+    }
+}
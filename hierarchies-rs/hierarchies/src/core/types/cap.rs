@@ -9,7 +9,7 @@
 use std::str::FromStr;
 
 use iota_interaction::MoveType;
-use iota_interaction::types::base_types::{ObjectID, TypeTag};
+use iota_interaction::types::base_types::{ObjectID, ObjectRef, TypeTag};
 use iota_interaction::types::id::UID;
 use serde::{Deserialize, Serialize};
 
@@ -18,11 +18,33 @@ use super::move_names;
 pub(crate) const ROOT_AUTHORITY_CAP_TYPE: &str = "RootAuthorityCap";
 pub(crate) const ACCREDIT_CAP_TYPE: &str = "AccreditCap";
 
+/// The kinds of capabilities a federation hands out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CapabilityKind {
+    /// [`RootAuthorityCap`]
+    RootAuthority,
+    /// [`AccreditCap`]
+    Accredit,
+}
+
+/// A capability owned by an address, as listed by
+/// [`HierarchiesClientReadOnly::get_owned_capabilities`](crate::client::HierarchiesClientReadOnly::get_owned_capabilities).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OwnedCapability {
+    /// The federation the capability belongs to
+    pub federation_id: ObjectID,
+    /// The kind of the capability
+    pub kind: CapabilityKind,
+    /// The reference of the capability object
+    pub object_ref: ObjectRef,
+}
+
 /// Capability for root authority operations.
 ///
 /// This capability grants full administrative access to a federation,
 /// including the ability to add/remove other root authorities and manage properties.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct RootAuthorityCap {
     pub id: UID,
     pub federation_id: ObjectID,
@@ -41,6 +63,7 @@ impl MoveType for RootAuthorityCap {
 /// This capability grants the ability to delegate accreditation and attestation rights
 /// to other accounts within a federation.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct AccreditCap {
     pub id: UID,
     pub federation_id: ObjectID,
@@ -0,0 +1,124 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Credential Anchoring
+//!
+//! Anchors externally issued credentials, such as a PDF diploma or a JWT VC, in a federation.
+//!
+//! The credential itself stays off-chain. Its [`CredentialHash`] is granted as an allowed value of a
+//! dedicated property to the attester that issued it, so a verifier holding the credential can
+//! recompute the hash and check that the issuer is accredited to attest exactly this credential.
+
+use std::fmt;
+use std::str::FromStr;
+
+use sha3::{Digest, Sha3_256};
+
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_shape::PropertyShape;
+use crate::core::types::property_value::PropertyValue;
+
+/// The prefix of anchored credential hashes, naming the hash function.
+pub const CREDENTIAL_HASH_PREFIX: &str = "sha3-256:";
+
+/// The SHA3-256 hash of a credential.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CredentialHash([u8; 32]);
+
+impl CredentialHash {
+    /// Hashes the raw bytes of `credential`, e.g. the file of a PDF or the compact form of a JWT.
+    pub fn of(credential: impl AsRef<[u8]>) -> Self {
+        Self(Sha3_256::digest(credential.as_ref()).into())
+    }
+
+    /// Returns the hash bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the property value the hash is anchored as.
+    pub fn to_property_value(&self) -> PropertyValue {
+        PropertyValue::Text(self.to_string())
+    }
+
+    /// Returns the hash anchored as `value`, if it is a credential hash.
+    pub fn from_property_value(value: &PropertyValue) -> Option<Self> {
+        match value {
+            PropertyValue::Text(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if `credential` hashes to this hash.
+    pub fn matches(&self, credential: impl AsRef<[u8]>) -> bool {
+        Self::of(credential) == *self
+    }
+}
+
+impl fmt::Display for CredentialHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(CREDENTIAL_HASH_PREFIX)?;
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl FromStr for CredentialHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix(CREDENTIAL_HASH_PREFIX)
+            .ok_or_else(|| format!("credential hash must start with `{CREDENTIAL_HASH_PREFIX}`"))?;
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err("credential hash must have 64 hex digits".to_string());
+        }
+
+        let mut bytes = [0; 32];
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).expect("ascii");
+            *byte = u8::from_str_radix(digits, 16).map_err(|e| format!("invalid hex digits `{digits}`: {e}"))?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+/// Returns the definition of a property dedicated to anchored credentials.
+///
+/// Add it to the federation once; its shape admits any credential hash, so accreditors can
+/// grant the hashes of individual credentials to their issuers.
+pub fn anchor_property(name: impl Into<PropertyName>) -> FederationProperty {
+    FederationProperty::new(name).with_expression(PropertyShape::StartsWith(CREDENTIAL_HASH_PREFIX.to_string()))
+}
+
+/// Returns the grant anchoring `hash` under the property `name`.
+pub fn anchored_credential(name: impl Into<PropertyName>, hash: CredentialHash) -> FederationProperty {
+    FederationProperty::new(name).with_allowed_values([hash.to_property_value()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credential_hash_round_trip() {
+        let credential = b"eyJhbGciOiJFZERTQSJ9.eyJzdWIiOiJkaWQ6aW90YTp4In0.c2ln";
+        let hash = CredentialHash::of(credential);
+
+        let value = hash.to_property_value();
+        assert_eq!(CredentialHash::from_property_value(&value), Some(hash));
+        assert!(hash.matches(credential));
+        assert!(!hash.matches(b"tampered"));
+
+        let property = anchor_property("credential.diploma");
+        assert!(property.shape.as_ref().unwrap().matches(&value));
+
+        assert!("sha3-256:zz".parse::<CredentialHash>().is_err());
+        assert!(
+            hash.to_string()[CREDENTIAL_HASH_PREFIX.len()..]
+                .parse::<CredentialHash>()
+                .is_err()
+        );
+        assert_eq!(CredentialHash::from_property_value(&PropertyValue::Number(1)), None);
+    }
+}
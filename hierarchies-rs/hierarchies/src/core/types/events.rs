@@ -4,36 +4,237 @@
 //! # Hierarchies Events
 //!
 //! This module provides event types for the Hierarchies (IOTA Trust Hierarchy) module.
+//!
+//! ## Wire format
+//!
+//! Events exported to long-lived consumers (queues, archives, other services) should be
+//! wrapped in a [`VersionedEvent`], which serializes to JSON as the event's fields plus a
+//! `"version"` tag:
+//!
+//! ```json
+//! { "version": "3", "federation_address": "0x…", "property_name": { "names": ["kyc"] }, "valid_to_ms": 0, "critical": true }
+//! ```
+//!
+//! All versions decode into the same event types. A version only adds fields, and every added
+//! field is marked `#[serde(default)]`, so consumers built against this version keep decoding
+//! events written by older producers. Fields are never removed or renamed. Adding a field to an
+//! exported event, or to a type nested in one, means adding a row below and bumping
+//! [`VersionedEvent::CURRENT_VERSION`]; the tests decode a payload of the previous version.
+//! Versions newer than the SDK are rejected.
+//!
+//! | Version | Changes                                                                                 |
+//! |---------|-----------------------------------------------------------------------------------------|
+//! | `1`     | Initial format                                                                          |
+//! | `2`     | Adds `critical` to [`PropertyRevokedEvent`]                                             |
+//! | `3`     | Adds `value_dictionary` and `metadata` to properties, `objection_window_ms` to policies |
+//!
+//! Unknown fields in JSON payloads are ignored by default. With the `strict-decode` feature
+//! enabled they are rejected instead, surfacing schema drift between the SDK and the deployed
+//! Move package. The feature has no effect on BCS content: BCS carries no field names, so a
+//! payload whose layout differs from the SDK's fails to decode whether the feature is enabled
+//! or not.
+//!
+//! ## Decoding transaction events
+//!
+//! [`decode_all`] decodes the BCS content of the events emitted by a transaction into
+//! [`HierarchiesEvent`]s, so consumers don't have to match on Move struct names themselves:
+//!
+//! ```ignore
+//! let events = response.events.expect("events were requested");
+//! for event in decode_all(&events, &client.package_history())? {
+//!     if let HierarchiesEvent::FederationCreated(created) = event {
+//!         println!("created federation {}", created.federation_address);
+//!     }
+//! }
+//! ```
 
-use iota_interaction::types::base_types::ObjectID;
+use std::collections::HashSet;
+
+use iota_interaction::rpc_types::{IotaEvent, IotaTransactionBlockEvents};
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::core::transactions::TransactionError;
+use crate::core::types::grant_policy::GrantPolicy;
+use crate::core::types::property::{FederationProperty, PropertyMetadata};
 use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::quorum::GovernanceAction;
+use crate::core::types::{FederationMetadata, move_names};
+use crate::utils::deserialize_vec_set;
+
+/// Versioned wire format of an exported event.
+///
+/// The version records the [wire format](self#wire-format) the producer wrote, every version
+/// decodes into the same event type. Only self-describing formats such as JSON are supported,
+/// as the version is stored as an internal `"version"` tag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VersionedEvent<E>(WireVersion<E>);
+
+/// The `"version"` tag of a [`VersionedEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "version")]
+enum WireVersion<E> {
+    #[serde(rename = "1")]
+    V1(E),
+    #[serde(rename = "2")]
+    V2(E),
+    #[serde(rename = "3")]
+    V3(E),
+}
+
+impl<E> VersionedEvent<E> {
+    /// The wire format version written by [`VersionedEvent::new`].
+    pub const CURRENT_VERSION: u32 = 3;
+
+    /// Wraps `event` in the current wire format version.
+    pub fn new(event: E) -> Self {
+        Self(WireVersion::V3(event))
+    }
+
+    /// Returns the wire format version the event was encoded with.
+    pub fn version(&self) -> u32 {
+        match self.0 {
+            WireVersion::V1(_) => 1,
+            WireVersion::V2(_) => 2,
+            WireVersion::V3(_) => 3,
+        }
+    }
+
+    /// Returns the wrapped event.
+    pub fn event(&self) -> &E {
+        match &self.0 {
+            WireVersion::V1(event) | WireVersion::V2(event) | WireVersion::V3(event) => event,
+        }
+    }
+
+    /// Returns the wrapped event.
+    pub fn into_inner(self) -> E {
+        match self.0 {
+            WireVersion::V1(event) | WireVersion::V2(event) | WireVersion::V3(event) => event,
+        }
+    }
+}
 
 /// Event emitted when a new federation is created
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct FederationCreatedEvent {
     pub federation_address: ObjectID,
 }
 
+/// Event emitted when the metadata of a federation is set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct FederationMetadataSetEvent {
+    pub federation_address: ObjectID,
+    pub metadata: FederationMetadata,
+}
+
+/// Event emitted when a child federation is recognized by a federation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct FederationLinkedEvent {
+    pub federation_address: ObjectID,
+    pub child_federation_id: ObjectID,
+}
+
+/// Event emitted when a child federation is no longer recognized by a federation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct FederationUnlinkedEvent {
+    pub federation_address: ObjectID,
+    pub child_federation_id: ObjectID,
+}
+
+/// Event emitted when the grant policy of a federation is changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct GrantPolicySetEvent {
+    pub federation_address: ObjectID,
+    pub policy: GrantPolicy,
+}
+
 /// Event emitted when a property is added to the federation
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct PropertyAddedEvent {
     pub federation_address: ObjectID,
-    pub property_name: PropertyName,
-    pub allow_any: bool,
+    pub property: FederationProperty,
 }
 
 /// Event emitted when a property is revoked
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct PropertyRevokedEvent {
     pub federation_address: ObjectID,
     pub property_name: PropertyName,
     pub valid_to_ms: u64,
+    /// Whether the revoked property is marked as critical
+    #[serde(default)]
+    pub critical: bool,
+}
+
+/// Event emitted when the metadata of a property is set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct PropertyMetadataSetEvent {
+    pub federation_address: ObjectID,
+    pub property_name: PropertyName,
+    pub metadata: PropertyMetadata,
+}
+
+/// Event emitted when the tags of a property are set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct PropertyTagsSetEvent {
+    pub federation_address: ObjectID,
+    pub property_name: PropertyName,
+    #[serde(deserialize_with = "deserialize_vec_set")]
+    pub tags: HashSet<String>,
+}
+
+/// Event emitted when a property is marked or unmarked as critical
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct PropertyCriticalityChangedEvent {
+    pub federation_address: ObjectID,
+    pub property_name: PropertyName,
+    pub critical: bool,
+}
+
+/// Event emitted when a property is deprecated
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct PropertyDeprecatedEvent {
+    pub federation_address: ObjectID,
+    pub property_name: PropertyName,
+    pub replacement: Option<PropertyName>,
+}
+
+/// Event emitted when a value dictionary is created or replaced
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct ValueDictionarySetEvent {
+    pub federation_address: ObjectID,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_vec_set")]
+    pub values: HashSet<PropertyValue>,
+}
+
+/// Event emitted when a value dictionary is removed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct ValueDictionaryRemovedEvent {
+    pub federation_address: ObjectID,
+    pub name: String,
 }
 
 /// Event emitted when a root authority is added
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct RootAuthorityAddedEvent {
     pub federation_address: ObjectID,
     pub account_id: ObjectID,
@@ -41,6 +242,7 @@ pub struct RootAuthorityAddedEvent {
 
 /// Event emitted when a root authority is revoked
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct RootAuthorityRevokedEvent {
     pub federation_address: ObjectID,
     pub account_id: ObjectID,
@@ -48,6 +250,7 @@ pub struct RootAuthorityRevokedEvent {
 
 /// Event emitted when a root authority is reinstated
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct RootAuthorityReinstatedEvent {
     pub federation_address: ObjectID,
     pub account_id: ObjectID,
@@ -56,6 +259,7 @@ pub struct RootAuthorityReinstatedEvent {
 
 /// Event emitted when accreditation to accredit is created
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct AccreditationToAccreditCreatedEvent {
     pub federation_address: ObjectID,
     pub receiver: ObjectID,
@@ -64,6 +268,7 @@ pub struct AccreditationToAccreditCreatedEvent {
 
 /// Event emitted when accreditation to attest is created
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct AccreditationToAttestCreatedEvent {
     pub federation_address: ObjectID,
     pub receiver: ObjectID,
@@ -72,6 +277,7 @@ pub struct AccreditationToAttestCreatedEvent {
 
 /// Event emitted when accreditation to attest is revoked
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct AccreditationToAttestRevokedEvent {
     pub federation_address: ObjectID,
     pub entity_id: ObjectID,
@@ -81,9 +287,799 @@ pub struct AccreditationToAttestRevokedEvent {
 
 /// Event emitted when accreditation to accredit is revoked
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct AccreditationToAccreditRevokedEvent {
     pub federation_address: ObjectID,
     pub entity_id: ObjectID,
     pub permission_id: ObjectID,
     pub revoker: ObjectID,
 }
+
+/// Event emitted when the objection window of an accreditation is ended early
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct AccreditationActivatedEvent {
+    pub federation_address: ObjectID,
+    pub accreditation_id: ObjectID,
+    pub activated_by: ObjectID,
+}
+
+/// Event emitted when the tags of an accreditation are set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct AccreditationTagsSetEvent {
+    pub federation_address: ObjectID,
+    pub accreditation_id: ObjectID,
+    #[serde(deserialize_with = "deserialize_vec_set")]
+    pub tags: HashSet<String>,
+}
+
+/// Event emitted when the quorum threshold of a federation is set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct QuorumThresholdSetEvent {
+    pub federation_address: ObjectID,
+    pub threshold: u64,
+}
+
+/// Event emitted when a governance action is proposed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct ActionProposedEvent {
+    pub federation_address: ObjectID,
+    pub proposal_id: u64,
+    pub action: GovernanceAction,
+    pub proposed_by: ObjectID,
+}
+
+/// Event emitted when a governance proposal is approved by a root authority
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct ActionApprovedEvent {
+    pub federation_address: ObjectID,
+    pub proposal_id: u64,
+    pub approved_by: ObjectID,
+}
+
+/// Event emitted when a governance proposal is executed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct ActionExecutedEvent {
+    pub federation_address: ObjectID,
+    pub proposal_id: u64,
+    pub action: GovernanceAction,
+    pub executed_by: ObjectID,
+}
+
+/// Event emitted when a root authority confirms the transfer of a capability
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct CapabilityTransferConfirmedEvent {
+    pub federation_address: ObjectID,
+    pub capability_id: ObjectID,
+    pub recipient: IotaAddress,
+    pub confirmed_by: ObjectID,
+}
+
+/// Event emitted when a capability is transferred to a new address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct CapabilityTransferredEvent {
+    pub federation_address: ObjectID,
+    pub capability_id: ObjectID,
+    pub from: IotaAddress,
+    pub to: IotaAddress,
+}
+
+/// Event emitted along with a revocation to record why it was made
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct RevocationReasonEvent {
+    pub federation_address: ObjectID,
+    pub reason_code: u64,
+    pub annotation: String,
+    pub revoked_by: ObjectID,
+}
+
+/// Event emitted when an attestation is issued
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct AttestationCreatedEvent {
+    pub federation_address: ObjectID,
+    pub attestation_id: ObjectID,
+    pub issuer: ObjectID,
+    pub receiver: IotaAddress,
+}
+
+/// Event emitted when an attestation is revoked
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct AttestationRevokedEvent {
+    pub federation_address: ObjectID,
+    pub attestation_id: ObjectID,
+    pub revoker: ObjectID,
+}
+
+/// A Hierarchies event decoded from its on-chain representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HierarchiesEvent {
+    FederationCreated(FederationCreatedEvent),
+    FederationMetadataSet(FederationMetadataSetEvent),
+    FederationLinked(FederationLinkedEvent),
+    FederationUnlinked(FederationUnlinkedEvent),
+    GrantPolicySet(GrantPolicySetEvent),
+    PropertyAdded(PropertyAddedEvent),
+    PropertyRevoked(PropertyRevokedEvent),
+    PropertyCriticalityChanged(PropertyCriticalityChangedEvent),
+    PropertyDeprecated(PropertyDeprecatedEvent),
+    PropertyMetadataSet(PropertyMetadataSetEvent),
+    PropertyTagsSet(PropertyTagsSetEvent),
+    ValueDictionarySet(ValueDictionarySetEvent),
+    ValueDictionaryRemoved(ValueDictionaryRemovedEvent),
+    RootAuthorityAdded(RootAuthorityAddedEvent),
+    RootAuthorityRevoked(RootAuthorityRevokedEvent),
+    RootAuthorityReinstated(RootAuthorityReinstatedEvent),
+    AccreditationToAccreditCreated(AccreditationToAccreditCreatedEvent),
+    AccreditationToAttestCreated(AccreditationToAttestCreatedEvent),
+    AccreditationToAttestRevoked(AccreditationToAttestRevokedEvent),
+    AccreditationToAccreditRevoked(AccreditationToAccreditRevokedEvent),
+    AccreditationActivated(AccreditationActivatedEvent),
+    AccreditationTagsSet(AccreditationTagsSetEvent),
+    QuorumThresholdSet(QuorumThresholdSetEvent),
+    ActionProposed(ActionProposedEvent),
+    ActionApproved(ActionApprovedEvent),
+    ActionExecuted(ActionExecutedEvent),
+    CapabilityTransferConfirmed(CapabilityTransferConfirmedEvent),
+    CapabilityTransferred(CapabilityTransferredEvent),
+    RevocationReason(RevocationReasonEvent),
+    AttestationCreated(AttestationCreatedEvent),
+    AttestationRevoked(AttestationRevokedEvent),
+}
+
+impl HierarchiesEvent {
+    /// Decodes the JSON payload of the Move event struct named `struct_name`.
+    ///
+    /// Returns `Ok(None)` for events without a typed representation.
+    pub fn from_parsed_json(struct_name: &str, json: serde_json::Value) -> Result<Option<Self>, serde_json::Error> {
+        Self::decode(struct_name, json)
+    }
+
+    /// Decodes the BCS content of the Move event struct named `struct_name`.
+    ///
+    /// Unlike the JSON payload, the BCS content keeps the exact Move layout, e.g. of `u64`
+    /// fields and enums. Returns `Ok(None)` for events without a typed representation.
+    pub fn from_bcs(struct_name: &str, bytes: &[u8]) -> Result<Option<Self>, bcs::Error> {
+        Self::decode(struct_name, bytes)
+    }
+
+    /// Decodes `event` if it was emitted by the Hierarchies module.
+    ///
+    /// `package_history` holds the IDs of all versions of the Hierarchies package, see
+    /// [`CoreClientReadOnly::package_history`](product_common::core_client::CoreClientReadOnly::package_history).
+    /// Returns `Ok(None)` for events of other packages or modules, even if their struct is named
+    /// like a Hierarchies event, and for events without a typed representation.
+    pub fn from_iota_event(event: &IotaEvent, package_history: &[ObjectID]) -> Result<Option<Self>, bcs::Error> {
+        if event.type_.module.as_str() != move_names::MODULE_MAIN
+            || !package_history.contains(&ObjectID::from(event.type_.address))
+        {
+            return Ok(None);
+        }
+        Self::from_bcs(event.type_.name.as_str(), event.bcs.bytes())
+    }
+
+    fn decode<P: EventPayload>(struct_name: &str, payload: P) -> Result<Option<Self>, P::Error> {
+        let event = match struct_name {
+            "FederationCreatedEvent" => Self::FederationCreated(payload.decode()?),
+            "FederationMetadataSetEvent" => Self::FederationMetadataSet(payload.decode()?),
+            "FederationLinkedEvent" => Self::FederationLinked(payload.decode()?),
+            "FederationUnlinkedEvent" => Self::FederationUnlinked(payload.decode()?),
+            "GrantPolicySetEvent" => Self::GrantPolicySet(payload.decode()?),
+            "PropertyAddedEvent" => Self::PropertyAdded(payload.decode()?),
+            "PropertyRevokedEvent" => Self::PropertyRevoked(payload.decode()?),
+            "PropertyCriticalityChangedEvent" => Self::PropertyCriticalityChanged(payload.decode()?),
+            "PropertyDeprecatedEvent" => Self::PropertyDeprecated(payload.decode()?),
+            "PropertyMetadataSetEvent" => Self::PropertyMetadataSet(payload.decode()?),
+            "PropertyTagsSetEvent" => Self::PropertyTagsSet(payload.decode()?),
+            "ValueDictionarySetEvent" => Self::ValueDictionarySet(payload.decode()?),
+            "ValueDictionaryRemovedEvent" => Self::ValueDictionaryRemoved(payload.decode()?),
+            "RootAuthorityAddedEvent" => Self::RootAuthorityAdded(payload.decode()?),
+            "RootAuthorityRevokedEvent" => Self::RootAuthorityRevoked(payload.decode()?),
+            "RootAuthorityReinstatedEvent" => Self::RootAuthorityReinstated(payload.decode()?),
+            "AccreditationToAccreditCreatedEvent" => Self::AccreditationToAccreditCreated(payload.decode()?),
+            "AccreditationToAttestCreatedEvent" => Self::AccreditationToAttestCreated(payload.decode()?),
+            "AccreditationToAttestRevokedEvent" => Self::AccreditationToAttestRevoked(payload.decode()?),
+            "AccreditationToAccreditRevokedEvent" => Self::AccreditationToAccreditRevoked(payload.decode()?),
+            "AccreditationActivatedEvent" => Self::AccreditationActivated(payload.decode()?),
+            "AccreditationTagsSetEvent" => Self::AccreditationTagsSet(payload.decode()?),
+            "QuorumThresholdSetEvent" => Self::QuorumThresholdSet(payload.decode()?),
+            "ActionProposedEvent" => Self::ActionProposed(payload.decode()?),
+            "ActionApprovedEvent" => Self::ActionApproved(payload.decode()?),
+            "ActionExecutedEvent" => Self::ActionExecuted(payload.decode()?),
+            "CapabilityTransferConfirmedEvent" => Self::CapabilityTransferConfirmed(payload.decode()?),
+            "CapabilityTransferredEvent" => Self::CapabilityTransferred(payload.decode()?),
+            "RevocationReasonEvent" => Self::RevocationReason(payload.decode()?),
+            "AttestationCreatedEvent" => Self::AttestationCreated(payload.decode()?),
+            "AttestationRevokedEvent" => Self::AttestationRevoked(payload.decode()?),
+            _ => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+
+    /// Returns a short human-readable description of the change.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::FederationCreated(_) => "created the federation".to_string(),
+            Self::FederationMetadataSet(_) => "set the federation metadata".to_string(),
+            Self::FederationLinked(event) => {
+                format!("recognized child federation {}", event.child_federation_id)
+            }
+            Self::FederationUnlinked(event) => {
+                format!("stopped recognizing child federation {}", event.child_federation_id)
+            }
+            Self::GrantPolicySet(_) => "set the grant policy".to_string(),
+            Self::PropertyAdded(event) => format!("added property {}", dotted(&event.property.name)),
+            Self::PropertyRevoked(event) => format!(
+                "revoked property {} at {}",
+                dotted(&event.property_name),
+                event.valid_to_ms
+            ),
+            Self::PropertyCriticalityChanged(event) if event.critical => {
+                format!("marked property {} as critical", dotted(&event.property_name))
+            }
+            Self::PropertyCriticalityChanged(event) => {
+                format!("unmarked property {} as critical", dotted(&event.property_name))
+            }
+            Self::PropertyDeprecated(event) => match &event.replacement {
+                Some(replacement) => format!(
+                    "deprecated property {} in favour of {}",
+                    dotted(&event.property_name),
+                    dotted(replacement)
+                ),
+                None => format!("deprecated property {}", dotted(&event.property_name)),
+            },
+            Self::PropertyMetadataSet(event) => {
+                format!("set the metadata of property {}", dotted(&event.property_name))
+            }
+            Self::PropertyTagsSet(event) => {
+                format!("set the tags of property {}", dotted(&event.property_name))
+            }
+            Self::ValueDictionarySet(event) => format!("set value dictionary {}", event.name),
+            Self::ValueDictionaryRemoved(event) => format!("removed value dictionary {}", event.name),
+            Self::RootAuthorityAdded(event) => format!("added root authority {}", event.account_id),
+            Self::RootAuthorityRevoked(event) => format!("revoked root authority {}", event.account_id),
+            Self::RootAuthorityReinstated(event) => {
+                format!("reinstated root authority {}", event.account_id)
+            }
+            Self::AccreditationToAccreditCreated(event) => {
+                format!("granted {} an accreditation to accredit", event.receiver)
+            }
+            Self::AccreditationToAttestCreated(event) => {
+                format!("granted {} an accreditation to attest", event.receiver)
+            }
+            Self::AccreditationToAttestRevoked(event) => format!(
+                "revoked accreditation to attest {} of {}",
+                event.permission_id, event.entity_id
+            ),
+            Self::AccreditationToAccreditRevoked(event) => format!(
+                "revoked accreditation to accredit {} of {}",
+                event.permission_id, event.entity_id
+            ),
+            Self::AccreditationActivated(event) => {
+                format!("activated accreditation {}", event.accreditation_id)
+            }
+            Self::AccreditationTagsSet(event) => {
+                format!("set the tags of accreditation {}", event.accreditation_id)
+            }
+            Self::QuorumThresholdSet(event) => format!("set the quorum threshold to {}", event.threshold),
+            Self::ActionProposed(event) => format!("proposed action #{}", event.proposal_id),
+            Self::ActionApproved(event) => format!("approved action #{}", event.proposal_id),
+            Self::ActionExecuted(event) => format!("executed action #{}", event.proposal_id),
+            Self::CapabilityTransferConfirmed(event) => format!(
+                "confirmed the transfer of capability {} to {}",
+                event.capability_id, event.recipient
+            ),
+            Self::CapabilityTransferred(event) => format!(
+                "transferred capability {} from {} to {}",
+                event.capability_id, event.from, event.to
+            ),
+            Self::RevocationReason(event) if event.annotation.is_empty() => {
+                format!("gave reason {} for the revocation", event.reason_code)
+            }
+            Self::RevocationReason(event) => format!(
+                "gave reason {} for the revocation: {}",
+                event.reason_code, event.annotation
+            ),
+            Self::AttestationCreated(event) => {
+                format!("issued attestation {} to {}", event.attestation_id, event.receiver)
+            }
+            Self::AttestationRevoked(event) => format!("revoked attestation {}", event.attestation_id),
+        }
+    }
+
+    /// Returns the federation the event belongs to.
+    pub fn federation_address(&self) -> ObjectID {
+        match self {
+            Self::FederationCreated(event) => event.federation_address,
+            Self::FederationMetadataSet(event) => event.federation_address,
+            Self::FederationLinked(event) => event.federation_address,
+            Self::FederationUnlinked(event) => event.federation_address,
+            Self::GrantPolicySet(event) => event.federation_address,
+            Self::PropertyAdded(event) => event.federation_address,
+            Self::PropertyRevoked(event) => event.federation_address,
+            Self::PropertyCriticalityChanged(event) => event.federation_address,
+            Self::PropertyDeprecated(event) => event.federation_address,
+            Self::PropertyMetadataSet(event) => event.federation_address,
+            Self::PropertyTagsSet(event) => event.federation_address,
+            Self::ValueDictionarySet(event) => event.federation_address,
+            Self::ValueDictionaryRemoved(event) => event.federation_address,
+            Self::RootAuthorityAdded(event) => event.federation_address,
+            Self::RootAuthorityRevoked(event) => event.federation_address,
+            Self::RootAuthorityReinstated(event) => event.federation_address,
+            Self::AccreditationToAccreditCreated(event) => event.federation_address,
+            Self::AccreditationToAttestCreated(event) => event.federation_address,
+            Self::AccreditationToAttestRevoked(event) => event.federation_address,
+            Self::AccreditationToAccreditRevoked(event) => event.federation_address,
+            Self::AccreditationActivated(event) => event.federation_address,
+            Self::AccreditationTagsSet(event) => event.federation_address,
+            Self::QuorumThresholdSet(event) => event.federation_address,
+            Self::ActionProposed(event) => event.federation_address,
+            Self::ActionApproved(event) => event.federation_address,
+            Self::ActionExecuted(event) => event.federation_address,
+            Self::CapabilityTransferConfirmed(event) => event.federation_address,
+            Self::CapabilityTransferred(event) => event.federation_address,
+            Self::RevocationReason(event) => event.federation_address,
+            Self::AttestationCreated(event) => event.federation_address,
+            Self::AttestationRevoked(event) => event.federation_address,
+        }
+    }
+}
+
+fn dotted(property_name: &PropertyName) -> String {
+    property_name.names().join(".")
+}
+
+/// Decodes the Hierarchies events emitted by a transaction, in emission order.
+///
+/// Events of other packages or modules and events without a typed representation are skipped,
+/// see [`HierarchiesEvent::from_iota_event`].
+///
+/// # Errors
+///
+/// Fails with [`TransactionError::EventProcessingFailed`] if the content of a Hierarchies
+/// event doesn't match its expected layout.
+pub fn decode_all(
+    events: &IotaTransactionBlockEvents,
+    package_history: &[ObjectID],
+) -> Result<Vec<HierarchiesEvent>, TransactionError> {
+    events
+        .data
+        .iter()
+        .filter_map(|event| {
+            HierarchiesEvent::from_iota_event(event, package_history)
+                .map_err(|_| TransactionError::EventProcessingFailed {
+                    event_type: event.type_.name.to_string(),
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// An encoded event payload.
+trait EventPayload {
+    type Error;
+
+    fn decode<T: DeserializeOwned>(self) -> Result<T, Self::Error>;
+}
+
+impl EventPayload for serde_json::Value {
+    type Error = serde_json::Error;
+
+    fn decode<T: DeserializeOwned>(self) -> Result<T, Self::Error> {
+        serde_json::from_value(self)
+    }
+}
+
+impl EventPayload for &[u8] {
+    type Error = bcs::Error;
+
+    fn decode<T: DeserializeOwned>(self) -> Result<T, Self::Error> {
+        bcs::from_bytes(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::ident_str;
+    use iota_interaction::rpc_types::BcsEvent;
+    use serde_json::json;
+
+    use super::*;
+    use crate::core::types::property_shape::PropertyShape;
+
+    #[test]
+    fn test_versioned_event_roundtrip() {
+        let event = PropertyRevokedEvent {
+            federation_address: ObjectID::ZERO,
+            property_name: PropertyName::from("kyc"),
+            valid_to_ms: 10,
+            critical: true,
+        };
+        let versioned = VersionedEvent::new(event.clone());
+
+        let json = serde_json::to_value(&versioned).unwrap();
+        assert_eq!(json["version"], "3");
+        assert_eq!(json["critical"], true);
+
+        let decoded: VersionedEvent<PropertyRevokedEvent> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.version(), 3);
+        assert_eq!(decoded.into_inner(), event);
+    }
+
+    #[test]
+    fn test_every_event_roundtrips_through_json() {
+        fn roundtrip<E>(event: &E)
+        where
+            E: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug + Clone,
+        {
+            let json = serde_json::to_value(VersionedEvent::new(event.clone())).unwrap();
+            let decoded: VersionedEvent<E> = serde_json::from_value(json).unwrap();
+            assert_eq!(decoded.version(), VersionedEvent::<E>::CURRENT_VERSION);
+            assert_eq!(decoded.event(), event);
+        }
+
+        for event in sample_events() {
+            // Exhaustive, so new events have to be added to `sample_events`
+            match &event {
+                HierarchiesEvent::FederationCreated(e) => roundtrip(e),
+                HierarchiesEvent::FederationMetadataSet(e) => roundtrip(e),
+                HierarchiesEvent::FederationLinked(e) => roundtrip(e),
+                HierarchiesEvent::FederationUnlinked(e) => roundtrip(e),
+                HierarchiesEvent::GrantPolicySet(e) => roundtrip(e),
+                HierarchiesEvent::PropertyAdded(e) => roundtrip(e),
+                HierarchiesEvent::PropertyRevoked(e) => roundtrip(e),
+                HierarchiesEvent::PropertyCriticalityChanged(e) => roundtrip(e),
+                HierarchiesEvent::PropertyDeprecated(e) => roundtrip(e),
+                HierarchiesEvent::PropertyMetadataSet(e) => roundtrip(e),
+                HierarchiesEvent::PropertyTagsSet(e) => roundtrip(e),
+                HierarchiesEvent::ValueDictionarySet(e) => roundtrip(e),
+                HierarchiesEvent::ValueDictionaryRemoved(e) => roundtrip(e),
+                HierarchiesEvent::RootAuthorityAdded(e) => roundtrip(e),
+                HierarchiesEvent::RootAuthorityRevoked(e) => roundtrip(e),
+                HierarchiesEvent::RootAuthorityReinstated(e) => roundtrip(e),
+                HierarchiesEvent::AccreditationToAccreditCreated(e) => roundtrip(e),
+                HierarchiesEvent::AccreditationToAttestCreated(e) => roundtrip(e),
+                HierarchiesEvent::AccreditationToAttestRevoked(e) => roundtrip(e),
+                HierarchiesEvent::AccreditationToAccreditRevoked(e) => roundtrip(e),
+                HierarchiesEvent::AccreditationActivated(e) => roundtrip(e),
+                HierarchiesEvent::AccreditationTagsSet(e) => roundtrip(e),
+                HierarchiesEvent::QuorumThresholdSet(e) => roundtrip(e),
+                HierarchiesEvent::ActionProposed(e) => roundtrip(e),
+                HierarchiesEvent::ActionApproved(e) => roundtrip(e),
+                HierarchiesEvent::ActionExecuted(e) => roundtrip(e),
+                HierarchiesEvent::CapabilityTransferConfirmed(e) => roundtrip(e),
+                HierarchiesEvent::CapabilityTransferred(e) => roundtrip(e),
+                HierarchiesEvent::RevocationReason(e) => roundtrip(e),
+                HierarchiesEvent::AttestationCreated(e) => roundtrip(e),
+                HierarchiesEvent::AttestationRevoked(e) => roundtrip(e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_version_is_rejected() {
+        let json = json!({
+            "version": "99",
+            "federation_address": ObjectID::ZERO,
+        });
+
+        assert!(serde_json::from_value::<VersionedEvent<FederationCreatedEvent>>(json).is_err());
+    }
+
+    /// Returns an event of every kind, with sets and maps filled in.
+    fn sample_events() -> Vec<HierarchiesEvent> {
+        let federation_address = ObjectID::random();
+        let [account, other] = [ObjectID::random(), ObjectID::random()];
+        let address = IotaAddress::from(ObjectID::random());
+        let property_name = PropertyName::new(["degree", "level"]);
+        let tags = HashSet::from(["identity".to_string(), "education".to_string()]);
+        let property = FederationProperty::new(property_name.clone())
+            .with_allowed_values([PropertyValue::Text("bachelor".to_string()), PropertyValue::Number(3)])
+            .with_expression(PropertyShape::StartsWith("b".to_string()));
+        let action = GovernanceAction::RevokeProperty {
+            property_name: property_name.clone(),
+            valid_to_ms: Some(10),
+        };
+
+        vec![
+            HierarchiesEvent::FederationCreated(FederationCreatedEvent { federation_address }),
+            HierarchiesEvent::FederationMetadataSet(FederationMetadataSetEvent {
+                federation_address,
+                metadata: FederationMetadata {
+                    name: Some("Universities".to_string()),
+                    description: None,
+                    logo_uri: None,
+                    contact: Some("mailto:ops@example.org".to_string()),
+                },
+            }),
+            HierarchiesEvent::FederationLinked(FederationLinkedEvent {
+                federation_address,
+                child_federation_id: other,
+            }),
+            HierarchiesEvent::FederationUnlinked(FederationUnlinkedEvent {
+                federation_address,
+                child_federation_id: other,
+            }),
+            HierarchiesEvent::GrantPolicySet(GrantPolicySetEvent {
+                federation_address,
+                policy: GrantPolicy::default().with_max_allowed_values(4),
+            }),
+            HierarchiesEvent::PropertyAdded(PropertyAddedEvent {
+                federation_address,
+                property,
+            }),
+            HierarchiesEvent::PropertyRevoked(PropertyRevokedEvent {
+                federation_address,
+                property_name: property_name.clone(),
+                valid_to_ms: 10,
+                critical: true,
+            }),
+            HierarchiesEvent::PropertyCriticalityChanged(PropertyCriticalityChangedEvent {
+                federation_address,
+                property_name: property_name.clone(),
+                critical: true,
+            }),
+            HierarchiesEvent::PropertyDeprecated(PropertyDeprecatedEvent {
+                federation_address,
+                property_name: property_name.clone(),
+                replacement: Some(PropertyName::from("level")),
+            }),
+            HierarchiesEvent::PropertyMetadataSet(PropertyMetadataSetEvent {
+                federation_address,
+                property_name: property_name.clone(),
+                metadata: PropertyMetadata::default()
+                    .with_display_name("Degree level")
+                    .with_unit("level"),
+            }),
+            HierarchiesEvent::PropertyTagsSet(PropertyTagsSetEvent {
+                federation_address,
+                property_name: property_name.clone(),
+                tags: tags.clone(),
+            }),
+            HierarchiesEvent::ValueDictionarySet(ValueDictionarySetEvent {
+                federation_address,
+                name: "levels".to_string(),
+                values: HashSet::from([PropertyValue::Number(1), PropertyValue::Bool(true)]),
+            }),
+            HierarchiesEvent::ValueDictionaryRemoved(ValueDictionaryRemovedEvent {
+                federation_address,
+                name: "levels".to_string(),
+            }),
+            HierarchiesEvent::RootAuthorityAdded(RootAuthorityAddedEvent {
+                federation_address,
+                account_id: account,
+            }),
+            HierarchiesEvent::RootAuthorityRevoked(RootAuthorityRevokedEvent {
+                federation_address,
+                account_id: account,
+            }),
+            HierarchiesEvent::RootAuthorityReinstated(RootAuthorityReinstatedEvent {
+                federation_address,
+                account_id: account,
+                reinstated_by: other,
+            }),
+            HierarchiesEvent::AccreditationToAccreditCreated(AccreditationToAccreditCreatedEvent {
+                federation_address,
+                receiver: account,
+                accreditor: other,
+            }),
+            HierarchiesEvent::AccreditationToAttestCreated(AccreditationToAttestCreatedEvent {
+                federation_address,
+                receiver: account,
+                accreditor: other,
+            }),
+            HierarchiesEvent::AccreditationToAttestRevoked(AccreditationToAttestRevokedEvent {
+                federation_address,
+                entity_id: account,
+                permission_id: ObjectID::random(),
+                revoker: other,
+            }),
+            HierarchiesEvent::AccreditationToAccreditRevoked(AccreditationToAccreditRevokedEvent {
+                federation_address,
+                entity_id: account,
+                permission_id: ObjectID::random(),
+                revoker: other,
+            }),
+            HierarchiesEvent::AccreditationActivated(AccreditationActivatedEvent {
+                federation_address,
+                accreditation_id: ObjectID::random(),
+                activated_by: other,
+            }),
+            HierarchiesEvent::AccreditationTagsSet(AccreditationTagsSetEvent {
+                federation_address,
+                accreditation_id: ObjectID::random(),
+                tags,
+            }),
+            HierarchiesEvent::QuorumThresholdSet(QuorumThresholdSetEvent {
+                federation_address,
+                threshold: 2,
+            }),
+            HierarchiesEvent::ActionProposed(ActionProposedEvent {
+                federation_address,
+                proposal_id: 7,
+                action: action.clone(),
+                proposed_by: account,
+            }),
+            HierarchiesEvent::ActionApproved(ActionApprovedEvent {
+                federation_address,
+                proposal_id: 7,
+                approved_by: other,
+            }),
+            HierarchiesEvent::ActionExecuted(ActionExecutedEvent {
+                federation_address,
+                proposal_id: 7,
+                action,
+                executed_by: other,
+            }),
+            HierarchiesEvent::CapabilityTransferConfirmed(CapabilityTransferConfirmedEvent {
+                federation_address,
+                capability_id: ObjectID::random(),
+                recipient: address,
+                confirmed_by: other,
+            }),
+            HierarchiesEvent::CapabilityTransferred(CapabilityTransferredEvent {
+                federation_address,
+                capability_id: ObjectID::random(),
+                from: IotaAddress::ZERO,
+                to: address,
+            }),
+            HierarchiesEvent::RevocationReason(RevocationReasonEvent {
+                federation_address,
+                reason_code: 3,
+                annotation: "key compromised".to_string(),
+                revoked_by: other,
+            }),
+            HierarchiesEvent::AttestationCreated(AttestationCreatedEvent {
+                federation_address,
+                attestation_id: ObjectID::random(),
+                issuer: account,
+                receiver: address,
+            }),
+            HierarchiesEvent::AttestationRevoked(AttestationRevokedEvent {
+                federation_address,
+                attestation_id: ObjectID::random(),
+                revoker: account,
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_decode_hierarchies_event() {
+        let json = json!({
+            "federation_address": ObjectID::ZERO,
+            "account_id": ObjectID::ZERO,
+        });
+
+        let event = HierarchiesEvent::from_parsed_json("RootAuthorityAddedEvent", json.clone())
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, HierarchiesEvent::RootAuthorityAdded(_)));
+        assert_eq!(event.federation_address(), ObjectID::ZERO);
+
+        assert_eq!(
+            HierarchiesEvent::from_parsed_json("UnknownEvent", json.clone()).unwrap(),
+            None
+        );
+        assert!(HierarchiesEvent::from_parsed_json("PropertyAddedEvent", json).is_err());
+    }
+
+    #[test]
+    fn test_decode_hierarchies_event_from_bcs() {
+        let proposed = ActionProposedEvent {
+            federation_address: ObjectID::ZERO,
+            proposal_id: 7,
+            action: GovernanceAction::RevokeProperty {
+                property_name: PropertyName::from("kyc"),
+                valid_to_ms: Some(10),
+            },
+            proposed_by: ObjectID::ZERO,
+        };
+        let bytes = bcs::to_bytes(&proposed).unwrap();
+
+        let event = HierarchiesEvent::from_bcs("ActionProposedEvent", &bytes)
+            .unwrap()
+            .unwrap();
+        assert_eq!(event, HierarchiesEvent::ActionProposed(proposed));
+
+        let tags = PropertyTagsSetEvent {
+            federation_address: ObjectID::ZERO,
+            property_name: PropertyName::from("kyc"),
+            tags: HashSet::from(["identity".to_string()]),
+        };
+        let bytes = bcs::to_bytes(&tags).unwrap();
+        let event = HierarchiesEvent::from_bcs("PropertyTagsSetEvent", &bytes)
+            .unwrap()
+            .unwrap();
+        assert_eq!(event, HierarchiesEvent::PropertyTagsSet(tags));
+
+        assert_eq!(HierarchiesEvent::from_bcs("UnknownEvent", &bytes).unwrap(), None);
+        assert!(HierarchiesEvent::from_bcs("ActionProposedEvent", &bytes[..4]).is_err());
+    }
+
+    #[test]
+    fn test_events_of_other_packages_are_skipped() {
+        let hierarchies_package = ObjectID::random();
+        let mut event = IotaEvent::random_for_testing();
+        event.type_.module = ident_str!(move_names::MODULE_MAIN).to_owned();
+        event.type_.name = ident_str!("PropertyAddedEvent").to_owned();
+        // A foreign payload that doesn't decode as a `PropertyAddedEvent`
+        event.bcs = BcsEvent::new(vec![0xff]);
+
+        event.type_.address = ObjectID::random().into();
+        assert_eq!(
+            HierarchiesEvent::from_iota_event(&event, &[hierarchies_package]).unwrap(),
+            None
+        );
+        let events = IotaTransactionBlockEvents {
+            data: vec![event.clone()],
+        };
+        assert!(decode_all(&events, &[hierarchies_package]).unwrap().is_empty());
+
+        event.type_.address = hierarchies_package.into();
+        assert!(HierarchiesEvent::from_iota_event(&event, &[hierarchies_package]).is_err());
+    }
+
+    #[test]
+    fn test_decode_previous_version() {
+        let json = json!({
+            "version": "1",
+            "federation_address": ObjectID::ZERO,
+            "property_name": { "names": ["kyc"] },
+            "valid_to_ms": 10,
+        });
+
+        let decoded: VersionedEvent<PropertyRevokedEvent> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.version(), 1);
+        assert!(!decoded.into_inner().critical);
+    }
+
+    #[test]
+    fn test_decode_version_2_property_added() {
+        let json = json!({
+            "version": "2",
+            "federation_address": ObjectID::ZERO,
+            "property": {
+                "name": { "names": ["degree"] },
+                "allowed_values": [{ "Text": "bachelor" }],
+                "shape": null,
+                "allow_any": false,
+                "timespan": { "valid_from_ms": null, "valid_until_ms": null },
+            },
+        });
+
+        let decoded: VersionedEvent<PropertyAddedEvent> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.version(), 2);
+        let property = decoded.into_inner().property;
+        assert_eq!(
+            property,
+            FederationProperty::new("degree").with_allowed_values([PropertyValue::Text("bachelor".to_string())])
+        );
+        assert_eq!(property.value_dictionary, None);
+        assert!(property.metadata.entries().is_empty());
+    }
+
+    #[cfg(feature = "strict-decode")]
+    #[test]
+    fn test_strict_decode_rejects_unknown_fields() {
+        let json = json!({
+            "version": "2",
+            "federation_address": ObjectID::ZERO,
+            "property_name": { "names": ["kyc"] },
+            "valid_to_ms": 10,
+            "critical": true,
+            "reason": "unexpected",
+        });
+
+        let err = serde_json::from_value::<VersionedEvent<PropertyRevokedEvent>>(json).unwrap_err();
+        assert!(err.to_string().contains("unknown field `reason`"));
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Federation Sets
+//!
+//! Checks an entity against several federations at once, e.g. an importer that must be
+//! compliant with both an EU and an FDA federation.
+//!
+//! A [`FederationSet`] lists the properties to validate in each federation. It is validated with
+//! [`validate_federation_set`](crate::client::HierarchiesClientReadOnly::validate_federation_set) in
+//! a single read-only transaction, which returns a [`FederationSetValidation`] with the result of
+//! every federation.
+//!
+//! ```rust,ignore
+//! let set = FederationSet::new()
+//!     .with(eu_federation, [("import.ce".into(), PropertyValue::Bool(true))])
+//!     .with(fda_federation, [("import.fda".into(), PropertyValue::Bool(true))]);
+//!
+//! let validation = client.validate_federation_set(&set, importer).await?;
+//! assert!(validation.is_valid_in_all());
+//! ```
+
+use std::collections::HashMap;
+
+use iota_interaction::types::base_types::ObjectID;
+
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+
+/// The properties to validate in each of several federations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FederationSet {
+    federations: Vec<(ObjectID, HashMap<PropertyName, PropertyValue>)>,
+}
+
+impl FederationSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `properties` to validate in `federation_id`.
+    ///
+    /// Properties of a federation that is already part of the set are merged into its entry.
+    pub fn with(
+        mut self,
+        federation_id: ObjectID,
+        properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
+    ) -> Self {
+        match self.federations.iter_mut().find(|(id, _)| *id == federation_id) {
+            Some((_, existing)) => existing.extend(properties),
+            None => self.federations.push((federation_id, properties.into_iter().collect())),
+        }
+        self
+    }
+
+    /// Returns the federations of the set in the order they were added.
+    pub fn federation_ids(&self) -> impl Iterator<Item = ObjectID> + '_ {
+        self.federations.iter().map(|(id, _)| *id)
+    }
+
+    /// Returns the properties to validate in `federation_id`.
+    pub fn properties(&self, federation_id: &ObjectID) -> Option<&HashMap<PropertyName, PropertyValue>> {
+        self.federations
+            .iter()
+            .find(|(id, _)| id == federation_id)
+            .map(|(_, properties)| properties)
+    }
+
+    pub fn len(&self) -> usize {
+        self.federations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.federations.is_empty()
+    }
+
+    /// Returns the checks to pass to
+    /// [`validate_properties_multi`](crate::client::HierarchiesClientReadOnly::validate_properties_multi).
+    pub fn into_checks(self) -> Vec<(ObjectID, HashMap<PropertyName, PropertyValue>)> {
+        self.federations
+    }
+}
+
+/// The results of validating an entity against a [`FederationSet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationSetValidation {
+    /// The result of every federation, in the order of the set
+    pub results: Vec<(ObjectID, bool)>,
+}
+
+impl FederationSetValidation {
+    /// Returns true if the entity is valid in every federation of the set.
+    ///
+    /// An empty set is valid.
+    pub fn is_valid_in_all(&self) -> bool {
+        self.results.iter().all(|(_, valid)| *valid)
+    }
+
+    /// Returns true if the entity is valid in at least one federation of the set.
+    pub fn is_valid_in_any(&self) -> bool {
+        self.results.iter().any(|(_, valid)| *valid)
+    }
+
+    /// Returns the result of `federation_id`, if it is part of the set.
+    pub fn get(&self, federation_id: &ObjectID) -> Option<bool> {
+        self.results
+            .iter()
+            .find(|(id, _)| id == federation_id)
+            .map(|(_, valid)| *valid)
+    }
+
+    /// Returns the federations the entity is not valid in.
+    pub fn failed(&self) -> impl Iterator<Item = ObjectID> + '_ {
+        self.results.iter().filter(|(_, valid)| !valid).map(|(id, _)| *id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_and_aggregate() {
+        let eu = ObjectID::random();
+        let fda = ObjectID::random();
+        let set = FederationSet::new()
+            .with(eu, [(PropertyName::from("ce"), PropertyValue::Bool(true))])
+            .with(fda, [(PropertyName::from("fda"), PropertyValue::Bool(true))])
+            .with(eu, [(PropertyName::from("reach"), PropertyValue::Bool(true))]);
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.properties(&eu).map(HashMap::len), Some(2));
+        assert_eq!(set.federation_ids().collect::<Vec<_>>(), vec![eu, fda]);
+
+        let validation = FederationSetValidation {
+            results: vec![(eu, true), (fda, false)],
+        };
+        assert!(!validation.is_valid_in_all());
+        assert!(validation.is_valid_in_any());
+        assert_eq!(validation.failed().collect::<Vec<_>>(), vec![fda]);
+        assert_eq!(validation.get(&eu), Some(true));
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::GrantPolicyViolation;
+use crate::core::types::property::FederationProperty;
+
+/// Federation-wide defaults and limits for granting accreditations.
+///
+/// The policy is set by root authorities and enforced on-chain whenever an
+/// accreditation is created. Existing accreditations are not affected by changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct GrantPolicy {
+    /// Validity length applied to granted properties without an explicit end, at most
+    /// [`Self::MAX_DEFAULT_VALIDITY_MS`]
+    pub default_validity_ms: Option<u64>,
+    /// Whether granted properties may allow any value
+    pub allow_any_permitted: bool,
+    /// Maximum number of allowed values of a granted property
+    pub max_allowed_values: Option<u64>,
+    /// Delay between granting an accreditation and it becoming effective, during which objections can be raised
+    #[serde(default)]
+    pub objection_window_ms: Option<u64>,
+}
+
+impl Default for GrantPolicy {
+    fn default() -> Self {
+        Self {
+            default_validity_ms: None,
+            allow_any_permitted: true,
+            max_allowed_values: None,
+            objection_window_ms: None,
+        }
+    }
+}
+
+impl GrantPolicy {
+    /// Maximum default validity accepted on-chain, 100 years.
+    pub const MAX_DEFAULT_VALIDITY_MS: u64 = 3_153_600_000_000;
+
+    pub fn with_default_validity_ms(mut self, default_validity_ms: u64) -> Self {
+        self.default_validity_ms = Some(default_validity_ms);
+        self
+    }
+
+    pub fn with_allow_any_permitted(mut self, allow_any_permitted: bool) -> Self {
+        self.allow_any_permitted = allow_any_permitted;
+        self
+    }
+
+    pub fn with_max_allowed_values(mut self, max_allowed_values: u64) -> Self {
+        self.max_allowed_values = Some(max_allowed_values);
+        self
+    }
+
+    /// Delays new accreditations from becoming effective by `objection_window_ms`.
+    ///
+    /// Root authorities can end the window of an accreditation early with
+    /// `HierarchiesClient::activate_accreditation`.
+    pub fn with_objection_window_ms(mut self, objection_window_ms: u64) -> Self {
+        self.objection_window_ms = Some(objection_window_ms);
+        self
+    }
+
+    /// Returns the end of the objection window for an accreditation granted at `now_ms`, if any.
+    pub fn pending_until_ms(&self, now_ms: u64) -> Option<u64> {
+        self.objection_window_ms.map(|window_ms| now_ms + window_ms)
+    }
+
+    /// Checks a property to be granted against the limits of the policy.
+    ///
+    /// Mirrors the on-chain check, so violations are reported before a transaction is submitted.
+    pub fn check(&self, property: &FederationProperty) -> Result<(), GrantPolicyViolation> {
+        let name = || property.name.names().join(".");
+
+        if property.allow_any && !self.allow_any_permitted {
+            return Err(GrantPolicyViolation::AllowAnyNotPermitted { property: name() });
+        }
+
+        let count = property.allowed_values.len();
+        match self.max_allowed_values {
+            Some(max) if count as u64 > max => Err(GrantPolicyViolation::AllowedValuesLimitExceeded {
+                property: name(),
+                count,
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies the default validity of the policy to a property granted at `now_ms`.
+    ///
+    /// Mirrors the on-chain behavior: properties with an explicit end are left untouched,
+    /// others are valid for the default length from their start, or from `now_ms`.
+    pub fn apply_defaults(&self, mut property: FederationProperty, now_ms: u64) -> FederationProperty {
+        if let (Some(validity_ms), None) = (self.default_validity_ms, property.timespan.valid_until_ms) {
+            let start_ms = property.timespan.valid_from_ms.map_or(now_ms, |from| from.max(now_ms));
+            property.timespan.valid_until_ms = Some(start_ms + validity_ms);
+        }
+        property
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::property_value::PropertyValue;
+    use crate::core::types::timespan::Timespan;
+
+    #[test]
+    fn test_grant_policy() {
+        let policy = GrantPolicy::default()
+            .with_default_validity_ms(500)
+            .with_allow_any_permitted(false)
+            .with_max_allowed_values(1)
+            .with_objection_window_ms(100);
+
+        let property = FederationProperty::new("role").with_allowed_values([PropertyValue::Number(1)]);
+        assert!(policy.check(&property).is_ok());
+        assert_eq!(policy.pending_until_ms(1000), Some(1100));
+        assert_eq!(GrantPolicy::default().pending_until_ms(1000), None);
+        assert_eq!(
+            policy.apply_defaults(property.clone(), 1000).timespan.valid_until_ms,
+            Some(1500)
+        );
+
+        let scheduled = property.clone().with_timespan(Timespan {
+            valid_from_ms: Some(2000),
+            valid_until_ms: None,
+        });
+        assert_eq!(
+            policy.apply_defaults(scheduled, 1000).timespan.valid_until_ms,
+            Some(2500)
+        );
+
+        let allow_any = property.clone().with_allow_any(true);
+        assert!(matches!(
+            policy.check(&allow_any),
+            Err(GrantPolicyViolation::AllowAnyNotPermitted { .. })
+        ));
+
+        let too_many = property.with_allowed_values([PropertyValue::Number(1), PropertyValue::Number(2)]);
+        assert!(matches!(
+            policy.check(&too_many),
+            Err(GrantPolicyViolation::AllowedValuesLimitExceeded { count: 2, max: 1, .. })
+        ));
+    }
+}
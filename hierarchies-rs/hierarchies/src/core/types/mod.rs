@@ -4,15 +4,26 @@
 //! Types for the Hierarchies protocol.
 
 mod accreditation;
+pub mod accreditation_query;
+pub mod attestation;
 mod cap;
+pub mod credential_anchor;
 pub mod events;
+pub mod federation_set;
+pub mod grant_policy;
 pub mod property;
 pub mod property_name;
+pub mod property_pattern;
 pub mod property_shape;
 pub mod property_value;
+pub mod quorum;
+pub mod revocation_reason;
+pub mod selective_disclosure;
+pub mod subject_id;
 pub mod timespan;
+pub mod trust_chain;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub use accreditation::*;
 pub use cap::*;
@@ -20,8 +31,12 @@ use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::id::UID;
 use serde::{Deserialize, Serialize};
 
+use crate::core::types::grant_policy::GrantPolicy;
 use crate::core::types::property::FederationProperties;
-use crate::utils::deserialize_vec_map;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::quorum::Quorum;
+use crate::utils::{deserialize_vec_map, deserialize_vec_map_of_vec_sets, deserialize_vec_set};
 
 /// Move package module names for Hierarchies smart contract interactions.
 ///
@@ -42,19 +57,118 @@ pub mod move_names {
     pub const MODULE_SHAPE: &str = "property_shape";
     /// Utility module for common operations
     pub const MODULE_UTILS: &str = "utils";
+    /// Module for attestation objects
+    pub const MODULE_ATTESTATION: &str = "attestation";
 }
 
 /// Represents a federation. A federation is a group of entities that have agreed to work together
+///
+/// With the `strict-decode` feature, unknown fields are rejected when decoding from
+/// self-describing formats such as JSON. BCS is positional, so on-chain layout drift is
+/// reported by [`crate::core::diagnostics`] instead.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct Federation {
     pub id: UID,
     pub governance: Governance,
     pub root_authorities: Vec<RootAuthority>,
     pub revoked_root_authorities: Vec<ObjectID>,
+    /// Summary statistics kept up to date by every mutation
+    pub stats: FederationStats,
+    /// Human-readable description shown by wallets and explorers
+    pub metadata: FederationMetadata,
+}
+
+impl Federation {
+    /// Returns the metadata of the federation.
+    pub fn metadata(&self) -> &FederationMetadata {
+        &self.metadata
+    }
+
+    /// Returns the display name of the federation, falling back to its ID if none is set.
+    pub fn display_name(&self) -> String {
+        self.metadata
+            .name
+            .clone()
+            .unwrap_or_else(|| self.id.object_id().to_string())
+    }
+}
+
+/// Summary statistics of a federation, maintained on-chain by every mutation.
+///
+/// Reading them with `HierarchiesClientReadOnly::get_federation_stats` neither decodes the whole
+/// federation nor scans its events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct FederationStats {
+    /// Number of properties, including revoked ones
+    pub property_count: u64,
+    /// Number of active root authorities
+    pub root_authority_count: u64,
+    /// Number of accreditations to attest held by all entities
+    pub accreditation_to_attest_count: u64,
+    /// Number of accreditations to accredit held by all entities
+    pub accreditation_to_accredit_count: u64,
+    /// Number of mutations applied since the federation was created
+    pub modification_count: u64,
+    /// Start of the epoch in which the last mutation was applied
+    pub last_modified_epoch_ms: u64,
+}
+
+/// Optional human-readable description of a federation, set by root authorities.
+///
+/// The metadata is informational only and never used by validation. Every set field must be
+/// non-empty and at most [`FederationMetadata::MAX_FIELD_LENGTH`] bytes long.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct FederationMetadata {
+    /// Display name, e.g. "International Standards Consortium"
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// URI of the logo
+    pub logo_uri: Option<String>,
+    /// Contact of the federation operators, e.g. an email address or a URL
+    pub contact: Option<String>,
+}
+
+impl FederationMetadata {
+    /// Maximum length of a metadata field in bytes, mirroring the on-chain limit.
+    pub const MAX_FIELD_LENGTH: usize = 1024;
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_logo_uri(mut self, logo_uri: impl Into<String>) -> Self {
+        self.logo_uri = Some(logo_uri.into());
+        self
+    }
+
+    pub fn with_contact(mut self, contact: impl Into<String>) -> Self {
+        self.contact = Some(contact.into());
+        self
+    }
+
+    /// Returns true if every set field is non-empty and within [`Self::MAX_FIELD_LENGTH`].
+    ///
+    /// Mirrors the on-chain check, so invalid metadata is reported before a transaction is submitted.
+    pub fn is_valid(&self) -> bool {
+        [&self.name, &self.description, &self.logo_uri, &self.contact]
+            .into_iter()
+            .flatten()
+            .all(|field| !field.is_empty() && field.len() <= Self::MAX_FIELD_LENGTH)
+    }
 }
 
 /// Represents a root authority. A root authority is an entity that has the highest level of authority in a federation
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct RootAuthority {
     pub id: UID,
     pub account_id: ObjectID,
@@ -62,6 +176,7 @@ pub struct RootAuthority {
 
 /// Represents the governance of a federation
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct Governance {
     pub id: UID,
     pub properties: FederationProperties,
@@ -69,4 +184,44 @@ pub struct Governance {
     pub accreditations_to_accredit: HashMap<ObjectID, Accreditations>,
     #[serde(deserialize_with = "deserialize_vec_map")]
     pub accreditations_to_attest: HashMap<ObjectID, Accreditations>,
+    /// Properties marked as critical by the root authorities
+    #[serde(deserialize_with = "deserialize_vec_set")]
+    pub critical_properties: HashSet<PropertyName>,
+    /// Named sets of values shared by the properties referencing them
+    #[serde(deserialize_with = "deserialize_vec_map_of_vec_sets")]
+    pub value_dictionaries: HashMap<String, HashSet<PropertyValue>>,
+    /// Defaults and limits applied to newly granted accreditations
+    pub grant_policy: GrantPolicy,
+    /// Free-form labels grouping properties
+    #[serde(deserialize_with = "deserialize_vec_map_of_vec_sets")]
+    pub property_tags: HashMap<PropertyName, HashSet<String>>,
+    /// Free-form labels grouping accreditations
+    #[serde(deserialize_with = "deserialize_vec_map_of_vec_sets")]
+    pub accreditation_tags: HashMap<ObjectID, HashSet<String>>,
+    /// Approvals required for destructive actions and the open proposals
+    pub quorum: Quorum,
+    /// Child federations whose root authorities are trusted like those of this federation
+    #[serde(deserialize_with = "deserialize_vec_set")]
+    pub recognized_federations: HashSet<ObjectID>,
+    /// Deprecated properties, mapped to the property replacing them if any
+    #[serde(deserialize_with = "deserialize_vec_map")]
+    pub deprecated_properties: HashMap<PropertyName, Option<PropertyName>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_federation_metadata_validity() {
+        let metadata = FederationMetadata::default()
+            .with_name("International Standards Consortium")
+            .with_contact("admin@example.org");
+        assert!(metadata.is_valid());
+        assert!(FederationMetadata::default().is_valid());
+
+        assert!(!metadata.clone().with_description("").is_valid());
+        let too_long = "a".repeat(FederationMetadata::MAX_FIELD_LENGTH + 1);
+        assert!(!metadata.with_logo_uri(too_long).is_valid());
+    }
 }
@@ -3,7 +3,9 @@
 
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::time::Duration;
 
+use chrono::{DateTime, TimeZone};
 use iota_interaction::types::base_types::{ObjectID, TypeTag};
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use iota_interaction::types::transaction::{Argument, Command};
@@ -12,19 +14,45 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::types::property_name::PropertyName;
 use crate::core::types::property_shape::PropertyShape;
-use crate::core::types::property_value::PropertyValue;
+use crate::core::types::property_value::{PropertyValue, values_to_ptb};
 use crate::core::types::timespan::Timespan;
 use crate::utils::{self, deserialize_vec_map, deserialize_vec_set};
 
 // FederationProperties is a struct that contains a map of PropertyName to FederationProperty
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct FederationProperties {
     #[serde(deserialize_with = "deserialize_vec_map")]
     pub data: HashMap<PropertyName, FederationProperty>,
 }
 
-// The evaluation order: allow_any => shape => allowed_values
+impl FederationProperties {
+    /// Returns the properties in the namespace `prefix`, see [`PropertyName::starts_with`].
+    pub fn subtree(&self, prefix: &PropertyName) -> FederationProperties {
+        FederationProperties {
+            data: self
+                .data
+                .iter()
+                .filter(|(name, _)| name.starts_with(prefix))
+                .map(|(name, property)| (name.clone(), property.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// A page of properties returned by the paged on-chain view.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct PropertyPage {
+    /// The properties of the page, in the order they were added.
+    pub properties: Vec<FederationProperty>,
+    /// The cursor to request the next page with, `None` if this is the last page.
+    pub next_cursor: Option<u64>,
+}
+
+// The evaluation order: allow_any => shape => allowed_values => value_dictionary
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct FederationProperty {
     pub name: PropertyName,
     /// Allow only values that are in the set
@@ -36,16 +64,41 @@ pub struct FederationProperty {
     pub allow_any: bool,
     /// The time span of the property
     pub timespan: Timespan,
+    /// Allow only values of the named federation value dictionary
+    #[serde(default)]
+    pub value_dictionary: Option<String>,
+    /// Human-readable schema information, set by root authorities
+    #[serde(default)]
+    pub metadata: PropertyMetadata,
 }
 
 impl FederationProperty {
+    /// Creates a property named `name` that allows no value.
+    ///
+    /// A wildcard `name` can only be granted as an accreditation, adding it to a federation fails with
+    /// [`OperationError::WildcardProperty`](crate::core::OperationError::WildcardProperty). Prefer
+    /// [`FederationProperty::new_prefix`] to grant a namespace.
     pub fn new(name: impl Into<PropertyName>) -> Self {
+        Self::named(name.into())
+    }
+
+    /// Creates a property granting every property in the namespace `prefix`, including
+    /// properties added to the federation later on, see [`PropertyName::wildcard`].
+    ///
+    /// Wildcards can only be granted as accreditations and can't reference a value dictionary.
+    pub fn new_prefix(prefix: impl Into<PropertyName>) -> Self {
+        Self::named(PropertyName::wildcard(prefix))
+    }
+
+    fn named(name: PropertyName) -> Self {
         Self {
-            name: name.into(),
+            name,
             allowed_values: HashSet::new(),
             shape: None,
             allow_any: false,
             timespan: Timespan::default(),
+            value_dictionary: None,
+            metadata: PropertyMetadata::default(),
         }
     }
 
@@ -64,10 +117,155 @@ impl FederationProperty {
         self
     }
 
+    /// Makes the property valid from `from` on, see [`Timespan::valid_from`].
+    pub fn valid_from<Tz: TimeZone>(mut self, from: DateTime<Tz>) -> Self {
+        self.timespan = self.timespan.valid_from(from);
+        self
+    }
+
+    /// Makes the property valid until `until`, see [`Timespan::valid_until`].
+    pub fn valid_until<Tz: TimeZone>(mut self, until: DateTime<Tz>) -> Self {
+        self.timespan = self.timespan.valid_until(until);
+        self
+    }
+
+    /// Makes the property valid for `duration` from its start, or from now if it has no start,
+    /// see [`Timespan::valid_for`].
+    pub fn valid_for(mut self, duration: Duration) -> Self {
+        self.timespan = self.timespan.valid_for(duration);
+        self
+    }
+
     pub fn with_allow_any(mut self, allow_any: bool) -> Self {
         self.allow_any = allow_any;
         self
     }
+
+    /// Returns true if the property allows `value` at `now_ms`.
+    ///
+    /// Mirrors the on-chain `matches_value` check. Membership in a referenced value
+    /// dictionary is checked by the federation, so a property referencing a dictionary
+    /// allows every value here.
+    pub fn matches_value(&self, value: &PropertyValue, now_ms: u64) -> bool {
+        if !self.timespan.timestamp_matches(now_ms) {
+            return false;
+        }
+
+        self.allow_any
+            || self.shape.as_ref().is_some_and(|shape| shape.matches(value))
+            || self.allowed_values.contains(value)
+            || self.value_dictionary.is_some()
+    }
+
+    /// Returns true if the property allows delegating `value_dictionary` at `now_ms`.
+    ///
+    /// Mirrors the on-chain `covers_value_dictionary` check.
+    pub fn covers_value_dictionary(&self, value_dictionary: &str, now_ms: u64) -> bool {
+        self.timespan.timestamp_matches(now_ms)
+            && (self.allow_any || self.value_dictionary.as_deref() == Some(value_dictionary))
+    }
+
+    /// Restricts the property to the values of a federation value dictionary.
+    ///
+    /// The dictionary replaces allowed values and shape, which are not sent on-chain
+    /// for properties referencing a dictionary.
+    pub fn with_value_dictionary(mut self, value_dictionary: impl Into<String>) -> Self {
+        self.value_dictionary = Some(value_dictionary.into());
+        self
+    }
+}
+
+/// Human-readable schema information of a property, e.g. its display name or unit.
+///
+/// Verifier UIs use it to render values such as `grade.gpa = 385` meaningfully. The metadata is
+/// informational only and never used by validation. Besides the well-known keys, any key of at
+/// most 64 bytes is accepted; values must be non-empty and at most 1024 bytes long.
+///
+/// The metadata is set with `HierarchiesClient::set_property_metadata` once the property is
+/// part of the federation, so it is not sent when adding or granting a property.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PropertyMetadata(#[serde(deserialize_with = "deserialize_vec_map")] HashMap<String, String>);
+
+impl PropertyMetadata {
+    /// Key of the name shown instead of the property name
+    pub const DISPLAY_NAME: &'static str = "display_name";
+    /// Key of the description of the property
+    pub const DESCRIPTION: &'static str = "description";
+    /// Key of the unit of the values, e.g. `hundredths`
+    pub const UNIT: &'static str = "unit";
+    /// Key of the hint on how to interpret the values, e.g. `decimal`
+    pub const DATA_TYPE: &'static str = "data_type";
+    /// Key of the locale of the texts, e.g. `en-US`
+    pub const LOCALE: &'static str = "locale";
+    /// Maximum number of entries, mirroring the on-chain limit
+    pub const MAX_ENTRIES: usize = 16;
+
+    /// Sets the entry `key` to `value`.
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_display_name(self, display_name: impl Into<String>) -> Self {
+        self.with(Self::DISPLAY_NAME, display_name)
+    }
+
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        self.with(Self::DESCRIPTION, description)
+    }
+
+    pub fn with_unit(self, unit: impl Into<String>) -> Self {
+        self.with(Self::UNIT, unit)
+    }
+
+    pub fn with_data_type(self, data_type: impl Into<String>) -> Self {
+        self.with(Self::DATA_TYPE, data_type)
+    }
+
+    pub fn with_locale(self, locale: impl Into<String>) -> Self {
+        self.with(Self::LOCALE, locale)
+    }
+
+    /// Returns the value of the entry `key`, if any.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    pub fn display_name(&self) -> Option<&str> {
+        self.get(Self::DISPLAY_NAME)
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.get(Self::DESCRIPTION)
+    }
+
+    pub fn unit(&self) -> Option<&str> {
+        self.get(Self::UNIT)
+    }
+
+    pub fn data_type(&self) -> Option<&str> {
+        self.get(Self::DATA_TYPE)
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.get(Self::LOCALE)
+    }
+
+    /// Returns the entries of the metadata.
+    pub fn entries(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(String, String)> for PropertyMetadata {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
 }
 
 impl MoveType for FederationProperty {
@@ -87,18 +285,13 @@ pub(crate) fn new_property(
 
     let property_names = property.name.to_ptb(ptb, package_id)?;
 
-    let allow_any = ptb.pure(property.allow_any)?;
+    if let Some(value_dictionary) = property.value_dictionary {
+        return new_property_with_value_dictionary(package_id, ptb, property_names, value_dictionary);
+    }
 
-    let allowed_values = property
-        .allowed_values
-        .into_iter()
-        .map(|value| {
-            value
-                .to_ptb(ptb, package_id)
-                .expect("failed to create new property value")
-        })
-        .collect();
+    let allow_any = ptb.pure(property.allow_any)?;
 
+    let allowed_values = values_to_ptb(&property.allowed_values, ptb, package_id)?;
     let allowed_values = utils::create_vec_set_from_move_values(allowed_values, value_tag, ptb, package_id);
 
     let property_shape_tag = PropertyShape::move_type(package_id);
@@ -122,6 +315,24 @@ pub(crate) fn new_property(
     Ok(property)
 }
 
+/// Creates a new move type for a Property referencing a value dictionary
+fn new_property_with_value_dictionary(
+    package_id: ObjectID,
+    ptb: &mut ProgrammableTransactionBuilder,
+    property_names: Argument,
+    value_dictionary: String,
+) -> anyhow::Result<Argument> {
+    let value_dictionary = ptb.pure(value_dictionary)?;
+
+    Ok(ptb.programmable_move_call(
+        package_id,
+        ident_str!("property").as_str().into(),
+        ident_str!("new_property_with_value_dictionary").as_str().into(),
+        vec![],
+        vec![property_names, value_dictionary],
+    ))
+}
+
 /// Creates a new move type for a list of Properties
 pub(crate) fn new_properties(
     package_id: ObjectID,
@@ -134,18 +345,19 @@ pub(crate) fn new_properties(
 
         let property_names = property.name.to_ptb(ptb, package_id)?;
 
-        let allow_any = ptb.pure(property.allow_any)?;
+        if let Some(value_dictionary) = property.value_dictionary {
+            property_args.push(new_property_with_value_dictionary(
+                package_id,
+                ptb,
+                property_names,
+                value_dictionary,
+            )?);
+            continue;
+        }
 
-        let allowed_values = property
-            .allowed_values
-            .into_iter()
-            .map(|value| {
-                value
-                    .to_ptb(ptb, package_id)
-                    .expect("failed to create new property value")
-            })
-            .collect();
+        let allow_any = ptb.pure(property.allow_any)?;
 
+        let allowed_values = values_to_ptb(&property.allowed_values, ptb, package_id)?;
         let allowed_values = utils::create_vec_set_from_move_values(allowed_values, value_tag, ptb, package_id);
 
         let property_expression_tag = PropertyShape::move_type(package_id);
@@ -174,3 +386,59 @@ pub(crate) fn new_properties(
         property_args,
     )))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subtree() {
+        let names = [
+            PropertyName::new(["compliance", "iso", "9001"]),
+            PropertyName::new(["compliance", "gdpr"]),
+            PropertyName::from("role"),
+        ];
+        let properties = FederationProperties {
+            data: names
+                .iter()
+                .map(|name| (name.clone(), FederationProperty::new(name.clone())))
+                .collect(),
+        };
+
+        let compliance = properties.subtree(&PropertyName::from("compliance"));
+        assert_eq!(compliance.data.len(), 2);
+        assert!(!compliance.data.contains_key(&names[2]));
+
+        let iso = compliance.subtree(&PropertyName::new(["compliance", "iso"]));
+        assert_eq!(iso.data.keys().collect::<Vec<_>>(), [&names[0]]);
+        assert!(properties.subtree(&PropertyName::from("comp")).data.is_empty());
+    }
+
+    #[test]
+    fn test_new_accepts_wildcard_names() {
+        let prefix = FederationProperty::new_prefix("degree");
+        assert!(prefix.name.is_wildcard());
+
+        assert_eq!(FederationProperty::new(PropertyName::new(["degree", "*"])), prefix);
+    }
+
+    #[test]
+    fn test_property_metadata_decodes_vec_map() {
+        let json = serde_json::json!({
+            "contents": [
+                { "key": "display_name", "value": "Grade point average" },
+                { "key": "unit", "value": "hundredths" },
+            ]
+        });
+
+        let metadata: PropertyMetadata = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            metadata,
+            PropertyMetadata::default()
+                .with_display_name("Grade point average")
+                .with_unit("hundredths")
+        );
+        assert_eq!(metadata.unit(), Some("hundredths"));
+        assert_eq!(metadata.description(), None);
+    }
+}
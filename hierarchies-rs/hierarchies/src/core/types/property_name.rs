@@ -13,8 +13,12 @@ use iota_interaction::types::transaction::Argument;
 use iota_interaction::{MoveType, ident_str};
 use serde::{Deserialize, Serialize};
 
+/// The last segment of a wildcard name, see [`PropertyName::wildcard`].
+pub const WILDCARD: &str = "*";
+
 /// PropertyName represents the name of a Property
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct PropertyName {
     names: Vec<String>,
 }
@@ -41,10 +45,47 @@ impl PropertyName {
         }
     }
 
+    /// Creates the wildcard name covering every property in the namespace `prefix`, e.g. `degree.*`.
+    pub fn wildcard(prefix: impl Into<PropertyName>) -> Self {
+        let mut name = prefix.into();
+        name.names.push(WILDCARD.to_string());
+        name
+    }
+
     pub fn names(&self) -> &Vec<String> {
         &self.names
     }
 
+    /// Returns true if the last segment is [`WILDCARD`].
+    pub fn is_wildcard(&self) -> bool {
+        self.names.last().is_some_and(|name| name == WILDCARD)
+    }
+
+    /// Returns true if a grant for this name covers `name`.
+    ///
+    /// A wildcard `a.b.*` covers every name below `a.b`, including narrower wildcards like
+    /// `a.b.c.*`; any other name covers only itself. Mirrors the on-chain `covers`.
+    pub fn covers(&self, name: &PropertyName) -> bool {
+        match self.names.split_last() {
+            Some((last, prefix)) if last == WILDCARD => {
+                name.names.len() > prefix.len() && name.names.starts_with(prefix)
+            }
+            _ => self == name,
+        }
+    }
+
+    /// Returns true if the leading segments of this name are equal to `prefix`.
+    pub fn starts_with(&self, prefix: &PropertyName) -> bool {
+        self.names.starts_with(&prefix.names)
+    }
+
+    /// Returns the name made of the segments of `self` followed by those of `suffix`.
+    pub fn join(&self, suffix: &PropertyName) -> PropertyName {
+        Self {
+            names: self.names.iter().chain(&suffix.names).cloned().collect(),
+        }
+    }
+
     pub fn to_ptb(&self, ptb: &mut ProgrammableTransactionBuilder, package_id: ObjectID) -> anyhow::Result<Argument> {
         new_property_name(self, ptb, package_id)
     }
@@ -92,4 +133,31 @@ mod tests {
         assert_eq!(serde_json::to_value(&name).unwrap(), json);
         assert_eq!(serde_json::from_value::<PropertyName>(json).unwrap(), name);
     }
+
+    #[test]
+    fn test_join_property_names() {
+        let scope = PropertyName::from("iso");
+        let name = scope.join(&PropertyName::new(["9001", "cert"]));
+
+        assert_eq!(name, PropertyName::new(["iso", "9001", "cert"]));
+        assert!(name.starts_with(&scope));
+    }
+
+    #[test]
+    fn test_wildcard_covers_namespace() {
+        let degrees = PropertyName::wildcard("degree");
+        assert_eq!(degrees, PropertyName::new(["degree", WILDCARD]));
+        assert!(degrees.is_wildcard());
+
+        assert!(degrees.covers(&PropertyName::new(["degree", "bachelor"])));
+        assert!(degrees.covers(&PropertyName::wildcard(PropertyName::new(["degree", "bachelor"]))));
+        assert!(degrees.covers(&degrees));
+        assert!(!degrees.covers(&PropertyName::from("degree")));
+        assert!(!degrees.covers(&PropertyName::from("role")));
+
+        let bachelor = PropertyName::new(["degree", "bachelor"]);
+        assert!(!bachelor.is_wildcard());
+        assert!(bachelor.covers(&bachelor));
+        assert!(!bachelor.covers(&PropertyName::new(["degree", "bachelor", "cs"])));
+    }
 }
@@ -0,0 +1,216 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies Property Pattern
+//!
+//! The pattern syntax of [`PropertyShape::MatchesPattern`](crate::core::types::property_shape::PropertyShape),
+//! a constrained glob syntax the Move package can evaluate at a cost bounded by the product of the
+//! pattern and value lengths.
+//!
+//! Patterns are ASCII and matched against the whole value:
+//! - `*` matches any sequence of characters, including none
+//! - `?` matches a single ASCII character
+//! - `#` matches an ASCII digit
+//! - `@` matches an ASCII letter
+//! - `[...]` matches one of the listed characters or ranges, e.g. `[A-Z]` or `[a-z0-9_]`; a `-` before the closing `]`
+//!   is literal
+//! - `\` matches the following character literally
+//! - any other character matches itself
+//!
+//! Non-ASCII characters of a value can only be matched by `*`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use hierarchies::core::types::property_pattern::{pattern_matches, validate_pattern};
+//!
+//! validate_pattern("S-####").unwrap();
+//! assert!(pattern_matches("S-####", "S-2024"));
+//! assert!(pattern_matches("[A-Z][A-Z]", "CH"));
+//! assert!(validate_pattern("[A-Z").is_err());
+//! ```
+
+use crate::core::error::PatternError;
+
+const ANY_SEQUENCE: u8 = b'*';
+const ANY_CHAR: u8 = b'?';
+const DIGIT: u8 = b'#';
+const LETTER: u8 = b'@';
+const ESCAPE: u8 = b'\\';
+const CLASS_START: u8 = b'[';
+const CLASS_END: u8 = b']';
+const RANGE: u8 = b'-';
+
+/// Checks that `pattern` is valid, mirroring the check of `new_property_shape_matches_pattern` in the
+/// Move package, so invalid patterns are reported before a transaction is submitted.
+pub fn validate_pattern(pattern: &str) -> Result<(), PatternError> {
+    let pattern = pattern.as_bytes();
+    let mut p = 0;
+    while p < pattern.len() {
+        if !pattern[p].is_ascii() {
+            return Err(PatternError::NonAscii { position: p });
+        }
+        match pattern[p] {
+            ESCAPE => match pattern.get(p + 1) {
+                Some(escaped) if escaped.is_ascii() => p += 2,
+                Some(_) => return Err(PatternError::NonAscii { position: p + 1 }),
+                None => return Err(PatternError::UnterminatedEscape { position: p }),
+            },
+            CLASS_START => {
+                let mut i = p + 1;
+                if pattern.get(i) == Some(&CLASS_END) {
+                    return Err(PatternError::EmptyClass { position: p });
+                }
+                while i < pattern.len() && pattern[i] != CLASS_END {
+                    if !pattern[i].is_ascii() {
+                        return Err(PatternError::NonAscii { position: i });
+                    }
+                    if is_class_range(pattern, i) {
+                        if !pattern[i + 2].is_ascii() {
+                            return Err(PatternError::NonAscii { position: i + 2 });
+                        }
+                        if pattern[i] > pattern[i + 2] {
+                            return Err(PatternError::InvalidRange { position: i });
+                        }
+                        i += 3;
+                    } else {
+                        i += 1;
+                    }
+                }
+                if i >= pattern.len() {
+                    return Err(PatternError::UnterminatedClass { position: p });
+                }
+                p = i + 1;
+            }
+            _ => p += 1,
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if the valid `pattern` matches the whole `value`.
+///
+/// Mirrors the on-chain check. The result for invalid patterns is unspecified, as they can't be stored on-chain.
+pub fn pattern_matches(pattern: &str, value: &str) -> bool {
+    let (pattern, value) = (pattern.as_bytes(), value.as_bytes());
+    let (mut p, mut v) = (0, 0);
+    // The pattern position after the last `*` and the value position it has consumed up to
+    let mut star: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        if pattern.get(p) == Some(&ANY_SEQUENCE) {
+            p += 1;
+            star = Some((p, v));
+            continue;
+        }
+        if p < pattern.len() {
+            let (matched, next) = match_token(pattern, p, value[v]);
+            if matched {
+                p = next;
+                v += 1;
+                continue;
+            }
+        }
+        match star.as_mut() {
+            Some((star_p, star_v)) => {
+                // Let the last `*` consume one more byte
+                *star_v += 1;
+                p = *star_p;
+                v = *star_v;
+            }
+            None => return false,
+        }
+    }
+    while pattern.get(p) == Some(&ANY_SEQUENCE) {
+        p += 1;
+    }
+    p == pattern.len()
+}
+
+/// Checks if `byte` matches the token starting at `p`, returning the start of the next token.
+fn match_token(pattern: &[u8], p: usize, byte: u8) -> (bool, usize) {
+    match pattern[p] {
+        ANY_CHAR => (byte.is_ascii(), p + 1),
+        DIGIT => (byte.is_ascii_digit(), p + 1),
+        LETTER => (byte.is_ascii_alphabetic(), p + 1),
+        ESCAPE => (pattern.get(p + 1) == Some(&byte), p + 2),
+        CLASS_START => {
+            let mut matched = false;
+            let mut i = p + 1;
+            while i < pattern.len() && pattern[i] != CLASS_END {
+                if is_class_range(pattern, i) {
+                    matched |= (pattern[i]..=pattern[i + 2]).contains(&byte);
+                    i += 3;
+                } else {
+                    matched |= pattern[i] == byte;
+                    i += 1;
+                }
+            }
+            (matched, i + 1)
+        }
+        token => (token == byte, p + 1),
+    }
+}
+
+/// Checks if a range such as `a-z` starts at `i` inside a class.
+fn is_class_range(pattern: &[u8], i: usize) -> bool {
+    i + 2 < pattern.len() && pattern[i + 1] == RANGE && pattern[i + 2] != CLASS_END
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_tokens() {
+        assert!(pattern_matches("[A-Z][A-Z]", "CH"));
+        assert!(!pattern_matches("[A-Z][A-Z]", "ch"));
+        assert!(!pattern_matches("[A-Z][A-Z]", "CHE"));
+
+        assert!(pattern_matches("S-####-@?", "S-2024-ab"));
+        assert!(!pattern_matches("S-####-@?", "S-20x4-ab"));
+
+        assert!(pattern_matches("[a-c_-]*", "-x"));
+        assert!(!pattern_matches("[a-c_-]*", "d"));
+
+        // `?` matches ASCII only, other characters need `*`
+        assert!(!pattern_matches("?", "é"));
+        assert!(pattern_matches("caf*", "café"));
+    }
+
+    #[test]
+    fn test_pattern_backtracks_to_last_star() {
+        assert!(pattern_matches(r"*.edu\*", "mit.edu*"));
+        assert!(pattern_matches(r"*.edu\*", "a.edu.b.edu*"));
+        assert!(!pattern_matches(r"*.edu\*", "mit.edu"));
+        assert!(pattern_matches("a*b*c", "aXbYbZc"));
+        assert!(!pattern_matches("a*b*c", "aXbYbZ"));
+        assert!(pattern_matches("**", ""));
+        assert!(!pattern_matches("", "a"));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(validate_pattern(r"[a-z0-9_]\*#@?").is_ok());
+        assert!(matches!(
+            validate_pattern("[A-Z"),
+            Err(PatternError::UnterminatedClass { position: 0 })
+        ));
+        assert!(matches!(
+            validate_pattern("a[]"),
+            Err(PatternError::EmptyClass { position: 1 })
+        ));
+        assert!(matches!(
+            validate_pattern("[z-a]"),
+            Err(PatternError::InvalidRange { position: 1 })
+        ));
+        assert!(matches!(
+            validate_pattern(r"abc\"),
+            Err(PatternError::UnterminatedEscape { position: 3 })
+        ));
+        assert!(matches!(
+            validate_pattern("é"),
+            Err(PatternError::NonAscii { position: 0 })
+        ));
+    }
+}
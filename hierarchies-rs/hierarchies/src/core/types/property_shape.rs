@@ -4,27 +4,115 @@
 //! # Hierarchies Property Shape
 //!
 //! This module provides a shape that can be applied to a PropertyValue.
+//!
+//! Shapes can be combined with [`PropertyShape::All`], [`PropertyShape::Any`] and [`PropertyShape::Not`].
+//! Move types can't be recursive, so a composite shape is stored on-chain as a flat list of nodes in
+//! postfix order; the conversion happens when a shape is serialized or deserialized.
+//!
+//! [`PropertyShape::MatchesPattern`] uses the syntax described in [`crate::core::types::property_pattern`].
 
 use std::str::FromStr;
 use std::string::String;
 
 use iota_interaction::types::base_types::{ObjectID, TypeTag};
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
-use iota_interaction::types::transaction::Argument;
+use iota_interaction::types::transaction::{Argument, Command};
 use iota_interaction::{MoveType, ident_str};
 use serde::{Deserialize, Serialize};
 
+use crate::core::error::PatternError;
+use crate::core::types::property_pattern::{pattern_matches, validate_pattern};
+use crate::core::types::property_value::PropertyValue;
+
 /// PropertyShape is a shape that can be applied to a PropertyValue.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "OnChainShape", into = "OnChainShape")]
 pub enum PropertyShape {
     StartsWith(String),
     EndsWith(String),
     Contains(String),
     GreaterThan(u64),
     LowerThan(u64),
+    /// A number between the two bounds, both inclusive.
+    Between(u64, u64),
+    EqualTo(PropertyValue),
+    NotEqualTo(PropertyValue),
+    /// A text matching the pattern, see [`crate::core::types::property_pattern`].
+    MatchesPattern(String),
+    /// Matches if every shape matches.
+    All(Vec<PropertyShape>),
+    /// Matches if at least one shape matches.
+    Any(Vec<PropertyShape>),
+    /// Matches if the shape doesn't match.
+    Not(Box<PropertyShape>),
 }
 
 impl PropertyShape {
+    /// Combines `self` and `other`, matching if both match.
+    pub fn and(self, other: PropertyShape) -> Self {
+        PropertyShape::All(vec![self, other])
+    }
+
+    /// Combines `self` and `other`, matching if either matches.
+    pub fn or(self, other: PropertyShape) -> Self {
+        PropertyShape::Any(vec![self, other])
+    }
+
+    /// Negates `self`.
+    pub fn negate(self) -> Self {
+        PropertyShape::Not(Box::new(self))
+    }
+
+    /// Returns true if the shape combines other shapes.
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self,
+            PropertyShape::All(_) | PropertyShape::Any(_) | PropertyShape::Not(_)
+        )
+    }
+
+    /// Checks that the patterns of the shape are valid.
+    ///
+    /// Mirrors the on-chain check, so invalid patterns are reported before a transaction is submitted.
+    pub fn validate(&self) -> Result<(), PatternError> {
+        match self {
+            PropertyShape::MatchesPattern(pattern) => validate_pattern(pattern),
+            PropertyShape::All(shapes) | PropertyShape::Any(shapes) => shapes.iter().try_for_each(Self::validate),
+            PropertyShape::Not(shape) => shape.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns true if `value` matches the shape.
+    ///
+    /// Mirrors the on-chain `property_shape_matches` check, which compares the first
+    /// occurrence of the text, so e.g. `"abab"` does not end with `"ab"`.
+    ///
+    /// Numeric shapes match numbers and timestamps.
+    pub fn matches(&self, value: &PropertyValue) -> bool {
+        match (self, value) {
+            (PropertyShape::StartsWith(text), PropertyValue::Text(value)) => value.find(text.as_str()) == Some(0),
+            (PropertyShape::EndsWith(text), PropertyValue::Text(value)) => {
+                value.len() >= text.len() && value.find(text.as_str()) == Some(value.len() - text.len())
+            }
+            (PropertyShape::Contains(text), PropertyValue::Text(value)) => {
+                value.len() >= text.len() && value.find(text.as_str()).is_some_and(|idx| idx < value.len())
+            }
+            (PropertyShape::GreaterThan(bound), value) => value.as_ordered().is_some_and(|value| value > *bound),
+            (PropertyShape::LowerThan(bound), value) => value.as_ordered().is_some_and(|value| value < *bound),
+            (PropertyShape::Between(min, max), value) => {
+                value.as_ordered().is_some_and(|value| *min <= value && value <= *max)
+            }
+            (PropertyShape::EqualTo(expected), value) => value == expected,
+            (PropertyShape::NotEqualTo(expected), value) => value != expected,
+            (PropertyShape::MatchesPattern(pattern), PropertyValue::Text(value)) => pattern_matches(pattern, value),
+            (PropertyShape::All(shapes), value) => shapes.iter().all(|shape| shape.matches(value)),
+            (PropertyShape::Any(shapes), value) => shapes.iter().any(|shape| shape.matches(value)),
+            (PropertyShape::Not(shape), value) => !shape.matches(value),
+            _ => false,
+        }
+    }
+
     pub fn into_ptb(self, ptb: &mut ProgrammableTransactionBuilder, package_id: ObjectID) -> anyhow::Result<Argument> {
         match self {
             PropertyShape::StartsWith(text) => new_property_shape_starts_with(text, ptb, package_id),
@@ -32,10 +120,184 @@ impl PropertyShape {
             PropertyShape::Contains(text) => new_property_shape_contains(text, ptb, package_id),
             PropertyShape::GreaterThan(value) => new_property_shape_greater_than(value, ptb, package_id),
             PropertyShape::LowerThan(value) => new_property_shape_lower_than(value, ptb, package_id),
+            PropertyShape::Between(min, max) => new_property_shape_between(min, max, ptb, package_id),
+            PropertyShape::EqualTo(value) => new_property_shape_equal_to(value, ptb, package_id),
+            PropertyShape::NotEqualTo(value) => new_property_shape_not_equal_to(value, ptb, package_id),
+            PropertyShape::MatchesPattern(pattern) => new_property_shape_matches_pattern(pattern, ptb, package_id),
+            composite => new_property_shape_composite(composite.into_nodes(), ptb, package_id),
+        }
+    }
+
+    /// Flattens the shape into nodes in postfix order.
+    fn into_nodes(self) -> Vec<ShapeNode> {
+        let mut nodes = vec![];
+        self.push_nodes(&mut nodes);
+        nodes
+    }
+
+    fn push_nodes(self, nodes: &mut Vec<ShapeNode>) {
+        match self {
+            PropertyShape::All(shapes) => {
+                let count = shapes.len() as u64;
+                shapes.into_iter().for_each(|shape| shape.push_nodes(nodes));
+                nodes.push(ShapeNode::All(count));
+            }
+            PropertyShape::Any(shapes) => {
+                let count = shapes.len() as u64;
+                shapes.into_iter().for_each(|shape| shape.push_nodes(nodes));
+                nodes.push(ShapeNode::Any(count));
+            }
+            PropertyShape::Not(shape) => {
+                shape.push_nodes(nodes);
+                nodes.push(ShapeNode::Not);
+            }
+            leaf => nodes.push(ShapeNode::from_leaf(leaf)),
+        }
+    }
+
+    /// Rebuilds a shape from nodes in postfix order.
+    fn from_nodes(nodes: Vec<ShapeNode>) -> Result<Self, String> {
+        let mut shapes: Vec<PropertyShape> = vec![];
+        for node in nodes {
+            let shape = match node {
+                ShapeNode::All(count) | ShapeNode::Any(count) => {
+                    let count = usize::try_from(count)
+                        .ok()
+                        .filter(|count| *count <= shapes.len())
+                        .ok_or("composite shape node is missing operands")?;
+                    let operands = shapes.split_off(shapes.len() - count);
+                    if matches!(node, ShapeNode::All(_)) {
+                        PropertyShape::All(operands)
+                    } else {
+                        PropertyShape::Any(operands)
+                    }
+                }
+                ShapeNode::Not => {
+                    let operand = shapes.pop().ok_or("composite shape node is missing operands")?;
+                    operand.negate()
+                }
+                leaf => leaf.into_leaf().expect("operators are handled above"),
+            };
+            shapes.push(shape);
+        }
+
+        match <[PropertyShape; 1]>::try_from(shapes) {
+            Ok([shape]) => Ok(shape),
+            Err(_) => Err("composite shape nodes don't form a single shape".to_string()),
+        }
+    }
+}
+
+/// A node of a composite shape as stored on-chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ShapeNode {
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    GreaterThan(u64),
+    LowerThan(u64),
+    Between(u64, u64),
+    EqualTo(PropertyValue),
+    NotEqualTo(PropertyValue),
+    All(u64),
+    Any(u64),
+    Not,
+    MatchesPattern(String),
+}
+
+impl ShapeNode {
+    /// Creates a leaf node. `shape` must not be composite.
+    fn from_leaf(shape: PropertyShape) -> Self {
+        match shape {
+            PropertyShape::StartsWith(text) => ShapeNode::StartsWith(text),
+            PropertyShape::EndsWith(text) => ShapeNode::EndsWith(text),
+            PropertyShape::Contains(text) => ShapeNode::Contains(text),
+            PropertyShape::GreaterThan(value) => ShapeNode::GreaterThan(value),
+            PropertyShape::LowerThan(value) => ShapeNode::LowerThan(value),
+            PropertyShape::Between(min, max) => ShapeNode::Between(min, max),
+            PropertyShape::EqualTo(value) => ShapeNode::EqualTo(value),
+            PropertyShape::NotEqualTo(value) => ShapeNode::NotEqualTo(value),
+            PropertyShape::MatchesPattern(pattern) => ShapeNode::MatchesPattern(pattern),
+            PropertyShape::All(_) | PropertyShape::Any(_) | PropertyShape::Not(_) => {
+                unreachable!("composite shapes are flattened into operator nodes")
+            }
+        }
+    }
+
+    /// Returns the shape of a leaf node, `None` for operator nodes.
+    fn into_leaf(self) -> Option<PropertyShape> {
+        Some(match self {
+            ShapeNode::StartsWith(text) => PropertyShape::StartsWith(text),
+            ShapeNode::EndsWith(text) => PropertyShape::EndsWith(text),
+            ShapeNode::Contains(text) => PropertyShape::Contains(text),
+            ShapeNode::GreaterThan(value) => PropertyShape::GreaterThan(value),
+            ShapeNode::LowerThan(value) => PropertyShape::LowerThan(value),
+            ShapeNode::Between(min, max) => PropertyShape::Between(min, max),
+            ShapeNode::EqualTo(value) => PropertyShape::EqualTo(value),
+            ShapeNode::NotEqualTo(value) => PropertyShape::NotEqualTo(value),
+            ShapeNode::MatchesPattern(pattern) => PropertyShape::MatchesPattern(pattern),
+            ShapeNode::All(_) | ShapeNode::Any(_) | ShapeNode::Not => return None,
+        })
+    }
+}
+
+impl MoveType for ShapeNode {
+    fn move_type(package: ObjectID) -> TypeTag {
+        TypeTag::from_str(format!("{package}::property_shape::ShapeNode").as_str()).expect("Failed to create type tag")
+    }
+}
+
+/// The on-chain layout of a [`PropertyShape`].
+#[derive(Serialize, Deserialize)]
+enum OnChainShape {
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    GreaterThan(u64),
+    LowerThan(u64),
+    Between(u64, u64),
+    EqualTo(PropertyValue),
+    NotEqualTo(PropertyValue),
+    Composite(Vec<ShapeNode>),
+    MatchesPattern(String),
+}
+
+impl From<PropertyShape> for OnChainShape {
+    fn from(shape: PropertyShape) -> Self {
+        match shape {
+            PropertyShape::StartsWith(text) => OnChainShape::StartsWith(text),
+            PropertyShape::EndsWith(text) => OnChainShape::EndsWith(text),
+            PropertyShape::Contains(text) => OnChainShape::Contains(text),
+            PropertyShape::GreaterThan(value) => OnChainShape::GreaterThan(value),
+            PropertyShape::LowerThan(value) => OnChainShape::LowerThan(value),
+            PropertyShape::Between(min, max) => OnChainShape::Between(min, max),
+            PropertyShape::EqualTo(value) => OnChainShape::EqualTo(value),
+            PropertyShape::NotEqualTo(value) => OnChainShape::NotEqualTo(value),
+            PropertyShape::MatchesPattern(pattern) => OnChainShape::MatchesPattern(pattern),
+            composite => OnChainShape::Composite(composite.into_nodes()),
         }
     }
 }
 
+impl TryFrom<OnChainShape> for PropertyShape {
+    type Error = String;
+
+    fn try_from(shape: OnChainShape) -> Result<Self, Self::Error> {
+        Ok(match shape {
+            OnChainShape::StartsWith(text) => PropertyShape::StartsWith(text),
+            OnChainShape::EndsWith(text) => PropertyShape::EndsWith(text),
+            OnChainShape::Contains(text) => PropertyShape::Contains(text),
+            OnChainShape::GreaterThan(value) => PropertyShape::GreaterThan(value),
+            OnChainShape::LowerThan(value) => PropertyShape::LowerThan(value),
+            OnChainShape::Between(min, max) => PropertyShape::Between(min, max),
+            OnChainShape::EqualTo(value) => PropertyShape::EqualTo(value),
+            OnChainShape::NotEqualTo(value) => PropertyShape::NotEqualTo(value),
+            OnChainShape::Composite(nodes) => PropertyShape::from_nodes(nodes)?,
+            OnChainShape::MatchesPattern(pattern) => PropertyShape::MatchesPattern(pattern),
+        })
+    }
+}
+
 impl MoveType for PropertyShape {
     fn move_type(package: ObjectID) -> TypeTag {
         TypeTag::from_str(format!("{package}::property_shape::PropertyShape").as_str())
@@ -126,3 +388,239 @@ fn new_property_shape_lower_than(
     );
     Ok(condition)
 }
+
+fn new_property_shape_between(
+    min: u64,
+    max: u64,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Argument> {
+    anyhow::ensure!(
+        min <= max,
+        "invalid range: lower bound {min} is greater than upper bound {max}"
+    );
+
+    let min = ptb.pure(min)?;
+    let max = ptb.pure(max)?;
+    let condition = ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_shape").as_str().into(),
+        ident_str!("new_property_shape_between").as_str().into(),
+        vec![],
+        vec![min, max],
+    );
+    Ok(condition)
+}
+
+fn new_property_shape_equal_to(
+    value: PropertyValue,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Argument> {
+    let value = value.to_ptb(ptb, package_id)?;
+    let condition = ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_shape").as_str().into(),
+        ident_str!("new_property_shape_equal_to").as_str().into(),
+        vec![],
+        vec![value],
+    );
+    Ok(condition)
+}
+
+fn new_property_shape_not_equal_to(
+    value: PropertyValue,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Argument> {
+    let value = value.to_ptb(ptb, package_id)?;
+    let condition = ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_shape").as_str().into(),
+        ident_str!("new_property_shape_not_equal_to").as_str().into(),
+        vec![],
+        vec![value],
+    );
+    Ok(condition)
+}
+
+fn new_property_shape_matches_pattern(
+    pattern: String,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Argument> {
+    validate_pattern(&pattern)?;
+
+    let pattern = ptb.pure(pattern)?;
+    let condition = ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_shape").as_str().into(),
+        ident_str!("new_property_shape_matches_pattern").as_str().into(),
+        vec![],
+        vec![pattern],
+    );
+    Ok(condition)
+}
+
+fn new_property_shape_composite(
+    nodes: Vec<ShapeNode>,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Argument> {
+    let mut node_args = vec![];
+    for node in nodes {
+        let node_arg = match node {
+            ShapeNode::All(count) => {
+                let count = ptb.pure(count)?;
+                new_shape_node_call("new_shape_node_all", vec![count], ptb, package_id)
+            }
+            ShapeNode::Any(count) => {
+                let count = ptb.pure(count)?;
+                new_shape_node_call("new_shape_node_any", vec![count], ptb, package_id)
+            }
+            ShapeNode::Not => new_shape_node_call("new_shape_node_not", vec![], ptb, package_id),
+            leaf => {
+                let shape = leaf
+                    .into_leaf()
+                    .expect("operators are handled above")
+                    .into_ptb(ptb, package_id)?;
+                new_shape_node_call("new_shape_node", vec![shape], ptb, package_id)
+            }
+        };
+        node_args.push(node_arg);
+    }
+
+    let nodes = ptb.command(Command::new_make_move_vector(
+        Some(ShapeNode::move_type(package_id)),
+        node_args,
+    ));
+    let condition = ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_shape").as_str().into(),
+        ident_str!("new_property_shape_composite").as_str().into(),
+        vec![],
+        vec![nodes],
+    );
+    Ok(condition)
+}
+
+fn new_shape_node_call(
+    function: &'static str,
+    arguments: Vec<Argument>,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> Argument {
+    ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_shape").as_str().into(),
+        function.into(),
+        vec![],
+        arguments,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shapes_compare_first_occurrence() {
+        let text = |value: &str| PropertyValue::Text(value.to_string());
+
+        assert!(PropertyShape::EndsWith("ab".to_string()).matches(&text("cab")));
+        // The on-chain check compares the first occurrence only
+        assert!(!PropertyShape::EndsWith("ab".to_string()).matches(&text("abab")));
+        assert!(PropertyShape::Contains("b".to_string()).matches(&text("abc")));
+        assert!(!PropertyShape::Contains(String::new()).matches(&text("")));
+        assert!(!PropertyShape::GreaterThan(1).matches(&text("2")));
+        assert!(PropertyShape::LowerThan(2).matches(&PropertyValue::Number(1)));
+    }
+
+    #[test]
+    fn test_range_and_equality_shapes() {
+        let gpa = PropertyShape::Between(200, 400);
+        assert!(gpa.matches(&PropertyValue::Number(200)));
+        assert!(gpa.matches(&PropertyValue::Number(400)));
+        assert!(!gpa.matches(&PropertyValue::Number(401)));
+        assert!(!gpa.matches(&PropertyValue::Text("300".to_string())));
+        assert!(gpa.matches(&PropertyValue::Timestamp(300)));
+
+        let active = PropertyValue::Text("active".to_string());
+        assert!(PropertyShape::EqualTo(active.clone()).matches(&active));
+        assert!(!PropertyShape::EqualTo(active.clone()).matches(&PropertyValue::Number(1)));
+        assert!(PropertyShape::NotEqualTo(active.clone()).matches(&PropertyValue::Number(1)));
+        assert!(!PropertyShape::NotEqualTo(active.clone()).matches(&active));
+    }
+
+    #[test]
+    fn test_between_rejects_invalid_range() {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        assert!(
+            PropertyShape::Between(10, 5)
+                .into_ptb(&mut ptb, ObjectID::random())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_composite_shapes() {
+        let text = |value: &str| PropertyValue::Text(value.to_string());
+
+        let harvard = PropertyShape::StartsWith("HARV-".to_string()).and(PropertyShape::Contains("-".to_string()));
+        assert!(harvard.matches(&text("HARV-CS")));
+        assert!(!harvard.matches(&text("MIT-CS")));
+
+        let outside = PropertyShape::LowerThan(10).or(PropertyShape::GreaterThan(20)).negate();
+        assert!(outside.matches(&PropertyValue::Number(15)));
+        assert!(!outside.matches(&PropertyValue::Number(25)));
+
+        assert!(PropertyShape::All(vec![]).matches(&PropertyValue::Number(0)));
+        assert!(!PropertyShape::Any(vec![]).matches(&PropertyValue::Number(0)));
+    }
+
+    #[test]
+    fn test_composite_shapes_round_trip_through_on_chain_layout() {
+        let shape = PropertyShape::All(vec![
+            PropertyShape::StartsWith("HARV-".to_string()),
+            PropertyShape::Any(vec![
+                PropertyShape::Between(1, 5),
+                PropertyShape::EqualTo(PropertyValue::Number(10)),
+            ])
+            .negate(),
+        ]);
+
+        let bytes = bcs::to_bytes(&shape).unwrap();
+        assert_eq!(bcs::from_bytes::<PropertyShape>(&bytes).unwrap(), shape);
+
+        // Leaf shapes keep their original layout
+        let leaf = PropertyShape::GreaterThan(1);
+        assert_eq!(bcs::to_bytes(&leaf).unwrap(), [3, 1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_pattern_shapes() {
+        let country = PropertyShape::MatchesPattern("[A-Z][A-Z]".to_string());
+        assert!(country.matches(&PropertyValue::Text("CH".to_string())));
+        assert!(!country.matches(&PropertyValue::Text("CHE".to_string())));
+        assert!(!country.matches(&PropertyValue::Number(1)));
+
+        let invalid = PropertyShape::MatchesPattern("[A-Z".to_string()).negate();
+        assert!(invalid.validate().is_err());
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        assert!(invalid.into_ptb(&mut ptb, ObjectID::random()).is_err());
+
+        // Appended variants keep the layout of the existing ones
+        let bytes = bcs::to_bytes(&country).unwrap();
+        assert_eq!(bytes[0], 9);
+        assert_eq!(bcs::from_bytes::<PropertyShape>(&bytes).unwrap(), country);
+    }
+
+    #[test]
+    fn test_malformed_composite_nodes_are_rejected() {
+        let missing_operand = vec![ShapeNode::GreaterThan(1), ShapeNode::All(2)];
+        assert!(PropertyShape::from_nodes(missing_operand).is_err());
+
+        let dangling_result = vec![ShapeNode::GreaterThan(1), ShapeNode::LowerThan(5)];
+        assert!(PropertyShape::from_nodes(dangling_result).is_err());
+    }
+}
@@ -10,11 +10,15 @@ use iota_interaction::{MoveType, ident_str};
 use serde::{Deserialize, Serialize};
 
 /// PropertyValue represents the value of a Property
-/// It can be either a text or a number
-#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize)]
+/// It can be a text, a number, a boolean, a timestamp or bytes
+#[derive(Debug, Clone, PartialEq, Hash, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PropertyValue {
     Text(String),
     Number(u64),
+    Bool(bool),
+    /// Milliseconds since the Unix epoch
+    Timestamp(u64),
+    Bytes(Vec<u8>),
 }
 
 impl PropertyValue {
@@ -27,8 +31,57 @@ impl PropertyValue {
         match self.clone() {
             PropertyValue::Text(text) => new_property_value_string(text, ptb, package_id),
             PropertyValue::Number(number) => new_property_value_number(number, ptb, package_id),
+            PropertyValue::Bool(value) => new_property_value_call("new_property_value_bool", value, ptb, package_id),
+            PropertyValue::Timestamp(ms) => {
+                new_property_value_call("new_property_value_timestamp", ms, ptb, package_id)
+            }
+            PropertyValue::Bytes(bytes) => new_property_value_call("new_property_value_bytes", bytes, ptb, package_id),
         }
     }
+
+    /// Returns the value compared against the bounds of numeric shapes, i.e. a number or a timestamp.
+    pub fn as_ordered(&self) -> Option<u64> {
+        match self {
+            PropertyValue::Number(value) | PropertyValue::Timestamp(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        PropertyValue::Bool(value)
+    }
+}
+
+/// ValueSetMatch defines how a set of values attested for a single property is validated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ValueSetMatch {
+    /// At least one of the attested values must be accredited
+    Contains,
+    /// Every attested value must be accredited
+    #[default]
+    Superset,
+}
+
+impl ValueSetMatch {
+    /// Returns true if every value of the set must be accredited
+    pub fn require_all(&self) -> bool {
+        matches!(self, ValueSetMatch::Superset)
+    }
+}
+
+/// Converts a set of `values` to PTB arguments in ascending order.
+///
+/// Sets are hashed in a random order, sorting them makes equal sets build the same transaction.
+pub(crate) fn values_to_ptb<'a>(
+    values: impl IntoIterator<Item = &'a PropertyValue>,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Vec<Argument>> {
+    let mut values: Vec<_> = values.into_iter().collect();
+    values.sort();
+    values.into_iter().map(|value| value.to_ptb(ptb, package_id)).collect()
 }
 
 /// Creates a new move type for a Property value string
@@ -63,9 +116,62 @@ pub(crate) fn new_property_value_number(
     ))
 }
 
+/// Creates a new move type for a Property value of the other types
+fn new_property_value_call<T: Serialize>(
+    function: &'static str,
+    value: T,
+    ptb: &mut ProgrammableTransactionBuilder,
+    package_id: ObjectID,
+) -> anyhow::Result<Argument> {
+    let v = ptb.pure(value)?;
+    Ok(ptb.programmable_move_call(
+        package_id,
+        ident_str!("property_value").as_str().into(),
+        function.into(),
+        vec![],
+        vec![v],
+    ))
+}
+
 impl MoveType for PropertyValue {
     fn move_type(package: ObjectID) -> TypeTag {
         TypeTag::from_str(format!("{package}::property_value::PropertyValue").as_str())
             .expect("Failed to create type tag")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_values_keep_on_chain_layout() {
+        // Variants are appended, so existing values keep their encoding
+        assert_eq!(bcs::to_bytes(&PropertyValue::Number(1)).unwrap()[0], 1);
+        assert_eq!(bcs::to_bytes(&PropertyValue::Bool(true)).unwrap(), [2, 1]);
+        assert_eq!(bcs::to_bytes(&PropertyValue::Timestamp(1)).unwrap()[0], 3);
+        assert_eq!(bcs::to_bytes(&PropertyValue::Bytes(vec![7])).unwrap(), [4, 1, 7]);
+    }
+
+    #[test]
+    fn test_value_sets_build_reproducible_transactions() {
+        let values: Vec<_> = (0..16).map(PropertyValue::Number).collect();
+        let build = |values: std::collections::HashSet<PropertyValue>| {
+            let mut ptb = ProgrammableTransactionBuilder::new();
+            values_to_ptb(&values, &mut ptb, ObjectID::ZERO).unwrap();
+            ptb.finish()
+        };
+
+        let forward = build(values.iter().cloned().collect());
+        let backward = build(values.into_iter().rev().collect());
+
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn test_ordered_values() {
+        assert_eq!(PropertyValue::Timestamp(5).as_ordered(), Some(5));
+        assert_eq!(PropertyValue::Number(5).as_ordered(), Some(5));
+        assert_eq!(PropertyValue::from(true).as_ordered(), None);
+    }
+}
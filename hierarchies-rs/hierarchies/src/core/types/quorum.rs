@@ -0,0 +1,257 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Quorum Governance
+//!
+//! Adding, revoking or reinstating a root authority, revoking or deprecating a property, setting a
+//! value dictionary and unlinking a child federation can be configured to require the approval of
+//! several root authorities. With a [`Quorum::threshold`] above 1, the Move package rejects the
+//! direct calls and the actions go through a [`Proposal`]:
+//!
+//! 1. A root authority proposes a [`GovernanceAction`] with
+//!    [`ProposeAction`](crate::core::transactions::ProposeAction), approving it at the same time.
+//! 2. Other root authorities approve it with [`ApproveAction`](crate::core::transactions::ApproveAction).
+//! 3. Once approved by `threshold` active root authorities, any root authority executes it with
+//!    [`ExecuteAction`](crate::core::transactions::ExecuteAction).
+//!
+//! [`Quorum::status`] tells where an open proposal stands without calling the node.
+//!
+//! While a quorum is required, capabilities are only transferred with a [`TransferConfirmation`]
+//! of a root authority other than the holder, see
+//! [`TransferCapability`](crate::core::transactions::TransferCapability).
+
+use std::collections::{HashMap, HashSet};
+
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use iota_interaction::types::transaction::Argument;
+use iota_interaction::{MoveType, ident_str};
+use serde::{Deserialize, Serialize};
+
+use crate::core::types::move_names;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::{PropertyValue, values_to_ptb};
+use crate::utils::{self, deserialize_vec_map, deserialize_vec_set};
+
+/// The number of root authority approvals required for destructive actions, and the open proposals.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct Quorum {
+    /// Approvals required to execute a proposal; with 1 every root authority acts alone
+    pub threshold: u64,
+    /// Open proposals by their ID
+    #[serde(deserialize_with = "deserialize_vec_map")]
+    pub proposals: HashMap<u64, Proposal>,
+    /// The ID of the next proposal
+    pub next_proposal_id: u64,
+    /// Confirmed capability transfers by capability ID
+    #[serde(deserialize_with = "deserialize_vec_map")]
+    pub transfer_confirmations: HashMap<ObjectID, TransferConfirmation>,
+}
+
+impl Default for Quorum {
+    fn default() -> Self {
+        Self {
+            threshold: 1,
+            proposals: HashMap::new(),
+            next_proposal_id: 0,
+            transfer_confirmations: HashMap::new(),
+        }
+    }
+}
+
+impl Quorum {
+    /// Returns true if destructive actions have to be proposed and approved.
+    ///
+    /// Mirrors the on-chain `is_quorum_required`.
+    pub fn is_required(&self) -> bool {
+        self.threshold > 1
+    }
+
+    /// Returns the status of the proposal `proposal_id`, counting the approvals of the
+    /// `active_root_authorities` only.
+    pub fn status(&self, proposal_id: u64, active_root_authorities: &[ObjectID]) -> ProposalStatus {
+        let Some(proposal) = self.proposals.get(&proposal_id) else {
+            return ProposalStatus::Closed;
+        };
+
+        let approvals = proposal.active_approvals(active_root_authorities);
+        if approvals >= self.threshold {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Pending {
+                approvals,
+                threshold: self.threshold,
+            }
+        }
+    }
+}
+
+/// A destructive action subject to the quorum of a federation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GovernanceAction {
+    /// Revokes a root authority
+    RevokeRootAuthority { account_id: ObjectID },
+    /// Revokes a property at `valid_to_ms`, or when the proposal is executed if none
+    RevokeProperty {
+        property_name: PropertyName,
+        valid_to_ms: Option<u64>,
+    },
+    /// Changes the quorum threshold
+    SetQuorumThreshold { threshold: u64 },
+    /// Adds a root authority
+    AddRootAuthority { account_id: ObjectID },
+    /// Reinstates a revoked root authority
+    ReinstateRootAuthority { account_id: ObjectID },
+    /// Creates or replaces a value dictionary
+    SetValueDictionary {
+        name: String,
+        #[serde(deserialize_with = "deserialize_vec_set")]
+        values: HashSet<PropertyValue>,
+    },
+    /// Deprecates a property, optionally naming its replacement
+    DeprecateProperty {
+        property_name: PropertyName,
+        replacement: Option<PropertyName>,
+    },
+    /// Stops recognizing a child federation
+    UnlinkFederation { child_federation_id: ObjectID },
+}
+
+impl GovernanceAction {
+    /// Creates the Move value of the action.
+    pub(crate) fn to_ptb(
+        &self,
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectID,
+    ) -> anyhow::Result<Argument> {
+        let (function, arguments) = match self {
+            Self::RevokeRootAuthority { account_id } => {
+                ("new_action_revoke_root_authority", vec![ptb.pure(account_id)?])
+            }
+            Self::RevokeProperty {
+                property_name,
+                valid_to_ms,
+            } => (
+                "new_action_revoke_property",
+                vec![property_name.to_ptb(ptb, package_id)?, ptb.pure(valid_to_ms)?],
+            ),
+            Self::SetQuorumThreshold { threshold } => ("new_action_set_quorum_threshold", vec![ptb.pure(threshold)?]),
+            Self::AddRootAuthority { account_id } => ("new_action_add_root_authority", vec![ptb.pure(account_id)?]),
+            Self::ReinstateRootAuthority { account_id } => {
+                ("new_action_reinstate_root_authority", vec![ptb.pure(account_id)?])
+            }
+            Self::SetValueDictionary { name, values } => {
+                let name = ptb.pure(name)?;
+                let values = values_to_ptb(values, ptb, package_id)?;
+                let values = utils::create_vec_set_from_move_values(
+                    values,
+                    PropertyValue::move_type(package_id),
+                    ptb,
+                    package_id,
+                );
+                ("new_action_set_value_dictionary", vec![name, values])
+            }
+            Self::DeprecateProperty {
+                property_name,
+                replacement,
+            } => {
+                let property_name = property_name.to_ptb(ptb, package_id)?;
+                let replacement = replacement
+                    .as_ref()
+                    .map(|name| name.to_ptb(ptb, package_id))
+                    .transpose()?;
+                let replacement = utils::option_to_move(replacement, PropertyName::move_type(package_id), ptb)?;
+                ("new_action_deprecate_property", vec![property_name, replacement])
+            }
+            Self::UnlinkFederation { child_federation_id } => {
+                ("new_action_unlink_federation", vec![ptb.pure(child_federation_id)?])
+            }
+        };
+
+        Ok(ptb.programmable_move_call(
+            package_id,
+            ident_str!(move_names::MODULE_MAIN).as_str().into(),
+            function.into(),
+            vec![],
+            arguments,
+        ))
+    }
+}
+
+/// A proposed [`GovernanceAction`] and the root authorities that approved it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct Proposal {
+    pub action: GovernanceAction,
+    pub proposed_by: ObjectID,
+    #[serde(deserialize_with = "deserialize_vec_set")]
+    pub approvals: HashSet<ObjectID>,
+}
+
+impl Proposal {
+    /// Returns the number of approvals by the `active_root_authorities`.
+    ///
+    /// Mirrors the on-chain count: approvals of root authorities revoked since don't count.
+    pub fn active_approvals(&self, active_root_authorities: &[ObjectID]) -> u64 {
+        self.approvals
+            .iter()
+            .filter(|approver| active_root_authorities.contains(approver))
+            .count() as u64
+    }
+}
+
+/// The confirmation of a capability transfer by a root authority other than the holder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
+pub struct TransferConfirmation {
+    /// The account the capability may be transferred to
+    pub recipient: IotaAddress,
+    /// The root authority that confirmed the transfer
+    pub confirmed_by: ObjectID,
+}
+
+/// Where a proposal stands in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    /// More approvals are needed before the proposal can be executed
+    Pending { approvals: u64, threshold: u64 },
+    /// The proposal has enough approvals and can be executed
+    Approved,
+    /// The proposal was executed or doesn't exist
+    Closed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proposal_status_counts_active_approvals() {
+        let [alice, bob, charlie] = [ObjectID::random(), ObjectID::random(), ObjectID::random()];
+        let proposal = Proposal {
+            action: GovernanceAction::RevokeRootAuthority { account_id: charlie },
+            proposed_by: alice,
+            approvals: [alice, bob].into(),
+        };
+        let quorum = Quorum {
+            threshold: 2,
+            proposals: [(0, proposal)].into(),
+            next_proposal_id: 1,
+            transfer_confirmations: HashMap::new(),
+        };
+
+        assert!(quorum.is_required());
+        assert_eq!(quorum.status(0, &[alice, bob, charlie]), ProposalStatus::Approved);
+        // Bob was revoked after approving
+        assert_eq!(
+            quorum.status(0, &[alice, charlie]),
+            ProposalStatus::Pending {
+                approvals: 1,
+                threshold: 2
+            }
+        );
+        assert_eq!(quorum.status(1, &[alice, bob]), ProposalStatus::Closed);
+        assert!(!Quorum::default().is_required());
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reasons recorded with revocations.
+//!
+//! Revocations of properties, accreditations and root authorities can record why they were made.
+//! The reason is emitted as a [`RevocationReasonEvent`](super::events::RevocationReasonEvent) by the
+//! revoking transaction and shows up next to the revocation in the
+//! [audit log](crate::client::history::FederationHistory::revocation_reason).
+//!
+//! The reason codes are not interpreted on chain; [`RevocationReason`] defines codes for common
+//! cases, other values are free for application-specific use.
+
+use serde::{Deserialize, Serialize};
+
+/// Why a revocation was made.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationReason {
+    /// The reason code
+    pub code: u64,
+    /// A free-text annotation of at most [`Self::MAX_ANNOTATION_LENGTH`] bytes
+    pub annotation: String,
+}
+
+impl RevocationReason {
+    /// No reason code given.
+    pub const UNSPECIFIED: u64 = 0;
+    /// The key of the revoked party was compromised.
+    pub const KEY_COMPROMISE: u64 = 1;
+    /// The revoked party misbehaved.
+    pub const MISCONDUCT: u64 = 2;
+    /// The revoked right is replaced by a new one.
+    pub const SUPERSEDED: u64 = 3;
+    /// The attested subject was recalled, e.g. a product batch.
+    pub const RECALL: u64 = 4;
+
+    /// Maximum length of the annotation in bytes.
+    pub const MAX_ANNOTATION_LENGTH: usize = 256;
+
+    /// Creates a reason with `code` and `annotation`.
+    pub fn new(code: u64, annotation: impl Into<String>) -> Self {
+        Self {
+            code,
+            annotation: annotation.into(),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Selective Disclosure
+//!
+//! Keeps attested values off the public ledger while letting their holder reveal them one by one.
+//!
+//! Instead of a raw value, the [`DisclosureDigest`] of a [`Disclosure`] is attested: the SHA3-256
+//! hash of a random salt, the property name and the value. The issuer hands the disclosures to the
+//! holder, who later presents a [`DisclosureProof`] with the subset of values to reveal. A verifier
+//! recomputes the digests and compares them with the attested ones; values that aren't disclosed
+//! stay hidden, and the salt prevents guessing them from their digest.
+//!
+//! Issuers need an accreditation for the digests: grant them [`disclosure_property`] to attest any
+//! digest, or [`blinded_grant`] to accredit a single one.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! // issuer
+//! let (blinded, disclosures) = selective_disclosure::blind([(gpa, PropertyValue::Number(38))]);
+//! let attestation = client
+//!     .create_attestation(federation_id, holder, blinded, Timespan::default())
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output;
+//!
+//! // holder
+//! let proof = DisclosureProof::select(attestation.id(), &disclosures, [&gpa]);
+//!
+//! // verifier
+//! let attestation = client.get_attestation(proof.attestation_id).await?;
+//! let revealed = proof.verify(&attestation)?;
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use iota_interaction::types::base_types::ObjectID;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+use crate::core::error::DisclosureError;
+use crate::core::types::Accreditation;
+use crate::core::types::attestation::Attestation;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_shape::PropertyShape;
+use crate::core::types::property_value::PropertyValue;
+
+/// The prefix of disclosure digests, naming the hash function.
+pub const DISCLOSURE_DIGEST_PREFIX: &str = "sd-sha3-256:";
+
+/// The digest of a [`Disclosure`], attested in place of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisclosureDigest([u8; 32]);
+
+impl DisclosureDigest {
+    /// Returns the digest bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the property value the digest is attested as.
+    pub fn to_property_value(&self) -> PropertyValue {
+        PropertyValue::Text(self.to_string())
+    }
+
+    /// Returns the digest attested as `value`, if it is a disclosure digest.
+    pub fn from_property_value(value: &PropertyValue) -> Option<Self> {
+        match value {
+            PropertyValue::Text(text) => text.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DisclosureDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(DISCLOSURE_DIGEST_PREFIX)?;
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl FromStr for DisclosureDigest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix(DISCLOSURE_DIGEST_PREFIX)
+            .ok_or_else(|| format!("disclosure digest must start with `{DISCLOSURE_DIGEST_PREFIX}`"))?;
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err("disclosure digest must have 64 hex digits".to_string());
+        }
+
+        let mut bytes = [0; 32];
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).expect("ascii");
+            *byte = u8::from_str_radix(digits, 16).map_err(|e| format!("invalid hex digits `{digits}`: {e}"))?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+/// A property value together with the salt blinding it.
+///
+/// Disclosures are kept by the holder and must not be published, as they reveal the value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Disclosure {
+    pub property_name: PropertyName,
+    pub value: PropertyValue,
+    pub salt: [u8; 32],
+}
+
+impl Disclosure {
+    /// Blinds `value` with a random salt.
+    pub fn new(property_name: PropertyName, value: PropertyValue) -> Self {
+        let mut salt = [0; 32];
+        getrandom::getrandom(&mut salt).expect("the system provides randomness");
+
+        Self::with_salt(property_name, value, salt)
+    }
+
+    /// Blinds `value` with `salt`, which must be random and kept secret.
+    pub fn with_salt(property_name: PropertyName, value: PropertyValue, salt: [u8; 32]) -> Self {
+        Self {
+            property_name,
+            value,
+            salt,
+        }
+    }
+
+    /// Returns the digest attested in place of the value.
+    pub fn digest(&self) -> DisclosureDigest {
+        let mut hasher = Sha3_256::new();
+        hasher.update(self.salt);
+        hasher.update(bcs::to_bytes(&self.property_name).expect("property names serialize"));
+        hasher.update(bcs::to_bytes(&self.value).expect("property values serialize"));
+
+        DisclosureDigest(hasher.finalize().into())
+    }
+
+    /// Returns true if the disclosure hashes to the attested `value`.
+    pub fn matches(&self, value: &PropertyValue) -> bool {
+        DisclosureDigest::from_property_value(value) == Some(self.digest())
+    }
+}
+
+/// Blinds `properties` for an attestation.
+///
+/// Returns the properties to attest, which hold the digests instead of the values, and the
+/// disclosures to hand to the holder.
+pub fn blind(
+    properties: impl IntoIterator<Item = (PropertyName, PropertyValue)>,
+) -> (HashMap<PropertyName, PropertyValue>, Vec<Disclosure>) {
+    let disclosures: Vec<Disclosure> = properties
+        .into_iter()
+        .map(|(property_name, value)| Disclosure::new(property_name, value))
+        .collect();
+    let blinded = disclosures
+        .iter()
+        .map(|disclosure| {
+            (
+                disclosure.property_name.clone(),
+                disclosure.digest().to_property_value(),
+            )
+        })
+        .collect();
+
+    (blinded, disclosures)
+}
+
+/// Returns the definition of a property holding disclosure digests.
+///
+/// Its shape admits any disclosure digest, so accreditors can grant it to issuers of blinded
+/// attestations.
+pub fn disclosure_property(name: impl Into<PropertyName>) -> FederationProperty {
+    FederationProperty::new(name).with_expression(PropertyShape::StartsWith(DISCLOSURE_DIGEST_PREFIX.to_string()))
+}
+
+/// Returns the grant accrediting the blinded value of `disclosure`.
+pub fn blinded_grant(disclosure: &Disclosure) -> FederationProperty {
+    FederationProperty::new(disclosure.property_name.clone())
+        .with_allowed_values([disclosure.digest().to_property_value()])
+}
+
+/// The values of an attestation its holder chose to reveal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisclosureProof {
+    /// The attestation the values were attested in
+    pub attestation_id: ObjectID,
+    pub disclosures: Vec<Disclosure>,
+}
+
+impl DisclosureProof {
+    /// Creates a proof revealing `disclosures`.
+    pub fn new(attestation_id: ObjectID, disclosures: impl IntoIterator<Item = Disclosure>) -> Self {
+        Self {
+            attestation_id,
+            disclosures: disclosures.into_iter().collect(),
+        }
+    }
+
+    /// Creates a proof revealing the values of the properties named `reveal` only.
+    pub fn select<'a>(
+        attestation_id: ObjectID,
+        disclosures: &[Disclosure],
+        reveal: impl IntoIterator<Item = &'a PropertyName>,
+    ) -> Self {
+        let reveal: Vec<&PropertyName> = reveal.into_iter().collect();
+        Self::new(
+            attestation_id,
+            disclosures
+                .iter()
+                .filter(|disclosure| reveal.contains(&&disclosure.property_name))
+                .cloned(),
+        )
+    }
+
+    /// Verifies the disclosed values against the digests attested in `attestation`.
+    ///
+    /// Returns the revealed values. Whether the attestation itself is still valid is checked with
+    /// [`validate_attestation`](crate::client::HierarchiesClientReadOnly::validate_attestation).
+    pub fn verify(&self, attestation: &Attestation) -> Result<HashMap<PropertyName, PropertyValue>, DisclosureError> {
+        if attestation.id() != self.attestation_id {
+            return Err(DisclosureError::WrongAttestation {
+                expected: self.attestation_id.to_string(),
+                actual: attestation.id().to_string(),
+            });
+        }
+
+        self.reveal(|disclosure| {
+            let attested = attestation
+                .properties
+                .get(&disclosure.property_name)
+                .ok_or_else(|| not_attested(disclosure))?;
+            Ok(disclosure.matches(attested))
+        })
+    }
+
+    /// Verifies the disclosed values against the digests granted in `accreditation`.
+    ///
+    /// Returns the revealed values. The proof's attestation ID is not checked.
+    pub fn verify_accreditation(
+        &self,
+        accreditation: &Accreditation,
+    ) -> Result<HashMap<PropertyName, PropertyValue>, DisclosureError> {
+        self.reveal(|disclosure| {
+            let granted = accreditation
+                .properties
+                .get(&disclosure.property_name)
+                .ok_or_else(|| not_attested(disclosure))?;
+            Ok(granted
+                .allowed_values
+                .contains(&disclosure.digest().to_property_value()))
+        })
+    }
+
+    fn reveal(
+        &self,
+        matches: impl Fn(&Disclosure) -> Result<bool, DisclosureError>,
+    ) -> Result<HashMap<PropertyName, PropertyValue>, DisclosureError> {
+        self.disclosures
+            .iter()
+            .map(|disclosure| {
+                if !matches(disclosure)? {
+                    return Err(DisclosureError::DigestMismatch {
+                        property: disclosure.property_name.names().join("."),
+                    });
+                }
+                Ok((disclosure.property_name.clone(), disclosure.value.clone()))
+            })
+            .collect()
+    }
+}
+
+fn not_attested(disclosure: &Disclosure) -> DisclosureError {
+    DisclosureError::PropertyNotAttested {
+        property: disclosure.property_name.names().join("."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::id::UID;
+
+    use super::*;
+    use crate::core::types::timespan::Timespan;
+
+    fn attestation(properties: HashMap<PropertyName, PropertyValue>) -> Attestation {
+        Attestation {
+            id: UID::new(ObjectID::random()),
+            federation_id: ObjectID::random(),
+            issuer: ObjectID::random(),
+            properties,
+            timespan: Timespan::default(),
+            issued_at_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_disclose_single_value() {
+        let gpa = PropertyName::from("student.gpa");
+        let degree = PropertyName::from("student.degree");
+        let (blinded, disclosures) = blind([
+            (gpa.clone(), PropertyValue::Number(38)),
+            (degree.clone(), PropertyValue::Text("MSc".to_string())),
+        ]);
+        assert!(
+            blinded
+                .values()
+                .all(|value| disclosure_property("x").shape.unwrap().matches(value))
+        );
+
+        let attestation = attestation(blinded);
+        let proof = DisclosureProof::select(attestation.id(), &disclosures, [&gpa]);
+
+        let revealed = proof.verify(&attestation).unwrap();
+        assert_eq!(revealed, HashMap::from([(gpa, PropertyValue::Number(38))]));
+    }
+
+    #[test]
+    fn test_tampered_value_is_rejected() {
+        let gpa = PropertyName::from("student.gpa");
+        let (blinded, mut disclosures) = blind([(gpa.clone(), PropertyValue::Number(38))]);
+        let attestation = attestation(blinded);
+
+        disclosures[0].value = PropertyValue::Number(40);
+        let proof = DisclosureProof::new(attestation.id(), disclosures.clone());
+        assert!(matches!(
+            proof.verify(&attestation),
+            Err(DisclosureError::DigestMismatch { .. })
+        ));
+
+        let proof = DisclosureProof::new(ObjectID::random(), disclosures);
+        assert!(matches!(
+            proof.verify(&attestation),
+            Err(DisclosureError::WrongAttestation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_digest_round_trip() {
+        let disclosure = Disclosure::with_salt(PropertyName::from("a"), PropertyValue::Bool(true), [7; 32]);
+        let value = disclosure.digest().to_property_value();
+
+        assert_eq!(DisclosureDigest::from_property_value(&value), Some(disclosure.digest()));
+        assert!(disclosure.matches(&value));
+        assert_ne!(
+            Disclosure::with_salt(PropertyName::from("a"), PropertyValue::Bool(true), [8; 32]).digest(),
+            disclosure.digest()
+        );
+        assert!("sd-sha3-256:zz".parse::<DisclosureDigest>().is_err());
+    }
+}
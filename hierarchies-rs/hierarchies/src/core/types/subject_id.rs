@@ -0,0 +1,236 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Subject Identifiers
+//!
+//! Entities of a federation are identified by the [`ObjectID`] of their account. Identity-centric
+//! integrations can use the IOTA DID of an entity instead, e.g. `did:iota:testnet:0x…`.
+//!
+//! The method-specific identifier of an IOTA DID is the object ID of the DID document, so a DID
+//! resolves to an object ID without a network round-trip. The network of the DID is checked against
+//! the network of the client; a DID without network segment belongs to the IOTA Mainnet.
+
+use std::fmt;
+use std::str::FromStr;
+
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use thiserror::Error;
+
+/// The DID method of IOTA DIDs.
+pub const IOTA_DID_METHOD: &str = "iota";
+/// The network of IOTA DIDs without network segment.
+pub const IOTA_DID_DEFAULT_NETWORK: &str = "iota";
+
+/// Errors that can occur when parsing or resolving a [`SubjectId`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum SubjectIdError {
+    /// The identifier is neither an object ID nor an IOTA DID.
+    #[error("'{input}' is not a valid object ID or IOTA DID: {reason}")]
+    Invalid { input: String, reason: String },
+    /// The DID belongs to another network than the client.
+    #[error("DID '{did}' belongs to network '{did_network}', but the client is connected to '{network}'")]
+    NetworkMismatch {
+        did: String,
+        did_network: String,
+        network: String,
+    },
+}
+
+/// An IOTA DID, e.g. `did:iota:0x…` or `did:iota:testnet:0x…`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IotaDid {
+    network: Option<String>,
+    object_id: ObjectID,
+}
+
+impl IotaDid {
+    /// Creates the DID of the DID document `object_id` on `network`.
+    ///
+    /// The Mainnet is represented without network segment.
+    pub fn new(network: &str, object_id: ObjectID) -> Self {
+        Self {
+            network: (network != IOTA_DID_DEFAULT_NETWORK).then(|| network.to_string()),
+            object_id,
+        }
+    }
+
+    /// The network the DID belongs to.
+    pub fn network(&self) -> &str {
+        self.network.as_deref().unwrap_or(IOTA_DID_DEFAULT_NETWORK)
+    }
+
+    /// The object ID of the DID document.
+    pub fn object_id(&self) -> ObjectID {
+        self.object_id
+    }
+}
+
+impl FromStr for IotaDid {
+    type Err = SubjectIdError;
+
+    fn from_str(did: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| SubjectIdError::Invalid {
+            input: did.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let segments: Vec<&str> = did.split(':').collect();
+        let (network, tag) = match segments.as_slice() {
+            ["did", IOTA_DID_METHOD, tag] => (None, *tag),
+            ["did", IOTA_DID_METHOD, network, tag] => (Some(*network), *tag),
+            ["did", IOTA_DID_METHOD, ..] => return Err(invalid("expected `did:iota:[network:]tag`")),
+            _ => return Err(invalid("not an IOTA DID")),
+        };
+        if network.is_some_and(|network| {
+            network.is_empty() || !network.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        }) {
+            return Err(invalid("the network must consist of lowercase letters and digits"));
+        }
+        let object_id = ObjectID::from_str(tag).map_err(|_| invalid("the tag is not an object ID"))?;
+
+        Ok(Self::new(network.unwrap_or(IOTA_DID_DEFAULT_NETWORK), object_id))
+    }
+}
+
+impl fmt::Display for IotaDid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.network {
+            Some(network) => write!(f, "did:{IOTA_DID_METHOD}:{network}:{}", self.object_id),
+            None => write!(f, "did:{IOTA_DID_METHOD}:{}", self.object_id),
+        }
+    }
+}
+
+/// Identifies an entity of a federation, either by object ID or by IOTA DID.
+///
+/// Every API accepting a `SubjectId` also accepts a plain [`ObjectID`] or [`IotaAddress`].
+///
+/// ```
+/// use hierarchies::core::types::subject_id::SubjectId;
+///
+/// let subject: SubjectId = "did:iota:testnet:0x1".parse().unwrap();
+/// assert_eq!(subject.resolve("testnet").unwrap(), "0x1".parse().unwrap());
+/// assert!(subject.resolve("iota").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubjectId {
+    /// The object ID of the entity.
+    Object(ObjectID),
+    /// The IOTA DID of the entity.
+    Did(IotaDid),
+}
+
+impl SubjectId {
+    /// Resolves the subject to the object ID used on-chain, for a client connected to `network`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubjectIdError::NetworkMismatch`] if the subject is a DID of another network.
+    pub fn resolve(&self, network: &str) -> Result<ObjectID, SubjectIdError> {
+        match self {
+            Self::Object(object_id) => Ok(*object_id),
+            Self::Did(did) if did.network() == network => Ok(did.object_id()),
+            Self::Did(did) => Err(SubjectIdError::NetworkMismatch {
+                did: did.to_string(),
+                did_network: did.network().to_string(),
+                network: network.to_string(),
+            }),
+        }
+    }
+
+    /// The object ID of the subject, without checking the network of a DID.
+    pub fn object_id(&self) -> ObjectID {
+        match self {
+            Self::Object(object_id) => *object_id,
+            Self::Did(did) => did.object_id(),
+        }
+    }
+}
+
+impl From<ObjectID> for SubjectId {
+    fn from(object_id: ObjectID) -> Self {
+        Self::Object(object_id)
+    }
+}
+
+impl From<IotaAddress> for SubjectId {
+    fn from(address: IotaAddress) -> Self {
+        Self::Object(address.into())
+    }
+}
+
+impl From<IotaDid> for SubjectId {
+    fn from(did: IotaDid) -> Self {
+        Self::Did(did)
+    }
+}
+
+impl FromStr for SubjectId {
+    type Err = SubjectIdError;
+
+    /// Parses a DID if the input starts with `did:`, an object ID otherwise.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.starts_with("did:") {
+            return input.parse().map(Self::Did);
+        }
+
+        ObjectID::from_str(input)
+            .map(Self::Object)
+            .map_err(|_| SubjectIdError::Invalid {
+                input: input.to_string(),
+                reason: "not an object ID".to_string(),
+            })
+    }
+}
+
+impl fmt::Display for SubjectId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(object_id) => object_id.fmt(f),
+            Self::Did(did) => did.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_did() {
+        let object_id = ObjectID::random();
+
+        let mainnet: IotaDid = format!("did:iota:{object_id}").parse().unwrap();
+        assert_eq!(mainnet.network(), IOTA_DID_DEFAULT_NETWORK);
+        assert_eq!(mainnet.object_id(), object_id);
+        assert_eq!(mainnet.to_string(), format!("did:iota:{object_id}"));
+
+        let testnet: IotaDid = format!("did:iota:testnet:{object_id}").parse().unwrap();
+        assert_eq!(testnet.network(), "testnet");
+        assert_eq!(testnet.to_string(), format!("did:iota:testnet:{object_id}"));
+        assert_eq!(IotaDid::new("iota", object_id), mainnet);
+
+        assert!("did:web:example.com".parse::<IotaDid>().is_err());
+        assert!("did:iota:TestNet:0x1".parse::<IotaDid>().is_err());
+        assert!("did:iota:testnet:alice".parse::<IotaDid>().is_err());
+    }
+
+    #[test]
+    fn test_resolve_subject() {
+        let object_id = ObjectID::random();
+
+        assert_eq!(SubjectId::from(object_id).resolve("testnet"), Ok(object_id));
+
+        let did = SubjectId::from(IotaDid::new("testnet", object_id));
+        assert_eq!(did.resolve("testnet"), Ok(object_id));
+        assert!(matches!(
+            did.resolve("iota"),
+            Err(SubjectIdError::NetworkMismatch { did_network, .. }) if did_network == "testnet"
+        ));
+        assert_eq!(
+            object_id.to_string().parse::<SubjectId>(),
+            Ok(SubjectId::Object(object_id))
+        );
+    }
+}
@@ -4,11 +4,115 @@
 //! # Hierarchies Timespan
 //!
 //! This module provides a struct for representing a timespan.
+//!
+//! On-chain timestamps are milliseconds since the Unix epoch. The builder methods accept
+//! [`chrono`] dates and [`Duration`]s and convert them, so user code doesn't have to:
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use chrono::{TimeZone, Utc};
+//! use hierarchies::core::types::timespan::Timespan;
+//!
+//! let timespan = Timespan::default()
+//!     .valid_from(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+//!     .valid_for(Duration::from_secs(30 * 24 * 60 * 60));
+//! assert_eq!(timespan.valid_from_ms, Some(1_735_689_600_000));
+//! assert_eq!(timespan.valid_until_ms, Some(1_738_281_600_000));
+//! ```
+
+use std::time::Duration;
 
+use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Default, Deserialize)]
+#[cfg_attr(feature = "strict-decode", serde(deny_unknown_fields))]
 pub struct Timespan {
     pub valid_from_ms: Option<u64>,
     pub valid_until_ms: Option<u64>,
 }
+
+impl Timespan {
+    /// Returns true if `now_ms` falls within the timespan.
+    ///
+    /// Mirrors the on-chain `timestamp_matches` check: the lower bound is inclusive and the upper bound is
+    /// exclusive.
+    pub fn timestamp_matches(&self, now_ms: u64) -> bool {
+        self.valid_from_ms.is_none_or(|from| from <= now_ms) && self.valid_until_ms.is_none_or(|until| now_ms < until)
+    }
+
+    /// Sets the inclusive start of the timespan.
+    ///
+    /// Dates before the Unix epoch are clamped to it.
+    pub fn valid_from<Tz: TimeZone>(mut self, from: DateTime<Tz>) -> Self {
+        self.valid_from_ms = Some(to_ms(&from));
+        self
+    }
+
+    /// Sets the exclusive end of the timespan.
+    ///
+    /// Dates before the Unix epoch are clamped to it.
+    pub fn valid_until<Tz: TimeZone>(mut self, until: DateTime<Tz>) -> Self {
+        self.valid_until_ms = Some(to_ms(&until));
+        self
+    }
+
+    /// Ends the timespan `duration` after its start, or after the current system time if it has
+    /// no start.
+    pub fn valid_for(mut self, duration: Duration) -> Self {
+        let from_ms = self.valid_from_ms.unwrap_or_else(|| to_ms(&Utc::now()));
+        let duration_ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        self.valid_until_ms = Some(from_ms.saturating_add(duration_ms));
+        self
+    }
+
+    /// Returns the start of the timespan as a date, if any.
+    pub fn valid_from_date(&self) -> Option<DateTime<Utc>> {
+        self.valid_from_ms.and_then(from_ms)
+    }
+
+    /// Returns the end of the timespan as a date, if any.
+    pub fn valid_until_date(&self) -> Option<DateTime<Utc>> {
+        self.valid_until_ms.and_then(from_ms)
+    }
+}
+
+fn to_ms<Tz: TimeZone>(date: &DateTime<Tz>) -> u64 {
+    u64::try_from(date.timestamp_millis()).unwrap_or(0)
+}
+
+fn from_ms(ms: u64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(i64::try_from(ms).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::FixedOffset;
+
+    use super::*;
+
+    #[test]
+    fn test_dates_convert_to_ms() {
+        let from = FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2025, 6, 1, 2, 0, 0)
+            .unwrap();
+        let timespan = Timespan::default()
+            .valid_from(from)
+            .valid_for(Duration::from_millis(1500));
+
+        assert_eq!(timespan.valid_from_ms, Some(1_748_736_000_000));
+        assert_eq!(timespan.valid_until_ms, Some(1_748_736_001_500));
+        assert_eq!(timespan.valid_from_date(), Some(from.to_utc()));
+        assert!(timespan.timestamp_matches(1_748_736_001_499));
+        assert!(!timespan.timestamp_matches(1_748_736_001_500));
+    }
+
+    #[test]
+    fn test_dates_before_epoch_are_clamped() {
+        let timespan = Timespan::default().valid_until(Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap());
+
+        assert_eq!(timespan.valid_until_ms, Some(0));
+    }
+}
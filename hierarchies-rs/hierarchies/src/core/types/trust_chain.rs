@@ -0,0 +1,373 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Hierarchies Trust Chains
+//!
+//! This module resolves the delegation path through which an entity holds a property.
+//!
+//! Every accreditation records the address that granted it. Following these issuers through the
+//! accreditations to accredit of a [`Federation`] leads back to a root authority; the resulting
+//! [`TrustChain`] lists each delegation step with the constraints and timespan it was granted with.
+//!
+//! A federation may recognize child federations, e.g. a global consortium recognizing the
+//! federation operated by its European branch. A [`LinkedTrustChain`] crosses these links: an
+//! entity accredited in a recognized federation holds the property in the parent as well.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use iota_interaction::types::base_types::ObjectID;
+
+use crate::core::types::accreditation_query::AccreditationScope;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::{Accreditation, Accreditations, Federation};
+
+/// A single delegation step of a [`TrustChain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustLink {
+    /// The entity that granted the accreditation.
+    pub issuer: ObjectID,
+    /// The entity holding the accreditation.
+    pub entity_id: ObjectID,
+    /// The scope the accreditation was granted for.
+    pub scope: AccreditationScope,
+    /// The ID of the accreditation.
+    pub accreditation_id: ObjectID,
+    /// The granted property, with its allowed values, shape and timespan.
+    pub property: FederationProperty,
+}
+
+/// The delegation path from a root authority to an entity for a single property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustChain {
+    /// The root authority the chain starts at.
+    pub root_authority: ObjectID,
+    /// The delegation steps, ordered from the root authority to the entity.
+    ///
+    /// Empty if the entity is a root authority itself.
+    pub links: Vec<TrustLink>,
+}
+
+impl TrustChain {
+    /// Resolves the shortest delegation path through which `entity_id` holds `property_name`.
+    ///
+    /// The entity's accreditations to attest are followed first, then its accreditations to accredit.
+    /// Every further step follows the issuer's accreditations to accredit for the same property until an
+    /// active root authority is reached. Timespans are not evaluated; they are reported in the links.
+    ///
+    /// Returns `None` if no path leads to a root authority.
+    pub fn resolve(federation: &Federation, entity_id: ObjectID, property_name: &PropertyName) -> Option<Self> {
+        if is_root_authority(federation, entity_id) {
+            return Some(Self {
+                root_authority: entity_id,
+                links: vec![],
+            });
+        }
+
+        let governance = &federation.governance;
+        // Paths are built from the entity upwards and reversed once a root authority is found
+        let mut queue: VecDeque<Vec<TrustLink>> = [
+            (AccreditationScope::Attest, &governance.accreditations_to_attest),
+            (AccreditationScope::Accredit, &governance.accreditations_to_accredit),
+        ]
+        .into_iter()
+        .flat_map(|(scope, by_entity)| links(by_entity.get(&entity_id), entity_id, scope, property_name))
+        .map(|link| vec![link])
+        .collect();
+        let mut visited = HashSet::from([entity_id]);
+
+        while let Some(path) = queue.pop_front() {
+            let issuer = path.last().expect("paths are never empty").issuer;
+            if is_root_authority(federation, issuer) {
+                let mut links = path;
+                links.reverse();
+                return Some(Self {
+                    root_authority: issuer,
+                    links,
+                });
+            }
+            if !visited.insert(issuer) {
+                continue;
+            }
+
+            let accreditations = governance.accreditations_to_accredit.get(&issuer);
+            for link in links(accreditations, issuer, AccreditationScope::Accredit, property_name) {
+                let mut next = path.clone();
+                next.push(link);
+                queue.push_back(next);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the entities along the chain, starting with the root authority.
+    pub fn entities(&self) -> impl Iterator<Item = ObjectID> + '_ {
+        std::iter::once(self.root_authority).chain(self.links.iter().map(|link| link.entity_id))
+    }
+
+    /// Returns the number of delegation steps.
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Returns true if the chain has no delegation steps.
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+}
+
+impl fmt::Display for TrustChain {
+    /// Formats the chain as `root → entity → ...`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entities: Vec<String> = self.entities().map(|entity| entity.to_string()).collect();
+        write!(f, "{}", entities.join(" → "))
+    }
+}
+
+/// A [`TrustChain`] resolved in a federation recognized, directly or through further links, by the
+/// federation it was queried in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedTrustChain {
+    /// The federations from the queried one to the one the chain was resolved in, each
+    /// recognizing the next.
+    ///
+    /// Contains only the queried federation if the chain doesn't cross a link.
+    pub federations: Vec<ObjectID>,
+    /// The delegation path within the last federation.
+    pub chain: TrustChain,
+}
+
+impl LinkedTrustChain {
+    /// Maximum number of links followed from the queried federation.
+    pub const MAX_LINK_DEPTH: usize = 4;
+
+    /// Resolves the trust chain of `entity_id` for `property_name` in `federation_id` or one of
+    /// the federations it recognizes.
+    ///
+    /// The queried federation is tried first, then the recognized federations breadth-first, up
+    /// to [`Self::MAX_LINK_DEPTH`] links away, so the chain crossing the fewest links is returned.
+    /// Federations missing from `federations` are skipped.
+    ///
+    /// Returns `None` if no federation has a path to one of its root authorities.
+    pub fn resolve(
+        federations: &HashMap<ObjectID, Federation>,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        property_name: &PropertyName,
+    ) -> Option<Self> {
+        let mut queue = VecDeque::from([vec![federation_id]]);
+        let mut visited = HashSet::from([federation_id]);
+
+        while let Some(path) = queue.pop_front() {
+            let current = *path.last().expect("paths are never empty");
+            let Some(federation) = federations.get(&current) else {
+                continue;
+            };
+            if let Some(chain) = TrustChain::resolve(federation, entity_id, property_name) {
+                return Some(Self {
+                    federations: path,
+                    chain,
+                });
+            }
+            if path.len() > Self::MAX_LINK_DEPTH {
+                continue;
+            }
+
+            for child in &federation.governance.recognized_federations {
+                if visited.insert(*child) {
+                    let mut next = path.clone();
+                    next.push(*child);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns true if the chain crosses at least one federation link.
+    pub fn is_linked(&self) -> bool {
+        self.federations.len() > 1
+    }
+}
+
+/// Returns the links granting `property_name` to `entity_id` among `accreditations`.
+fn links<'a>(
+    accreditations: Option<&'a Accreditations>,
+    entity_id: ObjectID,
+    scope: AccreditationScope,
+    property_name: &'a PropertyName,
+) -> impl Iterator<Item = TrustLink> + 'a {
+    accreditations
+        .into_iter()
+        .flat_map(|accreditations| accreditations.iter())
+        .filter_map(move |accreditation| {
+            let property = accreditation.find_property(property_name)?;
+            Some(TrustLink {
+                issuer: issuer(accreditation)?,
+                entity_id,
+                scope,
+                accreditation_id: *accreditation.id.object_id(),
+                property: property.clone(),
+            })
+        })
+}
+
+/// Parses the address recorded as the issuer of `accreditation`.
+fn issuer(accreditation: &Accreditation) -> Option<ObjectID> {
+    ObjectID::from_str(&accreditation.accredited_by).ok()
+}
+
+fn is_root_authority(federation: &Federation, entity_id: ObjectID) -> bool {
+    federation
+        .root_authorities
+        .iter()
+        .any(|authority| authority.account_id == entity_id)
+        && !federation.revoked_root_authorities.contains(&entity_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use iota_interaction::types::id::UID;
+
+    use super::*;
+    use crate::core::types::grant_policy::GrantPolicy;
+    use crate::core::types::property::FederationProperties;
+    use crate::core::types::{FederationStats, Governance, RootAuthority};
+
+    fn accreditation(issuer: ObjectID, property: FederationProperty) -> Accreditation {
+        Accreditation {
+            id: UID::new(ObjectID::random()),
+            accredited_by: issuer.to_string(),
+            properties: HashMap::from([(property.name.clone(), property)]),
+            pending_until_ms: None,
+        }
+    }
+
+    fn federation(
+        root: ObjectID,
+        to_accredit: Vec<(ObjectID, Accreditation)>,
+        to_attest: Vec<(ObjectID, Accreditation)>,
+    ) -> Federation {
+        let by_entity = |grants: Vec<(ObjectID, Accreditation)>| {
+            grants
+                .into_iter()
+                .fold(HashMap::new(), |mut map, (entity, accreditation)| {
+                    map.entry(entity)
+                        .or_insert_with(|| Accreditations::new(vec![]))
+                        .accreditations
+                        .push(accreditation);
+                    map
+                })
+        };
+
+        Federation {
+            id: UID::new(ObjectID::random()),
+            governance: Governance {
+                id: UID::new(ObjectID::random()),
+                properties: FederationProperties { data: HashMap::new() },
+                accreditations_to_accredit: by_entity(to_accredit),
+                accreditations_to_attest: by_entity(to_attest),
+                critical_properties: HashSet::new(),
+                value_dictionaries: HashMap::new(),
+                grant_policy: GrantPolicy::default(),
+                property_tags: HashMap::new(),
+                accreditation_tags: HashMap::new(),
+                quorum: Default::default(),
+                recognized_federations: HashSet::new(),
+                deprecated_properties: HashMap::new(),
+            },
+            root_authorities: vec![RootAuthority {
+                id: UID::new(ObjectID::random()),
+                account_id: root,
+            }],
+            revoked_root_authorities: vec![],
+            stats: FederationStats::default(),
+            metadata: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_trust_chain() {
+        let degree = FederationProperty::new("degree").with_allow_any(true);
+        let other = FederationProperty::new("other").with_allow_any(true);
+        let [root, university, faculty, registrar, stranger] = std::array::from_fn(|_| ObjectID::random());
+
+        let federation = federation(
+            root,
+            vec![
+                (university, accreditation(root, degree.clone())),
+                (faculty, accreditation(university, degree.clone())),
+                // A cycle not leading to the root authority
+                (stranger, accreditation(faculty, other.clone())),
+                (faculty, accreditation(stranger, other.clone())),
+            ],
+            vec![
+                (registrar, accreditation(faculty, degree.clone())),
+                (registrar, accreditation(stranger, other.clone())),
+            ],
+        );
+
+        let chain = TrustChain::resolve(&federation, registrar, &degree.name).unwrap();
+        assert_eq!(chain.root_authority, root);
+        assert_eq!(
+            chain.entities().collect::<Vec<_>>(),
+            vec![root, university, faculty, registrar]
+        );
+        assert_eq!(chain.links[0].issuer, root);
+        assert_eq!(chain.links[2].scope, AccreditationScope::Attest);
+        assert_eq!(chain.links[2].property, degree);
+        assert_eq!(
+            chain.to_string(),
+            format!("{root} → {university} → {faculty} → {registrar}")
+        );
+
+        assert_eq!(TrustChain::resolve(&federation, registrar, &other.name), None);
+        assert_eq!(TrustChain::resolve(&federation, stranger, &degree.name), None);
+        assert!(TrustChain::resolve(&federation, root, &degree.name).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_linked_trust_chain() {
+        let degree = FederationProperty::new("degree").with_allow_any(true);
+        let [global_root, europe_root, registrar, stranger] = std::array::from_fn(|_| ObjectID::random());
+
+        let mut global = federation(global_root, vec![], vec![]);
+        let europe = federation(
+            europe_root,
+            vec![],
+            vec![(registrar, accreditation(europe_root, degree.clone()))],
+        );
+        let global_id = *global.id.object_id();
+        let europe_id = *europe.id.object_id();
+        // The child links back to the parent, which must not loop
+        let mut europe_linked = europe.clone();
+        europe_linked.governance.recognized_federations.insert(global_id);
+
+        let unlinked = HashMap::from([(global_id, global.clone()), (europe_id, europe_linked.clone())]);
+        assert_eq!(
+            LinkedTrustChain::resolve(&unlinked, global_id, registrar, &degree.name),
+            None
+        );
+
+        global.governance.recognized_federations.insert(europe_id);
+        let federations = HashMap::from([(global_id, global), (europe_id, europe_linked)]);
+
+        let linked = LinkedTrustChain::resolve(&federations, global_id, registrar, &degree.name).unwrap();
+        assert!(linked.is_linked());
+        assert_eq!(linked.federations, vec![global_id, europe_id]);
+        assert_eq!(linked.chain.root_authority, europe_root);
+
+        let direct = LinkedTrustChain::resolve(&federations, europe_id, registrar, &degree.name).unwrap();
+        assert!(!direct.is_linked());
+        assert_eq!(
+            LinkedTrustChain::resolve(&federations, global_id, stranger, &degree.name),
+            None
+        );
+    }
+}
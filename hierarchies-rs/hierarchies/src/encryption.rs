@@ -0,0 +1,388 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Property Value Encryption
+//!
+//! Envelope encryption of property values exchanged alongside hierarchies data, available with the
+//! `encryption` feature.
+//!
+//! A value is encrypted once with a random content key (AES-256-GCM), which is wrapped for each
+//! recipient with a key agreed over X25519. The recipients are usually the attesters accredited for
+//! the property, see [`Recipients::accredited_for`]; their public keys are distributed out of band.
+//!
+//! Every [`EncryptedValue`] carries the [`commitment`](EncryptedValue::commitment) of its value: the
+//! digest of a [`Disclosure`], which can be attested in place of the value. Validators work against
+//! the commitment, while recipients decrypt the disclosure and check it against the attested digest
+//! with a [`DisclosureProof`](crate::core::types::selective_disclosure::DisclosureProof).
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let federation = client.get_federation_by_id(federation_id).await?;
+//! let recipients = Recipients::accredited_for(&federation, &diagnosis, &directory, now_ms);
+//! let encrypted = EncryptedValue::encrypt(diagnosis, PropertyValue::Text("J45".into()), &recipients)?;
+//!
+//! // a recipient
+//! let disclosure = encrypted.decrypt(my_id, &my_secret_key)?;
+//! ```
+
+use std::collections::HashMap;
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use hkdf::Hkdf;
+use iota_interaction::types::base_types::ObjectID;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+use x25519_dalek::StaticSecret;
+
+use crate::core::types::Federation;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::selective_disclosure::{Disclosure, DisclosureDigest};
+
+const KEY_WRAP_INFO: &[u8] = b"hierarchies-encryption-v1";
+
+/// Errors of encrypting and decrypting property values
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum EncryptionError {
+    /// The value would be readable by nobody
+    #[error("encrypted values need at least one recipient")]
+    NoRecipients,
+
+    /// The content key isn't wrapped for the entity
+    #[error("{recipient} is not a recipient of the encrypted value")]
+    NotARecipient { recipient: ObjectID },
+
+    /// The key doesn't match or the ciphertext was tampered with
+    #[error("failed to decrypt the {what}")]
+    DecryptionFailed { what: &'static str },
+
+    /// The decrypted value doesn't match the commitment
+    #[error("decrypted value of property '{property}' doesn't match its commitment")]
+    CommitmentMismatch { property: String },
+
+    /// A key agreement with a low-order public key
+    #[error("public key of {recipient} is not a valid X25519 key")]
+    InvalidPublicKey { recipient: ObjectID },
+}
+
+/// The X25519 secret key of a recipient.
+#[derive(Clone)]
+pub struct SecretKey(StaticSecret);
+
+impl SecretKey {
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        Self::from_bytes(random())
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(StaticSecret::from(bytes))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Returns the public key to hand to senders.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(x25519_dalek::PublicKey::from(&self.0).to_bytes())
+    }
+}
+
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretKey(..)")
+    }
+}
+
+/// The X25519 public key of a recipient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PublicKey([u8; 32]);
+
+impl PublicKey {
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// The entities an encrypted value is readable by, with their public keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recipients(HashMap<ObjectID, PublicKey>);
+
+impl Recipients {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `recipient` with `public_key`.
+    pub fn with(mut self, recipient: ObjectID, public_key: PublicKey) -> Self {
+        self.0.insert(recipient, public_key);
+        self
+    }
+
+    /// Returns the attesters of `federation` accredited for `property_name` at `at_ms`.
+    ///
+    /// The public keys are looked up in `directory`; accredited entities without a key are
+    /// skipped and won't be able to read the value.
+    pub fn accredited_for(
+        federation: &Federation,
+        property_name: &PropertyName,
+        directory: &HashMap<ObjectID, PublicKey>,
+        at_ms: u64,
+    ) -> Self {
+        let recipients = federation
+            .governance
+            .accreditations_to_attest
+            .iter()
+            .filter(|(_, accreditations)| {
+                accreditations.iter().any(|accreditation| {
+                    !accreditation.is_pending(at_ms) && accreditation.find_property(property_name).is_some()
+                })
+            })
+            .filter_map(|(entity, _)| directory.get(entity).map(|key| (*entity, *key)))
+            .collect();
+
+        Self(recipients)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&ObjectID, &PublicKey)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The content key of an [`EncryptedValue`], wrapped for one recipient.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrappedKey {
+    pub ephemeral_public_key: [u8; 32],
+    pub nonce: [u8; 12],
+    pub ciphertext: Vec<u8>,
+}
+
+/// A property value encrypted for a set of recipients.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedValue {
+    pub property_name: PropertyName,
+    /// The digest of the value's disclosure, see [`EncryptedValue::commitment`]
+    pub commitment: PropertyValue,
+    pub nonce: [u8; 12],
+    pub ciphertext: Vec<u8>,
+    pub recipients: HashMap<ObjectID, WrappedKey>,
+}
+
+impl EncryptedValue {
+    /// Encrypts `value` for `recipients`.
+    pub fn encrypt(
+        property_name: PropertyName,
+        value: PropertyValue,
+        recipients: &Recipients,
+    ) -> Result<Self, EncryptionError> {
+        if recipients.is_empty() {
+            return Err(EncryptionError::NoRecipients);
+        }
+
+        let disclosure = Disclosure::new(property_name.clone(), value);
+        let commitment = disclosure.digest().to_property_value();
+        let content_key: [u8; 32] = random();
+        let nonce: [u8; 12] = random();
+
+        let plaintext = bcs::to_bytes(&disclosure).expect("disclosures serialize");
+        let ciphertext = seal(
+            &content_key,
+            &nonce,
+            &plaintext,
+            &content_aad(&property_name, &commitment),
+        );
+
+        let recipients = recipients
+            .iter()
+            .map(|(recipient, public_key)| Ok((*recipient, wrap_key(&content_key, *recipient, public_key)?)))
+            .collect::<Result<_, EncryptionError>>()?;
+
+        Ok(Self {
+            property_name,
+            commitment,
+            nonce,
+            ciphertext,
+            recipients,
+        })
+    }
+
+    /// Returns the commitment to attest in place of the value.
+    ///
+    /// Accredit attesters for it with
+    /// [`disclosure_property`](crate::core::types::selective_disclosure::disclosure_property).
+    pub fn commitment(&self) -> &PropertyValue {
+        &self.commitment
+    }
+
+    /// Decrypts the value as `recipient`.
+    ///
+    /// Returns the disclosure of the value, which is checked against the commitment.
+    pub fn decrypt(&self, recipient: ObjectID, secret_key: &SecretKey) -> Result<Disclosure, EncryptionError> {
+        let wrapped = self
+            .recipients
+            .get(&recipient)
+            .ok_or(EncryptionError::NotARecipient { recipient })?;
+        let content_key = unwrap_key(wrapped, recipient, secret_key)?;
+
+        let plaintext = open(
+            &content_key,
+            &self.nonce,
+            &self.ciphertext,
+            &content_aad(&self.property_name, &self.commitment),
+        )
+        .ok_or(EncryptionError::DecryptionFailed { what: "value" })?;
+        let disclosure: Disclosure =
+            bcs::from_bytes(&plaintext).map_err(|_| EncryptionError::DecryptionFailed { what: "value" })?;
+
+        if disclosure.property_name != self.property_name || !disclosure.matches(&self.commitment) {
+            return Err(EncryptionError::CommitmentMismatch {
+                property: self.property_name.names().join("."),
+            });
+        }
+        Ok(disclosure)
+    }
+
+    /// Returns the digest committed to, if the commitment is well-formed.
+    pub fn commitment_digest(&self) -> Option<DisclosureDigest> {
+        DisclosureDigest::from_property_value(&self.commitment)
+    }
+}
+
+fn wrap_key(
+    content_key: &[u8; 32],
+    recipient: ObjectID,
+    public_key: &PublicKey,
+) -> Result<WrappedKey, EncryptionError> {
+    let ephemeral = StaticSecret::from(random::<32>());
+    let ephemeral_public_key = x25519_dalek::PublicKey::from(&ephemeral).to_bytes();
+    let shared = ephemeral.diffie_hellman(&x25519_dalek::PublicKey::from(public_key.0));
+    if !shared.was_contributory() {
+        return Err(EncryptionError::InvalidPublicKey { recipient });
+    }
+
+    let key_encryption_key = derive_key(shared.as_bytes(), &ephemeral_public_key, &public_key.0);
+    let nonce: [u8; 12] = random();
+    let ciphertext = seal(&key_encryption_key, &nonce, content_key, recipient.as_ref());
+
+    Ok(WrappedKey {
+        ephemeral_public_key,
+        nonce,
+        ciphertext,
+    })
+}
+
+fn unwrap_key(wrapped: &WrappedKey, recipient: ObjectID, secret_key: &SecretKey) -> Result<[u8; 32], EncryptionError> {
+    let public_key = secret_key.public_key();
+    let shared = secret_key
+        .0
+        .diffie_hellman(&x25519_dalek::PublicKey::from(wrapped.ephemeral_public_key));
+
+    let key_encryption_key = derive_key(shared.as_bytes(), &wrapped.ephemeral_public_key, &public_key.0);
+    open(
+        &key_encryption_key,
+        &wrapped.nonce,
+        &wrapped.ciphertext,
+        recipient.as_ref(),
+    )
+    .and_then(|content_key| content_key.try_into().ok())
+    .ok_or(EncryptionError::DecryptionFailed { what: "content key" })
+}
+
+fn derive_key(shared_secret: &[u8; 32], ephemeral_public_key: &[u8; 32], recipient_public_key: &[u8; 32]) -> [u8; 32] {
+    let salt = [ephemeral_public_key.as_slice(), recipient_public_key.as_slice()].concat();
+    let mut key = [0; 32];
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret)
+        .expand(KEY_WRAP_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+fn content_aad(property_name: &PropertyName, commitment: &PropertyValue) -> Vec<u8> {
+    let mut aad = bcs::to_bytes(property_name).expect("property names serialize");
+    aad.extend(bcs::to_bytes(commitment).expect("property values serialize"));
+    aad
+}
+
+fn seal(key: &[u8; 32], nonce: &[u8; 12], msg: &[u8], aad: &[u8]) -> Vec<u8> {
+    Aes256Gcm::new(key.into())
+        .encrypt(Nonce::from_slice(nonce), Payload { msg, aad })
+        .expect("AES-GCM encryption of in-memory buffers doesn't fail")
+}
+
+fn open(key: &[u8; 32], nonce: &[u8; 12], msg: &[u8], aad: &[u8]) -> Option<Vec<u8>> {
+    Aes256Gcm::new(key.into())
+        .decrypt(Nonce::from_slice(nonce), Payload { msg, aad })
+        .ok()
+}
+
+fn random<const N: usize>() -> [u8; N] {
+    let mut bytes = [0; N];
+    getrandom::getrandom(&mut bytes).expect("the system provides randomness");
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_for_recipients() {
+        let alice = (ObjectID::random(), SecretKey::generate());
+        let bob = (ObjectID::random(), SecretKey::generate());
+        let recipients = Recipients::new()
+            .with(alice.0, alice.1.public_key())
+            .with(bob.0, bob.1.public_key());
+
+        let name = PropertyName::from("patient.diagnosis");
+        let value = PropertyValue::Text("J45".to_string());
+        let encrypted = EncryptedValue::encrypt(name.clone(), value.clone(), &recipients).unwrap();
+
+        for (id, key) in [&alice, &bob] {
+            let disclosure = encrypted.decrypt(*id, key).unwrap();
+            assert_eq!(disclosure.value, value);
+            assert!(disclosure.matches(encrypted.commitment()));
+        }
+
+        assert!(matches!(
+            encrypted.decrypt(ObjectID::random(), &alice.1),
+            Err(EncryptionError::NotARecipient { .. })
+        ));
+        assert!(matches!(
+            encrypted.decrypt(alice.0, &bob.1),
+            Err(EncryptionError::DecryptionFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_is_rejected() {
+        let recipient = (ObjectID::random(), SecretKey::generate());
+        let recipients = Recipients::new().with(recipient.0, recipient.1.public_key());
+        let mut encrypted =
+            EncryptedValue::encrypt(PropertyName::from("a"), PropertyValue::Number(1), &recipients).unwrap();
+
+        encrypted.commitment = PropertyValue::Number(2);
+        assert!(encrypted.decrypt(recipient.0, &recipient.1).is_err());
+
+        assert!(matches!(
+            EncryptedValue::encrypt(PropertyName::from("a"), PropertyValue::Number(1), &Recipients::new()),
+            Err(EncryptionError::NoRecipients)
+        ));
+    }
+}
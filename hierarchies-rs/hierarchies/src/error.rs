@@ -25,6 +25,7 @@
 //!
 //! ### Transaction Errors
 //! - [`TransactionError`] - Transaction building and execution
+//! - [`MoveAbortMapper`] - Translates Move abort codes of failed transactions into [`TransactionError`]s
 
 #[cfg(target_arch = "wasm32")]
 use product_common::impl_wasm_error_from;
@@ -32,8 +33,9 @@ use thiserror::Error;
 
 // Client errors
 pub use crate::client::ClientError;
+use crate::core::diagnostics::BcsDiagnostic;
 // Transaction errors
-pub use crate::core::transactions::TransactionError;
+pub use crate::core::transactions::{MoveAbort, MoveAbortMapper, TransactionError};
 // Core operation errors
 pub use crate::core::{CapabilityError, OperationError};
 use crate::iota_interaction_adapter::AdapterError;
@@ -63,6 +65,32 @@ pub enum ConfigError {
     /// Invalid configuration field
     #[error("invalid configuration: {field}")]
     Invalid { field: String },
+
+    /// Invalid value of an environment variable
+    #[error("invalid environment variable {variable}: {reason}")]
+    InvalidEnv { variable: String, reason: String },
+
+    /// The package registry overrides are malformed
+    #[error("invalid package registry: {reason}")]
+    InvalidPackageRegistry { reason: String },
+
+    /// The package deployed at the configured ID does not match the pinned digest
+    #[error("package integrity check failed for {package_id}: expected digest {expected}, got {actual}")]
+    PackageIntegrityMismatch {
+        package_id: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// The package deployed at the configured ID is of an older schema than an operation requires
+    #[error(
+        "package {package_id} has schema version {actual}, the operation requires version {required}; see `hierarchies::compat`"
+    )]
+    IncompatibleSchema {
+        package_id: String,
+        required: u32,
+        actual: u64,
+    },
 }
 
 /// Object lookup and retrieval errors
@@ -83,6 +111,14 @@ pub enum ObjectError {
     /// Object has wrong type
     #[error("wrong object type: expected {expected}, got {actual}")]
     WrongType { expected: String, actual: String },
+
+    /// The object content does not match the layout of the Rust type
+    #[error("failed to decode object {id}")]
+    Decoding {
+        id: String,
+        #[source]
+        diagnostic: Box<BcsDiagnostic>,
+    },
 }
 
 // Convert AdapterError to NetworkError
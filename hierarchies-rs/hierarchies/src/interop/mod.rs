@@ -0,0 +1,6 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions of Hierarchies data into formats of other ecosystems.
+
+pub mod vc;
@@ -0,0 +1,369 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # W3C Verifiable Credentials
+//!
+//! Converts an [`Accreditation`] into a [W3C Verifiable Credential](https://www.w3.org/TR/vc-data-model-2.0/)
+//! in JSON-LD.
+//!
+//! The federation is the issuer and the accreditor is recorded in its metadata. The holder of the
+//! accreditation is the credential subject, with one claim per accredited property. A resolved
+//! [`TrustChain`] is attached as evidence, so a verifier can follow the delegation path without
+//! querying the federation.
+//!
+//! The credential is not secured; attach a proof, e.g. a Data Integrity proof or a JWT envelope, before
+//! handing it out.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let chain = client.resolve_trust_chain(federation_id, holder_id, &property_name).await?;
+//! let credential = AccreditationCredential::new(federation_id, holder_id, AccreditationScope::Attest, &accreditation)
+//!     .with_trust_chain(chain.as_ref())
+//!     .build();
+//!
+//! println!("{}", credential.to_json_ld());
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SecondsFormat};
+use iota_interaction::types::base_types::ObjectID;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::types::Accreditation;
+use crate::core::types::accreditation_query::AccreditationScope;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::trust_chain::{TrustChain, TrustLink};
+
+/// The JSON-LD context of the W3C Verifiable Credentials Data Model v2.0.
+pub const CREDENTIALS_V2_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+/// The credential type of converted accreditations.
+pub const ACCREDITATION_CREDENTIAL_TYPE: &str = "HierarchiesAccreditationCredential";
+/// The evidence type of attached trust chains.
+pub const TRUST_CHAIN_EVIDENCE_TYPE: &str = "HierarchiesTrustChain";
+
+/// Returns the URN identifying a federation object.
+pub fn federation_urn(federation_id: ObjectID) -> String {
+    format!("urn:iota:hierarchies:federation:{federation_id}")
+}
+
+/// Returns the URN identifying an entity of a federation.
+pub fn entity_urn(entity_id: ObjectID) -> String {
+    format!("urn:iota:hierarchies:entity:{entity_id}")
+}
+
+/// Returns the URN identifying an accreditation.
+pub fn accreditation_urn(accreditation_id: ObjectID) -> String {
+    format!("urn:iota:hierarchies:accreditation:{accreditation_id}")
+}
+
+/// A W3C Verifiable Credential describing an accreditation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiableCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    pub issuer: CredentialIssuer,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<String>,
+    pub credential_subject: CredentialSubject,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<TrustChainEvidence>,
+}
+
+impl VerifiableCredential {
+    /// Returns the credential as a JSON-LD document.
+    pub fn to_json_ld(&self) -> Value {
+        serde_json::to_value(self).expect("credentials only hold JSON compatible values")
+    }
+}
+
+/// The issuer of a [`VerifiableCredential`]: the federation the accreditation was granted in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialIssuer {
+    /// The URN of the federation.
+    pub id: String,
+    /// The entity that granted the accreditation, as recorded on-chain.
+    pub accredited_by: String,
+}
+
+/// The holder of the accreditation and the properties it was accredited for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialSubject {
+    /// The URN of the entity holding the accreditation.
+    pub id: String,
+    /// The permission the accreditation grants, `attest` or `accredit`.
+    pub scope: String,
+    /// The accredited properties, keyed by their dot-separated name.
+    pub claims: BTreeMap<String, PropertyClaim>,
+}
+
+/// A claim about a single accredited property.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyClaim {
+    /// The values the holder may attest or accredit.
+    pub allowed_values: Vec<Value>,
+    /// True if any value is allowed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_any: bool,
+    /// The federation value dictionary the values are restricted to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_dictionary: Option<String>,
+    /// True if the values are further restricted by a property shape.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub restricted_by_shape: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<String>,
+}
+
+/// The delegation path through which the holder obtained the accreditation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustChainEvidence {
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    /// The URN of the root authority the chain starts at.
+    pub root_authority: String,
+    /// The delegation steps, ordered from the root authority to the holder.
+    pub links: Vec<TrustChainLink>,
+}
+
+/// A single delegation step of a [`TrustChainEvidence`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustChainLink {
+    pub issuer: String,
+    pub subject: String,
+    pub scope: String,
+    pub accreditation: String,
+    pub property: String,
+    pub claim: PropertyClaim,
+}
+
+/// A builder converting an accreditation into a [`VerifiableCredential`].
+#[derive(Debug, Clone)]
+pub struct AccreditationCredential<'a> {
+    federation_id: ObjectID,
+    holder_id: ObjectID,
+    scope: AccreditationScope,
+    accreditation: &'a Accreditation,
+    trust_chain: Option<&'a TrustChain>,
+}
+
+impl<'a> AccreditationCredential<'a> {
+    /// Describes `accreditation`, held by `holder_id` for `scope` in the federation `federation_id`.
+    pub fn new(
+        federation_id: ObjectID,
+        holder_id: ObjectID,
+        scope: AccreditationScope,
+        accreditation: &'a Accreditation,
+    ) -> Self {
+        Self {
+            federation_id,
+            holder_id,
+            scope,
+            accreditation,
+            trust_chain: None,
+        }
+    }
+
+    /// Attaches the trust chain of the accreditation as evidence.
+    pub fn with_trust_chain(mut self, trust_chain: Option<&'a TrustChain>) -> Self {
+        self.trust_chain = trust_chain;
+        self
+    }
+
+    /// Builds the credential.
+    ///
+    /// The credential is valid from the end of the objection window, if any. It is valid until the
+    /// last property expires, unless a property is valid indefinitely.
+    pub fn build(self) -> VerifiableCredential {
+        let accreditation = self.accreditation;
+        let claims = accreditation
+            .properties
+            .iter()
+            .map(|(name, property)| (claim_name(name), PropertyClaim::from(property)))
+            .collect();
+        let valid_until = accreditation
+            .properties
+            .values()
+            .map(|property| property.timespan.valid_until_ms)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|ends| ends.into_iter().max());
+
+        VerifiableCredential {
+            context: vec![CREDENTIALS_V2_CONTEXT.to_string()],
+            id: accreditation_urn(*accreditation.id.object_id()),
+            types: vec![
+                "VerifiableCredential".to_string(),
+                ACCREDITATION_CREDENTIAL_TYPE.to_string(),
+            ],
+            issuer: CredentialIssuer {
+                id: federation_urn(self.federation_id),
+                accredited_by: accreditation.accredited_by.clone(),
+            },
+            valid_from: accreditation.pending_until_ms.map(format_timestamp),
+            valid_until: valid_until.map(format_timestamp),
+            credential_subject: CredentialSubject {
+                id: entity_urn(self.holder_id),
+                scope: scope_name(self.scope).to_string(),
+                claims,
+            },
+            evidence: self.trust_chain.map(TrustChainEvidence::from).into_iter().collect(),
+        }
+    }
+}
+
+impl From<&FederationProperty> for PropertyClaim {
+    fn from(property: &FederationProperty) -> Self {
+        let mut allowed_values: Vec<Value> = property.allowed_values.iter().map(claim_value).collect();
+        // Allowed values are a set, sort them for a stable document
+        allowed_values.sort_by_key(Value::to_string);
+
+        Self {
+            allowed_values,
+            allow_any: property.allow_any,
+            value_dictionary: property.value_dictionary.clone(),
+            restricted_by_shape: property.shape.is_some(),
+            valid_from: property.timespan.valid_from_ms.map(format_timestamp),
+            valid_until: property.timespan.valid_until_ms.map(format_timestamp),
+        }
+    }
+}
+
+impl From<&TrustChain> for TrustChainEvidence {
+    fn from(chain: &TrustChain) -> Self {
+        Self {
+            types: vec![TRUST_CHAIN_EVIDENCE_TYPE.to_string()],
+            root_authority: entity_urn(chain.root_authority),
+            links: chain.links.iter().map(TrustChainLink::from).collect(),
+        }
+    }
+}
+
+impl From<&TrustLink> for TrustChainLink {
+    fn from(link: &TrustLink) -> Self {
+        Self {
+            issuer: entity_urn(link.issuer),
+            subject: entity_urn(link.entity_id),
+            scope: scope_name(link.scope).to_string(),
+            accreditation: accreditation_urn(link.accreditation_id),
+            property: claim_name(&link.property.name),
+            claim: PropertyClaim::from(&link.property),
+        }
+    }
+}
+
+fn claim_name(name: &PropertyName) -> String {
+    name.names().join(".")
+}
+
+fn scope_name(scope: AccreditationScope) -> &'static str {
+    match scope {
+        AccreditationScope::Attest => "attest",
+        AccreditationScope::Accredit => "accredit",
+    }
+}
+
+/// Converts a property value into its JSON form. Timestamps become date-times and bytes hex strings.
+fn claim_value(value: &PropertyValue) -> Value {
+    match value {
+        PropertyValue::Text(text) => Value::from(text.as_str()),
+        PropertyValue::Number(number) => Value::from(*number),
+        PropertyValue::Bool(flag) => Value::from(*flag),
+        PropertyValue::Timestamp(timestamp_ms) => Value::from(format_timestamp(*timestamp_ms)),
+        PropertyValue::Bytes(bytes) => {
+            let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+            Value::from(format!("0x{hex}"))
+        }
+    }
+}
+
+/// Formats milliseconds since the Unix epoch as an XML Schema date-time.
+fn format_timestamp(timestamp_ms: u64) -> String {
+    i64::try_from(timestamp_ms)
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .unwrap_or(DateTime::<chrono::Utc>::MAX_UTC)
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::id::UID;
+
+    use super::*;
+    use crate::core::types::timespan::Timespan;
+
+    #[test]
+    fn test_accreditation_to_credential() {
+        let property = FederationProperty::new(PropertyName::new(["degree", "level"]))
+            .with_allowed_values([PropertyValue::Text("msc".into()), PropertyValue::Text("bsc".into())])
+            .with_timespan(Timespan {
+                valid_from_ms: None,
+                valid_until_ms: Some(1_767_225_600_000),
+            });
+        let root = ObjectID::random();
+        let accreditation = Accreditation {
+            id: UID::new(ObjectID::random()),
+            accredited_by: root.to_string(),
+            properties: [(property.name.clone(), property.clone())].into(),
+            pending_until_ms: None,
+        };
+        let holder = ObjectID::random();
+        let chain = TrustChain {
+            root_authority: root,
+            links: vec![TrustLink {
+                issuer: root,
+                entity_id: holder,
+                scope: AccreditationScope::Attest,
+                accreditation_id: *accreditation.id.object_id(),
+                property,
+            }],
+        };
+
+        let credential =
+            AccreditationCredential::new(ObjectID::ZERO, holder, AccreditationScope::Attest, &accreditation)
+                .with_trust_chain(Some(&chain))
+                .build()
+                .to_json_ld();
+
+        assert_eq!(credential["@context"][0], CREDENTIALS_V2_CONTEXT);
+        assert_eq!(credential["issuer"]["id"], federation_urn(ObjectID::ZERO));
+        assert_eq!(credential["credentialSubject"]["id"], entity_urn(holder));
+        assert_eq!(
+            credential["credentialSubject"]["claims"]["degree.level"]["allowedValues"],
+            serde_json::json!(["bsc", "msc"])
+        );
+        assert_eq!(credential["validUntil"], "2026-01-01T00:00:00.000Z");
+        assert!(credential.get("validFrom").is_none());
+        assert_eq!(credential["evidence"][0]["links"][0]["subject"], entity_urn(holder));
+    }
+
+    #[test]
+    fn test_claim_values() {
+        assert_eq!(claim_value(&PropertyValue::Number(7)), serde_json::json!(7));
+        assert_eq!(
+            claim_value(&PropertyValue::Timestamp(0)),
+            serde_json::json!("1970-01-01T00:00:00.000Z")
+        );
+        assert_eq!(
+            claim_value(&PropertyValue::Bytes(vec![0xca, 0xfe])),
+            serde_json::json!("0xcafe")
+        );
+    }
+}
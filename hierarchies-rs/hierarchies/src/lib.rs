@@ -17,13 +17,30 @@
 //! More information about Hierarchies can be found in the [Hierarchies documentation](https://github.com/iotaledger/hierarchies).
 
 pub mod client;
+pub mod compat;
 pub mod core;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod error;
+pub mod interop;
 mod iota_interaction_adapter;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod package;
+#[cfg(feature = "kms")]
+pub mod signer;
+pub mod templates;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(all(feature = "testkit", not(target_arch = "wasm32")))]
+pub mod testkit;
 mod utils;
 
 #[cfg(feature = "gas-station")]
 pub mod http_client {
     pub use product_common::http_client::*;
 }
+#[cfg(feature = "gas-station")]
+pub mod gas_station {
+    pub use product_common::gas_station::*;
+}
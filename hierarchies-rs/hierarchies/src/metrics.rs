@@ -0,0 +1,175 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prometheus metrics of the clients.
+//!
+//! With the `metrics` feature, the clients record their activity into process-wide collectors.
+//! Services expose them by registering the collectors with the [`Registry`] they serve:
+//!
+//! ```rust,ignore
+//! let registry = prometheus::Registry::new();
+//! hierarchies::metrics::register(&registry)?;
+//!
+//! client
+//!     .add_property(federation_id, property)
+//!     .build_and_execute_metered(&client)
+//!     .await?;
+//! ```
+//!
+//! The following metrics are recorded:
+//!
+//! - `hierarchies_transactions_submitted_total`: transactions executed with
+//!   [`MeteredExecution::build_and_execute_metered`], labelled with their `outcome`
+//! - `hierarchies_validation_duration_seconds`: latency of the validation queries of
+//!   [`HierarchiesClientReadOnly`](crate::client::HierarchiesClientReadOnly)
+//! - `hierarchies_rpc_retries_total`: attempts repeated by a [`RetryPolicy`](crate::client::RetryPolicy)
+//! - `hierarchies_ptb_template_cache_requests_total`: lookups in a
+//!   [`PtbTemplateCache`](crate::core::ptb_template::PtbTemplateCache), labelled with their `result`
+
+use std::future::Future;
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use iota_interaction::{IotaKeySignature, OptionalSend, OptionalSync};
+use product_common::transaction::TransactionOutput;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use prometheus::{Histogram, HistogramOpts, IntCounter, IntCounterVec, Opts, Registry};
+use secret_storage::Signer;
+
+use crate::client::api::execution_error;
+use crate::client::{ClientError, HierarchiesClient};
+use crate::core::transactions::MoveAbortMapper;
+
+/// The collectors the clients record into.
+struct Metrics {
+    transactions_submitted: IntCounterVec,
+    validation_duration: Histogram,
+    rpc_retries: IntCounter,
+    template_cache_requests: IntCounterVec,
+}
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(|| Metrics {
+    transactions_submitted: IntCounterVec::new(
+        Opts::new(
+            "hierarchies_transactions_submitted_total",
+            "Transactions submitted to the network",
+        ),
+        &["outcome"],
+    )
+    .expect("valid metric"),
+    validation_duration: Histogram::with_opts(HistogramOpts::new(
+        "hierarchies_validation_duration_seconds",
+        "Latency of validation queries",
+    ))
+    .expect("valid metric"),
+    rpc_retries: IntCounter::new(
+        "hierarchies_rpc_retries_total",
+        "Attempts repeated after a transient failure",
+    )
+    .expect("valid metric"),
+    template_cache_requests: IntCounterVec::new(
+        Opts::new(
+            "hierarchies_ptb_template_cache_requests_total",
+            "Lookups in the transaction template cache",
+        ),
+        &["result"],
+    )
+    .expect("valid metric"),
+});
+
+/// Registers the collectors of the clients with `registry`.
+///
+/// # Errors
+///
+/// Fails if the collectors are already registered with `registry`.
+pub fn register(registry: &Registry) -> prometheus::Result<()> {
+    registry.register(Box::new(METRICS.transactions_submitted.clone()))?;
+    registry.register(Box::new(METRICS.validation_duration.clone()))?;
+    registry.register(Box::new(METRICS.rpc_retries.clone()))?;
+    registry.register(Box::new(METRICS.template_cache_requests.clone()))?;
+    Ok(())
+}
+
+pub(crate) fn record_retry() {
+    METRICS.rpc_retries.inc();
+}
+
+pub(crate) fn record_template_lookup(hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    METRICS.template_cache_requests.with_label_values(&[result]).inc();
+}
+
+/// Runs the validation query `validation`, recording its latency.
+pub(crate) async fn time_validation<T>(validation: impl Future<Output = T>) -> T {
+    let start = Instant::now();
+    let output = validation.await;
+    METRICS.validation_duration.observe(start.elapsed().as_secs_f64());
+    output
+}
+
+/// Executes a transaction builder, recording the submission.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait MeteredExecution {
+    /// The output of the executed transaction.
+    type Output;
+
+    /// Builds and executes the transaction with `client` and counts it in
+    /// `hierarchies_transactions_submitted_total` by outcome.
+    async fn build_and_execute_metered<S>(
+        self,
+        client: &HierarchiesClient<S>,
+    ) -> Result<TransactionOutput<Self::Output>, ClientError>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> MeteredExecution for TransactionBuilder<Tx>
+where
+    Tx: Transaction + OptionalSend,
+    Tx::Output: OptionalSend,
+{
+    type Output = Tx::Output;
+
+    async fn build_and_execute_metered<S>(
+        self,
+        client: &HierarchiesClient<S>,
+    ) -> Result<TransactionOutput<Self::Output>, ClientError>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let result = self
+            .build_and_execute(client)
+            .await
+            .map_err(|e| execution_error(MoveAbortMapper::new(), e));
+        let outcome = if result.is_ok() { "success" } else { "failure" };
+        METRICS.transactions_submitted.with_label_values(&[outcome]).inc();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_exposes_collectors() {
+        let registry = Registry::new();
+        register(&registry).unwrap();
+        record_retry();
+        record_template_lookup(true);
+
+        let names: Vec<_> = registry
+            .gather()
+            .iter()
+            .map(|family| family.get_name().to_string())
+            .collect();
+        assert!(names.contains(&"hierarchies_rpc_retries_total".to_string()));
+        assert!(names.contains(&"hierarchies_ptb_template_cache_requests_total".to_string()));
+        assert!(register(&registry).is_err());
+    }
+}
@@ -10,14 +10,35 @@
 //!
 //! The registry is used to lookup the package ID for the Hierarchies package for a
 //! given network.
+//!
+//! Deployments the file doesn't know about, or newer upgrades of known networks, are registered
+//! with [`PackageOverrides`]. The overrides are read from a JSON file mapping networks to their
+//! package history, oldest version first:
+//!
+//! ```json
+//! {
+//!   "testnet": ["0x<original package>", "0x<upgraded package>"],
+//!   "<chain identifier of a localnet>": ["0x<package>"]
+//! }
+//! ```
+//!
+//! A fresh deployment that isn't an upgrade of the known package of a network, like the packages
+//! of a newer schema (see [`crate::compat`]), replaces the history of the network instead of
+//! extending it, since its federations and events aren't shared with the older package.
+//!
+//! Networks are named by `mainnet`, `testnet` and `devnet` or by their chain identifier.
+//! [`HierarchiesConfig`](crate::client::HierarchiesConfig) applies the file named by the
+//! `HIERARCHIES_PACKAGE_REGISTRY` environment variable before connecting.
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use iota_interaction::types::base_types::ObjectID;
 use product_common::core_client::CoreClientReadOnly;
-use product_common::package_registry::PackageRegistry;
+use product_common::package_registry::{Env, PackageRegistry};
+use serde::Deserialize;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
 
 use crate::error::ConfigError;
@@ -25,18 +46,31 @@ use crate::error::ConfigError;
 type PackageRegistryLock = RwLockReadGuard<'static, PackageRegistry>;
 type PackageRegistryLockMut = RwLockWriteGuard<'static, PackageRegistry>;
 
+const PACKAGE_HISTORY_JSON: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../hierarchies-move/Move.history.json"
+));
+
 #[allow(deprecated)] // TODO : Remove after MoveHistoryManager is released with product-core
 static HIERARCHIES_PACKAGE_REGISTRY: LazyLock<RwLock<PackageRegistry>> = LazyLock::new(|| {
-    let package_history_json = include_str!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/../../hierarchies-move/Move.history.json"
-    ));
     RwLock::new(
-        PackageRegistry::from_package_history_json_str(package_history_json)
+        PackageRegistry::from_package_history_json_str(PACKAGE_HISTORY_JSON)
             .expect("Move.history.json exists and it's valid"),
     )
 });
 
+/// The chain identifiers of the named networks in `Move.history.json`.
+static CHAIN_ALIASES: LazyLock<HashMap<String, String>> = LazyLock::new(|| {
+    #[derive(Deserialize)]
+    struct PackageHistory {
+        aliases: HashMap<String, String>,
+    }
+
+    serde_json::from_str::<PackageHistory>(PACKAGE_HISTORY_JSON)
+        .expect("Move.history.json exists and it's valid")
+        .aliases
+});
+
 pub(crate) async fn hierarchies_package_registry() -> PackageRegistryLock {
     HIERARCHIES_PACKAGE_REGISTRY.read().await
 }
@@ -74,3 +108,122 @@ where
             network: network.to_string(),
         })
 }
+
+/// Package histories that extend or replace the histories of the package registry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct PackageOverrides {
+    histories: HashMap<String, Vec<ObjectID>>,
+}
+
+impl PackageOverrides {
+    /// Creates empty overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses overrides in the format described in the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidPackageRegistry`] if the JSON is malformed or a network
+    /// has an empty history.
+    pub fn from_json_str(json: &str) -> Result<Self, ConfigError> {
+        let overrides: Self =
+            serde_json::from_str(json).map_err(|e| ConfigError::InvalidPackageRegistry { reason: e.to_string() })?;
+        if let Some(network) = overrides.histories.iter().find_map(|(n, h)| h.is_empty().then_some(n)) {
+            return Err(ConfigError::InvalidPackageRegistry {
+                reason: format!("empty package history for network {network}"),
+            });
+        }
+
+        Ok(overrides)
+    }
+
+    /// Reads overrides from the JSON file at `path`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| ConfigError::InvalidPackageRegistry {
+            reason: format!("cannot read {}: {e}", path.display()),
+        })?;
+        Self::from_json_str(&json)
+    }
+
+    /// Sets the package history of `network`, oldest version first.
+    ///
+    /// `network` is `mainnet`, `testnet`, `devnet` or a chain identifier.
+    pub fn with_history(mut self, network: impl Into<String>, history: Vec<ObjectID>) -> Self {
+        self.histories.insert(network.into(), history);
+        self
+    }
+
+    /// Returns the package history of `network`, if overridden.
+    pub fn history(&self, network: &str) -> Option<&[ObjectID]> {
+        self.histories.get(network).map(Vec::as_slice)
+    }
+
+    /// Writes the histories to the package registry consulted by the clients.
+    ///
+    /// Clients created afterwards use the latest package of their network's history.
+    pub async fn apply(&self) {
+        let mut registry = hierarchies_package_registry_mut().await;
+        for (network, history) in &self.histories {
+            registry.insert_env_history(Env::new(chain_id(network)), history.clone());
+        }
+    }
+}
+
+/// Returns the chain identifier of `network`, which is either a network name known to
+/// `Move.history.json` or a chain identifier already.
+fn chain_id(network: &str) -> &str {
+    CHAIN_ALIASES.get(network).map(String::as_str).unwrap_or(network)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_package_overrides_from_json() {
+        let original = ObjectID::random();
+        let upgraded = ObjectID::random();
+        let json = format!(r#"{{ "testnet": ["{original}", "{upgraded}"] }}"#);
+
+        let overrides = PackageOverrides::from_json_str(&json).unwrap();
+
+        assert_eq!(overrides.history("testnet"), Some([original, upgraded].as_slice()));
+        assert_eq!(overrides.history("devnet"), None);
+        assert!(matches!(
+            PackageOverrides::from_json_str(r#"{ "testnet": [] }"#),
+            Err(ConfigError::InvalidPackageRegistry { .. })
+        ));
+        assert!(matches!(
+            PackageOverrides::from_json_str(r#"{ "testnet": "0x1" }"#),
+            Err(ConfigError::InvalidPackageRegistry { .. })
+        ));
+    }
+
+    #[test]
+    fn test_network_names_resolve_to_chain_ids() {
+        assert_eq!(chain_id("testnet"), "2304aa97");
+        assert_eq!(chain_id("mainnet"), "6364aad5");
+        assert_eq!(chain_id("abcd1234"), "abcd1234");
+    }
+
+    #[tokio::test]
+    async fn test_apply_overrides_registry() {
+        let chain_id = "0ff1ce00";
+        let package_id = ObjectID::random();
+
+        PackageOverrides::new()
+            .with_history(chain_id, vec![ObjectID::random(), package_id])
+            .apply()
+            .await;
+
+        assert_eq!(
+            hierarchies_package_registry().await.package_id(chain_id),
+            Some(package_id)
+        );
+    }
+}
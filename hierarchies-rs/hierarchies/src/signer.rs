@@ -0,0 +1,533 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Remote Transaction Signers
+//!
+//! Signing with keys that never leave a KMS or HSM, available with the `kms` feature.
+//!
+//! The full client signs with any [`Signer<IotaKeySignature>`](secret_storage::Signer), which
+//! has to assemble an IOTA signature from the transaction itself. Remote backends only sign a
+//! digest, so a [`TransactionSigner`] just implements that part: it returns its public key and
+//! signs the 32-byte intent digest of a transaction, which ECDSA keys sign as its SHA-256 hash.
+//! [`RemoteSigner`] wraps it into a `Signer` the client
+//! accepts, computing the intent digest of the transaction, normalizing ECDSA signatures and
+//! attaching the signature scheme flag and public key.
+//!
+//! [`AzureKeyVaultSigner`] is a ready backend for EC keys of Azure Key Vault and Managed HSM.
+//! Other backends, e.g. AWS KMS or PKCS#11 tokens, only need to implement [`TransactionSigner`].
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! let backend = AzureKeyVaultSigner::new(
+//!     "https://my-vault.vault.azure.net/keys/root-authority/0123456789abcdef",
+//!     StaticAccessToken::new(token),
+//! );
+//! let signer = RemoteSigner::new(backend);
+//! let client = HierarchiesClient::new(read_only_client, signer).await?;
+//! ```
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use blake2::Blake2b;
+use blake2::digest::Digest;
+use blake2::digest::consts::U32;
+use iota_interaction::shared_crypto::intent::{Intent, IntentMessage};
+use iota_interaction::types::crypto::{PublicKey, Signature, SignatureScheme, ToFromBytes};
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+use tokio::sync::OnceCell;
+
+/// The order of the secp256k1 group, big-endian.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xba, 0xae, 0xdc,
+    0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// The order of the secp256r1 group, big-endian.
+const SECP256R1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xbc, 0xe6, 0xfa,
+    0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+];
+
+/// The Key Vault REST API version used by [`AzureKeyVaultSigner`].
+const AZURE_API_VERSION: &str = "7.4";
+
+/// Errors of remote signers
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum SignerError {
+    /// The backend couldn't be reached or refused the request
+    #[error("remote signer request failed: {reason}")]
+    Remote { reason: String },
+
+    /// The key uses a curve or algorithm IOTA signatures don't support
+    #[error("key type '{key_type}' is not supported")]
+    UnsupportedKey { key_type: String },
+
+    /// The public key returned by the backend is malformed
+    #[error("invalid public key: {reason}")]
+    InvalidPublicKey { reason: String },
+
+    /// The signature returned by the backend is malformed
+    #[error("invalid signature: {reason}")]
+    InvalidSignature { reason: String },
+
+    /// The transaction couldn't be serialized for signing
+    #[error("failed to serialize the transaction")]
+    Serialization(#[from] bcs::Error),
+}
+
+impl From<SignerError> for secret_storage::Error {
+    fn from(error: SignerError) -> Self {
+        secret_storage::Error::Other(error.into())
+    }
+}
+
+/// A signer whose key is held by a remote backend, e.g. a KMS or an HSM.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait TransactionSigner: OptionalSync {
+    /// Identifies the key within the backend, e.g. its URL or ARN.
+    fn key_id(&self) -> String;
+
+    /// Returns the public key of the signing key.
+    async fn public_key(&self) -> Result<PublicKey, SignerError>;
+
+    /// Signs the 32-byte intent `digest` of a transaction, see [`signing_digest`].
+    ///
+    /// Ed25519 keys sign `digest` itself. Secp256k1 and secp256r1 keys sign its SHA-256 hash, as
+    /// IOTA verifies ECDSA signatures against `SHA-256(digest)`; backends taking a prehashed
+    /// value, like Key Vault, must be sent `SHA-256(digest)` rather than `digest`.
+    ///
+    /// Returns the raw signature: 64 bytes for Ed25519, `r || s` for ECDSA keys. ECDSA signatures
+    /// don't need to be normalized, [`RemoteSigner`] takes care of that.
+    async fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError>;
+}
+
+/// Adapts a [`TransactionSigner`] to the [`Signer`](secret_storage::Signer) of the full client.
+///
+/// The public key is fetched once and cached.
+#[derive(Debug)]
+pub struct RemoteSigner<T> {
+    backend: T,
+    public_key: OnceCell<PublicKey>,
+}
+
+impl<T: TransactionSigner> RemoteSigner<T> {
+    /// Creates a new [`RemoteSigner`] signing with `backend`.
+    pub fn new(backend: T) -> Self {
+        Self {
+            backend,
+            public_key: OnceCell::new(),
+        }
+    }
+
+    /// Returns the wrapped backend.
+    pub fn backend(&self) -> &T {
+        &self.backend
+    }
+
+    async fn cached_public_key(&self) -> Result<&PublicKey, SignerError> {
+        self.public_key.get_or_try_init(|| self.backend.public_key()).await
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<T: TransactionSigner> secret_storage::Signer<IotaKeySignature> for RemoteSigner<T> {
+    type KeyId = String;
+
+    fn key_id(&self) -> Self::KeyId {
+        self.backend.key_id()
+    }
+
+    async fn public_key(&self) -> secret_storage::Result<PublicKey> {
+        Ok(self.cached_public_key().await?.clone())
+    }
+
+    async fn sign(&self, data: &TransactionData) -> secret_storage::Result<Signature> {
+        let public_key = self.cached_public_key().await?;
+        let digest = signing_digest(data)?;
+        let signature = self.backend.sign_digest(&digest).await?;
+        let signature = normalize_signature(public_key.scheme(), signature)?;
+
+        let bytes = [&[public_key.flag()], signature.as_slice(), public_key.as_ref()].concat();
+        Signature::from_bytes(&bytes).map_err(|err| {
+            SignerError::InvalidSignature {
+                reason: err.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+/// Returns the digest IOTA signatures of `data` sign: the Blake2b-256 hash of its intent message.
+pub fn signing_digest(data: &TransactionData) -> Result<[u8; 32], SignerError> {
+    let intent_message = IntentMessage::new(Intent::iota_transaction(), data);
+    let bytes = bcs::to_bytes(&intent_message)?;
+
+    Ok(Blake2b::<U32>::digest(bytes).into())
+}
+
+/// Checks the length of a raw signature of `scheme` and converts ECDSA signatures to low-s form,
+/// the only form IOTA validators accept.
+fn normalize_signature(scheme: SignatureScheme, mut signature: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+    if signature.len() != 64 {
+        return Err(SignerError::InvalidSignature {
+            reason: format!("expected 64 bytes, got {}", signature.len()),
+        });
+    }
+    let order = match scheme {
+        SignatureScheme::ED25519 => return Ok(signature),
+        SignatureScheme::Secp256k1 => &SECP256K1_ORDER,
+        SignatureScheme::Secp256r1 => &SECP256R1_ORDER,
+        scheme => {
+            return Err(SignerError::UnsupportedKey {
+                key_type: format!("{scheme:?}"),
+            });
+        }
+    };
+
+    let s: &mut [u8; 32] = (&mut signature[32..]).try_into().expect("the signature has 64 bytes");
+    if is_high(s, order) {
+        *s = sub(order, s);
+    }
+    Ok(signature)
+}
+
+/// Returns true if `s` is greater than half of `order`.
+fn is_high(s: &[u8; 32], order: &[u8; 32]) -> bool {
+    let mut half = [0u8; 32];
+    let mut carry = 0;
+    for (half, byte) in half.iter_mut().zip(order) {
+        *half = (carry << 7) | (byte >> 1);
+        carry = byte & 1;
+    }
+    s > &half
+}
+
+/// Returns `a - b` for big-endian `a >= b`.
+fn sub(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut borrow = false;
+    for ((result, a), b) in result.iter_mut().zip(a).zip(b).rev() {
+        let (difference, underflow) = a.overflowing_sub(*b);
+        let (difference, borrowed) = difference.overflowing_sub(u8::from(borrow));
+        *result = difference;
+        borrow = underflow || borrowed;
+    }
+    result
+}
+
+/// Provides the OAuth access tokens authenticating requests to Azure Key Vault.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait AccessTokenProvider: OptionalSync {
+    /// Returns a valid access token for the `https://vault.azure.net` resource.
+    ///
+    /// Called before every request, so implementations should cache tokens until they expire.
+    async fn access_token(&self) -> Result<String, SignerError>;
+}
+
+/// An access token that is used as is, e.g. one obtained by a sidecar that restarts the process
+/// on expiry.
+#[derive(Clone)]
+pub struct StaticAccessToken(String);
+
+impl StaticAccessToken {
+    /// Creates a new [`StaticAccessToken`].
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl std::fmt::Debug for StaticAccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StaticAccessToken(..)")
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl AccessTokenProvider for StaticAccessToken {
+    async fn access_token(&self) -> Result<String, SignerError> {
+        Ok(self.0.clone())
+    }
+}
+
+/// The public part of an EC key, as returned by Key Vault.
+#[derive(Debug, Clone, Deserialize)]
+struct JsonWebKey {
+    kty: String,
+    crv: Option<String>,
+    x: Option<String>,
+    y: Option<String>,
+}
+
+impl JsonWebKey {
+    /// Returns the signature scheme and Key Vault signing algorithm of the key.
+    fn scheme(&self) -> Result<(SignatureScheme, &'static str), SignerError> {
+        match (self.kty.as_str(), self.crv.as_deref()) {
+            ("EC" | "EC-HSM", Some("P-256K")) => Ok((SignatureScheme::Secp256k1, "ES256K")),
+            ("EC" | "EC-HSM", Some("P-256")) => Ok((SignatureScheme::Secp256r1, "ES256")),
+            (kty, crv) => Err(SignerError::UnsupportedKey {
+                key_type: format!("{kty} {}", crv.unwrap_or_default()),
+            }),
+        }
+    }
+
+    /// Returns the compressed SEC1 encoding of the key.
+    fn compressed(&self) -> Result<Vec<u8>, SignerError> {
+        let coordinate = |value: &Option<String>| {
+            let bytes = URL_SAFE_NO_PAD
+                .decode(value.as_deref().unwrap_or_default())
+                .map_err(|err| SignerError::InvalidPublicKey {
+                    reason: err.to_string(),
+                })?;
+            <[u8; 32]>::try_from(bytes).map_err(|bytes| SignerError::InvalidPublicKey {
+                reason: format!("expected a 32-byte coordinate, got {} bytes", bytes.len()),
+            })
+        };
+        let x = coordinate(&self.x)?;
+        let y = coordinate(&self.y)?;
+
+        Ok([&[0x02 | (y[31] & 1)], x.as_slice()].concat())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyBundle {
+    key: JsonWebKey,
+}
+
+#[derive(Debug, Serialize)]
+struct SignRequest<'a> {
+    alg: &'a str,
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignResponse {
+    value: String,
+}
+
+/// A [`TransactionSigner`] backed by an EC key of Azure Key Vault or Managed HSM.
+///
+/// Supports `P-256K` (secp256k1) and `P-256` (secp256r1) keys, whose private part never leaves
+/// the vault. The identity of the access tokens needs the `get` and `sign` key permissions.
+pub struct AzureKeyVaultSigner<P> {
+    http: reqwest::Client,
+    key_url: String,
+    tokens: P,
+    key: OnceCell<JsonWebKey>,
+}
+
+impl<P: AccessTokenProvider> AzureKeyVaultSigner<P> {
+    /// Creates a new [`AzureKeyVaultSigner`] for the key at `key_url`, e.g.
+    /// `https://my-vault.vault.azure.net/keys/my-key/<version>`.
+    ///
+    /// Without a version, the latest version of the key is used, which changes when the key is
+    /// rotated in the vault.
+    pub fn new(key_url: impl Into<String>, tokens: P) -> Self {
+        Self::with_http_client(reqwest::Client::new(), key_url, tokens)
+    }
+
+    /// Creates a new [`AzureKeyVaultSigner`] sending its requests with `http`, e.g. to configure
+    /// timeouts or a proxy.
+    pub fn with_http_client(http: reqwest::Client, key_url: impl Into<String>, tokens: P) -> Self {
+        Self {
+            http,
+            key_url: key_url.into().trim_end_matches('/').to_string(),
+            tokens,
+            key: OnceCell::new(),
+        }
+    }
+
+    async fn key(&self) -> Result<&JsonWebKey, SignerError> {
+        self.key
+            .get_or_try_init(|| async {
+                let bundle: KeyBundle = self
+                    .send(
+                        self.http
+                            .get(&self.key_url)
+                            .query(&[("api-version", AZURE_API_VERSION)]),
+                    )
+                    .await?;
+                Ok(bundle.key)
+            })
+            .await
+    }
+
+    async fn send<R: serde::de::DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<R, SignerError> {
+        let remote = |err: reqwest::Error| SignerError::Remote {
+            reason: err.to_string(),
+        };
+        let token = self.tokens.access_token().await?;
+        let response = request.bearer_auth(token).send().await.map_err(remote)?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(SignerError::Remote {
+                reason: format!("Key Vault responded with {status}: {body}"),
+            });
+        }
+        response.json().await.map_err(remote)
+    }
+}
+
+impl<P> std::fmt::Debug for AzureKeyVaultSigner<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AzureKeyVaultSigner")
+            .field("key_url", &self.key_url)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<P: AccessTokenProvider> TransactionSigner for AzureKeyVaultSigner<P> {
+    fn key_id(&self) -> String {
+        self.key_url.clone()
+    }
+
+    async fn public_key(&self) -> Result<PublicKey, SignerError> {
+        let key = self.key().await?;
+        let (scheme, _) = key.scheme()?;
+
+        PublicKey::try_from_bytes(scheme, &key.compressed()?).map_err(|err| SignerError::InvalidPublicKey {
+            reason: err.to_string(),
+        })
+    }
+
+    async fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+        let (_, alg) = self.key().await?.scheme()?;
+        // ES256K and ES256 sign the value as a prehashed SHA-256 digest
+        let request = SignRequest {
+            alg,
+            value: URL_SAFE_NO_PAD.encode(Sha256::digest(digest)),
+        };
+        let response: SignResponse = self
+            .send(
+                self.http
+                    .post(format!("{}/sign", self.key_url))
+                    .query(&[("api-version", AZURE_API_VERSION)])
+                    .json(&request),
+            )
+            .await?;
+
+        URL_SAFE_NO_PAD
+            .decode(response.value)
+            .map_err(|err| SignerError::InvalidSignature {
+                reason: err.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::{IotaAddress, random_object_ref};
+    use iota_interaction::types::crypto::{IotaKeyPair, IotaSignature, get_key_pair};
+    use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+    use secret_storage::Signer as _;
+
+    use super::*;
+
+    /// Signs with a local key under the contract of [`TransactionSigner::sign_digest`].
+    struct LocalSigner(IotaKeyPair);
+
+    #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+    #[cfg_attr(feature = "send-sync-storage", async_trait)]
+    impl TransactionSigner for LocalSigner {
+        fn key_id(&self) -> String {
+            "local".to_string()
+        }
+
+        async fn public_key(&self) -> Result<PublicKey, SignerError> {
+            Ok(self.0.public())
+        }
+
+        async fn sign_digest(&self, digest: &[u8; 32]) -> Result<Vec<u8>, SignerError> {
+            // ECDSA key pairs hash the message with SHA-256 before signing
+            let signature = Signature::new_hashed(digest, &self.0);
+            Ok(signature.as_ref()[1..65].to_vec())
+        }
+    }
+
+    #[tokio::test]
+    async fn remote_signatures_verify() {
+        let signer = RemoteSigner::new(LocalSigner(IotaKeyPair::Secp256k1(get_key_pair().1)));
+        let public_key = signer.public_key().await.unwrap();
+        let sender = IotaAddress::from(&public_key);
+        let data = TransactionData::new_programmable(
+            sender,
+            vec![random_object_ref()],
+            ProgrammableTransactionBuilder::new().finish(),
+            10_000_000,
+            1_000,
+        );
+
+        let signature = signer.sign(&data).await.unwrap();
+
+        let intent_message = IntentMessage::new(Intent::iota_transaction(), data);
+        signature
+            .verify_secure(&intent_message, sender, SignatureScheme::Secp256k1)
+            .unwrap();
+    }
+
+    #[test]
+    fn high_s_is_normalized() {
+        let mut signature = vec![1u8; 32];
+        // n - 1 is the highest valid s, its low form is 1
+        let mut s = SECP256K1_ORDER;
+        s[31] -= 1;
+        signature.extend_from_slice(&s);
+
+        let normalized = normalize_signature(SignatureScheme::Secp256k1, signature).unwrap();
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(normalized[..32], [1u8; 32]);
+        assert_eq!(normalized[32..], one);
+    }
+
+    #[test]
+    fn low_s_and_ed25519_signatures_are_kept() {
+        let mut low = [0u8; 64];
+        low[63] = 7;
+        assert_eq!(
+            normalize_signature(SignatureScheme::Secp256r1, low.to_vec()).unwrap(),
+            low.to_vec()
+        );
+
+        let high = [0xffu8; 64];
+        assert_eq!(
+            normalize_signature(SignatureScheme::ED25519, high.to_vec()).unwrap(),
+            high.to_vec()
+        );
+        assert!(matches!(
+            normalize_signature(SignatureScheme::ED25519, vec![0; 63]),
+            Err(SignerError::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn json_web_key_is_compressed() {
+        let key = JsonWebKey {
+            kty: "EC".to_string(),
+            crv: Some("P-256K".to_string()),
+            x: Some(URL_SAFE_NO_PAD.encode([2u8; 32])),
+            y: Some(URL_SAFE_NO_PAD.encode([3u8; 32])),
+        };
+
+        let compressed = key.compressed().unwrap();
+
+        assert_eq!(key.scheme().unwrap(), (SignatureScheme::Secp256k1, "ES256K"));
+        assert_eq!(compressed[0], 0x03);
+        assert_eq!(compressed[1..], [2u8; 32]);
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Federation Templates
+//!
+//! Ready-made property schemas for common use cases, so federations of the same vertical share
+//! the same structure instead of hand-rolling it:
+//! - [`FederationTemplate::education`]: degrees and enrollment of students;
+//! - [`FederationTemplate::supply_chain`]: certifications and origin of products;
+//! - [`FederationTemplate::kyc`]: tiered know-your-customer checks.
+//!
+//! A template is a starting point: it can be extended before it is instantiated, and it is
+//! applied to a [`FederationBuilder`], so accreditations can be granted in the same transactions.
+//!
+//! ```rust,ignore
+//! let setup = FederationTemplate::education()
+//!     .with_property(FederationProperty::new("degree.honors").with_allow_any(true))
+//!     .instantiate(&client)
+//!     .await?;
+//! ```
+
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use secret_storage::Signer;
+
+use crate::client::{ClientError, FederationBuilder, FederationSetup, HierarchiesClient};
+use crate::core::types::grant_policy::GrantPolicy;
+use crate::core::types::property::FederationProperty;
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_shape::PropertyShape;
+use crate::core::types::property_value::PropertyValue;
+
+/// The number of milliseconds in a year of 365 days.
+const YEAR_MS: u64 = 365 * 24 * 60 * 60 * 1000;
+
+/// The initial structure of a federation: its properties, value dictionaries and grant policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationTemplate {
+    /// The name of the template, e.g. `education`
+    pub name: String,
+    pub grant_policy: Option<GrantPolicy>,
+    pub value_dictionaries: Vec<(String, Vec<PropertyValue>)>,
+    pub properties: Vec<FederationProperty>,
+    pub critical_properties: Vec<PropertyName>,
+}
+
+impl FederationTemplate {
+    /// Creates an empty template named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            grant_policy: None,
+            value_dictionaries: Vec::new(),
+            properties: Vec::new(),
+            critical_properties: Vec::new(),
+        }
+    }
+
+    /// Education credentials: the level, field and grade of a degree and the enrollment status.
+    ///
+    /// Degree levels are kept in the `degree.levels` value dictionary, so they can be extended
+    /// without touching the property. The GPA is recorded in hundredths, i.e. `385` for 3.85.
+    pub fn education() -> Self {
+        Self::new("education")
+            .with_value_dictionary("degree.levels", texts(["bachelor", "master", "doctorate"]))
+            .with_property(FederationProperty::new("degree.level").with_value_dictionary("degree.levels"))
+            .with_property(FederationProperty::new("degree.field").with_allow_any(true))
+            .with_property(FederationProperty::new("degree.gpa").with_expression(PropertyShape::Between(0, 400)))
+            .with_property(
+                FederationProperty::new("degree.graduation_year").with_expression(PropertyShape::Between(1900, 2100)),
+            )
+            .with_property(FederationProperty::new("enrollment.status").with_allowed_values(texts([
+                "enrolled",
+                "graduated",
+                "withdrawn",
+            ])))
+            .with_critical_property("degree.level")
+    }
+
+    /// Supply-chain certification: product certifications, country of origin and batch numbers.
+    pub fn supply_chain() -> Self {
+        Self::new("supply_chain")
+            .with_property(
+                FederationProperty::new("certification.organic").with_allowed_values([PropertyValue::Bool(true)]),
+            )
+            .with_property(
+                FederationProperty::new("certification.fair_trade").with_allowed_values([PropertyValue::Bool(true)]),
+            )
+            .with_property(
+                FederationProperty::new("certification.iso")
+                    .with_expression(PropertyShape::MatchesPattern("ISO #*".to_string())),
+            )
+            .with_property(
+                FederationProperty::new("origin.country")
+                    .with_expression(PropertyShape::MatchesPattern("[A-Z][A-Z]".to_string())),
+            )
+            .with_property(FederationProperty::new("product.batch").with_allow_any(true))
+            .with_critical_property("certification.organic")
+    }
+
+    /// Know-your-customer tiers: the verified tier, residence and politically exposed status.
+    ///
+    /// Accreditations expire after a year by default, so verifiers are re-accredited regularly.
+    pub fn kyc() -> Self {
+        Self::new("kyc")
+            .with_grant_policy(GrantPolicy::default().with_default_validity_ms(YEAR_MS))
+            .with_value_dictionary("kyc.tiers", texts(["basic", "standard", "enhanced"]))
+            .with_property(FederationProperty::new("kyc.tier").with_value_dictionary("kyc.tiers"))
+            .with_property(
+                FederationProperty::new("kyc.residence")
+                    .with_expression(PropertyShape::MatchesPattern("[A-Z][A-Z]".to_string())),
+            )
+            .with_property(
+                FederationProperty::new("kyc.politically_exposed")
+                    .with_allowed_values([PropertyValue::Bool(true), PropertyValue::Bool(false)]),
+            )
+            .with_critical_property("kyc.tier")
+    }
+
+    /// Sets the grant policy of the federation.
+    pub fn with_grant_policy(mut self, policy: GrantPolicy) -> Self {
+        self.grant_policy = Some(policy);
+        self
+    }
+
+    /// Adds the value dictionary `name`.
+    pub fn with_value_dictionary(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = PropertyValue>,
+    ) -> Self {
+        self.value_dictionaries
+            .push((name.into(), values.into_iter().collect()));
+        self
+    }
+
+    /// Adds `property`, replacing a property of the same name.
+    pub fn with_property(mut self, property: FederationProperty) -> Self {
+        self.properties.retain(|existing| existing.name != property.name);
+        self.properties.push(property);
+        self
+    }
+
+    /// Marks `property_name` as critical.
+    pub fn with_critical_property(mut self, property_name: impl Into<PropertyName>) -> Self {
+        self.critical_properties.push(property_name.into());
+        self
+    }
+
+    /// Returns the property named `property_name`, if the template defines it.
+    pub fn property(&self, property_name: &PropertyName) -> Option<&FederationProperty> {
+        self.properties.iter().find(|property| &property.name == property_name)
+    }
+
+    /// Describes the template on `builder`, which can then be extended, e.g. with accreditations.
+    pub fn apply<'c, S>(&self, builder: FederationBuilder<'c, S>) -> FederationBuilder<'c, S> {
+        let builder = match &self.grant_policy {
+            Some(policy) => builder.grant_policy(policy.clone()),
+            None => builder,
+        };
+        let builder = self.value_dictionaries.iter().fold(builder, |builder, (name, values)| {
+            builder.set_value_dictionary(name.clone(), values.clone())
+        });
+        let builder = self
+            .properties
+            .iter()
+            .fold(builder, |builder, property| builder.add_property(property.clone()));
+        self.critical_properties
+            .iter()
+            .fold(builder, |builder, name| builder.critical_property(name.clone()))
+    }
+
+    /// Creates a new federation with the structure of the template.
+    pub async fn instantiate<S>(&self, client: &HierarchiesClient<S>) -> Result<FederationSetup, ClientError>
+    where
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        self.apply(client.federation_builder()).execute().await
+    }
+}
+
+fn texts<const N: usize>(values: [&str; N]) -> Vec<PropertyValue> {
+    values
+        .into_iter()
+        .map(|value| PropertyValue::Text(value.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_are_consistent() {
+        for template in [
+            FederationTemplate::education(),
+            FederationTemplate::supply_chain(),
+            FederationTemplate::kyc(),
+        ] {
+            for property in &template.properties {
+                if let Some(dictionary) = &property.value_dictionary {
+                    assert!(
+                        template.value_dictionaries.iter().any(|(name, _)| name == dictionary),
+                        "{}: {:?} references a missing dictionary",
+                        template.name,
+                        property.name
+                    );
+                }
+            }
+            for name in &template.critical_properties {
+                assert!(
+                    template.property(name).is_some(),
+                    "{}: {name:?} is not defined",
+                    template.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_property_replaces_existing() {
+        let template = FederationTemplate::education()
+            .with_property(FederationProperty::new("degree.field").with_allowed_values(texts(["physics"])));
+
+        let field = template.property(&PropertyName::from("degree.field")).unwrap();
+        assert!(!field.allow_any);
+        assert_eq!(
+            template
+                .properties
+                .iter()
+                .filter(|property| property.name == field.name)
+                .count(),
+            1
+        );
+    }
+}
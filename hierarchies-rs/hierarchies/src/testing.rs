@@ -0,0 +1,893 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Testing
+//!
+//! An in-memory stand-in for the network, to unit-test code written against
+//! [`HierarchiesApi`] without a node. Enabled by the `testing` feature.
+//!
+//! [`MockHierarchiesClient`] applies every operation to an in-memory [`Federation`] with the
+//! checks of the Move package and reports failures with the same typed errors
+//! [`HierarchiesClient`](crate::client::HierarchiesClient) reports for the corresponding
+//! Move aborts. Validation is evaluated by [`crate::core::offline`]. Clients created with
+//! [`MockHierarchiesClient::with_account`] share the same network, and the network clock only
+//! moves when advanced explicitly.
+//!
+//! ```rust
+//! # use hierarchies::client::HierarchiesApi;
+//! # use hierarchies::core::types::property::FederationProperty;
+//! # use hierarchies::core::types::property_value::PropertyValue;
+//! # use hierarchies::testing::MockHierarchiesClient;
+//! # use iota_interaction::types::base_types::ObjectID;
+//! # async fn example() -> Result<(), hierarchies::client::ClientError> {
+//! let client = MockHierarchiesClient::new();
+//! let federation_id = client.create_federation().await?;
+//!
+//! let property =
+//!     FederationProperty::new("role").with_allowed_values([PropertyValue::Text("admin".into())]);
+//! client.add_property(federation_id, property.clone()).await?;
+//!
+//! let attester = ObjectID::random();
+//! client
+//!     .create_accreditation_to_attest(federation_id, attester, vec![property])
+//!     .await?;
+//! assert!(
+//!     client
+//!         .validate_property(
+//!             federation_id,
+//!             attester,
+//!             "role".into(),
+//!             PropertyValue::Text("admin".into())
+//!         )
+//!         .await?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::id::UID;
+
+use crate::client::{ClientError, HierarchiesApi};
+use crate::core::offline::ValidationReport;
+use crate::core::transactions::TransactionError;
+use crate::core::types::property::{FederationProperties, FederationProperty};
+use crate::core::types::property_name::PropertyName;
+use crate::core::types::property_value::PropertyValue;
+use crate::core::types::{
+    ACCREDIT_CAP_TYPE, Accreditation, Accreditations, Federation, FederationStats, Governance, ROOT_AUTHORITY_CAP_TYPE,
+    RootAuthority,
+};
+use crate::core::{CapabilityError, OperationError, offline};
+use crate::error::ObjectError;
+
+/// Minimum distance of a scheduled property revocation from the current time, as on-chain.
+const TIME_BUFFER_MS: u64 = 5000;
+
+/// The state shared by all clients of a mock network.
+#[derive(Debug, Default)]
+struct MockNetwork {
+    federations: HashMap<ObjectID, Federation>,
+    now_ms: u64,
+}
+
+/// A client executing operations against an in-memory network.
+#[derive(Debug, Clone)]
+pub struct MockHierarchiesClient {
+    account_id: ObjectID,
+    network: Arc<Mutex<MockNetwork>>,
+}
+
+impl Default for MockHierarchiesClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockHierarchiesClient {
+    /// Creates a client for a random account on a new, empty network.
+    pub fn new() -> Self {
+        Self {
+            account_id: ObjectID::random(),
+            network: Arc::default(),
+        }
+    }
+
+    /// Returns a client for `account_id` on the same network.
+    pub fn with_account(&self, account_id: ObjectID) -> Self {
+        Self {
+            account_id,
+            network: Arc::clone(&self.network),
+        }
+    }
+
+    /// The current time of the network clock.
+    pub fn now_ms(&self) -> u64 {
+        self.network().now_ms
+    }
+
+    /// Sets the network clock to `now_ms`.
+    pub fn set_time_ms(&self, now_ms: u64) {
+        self.network().now_ms = now_ms;
+    }
+
+    /// Moves the network clock forward by `duration_ms`.
+    pub fn advance_time_ms(&self, duration_ms: u64) {
+        self.network().now_ms += duration_ms;
+    }
+
+    fn network(&self) -> MutexGuard<'_, MockNetwork> {
+        // A panicking test must not fail the other tests sharing the network
+        self.network.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn federation(&self, federation_id: ObjectID) -> Result<Federation, ClientError> {
+        self.network()
+            .federations
+            .get(&federation_id)
+            .cloned()
+            .ok_or_else(|| not_found(federation_id))
+    }
+
+    /// Applies `operation` to a copy of the federation and stores the copy if it succeeds,
+    /// so failed operations leave no trace, like aborted transactions.
+    fn mutate<F>(&self, federation_id: ObjectID, operation: F) -> Result<(), ClientError>
+    where
+        F: FnOnce(&mut Federation, u64) -> Result<(), ClientError>,
+    {
+        let mut network = self.network();
+        let now_ms = network.now_ms;
+        let federation = network
+            .federations
+            .get_mut(&federation_id)
+            .ok_or_else(|| not_found(federation_id))?;
+
+        let mut updated = federation.clone();
+        operation(&mut updated, now_ms)?;
+
+        updated.stats.property_count = updated.governance.properties.data.len() as u64;
+        updated.stats.root_authority_count = updated.root_authorities.len() as u64;
+        updated.stats.modification_count += 1;
+        updated.stats.last_modified_epoch_ms = now_ms;
+        *federation = updated;
+
+        Ok(())
+    }
+
+    /// Checks that the account owns a `RootAuthorityCap`, which revoked root authorities keep.
+    fn assert_root_authority_cap(&self, federation: &Federation) -> Result<(), ClientError> {
+        if !is_root_authority(federation, &self.account_id)
+            && !federation.revoked_root_authorities.contains(&self.account_id)
+        {
+            return Err(missing_cap(ROOT_AUTHORITY_CAP_TYPE));
+        }
+        Ok(())
+    }
+
+    /// Checks that the account owns a `RootAuthorityCap` that has not been revoked.
+    fn assert_active_root_authority_cap(&self, federation: &Federation) -> Result<(), ClientError> {
+        self.assert_root_authority_cap(federation)?;
+        if federation.revoked_root_authorities.contains(&self.account_id) {
+            return Err(TransactionError::RevokedRootAuthority.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that the account owns an `AccreditCap`, which is granted with the first accreditation to accredit.
+    fn assert_accredit_cap(&self, federation: &Federation) -> Result<(), ClientError> {
+        if !federation
+            .governance
+            .accreditations_to_accredit
+            .contains_key(&self.account_id)
+        {
+            return Err(missing_cap(ACCREDIT_CAP_TYPE));
+        }
+        Ok(())
+    }
+
+    /// Checks and applies a grant, mirroring `create_accreditation_to_attest` and `create_accreditation_to_accredit`.
+    fn grant(
+        &self,
+        federation: &Federation,
+        properties: Vec<FederationProperty>,
+        now_ms: u64,
+    ) -> Result<Accreditation, ClientError> {
+        self.assert_accredit_cap(federation)?;
+
+        let policy = &federation.governance.grant_policy;
+        for property in &properties {
+            policy.check(property).map_err(OperationError::from)?;
+
+            if property.name.is_wildcard() {
+                if property.value_dictionary.is_some() {
+                    return Err(TransactionError::ValueDictionaryMismatch.into());
+                }
+                continue;
+            }
+            let name = Some(property.name.clone());
+            let federation_property = federation
+                .governance
+                .properties
+                .data
+                .get(&property.name)
+                .ok_or(TransactionError::PropertyNotInFederation { name: name.clone() })?;
+            if !federation_property.timespan.timestamp_matches(now_ms) {
+                return Err(TransactionError::PropertyRevoked { name }.into());
+            }
+            if property.value_dictionary.is_some() && property.value_dictionary != federation_property.value_dictionary
+            {
+                return Err(TransactionError::ValueDictionaryMismatch.into());
+            }
+        }
+
+        if !is_root_authority(federation, &self.account_id) && !self.holds(federation, &properties, now_ms) {
+            return Err(TransactionError::InsufficientAccreditation.into());
+        }
+
+        Ok(Accreditation {
+            id: UID::new(ObjectID::random()),
+            accredited_by: self.account_id.to_string(),
+            properties: properties
+                .into_iter()
+                .map(|property| (property.name.clone(), policy.apply_defaults(property, now_ms)))
+                .collect(),
+            pending_until_ms: policy.pending_until_ms(now_ms),
+        })
+    }
+
+    /// Checks that the account may revoke an accreditation granting `properties`.
+    fn assert_may_revoke(
+        &self,
+        federation: &Federation,
+        accreditation: &Accreditation,
+        now_ms: u64,
+    ) -> Result<(), ClientError> {
+        let properties: Vec<FederationProperty> = accreditation.properties.values().cloned().collect();
+        if !is_root_authority(federation, &self.account_id) && !self.holds(federation, &properties, now_ms) {
+            return Err(TransactionError::InsufficientAccreditation.into());
+        }
+        Ok(())
+    }
+
+    /// Returns true if the accreditations to accredit of the account cover `properties` at `now_ms`.
+    fn holds(&self, federation: &Federation, properties: &[FederationProperty], now_ms: u64) -> bool {
+        federation
+            .governance
+            .accreditations_to_accredit
+            .get(&self.account_id)
+            .is_some_and(|accreditations| accreditations.are_properties_compliant(properties, now_ms))
+    }
+}
+
+fn not_found(federation_id: ObjectID) -> ClientError {
+    ObjectError::NotFound {
+        id: federation_id.to_string(),
+    }
+    .into()
+}
+
+fn missing_cap(cap_type: &str) -> ClientError {
+    OperationError::Capability(CapabilityError::NotFound {
+        cap_type: cap_type.to_string(),
+    })
+    .into()
+}
+
+/// Mirrors the on-chain `is_root_authority`: revoked root authorities are not root authorities.
+fn is_root_authority(federation: &Federation, account_id: &ObjectID) -> bool {
+    !federation.revoked_root_authorities.contains(account_id)
+        && federation
+            .root_authorities
+            .iter()
+            .any(|root_authority| root_authority.account_id == *account_id)
+}
+
+/// Removes the accreditation `accreditation_id` of `entity_id` from `map`.
+fn remove_accreditation(
+    map: &mut HashMap<ObjectID, Accreditations>,
+    entity_id: &ObjectID,
+    accreditation_id: &ObjectID,
+) -> Result<Accreditation, ClientError> {
+    let accreditations = &mut map
+        .get_mut(entity_id)
+        .ok_or(TransactionError::AccreditationNotFound)?
+        .accreditations;
+    let idx = accreditations
+        .iter()
+        .position(|accreditation| accreditation.id.object_id() == accreditation_id)
+        .ok_or(TransactionError::AccreditationNotFound)?;
+
+    Ok(accreditations.remove(idx))
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl HierarchiesApi for MockHierarchiesClient {
+    fn account_id(&self) -> ObjectID {
+        self.account_id
+    }
+
+    async fn create_federation(&self) -> Result<ObjectID, ClientError> {
+        let mut network = self.network();
+        let federation_id = ObjectID::random();
+        let creator = || HashMap::from([(self.account_id, Accreditations::new(vec![]))]);
+
+        let federation = Federation {
+            id: UID::new(federation_id),
+            governance: Governance {
+                id: UID::new(ObjectID::random()),
+                properties: FederationProperties { data: HashMap::new() },
+                accreditations_to_accredit: creator(),
+                accreditations_to_attest: creator(),
+                critical_properties: Default::default(),
+                value_dictionaries: HashMap::new(),
+                grant_policy: Default::default(),
+                property_tags: HashMap::new(),
+                accreditation_tags: HashMap::new(),
+                quorum: Default::default(),
+                recognized_federations: Default::default(),
+                deprecated_properties: Default::default(),
+            },
+            root_authorities: vec![RootAuthority {
+                id: UID::new(ObjectID::random()),
+                account_id: self.account_id,
+            }],
+            revoked_root_authorities: vec![],
+            stats: FederationStats {
+                root_authority_count: 1,
+                last_modified_epoch_ms: network.now_ms,
+                ..Default::default()
+            },
+            metadata: Default::default(),
+        };
+        network.federations.insert(federation_id, federation);
+
+        Ok(federation_id)
+    }
+
+    async fn get_federation(&self, federation_id: ObjectID) -> Result<Federation, ClientError> {
+        self.federation(federation_id)
+    }
+
+    async fn add_property(&self, federation_id: ObjectID, property: FederationProperty) -> Result<(), ClientError> {
+        if let Some(shape) = &property.shape {
+            shape.validate().map_err(|err| ClientError::InvalidInput {
+                details: err.to_string(),
+            })?;
+        }
+
+        self.mutate(federation_id, |federation, _| {
+            self.assert_active_root_authority_cap(federation)?;
+            if property.allow_any && !property.allowed_values.is_empty() {
+                return Err(TransactionError::InvalidPropertyValueCondition.into());
+            }
+            if !property.allow_any && property.allowed_values.is_empty() && property.value_dictionary.is_none() {
+                return Err(TransactionError::EmptyAllowedValues.into());
+            }
+            let value_dictionaries = &federation.governance.value_dictionaries;
+            if property
+                .value_dictionary
+                .as_ref()
+                .is_some_and(|dictionary| !value_dictionaries.contains_key(dictionary))
+            {
+                return Err(TransactionError::ValueDictionaryNotFound.into());
+            }
+
+            let properties = &mut federation.governance.properties.data;
+            if properties.contains_key(&property.name) {
+                // `vec_map::insert` aborts with `EKeyAlreadyExists`
+                return Err(TransactionError::MoveAbort {
+                    module: "vec_map".to_string(),
+                    code: 0,
+                }
+                .into());
+            }
+            properties.insert(property.name.clone(), property);
+            Ok(())
+        })
+    }
+
+    async fn revoke_property(
+        &self,
+        federation_id: ObjectID,
+        property_name: PropertyName,
+        valid_to_ms: Option<u64>,
+    ) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, now_ms| {
+            self.assert_active_root_authority_cap(federation)?;
+            if federation.governance.quorum.is_required() {
+                return Err(TransactionError::QuorumRequired.into());
+            }
+            if valid_to_ms.is_some_and(|valid_to_ms| valid_to_ms <= now_ms + TIME_BUFFER_MS) {
+                return Err(TransactionError::TimestampInThePast.into());
+            }
+
+            let property = federation.governance.properties.data.get_mut(&property_name).ok_or(
+                TransactionError::PropertyNotInFederation {
+                    name: Some(property_name.clone()),
+                },
+            )?;
+            property.timespan.valid_until_ms = Some(valid_to_ms.unwrap_or(now_ms));
+            Ok(())
+        })
+    }
+
+    async fn add_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, _| {
+            self.assert_root_authority_cap(federation)?;
+            if is_root_authority(federation, &account_id) {
+                return Err(TransactionError::AlreadyRootAuthority.into());
+            }
+            self.assert_active_root_authority_cap(federation)?;
+
+            federation.root_authorities.push(RootAuthority {
+                id: UID::new(ObjectID::random()),
+                account_id,
+            });
+            Ok(())
+        })
+    }
+
+    async fn revoke_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, _| {
+            self.assert_root_authority_cap(federation)?;
+            if federation.governance.quorum.is_required() {
+                return Err(TransactionError::QuorumRequired.into());
+            }
+            if !is_root_authority(federation, &account_id) {
+                return Err(TransactionError::NotRootAuthority.into());
+            }
+            if federation.root_authorities.len() <= 1 {
+                return Err(TransactionError::LastRootAuthority.into());
+            }
+
+            federation
+                .root_authorities
+                .retain(|root_authority| root_authority.account_id != account_id);
+            federation.revoked_root_authorities.push(account_id);
+            Ok(())
+        })
+    }
+
+    async fn rotate_root_authority(
+        &self,
+        federation_id: ObjectID,
+        old_account_id: ObjectID,
+        new_account_id: ObjectID,
+    ) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, _| {
+            self.assert_root_authority_cap(federation)?;
+            if federation.governance.quorum.is_required() {
+                return Err(TransactionError::QuorumRequired.into());
+            }
+            if !is_root_authority(federation, &old_account_id) {
+                return Err(TransactionError::NotRootAuthority.into());
+            }
+            if is_root_authority(federation, &new_account_id) {
+                return Err(TransactionError::AlreadyRootAuthority.into());
+            }
+            self.assert_active_root_authority_cap(federation)?;
+
+            federation.root_authorities.push(RootAuthority {
+                id: UID::new(ObjectID::random()),
+                account_id: new_account_id,
+            });
+            federation
+                .root_authorities
+                .retain(|root_authority| root_authority.account_id != old_account_id);
+            federation.revoked_root_authorities.push(old_account_id);
+            Ok(())
+        })
+    }
+
+    async fn create_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    ) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, now_ms| {
+            let accreditation = self.grant(federation, properties, now_ms)?;
+            federation
+                .governance
+                .accreditations_to_attest
+                .entry(receiver)
+                .or_insert_with(|| Accreditations::new(vec![]))
+                .accreditations
+                .push(accreditation);
+            federation.stats.accreditation_to_attest_count += 1;
+            Ok(())
+        })
+    }
+
+    async fn create_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        receiver: ObjectID,
+        properties: Vec<FederationProperty>,
+    ) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, now_ms| {
+            let accreditation = self.grant(federation, properties, now_ms)?;
+            federation
+                .governance
+                .accreditations_to_accredit
+                .entry(receiver)
+                .or_insert_with(|| Accreditations::new(vec![]))
+                .accreditations
+                .push(accreditation);
+            federation.stats.accreditation_to_accredit_count += 1;
+            Ok(())
+        })
+    }
+
+    async fn revoke_accreditation_to_attest(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, now_ms| {
+            self.assert_accredit_cap(federation)?;
+            let accreditation = remove_accreditation(
+                &mut federation.governance.accreditations_to_attest,
+                &entity_id,
+                &accreditation_id,
+            )?;
+            self.assert_may_revoke(federation, &accreditation, now_ms)?;
+
+            federation.governance.accreditation_tags.remove(&accreditation_id);
+            federation.stats.accreditation_to_attest_count -= 1;
+            Ok(())
+        })
+    }
+
+    async fn revoke_accreditation_to_accredit(
+        &self,
+        federation_id: ObjectID,
+        entity_id: ObjectID,
+        accreditation_id: ObjectID,
+    ) -> Result<(), ClientError> {
+        self.mutate(federation_id, |federation, now_ms| {
+            self.assert_accredit_cap(federation)?;
+            let accreditation = remove_accreditation(
+                &mut federation.governance.accreditations_to_accredit,
+                &entity_id,
+                &accreditation_id,
+            )?;
+            // The revoker's grants are checked without the revoked accreditation, which only
+            // matters if accreditors revoke their own accreditations
+            self.assert_may_revoke(federation, &accreditation, now_ms)?;
+
+            federation.governance.accreditation_tags.remove(&accreditation_id);
+            federation.stats.accreditation_to_accredit_count -= 1;
+            Ok(())
+        })
+    }
+
+    async fn is_root_authority(&self, federation_id: ObjectID, account_id: ObjectID) -> Result<bool, ClientError> {
+        Ok(is_root_authority(&self.federation(federation_id)?, &account_id))
+    }
+
+    async fn validate_property(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        property_name: PropertyName,
+        property_value: PropertyValue,
+    ) -> Result<bool, ClientError> {
+        let federation = self.federation(federation_id)?;
+
+        Ok(offline::validate_property(
+            &federation,
+            &attester_id,
+            &property_name,
+            &property_value,
+            self.now_ms(),
+        ))
+    }
+
+    async fn validate_properties(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+    ) -> Result<bool, ClientError> {
+        let federation = self.federation(federation_id)?;
+
+        Ok(offline::validate_properties(
+            &federation,
+            &attester_id,
+            &properties,
+            self.now_ms(),
+        ))
+    }
+
+    async fn validate_properties_report(
+        &self,
+        federation_id: ObjectID,
+        attester_id: ObjectID,
+        properties: HashMap<PropertyName, PropertyValue>,
+    ) -> Result<ValidationReport, ClientError> {
+        let federation = self.federation(federation_id)?;
+
+        Ok(offline::diagnose_properties(
+            &federation,
+            &attester_id,
+            &properties,
+            self.now_ms(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::offline::ValidationFailure;
+    use crate::core::types::grant_policy::GrantPolicy;
+
+    fn role() -> FederationProperty {
+        FederationProperty::new("role")
+            .with_allowed_values([PropertyValue::Text("admin".into()), PropertyValue::Text("user".into())])
+    }
+
+    async fn federation_with_role(client: &MockHierarchiesClient) -> ObjectID {
+        let federation_id = client.create_federation().await.unwrap();
+        client.add_property(federation_id, role()).await.unwrap();
+        federation_id
+    }
+
+    #[tokio::test]
+    async fn accredited_attester_validates() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = federation_with_role(&client).await;
+        let attester = ObjectID::random();
+
+        client
+            .create_accreditation_to_attest(federation_id, attester, vec![role()])
+            .await
+            .unwrap();
+
+        assert!(
+            client
+                .validate_property(
+                    federation_id,
+                    attester,
+                    "role".into(),
+                    PropertyValue::Text("admin".into())
+                )
+                .await
+                .unwrap()
+        );
+        assert!(
+            !client
+                .validate_property(
+                    federation_id,
+                    attester,
+                    "role".into(),
+                    PropertyValue::Text("guest".into())
+                )
+                .await
+                .unwrap()
+        );
+
+        let stats = client.get_federation(federation_id).await.unwrap().stats;
+        assert_eq!(stats.property_count, 1);
+        assert_eq!(stats.accreditation_to_attest_count, 1);
+        assert_eq!(stats.modification_count, 2);
+    }
+
+    #[tokio::test]
+    async fn unknown_property_is_rejected() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = client.create_federation().await.unwrap();
+
+        let result = client
+            .create_accreditation_to_attest(federation_id, ObjectID::random(), vec![role()])
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Transaction(TransactionError::PropertyNotInFederation { name: Some(name) }))
+                if name == PropertyName::from("role")
+        ));
+    }
+
+    #[tokio::test]
+    async fn failed_operations_leave_no_trace() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = federation_with_role(&client).await;
+        let before = client.get_federation(federation_id).await.unwrap();
+
+        let unknown = FederationProperty::new("unknown").with_allow_any(true);
+        let result = client
+            .create_accreditation_to_attest(federation_id, ObjectID::random(), vec![role(), unknown])
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(client.get_federation(federation_id).await.unwrap(), before);
+    }
+
+    #[tokio::test]
+    async fn accreditors_can_only_delegate_what_they_hold() {
+        let owner = MockHierarchiesClient::new();
+        let federation_id = federation_with_role(&owner).await;
+        let accreditor = owner.with_account(ObjectID::random());
+
+        assert!(matches!(
+            accreditor
+                .create_accreditation_to_attest(federation_id, ObjectID::random(), vec![role()])
+                .await,
+            Err(ClientError::Operation(OperationError::Capability(
+                CapabilityError::NotFound { .. }
+            )))
+        ));
+
+        let user = FederationProperty::new("role").with_allowed_values([PropertyValue::Text("user".into())]);
+        owner
+            .create_accreditation_to_accredit(federation_id, accreditor.account_id(), vec![user.clone()])
+            .await
+            .unwrap();
+
+        accreditor
+            .create_accreditation_to_attest(federation_id, ObjectID::random(), vec![user])
+            .await
+            .unwrap();
+        assert!(matches!(
+            accreditor
+                .create_accreditation_to_attest(federation_id, ObjectID::random(), vec![role()])
+                .await,
+            Err(ClientError::Transaction(TransactionError::InsufficientAccreditation))
+        ));
+    }
+
+    #[tokio::test]
+    async fn wildcards_cover_properties_added_later() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = client.create_federation().await.unwrap();
+        let attester = ObjectID::random();
+        let master = PropertyName::new(["degree", "master"]);
+        let value = PropertyValue::Number(1);
+
+        client
+            .create_accreditation_to_attest(
+                federation_id,
+                attester,
+                vec![FederationProperty::new_prefix("degree").with_allow_any(true)],
+            )
+            .await
+            .unwrap();
+        assert!(
+            !client
+                .validate_property(federation_id, attester, master.clone(), value.clone())
+                .await
+                .unwrap()
+        );
+
+        client
+            .add_property(
+                federation_id,
+                FederationProperty::new(master.clone()).with_allow_any(true),
+            )
+            .await
+            .unwrap();
+        assert!(
+            client
+                .validate_property(federation_id, attester, master, value.clone())
+                .await
+                .unwrap()
+        );
+        assert!(
+            !client
+                .validate_property(federation_id, attester, "role".into(), value)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn last_root_authority_cannot_be_revoked() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = client.create_federation().await.unwrap();
+
+        assert!(matches!(
+            client.revoke_root_authority(federation_id, client.account_id()).await,
+            Err(ClientError::Transaction(TransactionError::LastRootAuthority))
+        ));
+
+        let second = ObjectID::random();
+        client.add_root_authority(federation_id, second).await.unwrap();
+        client
+            .revoke_root_authority(federation_id, client.account_id())
+            .await
+            .unwrap();
+
+        assert!(
+            !client
+                .is_root_authority(federation_id, client.account_id())
+                .await
+                .unwrap()
+        );
+        assert!(matches!(
+            client.add_property(federation_id, role()).await,
+            Err(ClientError::Transaction(TransactionError::RevokedRootAuthority))
+        ));
+    }
+
+    #[tokio::test]
+    async fn rotation_hands_over_the_root_authority() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = client.create_federation().await.unwrap();
+        let successor = ObjectID::random();
+
+        assert!(matches!(
+            client
+                .rotate_root_authority(federation_id, client.account_id(), client.account_id())
+                .await,
+            Err(ClientError::Transaction(TransactionError::AlreadyRootAuthority))
+        ));
+        client
+            .rotate_root_authority(federation_id, client.account_id(), successor)
+            .await
+            .unwrap();
+
+        assert!(client.is_root_authority(federation_id, successor).await.unwrap());
+        assert!(
+            !client
+                .is_root_authority(federation_id, client.account_id())
+                .await
+                .unwrap()
+        );
+        assert!(matches!(
+            client.add_property(federation_id, role()).await,
+            Err(ClientError::Transaction(TransactionError::RevokedRootAuthority))
+        ));
+    }
+
+    #[tokio::test]
+    async fn objection_window_follows_the_clock() {
+        let client = MockHierarchiesClient::new();
+        let federation_id = federation_with_role(&client).await;
+        client
+            .network()
+            .federations
+            .get_mut(&federation_id)
+            .unwrap()
+            .governance
+            .grant_policy = GrantPolicy::default().with_objection_window_ms(1000);
+
+        let attester = ObjectID::random();
+        client
+            .create_accreditation_to_attest(federation_id, attester, vec![role()])
+            .await
+            .unwrap();
+        let validate = || {
+            client.validate_property(
+                federation_id,
+                attester,
+                "role".into(),
+                PropertyValue::Text("user".into()),
+            )
+        };
+
+        assert!(!validate().await.unwrap());
+        let report = client
+            .validate_properties_report(
+                federation_id,
+                attester,
+                HashMap::from([("role".into(), PropertyValue::Text("user".into()))]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            report.checks[0].failure,
+            Some(ValidationFailure::AccreditationPending { pending_until_ms: 1000 })
+        );
+
+        client.advance_time_ms(1000);
+        assert!(validate().await.unwrap());
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Testkit
+//!
+//! Harness for end-to-end tests against a local IOTA network, as used by the tests of this crate.
+//! Enabled by the `testkit` feature.
+//!
+//! ```no_run
+//! # use hierarchies::testkit::{DEFAULT_PUBLISH_SCRIPT, Localnet};
+//! # async fn example() -> anyhow::Result<()> {
+//! let localnet = Localnet::attach_from_env().await?;
+//! let package_id = localnet.deploy_package(DEFAULT_PUBLISH_SCRIPT).await?;
+//! let [owner, attester] = localnet.funded_clients::<2>(package_id).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::{IOTA_LOCAL_NETWORK_URL, IotaClient, IotaClientBuilder};
+use product_common::test_utils::{InMemSigner, init_product_package, request_funds};
+
+use crate::client::{HierarchiesClient, HierarchiesClientReadOnly};
+
+/// Publish script of the Move package this crate was built with.
+pub const DEFAULT_PUBLISH_SCRIPT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../hierarchies-move/scripts/publish_hierarchies.sh"
+);
+
+/// Environment variable overriding the node URL of [`Localnet::attach_from_env`].
+pub const API_ENDPOINT_ENV: &str = "API_ENDPOINT";
+
+/// Time to wait for a started localnet to accept requests.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A local IOTA network used by tests.
+///
+/// A network started by [`Localnet::start`] is stopped when the value is dropped.
+pub struct Localnet {
+    url: String,
+    iota_client: IotaClient,
+    node: Option<Child>,
+}
+
+impl Localnet {
+    /// Attaches to the running network at `url`.
+    pub async fn attach(url: impl Into<String>) -> anyhow::Result<Self> {
+        let url = url.into();
+        let iota_client = IotaClientBuilder::default()
+            .build(&url)
+            .await
+            .with_context(|| format!("failed to connect to {url}"))?;
+
+        Ok(Self {
+            url,
+            iota_client,
+            node: None,
+        })
+    }
+
+    /// Attaches to the network at `API_ENDPOINT`, or the default localnet URL if unset.
+    pub async fn attach_from_env() -> anyhow::Result<Self> {
+        let url = std::env::var(API_ENDPOINT_ENV).unwrap_or_else(|_| IOTA_LOCAL_NETWORK_URL.to_string());
+        Self::attach(url).await
+    }
+
+    /// Starts a fresh localnet with a faucet using the `iota` binary on the `PATH`.
+    ///
+    /// The network is started from a new genesis, so every run begins from the same state.
+    pub async fn start() -> anyhow::Result<Self> {
+        let node = Command::new("iota")
+            .args(["start", "--force-regenesis", "--with-faucet"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .context("failed to run `iota start`, is the IOTA CLI installed?")?;
+
+        let started = Instant::now();
+        let mut node = Some(node);
+        loop {
+            match Self::attach(IOTA_LOCAL_NETWORK_URL).await {
+                Ok(mut localnet) => {
+                    localnet.node = node.take();
+                    return Ok(localnet);
+                }
+                Err(e) if started.elapsed() > STARTUP_TIMEOUT => {
+                    if let Some(mut node) = node.take() {
+                        let _ = node.kill();
+                    }
+                    return Err(e.context("localnet did not start in time"));
+                }
+                Err(_) => tokio::time::sleep(Duration::from_millis(500)).await,
+            }
+        }
+    }
+
+    /// Returns the URL of the network.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns a client connected to the network.
+    pub fn iota_client(&self) -> &IotaClient {
+        &self.iota_client
+    }
+
+    /// Publishes the Move package with `publish_script` and returns its ID.
+    ///
+    /// To test against a specific version of the package, pass the publish script of a
+    /// checkout of that version.
+    pub async fn deploy_package(&self, publish_script: &str) -> anyhow::Result<ObjectID> {
+        init_product_package(&self.iota_client, None, Some(publish_script)).await
+    }
+
+    /// Creates `N` signers with new keys and funds them from the faucet.
+    pub async fn funded_signers<const N: usize>(&self) -> anyhow::Result<[InMemSigner; N]> {
+        let mut signers = Vec::with_capacity(N);
+        for _ in 0..N {
+            let signer = InMemSigner::new();
+            request_funds(&signer.get_address().await?).await?;
+            signers.push(signer);
+        }
+        Ok(signers
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly N signers are created")))
+    }
+
+    /// Creates a client for the package `package_id` signing with `signer`.
+    pub async fn client(
+        &self,
+        package_id: ObjectID,
+        signer: InMemSigner,
+    ) -> anyhow::Result<HierarchiesClient<InMemSigner>> {
+        let read_only = HierarchiesClientReadOnly::new_with_pkg_id(self.iota_client.clone(), package_id).await?;
+        Ok(HierarchiesClient::new(read_only, signer).await?)
+    }
+
+    /// Creates `N` clients for the package `package_id` with funded accounts.
+    pub async fn funded_clients<const N: usize>(
+        &self,
+        package_id: ObjectID,
+    ) -> anyhow::Result<[HierarchiesClient<InMemSigner>; N]> {
+        let mut clients = Vec::with_capacity(N);
+        for signer in self.funded_signers::<N>().await? {
+            clients.push(self.client(package_id, signer).await?);
+        }
+        Ok(clients
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly N clients are created")))
+    }
+}
+
+impl Drop for Localnet {
+    fn drop(&mut self) {
+        if let Some(node) = &mut self.node {
+            let _ = node.kill();
+            let _ = node.wait();
+        }
+    }
+}
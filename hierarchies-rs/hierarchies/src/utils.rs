@@ -14,30 +14,97 @@ use iota_interaction::types::transaction::{Argument, Command};
 use serde::{Deserialize, Deserializer};
 
 /// Deserialize a [`VecMap`] into a [`HashMap`]
+///
+/// Human-readable formats also accept the plain map the [`HashMap`] is serialized as, so values
+/// serialized by the SDK decode again.
 pub(crate) fn deserialize_vec_map<'de, D, K, V>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
 where
     D: Deserializer<'de>,
     K: Deserialize<'de> + Eq + Hash + Debug,
     V: Deserialize<'de> + Debug,
 {
+    if deserializer.is_human_readable() {
+        return Ok(match HumanReadableMap::<K, V>::deserialize(deserializer)? {
+            HumanReadableMap::Move(vec_map) => vec_map_entries(vec_map).collect(),
+            HumanReadableMap::Plain(map) => map,
+        });
+    }
+
     let vec_map = VecMap::<K, V>::deserialize(deserializer)?;
-    Ok(vec_map
-        .contents
-        .into_iter()
-        .map(|entry| (entry.key, entry.value))
-        .collect())
+    Ok(vec_map_entries(vec_map).collect())
 }
 
 /// Deserialize a [`VecSet`] into a [`HashSet`]
+///
+/// Human-readable formats also accept the plain sequence the [`HashSet`] is serialized as.
 pub(crate) fn deserialize_vec_set<'de, D, T>(deserializer: D) -> Result<HashSet<T>, D::Error>
 where
     D: Deserializer<'de>,
     T: Deserialize<'de> + Eq + Hash,
 {
+    if deserializer.is_human_readable() {
+        return Ok(HumanReadableSet::<T>::deserialize(deserializer)?.into_set());
+    }
+
     let vec_set = VecSet::<T>::deserialize(deserializer)?;
     Ok(vec_set.contents.into_iter().collect())
 }
 
+/// Deserialize a [`VecMap`] of [`VecSet`]s into a [`HashMap`] of [`HashSet`]s
+///
+/// Human-readable formats also accept the plain map of sequences the [`HashMap`] is serialized as.
+pub(crate) fn deserialize_vec_map_of_vec_sets<'de, D, K, T>(deserializer: D) -> Result<HashMap<K, HashSet<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash + Debug,
+    T: Deserialize<'de> + Eq + Hash + Debug,
+{
+    if deserializer.is_human_readable() {
+        let map: HashMap<K, HumanReadableSet<T>> = match HumanReadableMap::deserialize(deserializer)? {
+            HumanReadableMap::Move(vec_map) => vec_map_entries(vec_map).collect(),
+            HumanReadableMap::Plain(map) => map,
+        };
+        return Ok(map.into_iter().map(|(key, values)| (key, values.into_set())).collect());
+    }
+
+    let vec_map = VecMap::<K, VecSet<T>>::deserialize(deserializer)?;
+    Ok(vec_map_entries(vec_map)
+        .map(|(key, values)| (key, values.contents.into_iter().collect()))
+        .collect())
+}
+
+fn vec_map_entries<K, V>(vec_map: VecMap<K, V>) -> impl Iterator<Item = (K, V)> {
+    vec_map.contents.into_iter().map(|entry| (entry.key, entry.value))
+}
+
+/// A [`VecMap`] in its Move layout, as in the JSON of RPC responses, or as a plain map.
+#[derive(Deserialize)]
+#[serde(
+    untagged,
+    bound(deserialize = "K: Deserialize<'de> + Eq + Hash, V: Deserialize<'de>")
+)]
+enum HumanReadableMap<K, V> {
+    Move(VecMap<K, V>),
+    Plain(HashMap<K, V>),
+}
+
+/// A [`VecSet`] in its Move layout, as in the JSON of RPC responses, or as a plain sequence.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HumanReadableSet<T> {
+    Move(VecSet<T>),
+    Plain(Vec<T>),
+}
+
+impl<T: Eq + Hash> HumanReadableSet<T> {
+    fn into_set(self) -> HashSet<T> {
+        match self {
+            Self::Move(vec_set) => vec_set.contents.into_iter().collect(),
+            Self::Plain(values) => values.into_iter().collect(),
+        }
+    }
+}
+
 /// Convert an option value into a [`ProgrammableMoveCall`] argument
 pub(crate) fn option_to_move(
     option: Option<Argument>,
@@ -130,4 +197,28 @@ mod tests {
 
         assert_eq!(deserialized, expected);
     }
+
+    #[test]
+    fn test_deserialize_vec_map_of_vec_sets() {
+        let entry = Entry {
+            key: "flags".to_string(),
+            value: VecSet {
+                contents: vec!["yes".to_string(), "no".to_string()],
+            },
+        };
+        let vec_map = VecMap { contents: vec![entry] };
+
+        let json = serde_json::to_value(&vec_map).unwrap();
+
+        let deserialized: HashMap<String, HashSet<String>> = serde_json::from_value(json)
+            .and_then(|value: Value| deserialize_vec_map_of_vec_sets(value))
+            .unwrap();
+
+        let expected = HashMap::from([(
+            "flags".to_string(),
+            HashSet::from(["yes".to_string(), "no".to_string()]),
+        )]);
+
+        assert_eq!(deserialized, expected);
+    }
 }
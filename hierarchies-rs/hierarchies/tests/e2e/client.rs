@@ -4,22 +4,17 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
-use hierarchies::client::{HierarchiesClient, HierarchiesClientReadOnly};
+use hierarchies::client::HierarchiesClient;
+use hierarchies::testkit::{DEFAULT_PUBLISH_SCRIPT, Localnet};
 use iota_interaction::types::base_types::{IotaAddress, ObjectID};
 use iota_interaction::types::crypto::PublicKey;
-use iota_interaction::{IOTA_LOCAL_NETWORK_URL, IotaClientBuilder};
 use iota_interaction_rust::IotaClientAdapter;
 use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use product_common::network_name::NetworkName;
-use product_common::test_utils::{InMemSigner, init_product_package, request_funds};
+use product_common::test_utils::{InMemSigner, request_funds};
 use tokio::sync::OnceCell;
 
-/// Script file for publishing the package.
-pub const PUBLISH_SCRIPT_FILE: &str = concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/../../hierarchies-move/scripts/publish_hierarchies.sh"
-);
-
+static LOCALNET: OnceCell<Localnet> = OnceCell::const_new();
 static PACKAGE_ID: OnceCell<ObjectID> = OnceCell::const_new();
 
 pub async fn get_funded_test_client() -> anyhow::Result<TestClient> {
@@ -43,15 +38,13 @@ impl TestClient {
         let active_address = signer.get_address().await?;
         request_funds(&active_address).await?;
 
-        let api_endpoint = std::env::var("API_ENDPOINT").unwrap_or_else(|_| IOTA_LOCAL_NETWORK_URL.to_string());
-        let client = IotaClientBuilder::default().build(&api_endpoint).await?;
+        let localnet = LOCALNET.get_or_try_init(Localnet::attach_from_env).await?;
         let package_id = PACKAGE_ID
-            .get_or_try_init(|| init_product_package(&client, None, Some(PUBLISH_SCRIPT_FILE)))
+            .get_or_try_init(|| localnet.deploy_package(DEFAULT_PUBLISH_SCRIPT))
             .await
             .copied()?;
 
-        let ith_client = HierarchiesClientReadOnly::new_with_pkg_id(client, package_id).await?;
-        let client = HierarchiesClient::new(ith_client, signer).await?;
+        let client = localnet.client(package_id, signer).await?;
 
         Ok(TestClient {
             client: Arc::new(client),
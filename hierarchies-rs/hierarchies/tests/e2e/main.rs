@@ -3,6 +3,9 @@
 
 mod client;
 mod test_accreditations;
+mod test_attestations;
 mod test_authority;
 mod test_new_federation;
+mod test_offline;
+mod test_package;
 mod test_properties;
@@ -1,12 +1,18 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use hierarchies::client::{ClientError, PartialFailurePolicy};
+use hierarchies::core::transactions::{Destructive, MoveAbortMapper, TransactionError};
 use hierarchies::core::types::Federation;
+use hierarchies::core::types::accreditation_query::{AccreditationFilter, AccreditationQuery, AccreditationScope};
+use hierarchies::core::types::credential_anchor::anchor_property;
+use hierarchies::core::types::grant_policy::GrantPolicy;
 use hierarchies::core::types::property::FederationProperty;
 use hierarchies::core::types::property_name::PropertyName;
-use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::property_value::{PropertyValue, ValueSetMatch};
+use hierarchies::core::types::trust_chain::TrustChain;
 use iota_interaction::types::base_types::ObjectID;
 
 use crate::client::get_funded_test_client;
@@ -515,6 +521,51 @@ async fn test_create_accreditation_to_attest_fails_for_nonexistent_property() ->
     Ok(())
 }
 
+#[tokio::test]
+async fn test_property_not_in_federation_maps_to_typed_error() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let mapper = MoveAbortMapper::new();
+
+    // Create a new federation (but don't add any properties)
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+
+    let property = FederationProperty::new(PropertyName::from("nonexistent.certification")).with_allow_any(true);
+    let receiver_id = ObjectID::random();
+
+    let result = client
+        .create_accreditation_to_accredit(*federation_id.object_id(), receiver_id, vec![property.clone()])
+        .build_and_execute(&client)
+        .await;
+    let error = result.err().unwrap();
+    assert!(
+        matches!(
+            mapper.map_error(&error),
+            Some(TransactionError::PropertyNotInFederation { .. })
+        ),
+        "Expected PropertyNotInFederation error, got: {error:?}"
+    );
+
+    let result = client
+        .create_accreditation_to_attest(*federation_id.object_id(), receiver_id, vec![property])
+        .build_and_execute(&client)
+        .await;
+    let error = result.err().unwrap();
+    assert!(
+        matches!(
+            mapper.map_error(&error),
+            Some(TransactionError::PropertyNotInFederation { .. })
+        ),
+        "Expected PropertyNotInFederation error, got: {error:?}"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_accreditation_succeeds_after_adding_property() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -628,3 +679,829 @@ async fn test_create_accreditation_with_multiple_properties_partial_exist() -> a
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_validate_property_values_with_set_semantics() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+
+    let property_name = PropertyName::from("compliance");
+    let eu = PropertyValue::Text("eu".to_string());
+    let fda = PropertyValue::Text("fda".to_string());
+    let iso = PropertyValue::Text("iso".to_string());
+    let allowed_values = HashSet::from([eu.clone(), fda.clone(), iso.clone()]);
+
+    client
+        .add_property(
+            *federation_id.object_id(),
+            FederationProperty::new(property_name.clone()).with_allowed_values(allowed_values),
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    // The attester is accredited for `eu` and `fda` in a single grant
+    let receiver_id = ObjectID::random();
+    let property =
+        FederationProperty::new(property_name.clone()).with_allowed_values(HashSet::from([eu.clone(), fda.clone()]));
+    client
+        .create_accreditation_to_attest(*federation_id.object_id(), receiver_id, vec![property])
+        .build_and_execute(&client)
+        .await?;
+
+    let validate = |values: Vec<PropertyValue>, value_match: ValueSetMatch| {
+        client.validate_property_values(
+            *federation_id.object_id(),
+            receiver_id,
+            property_name.clone(),
+            values,
+            value_match,
+        )
+    };
+
+    assert!(validate(vec![eu.clone(), fda.clone()], ValueSetMatch::Superset).await?);
+    assert!(!validate(vec![eu.clone(), iso.clone()], ValueSetMatch::Superset).await?);
+    assert!(validate(vec![eu.clone(), iso.clone()], ValueSetMatch::Contains).await?);
+    assert!(!validate(vec![iso], ValueSetMatch::Contains).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_accreditations() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+    let federation_id = *federation_id.object_id();
+
+    let iso_name = PropertyName::new(["iso", "9001"]);
+    let role_name = PropertyName::from("role");
+    for name in [&iso_name, &role_name] {
+        client
+            .add_property(
+                federation_id,
+                FederationProperty::new(name.clone()).with_allow_any(true),
+            )
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let receiver_id = ObjectID::random();
+    client
+        .create_accreditation_to_attest(
+            federation_id,
+            receiver_id,
+            vec![
+                FederationProperty::new(iso_name.clone()).with_allow_any(true),
+                FederationProperty::new(role_name).with_allow_any(true),
+            ],
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let query = AccreditationQuery::new()
+        .for_entity(receiver_id)
+        .scope(AccreditationScope::Attest)
+        .property_prefix("iso");
+
+    // The on-chain view and the local evaluation yield the same result
+    let on_chain = client.query_accreditations(federation_id, &query).await?;
+    let federation = client.get_federation_by_id(federation_id).await?;
+    assert_eq!(on_chain, query.evaluate(&federation));
+
+    assert_eq!(on_chain.len(), 1);
+    assert_eq!(on_chain[0].entity_id, receiver_id);
+    assert_eq!(
+        on_chain[0].accreditation.properties.keys().collect::<Vec<_>>(),
+        vec![&iso_name]
+    );
+
+    let accredit_query = AccreditationQuery::new().scope(AccreditationScope::Accredit);
+    let accreditors = client.query_accreditations(federation_id, &accredit_query).await?;
+    assert!(accreditors.iter().all(|m| m.entity_id != receiver_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_accreditations_covered_by_wildcard() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let bachelor = PropertyName::new(["degree", "bachelor"]);
+    let role = FederationProperty::new("role").with_allow_any(true);
+    for property in [
+        FederationProperty::new(bachelor.clone()).with_allow_any(true),
+        role.clone(),
+    ] {
+        client
+            .add_property(federation_id, property)
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let receiver_id = ObjectID::random();
+    let degrees = FederationProperty::new_prefix("degree").with_allow_any(true);
+    for property in [degrees.clone(), role] {
+        client
+            .create_accreditation_to_attest(federation_id, receiver_id, vec![property])
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let query = AccreditationQuery::new()
+        .for_entity(receiver_id)
+        .scope(AccreditationScope::Attest)
+        .property_prefix(bachelor.clone());
+
+    // The on-chain view and the local evaluation both return the wildcard accreditation
+    let on_chain = client.query_accreditations(federation_id, &query).await?;
+    let federation = client.get_federation_by_id(federation_id).await?;
+    assert_eq!(on_chain, query.evaluate(&federation));
+
+    assert_eq!(on_chain.len(), 1);
+    assert_eq!(
+        on_chain[0].accreditation.properties.keys().collect::<Vec<_>>(),
+        vec![&degrees.name]
+    );
+
+    let filter = AccreditationFilter::new().property_name(bachelor).active_only();
+    let page = client
+        .get_accreditations_to_attest_paged(federation_id, receiver_id, None, 10, &filter)
+        .await?;
+    assert_eq!(page.accreditations.len(), 1);
+    assert_eq!(page.accreditations[0].id, on_chain[0].accreditation.id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_accreditations_of_unknown_entity() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+    let federation = client.get_federation_by_id(federation_id).await?;
+
+    let unknown = ObjectID::random();
+    for scope in [AccreditationScope::Attest, AccreditationScope::Accredit] {
+        let query = AccreditationQuery::new().for_entity(unknown).scope(scope);
+
+        // The on-chain path agrees with the local evaluation instead of aborting
+        let on_chain = client.query_accreditations(federation_id, &query).await?;
+        assert!(on_chain.is_empty());
+        assert_eq!(on_chain, query.evaluate(&federation));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_is_allowed_to_attest_and_accredit() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+    let federation_id = *federation_id.object_id();
+
+    let property_name = PropertyName::from("role");
+    let property =
+        FederationProperty::new(property_name.clone()).with_allowed_values([PropertyValue::Text("admin".to_string())]);
+    client
+        .add_property(federation_id, property.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    let attester = ObjectID::random();
+    client
+        .create_accreditation_to_attest(federation_id, attester, vec![property.clone()])
+        .build_and_execute(&client)
+        .await?;
+    let accreditor = ObjectID::random();
+    client
+        .create_accreditation_to_accredit(federation_id, accreditor, vec![property])
+        .build_and_execute(&client)
+        .await?;
+
+    let admin = PropertyValue::Text("admin".to_string());
+    assert!(
+        client
+            .is_allowed_to_attest(federation_id, attester, property_name.clone(), admin.clone())
+            .await?
+    );
+    assert!(
+        !client
+            .is_allowed_to_attest(federation_id, accreditor, property_name.clone(), admin)
+            .await?
+    );
+    assert!(
+        client
+            .is_allowed_to_accredit(federation_id, accreditor, property_name.clone())
+            .await?
+    );
+    assert!(
+        !client
+            .is_allowed_to_accredit(federation_id, attester, property_name)
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_trust_chain() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let accreditor_client = get_funded_test_client().await?;
+
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+    let federation_id = *federation_id.object_id();
+
+    let property = FederationProperty::new("degree").with_allow_any(true);
+    client
+        .add_property(federation_id, property.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    let root: ObjectID = client.sender_address().into();
+    let accreditor: ObjectID = accreditor_client.sender_address().into();
+    client
+        .create_accreditation_to_accredit(federation_id, accreditor, vec![property.clone()])
+        .build_and_execute(&client)
+        .await?;
+    let attester = ObjectID::random();
+    accreditor_client
+        .create_accreditation_to_attest(federation_id, attester, vec![property.clone()])
+        .build_and_execute(&accreditor_client)
+        .await?;
+
+    let chain: TrustChain = client
+        .resolve_trust_chain(federation_id, attester, &property.name)
+        .await?
+        .expect("attester should have a trust chain");
+    assert_eq!(chain.entities().collect::<Vec<_>>(), vec![root, accreditor, attester]);
+    assert_eq!(chain.links[0].scope, AccreditationScope::Accredit);
+    assert_eq!(chain.links[1].scope, AccreditationScope::Attest);
+    assert_eq!(chain.links[1].issuer, accreditor);
+
+    let unknown = client
+        .resolve_trust_chain(federation_id, ObjectID::random(), &property.name)
+        .await?;
+    assert!(unknown.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_linked_trust_chain() -> anyhow::Result<()> {
+    let global = get_funded_test_client().await?;
+    let europe = get_funded_test_client().await?;
+
+    let global_id = *global
+        .create_new_federation()
+        .build_and_execute(&global)
+        .await?
+        .output
+        .id
+        .object_id();
+    let europe_id = *europe
+        .create_new_federation()
+        .build_and_execute(&europe)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let property = FederationProperty::new("iso.9001").with_allow_any(true);
+    europe
+        .add_property(europe_id, property.clone())
+        .build_and_execute(&europe)
+        .await?;
+    let attester = ObjectID::random();
+    europe
+        .create_accreditation_to_attest(europe_id, attester, vec![property.clone()])
+        .build_and_execute(&europe)
+        .await?;
+
+    assert!(
+        global
+            .resolve_linked_trust_chain(global_id, attester, &property.name)
+            .await?
+            .is_none()
+    );
+
+    global
+        .link_federation(global_id, europe_id)
+        .build_and_execute(&global)
+        .await?;
+    assert_eq!(global.get_recognized_federations(global_id).await?, vec![europe_id]);
+
+    let linked = global
+        .resolve_linked_trust_chain(global_id, attester, &property.name)
+        .await?
+        .expect("attester should be trusted through the link");
+    assert_eq!(linked.federations, vec![global_id, europe_id]);
+    assert_eq!(linked.chain.root_authority, europe.sender_address().into());
+
+    let result = global
+        .link_federation(global_id, europe_id)
+        .build_and_execute(&global)
+        .await;
+    assert!(matches!(
+        MoveAbortMapper::new().map_error(&result.unwrap_err()),
+        Some(TransactionError::FederationAlreadyLinked)
+    ));
+
+    global
+        .unlink_federation(global_id, europe_id)
+        .build_and_execute(&global)
+        .await?;
+    assert!(
+        global
+            .resolve_linked_trust_chain(global_id, attester, &property.name)
+            .await?
+            .is_none()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_create_accreditations_to_attest_policies() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+    let federation_id = *federation_id.object_id();
+
+    let known = FederationProperty::new("known").with_allow_any(true);
+    client
+        .add_property(federation_id, known.clone())
+        .build_and_execute(&client)
+        .await?;
+    let unknown = FederationProperty::new("unknown").with_allow_any(true);
+
+    let grants = || {
+        vec![
+            (ObjectID::random(), vec![known.clone()]),
+            (ObjectID::random(), vec![unknown.clone()]),
+        ]
+    };
+
+    // Atomic: the failing grant rolls back the whole batch
+    let atomic_grants = grants();
+    let results = client
+        .batch_create_accreditations_to_attest(federation_id, atomic_grants.clone(), PartialFailurePolicy::Atomic)
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| !r.is_ok()));
+    assert!(!client.is_attester(federation_id, atomic_grants[0].0).await?);
+
+    // Continue and report: the valid grant is applied, the failing one is reported
+    let partial_grants = grants();
+    let results = client
+        .batch_create_accreditations_to_attest(
+            federation_id,
+            partial_grants.clone(),
+            PartialFailurePolicy::ContinueAndReport,
+        )
+        .await;
+    assert!(results[0].is_ok());
+    assert!(!results[1].is_ok());
+    assert!(client.is_attester(federation_id, partial_grants[0].0).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_revoke_accreditations_to_attest_requires_confirmation() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let known = FederationProperty::new("known").with_allow_any(true);
+    client
+        .add_property(federation_id, known.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    let receivers = [ObjectID::random(), ObjectID::random()];
+    client
+        .create_accreditations_to_attest(federation_id, receivers.map(|receiver| (receiver, vec![known.clone()])))
+        .build_and_execute(&client)
+        .await?;
+
+    let mut revocations = vec![];
+    for receiver in receivers {
+        let query = AccreditationQuery::new()
+            .for_entity(receiver)
+            .scope(AccreditationScope::Attest);
+        let accreditations = client.query_accreditations(federation_id, &query).await?;
+        revocations.push((receiver, *accreditations[0].accreditation.id.object_id()));
+    }
+
+    // Revoking several accreditations at once must not be built without confirmation
+    let result = client
+        .revoke_accreditations_to_attest(federation_id, revocations.clone())
+        .build_and_execute(&client)
+        .await;
+    let error = result.expect_err("unconfirmed revocations should fail");
+    assert!(format!("{error:?}").contains("DestructiveNotConfirmed"));
+    assert!(client.is_attester(federation_id, receivers[0]).await?);
+
+    client
+        .revoke_accreditations_to_attest(federation_id, revocations)
+        .confirm_irreversible()
+        .build_and_execute(&client)
+        .await?;
+    for receiver in receivers {
+        assert!(!client.is_attester(federation_id, receiver).await?);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bulk_and_transaction_batch_policies() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let known = FederationProperty::new("known").with_allow_any(true);
+    client
+        .add_property(federation_id, known.clone())
+        .build_and_execute(&client)
+        .await?;
+    let unknown = FederationProperty::new("unknown").with_allow_any(true);
+
+    // Bulk, continue and report: the grants of the aborted transaction are retried one by one
+    let grants = vec![
+        (ObjectID::random(), vec![known.clone()]),
+        (ObjectID::random(), vec![unknown.clone()]),
+    ];
+    let results = client
+        .create_accreditations_to_attest_bulk(federation_id, grants.clone(), PartialFailurePolicy::ContinueAndReport)
+        .await;
+    assert!(results[0].is_ok());
+    let error = results[1].result.as_ref().unwrap_err();
+    assert!(matches!(**error, ClientError::Transaction(_)), "{error:?}");
+    assert!(client.is_attester(federation_id, grants[0].0).await?);
+
+    // Transaction batch, atomic: the unknown property rolls back the whole batch
+    let receiver = ObjectID::random();
+    let batch = || {
+        client
+            .transaction_batch(federation_id)
+            .create_accreditation_to_attest(receiver, [known.clone()])
+            .create_accreditation_to_attest(ObjectID::random(), [unknown.clone()])
+    };
+    let results = client
+        .execute_transaction_batch(batch(), PartialFailurePolicy::Atomic)
+        .await;
+    assert!(results.iter().all(|r| !r.is_ok()));
+    assert!(!client.is_attester(federation_id, receiver).await?);
+
+    let results = client
+        .execute_transaction_batch(batch(), PartialFailurePolicy::ContinueAndReport)
+        .await;
+    assert!(results[0].is_ok());
+    assert!(!results[1].is_ok());
+    assert!(client.is_attester(federation_id, receiver).await?);
+
+    // Batch validation, continue and report: a missing federation only fails its own check
+    let checks = vec![
+        (
+            federation_id,
+            HashMap::from([(known.name.clone(), PropertyValue::Number(1))]),
+        ),
+        (
+            ObjectID::random(),
+            HashMap::from([(known.name.clone(), PropertyValue::Number(1))]),
+        ),
+    ];
+    let results = client
+        .batch_validate_properties(checks, receiver, PartialFailurePolicy::ContinueAndReport)
+        .await;
+    assert!(matches!(results[0].result, Ok(true)));
+    assert!(!results[1].is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grant_policy() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let property_name = PropertyName::from("role");
+    let property = FederationProperty::new(property_name.clone()).with_allowed_values([PropertyValue::Number(1)]);
+    client
+        .add_property(federation_id, property.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    let policy = GrantPolicy::default()
+        .with_default_validity_ms(60_000)
+        .with_allow_any_permitted(false)
+        .with_max_allowed_values(1);
+    client
+        .set_grant_policy(federation_id, policy.clone())
+        .build_and_execute(&client)
+        .await?;
+    assert_eq!(client.get_grant_policy(federation_id).await?, policy);
+
+    // Grants violating the policy are rejected before submission
+    let receiver = ObjectID::random();
+    let rejected = client
+        .create_accreditation_to_attest(federation_id, receiver, vec![property.clone().with_allow_any(true)])
+        .build_and_execute(&client)
+        .await;
+    assert!(rejected.is_err());
+
+    // The default validity is applied to grants without an explicit end
+    client
+        .create_accreditation_to_attest(federation_id, receiver, vec![property])
+        .build_and_execute(&client)
+        .await?;
+
+    let query = AccreditationQuery::new()
+        .for_entity(receiver)
+        .scope(AccreditationScope::Attest);
+    let accreditations = client.query_accreditations(federation_id, &query).await?;
+    let granted = &accreditations[0].accreditation.properties[&property_name];
+    assert!(granted.timespan.valid_until_ms.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tags() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let property_name = PropertyName::from("haccp.certified");
+    let property = FederationProperty::new(property_name.clone()).with_allowed_values([PropertyValue::Number(1)]);
+    client
+        .add_property(federation_id, property.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    client
+        .set_property_tags(federation_id, property_name.clone(), ["food-safety"])
+        .build_and_execute(&client)
+        .await?;
+    assert_eq!(
+        client.get_properties_by_tag(federation_id, "food-safety").await?,
+        vec![property_name]
+    );
+
+    let receiver = ObjectID::random();
+    client
+        .create_accreditation_to_attest(federation_id, receiver, vec![property])
+        .build_and_execute(&client)
+        .await?;
+    let query = AccreditationQuery::new()
+        .for_entity(receiver)
+        .scope(AccreditationScope::Attest);
+    let accreditations = client.query_accreditations(federation_id, &query).await?;
+    let accreditation_id = *accreditations[0].accreditation.id.object_id();
+
+    client
+        .set_accreditation_tags(federation_id, accreditation_id, ["audit-2025"])
+        .build_and_execute(&client)
+        .await?;
+    assert_eq!(
+        client.get_accreditation_tags(federation_id, accreditation_id).await?,
+        vec!["audit-2025".to_string()]
+    );
+
+    let matches = client
+        .query_accreditations(federation_id, &AccreditationQuery::new().tagged("audit-2025"))
+        .await?;
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].entity_id, receiver);
+
+    let matches = client
+        .query_accreditations(federation_id, &AccreditationQuery::new().tagged("other"))
+        .await?;
+    assert!(matches.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_anchor_credential() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let property_name = PropertyName::from("credential.diploma");
+    client
+        .add_property(federation_id, anchor_property(property_name.clone()))
+        .build_and_execute(&client)
+        .await?;
+
+    let issuer = ObjectID::random();
+    let credential = b"%PDF-1.7 diploma of Alice";
+    client
+        .anchor_credential(federation_id, issuer, property_name.clone(), credential)
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(
+        client
+            .verify_anchored_credential(federation_id, issuer, property_name.clone(), credential)
+            .await?
+    );
+    assert!(
+        !client
+            .verify_anchored_credential(
+                federation_id,
+                issuer,
+                property_name.clone(),
+                b"%PDF-1.7 diploma of Mallory"
+            )
+            .await?
+    );
+    assert!(
+        !client
+            .verify_anchored_credential(federation_id, ObjectID::random(), property_name, credential)
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_accreditations_paged() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let role = FederationProperty::new("role").with_allowed_values([PropertyValue::Text("admin".into())]);
+    let degree = FederationProperty::new("degree").with_allowed_values([PropertyValue::Text("msc".into())]);
+    for property in [&role, &degree] {
+        client
+            .add_property(federation_id, property.clone())
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let receiver = ObjectID::random();
+    for property in [&role, &degree, &role] {
+        client
+            .create_accreditation_to_attest(federation_id, receiver, vec![property.clone()])
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let first = client
+        .get_accreditations_to_attest_paged(federation_id, receiver, None, 2, &AccreditationFilter::new())
+        .await?;
+    assert_eq!(first.accreditations.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = client
+        .get_accreditations_to_attest_paged(
+            federation_id,
+            receiver,
+            first.next_cursor,
+            2,
+            &AccreditationFilter::new(),
+        )
+        .await?;
+    assert_eq!(second.accreditations.len(), 1);
+    assert_eq!(second.next_cursor, None);
+
+    let filter = AccreditationFilter::new()
+        .property_name("role")
+        .accredited_by(client.sender_address().into())
+        .active_only();
+    let roles = client
+        .get_accreditations_to_attest_paged(federation_id, receiver, None, 10, &filter)
+        .await?;
+    assert_eq!(roles.accreditations.len(), 2);
+
+    let unknown = client
+        .get_accreditations_to_accredit_paged(federation_id, receiver, None, 10, &AccreditationFilter::new())
+        .await?;
+    assert!(unknown.accreditations.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_batcher() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let role = FederationProperty::new("role").with_allowed_values([PropertyValue::Text("admin".into())]);
+    client
+        .add_property(federation_id, role.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    let receiver = ObjectID::random();
+    client
+        .create_accreditation_to_attest(federation_id, receiver, vec![role.clone()])
+        .build_and_execute(&client)
+        .await?;
+
+    let mut batch = client.query_batcher();
+    let is_attester = batch.is_attester(federation_id, receiver);
+    let is_accreditor = batch.is_accreditor(federation_id, receiver);
+    let is_root_authority = batch.is_root_authority(federation_id, client.sender_address().into());
+    let accreditations = batch.get_accreditations_to_attest(federation_id, receiver);
+    let properties = batch.get_properties(federation_id);
+    let has_degree = batch.is_property_in_federation(federation_id, PropertyName::from("degree"));
+    assert_eq!(batch.len(), 6);
+
+    let results = batch.execute().await?;
+    assert!(results.get(is_attester)?);
+    assert!(!results.get(is_accreditor)?);
+    assert!(results.get(is_root_authority)?);
+    assert_eq!(results.get(accreditations)?.accreditations.len(), 1);
+    assert_eq!(results.get(properties)?, vec![role.name]);
+    assert!(!results.get(has_degree)?);
+
+    Ok(())
+}
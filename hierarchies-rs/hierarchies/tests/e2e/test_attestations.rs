@@ -0,0 +1,201 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use hierarchies::core::transactions::{MoveAbortMapper, TransactionError};
+use hierarchies::core::types::property::FederationProperty;
+use hierarchies::core::types::property_name::PropertyName;
+use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::selective_disclosure::{DisclosureProof, disclosure_property};
+use hierarchies::core::types::timespan::Timespan;
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn test_create_and_revoke_attestation() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let property_name = PropertyName::from("degree.level");
+    let value = PropertyValue::Text("master".to_string());
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allowed_values(HashSet::from([value.clone()])),
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    // Root authorities need an accreditation to attest like every other attester
+    client
+        .create_accreditation_to_attest(
+            federation_id,
+            client.sender_address().into(),
+            vec![FederationProperty::new(property_name.clone()).with_allowed_values(HashSet::from([value.clone()]))],
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let attestation = client
+        .create_attestation(
+            federation_id,
+            client.sender_address(),
+            [(property_name.clone(), value.clone())],
+            Timespan::default(),
+        )
+        .build_and_execute(&client)
+        .await?
+        .output;
+
+    assert_eq!(attestation.federation_id, federation_id);
+    assert_eq!(attestation.properties.get(&property_name), Some(&value));
+    assert_eq!(client.get_attestation(attestation.id()).await?, attestation);
+    assert!(client.validate_attestation(federation_id, attestation.id()).await?);
+
+    client
+        .revoke_attestation(federation_id, attestation.id())
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(client.is_attestation_revoked(federation_id, attestation.id()).await?);
+    assert!(!client.validate_attestation(federation_id, attestation.id()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_attestation_with_invalid_validity_window() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let property_name = PropertyName::from("degree.level");
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .build_and_execute(&client)
+        .await?;
+    client
+        .create_accreditation_to_attest(
+            federation_id,
+            client.sender_address().into(),
+            vec![FederationProperty::new(property_name.clone()).with_allow_any(true)],
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let result = client
+        .create_attestation(
+            federation_id,
+            client.sender_address(),
+            [(property_name, PropertyValue::Number(1))],
+            Timespan {
+                valid_from_ms: Some(2000),
+                valid_until_ms: Some(1000),
+            },
+        )
+        .build_and_execute(&client)
+        .await;
+
+    let error = result.expect_err("the validity window ends before it starts");
+    assert!(matches!(
+        MoveAbortMapper::new().map_error(&error),
+        Some(TransactionError::InvalidValidityWindow)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_attestation_without_properties() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let result = client
+        .create_attestation(federation_id, client.sender_address(), [], Timespan::default())
+        .build_and_execute(&client)
+        .await;
+
+    let error = result.expect_err("an attestation needs at least one property");
+    assert!(matches!(
+        MoveAbortMapper::new().map_error(&error),
+        Some(TransactionError::EmptyAttestation)
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_disclose_blinded_attestation() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let gpa = PropertyName::from("student.gpa");
+    let degree = PropertyName::from("student.degree");
+    for name in [&gpa, &degree] {
+        client
+            .add_property(federation_id, disclosure_property(name.clone()))
+            .build_and_execute(&client)
+            .await?;
+    }
+    client
+        .create_accreditation_to_attest(
+            federation_id,
+            client.sender_address().into(),
+            vec![disclosure_property(gpa.clone()), disclosure_property(degree.clone())],
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let (builder, disclosures) = client.create_blinded_attestation(
+        federation_id,
+        client.sender_address(),
+        [
+            (gpa.clone(), PropertyValue::Number(38)),
+            (degree.clone(), PropertyValue::Text("MSc".to_string())),
+        ],
+        Timespan::default(),
+    );
+    let attestation = builder.build_and_execute(&client).await?.output;
+
+    // The raw values are not stored on-chain
+    assert_ne!(attestation.properties.get(&gpa), Some(&PropertyValue::Number(38)));
+    assert!(client.validate_attestation(federation_id, attestation.id()).await?);
+
+    let proof = DisclosureProof::select(attestation.id(), &disclosures, [&gpa]);
+    let revealed = proof.verify(&client.get_attestation(proof.attestation_id).await?)?;
+    assert_eq!(revealed.get(&gpa), Some(&PropertyValue::Number(38)));
+    assert!(!revealed.contains_key(&degree));
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use hierarchies::core::transactions::{Destructive, MoveAbortMapper, TransactionError};
+use hierarchies::core::types::CapabilityKind;
 use iota_interaction::types::base_types::ObjectID;
 use product_common::core_client::CoreClient;
 
@@ -70,6 +72,7 @@ async fn test_revoke_root_authority_success() -> anyhow::Result<()> {
     // Revoke Bob as root authority
     client
         .revoke_root_authority(*federation.object_id(), bob_id)
+        .confirm_irreversible()
         .build_and_execute(&client)
         .await?;
 
@@ -81,6 +84,74 @@ async fn test_revoke_root_authority_success() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_rotate_root_authority_success() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    // Create a new federation
+    let federation = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await
+        .unwrap()
+        .output
+        .id;
+
+    let alice_id = ObjectID::from_address(client.sender_address());
+    let bob_id = ObjectID::random();
+
+    // Alice hands her root authority over to Bob
+    client
+        .rotate_root_authority(*federation.object_id(), alice_id, bob_id)
+        .confirm_irreversible()
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(!client.is_root_authority(*federation.object_id(), alice_id).await?);
+    assert!(client.is_root_authority(*federation.object_id(), bob_id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rotate_root_authority_to_existing_is_atomic() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    // Create a new federation
+    let federation = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await
+        .unwrap()
+        .output
+        .id;
+
+    let alice_id = ObjectID::from_address(client.sender_address());
+    let bob_id = ObjectID::random();
+
+    client
+        .add_root_authority(*federation.object_id(), bob_id)
+        .build_and_execute(&client)
+        .await?;
+
+    let result = client
+        .rotate_root_authority(*federation.object_id(), alice_id, bob_id)
+        .confirm_irreversible()
+        .build_and_execute(&client)
+        .await;
+
+    assert!(matches!(
+        MoveAbortMapper::new().map_error(&result.unwrap_err()),
+        Some(TransactionError::AlreadyRootAuthority)
+    ));
+
+    // Alice was not revoked
+    assert!(client.is_root_authority(*federation.object_id(), alice_id).await?);
+    assert!(client.is_root_authority(*federation.object_id(), bob_id).await?);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_revoke_root_authority_not_found() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -99,6 +170,7 @@ async fn test_revoke_root_authority_not_found() -> anyhow::Result<()> {
     // Try to revoke a non-existent root authority
     let result = client
         .revoke_root_authority(*federation.object_id(), non_existent_id)
+        .confirm_irreversible()
         .build_and_execute(&client)
         .await;
 
@@ -128,6 +200,7 @@ async fn test_cannot_revoke_last_root_authority() -> anyhow::Result<()> {
     // Try to revoke the only root authority (Alice)
     let result = client
         .revoke_root_authority(*federation.object_id(), alice_id)
+        .confirm_irreversible()
         .build_and_execute(&client)
         .await;
 
@@ -199,6 +272,7 @@ async fn test_reinstate_root_authority_success() -> anyhow::Result<()> {
 
     client
         .revoke_root_authority(*federation.object_id(), bob_id)
+        .confirm_irreversible()
         .build_and_execute(&client)
         .await?;
 
@@ -273,6 +347,68 @@ async fn test_reinstate_root_authority_already_active() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_root_authority_aborts_map_to_typed_errors() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let mapper = MoveAbortMapper::new();
+
+    // Create a new federation
+    let federation = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await
+        .unwrap()
+        .output
+        .id;
+
+    let alice_id = ObjectID::from_address(client.sender_address());
+    let bob_id = ObjectID::random();
+
+    let result = client
+        .revoke_root_authority(*federation.object_id(), bob_id)
+        .confirm_irreversible()
+        .build_and_execute(&client)
+        .await;
+    assert!(matches!(
+        mapper.map_error(&result.unwrap_err()),
+        Some(TransactionError::NotRootAuthority)
+    ));
+
+    let result = client
+        .revoke_root_authority(*federation.object_id(), alice_id)
+        .confirm_irreversible()
+        .build_and_execute(&client)
+        .await;
+    assert!(matches!(
+        mapper.map_error(&result.unwrap_err()),
+        Some(TransactionError::LastRootAuthority)
+    ));
+
+    let result = client
+        .reinstate_root_authority(*federation.object_id(), bob_id)
+        .build_and_execute(&client)
+        .await;
+    assert!(matches!(
+        mapper.map_error(&result.unwrap_err()),
+        Some(TransactionError::RootAuthorityNotRevoked)
+    ));
+
+    client
+        .add_root_authority(*federation.object_id(), bob_id)
+        .build_and_execute(&client)
+        .await?;
+    let result = client
+        .reinstate_root_authority(*federation.object_id(), bob_id)
+        .build_and_execute(&client)
+        .await;
+    assert!(matches!(
+        mapper.map_error(&result.unwrap_err()),
+        Some(TransactionError::AlreadyRootAuthority)
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_reinstated_authority_can_perform_actions() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -295,6 +431,7 @@ async fn test_reinstated_authority_can_perform_actions() -> anyhow::Result<()> {
 
     client
         .revoke_root_authority(*federation.object_id(), bob_id)
+        .confirm_irreversible()
         .build_and_execute(&client)
         .await?;
 
@@ -316,3 +453,39 @@ async fn test_reinstated_authority_can_perform_actions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_list_my_capabilities() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    // Create two federations
+    let first = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+    let second = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let capabilities = client.list_my_capabilities().await?;
+
+    // The creator holds a RootAuthorityCap and an AccreditCap for each federation
+    for federation_id in [first, second] {
+        for kind in [CapabilityKind::RootAuthority, CapabilityKind::Accredit] {
+            assert!(
+                capabilities
+                    .iter()
+                    .any(|cap| cap.federation_id == federation_id && cap.kind == kind)
+            );
+        }
+    }
+
+    Ok(())
+}
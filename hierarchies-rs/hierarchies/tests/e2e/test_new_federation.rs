@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hierarchies::core::types::Federation;
-use hierarchies::core::types::events::FederationCreatedEvent;
+use hierarchies::core::types::events::{self, FederationCreatedEvent, HierarchiesEvent};
+use hierarchies::core::types::property::FederationProperty;
 use iota_interaction::types::base_types::ObjectID;
-use product_common::core_client::CoreClient;
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
 
 use crate::client::get_funded_test_client;
 
@@ -42,6 +43,23 @@ async fn test_creation_of_federation() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_creation_events_are_decoded() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation = client.create_new_federation().build_and_execute(&client).await?;
+    let federation_id = *federation.output.id.object_id();
+
+    let events = events::decode_all(&federation.response.events.unwrap(), &client.package_history())?;
+
+    match events.first() {
+        Some(HierarchiesEvent::FederationCreated(event)) => assert_eq!(event.federation_address, federation_id),
+        other => panic!("unexpected event {other:?}"),
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_creation_of_federation_with_root_authorities() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -79,3 +97,36 @@ async fn test_creation_of_federation_with_root_authorities() -> anyhow::Result<(
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_federation_stats() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let federation = client.create_new_federation().build_and_execute(&client).await?.output;
+    let federation_id = *federation.id.object_id();
+    assert_eq!(federation.stats.root_authority_count, 1);
+    assert_eq!(federation.stats.modification_count, 0);
+
+    client
+        .add_property(federation_id, FederationProperty::new("role").with_allow_any(true))
+        .build_and_execute(&client)
+        .await?;
+    client
+        .create_accreditation_to_attest(
+            federation_id,
+            ObjectID::random(),
+            vec![FederationProperty::new("role").with_allow_any(true)],
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let stats = client.get_federation_stats(federation_id).await?;
+    assert_eq!(stats.property_count, 1);
+    assert_eq!(stats.root_authority_count, 1);
+    assert_eq!(stats.accreditation_to_attest_count, 1);
+    assert_eq!(stats.accreditation_to_accredit_count, 0);
+    assert_eq!(stats.modification_count, 2);
+    assert_eq!(stats, client.get_federation_by_id(federation_id).await?.stats);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hierarchies::core::offline;
+use hierarchies::core::offline::vectors::{DICTIONARY, validation_vectors};
+use hierarchies::core::transactions::Destructive;
+use hierarchies::core::types::property_name::PropertyName;
+use iota_interaction::types::base_types::ObjectID;
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn test_offline_validation_parity() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let vectors = validation_vectors(|i| PropertyName::new(["vector".to_string(), i.to_string()]));
+    let mut attesters = Vec::with_capacity(vectors.len());
+    for vector in &vectors {
+        if let Some(values) = &vector.dictionary {
+            client
+                .set_value_dictionary(federation_id, DICTIONARY, values.clone())
+                .build_and_execute(&client)
+                .await?;
+        }
+        client
+            .add_property(federation_id, vector.federation_property.clone())
+            .build_and_execute(&client)
+            .await?;
+
+        let attester = ObjectID::random();
+        if let Some(grant) = &vector.grant {
+            client
+                .create_accreditation_to_attest(federation_id, attester, [grant.clone()])
+                .build_and_execute(&client)
+                .await?;
+        }
+        if vector.revoked {
+            client
+                .revoke_property(federation_id, vector.federation_property.name.clone(), None)
+                .confirm_irreversible()
+                .build_and_execute(&client)
+                .await?;
+        }
+        attesters.push(attester);
+    }
+
+    let federation = client.get_federation_by_id(federation_id).await?;
+    let now_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+
+    for (vector, attester) in vectors.iter().zip(&attesters) {
+        let name = &vector.federation_property.name;
+        let on_chain = client
+            .validate_property(federation_id, *attester, name.clone(), vector.value.clone())
+            .await?;
+        let offline = offline::validate_property(&federation, attester, name, &vector.value, now_ms);
+
+        assert_eq!(on_chain, vector.expected, "on-chain: {}", vector.name);
+        assert_eq!(offline, on_chain, "offline: {}", vector.name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use hierarchies::client::ClientError;
+use hierarchies::error::ConfigError;
+use iota_interaction::IotaClientTrait;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::types::digests::ObjectDigest;
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn test_verify_package_integrity() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    let package = client
+        .read_api()
+        .get_object_with_options(client.package_id(), IotaObjectDataOptions::new())
+        .await?
+        .data
+        .expect("the package is deployed");
+
+    client.verify_package_integrity(package.digest).await?;
+
+    let error = client
+        .verify_package_integrity(ObjectDigest::random())
+        .await
+        .expect_err("a different digest must be rejected");
+    assert!(
+        matches!(
+            error,
+            ClientError::Configuration(ConfigError::PackageIntegrityMismatch { .. })
+        ),
+        "{error:?}"
+    );
+
+    Ok(())
+}
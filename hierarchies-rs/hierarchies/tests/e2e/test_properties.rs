@@ -1,14 +1,25 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
+use futures::{StreamExt, TryStreamExt};
+use hierarchies::client::events::{SubscribeOptions, SubscriptionMode};
 use hierarchies::client::get_object_ref_by_id_with_bcs;
-use hierarchies::core::types::Federation;
+use hierarchies::core::offline::{ValidationWarning, diagnose_property};
+use hierarchies::core::prefetch::{PrefetchedInputs, WithPrefetchedInputs};
+use hierarchies::core::transactions::{
+    Destructive, InvariantChecked, MoveAbortMapper, TransactionError, WithCapability,
+};
+use hierarchies::core::types::events::HierarchiesEvent;
+use hierarchies::core::types::federation_set::FederationSet;
 use hierarchies::core::types::property::FederationProperty;
 use hierarchies::core::types::property_name::PropertyName;
 use hierarchies::core::types::property_shape::PropertyShape;
 use hierarchies::core::types::property_value::PropertyValue;
+use hierarchies::core::types::{CapabilityKind, Federation, RootAuthorityCap};
+use iota_interaction::types::base_types::ObjectID;
 use product_common::core_client::{CoreClient, CoreClientReadOnly};
 
 use crate::client::{TestClient, get_funded_test_client};
@@ -68,6 +79,24 @@ async fn test_add_property() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_add_property_rejects_wildcard_name() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+
+    // Wildcards can be built like any property, but only granted as accreditations
+    let wildcard = FederationProperty::new(PropertyName::new(["degree", "*"])).with_allow_any(true);
+    let result = client
+        .add_property(*federation.id.object_id(), wildcard)
+        .build_and_execute(&client)
+        .await;
+
+    // The debug representation includes the `OperationError` the builder failed with
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(error.contains("WildcardProperty"), "unexpected error: {error}");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_revoke_property() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -94,6 +123,7 @@ async fn test_revoke_property() -> anyhow::Result<()> {
         .await?;
     let result = client
         .revoke_property(*federation_id.object_id(), property_name.clone(), None)
+        .confirm_irreversible()
         .build_and_execute(&client)
         .await;
 
@@ -102,6 +132,38 @@ async fn test_revoke_property() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_revoke_property_requires_confirmation() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+
+    let property_name = PropertyName::from("test.irreversible");
+    client
+        .add_property(
+            *federation.id.object_id(),
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    // An immediate revocation without confirmation must not be built
+    let result = client
+        .revoke_property(*federation.id.object_id(), property_name.clone(), None)
+        .build_and_execute(&client)
+        .await;
+
+    let error = result.expect_err("unconfirmed revocation should fail");
+    assert!(format!("{error:?}").contains("DestructiveNotConfirmed"));
+
+    // The property is still part of the federation
+    assert!(
+        client
+            .is_property_in_federation(*federation.id.object_id(), property_name)
+            .await?
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_and_get_properties() -> anyhow::Result<()> {
     let (federation, client) = create_test_federation().await?;
@@ -129,6 +191,68 @@ async fn test_create_and_get_properties() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_get_properties_by_prefix() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let iso_9001 = PropertyName::new(["compliance", "iso", "9001"]);
+    let gdpr = PropertyName::new(["compliance", "gdpr"]);
+    for name in [iso_9001.clone(), gdpr.clone(), PropertyName::from("role")] {
+        client
+            .add_property(federation_id, FederationProperty::new(name).with_allow_any(true))
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let mut compliance = client.get_properties_by_prefix(federation_id, "compliance").await?;
+    compliance.sort();
+    assert_eq!(compliance, vec![gdpr, iso_9001.clone()]);
+
+    let iso = client
+        .get_properties_by_prefix(federation_id, PropertyName::new(["compliance", "iso"]))
+        .await?;
+    assert_eq!(iso, vec![iso_9001]);
+
+    assert!(client.get_properties_by_prefix(federation_id, "comp").await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_properties_paged() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let names: Vec<PropertyName> = ["role", "department", "level"].map(PropertyName::from).into();
+    for name in &names {
+        client
+            .add_property(
+                federation_id,
+                FederationProperty::new(name.clone()).with_allow_any(true),
+            )
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let first = client.get_properties_paged(federation_id, None, 2).await?;
+    assert_eq!(first.properties.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = client.get_properties_paged(federation_id, first.next_cursor, 2).await?;
+    assert_eq!(second.properties.len(), 1);
+    assert_eq!(second.next_cursor, None);
+
+    let streamed: Vec<PropertyName> = client
+        .stream_properties(federation_id, 2)
+        .map_ok(|property| property.name)
+        .try_collect()
+        .await?;
+    assert_eq!(streamed, names);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_create_and_validate_property() -> anyhow::Result<()> {
     let (federation, client) = create_test_federation().await?;
@@ -149,7 +273,7 @@ async fn test_create_and_validate_property() -> anyhow::Result<()> {
     client
         .validate_property(
             *federation.id.object_id(),
-            client.sender_address().into(),
+            client.sender_address(),
             property_name.clone(),
             property_value,
         )
@@ -158,6 +282,112 @@ async fn test_create_and_validate_property() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_add_property_with_known_capability() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let cap: RootAuthorityCap = client
+        .find_object_for_address(client.sender_address(), |cap: &RootAuthorityCap| {
+            cap.federation_id == federation_id
+        })
+        .await?
+        .expect("root authority cap should exist");
+
+    let property_name = PropertyName::from("test.known.capability");
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .with_capability(*cap.id.object_id())
+        .build_and_execute(&client)
+        .await?;
+
+    let federation: Federation = get_object_ref_by_id_with_bcs(&client, &federation_id).await?;
+    assert!(federation.governance.properties.data.contains_key(&property_name));
+
+    // A capability that doesn't exist fails before anything is submitted.
+    let result = client
+        .add_property(
+            federation_id,
+            FederationProperty::new("test.unknown.capability").with_allow_any(true),
+        )
+        .with_capability(ObjectID::random())
+        .build_and_execute(&client)
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_property_with_prefetched_inputs() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let inputs = client
+        .prefetch_inputs(federation_id, client.sender_address(), CapabilityKind::RootAuthority)
+        .await?;
+    let inputs: PrefetchedInputs = serde_json::from_str(&serde_json::to_string(&inputs)?)?;
+
+    let property_name = PropertyName::from("test.prefetched.inputs");
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .with_prefetched_inputs(&inputs)
+        .build_and_execute(&client)
+        .await?;
+
+    let federation: Federation = get_object_ref_by_id_with_bcs(&client, &federation_id).await?;
+    assert!(federation.governance.properties.data.contains_key(&property_name));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scoped_client() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    client
+        .add_property(federation_id, FederationProperty::new("other").with_allow_any(true))
+        .build_and_execute(&client)
+        .await?;
+
+    let iso = client.scoped("iso");
+    iso.add_property(federation_id, FederationProperty::new("9001").with_allow_any(true))
+        .build_and_execute(&client)
+        .await?;
+
+    let full_name = PropertyName::new(["iso", "9001"]);
+    assert_eq!(iso.get_properties(federation_id).await?, vec![full_name.clone()]);
+    assert!(client.is_property_in_federation(federation_id, full_name).await?);
+
+    let attester = ObjectID::random();
+    iso.create_accreditation_to_attest(
+        federation_id,
+        attester,
+        vec![FederationProperty::new("9001").with_allow_any(true)],
+    )
+    .build_and_execute(&client)
+    .await?;
+
+    let value = PropertyValue::Text("certified".to_string());
+    assert!(
+        iso.validate_property(federation_id, attester, "9001".into(), value.clone())
+            .await?
+    );
+    assert!(
+        !iso.validate_property(federation_id, attester, "other".into(), value)
+            .await?
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_add_property_with_allow_any() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -235,6 +465,38 @@ async fn test_add_property_with_empty_allowed_values_and_allow_any_false_fails()
     Ok(())
 }
 
+#[tokio::test]
+async fn test_empty_allowed_values_maps_to_typed_error() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+
+    // Create a new federation
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+
+    let result = client
+        .add_property(
+            *federation_id.object_id(),
+            FederationProperty::new(PropertyName::from("test.invalid.property")),
+        )
+        .build_and_execute(&client)
+        .await;
+
+    let error = result.unwrap_err();
+    assert!(
+        matches!(
+            MoveAbortMapper::new().map_error(&error),
+            Some(TransactionError::EmptyAllowedValues)
+        ),
+        "Expected EmptyAllowedValues error, got: {error:?}"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_add_property_with_empty_allowed_values_and_allow_any_true_succeeds() -> anyhow::Result<()> {
     let client = get_funded_test_client().await?;
@@ -356,3 +618,334 @@ async fn test_add_property_with_expression_and_allowed_values() -> anyhow::Resul
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_set_property_critical() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let property_name = PropertyName::from("kyc.level");
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(
+        !client
+            .is_property_critical(federation_id, property_name.clone())
+            .await?
+    );
+
+    client
+        .set_property_critical(federation_id, property_name.clone(), true)
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(
+        client
+            .is_property_critical(federation_id, property_name.clone())
+            .await?
+    );
+    assert_eq!(
+        client.get_critical_properties(federation_id).await?,
+        vec![property_name.clone()]
+    );
+
+    let federation: Federation = client.get_federation_by_id(federation_id).await?;
+    assert!(federation.governance.critical_properties.contains(&property_name));
+
+    client
+        .set_property_critical(federation_id, property_name.clone(), false)
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(!client.is_property_critical(federation_id, property_name).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deprecate_property() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let old_name = PropertyName::from("kyc.level");
+    let new_name = PropertyName::from("kyc.tier");
+    for name in [&old_name, &new_name] {
+        client
+            .add_property(
+                federation_id,
+                FederationProperty::new(name.clone()).with_allow_any(true),
+            )
+            .build_and_execute(&client)
+            .await?;
+    }
+
+    let result = client
+        .deprecate_property(federation_id, old_name.clone(), Some(PropertyName::from("kyc.unknown")))
+        .build_and_execute(&client)
+        .await;
+    assert!(matches!(
+        MoveAbortMapper::new().map_error(&result.unwrap_err()),
+        Some(TransactionError::InvalidPropertyReplacement)
+    ));
+    assert!(!client.is_property_deprecated(federation_id, old_name.clone()).await?);
+
+    client
+        .deprecate_property(federation_id, old_name.clone(), Some(new_name.clone()))
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(client.is_property_deprecated(federation_id, old_name.clone()).await?);
+    assert!(!client.is_property_deprecated(federation_id, new_name.clone()).await?);
+    assert_eq!(
+        client.get_deprecated_properties(federation_id).await?,
+        HashMap::from([(old_name.clone(), Some(new_name.clone()))])
+    );
+    // Deprecated properties stay part of the federation
+    assert!(client.get_properties(federation_id).await?.contains(&old_name));
+
+    let federation: Federation = client.get_federation_by_id(federation_id).await?;
+    let report = diagnose_property(
+        &federation,
+        &ObjectID::random(),
+        &old_name,
+        &PropertyValue::Text("basic".to_string()),
+        0,
+    );
+    assert_eq!(
+        report.checks[0].warning,
+        Some(ValidationWarning::Deprecated {
+            replacement: Some(new_name)
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_value_dictionary() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    client
+        .set_value_dictionary(
+            federation_id,
+            "countries",
+            [
+                PropertyValue::Text("CH".to_string()),
+                PropertyValue::Text("DE".to_string()),
+            ],
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let property_name = PropertyName::from("address.country");
+    let property = FederationProperty::new(property_name.clone()).with_value_dictionary("countries");
+    client
+        .add_property(federation_id, property.clone())
+        .build_and_execute(&client)
+        .await?;
+
+    let receiver = ObjectID::random();
+    client
+        .create_accreditation_to_attest(federation_id, receiver, vec![property])
+        .build_and_execute(&client)
+        .await?;
+
+    let attests = |value: &str| {
+        client.validate_property(
+            federation_id,
+            receiver,
+            property_name.clone(),
+            PropertyValue::Text(value.to_string()),
+        )
+    };
+    assert!(attests("CH").await?);
+    assert!(!attests("FR").await?);
+
+    client
+        .set_value_dictionary(federation_id, "countries", [PropertyValue::Text("FR".to_string())])
+        .build_and_execute(&client)
+        .await?;
+
+    assert!(!attests("CH").await?);
+    assert!(attests("FR").await?);
+    assert_eq!(
+        client.get_value_dictionary_names(federation_id).await?,
+        vec!["countries".to_string()]
+    );
+
+    let federation: Federation = client.get_federation_by_id(federation_id).await?;
+    assert_eq!(
+        federation.governance.value_dictionaries["countries"],
+        HashSet::from([PropertyValue::Text("FR".to_string())])
+    );
+
+    // A dictionary referenced by a property cannot be removed
+    let removed = client
+        .remove_value_dictionary(federation_id, "countries")
+        .build_and_execute(&client)
+        .await;
+    assert!(removed.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invariant_checks() -> anyhow::Result<()> {
+    let (federation, client) = create_test_federation().await?;
+    let federation_id = *federation.id.object_id();
+
+    let property_name = PropertyName::from("audit.passed");
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .with_invariant_checks()
+        .build_and_execute(&client)
+        .await?;
+
+    client
+        .revoke_property(federation_id, property_name, None)
+        .confirm_irreversible()
+        .with_invariant_checks()
+        .build_and_execute(&client)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_events_polling() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let federation_id = client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id;
+    let federation_id = *federation_id.object_id();
+
+    let options = SubscribeOptions::default()
+        .with_mode(SubscriptionMode::Polling)
+        .with_poll_interval(Duration::from_millis(200));
+    let mut events = client.subscribe_events_with(federation_id, options).await?;
+
+    let property_name = PropertyName::from("subscribed.property");
+    client
+        .add_property(
+            federation_id,
+            FederationProperty::new(property_name.clone()).with_allow_any(true),
+        )
+        .build_and_execute(&client)
+        .await?;
+
+    let event = tokio::time::timeout(Duration::from_secs(30), events.next())
+        .await?
+        .expect("stream ended")?;
+    match event.event {
+        HierarchiesEvent::PropertyAdded(added) => assert_eq!(added.property.name, property_name),
+        other => panic!("unexpected event {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_batch() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let federation_id = *client
+        .create_new_federation()
+        .build_and_execute(&client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let role = FederationProperty::new(PropertyName::from("batch.role")).with_allow_any(true);
+    let level = FederationProperty::new(PropertyName::from("batch.level")).with_value_dictionary("levels");
+    let attester = ObjectID::random();
+
+    client
+        .transaction_batch(federation_id)
+        .set_value_dictionary("levels", [PropertyValue::Number(1), PropertyValue::Number(2)])
+        .add_property(role.clone())
+        .add_property(level.clone())
+        .set_property_critical(role.name.clone(), true)
+        .create_accreditation_to_attest(attester, [role.clone()])
+        .into_builder()
+        .build_and_execute(&client)
+        .await?;
+
+    let properties = client.get_properties(federation_id).await?;
+    assert!(properties.contains(&role.name));
+    assert!(properties.contains(&level.name));
+    assert!(client.is_property_critical(federation_id, role.name.clone()).await?);
+    assert!(
+        client
+            .validate_property(
+                federation_id,
+                attester,
+                role.name,
+                PropertyValue::Text("admin".to_string())
+            )
+            .await?
+    );
+
+    let empty = client
+        .transaction_batch(federation_id)
+        .into_builder()
+        .build(&client)
+        .await;
+    assert!(empty.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_validate_federation_set() -> anyhow::Result<()> {
+    let client = get_funded_test_client().await?;
+    let attester = ObjectID::random();
+    let approved = PropertyName::from("import.approved");
+    let value = PropertyValue::Bool(true);
+
+    let mut federation_ids = Vec::new();
+    for accredited in [true, false] {
+        let federation_id = *client
+            .create_new_federation()
+            .build_and_execute(&client)
+            .await?
+            .output
+            .id
+            .object_id();
+        let property = FederationProperty::new(approved.clone()).with_allowed_values([value.clone()]);
+        client
+            .add_property(federation_id, property.clone())
+            .build_and_execute(&client)
+            .await?;
+        if accredited {
+            client
+                .create_accreditation_to_attest(federation_id, attester, [property])
+                .build_and_execute(&client)
+                .await?;
+        }
+        federation_ids.push(federation_id);
+    }
+
+    let set = federation_ids.iter().fold(FederationSet::new(), |set, federation_id| {
+        set.with(*federation_id, [(approved.clone(), value.clone())])
+    });
+    let validation = client.validate_federation_set(&set, attester).await?;
+
+    assert_eq!(validation.get(&federation_ids[0]), Some(true));
+    assert_eq!(validation.get(&federation_ids[1]), Some(false));
+    assert!(validation.is_valid_in_any());
+    assert!(!validation.is_valid_in_all());
+
+    Ok(())
+}